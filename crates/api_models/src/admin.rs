@@ -332,7 +332,8 @@ pub struct PrimaryBusinessDetails {
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct WebhookDetails {
-    ///The version for Webhook
+    ///The version for Webhook. The major version pins the shape of the outgoing webhook payload,
+    ///version 1 is used if it is not provided
     #[schema(max_length = 255, max_length = 255, example = "1.0.2")]
     pub webhook_version: Option<String>,
 
@@ -359,6 +360,11 @@ pub struct WebhookDetails {
     /// If this property is true, a webhook message is posted whenever a payment fails
     #[schema(example = true)]
     pub payment_failed_enabled: Option<bool>,
+
+    /// The event types for which a webhook message is posted. If this property is not provided, a
+    /// webhook message is posted for every event type
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["payment_succeeded", "refund_failed"]))]
+    pub enabled_events: Option<Vec<api_enums::EventType>>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -403,6 +409,10 @@ pub struct MerchantConnectorCreate {
     #[schema(value_type = Option<MerchantConnectorDetails>,example = json!({ "auth_type": "HeaderKey","api_key": "Basic MyVerySecretApiKey" }))]
     pub connector_account_details: Option<pii::SecretSerdeValue>,
 
+    /// The credentials for the sandbox environment of the Connector, in the same format as `connector_account_details`. These are used instead of `connector_account_details` for requests authenticated with a test mode API key.
+    #[schema(value_type = Option<MerchantConnectorDetails>,example = json!({ "auth_type": "HeaderKey","api_key": "Basic MyVerySecretSandboxApiKey" }))]
+    pub test_connector_account_details: Option<pii::SecretSerdeValue>,
+
     /// An object containing the details about the payment methods that need to be enabled under this merchant connector account
     #[schema(example = json!([
         {
@@ -478,6 +488,10 @@ pub struct MerchantConnectorCreate {
 
     #[schema(value_type = Option<ConnectorStatus>, example = "inactive")]
     pub status: Option<api_enums::ConnectorStatus>,
+
+    /// A boolean value to indicate if the connector account details should be verified with the connector, by making a verification call to it. The result is returned in `credentials_verified`. By default, its value is false.
+    #[schema(default = false, example = false)]
+    pub verify_credentials: Option<bool>,
 }
 
 // Different patterns of authentication.
@@ -552,10 +566,16 @@ pub struct MerchantConnectorResponse {
     #[schema(max_length = 64)]
     pub profile_id: Option<String>,
 
-    /// An object containing the required details/credentials for a Connector account.
-    #[schema(value_type = Option<MerchantConnectorDetails>,example = json!({ "auth_type": "HeaderKey","api_key": "Basic MyVerySecretApiKey" }))]
+    /// An object containing the required details/credentials for a Connector account. The
+    /// credentials are masked, and only the last four characters of each credential are returned.
+    #[schema(value_type = Option<MerchantConnectorDetails>,example = json!({ "auth_type": "HeaderKey","api_key": "********************iKey" }))]
     pub connector_account_details: pii::SecretSerdeValue,
 
+    /// The credentials for the sandbox environment of the Connector, masked in the same way as
+    /// `connector_account_details`.
+    #[schema(value_type = Option<MerchantConnectorDetails>,example = json!({ "auth_type": "HeaderKey","api_key": "********************iKey" }))]
+    pub test_connector_account_details: Option<pii::SecretSerdeValue>,
+
     /// An object containing the details about the payment methods that need to be enabled under this merchant connector account
     #[schema(example = json!([
         {
@@ -631,6 +651,10 @@ pub struct MerchantConnectorResponse {
 
     #[schema(value_type = ConnectorStatus, example = "inactive")]
     pub status: api_enums::ConnectorStatus,
+
+    /// Whether the connector accepted the connector account details, if they were verified on this request. This is null if verification was not requested or is not supported for the connector.
+    #[schema(example = true)]
+    pub credentials_verified: Option<bool>,
 }
 
 /// Create a new Merchant Connector for the merchant account. The connector could be a payment processor / facilitator / acquirer or specialized services like Fraud / Accounting etc."
@@ -649,6 +673,10 @@ pub struct MerchantConnectorUpdate {
     #[schema(value_type = Option<MerchantConnectorDetails>,example = json!({ "auth_type": "HeaderKey","api_key": "Basic MyVerySecretApiKey" }))]
     pub connector_account_details: Option<pii::SecretSerdeValue>,
 
+    /// The credentials for the sandbox environment of the Connector, in the same format as `connector_account_details`. These are used instead of `connector_account_details` for requests authenticated with a test mode API key.
+    #[schema(value_type = Option<MerchantConnectorDetails>,example = json!({ "auth_type": "HeaderKey","api_key": "Basic MyVerySecretSandboxApiKey" }))]
+    pub test_connector_account_details: Option<pii::SecretSerdeValue>,
+
     /// An object containing the details about the payment methods that need to be enabled under this merchant connector account
     #[schema(example = json!([
         {
@@ -709,6 +737,10 @@ pub struct MerchantConnectorUpdate {
 
     #[schema(value_type = ConnectorStatus, example = "inactive")]
     pub status: Option<api_enums::ConnectorStatus>,
+
+    /// A boolean value to indicate if the connector account details should be verified with the connector, by making a verification call to it. The result is returned in `credentials_verified`. By default, its value is false.
+    #[schema(default = false, example = false)]
+    pub verify_credentials: Option<bool>,
 }
 
 ///Details of FrmConfigs are mentioned here... it should be passed in payment connector create api call, and stored in merchant_connector_table
@@ -913,6 +945,16 @@ pub struct BusinessProfileCreate {
 
     /// External 3DS authentication details
     pub authentication_connector_details: Option<AuthenticationConnectorDetails>,
+
+    /// Static HTTP headers sent with every outgoing webhook, for example an `Authorization` header
+    /// with a bearer token or basic auth credentials. The headers are stored encrypted
+    #[schema(value_type = Option<Object>, example = json!({"Authorization": "Bearer token"}))]
+    pub outgoing_webhook_custom_http_headers: Option<HashMap<String, Secret<String>>>,
+
+    /// The default statement descriptor for payments created under this business profile, used
+    /// when the payment request does not specify `statement_descriptor_name`
+    #[schema(max_length = 255, example = "Hyperswitch Router")]
+    pub statement_descriptor_name: Option<String>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -982,6 +1024,11 @@ pub struct BusinessProfileResponse {
 
     /// External 3DS authentication details
     pub authentication_connector_details: Option<AuthenticationConnectorDetails>,
+
+    /// The default statement descriptor for payments created under this business profile, used
+    /// when the payment request does not specify `statement_descriptor_name`
+    #[schema(max_length = 255, example = "Hyperswitch Router")]
+    pub statement_descriptor_name: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -999,7 +1046,7 @@ pub struct BusinessProfileUpdate {
     #[schema(default = true, example = true)]
     pub enable_payment_response_hash: Option<bool>,
 
-    /// Refers to the hash key used for calculating the signature for webhooks and redirect response. If the value is not provided, a default value is used.
+    /// Refers to the hash key used for calculating the signature for webhooks and redirect response. If the value is not provided, a default value is used. When the hash key is changed, outgoing webhooks are signed with both the new and the previous key for the next 24 hours.
     pub payment_response_hash_key: Option<String>,
 
     /// A boolean value to indicate if redirect to merchant with http post needs to be enabled
@@ -1046,6 +1093,127 @@ pub struct BusinessProfileUpdate {
 
     /// Merchant's config to support extended card info feature
     pub extended_card_info_config: Option<ExtendedCardInfoConfig>,
+
+    /// Static HTTP headers sent with every outgoing webhook, for example an `Authorization` header
+    /// with a bearer token or basic auth credentials. The headers are stored encrypted
+    #[schema(value_type = Option<Object>, example = json!({"Authorization": "Bearer token"}))]
+    pub outgoing_webhook_custom_http_headers: Option<HashMap<String, Secret<String>>>,
+
+    /// The default statement descriptor for payments created under this business profile, used
+    /// when the payment request does not specify `statement_descriptor_name`
+    #[schema(max_length = 255, example = "Hyperswitch Router")]
+    pub statement_descriptor_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointCreate {
+    /// The URL to which webhook messages are posted
+    #[schema(value_type = String, example = "https://erp.example.com/webhooks")]
+    pub url: Secret<String>,
+
+    /// A description of what the endpoint is used for
+    #[schema(max_length = 255, example = "ERP integration")]
+    pub description: Option<String>,
+
+    /// The secret used to sign the webhook messages posted to the endpoint. A secret is generated
+    /// if it is not provided
+    #[schema(value_type = Option<String>, max_length = 255)]
+    pub secret: Option<Secret<String>>,
+
+    /// The event types for which a webhook message is posted to the endpoint. If this property is
+    /// not provided, a webhook message is posted for every event type
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["payment_succeeded", "refund_failed"]))]
+    pub enabled_events: Option<Vec<api_enums::EventType>>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointUpdate {
+    /// The URL to which webhook messages are posted
+    #[schema(value_type = Option<String>, example = "https://erp.example.com/webhooks")]
+    pub url: Option<Secret<String>>,
+
+    /// A description of what the endpoint is used for
+    #[schema(max_length = 255, example = "ERP integration")]
+    pub description: Option<String>,
+
+    /// The secret used to sign the webhook messages posted to the endpoint
+    #[schema(value_type = Option<String>, max_length = 255)]
+    pub secret: Option<Secret<String>>,
+
+    /// The event types for which a webhook message is posted to the endpoint
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["payment_succeeded", "refund_failed"]))]
+    pub enabled_events: Option<Vec<api_enums::EventType>>,
+
+    /// Whether webhook messages are posted to the endpoint. Messages are not posted to disabled
+    /// endpoints, while their configuration is retained
+    pub is_active: Option<bool>,
+}
+
+#[derive(Clone, Debug, ToSchema, Serialize)]
+pub struct WebhookEndpointResponse {
+    /// The identifier for the webhook endpoint
+    #[schema(max_length = 64, example = "whe_abcdefghijklmnopqrst")]
+    pub endpoint_id: String,
+
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The identifier for the Business Profile
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnopqrst")]
+    pub profile_id: String,
+
+    /// The URL to which webhook messages are posted
+    #[schema(value_type = String, example = "https://erp.example.com/webhooks")]
+    pub url: Secret<String>,
+
+    /// A description of what the endpoint is used for
+    pub description: Option<String>,
+
+    /// The secret used to sign the webhook messages posted to the endpoint. It is only returned
+    /// in the response of the request that creates the endpoint
+    #[schema(value_type = Option<String>)]
+    pub secret: Option<Secret<String>>,
+
+    /// The event types for which a webhook message is posted to the endpoint
+    #[schema(value_type = Option<Vec<EventType>>)]
+    pub enabled_events: Option<Vec<api_enums::EventType>>,
+
+    /// Whether webhook messages are posted to the endpoint
+    pub is_active: bool,
+
+    /// Time at which the endpoint was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+
+    /// Time at which the endpoint was last modified
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, ToSchema, Serialize)]
+pub struct WebhookEndpointTestResponse {
+    /// The identifier for the webhook endpoint
+    #[schema(max_length = 64, example = "whe_abcdefghijklmnopqrst")]
+    pub endpoint_id: String,
+
+    /// Whether the endpoint responded to the test event with a success (2xx) status code
+    pub is_successful: bool,
+
+    /// The status code with which the endpoint responded, if a response was received
+    #[schema(example = 200)]
+    pub status_code: Option<u16>,
+
+    /// The time taken for the endpoint to respond, in milliseconds
+    #[schema(example = 120)]
+    pub latency_ms: u64,
+
+    /// The reason the test event could not be delivered, if no response was received
+    pub error_message: Option<String>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, ToSchema)]
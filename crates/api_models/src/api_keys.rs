@@ -23,6 +23,22 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// Whether the API Key is a test mode key. Requests authenticated with a test mode key use the
+    /// test connector credentials configured on the merchant connector accounts.
+    #[schema(default = false, example = false)]
+    #[serde(default)]
+    pub test_mode: bool,
+
+    /// The IP addresses or CIDR blocks from which the API Key can be used. Requests from other IP
+    /// addresses are rejected. If not provided, the API Key can be used from any IP address.
+    #[schema(example = json!(["192.0.2.1", "198.51.100.0/24"]))]
+    pub ip_allowlist: Option<Vec<String>>,
+
+    /// The role of the API Key, which restricts the operations that can be performed with it to the
+    /// permissions of the role. If not provided, the API Key can perform all operations.
+    #[schema(max_length = 64, example = "merchant_developer")]
+    pub role_id: Option<String>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +76,18 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// Whether the API Key is a test mode key.
+    #[schema(example = false)]
+    pub test_mode: bool,
+
+    /// The IP addresses or CIDR blocks from which the API Key can be used.
+    #[schema(example = json!(["192.0.2.1", "198.51.100.0/24"]))]
+    pub ip_allowlist: Option<Vec<String>>,
+
+    /// The role of the API Key.
+    #[schema(max_length = 64, example = "merchant_developer")]
+    pub role_id: Option<String>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,12 +130,23 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
-    /*
+
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub last_used: Option<PrimitiveDateTime>,
-    */
+
+    /// Whether the API Key is a test mode key.
+    #[schema(example = false)]
+    pub test_mode: bool,
+
+    /// The IP addresses or CIDR blocks from which the API Key can be used.
+    #[schema(example = json!(["192.0.2.1", "198.51.100.0/24"]))]
+    pub ip_allowlist: Option<Vec<String>>,
+
+    /// The role of the API Key.
+    #[schema(max_length = 64, example = "merchant_developer")]
+    pub role_id: Option<String>,
 }
 
 /// The request body for updating an API Key.
@@ -130,6 +169,32 @@ pub struct UpdateApiKeyRequest {
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
 
+    /// The IP addresses or CIDR blocks from which the API Key can be used. An empty list allows
+    /// the API Key to be used from any IP address.
+    #[schema(example = json!(["192.0.2.1", "198.51.100.0/24"]))]
+    pub ip_allowlist: Option<Vec<String>>,
+
+    #[serde(skip_deserializing)]
+    pub key_id: String,
+
+    #[serde(skip_deserializing)]
+    pub merchant_id: String,
+}
+
+/// The request body for rotating an API Key.
+#[derive(Debug, Default, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RotateApiKeyRequest {
+    /// An expiration date for the newly created API Key. If not provided, the expiration of the
+    /// API Key being rotated is retained.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    pub expiration: Option<ApiKeyExpiration>,
+
+    /// The number of hours for which the API Key being rotated remains valid alongside the newly
+    /// created API Key, allowing it to be replaced without any downtime. Defaults to 24 hours.
+    #[schema(maximum = 720, example = 24)]
+    pub previous_key_validity_hours: Option<u16>,
+
     #[serde(skip_deserializing)]
     pub key_id: String,
 
@@ -0,0 +1,86 @@
+use common_enums::{AuditLogAction, AuditLogActorType, AuditLogEntityType};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The constraints to apply when listing audit logs.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogListConstraints {
+    /// Filter audit logs of changes to the specified type of configuration.
+    pub entity_type: Option<AuditLogEntityType>,
+
+    /// Filter audit logs of changes to the configuration with the specified identifier (Merchant
+    /// Connector ID, API Key ID, etc.)
+    pub entity_id: Option<String>,
+
+    /// Filter audit logs created after the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Filter audit logs created before the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Include at most the specified number of audit logs.
+    pub limit: Option<u16>,
+
+    /// Include audit logs after the specified offset.
+    pub offset: Option<u16>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuditLogListRequestInternal {
+    pub merchant_id: String,
+    pub constraints: AuditLogListConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for AuditLogListRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::ResourceListAPI)
+    }
+}
+
+/// The response body for each item when listing audit logs.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuditLogResponse {
+    /// The identifier for the audit log entry.
+    #[schema(max_length = 64, example = "audit_018e31720d1b7a2b82677d3032cab959")]
+    pub audit_log_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The type of configuration that was changed.
+    pub entity_type: AuditLogEntityType,
+
+    /// The identifier for the configuration that was changed (Merchant Connector ID, API Key ID,
+    /// etc.)
+    #[schema(max_length = 64, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub entity_id: String,
+
+    /// The change that was made to the configuration.
+    pub action: AuditLogAction,
+
+    /// The type of credential that the change was made with.
+    pub actor_type: AuditLogActorType,
+
+    /// The identifier for the API key or the user that made the change, if any.
+    #[schema(max_length = 255, example = "dev_Z0xn4QAmjl6bhAc6RXcf")]
+    pub actor_id: Option<String>,
+
+    /// The configuration before the change, with secrets masked. This is null for configurations
+    /// that were created.
+    #[schema(value_type = Option<Object>)]
+    pub old_value: Option<serde_json::Value>,
+
+    /// The configuration after the change, with secrets masked. This is null for configurations
+    /// that were deleted.
+    #[schema(value_type = Option<Object>)]
+    pub new_value: Option<serde_json::Value>,
+
+    /// Time at which the change was made.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
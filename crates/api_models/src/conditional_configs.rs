@@ -58,6 +58,12 @@ impl EuclidAnalysable for AuthenticationType {
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ConditionalConfigs {
     pub override_3ds: Option<AuthenticationType>,
+    /// Whether `override_3ds` takes precedence over the authentication type of the payment
+    /// request, e.g. for payments where strong customer authentication is mandated
+    #[serde(default)]
+    pub enforce_3ds: bool,
+    /// Reason for the authentication type decision, recorded on the payment attempt
+    pub decision_reason: Option<String>,
 }
 impl EuclidDirFilter for ConditionalConfigs {
     const ALLOWED: &'static [DirKeyKind] = &[
@@ -70,6 +76,10 @@ impl EuclidDirFilter for ConditionalConfigs {
         DirKeyKind::CaptureMethod,
         DirKeyKind::BillingCountry,
         DirKeyKind::BusinessCountry,
+        DirKeyKind::ShippingCountry,
+        DirKeyKind::IpCountry,
+        DirKeyKind::CardCategory,
+        DirKeyKind::CardIssuingCountry,
     ];
 }
 
@@ -0,0 +1,61 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::enums;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectorCapabilitiesRequest {
+    /// The connector whose capabilities are to be retrieved
+    pub connector: enums::Connector,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ConnectorCapabilitiesResponse {
+    /// The connector whose capabilities were retrieved
+    #[schema(value_type = Connector, example = "stripe")]
+    pub connector: enums::Connector,
+    /// Payment method types and card networks for which the connector has restrictions configured
+    pub payment_methods: Vec<PaymentMethodCapability>,
+    /// The capture methods supported by the connector
+    #[schema(value_type = Vec<CaptureMethod>)]
+    pub supported_capture_methods: Vec<enums::CaptureMethod>,
+    /// Payment method types for which the connector supports creating mandates
+    #[schema(value_type = Vec<PaymentMethodType>)]
+    pub mandate_payment_method_types: Vec<enums::PaymentMethodType>,
+    /// Whether the connector can be used for separate (external) 3DS authentication
+    pub separate_authentication_supported: bool,
+    /// Whether the source of the webhooks sent by the connector must be verified
+    pub webhook_source_verification_mandatory: bool,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PaymentMethodCapability {
+    /// The payment method type the capability applies to
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+    /// The card network the capability applies to
+    #[schema(value_type = Option<CardNetwork>, example = "Visa")]
+    pub card_network: Option<enums::CardNetwork>,
+    /// The currencies supported, `None` if there is no restriction
+    #[schema(value_type = Option<Vec<Currency>>)]
+    pub currencies: Option<Vec<enums::Currency>>,
+    /// The countries supported, `None` if there is no restriction
+    #[schema(value_type = Option<Vec<CountryAlpha2>>)]
+    pub countries: Option<Vec<enums::CountryAlpha2>>,
+    /// The capture methods supported by the connector for this payment method
+    #[schema(value_type = Vec<CaptureMethod>)]
+    pub supported_capture_methods: Vec<enums::CaptureMethod>,
+}
+
+impl ApiEventMetric for ConnectorCapabilitiesRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for ConnectorCapabilitiesResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
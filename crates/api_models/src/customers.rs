@@ -41,6 +41,55 @@ pub struct CustomerRequest {
     pub metadata: Option<pii::SecretSerdeValue>,
 }
 
+/// The constraints to apply when listing customers
+#[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerListConstraints {
+    /// Include at most the specified number of customers.
+    pub limit: Option<u16>,
+
+    /// Include customers after the specified offset.
+    pub offset: Option<u16>,
+
+    /// A cursor for use in pagination, fetch the next list after the specified customer.
+    #[schema(example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub starting_after: Option<String>,
+
+    /// A cursor for use in pagination, fetch the previous list before the specified customer.
+    #[schema(example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub ending_before: Option<String>,
+
+    /// Filter customers by their email address.
+    #[schema(value_type = Option<String>, example = "JonTest@test.com")]
+    pub email: Option<pii::Email>,
+
+    /// Filter customers by their phone number.
+    #[schema(value_type = Option<String>, example = "9999999999")]
+    pub phone: Option<Secret<String>>,
+
+    /// Filter customers created after the specified time.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub created_after: Option<time::PrimitiveDateTime>,
+
+    /// Filter customers created before the specified time.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub created_before: Option<time::PrimitiveDateTime>,
+
+    /// Filter customers whose metadata contains the specified key.
+    #[schema(example = "udf1")]
+    pub metadata_key: Option<String>,
+}
+
+impl CustomerListConstraints {
+    /// Whether the constraints contain filters that can only be applied on decrypted customer
+    /// records, and hence cannot be pushed down to the database query.
+    pub fn has_decrypted_field_filters(&self) -> bool {
+        self.email.is_some() || self.phone.is_some() || self.metadata_key.is_some()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct CustomerResponse {
     /// The identifier for the customer object. If not provided the customer ID will be autogenerated.
@@ -97,6 +146,21 @@ pub struct CustomerDeleteResponse {
     /// Whether payment methods deleted or not
     #[schema(example = false)]
     pub payment_methods_deleted: bool,
+    /// The records from which the customer's personal information was redacted
+    pub redaction_report: CustomerRedactionReport,
+}
+
+#[derive(Default, Debug, Deserialize, Serialize, ToSchema)]
+pub struct CustomerRedactionReport {
+    /// Number of saved payment methods of the customer that were redacted
+    #[schema(example = 2)]
+    pub payment_methods_redacted: usize,
+    /// Number of addresses of the customer that were redacted
+    #[schema(example = 1)]
+    pub addresses_redacted: usize,
+    /// Number of payments made by the customer whose billing or shipping address was redacted
+    #[schema(example = 5)]
+    pub payments_redacted: usize,
 }
 
 pub fn generate_customer_id() -> String {
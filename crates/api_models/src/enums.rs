@@ -115,6 +115,7 @@ pub enum Connector {
     Powertranz,
     Prophetpay,
     Rapyd,
+    Razorpay,
     Shift4,
     Square,
     Stax,
@@ -204,7 +205,6 @@ impl Connector {
             | Self::Ebanx
             | Self::Fiserv
             | Self::Forte
-            | Self::Globalpay
             | Self::Globepay
             | Self::Gocardless
             | Self::Helcim
@@ -222,6 +222,7 @@ impl Connector {
             | Self::Powertranz
             | Self::Prophetpay
             | Self::Rapyd
+            | Self::Razorpay
             | Self::Shift4
             | Self::Square
             | Self::Stax
@@ -241,7 +242,7 @@ impl Connector {
             | Self::Cybersource
             | Self::Noon
             | Self::Stripe => false,
-            Self::Checkout | Self::Nmi => true,
+            Self::Checkout | Self::Nmi | Self::Globalpay => true,
         }
         #[cfg(not(feature = "dummy_connector"))]
         match self {
@@ -263,7 +264,6 @@ impl Connector {
             | Self::Ebanx
             | Self::Fiserv
             | Self::Forte
-            | Self::Globalpay
             | Self::Globepay
             | Self::Gocardless
             | Self::Helcim
@@ -282,6 +282,7 @@ impl Connector {
             | Self::Powertranz
             | Self::Prophetpay
             | Self::Rapyd
+            | Self::Razorpay
             | Self::Shift4
             | Self::Square
             | Self::Stax
@@ -301,7 +302,7 @@ impl Connector {
             | Self::Noon
             | Self::Netcetera
             | Self::Stripe => false,
-            Self::Checkout => true,
+            Self::Checkout | Self::Globalpay => true,
         }
     }
 }
@@ -57,6 +57,10 @@ impl_misc_api_event_type!(
     BusinessProfileResponse,
     BusinessProfileUpdate,
     BusinessProfileCreate,
+    WebhookEndpointCreate,
+    WebhookEndpointUpdate,
+    WebhookEndpointResponse,
+    WebhookEndpointTestResponse,
     RevokeApiKeyResponse,
     ToggleKVResponse,
     ToggleKVRequest,
@@ -78,6 +82,7 @@ impl_misc_api_event_type!(
     ApplepayMerchantResponse,
     ApplepayVerifiedDomainsResponse,
     UpdateApiKeyRequest,
+    RotateApiKeyRequest,
     GetApiEventFiltersRequest,
     ApiEventFiltersResponse,
     GetInfoResponse,
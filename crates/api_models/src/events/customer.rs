@@ -1,6 +1,8 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
-use crate::customers::{CustomerDeleteResponse, CustomerId, CustomerRequest, CustomerResponse};
+use crate::customers::{
+    CustomerDeleteResponse, CustomerId, CustomerListConstraints, CustomerRequest, CustomerResponse,
+};
 
 impl ApiEventMetric for CustomerDeleteResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -33,3 +35,5 @@ impl ApiEventMetric for CustomerId {
         })
     }
 }
+
+impl ApiEventMetric for CustomerListConstraints {}
@@ -2,9 +2,11 @@
 pub mod admin;
 pub mod analytics;
 pub mod api_keys;
+pub mod audit_log;
 pub mod blocklist;
 pub mod cards_info;
 pub mod conditional_configs;
+pub mod connector_capabilities;
 pub mod connector_onboarding;
 pub mod currency;
 pub mod customers;
@@ -20,6 +22,7 @@ pub mod health_check;
 pub mod locker_migration;
 pub mod mandates;
 pub mod organization;
+pub mod payment_exports;
 pub mod payment_methods;
 pub mod payments;
 #[cfg(feature = "payouts")]
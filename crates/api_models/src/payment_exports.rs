@@ -0,0 +1,64 @@
+use common_utils::events::ApiEventMetric;
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::{enums, payments::TimeRange};
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentExportRequest {
+    /// The time range of creation of the payments to export. If the end time is not passed, the
+    /// payments created until the export is requested are included
+    #[serde(flatten)]
+    pub time_range: TimeRange,
+    /// The list of payment status to include in the export
+    #[schema(value_type = Option<Vec<IntentStatus>>)]
+    pub status: Option<Vec<enums::IntentStatus>>,
+    /// The list of connectors to include in the export
+    #[schema(value_type = Option<Vec<Connector>>)]
+    pub connector: Option<Vec<enums::Connector>>,
+    /// The file format of the export
+    #[serde(default)]
+    #[schema(value_type = PaymentExportFormat)]
+    pub format: enums::PaymentExportFormat,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentExportResponse {
+    /// The identifier for the export
+    pub export_id: String,
+    /// The status of the export
+    #[schema(value_type = PaymentExportStatus)]
+    pub status: enums::PaymentExportStatus,
+    /// The file format of the export
+    #[schema(value_type = PaymentExportFormat)]
+    pub format: enums::PaymentExportFormat,
+    /// The number of payments included in the export, once it has succeeded
+    pub row_count: Option<i64>,
+    /// A signed URL to download the export file from, once the export has succeeded. The URL can
+    /// be used without authentication until it expires; retrieve the export again for a new one
+    pub download_url: Option<String>,
+    /// The time at which the download URL expires
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub download_url_expires_at: Option<PrimitiveDateTime>,
+    /// The time at which the export was requested
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PaymentExportId {
+    pub export_id: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PaymentExportDownloadRequest {
+    pub export_id: String,
+    pub token: String,
+}
+
+impl ApiEventMetric for PaymentExportRequest {}
+impl ApiEventMetric for PaymentExportResponse {}
+impl ApiEventMetric for PaymentExportId {}
+impl ApiEventMetric for PaymentExportDownloadRequest {}
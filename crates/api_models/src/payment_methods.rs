@@ -205,7 +205,7 @@ impl CardDetailUpdate {
     }
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PaymentMethodResponse {
     /// Unique identifier for a merchant
     #[schema(example = "merchant_1671528864")]
@@ -264,6 +264,10 @@ pub struct PaymentMethodResponse {
 
     /// For Client based calls
     pub client_secret: Option<String>,
+
+    /// A stable identifier of the card number or bank account, which is the same for every payment method of the merchant created with the same details
+    #[schema(example = "fp_1a2b3c4d5e6f7g8h9i0j")]
+    pub fingerprint_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -951,6 +955,10 @@ pub struct CustomerPaymentMethod {
     /// Indicates if the payment method has been set to default or not
     #[schema(example = true)]
     pub default_payment_method_set: bool,
+
+    /// A stable identifier of the card number or bank account, which is the same for every payment method of the merchant created with the same details
+    #[schema(example = "fp_1a2b3c4d5e6f7g8h9i0j")]
+    pub fingerprint_id: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -1612,7 +1612,7 @@ impl GetPaymentMethodType for BankTransferData {
             Self::CimbVaBankTransfer { .. } => api_enums::PaymentMethodType::CimbVa,
             Self::DanamonVaBankTransfer { .. } => api_enums::PaymentMethodType::DanamonVa,
             Self::MandiriVaBankTransfer { .. } => api_enums::PaymentMethodType::MandiriVa,
-            Self::Pix {} => api_enums::PaymentMethodType::Pix,
+            Self::Pix { .. } => api_enums::PaymentMethodType::Pix,
             Self::Pse {} => api_enums::PaymentMethodType::Pse,
             Self::LocalBankTransfer { .. } => api_enums::PaymentMethodType::LocalBankTransfer,
         }
@@ -2177,7 +2177,14 @@ pub enum BankTransferData {
         /// The billing details for BniVa Bank Transfer
         billing_details: DokuBillingDetails,
     },
-    Pix {},
+    Pix {
+        /// The CPF (individual taxpayer registry) number of the payer
+        #[schema(value_type = Option<String>)]
+        cpf: Option<Secret<String>>,
+        /// The CNPJ (company registry) number of the payer
+        #[schema(value_type = Option<String>)]
+        cnpj: Option<Secret<String>>,
+    },
     Pse {},
     LocalBankTransfer {
         bank_code: Option<String>,
@@ -2232,7 +2239,7 @@ impl GetAddressFromPaymentMethodData for BankTransferData {
                 phone: None,
                 email: Some(billing_details.email.clone()),
             }),
-            Self::LocalBankTransfer { .. } | Self::Pix {} | Self::Pse {} => None,
+            Self::LocalBankTransfer { .. } | Self::Pix { .. } | Self::Pse {} => None,
         }
     }
 }
@@ -4731,6 +4738,79 @@ pub struct ExtendedCardInfoResponse {
     pub payload: String,
 }
 
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorPayloadsRequest {
+    /// The identifier for the payment
+    pub payment_id: String,
+    /// The identifier for the payment attempt
+    pub attempt_id: String,
+}
+
+/// A call made to the connector while processing a payment attempt
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorPayload {
+    /// The connector which was called
+    pub connector: String,
+    /// The flow for which the connector was called, e.g. Authorize
+    pub flow: String,
+    /// The url of the connector endpoint
+    pub url: String,
+    /// The http method used for the call
+    pub method: String,
+    /// The http status code returned by the connector
+    pub status_code: u16,
+    /// The masked request body sent to the connector
+    pub request: String,
+    /// The masked response body received from the connector
+    pub response: Option<String>,
+    /// The error encountered while calling the connector or handling its response
+    pub error: Option<String>,
+    /// The time at which the call was made
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorPayloadsResponse {
+    /// The identifier for the payment
+    pub payment_id: String,
+    /// The identifier for the payment attempt
+    pub attempt_id: String,
+    /// The connector calls made for the payment attempt, in the order they were made
+    pub payloads: Vec<ConnectorPayload>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct PaymentRoutingDecisionsRequest {
+    /// The identifier for the payment
+    pub payment_id: String,
+}
+
+/// The routing decision made for an attempt of a payment
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct PaymentAttemptRoutingDecision {
+    /// The identifier for the payment attempt
+    pub attempt_id: String,
+    /// The connector the attempt was routed to
+    pub connector: Option<String>,
+    /// The status of the attempt
+    pub status: enums::AttemptStatus,
+    /// The time at which the attempt was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The routing decision, `None` if the connector was not chosen by routing, e.g. when it was
+    /// given in the request or reused from the mandate
+    pub routing_decision: Option<crate::routing::RoutingDecision>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct PaymentRoutingDecisionsResponse {
+    /// The identifier for the payment
+    pub payment_id: String,
+    /// The routing decisions of the attempts of the payment, in the order they were created
+    pub attempts: Vec<PaymentAttemptRoutingDecision>,
+}
+
 #[cfg(test)]
 mod payments_request_api_contract {
     #![allow(clippy::unwrap_used)]
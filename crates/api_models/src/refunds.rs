@@ -146,6 +146,12 @@ pub struct RefundListRequest {
     pub limit: Option<i64>,
     /// The starting point within a list of objects
     pub offset: Option<i64>,
+    /// A cursor for use in pagination, fetch the next list after some refund
+    #[schema(example = "ref_fafa124123")]
+    pub starting_after: Option<String>,
+    /// A cursor for use in pagination, fetch the previous list before some refund
+    #[schema(example = "ref_fafa124123")]
+    pub ending_before: Option<String>,
     /// The time range for which objects are needed. TimeRange has two fields start_time and end_time from which objects can be filtered as per required scenarios (created_at, time less than, greater than etc).
     #[serde(flatten)]
     pub time_range: Option<TimeRange>,
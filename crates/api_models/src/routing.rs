@@ -93,6 +93,8 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::CardBin,
         DirKeyKind::CardType,
         DirKeyKind::CardNetwork,
+        DirKeyKind::CardCategory,
+        DirKeyKind::CardIssuingCountry,
         DirKeyKind::PayLaterType,
         DirKeyKind::WalletType,
         DirKeyKind::UpiType,
@@ -109,6 +111,8 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::SetupFutureUsage,
         DirKeyKind::CaptureMethod,
         DirKeyKind::BillingCountry,
+        DirKeyKind::ShippingCountry,
+        DirKeyKind::IpCountry,
         DirKeyKind::BusinessCountry,
         DirKeyKind::BusinessLabel,
         DirKeyKind::MetaData,
@@ -533,3 +537,117 @@ pub enum RoutingKind {
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 #[serde(transparent)]
 pub struct RoutingAlgorithmId(pub String);
+
+#[derive(Debug, Clone, serde::Deserialize, ToSchema)]
+pub struct RoutingSimulationRequest {
+    /// The business profile whose routing configs are simulated, all the business profiles of the
+    /// merchant are considered if not provided
+    pub profile_id: Option<String>,
+    /// The hypothetical payment, described by the parameters that routing rules are evaluated on
+    #[schema(value_type = Object)]
+    pub payment: euclid::backend::BackendInput,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationResult {
+    pub algorithm_id: String,
+    #[cfg(feature = "business_profile_routing")]
+    pub profile_id: String,
+    pub name: String,
+    pub kind: RoutingAlgorithmKind,
+    /// Whether the config is the active routing config, or a draft
+    pub is_active: bool,
+    /// The connectors selected by the config for the payment, in the order they would be tried.
+    /// Volume split selections are drawn at random, weighted by the splits.
+    pub connectors: Vec<RoutableConnectorChoice>,
+    /// Name of the rule of an advanced config that selected the connectors, `None` if the default
+    /// selection was used
+    pub matched_rule: Option<String>,
+    /// The rules of an advanced config evaluated for the payment, in the order of evaluation
+    #[schema(value_type = Vec<Object>)]
+    pub rule_evaluations: Vec<euclid::backend::RuleEvaluation>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationResponse {
+    pub results: Vec<RoutingSimulationResult>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingVolumeSplitUpdateRequest {
+    /// The new volume splits of the config, the splits must add up to 100
+    pub splits: Vec<ConnectorVolumeSplit>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct RoutingVolumeSplitPayloadWrapper {
+    pub updated_config: RoutingVolumeSplitUpdateRequest,
+    pub algorithm_id: RoutingAlgorithmId,
+}
+
+/// The routing decision made for a payment attempt, kept for audit
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingDecision {
+    /// The routing config used, `None` if the default fallback connectors were used
+    pub algorithm_id: Option<String>,
+    /// The version of the routing config used, as the time it was activated or last updated
+    pub algorithm_version: Option<i64>,
+    /// Name of the rule of an advanced config that selected the connectors, `None` if the default
+    /// selection was used
+    pub matched_rule: Option<String>,
+    /// The rules of an advanced config evaluated for the payment, in the order of evaluation
+    #[schema(value_type = Vec<Object>)]
+    pub rule_evaluations: Vec<euclid::backend::RuleEvaluation>,
+    /// The connectors selected by the routing config
+    pub routed_connectors: Vec<RoutableConnectorChoice>,
+    /// The routed connectors that were filtered out before processing the payment
+    pub filtered_connectors: Vec<FilteredConnector>,
+    /// The connectors left after filtering and fallback, in the order they would be tried
+    pub eligible_connectors: Vec<RoutableConnectorChoice>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct FilteredConnector {
+    pub connector: RoutableConnectorChoice,
+    pub reason: ConnectorFilterReason,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorFilterReason {
+    /// The connector is not one of the eligible connectors given in the request
+    NotRequested,
+    /// The connector does not support the payment as configured, e.g. its payment method,
+    /// country or currency
+    NotEligible,
+    /// The circuit breaker of the connector is open, as too many of its recent calls failed
+    CircuitBreakerOpen,
+}
+
+/// Health of a connector of a merchant, as tracked by its circuit breaker
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorHealthResponse {
+    pub merchant_id: String,
+    /// The connector the circuit breaker tracks
+    pub connector: String,
+    /// The connector account the circuit breaker tracks, absent when the breaker is shared by all
+    /// the accounts of the connector
+    pub merchant_connector_id: Option<String>,
+    pub status: ConnectorHealthStatus,
+    /// Share of the recent calls to the connector that failed or timed out
+    pub failure_rate: f64,
+    /// The time at which the circuit breaker opened, as a unix timestamp
+    pub opened_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorHealthStatus {
+    /// The circuit breaker is closed, the connector is routed to as usual
+    Healthy,
+    /// The circuit breaker is open, the connector is not routed to
+    Unhealthy,
+    /// The circuit breaker is half-open, a share of the payments is routed to the connector to
+    /// probe whether it recovered
+    Probing,
+}
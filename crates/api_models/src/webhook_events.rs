@@ -76,6 +76,17 @@ pub struct EventListItemResponse {
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created: PrimitiveDateTime,
+
+    /// Time at which the event was moved to the dead-letter queue, after its delivery could not
+    /// be completed within the configured retries.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub dead_lettered_at: Option<PrimitiveDateTime>,
+
+    /// The identifier of the webhook endpoint the event was delivered to. This is not present for
+    /// events delivered to the webhook URL of the business profile.
+    #[schema(max_length = 64, example = "whe_abcdefghijklmnopqrst")]
+    pub webhook_endpoint_id: Option<String>,
 }
 
 /// The response body for retrieving an event.
@@ -183,3 +194,59 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+/// The constraints to apply when listing dead-lettered events.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DeadLetteredEventListConstraints {
+    /// Include at most the specified number of events.
+    pub limit: Option<u16>,
+
+    /// Include events after the specified offset.
+    pub offset: Option<u16>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DeadLetteredEventListRequestInternal {
+    pub merchant_id_or_profile_id: String,
+    pub constraints: DeadLetteredEventListConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for DeadLetteredEventListRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id_or_profile_id: self.merchant_id_or_profile_id.clone(),
+        })
+    }
+}
+
+/// The request body for requeueing dead-lettered events.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DeadLetteredEventsRequeueRequest {
+    /// The identifiers of the dead-lettered events to requeue for delivery.
+    #[schema(example = json!(["evt_018e31720d1b7a2b82677d3032cab959"]))]
+    pub event_ids: Vec<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DeadLetteredEventsRequeueRequestInternal {
+    pub merchant_id_or_profile_id: String,
+    pub request: DeadLetteredEventsRequeueRequest,
+}
+
+impl common_utils::events::ApiEventMetric for DeadLetteredEventsRequeueRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id_or_profile_id: self.merchant_id_or_profile_id.clone(),
+        })
+    }
+}
+
+/// The response body for requeueing dead-lettered events.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeadLetteredEventsRequeueResponse {
+    /// The identifiers of the events that were requeued for delivery.
+    #[schema(example = json!(["evt_018e31720d1b7a2b82677d3032cab959"]))]
+    pub requeued_event_ids: Vec<String>,
+}
+
+impl common_utils::events::ApiEventMetric for DeadLetteredEventsRequeueResponse {}
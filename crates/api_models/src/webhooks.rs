@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use crate::{disputes, enums as api_enums, mandates, payments, refunds};
+use crate::{disputes, enums as api_enums, mandates, payment_methods, payments, refunds, routing};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -174,6 +174,117 @@ pub struct OutgoingWebhook {
     /// The time at which webhook was sent
     #[serde(default, with = "custom_serde::iso8601")]
     pub timestamp: PrimitiveDateTime,
+
+    /// A number that increases with every event raised for the object, so that out-of-order
+    /// deliveries can be detected. Retried deliveries of an event carry the same sequence number.
+    #[schema(example = 3)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence_number: Option<i64>,
+
+    /// The identifier for the attempt (for example, the payment attempt) that triggered the event
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub triggering_attempt_id: Option<String>,
+}
+
+/// The event posted to a webhook endpoint when it is created or tested, so that the merchant can
+/// verify that the endpoint receives and validates signed webhooks
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookEndpointTestEvent {
+    /// The merchant id of the merchant
+    pub merchant_id: String,
+
+    /// The unique event id for each webhook
+    pub event_id: String,
+
+    /// The type of the event, always `webhook_endpoint_test`
+    #[schema(example = "webhook_endpoint_test")]
+    pub event_type: String,
+
+    /// The identifier for the webhook endpoint being tested
+    pub endpoint_id: String,
+
+    /// The time at which webhook was sent
+    #[serde(with = "custom_serde::iso8601")]
+    pub timestamp: PrimitiveDateTime,
+}
+
+/// Version of the outgoing webhook payload. Merchants pin a version through the `webhook_version`
+/// of their webhook details, so that the payload can evolve without breaking existing consumers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum OutgoingWebhookVersion {
+    /// The payload of [`OutgoingWebhook`], used when no version is pinned
+    #[default]
+    #[serde(rename = "1")]
+    V1,
+    /// The payload of [`OutgoingWebhookV2`]
+    #[serde(rename = "2")]
+    V2,
+}
+
+impl OutgoingWebhookVersion {
+    /// Only the major version of the pinned version is considered, so that `1.0.2` is version 1
+    pub fn from_pinned_version(version: &str) -> Option<Self> {
+        match version.split('.').next()?.trim() {
+            "1" => Some(Self::V1),
+            "2" => Some(Self::V2),
+            _ => None,
+        }
+    }
+}
+
+/// Version 2 of the outgoing webhook payload. Unlike version 1, the payload states its version,
+/// and the time at which the webhook was sent is a unix timestamp in `created`, instead of an
+/// ISO 8601 date in `timestamp`.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct OutgoingWebhookV2 {
+    /// The version of the payload, always `2`
+    #[schema(value_type = String, example = "2")]
+    pub version: OutgoingWebhookVersion,
+
+    /// The merchant id of the merchant
+    pub merchant_id: String,
+
+    /// The unique event id for each webhook
+    pub event_id: String,
+
+    /// The type of event this webhook corresponds to.
+    #[schema(value_type = EventType)]
+    pub event_type: api_enums::EventType,
+
+    /// This is specific to the flow, for ex: it will be `PaymentsResponse` for payments flow
+    pub content: OutgoingWebhookContent,
+
+    /// The time at which webhook was sent, as a unix timestamp
+    #[schema(value_type = i64, example = 1716000000)]
+    #[serde(with = "custom_serde::timestamp")]
+    pub created: PrimitiveDateTime,
+
+    /// A number that increases with every event raised for the object, so that out-of-order
+    /// deliveries can be detected. Retried deliveries of an event carry the same sequence number.
+    #[schema(example = 3)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence_number: Option<i64>,
+
+    /// The identifier for the attempt (for example, the payment attempt) that triggered the event
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub triggering_attempt_id: Option<String>,
+}
+
+impl From<OutgoingWebhook> for OutgoingWebhookV2 {
+    fn from(webhook: OutgoingWebhook) -> Self {
+        Self {
+            version: OutgoingWebhookVersion::V2,
+            merchant_id: webhook.merchant_id,
+            event_id: webhook.event_id,
+            event_type: webhook.event_type,
+            content: webhook.content,
+            created: webhook.timestamp,
+            sequence_number: webhook.sequence_number,
+            triggering_attempt_id: webhook.triggering_attempt_id,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -187,6 +298,10 @@ pub enum OutgoingWebhookContent {
     DisputeDetails(Box<disputes::DisputeResponse>),
     #[schema(value_type = MandateResponse, title = "MandateResponse")]
     MandateDetails(Box<mandates::MandateResponse>),
+    #[schema(value_type = PaymentMethodResponse, title = "PaymentMethodResponse")]
+    PaymentMethodDetails(Box<payment_methods::PaymentMethodResponse>),
+    #[schema(value_type = ConnectorHealthResponse, title = "ConnectorHealthResponse")]
+    ConnectorHealthDetails(Box<routing::ConnectorHealthResponse>),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -153,6 +153,7 @@ pub enum RoutableConnectors {
     Powertranz,
     Prophetpay,
     Rapyd,
+    Razorpay,
     Riskified,
     Shift4,
     Signifyd,
@@ -1067,6 +1068,8 @@ pub enum EventClass {
     Refunds,
     Disputes,
     Mandates,
+    PaymentMethods,
+    Connectors,
 }
 
 #[derive(
@@ -1093,6 +1096,10 @@ pub enum EventType {
     PaymentCancelled,
     PaymentAuthorized,
     PaymentCaptured,
+    /// The authorization of an uncaptured payment is about to expire
+    PaymentAuthorizationExpiring,
+    /// The capture scheduled for a payment failed, and will not be retried any further
+    PaymentCaptureFailed,
     ActionRequired,
     RefundSucceeded,
     RefundFailed,
@@ -1105,6 +1112,11 @@ pub enum EventType {
     DisputeLost,
     MandateActive,
     MandateRevoked,
+    PaymentMethodDeleted,
+    PaymentMethodUpdated,
+    PaymentMethodClosed,
+    /// The circuit breaker of a connector opened, the connector is temporarily not routed to
+    ConnectorCircuitBreakerOpened,
 }
 
 #[derive(
@@ -2720,3 +2732,135 @@ pub enum TokenPurpose {
     AcceptInvite,
     UserInfo,
 }
+
+/// The kind of admin configuration that an audit log entry records a change of
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AuditLogEntityType {
+    MerchantAccount,
+    MerchantConnectorAccount,
+    ApiKey,
+    RoutingAlgorithm,
+    BusinessProfile,
+    WebhookEndpoint,
+}
+
+/// The change that an audit log entry records
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AuditLogAction {
+    Create,
+    Update,
+    Delete,
+    Activate,
+    Deactivate,
+}
+
+/// The kind of credential that the change recorded by an audit log entry was made with
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AuditLogActorType {
+    /// The admin API key
+    AdminApiKey,
+    /// An API key of the merchant, identified by its key id
+    ApiKey,
+    /// A dashboard user, identified by their user id
+    User,
+    /// The application itself, outside of any API request
+    System,
+}
+
+/// The status of a payments export
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentExportStatus {
+    /// The export file is being generated
+    #[default]
+    Pending,
+    /// The export file has been generated and can be downloaded
+    Succeeded,
+    /// The export file could not be generated
+    Failed,
+}
+
+/// The file format of a payments export
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentExportFormat {
+    #[default]
+    Csv,
+    /// Not supported yet, exports requested in this format are rejected
+    Parquet,
+}
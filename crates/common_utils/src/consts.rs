@@ -14,6 +14,9 @@ pub(crate) const ALPHABETS: [char; 62] = [
 /// TTL for token
 pub const TOKEN_TTL: i64 = 900;
 
+/// TTL for multi use payment method tokens, which are not tied to the checkout of a payment
+pub const MULTI_USE_TOKEN_TTL: i64 = 86400;
+
 ///an example of the frm_configs json
 pub static FRM_CONFIGS_EG: &str = r#"
 [{"gateway":"stripe","payment_methods":[{"payment_method":"card","payment_method_types":[{"payment_method_type":"credit","card_networks":["Visa"],"flow":"pre","action":"cancel_txn"},{"payment_method_type":"debit","card_networks":["Visa"],"flow":"pre"}]}]}]
@@ -34,6 +37,15 @@ pub const STRIPE_ACCOUNT_ONBOARDING_DELAY_IN_SECONDS: i64 = 15;
 /// Maximum limit for payment link list get api
 pub const PAYMENTS_LINK_LIST_LIMIT: u32 = 100;
 
+/// Maximum limit for customers list api
+pub const CUSTOMERS_LIST_MAX_LIMIT: u16 = 100;
+
+/// Number of customers fetched and decrypted at a time when filtering customers on encrypted fields
+pub const CUSTOMERS_LIST_FILTER_BATCH_SIZE: u16 = 100;
+
+/// Maximum number of batches scanned when filtering customers on encrypted fields
+pub const CUSTOMERS_LIST_FILTER_MAX_BATCHES: u16 = 50;
+
 /// Maximum limit for payouts list get api
 pub const PAYOUTS_LIST_MAX_LIMIT_GET: u32 = 100;
 /// Maximum limit for payouts list post api
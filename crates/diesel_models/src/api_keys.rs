@@ -16,6 +16,10 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub test_mode: bool,
+    #[diesel(deserialize_as = crate::OptionalDieselArray<String>)]
+    pub ip_allowlist: Option<Vec<String>>,
+    pub role_id: Option<String>,
 }
 
 #[derive(Debug, Insertable)]
@@ -30,6 +34,9 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub test_mode: bool,
+    pub ip_allowlist: Option<Vec<String>>,
+    pub role_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -39,6 +46,7 @@ pub enum ApiKeyUpdate {
         description: Option<String>,
         expires_at: Option<Option<PrimitiveDateTime>>,
         last_used: Option<PrimitiveDateTime>,
+        ip_allowlist: Option<Vec<String>>,
     },
     LastUsedUpdate {
         last_used: PrimitiveDateTime,
@@ -52,6 +60,7 @@ pub(crate) struct ApiKeyUpdateInternal {
     pub description: Option<String>,
     pub expires_at: Option<Option<PrimitiveDateTime>>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub ip_allowlist: Option<Vec<String>>,
 }
 
 impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
@@ -62,17 +71,20 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
                 description,
                 expires_at,
                 last_used,
+                ip_allowlist,
             } => Self {
                 name,
                 description,
                 expires_at,
                 last_used,
+                ip_allowlist,
             },
             ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
                 last_used: Some(last_used),
                 name: None,
                 description: None,
                 expires_at: None,
+                ip_allowlist: None,
             },
         }
     }
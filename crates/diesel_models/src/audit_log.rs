@@ -0,0 +1,44 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::audit_log};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = audit_log)]
+pub struct AuditLogNew {
+    pub audit_log_id: String,
+    pub merchant_id: String,
+    pub entity_type: storage_enums::AuditLogEntityType,
+    pub entity_id: String,
+    pub action: storage_enums::AuditLogAction,
+    pub actor_type: storage_enums::AuditLogActorType,
+    pub actor_id: Option<String>,
+    pub old_value: Option<serde_json::Value>,
+    pub new_value: Option<serde_json::Value>,
+    pub created_at: PrimitiveDateTime,
+}
+
+/// An entry of the append-only admin audit log. Entries are only ever inserted and listed, and
+/// never updated or deleted.
+#[derive(
+    Clone,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    Identifiable,
+    Queryable,
+    router_derive::DebugAsDisplay,
+)]
+#[diesel(table_name = audit_log, primary_key(audit_log_id))]
+pub struct AuditLog {
+    pub audit_log_id: String,
+    pub merchant_id: String,
+    pub entity_type: storage_enums::AuditLogEntityType,
+    pub entity_id: String,
+    pub action: storage_enums::AuditLogAction,
+    pub actor_type: storage_enums::AuditLogActorType,
+    pub actor_id: Option<String>,
+    pub old_value: Option<serde_json::Value>,
+    pub new_value: Option<serde_json::Value>,
+    pub created_at: PrimitiveDateTime,
+}
@@ -1,7 +1,7 @@
 use common_utils::pii;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 
-use crate::schema::business_profile;
+use crate::{encryption::Encryption, schema::business_profile};
 
 #[derive(
     Clone,
@@ -37,6 +37,10 @@ pub struct BusinessProfile {
     pub authentication_connector_details: Option<serde_json::Value>,
     pub is_extended_card_info_enabled: Option<bool>,
     pub extended_card_info_config: Option<pii::SecretSerdeValue>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
+    pub outgoing_webhook_custom_http_headers: Option<Encryption>,
+    pub statement_descriptor_name: Option<String>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -65,6 +69,10 @@ pub struct BusinessProfileNew {
     pub authentication_connector_details: Option<serde_json::Value>,
     pub is_extended_card_info_enabled: Option<bool>,
     pub extended_card_info_config: Option<pii::SecretSerdeValue>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
+    pub outgoing_webhook_custom_http_headers: Option<Encryption>,
+    pub statement_descriptor_name: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -90,6 +98,10 @@ pub struct BusinessProfileUpdateInternal {
     pub authentication_connector_details: Option<serde_json::Value>,
     pub is_extended_card_info_enabled: Option<bool>,
     pub extended_card_info_config: Option<pii::SecretSerdeValue>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
+    pub outgoing_webhook_custom_http_headers: Option<Encryption>,
+    pub statement_descriptor_name: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -113,6 +125,10 @@ pub enum BusinessProfileUpdate {
         session_expiry: Option<i64>,
         authentication_connector_details: Option<serde_json::Value>,
         extended_card_info_config: Option<pii::SecretSerdeValue>,
+        previous_payment_response_hash_key: Option<String>,
+        previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
+        outgoing_webhook_custom_http_headers: Option<Encryption>,
+        statement_descriptor_name: Option<String>,
     },
     ExtendedCardInfoUpdate {
         is_extended_card_info_enabled: Option<bool>,
@@ -141,6 +157,10 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 session_expiry,
                 authentication_connector_details,
                 extended_card_info_config,
+                previous_payment_response_hash_key,
+                previous_payment_response_hash_key_expires_at,
+                outgoing_webhook_custom_http_headers,
+                statement_descriptor_name,
             } => Self {
                 profile_name,
                 modified_at,
@@ -160,6 +180,10 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 session_expiry,
                 authentication_connector_details,
                 extended_card_info_config,
+                previous_payment_response_hash_key,
+                previous_payment_response_hash_key_expires_at,
+                outgoing_webhook_custom_http_headers,
+                statement_descriptor_name,
                 ..Default::default()
             },
             BusinessProfileUpdate::ExtendedCardInfoUpdate {
@@ -197,6 +221,11 @@ impl From<BusinessProfileNew> for BusinessProfile {
             authentication_connector_details: new.authentication_connector_details,
             is_extended_card_info_enabled: new.is_extended_card_info_enabled,
             extended_card_info_config: new.extended_card_info_config,
+            previous_payment_response_hash_key: new.previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at: new
+                .previous_payment_response_hash_key_expires_at,
+            outgoing_webhook_custom_http_headers: new.outgoing_webhook_custom_http_headers,
+            statement_descriptor_name: new.statement_descriptor_name,
         }
     }
 }
@@ -223,6 +252,10 @@ impl BusinessProfileUpdate {
             authentication_connector_details,
             is_extended_card_info_enabled,
             extended_card_info_config,
+            previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at,
+            outgoing_webhook_custom_http_headers,
+            statement_descriptor_name,
         } = self.into();
         BusinessProfile {
             profile_name: profile_name.unwrap_or(source.profile_name),
@@ -246,6 +279,15 @@ impl BusinessProfileUpdate {
             authentication_connector_details,
             is_extended_card_info_enabled,
             extended_card_info_config,
+            previous_payment_response_hash_key: previous_payment_response_hash_key
+                .or(source.previous_payment_response_hash_key),
+            previous_payment_response_hash_key_expires_at:
+                previous_payment_response_hash_key_expires_at
+                    .or(source.previous_payment_response_hash_key_expires_at),
+            outgoing_webhook_custom_http_headers: outgoing_webhook_custom_http_headers
+                .or(source.outgoing_webhook_custom_http_headers),
+            statement_descriptor_name: statement_descriptor_name
+                .or(source.statement_descriptor_name),
             ..source
         }
     }
@@ -137,3 +137,20 @@ impl From<CaptureUpdate> for CaptureUpdateInternal {
         }
     }
 }
+
+// Tracking data by process_tracker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledCaptureTrackingData {
+    pub payment_id: String,
+    pub merchant_id: String,
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorizationExpiryTrackingData {
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    /// The time at which the authorization is expected to expire
+    pub expires_at: PrimitiveDateTime,
+}
@@ -0,0 +1,47 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::connector_payload;
+
+#[derive(
+    Clone,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    Identifiable,
+    Queryable,
+    router_derive::DebugAsDisplay,
+)]
+#[diesel(table_name = connector_payload)]
+pub struct ConnectorPayload {
+    pub id: i32,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub connector: String,
+    pub flow: String,
+    pub url: String,
+    pub method: String,
+    pub status_code: i32,
+    pub request: String,
+    pub response: Option<String>,
+    pub error: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = connector_payload)]
+pub struct ConnectorPayloadNew {
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub connector: String,
+    pub flow: String,
+    pub url: String,
+    pub method: String,
+    pub status_code: i32,
+    pub request: String,
+    pub response: Option<String>,
+    pub error: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
@@ -118,3 +118,16 @@ impl CustomerUpdateInternal {
         }
     }
 }
+
+/// The constraints applied by the database query when listing the customers of a merchant.
+#[derive(Clone, Debug, Default)]
+pub struct CustomerListConstraints {
+    pub created_after: Option<PrimitiveDateTime>,
+    pub created_before: Option<PrimitiveDateTime>,
+    /// Only include customers that come after this customer, in descending creation order.
+    pub starting_after: Option<String>,
+    /// Only include customers that come before this customer, in descending creation order.
+    pub ending_before: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
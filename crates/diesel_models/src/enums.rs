@@ -68,6 +68,8 @@ pub enum EventObjectType {
     RefundDetails,
     DisputeDetails,
     MandateDetails,
+    PaymentMethodDetails,
+    ConnectorHealthDetails,
 }
 
 #[derive(
@@ -8,6 +8,8 @@ pub enum DatabaseError {
     UniqueViolation,
     #[error("No fields were provided to be updated")]
     NoFieldsToUpdate,
+    #[error("The record was modified by another request since it was read")]
+    ConcurrentModification,
     #[error("An error occurred when generating typed SQL query")]
     QueryGenerationFailed,
     // InsertFailed,
@@ -0,0 +1,19 @@
+use diesel::{Identifiable, Queryable};
+
+use crate::{enums as storage_enums, schema::event_outbox};
+
+/// A status change of a payment or refund, recorded by a database trigger in the same transaction
+/// as the change, whose outgoing webhook is yet to be relayed.
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = event_outbox)]
+pub struct EventOutbox {
+    pub id: i64,
+    pub merchant_id: String,
+    pub primary_object_id: String,
+    pub event_class: storage_enums::EventClass,
+    /// The status of the object after the change, as stored in the database
+    pub status: String,
+    /// Number of relay runs in which relaying the entry failed
+    pub retry_count: i32,
+    pub created_at: time::PrimitiveDateTime,
+}
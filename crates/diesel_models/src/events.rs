@@ -23,6 +23,9 @@ pub struct EventNew {
     pub request: Option<Encryption>,
     pub response: Option<Encryption>,
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
+    pub webhook_endpoint_id: Option<String>,
+    pub sequence_number: Option<i64>,
+    pub triggering_attempt_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -30,6 +33,7 @@ pub struct EventNew {
 pub struct EventUpdateInternal {
     pub is_webhook_notified: Option<bool>,
     pub response: Option<Encryption>,
+    pub dead_lettered_at: Option<Option<PrimitiveDateTime>>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
@@ -53,4 +57,8 @@ pub struct Event {
     pub request: Option<Encryption>,
     pub response: Option<Encryption>,
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
+    pub dead_lettered_at: Option<PrimitiveDateTime>,
+    pub webhook_endpoint_id: Option<String>,
+    pub sequence_number: Option<i64>,
+    pub triggering_attempt_id: Option<String>,
 }
@@ -19,6 +19,7 @@ pub struct FileMetadataNew {
     pub connector_label: Option<String>,
     pub profile_id: Option<String>,
     pub merchant_connector_id: Option<String>,
+    pub test_mode: Option<bool>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
@@ -38,6 +39,7 @@ pub struct FileMetadata {
     pub connector_label: Option<String>,
     pub profile_id: Option<String>,
     pub merchant_connector_id: Option<String>,
+    pub test_mode: Option<bool>,
 }
 
 #[derive(Debug)]
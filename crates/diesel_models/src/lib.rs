@@ -1,10 +1,12 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_payload;
 
 pub mod authentication;
 pub mod authorization;
@@ -16,6 +18,7 @@ pub mod encryption;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod errors;
+pub mod event_outbox;
 pub mod events;
 pub mod file;
 #[allow(unused)]
@@ -30,6 +33,7 @@ pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_attempt;
+pub mod payment_export;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
@@ -45,6 +49,7 @@ pub mod routing_algorithm;
 pub mod schema;
 pub mod user;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
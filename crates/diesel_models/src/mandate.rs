@@ -32,6 +32,7 @@ pub struct Mandate {
     pub connector_mandate_ids: Option<pii::SecretSerdeValue>,
     pub original_payment_id: Option<String>,
     pub merchant_connector_id: Option<String>,
+    pub test_mode: Option<bool>,
 }
 
 #[derive(
@@ -69,6 +70,7 @@ pub struct MandateNew {
     pub connector_mandate_ids: Option<pii::SecretSerdeValue>,
     pub original_payment_id: Option<String>,
     pub merchant_connector_id: Option<String>,
+    pub test_mode: Option<bool>,
 }
 
 #[derive(Debug)]
@@ -208,6 +210,7 @@ impl From<&MandateNew> for Mandate {
             connector_mandate_ids: mandate_new.connector_mandate_ids.clone(),
             original_payment_id: mandate_new.original_payment_id.clone(),
             merchant_connector_id: mandate_new.merchant_connector_id.clone(),
+            test_mode: mandate_new.test_mode,
         }
     }
 }
@@ -101,3 +101,68 @@ pub struct MerchantAccountUpdateInternal {
     pub recon_status: storage_enums::ReconStatus,
     pub payment_link_config: Option<serde_json::Value>,
 }
+
+impl MerchantAccountUpdateInternal {
+    pub fn apply_changeset(self, source: MerchantAccount) -> MerchantAccount {
+        let Self {
+            merchant_name,
+            merchant_details,
+            return_url,
+            webhook_details,
+            sub_merchants_enabled,
+            parent_merchant_id,
+            enable_payment_response_hash,
+            payment_response_hash_key,
+            redirect_to_merchant_with_http_post,
+            publishable_key,
+            storage_scheme,
+            locker_id,
+            metadata,
+            routing_algorithm,
+            primary_business_details,
+            modified_at,
+            intent_fulfillment_time,
+            frm_routing_algorithm,
+            payout_routing_algorithm,
+            organization_id,
+            is_recon_enabled,
+            default_profile,
+            recon_status,
+            payment_link_config,
+        } = self;
+
+        MerchantAccount {
+            merchant_name: merchant_name.map_or(source.merchant_name, Some),
+            merchant_details: merchant_details.map_or(source.merchant_details, Some),
+            return_url: return_url.map_or(source.return_url, Some),
+            webhook_details: webhook_details.map_or(source.webhook_details, Some),
+            sub_merchants_enabled: sub_merchants_enabled.map_or(source.sub_merchants_enabled, Some),
+            parent_merchant_id: parent_merchant_id.map_or(source.parent_merchant_id, Some),
+            enable_payment_response_hash: enable_payment_response_hash
+                .unwrap_or(source.enable_payment_response_hash),
+            payment_response_hash_key: payment_response_hash_key
+                .map_or(source.payment_response_hash_key, Some),
+            redirect_to_merchant_with_http_post: redirect_to_merchant_with_http_post
+                .unwrap_or(source.redirect_to_merchant_with_http_post),
+            publishable_key: publishable_key.map_or(source.publishable_key, Some),
+            storage_scheme: storage_scheme.unwrap_or(source.storage_scheme),
+            locker_id: locker_id.map_or(source.locker_id, Some),
+            metadata: metadata.map_or(source.metadata, Some),
+            routing_algorithm: routing_algorithm.map_or(source.routing_algorithm, Some),
+            primary_business_details: primary_business_details
+                .unwrap_or(source.primary_business_details),
+            modified_at: modified_at.unwrap_or_else(common_utils::date_time::now),
+            intent_fulfillment_time: intent_fulfillment_time
+                .map_or(source.intent_fulfillment_time, Some),
+            frm_routing_algorithm: frm_routing_algorithm.map_or(source.frm_routing_algorithm, Some),
+            payout_routing_algorithm: payout_routing_algorithm
+                .map_or(source.payout_routing_algorithm, Some),
+            organization_id: organization_id.unwrap_or(source.organization_id),
+            is_recon_enabled,
+            default_profile: default_profile.unwrap_or(source.default_profile),
+            recon_status,
+            payment_link_config: payment_link_config.map_or(source.payment_link_config, Some),
+            ..source
+        }
+    }
+}
@@ -70,6 +70,9 @@ pub struct PaymentAttempt {
     pub mandate_data: Option<storage_enums::MandateDetails>,
     pub fingerprint_id: Option<String>,
     pub payment_method_billing_address_id: Option<String>,
+    pub estimated_connector_fee: Option<i64>,
+    pub three_ds_decision_reason: Option<String>,
+    pub routing_decision: Option<serde_json::Value>,
 }
 
 impl PaymentAttempt {
@@ -150,6 +153,9 @@ pub struct PaymentAttemptNew {
     pub mandate_data: Option<storage_enums::MandateDetails>,
     pub fingerprint_id: Option<String>,
     pub payment_method_billing_address_id: Option<String>,
+    pub estimated_connector_fee: Option<i64>,
+    pub three_ds_decision_reason: Option<String>,
+    pub routing_decision: Option<serde_json::Value>,
 }
 
 impl PaymentAttemptNew {
@@ -199,6 +205,7 @@ pub enum PaymentAttemptUpdate {
         tax_amount: Option<i64>,
         updated_by: String,
         merchant_connector_id: Option<String>,
+        routing_decision: Option<serde_json::Value>,
     },
     AuthenticationTypeUpdate {
         authentication_type: storage_enums::AuthenticationType,
@@ -232,6 +239,9 @@ pub enum PaymentAttemptUpdate {
         authentication_connector: Option<String>,
         authentication_id: Option<String>,
         payment_method_billing_address_id: Option<String>,
+        estimated_connector_fee: Option<i64>,
+        three_ds_decision_reason: Option<String>,
+        routing_decision: Option<serde_json::Value>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -387,6 +397,9 @@ pub struct PaymentAttemptUpdateInternal {
     authentication_id: Option<String>,
     fingerprint_id: Option<String>,
     payment_method_billing_address_id: Option<String>,
+    estimated_connector_fee: Option<i64>,
+    three_ds_decision_reason: Option<String>,
+    routing_decision: Option<serde_json::Value>,
 }
 
 impl PaymentAttemptUpdateInternal {
@@ -452,6 +465,9 @@ impl PaymentAttemptUpdate {
             authentication_id,
             payment_method_billing_address_id,
             fingerprint_id,
+            estimated_connector_fee,
+            three_ds_decision_reason,
+            routing_decision,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -500,6 +516,9 @@ impl PaymentAttemptUpdate {
             payment_method_billing_address_id: payment_method_billing_address_id
                 .or(source.payment_method_billing_address_id),
             fingerprint_id: fingerprint_id.or(source.fingerprint_id),
+            estimated_connector_fee: estimated_connector_fee.or(source.estimated_connector_fee),
+            three_ds_decision_reason: three_ds_decision_reason.or(source.three_ds_decision_reason),
+            routing_decision: routing_decision.or(source.routing_decision),
             ..source
         }
     }
@@ -584,6 +603,9 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_method_billing_address_id,
                 fingerprint_id,
                 payment_method_id,
+                estimated_connector_fee,
+                three_ds_decision_reason,
+                routing_decision,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -613,6 +635,9 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 fingerprint_id,
                 payment_method_id,
                 capture_method,
+                estimated_connector_fee,
+                three_ds_decision_reason,
+                routing_decision,
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -743,6 +768,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 tax_amount,
                 updated_by,
                 merchant_connector_id,
+                routing_decision,
             } => Self {
                 payment_token,
                 connector: connector.map(Some),
@@ -752,6 +778,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: merchant_connector_id.map(Some),
+                routing_decision,
                 ..Default::default()
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
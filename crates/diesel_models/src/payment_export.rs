@@ -0,0 +1,79 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_export};
+
+/// An export of the payments of a merchant matching a set of filters, generated asynchronously by
+/// the scheduler.
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = payment_export, primary_key(export_id, merchant_id))]
+pub struct PaymentExport {
+    pub export_id: String,
+    pub merchant_id: String,
+    pub status: storage_enums::PaymentExportStatus,
+    pub format: storage_enums::PaymentExportFormat,
+    pub filters: serde_json::Value,
+    pub file_key: Option<String>,
+    pub row_count: Option<i64>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize,
+)]
+#[diesel(table_name = payment_export)]
+pub struct PaymentExportNew {
+    pub export_id: String,
+    pub merchant_id: String,
+    pub status: storage_enums::PaymentExportStatus,
+    pub format: storage_enums::PaymentExportFormat,
+    pub filters: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PaymentExportUpdate {
+    Succeeded { file_key: String, row_count: i64 },
+    Failed,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_export)]
+pub struct PaymentExportUpdateInternal {
+    status: storage_enums::PaymentExportStatus,
+    file_key: Option<String>,
+    row_count: Option<i64>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<PaymentExportUpdate> for PaymentExportUpdateInternal {
+    fn from(payment_export_update: PaymentExportUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match payment_export_update {
+            PaymentExportUpdate::Succeeded {
+                file_key,
+                row_count,
+            } => Self {
+                status: storage_enums::PaymentExportStatus::Succeeded,
+                file_key: Some(file_key),
+                row_count: Some(row_count),
+                modified_at,
+            },
+            PaymentExportUpdate::Failed => Self {
+                status: storage_enums::PaymentExportStatus::Failed,
+                file_key: None,
+                row_count: None,
+                modified_at,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PaymentExportTrackingData {
+    pub export_id: String,
+    pub merchant_id: String,
+}
@@ -58,6 +58,7 @@ pub struct PaymentIntent {
     pub session_expiry: Option<PrimitiveDateTime>,
     pub fingerprint_id: Option<String>,
     pub request_external_three_ds_authentication: Option<bool>,
+    pub test_mode: Option<bool>,
 }
 
 #[derive(
@@ -111,6 +112,7 @@ pub struct PaymentIntentNew {
     pub session_expiry: Option<PrimitiveDateTime>,
     pub fingerprint_id: Option<String>,
     pub request_external_three_ds_authentication: Option<bool>,
+    pub test_mode: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -498,6 +500,14 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
     }
 }
 
+// Tracking data by process_tracker
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PaymentStatusSyncTrackingData {
+    /// The `modified_at` and `payment_id` of the last payment picked up by the previous run, the
+    /// next run resumes after it so that payments which fail to sync do not hold up the others
+    pub last_synced: Option<(PrimitiveDateTime, String)>,
+}
+
 mod tests {
     #[test]
     fn test_backwards_compatibility() {
@@ -33,6 +33,7 @@ pub struct Payouts {
     pub profile_id: String,
     pub status: storage_enums::PayoutStatus,
     pub confirm: Option<bool>,
+    pub test_mode: Option<bool>,
 }
 
 #[derive(
@@ -72,6 +73,7 @@ pub struct PayoutsNew {
     pub profile_id: String,
     pub status: storage_enums::PayoutStatus,
     pub confirm: Option<bool>,
+    pub test_mode: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -198,6 +198,7 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
     Debug,
     PartialEq,
     Eq,
+    Hash,
     strum::EnumString,
     strum::Display,
 )]
@@ -210,6 +211,13 @@ pub enum ProcessTrackerRunner {
     ApiKeyExpiryWorkflow,
     OutgoingWebhookRetryWorkflow,
     AttachPayoutAccountWorkflow,
+    CardAccountUpdaterWorkflow,
+    PaymentDataArchivalWorkflow,
+    EventOutboxRelayWorkflow,
+    PaymentExportWorkflow,
+    ScheduledCaptureWorkflow,
+    PaymentStatusSyncWorkflow,
+    AuthorizationExpiryWorkflow,
 }
 
 #[cfg(test)]
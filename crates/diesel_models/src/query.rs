@@ -1,10 +1,12 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod blocklist_lookup;
 pub mod business_profile;
 mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_payload;
 
 pub mod authentication;
 pub mod authorization;
@@ -13,6 +15,7 @@ pub mod blocklist_fingerprint;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
+pub mod event_outbox;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
@@ -25,6 +28,7 @@ pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_attempt;
+pub mod payment_export;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
@@ -37,3 +41,4 @@ pub mod role;
 pub mod routing_algorithm;
 pub mod user;
 pub mod user_role;
+pub mod webhook_endpoint;
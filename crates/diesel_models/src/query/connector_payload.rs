@@ -0,0 +1,33 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    connector_payload::{ConnectorPayload, ConnectorPayloadNew},
+    schema::connector_payload::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ConnectorPayloadNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ConnectorPayload> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ConnectorPayload {
+    pub async fn find_by_merchant_id_attempt_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        attempt_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::attempt_id.eq(attempt_id.to_owned())),
+            None,
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
+}
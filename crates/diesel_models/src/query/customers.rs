@@ -2,7 +2,7 @@ use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 
 use super::generics;
 use crate::{
-    customers::{Customer, CustomerNew, CustomerUpdateInternal},
+    customers::{Customer, CustomerListConstraints, CustomerNew, CustomerUpdateInternal},
     errors,
     schema::customers::dsl,
     PgPooledConn, StorageResult,
@@ -82,6 +82,89 @@ impl Customer {
         .await
     }
 
+    pub async fn list_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &CustomerListConstraints,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+
+        let mut query = Self::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order((dsl::created_at.desc(), dsl::customer_id.desc()))
+            .into_boxed();
+        let mut is_reversed = false;
+
+        if let Some(created_after) = constraints.created_after {
+            query = query.filter(dsl::created_at.ge(created_after));
+        }
+
+        if let Some(created_before) = constraints.created_before {
+            query = query.filter(dsl::created_at.le(created_before));
+        }
+
+        // Keyset pagination on `(created_at, customer_id)`, so that deep pages are read straight
+        // off the index instead of skipping over every earlier row.
+        if let Some(starting_after) = &constraints.starting_after {
+            let cursor = Self::find_by_customer_id_merchant_id(conn, starting_after, merchant_id)
+                .await
+                .attach_printable("Failed to find the customer used as the pagination cursor")?;
+            query = query.filter(
+                dsl::created_at.le(cursor.created_at).and(
+                    dsl::created_at
+                        .lt(cursor.created_at)
+                        .or(dsl::customer_id.lt(cursor.customer_id)),
+                ),
+            );
+        }
+
+        if let Some(ending_before) = &constraints.ending_before {
+            let cursor = Self::find_by_customer_id_merchant_id(conn, ending_before, merchant_id)
+                .await
+                .attach_printable("Failed to find the customer used as the pagination cursor")?;
+            query = query.filter(
+                dsl::created_at.ge(cursor.created_at).and(
+                    dsl::created_at
+                        .gt(cursor.created_at)
+                        .or(dsl::customer_id.gt(cursor.customer_id)),
+                ),
+            );
+
+            // Read upwards from the cursor so that the page is the one right before it, and put
+            // the rows back in descending order once fetched.
+            if constraints.starting_after.is_none() {
+                query = query.order((dsl::created_at.asc(), dsl::customer_id.asc()));
+                is_reversed = true;
+            }
+        }
+
+        if let Some(limit) = constraints.limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = constraints.offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .map(|mut customers: Vec<Self>| {
+                if is_reversed {
+                    customers.reverse();
+                }
+                customers
+            })
+            .change_context(errors::DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering customers by constraints")
+    }
+
     pub async fn find_optional_by_customer_id_merchant_id(
         conn: &PgPooledConn,
         customer_id: &str,
@@ -0,0 +1,38 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{event_outbox::EventOutbox, schema::event_outbox::dsl, PgPooledConn, StorageResult};
+
+impl EventOutbox {
+    pub async fn find_created_before(
+        conn: &PgPooledConn,
+        created_before: time::PrimitiveDateTime,
+        after_id: i64,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::created_at.lt(created_before).and(dsl::id.gt(after_id)),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_ids(conn: &PgPooledConn, ids: Vec<i64>) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(conn, dsl::id.eq_any(ids)).await
+    }
+
+    pub async fn increment_retry_count_by_ids(
+        conn: &PgPooledConn,
+        ids: Vec<i64>,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::id.eq_any(ids),
+            dsl::retry_count.eq(dsl::retry_count + 1),
+        )
+        .await
+    }
+}
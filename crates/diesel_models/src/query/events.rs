@@ -1,9 +1,17 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
 use diesel::{
-    associations::HasTable, BoolExpressionMethods, ExpressionMethods, NullableExpressionMethods,
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    NullableExpressionMethods, QueryDsl,
 };
+use error_stack::ResultExt;
+use router_env::logger;
 
-use super::generics;
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
 use crate::{
+    errors::DatabaseError,
     events::{Event, EventNew, EventUpdateInternal},
     schema::events::dsl,
     PgPooledConn, StorageResult,
@@ -202,6 +210,65 @@ impl Event {
         .await
     }
 
+    pub async fn list_dead_lettered_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::dead_lettered_at.is_not_null()),
+            limit,
+            offset,
+            Some(dsl::dead_lettered_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn count_dead_lettered_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<i64> {
+        let query = <Self as HasTable>::table()
+            .count()
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::dead_lettered_at.is_not_null()),
+            )
+            .into_boxed();
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(
+            query.get_result_async::<i64>(conn),
+            DatabaseOperation::Count,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Failed to get a count of dead-lettered events")
+    }
+
+    pub async fn list_dead_lettered_by_profile_id(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::business_profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::dead_lettered_at.is_not_null()),
+            limit,
+            offset,
+            Some(dsl::dead_lettered_at.desc()),
+        )
+        .await
+    }
+
     pub async fn update_by_merchant_id_event_id(
         conn: &PgPooledConn,
         merchant_id: &str,
@@ -222,4 +289,19 @@ impl Event {
         )
         .await
     }
+
+    /// Obtains the next value of the sequence the sequence numbers of events are drawn from.
+    /// Values are handed out atomically, so that concurrently raised events are always assigned
+    /// distinct, increasing sequence numbers.
+    pub async fn get_next_sequence_number(conn: &PgPooledConn) -> StorageResult<i64> {
+        let query = diesel::select(diesel::dsl::sql::<diesel::sql_types::BigInt>(
+            "nextval('events_sequence_number_seq')",
+        ));
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_result_async(conn), DatabaseOperation::FindOne)
+            .await
+            .change_context(DatabaseError::Others)
+            .attach_printable("Error obtaining the next event sequence number")
+    }
 }
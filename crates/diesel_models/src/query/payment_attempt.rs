@@ -15,7 +15,7 @@ use crate::{
         PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate, PaymentAttemptUpdateInternal,
     },
     query::generics::db_metrics,
-    schema::payment_attempt::dsl,
+    schema::{payment_attempt::dsl, payment_attempt_archive},
     PaymentIntent, PgPooledConn, StorageResult,
 };
 
@@ -31,7 +31,38 @@ impl PaymentAttempt {
         conn: &PgPooledConn,
         payment_attempt: PaymentAttemptUpdate,
     ) -> StorageResult<Self> {
-        match generics::generic_update_with_unique_predicate_get_result::<
+        let payment_attempt_update =
+            PaymentAttemptUpdateInternal::from(payment_attempt).populate_derived_fields(&self);
+        match self
+            .update_by_attempt_id(conn, payment_attempt_update.clone())
+            .await
+        {
+            Err(error) => match error.current_context() {
+                DatabaseError::NoFieldsToUpdate => Ok(self),
+                // Attempts of archived payments are moved back from the archive before they are
+                // updated
+                DatabaseError::NotFound => {
+                    if PaymentIntent::restore_archived(conn, &self.payment_id, &self.merchant_id)
+                        .await?
+                    {
+                        self.update_by_attempt_id(conn, payment_attempt_update)
+                            .await
+                    } else {
+                        Err(error)
+                    }
+                }
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    async fn update_by_attempt_id(
+        &self,
+        conn: &PgPooledConn,
+        payment_attempt_update: PaymentAttemptUpdateInternal,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
             <Self as HasTable>::Table,
             _,
             _,
@@ -41,16 +72,9 @@ impl PaymentAttempt {
             dsl::attempt_id
                 .eq(self.attempt_id.to_owned())
                 .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
-            PaymentAttemptUpdateInternal::from(payment_attempt).populate_derived_fields(&self),
+            payment_attempt_update,
         )
         .await
-        {
-            Err(error) => match error.current_context() {
-                DatabaseError::NoFieldsToUpdate => Ok(self),
-                _ => Err(error),
-            },
-            result => result,
-        }
     }
 
     pub async fn find_optional_by_payment_id_merchant_id(
@@ -212,6 +236,80 @@ impl PaymentAttempt {
         .await
     }
 
+    pub async fn find_archived_by_payment_id_merchant_id_attempt_id(
+        conn: &PgPooledConn,
+        payment_id: &str,
+        merchant_id: &str,
+        attempt_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<payment_attempt_archive::table, _, _>(
+            conn,
+            payment_attempt_archive::payment_id
+                .eq(payment_id.to_owned())
+                .and(
+                    payment_attempt_archive::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(payment_attempt_archive::attempt_id.eq(attempt_id.to_owned())),
+                ),
+        )
+        .await
+    }
+
+    pub async fn find_archived_by_connector_transaction_id_payment_id_merchant_id(
+        conn: &PgPooledConn,
+        connector_transaction_id: &str,
+        payment_id: &str,
+        merchant_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<payment_attempt_archive::table, _, _>(
+            conn,
+            payment_attempt_archive::connector_transaction_id
+                .eq(connector_transaction_id.to_owned())
+                .and(payment_attempt_archive::payment_id.eq(payment_id.to_owned()))
+                .and(payment_attempt_archive::merchant_id.eq(merchant_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_archived_by_merchant_id_connector_txn_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector_txn_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<payment_attempt_archive::table, _, _>(
+            conn,
+            payment_attempt_archive::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(
+                    payment_attempt_archive::connector_transaction_id
+                        .eq(connector_txn_id.to_owned()),
+                ),
+        )
+        .await
+    }
+
+    pub async fn find_archived_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            payment_attempt_archive::table,
+            _,
+            <payment_attempt_archive::table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            payment_attempt_archive::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(payment_attempt_archive::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
     pub async fn get_filters_for_payments(
         conn: &PgPooledConn,
         pi: &[PaymentIntent],
@@ -0,0 +1,51 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    payment_export::{PaymentExport, PaymentExportNew, PaymentExportUpdateInternal},
+    schema::payment_export::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentExportNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentExport> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentExport {
+    pub async fn find_by_merchant_id_export_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        export_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::export_id.eq(export_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_export_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        export_id: &str,
+        payment_export_update: PaymentExportUpdateInternal,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::export_id.eq(export_id.to_owned())),
+            payment_export_update,
+        )
+        .await
+    }
+}
@@ -1,17 +1,96 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable,
+    pg::{Pg, PgQueryBuilder},
+    query_builder::{QueryBuilder, QueryFragment},
+    sql_types, BoolExpressionMethods, ExpressionMethods,
+};
+use error_stack::ResultExt;
 
-use super::generics;
+use super::generics::{self, db_metrics};
 use crate::{
-    errors,
+    enums as storage_enums, errors,
     payment_intent::{
         PaymentIntent, PaymentIntentNew, PaymentIntentUpdate, PaymentIntentUpdateInternal,
     },
-    schema::payment_intent::dsl,
+    schema::{payment_attempt, payment_intent, payment_intent::dsl, payment_intent_archive},
     PgPooledConn, StorageResult,
 };
 
+/// Lists the columns of a table from its schema, so that archiving does not depend on the column
+/// order of the table and its archive table.
+fn column_list<T: QueryFragment<Pg>>(all_columns: T, table_name: &str) -> StorageResult<String> {
+    let mut query_builder = PgQueryBuilder::default();
+    all_columns
+        .to_sql(&mut query_builder, &Pg)
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while listing the columns of the table")?;
+
+    Ok(query_builder
+        .finish()
+        .replace(&format!("\"{table_name}\"."), ""))
+}
+
+/// Moves a batch of payments in a terminal status created before `$1`, together with their
+/// attempts, into the archive tables. At most `$2` payments are moved per execution.
+fn archive_payments_query() -> StorageResult<String> {
+    let payment_intent_columns = column_list(payment_intent::all_columns, "payment_intent")?;
+    let payment_attempt_columns = column_list(payment_attempt::all_columns, "payment_attempt")?;
+
+    Ok(format!(
+        "WITH archived_intents AS (
+    DELETE FROM payment_intent
+    WHERE id IN (
+        SELECT id FROM payment_intent
+        WHERE created_at < $1 AND status IN ('succeeded', 'failed', 'cancelled')
+        ORDER BY created_at
+        LIMIT $2
+    )
+    RETURNING {payment_intent_columns}
+),
+archived_attempts AS (
+    DELETE FROM payment_attempt
+    WHERE (merchant_id, payment_id) IN (SELECT merchant_id, payment_id FROM archived_intents)
+    RETURNING {payment_attempt_columns}
+),
+inserted_attempts AS (
+    INSERT INTO payment_attempt_archive ({payment_attempt_columns})
+    SELECT {payment_attempt_columns} FROM archived_attempts
+)
+INSERT INTO payment_intent_archive ({payment_intent_columns})
+SELECT {payment_intent_columns} FROM archived_intents"
+    ))
+}
+
+/// Moves an archived payment identified by `$1` (payment ID) and `$2` (merchant ID), together
+/// with its attempts, back from the archive tables so that it can be updated.
+fn restore_archived_payment_query() -> StorageResult<String> {
+    let payment_intent_columns = column_list(payment_intent::all_columns, "payment_intent")?;
+    let payment_attempt_columns = column_list(payment_attempt::all_columns, "payment_attempt")?;
+
+    Ok(format!(
+        "WITH restored_intents AS (
+    DELETE FROM payment_intent_archive
+    WHERE payment_id = $1 AND merchant_id = $2
+    RETURNING {payment_intent_columns}
+),
+restored_attempts AS (
+    DELETE FROM payment_attempt_archive
+    WHERE payment_id = $1 AND merchant_id = $2
+    RETURNING {payment_attempt_columns}
+),
+inserted_attempts AS (
+    INSERT INTO payment_attempt ({payment_attempt_columns})
+    SELECT {payment_attempt_columns} FROM restored_attempts
+)
+INSERT INTO payment_intent ({payment_intent_columns})
+SELECT {payment_intent_columns} FROM restored_intents"
+    ))
+}
+
 impl PaymentIntentNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentIntent> {
+        // Payment IDs of archived payments are rejected by the database
         generics::generic_insert(conn, self).await
     }
 }
@@ -22,25 +101,127 @@ impl PaymentIntent {
         conn: &PgPooledConn,
         payment_intent: PaymentIntentUpdate,
     ) -> StorageResult<Self> {
-        match generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
-            conn,
-            dsl::payment_id
-                .eq(self.payment_id.to_owned())
-                .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
-            PaymentIntentUpdateInternal::from(payment_intent),
-        )
-        .await
+        let payment_intent_update = PaymentIntentUpdateInternal::from(payment_intent);
+        match self
+            .update_by_payment_id(conn, payment_intent_update.clone(), false)
+            .await
         {
             Err(error) => match error.current_context() {
                 errors::DatabaseError::NoFieldsToUpdate => Ok(self),
                 _ => Err(error),
             },
-            Ok(mut payment_intents) => payment_intents
-                .pop()
-                .ok_or(error_stack::report!(errors::DatabaseError::NotFound)),
+            Ok(Some(payment_intent)) => Ok(payment_intent),
+            Ok(None) => {
+                // Archived payments are moved back from the archive before they are updated
+                if Self::restore_archived(conn, &self.payment_id, &self.merchant_id).await? {
+                    self.update_by_payment_id(conn, payment_intent_update, false)
+                        .await?
+                        .ok_or(error_stack::report!(errors::DatabaseError::NotFound))
+                } else {
+                    Err(error_stack::report!(errors::DatabaseError::NotFound))
+                }
+            }
         }
     }
 
+    /// Updates the payment intent only if it has not been modified since `self` was read, so that
+    /// concurrent writers can't overwrite each other's state transitions.
+    pub async fn update_if_unmodified(
+        self,
+        conn: &PgPooledConn,
+        payment_intent: PaymentIntentUpdate,
+    ) -> StorageResult<Self> {
+        let payment_intent_update = PaymentIntentUpdateInternal::from(payment_intent);
+        match self
+            .update_by_payment_id(conn, payment_intent_update.clone(), true)
+            .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            Ok(Some(payment_intent)) => Ok(payment_intent),
+            Ok(None) => {
+                // Archived payments keep their `modified_at` when they are restored
+                let updated_payment_intent =
+                    if Self::restore_archived(conn, &self.payment_id, &self.merchant_id).await? {
+                        self.update_by_payment_id(conn, payment_intent_update, true)
+                            .await?
+                    } else {
+                        None
+                    };
+
+                match updated_payment_intent {
+                    Some(payment_intent) => Ok(payment_intent),
+                    None => {
+                        // Either the payment intent does not exist, or another writer got there
+                        // first
+                        Self::find_by_payment_id_merchant_id(
+                            conn,
+                            &self.payment_id,
+                            &self.merchant_id,
+                        )
+                        .await?;
+                        Err(error_stack::report!(
+                            errors::DatabaseError::ConcurrentModification
+                        ))
+                    }
+                }
+            }
+        }
+    }
+
+    async fn update_by_payment_id(
+        &self,
+        conn: &PgPooledConn,
+        payment_intent_update: PaymentIntentUpdateInternal,
+        only_if_unmodified: bool,
+    ) -> StorageResult<Option<Self>> {
+        let payment_intents = if only_if_unmodified {
+            generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+                conn,
+                dsl::payment_id
+                    .eq(self.payment_id.to_owned())
+                    .and(dsl::merchant_id.eq(self.merchant_id.to_owned()))
+                    .and(dsl::modified_at.eq(self.modified_at)),
+                payment_intent_update,
+            )
+            .await?
+        } else {
+            generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+                conn,
+                dsl::payment_id
+                    .eq(self.payment_id.to_owned())
+                    .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
+                payment_intent_update,
+            )
+            .await?
+        };
+
+        Ok(payment_intents.into_iter().next())
+    }
+
+    /// Moves an archived payment, along with its attempts, back from the archive tables. Returns
+    /// whether the payment was found in the archive.
+    pub async fn restore_archived(
+        conn: &PgPooledConn,
+        payment_id: &str,
+        merchant_id: &str,
+    ) -> StorageResult<bool> {
+        let query = diesel::sql_query(restore_archived_payment_query()?)
+            .bind::<sql_types::Text, _>(payment_id.to_owned())
+            .bind::<sql_types::Text, _>(merchant_id.to_owned());
+
+        db_metrics::track_database_call::<Self, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while restoring archived payment")
+        .map(|restored_count| restored_count > 0)
+    }
+
     pub async fn find_by_payment_id_merchant_id(
         conn: &PgPooledConn,
         payment_id: &str,
@@ -68,4 +249,169 @@ impl PaymentIntent {
         )
         .await
     }
+
+    pub async fn find_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as diesel::Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    pub async fn find_archived_by_payment_id_merchant_id(
+        conn: &PgPooledConn,
+        payment_id: &str,
+        merchant_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<payment_intent_archive::table, _, _>(
+            conn,
+            payment_intent_archive::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(payment_intent_archive::payment_id.eq(payment_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_optional_archived_by_payment_id_merchant_id(
+        conn: &PgPooledConn,
+        payment_id: &str,
+        merchant_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<payment_intent_archive::table, _, _>(
+            conn,
+            payment_intent_archive::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(payment_intent_archive::payment_id.eq(payment_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_archived_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            payment_intent_archive::table,
+            _,
+            <payment_intent_archive::table as diesel::Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            payment_intent_archive::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(payment_intent_archive::customer_id.eq(customer_id.to_owned())),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Archives up to `limit` payments created before `created_before`, returning the number of
+    /// payments that were archived.
+    pub async fn archive_created_before(
+        conn: &PgPooledConn,
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<usize> {
+        let query = diesel::sql_query(archive_payments_query()?)
+            .bind::<sql_types::Timestamp, _>(created_before)
+            .bind::<sql_types::BigInt, _>(limit);
+
+        db_metrics::track_database_call::<Self, _, _>(
+            query.execute_async(conn),
+            db_metrics::DatabaseOperation::Delete,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while archiving payments")
+    }
+
+    /// Finds up to `limit` payments in one of `statuses` that were created after `created_after`
+    /// and last modified before `modified_before`, ordered by `modified_at` and `payment_id`,
+    /// starting after the payment identified by `after`.
+    pub async fn find_by_statuses_modified_before(
+        conn: &PgPooledConn,
+        statuses: Vec<storage_enums::IntentStatus>,
+        created_after: time::PrimitiveDateTime,
+        modified_before: time::PrimitiveDateTime,
+        after: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        // Payments are modified no earlier than they are created, so starting after
+        // `created_after` covers all the matching payments
+        let (after_modified_at, after_payment_id) = after.unwrap_or((created_after, String::new()));
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::status
+                .eq_any(statuses)
+                .and(dsl::created_at.gt(created_after))
+                .and(dsl::modified_at.lt(modified_before))
+                .and(
+                    dsl::modified_at.gt(after_modified_at).or(dsl::modified_at
+                        .eq(after_modified_at)
+                        .and(dsl::payment_id.gt(after_payment_id))),
+                ),
+            Some(limit),
+            None,
+            Some((dsl::modified_at.asc(), dsl::payment_id.asc())),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::column_list;
+    use crate::schema::{
+        payment_attempt, payment_attempt_archive, payment_intent, payment_intent_archive,
+    };
+
+    #[test]
+    fn test_payment_intent_archive_has_the_columns_of_payment_intent() {
+        assert_eq!(
+            column_list(payment_intent::all_columns, "payment_intent").unwrap(),
+            column_list(
+                payment_intent_archive::all_columns,
+                "payment_intent_archive"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_payment_attempt_archive_has_the_columns_of_payment_attempt() {
+        assert_eq!(
+            column_list(payment_attempt::all_columns, "payment_attempt").unwrap(),
+            column_list(
+                payment_attempt_archive::all_columns,
+                "payment_attempt_archive"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_column_list_is_unqualified() {
+        let columns = column_list(payment_intent::all_columns, "payment_intent").unwrap();
+
+        assert!(columns.starts_with("\"id\", \"payment_id\", \"merchant_id\""));
+        assert!(!columns.contains("payment_intent"));
+    }
 }
@@ -148,6 +148,25 @@ impl PaymentMethod {
         .await
     }
 
+    pub async fn find_by_customer_id_merchant_id_fingerprint_id(
+        conn: &PgPooledConn,
+        customer_id: &str,
+        merchant_id: &str,
+        fingerprint_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::customer_id
+                .eq(customer_id.to_owned())
+                .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+                .and(dsl::fingerprint_id.eq(fingerprint_id.to_owned())),
+            None,
+            None,
+            Some(dsl::last_used_at.desc()),
+        )
+        .await
+    }
+
     pub async fn update_with_payment_method_id(
         self,
         conn: &PgPooledConn,
@@ -7,7 +7,10 @@ use crate::{
     enums,
     errors::DatabaseError,
     query::generics,
-    routing_algorithm::{RoutingAlgorithm, RoutingAlgorithmMetadata, RoutingProfileMetadata},
+    routing_algorithm::{
+        RoutingAlgorithm, RoutingAlgorithmMetadata, RoutingAlgorithmUpdate,
+        RoutingAlgorithmUpdateInternal, RoutingProfileMetadata,
+    },
     schema::routing_algorithm::dsl,
     PgPooledConn, StorageResult,
 };
@@ -31,6 +34,27 @@ impl RoutingAlgorithm {
         .await
     }
 
+    pub async fn update_by_algorithm_id_merchant_id(
+        conn: &PgPooledConn,
+        algorithm_id: &str,
+        merchant_id: &str,
+        routing_algorithm_update: RoutingAlgorithmUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::algorithm_id
+                .eq(algorithm_id.to_owned())
+                .and(dsl::merchant_id.eq(merchant_id.to_owned())),
+            RoutingAlgorithmUpdateInternal::from(routing_algorithm_update),
+        )
+        .await
+    }
+
     pub async fn find_by_algorithm_id_profile_id(
         conn: &PgPooledConn,
         algorithm_id: &str,
@@ -1,4 +1,4 @@
-use diesel::{Identifiable, Insertable, Queryable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
 
 use crate::{enums, schema::routing_algorithm};
@@ -18,6 +18,34 @@ pub struct RoutingAlgorithm {
     pub algorithm_for: enums::TransactionType,
 }
 
+#[derive(Clone, Debug, AsChangeset)]
+#[diesel(table_name = routing_algorithm)]
+pub struct RoutingAlgorithmUpdateInternal {
+    algorithm_data: serde_json::Value,
+    modified_at: time::PrimitiveDateTime,
+}
+
+pub enum RoutingAlgorithmUpdate {
+    AlgorithmDataUpdate {
+        algorithm_data: serde_json::Value,
+        modified_at: time::PrimitiveDateTime,
+    },
+}
+
+impl From<RoutingAlgorithmUpdate> for RoutingAlgorithmUpdateInternal {
+    fn from(value: RoutingAlgorithmUpdate) -> Self {
+        match value {
+            RoutingAlgorithmUpdate::AlgorithmDataUpdate {
+                algorithm_data,
+                modified_at,
+            } => Self {
+                algorithm_data,
+                modified_at,
+            },
+        }
+    }
+}
+
 pub struct RoutingAlgorithmMetadata {
     pub algorithm_id: String,
     pub name: String,
@@ -55,6 +55,35 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        test_mode -> Bool,
+        ip_allowlist -> Nullable<Array<Nullable<Text>>>,
+        #[max_length = 64]
+        role_id -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    audit_log (audit_log_id) {
+        #[max_length = 64]
+        audit_log_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        entity_type -> Varchar,
+        #[max_length = 64]
+        entity_id -> Varchar,
+        #[max_length = 64]
+        action -> Varchar,
+        #[max_length = 64]
+        actor_type -> Varchar,
+        #[max_length = 255]
+        actor_id -> Nullable<Varchar>,
+        old_value -> Nullable<Jsonb>,
+        new_value -> Nullable<Jsonb>,
+        created_at -> Timestamp,
     }
 }
 
@@ -194,6 +223,12 @@ diesel::table! {
         authentication_connector_details -> Nullable<Jsonb>,
         is_extended_card_info_enabled -> Nullable<Bool>,
         extended_card_info_config -> Nullable<Jsonb>,
+        #[max_length = 255]
+        previous_payment_response_hash_key -> Nullable<Varchar>,
+        previous_payment_response_hash_key_expires_at -> Nullable<Timestamp>,
+        outgoing_webhook_custom_http_headers -> Nullable<Bytea>,
+        #[max_length = 255]
+        statement_descriptor_name -> Nullable<Varchar>,
     }
 }
 
@@ -268,6 +303,33 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    connector_payload (id) {
+        id -> Int4,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 64]
+        flow -> Varchar,
+        url -> Text,
+        #[max_length = 16]
+        method -> Varchar,
+        status_code -> Int4,
+        request -> Text,
+        response -> Nullable<Text>,
+        error -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -363,6 +425,24 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    event_outbox (id) {
+        id -> Int8,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        primary_object_id -> Varchar,
+        event_class -> EventClass,
+        #[max_length = 64]
+        status -> Varchar,
+        retry_count -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -382,13 +462,19 @@ diesel::table! {
         #[max_length = 64]
         business_profile_id -> Nullable<Varchar>,
         primary_object_created_at -> Nullable<Timestamp>,
-        #[max_length = 64]
+        #[max_length = 128]
         idempotent_event_id -> Nullable<Varchar>,
         #[max_length = 64]
         initial_attempt_id -> Nullable<Varchar>,
         request -> Nullable<Bytea>,
         response -> Nullable<Bytea>,
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
+        dead_lettered_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        webhook_endpoint_id -> Nullable<Varchar>,
+        sequence_number -> Nullable<Int8>,
+        #[max_length = 64]
+        triggering_attempt_id -> Nullable<Varchar>,
     }
 }
 
@@ -418,6 +504,7 @@ diesel::table! {
         profile_id -> Nullable<Varchar>,
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
+        test_mode -> Nullable<Bool>,
     }
 }
 
@@ -588,6 +675,7 @@ diesel::table! {
         original_payment_id -> Nullable<Varchar>,
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
+        test_mode -> Nullable<Bool>,
     }
 }
 
@@ -669,6 +757,7 @@ diesel::table! {
         applepay_verified_domains -> Nullable<Array<Nullable<Text>>>,
         pm_auth_config -> Nullable<Jsonb>,
         status -> ConnectorStatus,
+        test_connector_account_details -> Nullable<Bytea>,
     }
 }
 
@@ -776,6 +865,120 @@ diesel::table! {
         fingerprint_id -> Nullable<Varchar>,
         #[max_length = 64]
         payment_method_billing_address_id -> Nullable<Varchar>,
+        estimated_connector_fee -> Nullable<Int8>,
+        #[max_length = 255]
+        three_ds_decision_reason -> Nullable<Varchar>,
+        routing_decision -> Nullable<Jsonb>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_attempt_archive (id) {
+        id -> Int4,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        status -> AttemptStatus,
+        amount -> Int8,
+        currency -> Nullable<Currency>,
+        save_to_locker -> Nullable<Bool>,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        offer_amount -> Nullable<Int8>,
+        surcharge_amount -> Nullable<Int8>,
+        tax_amount -> Nullable<Int8>,
+        #[max_length = 64]
+        payment_method_id -> Nullable<Varchar>,
+        payment_method -> Nullable<Varchar>,
+        #[max_length = 128]
+        connector_transaction_id -> Nullable<Varchar>,
+        capture_method -> Nullable<CaptureMethod>,
+        capture_on -> Nullable<Timestamp>,
+        confirm -> Bool,
+        authentication_type -> Nullable<AuthenticationType>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        last_synced -> Nullable<Timestamp>,
+        #[max_length = 255]
+        cancellation_reason -> Nullable<Varchar>,
+        amount_to_capture -> Nullable<Int8>,
+        #[max_length = 64]
+        mandate_id -> Nullable<Varchar>,
+        browser_info -> Nullable<Jsonb>,
+        #[max_length = 255]
+        error_code -> Nullable<Varchar>,
+        #[max_length = 128]
+        payment_token -> Nullable<Varchar>,
+        connector_metadata -> Nullable<Jsonb>,
+        #[max_length = 50]
+        payment_experience -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_method_type -> Nullable<Varchar>,
+        payment_method_data -> Nullable<Jsonb>,
+        #[max_length = 64]
+        business_sub_label -> Nullable<Varchar>,
+        straight_through_algorithm -> Nullable<Jsonb>,
+        preprocessing_step_id -> Nullable<Varchar>,
+        mandate_details -> Nullable<Jsonb>,
+        error_reason -> Nullable<Text>,
+        multiple_capture_count -> Nullable<Int2>,
+        #[max_length = 128]
+        connector_response_reference_id -> Nullable<Varchar>,
+        amount_capturable -> Int8,
+        #[max_length = 32]
+        updated_by -> Varchar,
+        #[max_length = 32]
+        merchant_connector_id -> Nullable<Varchar>,
+        authentication_data -> Nullable<Json>,
+        encoded_data -> Nullable<Text>,
+        #[max_length = 255]
+        unified_code -> Nullable<Varchar>,
+        #[max_length = 1024]
+        unified_message -> Nullable<Varchar>,
+        net_amount -> Nullable<Int8>,
+        external_three_ds_authentication_attempted -> Nullable<Bool>,
+        #[max_length = 64]
+        authentication_connector -> Nullable<Varchar>,
+        #[max_length = 64]
+        authentication_id -> Nullable<Varchar>,
+        mandate_data -> Nullable<Jsonb>,
+        #[max_length = 64]
+        fingerprint_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_method_billing_address_id -> Nullable<Varchar>,
+        estimated_connector_fee -> Nullable<Int8>,
+        #[max_length = 255]
+        three_ds_decision_reason -> Nullable<Varchar>,
+        routing_decision -> Nullable<Jsonb>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_export (export_id, merchant_id) {
+        #[max_length = 64]
+        export_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 32]
+        format -> Varchar,
+        filters -> Jsonb,
+        #[max_length = 255]
+        file_key -> Nullable<Varchar>,
+        row_count -> Nullable<Int8>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -844,6 +1047,76 @@ diesel::table! {
         #[max_length = 64]
         fingerprint_id -> Nullable<Varchar>,
         request_external_three_ds_authentication -> Nullable<Bool>,
+        test_mode -> Nullable<Bool>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_intent_archive (id) {
+        id -> Int4,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        status -> IntentStatus,
+        amount -> Int8,
+        currency -> Nullable<Currency>,
+        amount_captured -> Nullable<Int8>,
+        #[max_length = 64]
+        customer_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        #[max_length = 255]
+        return_url -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        #[max_length = 64]
+        connector_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        shipping_address_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        billing_address_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        statement_descriptor_name -> Nullable<Varchar>,
+        #[max_length = 255]
+        statement_descriptor_suffix -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        last_synced -> Nullable<Timestamp>,
+        setup_future_usage -> Nullable<FutureUsage>,
+        off_session -> Nullable<Bool>,
+        #[max_length = 128]
+        client_secret -> Nullable<Varchar>,
+        #[max_length = 64]
+        active_attempt_id -> Varchar,
+        business_country -> Nullable<CountryAlpha2>,
+        #[max_length = 64]
+        business_label -> Nullable<Varchar>,
+        order_details -> Nullable<Array<Nullable<Jsonb>>>,
+        allowed_payment_method_types -> Nullable<Json>,
+        connector_metadata -> Nullable<Json>,
+        feature_metadata -> Nullable<Json>,
+        attempt_count -> Int2,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        merchant_decision -> Nullable<Varchar>,
+        #[max_length = 255]
+        payment_link_id -> Nullable<Varchar>,
+        payment_confirm_source -> Nullable<PaymentSource>,
+        #[max_length = 32]
+        updated_by -> Varchar,
+        surcharge_applicable -> Nullable<Bool>,
+        request_incremental_authorization -> Nullable<RequestIncrementalAuthorization>,
+        incremental_authorization_allowed -> Nullable<Bool>,
+        authorization_count -> Nullable<Int4>,
+        session_expiry -> Nullable<Timestamp>,
+        #[max_length = 64]
+        fingerprint_id -> Nullable<Varchar>,
+        request_external_three_ds_authentication -> Nullable<Bool>,
+        test_mode -> Nullable<Bool>,
     }
 }
 
@@ -925,6 +1198,8 @@ diesel::table! {
         network_transaction_id -> Nullable<Varchar>,
         #[max_length = 128]
         client_secret -> Nullable<Varchar>,
+        #[max_length = 64]
+        fingerprint_id -> Nullable<Varchar>,
     }
 }
 
@@ -1002,6 +1277,7 @@ diesel::table! {
         profile_id -> Varchar,
         status -> PayoutStatus,
         confirm -> Nullable<Bool>,
+        test_mode -> Nullable<Bool>,
     }
 }
 
@@ -1193,9 +1469,32 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_endpoint (endpoint_id) {
+        #[max_length = 64]
+        endpoint_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        url -> Text,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        secret -> Bytea,
+        enabled_events -> Nullable<Jsonb>,
+        is_active -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
+    audit_log,
     authentication,
     blocklist,
     blocklist_fingerprint,
@@ -1204,9 +1503,11 @@ diesel::allow_tables_to_appear_in_same_query!(
     captures,
     cards_info,
     configs,
+    connector_payload,
     customers,
     dashboard_metadata,
     dispute,
+    event_outbox,
     events,
     file_metadata,
     fraud_check,
@@ -1219,7 +1520,10 @@ diesel::allow_tables_to_appear_in_same_query!(
     merchant_key_store,
     organization,
     payment_attempt,
+    payment_attempt_archive,
+    payment_export,
     payment_intent,
+    payment_intent_archive,
     payment_link,
     payment_methods,
     payout_attempt,
@@ -1231,4 +1535,5 @@ diesel::allow_tables_to_appear_in_same_query!(
     routing_algorithm,
     user_roles,
     users,
+    webhook_endpoint,
 );
@@ -73,6 +73,9 @@ pub struct PaymentAttemptBatchNew {
     pub mandate_data: Option<MandateDetails>,
     pub payment_method_billing_address_id: Option<String>,
     pub fingerprint_id: Option<String>,
+    pub estimated_connector_fee: Option<i64>,
+    pub three_ds_decision_reason: Option<String>,
+    pub routing_decision: Option<serde_json::Value>,
 }
 
 #[allow(dead_code)]
@@ -133,6 +136,9 @@ impl PaymentAttemptBatchNew {
             mandate_data: self.mandate_data,
             payment_method_billing_address_id: self.payment_method_billing_address_id,
             fingerprint_id: self.fingerprint_id,
+            estimated_connector_fee: self.estimated_connector_fee,
+            three_ds_decision_reason: self.three_ds_decision_reason,
+            routing_decision: self.routing_decision,
         }
     }
 }
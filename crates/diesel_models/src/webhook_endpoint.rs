@@ -0,0 +1,76 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use masking::Secret;
+use time::PrimitiveDateTime;
+
+use crate::{encryption::Encryption, schema::webhook_endpoint};
+
+#[derive(
+    Clone,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    Identifiable,
+    Queryable,
+    router_derive::DebugAsDisplay,
+)]
+#[diesel(table_name = webhook_endpoint, primary_key(endpoint_id))]
+pub struct WebhookEndpoint {
+    pub endpoint_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub url: Secret<String>,
+    pub description: Option<String>,
+    pub secret: Encryption,
+    pub enabled_events: Option<serde_json::Value>,
+    pub is_active: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointNew {
+    pub endpoint_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub url: Secret<String>,
+    pub description: Option<String>,
+    pub secret: Encryption,
+    pub enabled_events: Option<serde_json::Value>,
+    pub is_active: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointUpdateInternal {
+    pub url: Option<Secret<String>>,
+    pub description: Option<String>,
+    pub secret: Option<Encryption>,
+    pub enabled_events: Option<serde_json::Value>,
+    pub is_active: Option<bool>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl WebhookEndpointUpdateInternal {
+    pub fn apply_changeset(self, source: WebhookEndpoint) -> WebhookEndpoint {
+        let Self {
+            url,
+            description,
+            secret,
+            enabled_events,
+            is_active,
+            modified_at,
+        } = self;
+        WebhookEndpoint {
+            url: url.unwrap_or(source.url),
+            description: description.or(source.description),
+            secret: secret.unwrap_or(source.secret),
+            enabled_events: enabled_events.or(source.enabled_events),
+            is_active: is_active.unwrap_or(source.is_active),
+            modified_at,
+            ..source
+        }
+    }
+}
@@ -7,7 +7,7 @@ use tokio::{
 };
 
 use crate::{
-    errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
+    errors, instrument, logger, metrics, query::QueryExecutor, tracing, utils, DrainerSettings,
     Store, StreamData,
 };
 
@@ -32,11 +32,16 @@ pub struct HandlerInner {
     active_tasks: Arc<atomic::AtomicU64>,
     conf: DrainerSettings,
     store: Arc<Store>,
+    query_executor: Arc<dyn QueryExecutor>,
     running: Arc<atomic::AtomicBool>,
 }
 
 impl Handler {
-    pub fn from_conf(conf: DrainerSettings, store: Arc<Store>) -> Self {
+    pub fn from_conf(
+        conf: DrainerSettings,
+        store: Arc<Store>,
+        query_executor: Arc<dyn QueryExecutor>,
+    ) -> Self {
         let shutdown_interval = Duration::from_millis(conf.shutdown_interval.into());
         let loop_interval = Duration::from_millis(conf.loop_interval.into());
 
@@ -50,6 +55,7 @@ impl Handler {
             active_tasks,
             conf,
             store,
+            query_executor,
             running,
         };
 
@@ -72,6 +78,7 @@ impl Handler {
                 let _task_handle = tokio::spawn(
                     drainer_handler(
                         self.store.clone(),
+                        self.query_executor.clone(),
                         stream_index,
                         self.conf.max_read_count,
                         self.active_tasks.clone(),
@@ -148,6 +155,7 @@ pub async fn redis_error_receiver(rx: oneshot::Receiver<()>, shutdown_channel: m
 #[router_env::instrument(skip_all)]
 async fn drainer_handler(
     store: Arc<Store>,
+    query_executor: Arc<dyn QueryExecutor>,
     stream_index: u8,
     max_read_count: u64,
     active_tasks: Arc<atomic::AtomicU64>,
@@ -159,6 +167,7 @@ async fn drainer_handler(
 
     let drainer_result = Box::pin(drainer(
         store.clone(),
+        query_executor,
         max_read_count,
         stream_name.as_str(),
         jobs_picked,
@@ -182,6 +191,7 @@ async fn drainer_handler(
 #[instrument(skip_all, fields(global_id, request_id, session_id))]
 async fn drainer(
     store: Arc<Store>,
+    query_executor: Arc<dyn QueryExecutor>,
     max_read_count: u64,
     stream_name: &str,
     jobs_picked: Arc<atomic::AtomicU8>,
@@ -247,7 +257,10 @@ async fn drainer(
         tracing::Span::current().record("global_id", data.global_id);
         tracing::Span::current().record("session_id", &session_id);
 
-        match data.typed_sql.execute_query(&store, data.pushed_at).await {
+        match query_executor
+            .execute_query(data.typed_sql, data.pushed_at)
+            .await
+        {
             Ok(_) => {
                 last_processed_id = entry_id;
             }
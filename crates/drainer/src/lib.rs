@@ -4,7 +4,7 @@ mod handler;
 mod health_check;
 pub mod logger;
 pub(crate) mod metrics;
-mod query;
+pub mod query;
 pub mod services;
 pub mod settings;
 mod stream;
@@ -31,7 +31,19 @@ use crate::{
 };
 
 pub async fn start_drainer(store: Arc<Store>, conf: DrainerSettings) -> errors::DrainerResult<()> {
-    let drainer_handler = handler::Handler::from_conf(conf, store);
+    start_drainer_with_query_layer(store, conf, Box::new(|query_executor| query_executor)).await
+}
+
+/// Starts the drainer with the default query executor wrapped by `query_layer`, so that deployments
+/// that plug alternative storage implementations into the router can drain the operations on those
+/// tables to the same backend.
+pub async fn start_drainer_with_query_layer(
+    store: Arc<Store>,
+    conf: DrainerSettings,
+    query_layer: query::QueryLayer,
+) -> errors::DrainerResult<()> {
+    let query_executor = query_layer(Arc::new(query::StoreQueryExecutor::new(store.clone())));
+    let drainer_handler = handler::Handler::from_conf(conf, store, query_executor);
 
     let (tx, rx) = mpsc::channel::<()>(1);
 
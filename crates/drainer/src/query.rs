@@ -5,6 +5,45 @@ use diesel_models::errors::DatabaseError;
 
 use crate::{kv, logger, metrics, pg_connection, services::Store};
 
+/// Applies the database operations read from the drainer streams.
+///
+/// Deployments that serve some of the storage interfaces of the router from an alternative
+/// backend (see `router::db::StoreLayer`) can wrap the default executor with a [`QueryLayer`], so
+/// that the operations on the tables they serve are applied to the same backend.
+#[async_trait::async_trait]
+pub trait QueryExecutor: Send + Sync {
+    async fn execute_query(
+        &self,
+        operation: kv::DBOperation,
+        pushed_at: i64,
+    ) -> CustomResult<(), DatabaseError>;
+}
+
+/// Wraps the default query executor before the drainer uses it.
+pub type QueryLayer = Box<dyn FnOnce(Arc<dyn QueryExecutor>) -> Arc<dyn QueryExecutor> + Send>;
+
+/// Applies the database operations to the master database of the drainer store
+pub struct StoreQueryExecutor {
+    store: Arc<Store>,
+}
+
+impl StoreQueryExecutor {
+    pub fn new(store: Arc<Store>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryExecutor for StoreQueryExecutor {
+    async fn execute_query(
+        &self,
+        operation: kv::DBOperation,
+        pushed_at: i64,
+    ) -> CustomResult<(), DatabaseError> {
+        operation.execute_query(&self.store, pushed_at).await
+    }
+}
+
 #[async_trait::async_trait]
 pub trait ExecuteQuery {
     async fn execute_query(
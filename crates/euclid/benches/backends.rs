@@ -44,6 +44,8 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
             capture_method: Some(enums::CaptureMethod::Automatic),
             business_country: Some(enums::Country::UnitedStatesOfAmerica),
             billing_country: Some(enums::Country::France),
+            shipping_country: None,
+            ip_country: None,
             business_label: None,
             setup_future_usage: None,
         },
@@ -51,6 +53,8 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
             payment_method: Some(enums::PaymentMethod::PayLater),
             payment_method_type: Some(enums::PaymentMethodType::Sofort),
             card_network: None,
+            card_category: None,
+            card_issuing_country: None,
         },
         mandate: inputs::MandateData {
             mandate_acceptance_type: None,
@@ -16,6 +16,13 @@ pub struct BackendOutput<O> {
     pub connector_selection: O,
 }
 
+/// Outcome of the evaluation of a single rule of a program
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RuleEvaluation {
+    pub rule_name: String,
+    pub matched: bool,
+}
+
 pub trait EuclidBackend<O>: Sized {
     type Error: serde::Serialize;
 
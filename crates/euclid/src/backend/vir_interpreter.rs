@@ -7,6 +7,7 @@ use crate::{
         dir::{self, EuclidDirFilter},
         vir,
     },
+    types::EuclidKey,
 };
 
 pub struct VirInterpreterBackend<O> {
@@ -71,6 +72,67 @@ where
     }
 }
 
+impl<O> VirInterpreterBackend<O>
+where
+    O: Clone,
+{
+    /// Executes the program, also returning the outcome of each rule evaluated before the
+    /// selection was made, in the order of evaluation
+    pub fn execute_with_trace(
+        &self,
+        input: inputs::BackendInput,
+    ) -> (backend::BackendOutput<O>, Vec<backend::RuleEvaluation>) {
+        let ctx = types::Context::from_input(input);
+        let mut trace = Vec::new();
+
+        for rule in &self.program.rules {
+            let matched = Self::eval_rule(rule, &ctx);
+            trace.push(backend::RuleEvaluation {
+                rule_name: rule.name.clone(),
+                matched,
+            });
+            if matched {
+                let output = backend::BackendOutput {
+                    connector_selection: rule.connector_selection.clone(),
+                    rule_name: Some(rule.name.clone()),
+                };
+                return (output, trace);
+            }
+        }
+
+        let output = backend::BackendOutput {
+            connector_selection: self.program.default_selection.clone(),
+            rule_name: None,
+        };
+        (output, trace)
+    }
+}
+
+impl<O> VirInterpreterBackend<O> {
+    fn statement_references_key(statement: &vir::ValuedIfStatement, key: &EuclidKey) -> bool {
+        statement.condition.iter().any(|comparison| {
+            comparison
+                .values
+                .iter()
+                .any(|value| value.get_key() == *key)
+        }) || statement.nested.as_ref().is_some_and(|nested_statements| {
+            nested_statements
+                .iter()
+                .any(|nested_statement| Self::statement_references_key(nested_statement, key))
+        })
+    }
+
+    /// Whether any condition of the program compares the value of the key, so that the inputs
+    /// which are expensive to obtain are only obtained for the programs that use them
+    pub fn references_key(&self, key: &EuclidKey) -> bool {
+        self.program.rules.iter().any(|rule| {
+            rule.statements
+                .iter()
+                .any(|statement| Self::statement_references_key(statement, key))
+        })
+    }
+}
+
 impl<O> EuclidBackend<O> for VirInterpreterBackend<O>
 where
     O: Clone + EuclidDirFilter,
@@ -132,6 +194,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                shipping_country: None,
+                ip_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -139,6 +203,8 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_category: None,
+                card_issuing_country: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -148,8 +214,21 @@ mod test {
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
-        let result = backend.execute(inp).expect("Execution");
+        assert!(backend.references_key(&EuclidKey::PaymentMethodType));
+        assert!(!backend.references_key(&EuclidKey::IpCountry));
+
+        let result = backend.execute(inp.clone()).expect("Execution");
         assert_eq!(result.rule_name.expect("Rule Name").as_str(), "rule_2");
+
+        let (result, trace) = backend.execute_with_trace(inp);
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "rule_2");
+        assert_eq!(
+            trace
+                .iter()
+                .map(|evaluation| (evaluation.rule_name.as_str(), evaluation.matched))
+                .collect::<Vec<_>>(),
+            vec![("rule_1", false), ("rule_2", true)]
+        );
     }
     #[test]
     fn test_payment_type() {
@@ -172,6 +251,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                shipping_country: None,
+                ip_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -179,6 +260,8 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_category: None,
+                card_issuing_country: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -213,6 +296,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                shipping_country: None,
+                ip_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -220,6 +305,8 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_category: None,
+                card_issuing_country: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -254,6 +341,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                shipping_country: None,
+                ip_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -261,6 +350,8 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_category: None,
+                card_issuing_country: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: Some(enums::MandateAcceptanceType::Online),
@@ -295,6 +386,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                shipping_country: None,
+                ip_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -302,6 +395,8 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_category: None,
+                card_issuing_country: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -336,6 +431,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                shipping_country: None,
+                ip_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -343,6 +440,8 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_category: None,
+                card_issuing_country: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -377,6 +476,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                shipping_country: None,
+                ip_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -384,6 +485,8 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_category: None,
+                card_issuing_country: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -418,6 +521,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                shipping_country: None,
+                ip_country: None,
                 business_label: None,
                 setup_future_usage: Some(enums::SetupFutureUsage::OffSession),
             },
@@ -425,6 +530,8 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_category: None,
+                card_issuing_country: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -461,6 +568,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                shipping_country: None,
+                ip_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -468,6 +577,8 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_category: None,
+                card_issuing_country: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -502,6 +613,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                shipping_country: None,
+                ip_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -509,6 +622,8 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_category: None,
+                card_issuing_country: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -552,6 +667,8 @@ mod test {
                 capture_method: Some(enums::CaptureMethod::Automatic),
                 business_country: Some(enums::Country::UnitedStatesOfAmerica),
                 billing_country: Some(enums::Country::France),
+                shipping_country: None,
+                ip_country: None,
                 business_label: None,
                 setup_future_usage: None,
             },
@@ -559,6 +676,8 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_category: None,
+                card_issuing_country: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -78,6 +78,14 @@ impl Context {
             enum_values.insert(EuclidValue::CardNetwork(card_network));
         }
 
+        if let Some(card_category) = payment_method.card_category {
+            enum_values.insert(EuclidValue::CardCategory(card_category));
+        }
+
+        if let Some(country) = payment_method.card_issuing_country {
+            enum_values.insert(EuclidValue::CardIssuingCountry(country));
+        }
+
         if let Some(at) = payment.authentication_type {
             enum_values.insert(EuclidValue::AuthenticationType(at));
         }
@@ -93,6 +101,14 @@ impl Context {
         if let Some(country) = payment.billing_country {
             enum_values.insert(EuclidValue::BillingCountry(country));
         }
+
+        if let Some(country) = payment.shipping_country {
+            enum_values.insert(EuclidValue::ShippingCountry(country));
+        }
+
+        if let Some(country) = payment.ip_country {
+            enum_values.insert(EuclidValue::IpCountry(country));
+        }
         if let Some(card_bin) = payment.card_bin {
             enum_values.insert(EuclidValue::CardBin(StrValue { value: card_bin }));
         }
@@ -232,6 +232,10 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
 
         dir::DirKeyKind::CardNetwork => lower_enum!(CardNetwork, value),
 
+        dir::DirKeyKind::CardCategory => lower_enum!(CardCategory, value),
+
+        dir::DirKeyKind::CardIssuingCountry => lower_enum!(CardIssuingCountry, value),
+
         dir::DirKeyKind::PayLaterType => lower_enum!(PayLaterType, value),
 
         dir::DirKeyKind::WalletType => lower_enum!(WalletType, value),
@@ -260,6 +264,10 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
 
         dir::DirKeyKind::BillingCountry => lower_enum!(BillingCountry, value),
 
+        dir::DirKeyKind::ShippingCountry => lower_enum!(ShippingCountry, value),
+
+        dir::DirKeyKind::IpCountry => lower_enum!(IpCountry, value),
+
         dir::DirKeyKind::SetupFutureUsage => lower_enum!(SetupFutureUsage, value),
 
         dir::DirKeyKind::UpiType => lower_enum!(UpiType, value),
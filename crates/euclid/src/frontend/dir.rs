@@ -147,6 +147,20 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "card_network")]
     CardNetwork,
+    #[strum(
+        serialize = "card_category",
+        detailed_message = "Category of the payment card, as per its BIN - eg. consumer, commercial",
+        props(Category = "Payment Methods")
+    )]
+    #[serde(rename = "card_category")]
+    CardCategory,
+    #[strum(
+        serialize = "card_issuing_country",
+        detailed_message = "Country of the issuer of the payment card, as per its BIN",
+        props(Category = "Payment Methods")
+    )]
+    #[serde(rename = "card_issuing_country")]
+    CardIssuingCountry,
     #[strum(
         serialize = "pay_later",
         detailed_message = "Supported types of Pay Later payment method",
@@ -288,6 +302,20 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "billing_country")]
     BillingCountry,
+    #[strum(
+        serialize = "shipping_country",
+        detailed_message = "Country of the shipping address of the customer",
+        props(Category = "Customer")
+    )]
+    #[serde(rename = "shipping_country")]
+    ShippingCountry,
+    #[strum(
+        serialize = "ip_country",
+        detailed_message = "Country of the customer, derived from the IP address of the customer",
+        props(Category = "Customer")
+    )]
+    #[serde(rename = "ip_country")]
+    IpCountry,
     #[serde(skip_deserializing, rename = "connector")]
     #[strum(disabled)]
     Connector,
@@ -335,6 +363,8 @@ impl DirKeyKind {
             Self::CardBin => types::DataType::StrValue,
             Self::CardType => types::DataType::EnumVariant,
             Self::CardNetwork => types::DataType::EnumVariant,
+            Self::CardCategory => types::DataType::EnumVariant,
+            Self::CardIssuingCountry => types::DataType::EnumVariant,
             Self::MetaData => types::DataType::MetadataValue,
             Self::MandateType => types::DataType::EnumVariant,
             Self::PaymentType => types::DataType::EnumVariant,
@@ -354,6 +384,8 @@ impl DirKeyKind {
             Self::CaptureMethod => types::DataType::EnumVariant,
             Self::BusinessCountry => types::DataType::EnumVariant,
             Self::BillingCountry => types::DataType::EnumVariant,
+            Self::ShippingCountry => types::DataType::EnumVariant,
+            Self::IpCountry => types::DataType::EnumVariant,
             Self::Connector => types::DataType::EnumVariant,
             Self::BankDebitType => types::DataType::EnumVariant,
             Self::BusinessLabel => types::DataType::StrValue,
@@ -390,6 +422,16 @@ impl DirKeyKind {
                     .map(DirValue::CardNetwork)
                     .collect(),
             ),
+            Self::CardCategory => Some(
+                enums::CardCategory::iter()
+                    .map(DirValue::CardCategory)
+                    .collect(),
+            ),
+            Self::CardIssuingCountry => Some(
+                enums::Country::iter()
+                    .map(DirValue::CardIssuingCountry)
+                    .collect(),
+            ),
             Self::PayLaterType => Some(
                 enums::PayLaterType::iter()
                     .map(DirValue::PayLaterType)
@@ -463,6 +505,12 @@ impl DirKeyKind {
                     .map(DirValue::BillingCountry)
                     .collect(),
             ),
+            Self::ShippingCountry => Some(
+                enums::Country::iter()
+                    .map(DirValue::ShippingCountry)
+                    .collect(),
+            ),
+            Self::IpCountry => Some(enums::Country::iter().map(DirValue::IpCountry).collect()),
             Self::Connector => Some(
                 common_enums::RoutableConnectors::iter()
                     .map(|connector| {
@@ -502,6 +550,10 @@ pub enum DirValue {
     CardType(enums::CardType),
     #[serde(rename = "card_network")]
     CardNetwork(enums::CardNetwork),
+    #[serde(rename = "card_category")]
+    CardCategory(enums::CardCategory),
+    #[serde(rename = "card_issuing_country")]
+    CardIssuingCountry(enums::Country),
     #[serde(rename = "metadata")]
     MetaData(types::MetadataValue),
     #[serde(rename = "pay_later")]
@@ -542,6 +594,10 @@ pub enum DirValue {
     BusinessCountry(enums::Country),
     #[serde(rename = "billing_country")]
     BillingCountry(enums::Country),
+    #[serde(rename = "shipping_country")]
+    ShippingCountry(enums::Country),
+    #[serde(rename = "ip_country")]
+    IpCountry(enums::Country),
     #[serde(skip_deserializing, rename = "connector")]
     Connector(Box<ast::ConnectorChoice>),
     #[serde(rename = "business_label")]
@@ -560,10 +616,14 @@ impl DirValue {
             Self::RewardType(_) => (DirKeyKind::RewardType, None),
             Self::BusinessCountry(_) => (DirKeyKind::BusinessCountry, None),
             Self::BillingCountry(_) => (DirKeyKind::BillingCountry, None),
+            Self::ShippingCountry(_) => (DirKeyKind::ShippingCountry, None),
+            Self::IpCountry(_) => (DirKeyKind::IpCountry, None),
             Self::BankTransferType(_) => (DirKeyKind::BankTransferType, None),
             Self::UpiType(_) => (DirKeyKind::UpiType, None),
             Self::CardType(_) => (DirKeyKind::CardType, None),
             Self::CardNetwork(_) => (DirKeyKind::CardNetwork, None),
+            Self::CardCategory(_) => (DirKeyKind::CardCategory, None),
+            Self::CardIssuingCountry(_) => (DirKeyKind::CardIssuingCountry, None),
             Self::MetaData(met) => (DirKeyKind::MetaData, Some(met.key.clone())),
             Self::PayLaterType(_) => (DirKeyKind::PayLaterType, None),
             Self::WalletType(_) => (DirKeyKind::WalletType, None),
@@ -594,6 +654,8 @@ impl DirValue {
             Self::CardBin(_) => None,
             Self::CardType(_) => None,
             Self::CardNetwork(_) => None,
+            Self::CardCategory(_) => None,
+            Self::CardIssuingCountry(_) => None,
             Self::PayLaterType(_) => None,
             Self::WalletType(_) => None,
             Self::BankRedirectType(_) => None,
@@ -605,6 +667,8 @@ impl DirValue {
             Self::PaymentCurrency(_) => None,
             Self::BusinessCountry(_) => None,
             Self::BillingCountry(_) => None,
+            Self::ShippingCountry(_) => None,
+            Self::IpCountry(_) => None,
             Self::Connector(_) => None,
             Self::BankTransferType(_) => None,
             Self::UpiType(_) => None,
@@ -639,6 +703,8 @@ impl DirValue {
             (Self::PaymentMethod(pm1), Self::PaymentMethod(pm2)) => pm1 == pm2,
             (Self::CardType(ct1), Self::CardType(ct2)) => ct1 == ct2,
             (Self::CardNetwork(cn1), Self::CardNetwork(cn2)) => cn1 == cn2,
+            (Self::CardCategory(cc1), Self::CardCategory(cc2)) => cc1 == cc2,
+            (Self::CardIssuingCountry(c1), Self::CardIssuingCountry(c2)) => c1 == c2,
             (Self::MetaData(md1), Self::MetaData(md2)) => md1 == md2,
             (Self::PayLaterType(plt1), Self::PayLaterType(plt2)) => plt1 == plt2,
             (Self::WalletType(wt1), Self::WalletType(wt2)) => wt1 == wt2,
@@ -652,6 +718,8 @@ impl DirValue {
             (Self::PaymentCurrency(pc1), Self::PaymentCurrency(pc2)) => pc1 == pc2,
             (Self::BusinessCountry(c1), Self::BusinessCountry(c2)) => c1 == c2,
             (Self::BillingCountry(c1), Self::BillingCountry(c2)) => c1 == c2,
+            (Self::ShippingCountry(c1), Self::ShippingCountry(c2)) => c1 == c2,
+            (Self::IpCountry(c1), Self::IpCountry(c2)) => c1 == c2,
             (Self::PaymentType(pt1), Self::PaymentType(pt2)) => pt1 == pt2,
             (Self::MandateType(mt1), Self::MandateType(mt2)) => mt1 == mt2,
             (Self::MandateAcceptanceType(mat1), Self::MandateAcceptanceType(mat2)) => mat1 == mat2,
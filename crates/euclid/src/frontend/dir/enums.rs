@@ -2,9 +2,11 @@ use strum::VariantNames;
 
 use crate::enums::collect_variants;
 pub use crate::enums::{
-    AuthenticationType, CaptureMethod, CardNetwork, Country, Country as BusinessCountry,
-    Country as BillingCountry, Currency as PaymentCurrency, MandateAcceptanceType, MandateType,
-    PaymentMethod, PaymentType, RoutableConnectors, SetupFutureUsage,
+    AuthenticationType, CaptureMethod, CardCategory, CardNetwork, Country,
+    Country as BusinessCountry, Country as BillingCountry, Country as ShippingCountry,
+    Country as IpCountry, Country as CardIssuingCountry, Currency as PaymentCurrency,
+    MandateAcceptanceType, MandateType, PaymentMethod, PaymentType, RoutableConnectors,
+    SetupFutureUsage,
 };
 #[cfg(feature = "payouts")]
 pub use crate::enums::{PayoutBankTransferType, PayoutType, PayoutWalletType};
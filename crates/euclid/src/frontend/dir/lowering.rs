@@ -197,6 +197,8 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::CardBin(ci) => EuclidValue::CardBin(ci),
         dir::DirValue::CardType(ct) => EuclidValue::PaymentMethodType(ct.into()),
         dir::DirValue::CardNetwork(cn) => EuclidValue::CardNetwork(cn),
+        dir::DirValue::CardCategory(cc) => EuclidValue::CardCategory(cc),
+        dir::DirValue::CardIssuingCountry(cic) => EuclidValue::CardIssuingCountry(cic),
         dir::DirValue::MetaData(md) => EuclidValue::Metadata(md),
         dir::DirValue::PayLaterType(plt) => EuclidValue::PaymentMethodType(plt.into()),
         dir::DirValue::WalletType(wt) => EuclidValue::PaymentMethodType(wt.into()),
@@ -213,6 +215,8 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::PaymentCurrency(pc) => EuclidValue::PaymentCurrency(pc),
         dir::DirValue::BusinessCountry(buc) => EuclidValue::BusinessCountry(buc),
         dir::DirValue::BillingCountry(bic) => EuclidValue::BillingCountry(bic),
+        dir::DirValue::ShippingCountry(shc) => EuclidValue::ShippingCountry(shc),
+        dir::DirValue::IpCountry(ipc) => EuclidValue::IpCountry(ipc),
         dir::DirValue::MandateAcceptanceType(mat) => EuclidValue::MandateAcceptanceType(mat),
         dir::DirValue::MandateType(mt) => EuclidValue::MandateType(mt),
         dir::DirValue::PaymentType(pt) => EuclidValue::PaymentType(pt),
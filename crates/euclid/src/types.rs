@@ -43,6 +43,10 @@ pub enum EuclidKey {
     PaymentMethodType,
     #[strum(serialize = "card_network")]
     CardNetwork,
+    #[strum(serialize = "card_category")]
+    CardCategory,
+    #[strum(serialize = "card_issuing_country")]
+    CardIssuingCountry,
     #[strum(serialize = "authentication_type")]
     AuthenticationType,
     #[strum(serialize = "capture_method")]
@@ -55,6 +59,10 @@ pub enum EuclidKey {
     BusinessCountry,
     #[strum(serialize = "billing_country")]
     BillingCountry,
+    #[strum(serialize = "shipping_country")]
+    ShippingCountry,
+    #[strum(serialize = "ip_country")]
+    IpCountry,
     #[strum(serialize = "business_label")]
     BusinessLabel,
     #[strum(serialize = "setup_future_usage")]
@@ -126,12 +134,16 @@ impl EuclidKey {
             Self::Metadata => DataType::MetadataValue,
             Self::PaymentMethodType => DataType::EnumVariant,
             Self::CardNetwork => DataType::EnumVariant,
+            Self::CardCategory => DataType::EnumVariant,
+            Self::CardIssuingCountry => DataType::EnumVariant,
             Self::AuthenticationType => DataType::EnumVariant,
             Self::CaptureMethod => DataType::EnumVariant,
             Self::PaymentAmount => DataType::Number,
             Self::PaymentCurrency => DataType::EnumVariant,
             Self::BusinessCountry => DataType::EnumVariant,
             Self::BillingCountry => DataType::EnumVariant,
+            Self::ShippingCountry => DataType::EnumVariant,
+            Self::IpCountry => DataType::EnumVariant,
             Self::MandateType => DataType::EnumVariant,
             Self::MandateAcceptanceType => DataType::EnumVariant,
             Self::PaymentType => DataType::EnumVariant,
@@ -241,6 +253,8 @@ pub enum EuclidValue {
     Metadata(MetadataValue),
     PaymentMethodType(enums::PaymentMethodType),
     CardNetwork(enums::CardNetwork),
+    CardCategory(enums::CardCategory),
+    CardIssuingCountry(enums::Country),
     AuthenticationType(enums::AuthenticationType),
     CaptureMethod(enums::CaptureMethod),
     PaymentType(enums::PaymentType),
@@ -250,6 +264,8 @@ pub enum EuclidValue {
     PaymentCurrency(enums::Currency),
     BusinessCountry(enums::Country),
     BillingCountry(enums::Country),
+    ShippingCountry(enums::Country),
+    IpCountry(enums::Country),
     BusinessLabel(StrValue),
     SetupFutureUsage(enums::SetupFutureUsage),
 }
@@ -272,12 +288,16 @@ impl EuclidValue {
             Self::PaymentType(_) => EuclidKey::PaymentType,
             Self::MandateAcceptanceType(_) => EuclidKey::MandateAcceptanceType,
             Self::CardNetwork(_) => EuclidKey::CardNetwork,
+            Self::CardCategory(_) => EuclidKey::CardCategory,
+            Self::CardIssuingCountry(_) => EuclidKey::CardIssuingCountry,
             Self::AuthenticationType(_) => EuclidKey::AuthenticationType,
             Self::CaptureMethod(_) => EuclidKey::CaptureMethod,
             Self::PaymentAmount(_) => EuclidKey::PaymentAmount,
             Self::PaymentCurrency(_) => EuclidKey::PaymentCurrency,
             Self::BusinessCountry(_) => EuclidKey::BusinessCountry,
             Self::BillingCountry(_) => EuclidKey::BillingCountry,
+            Self::ShippingCountry(_) => EuclidKey::ShippingCountry,
+            Self::IpCountry(_) => EuclidKey::IpCountry,
             Self::BusinessLabel(_) => EuclidKey::BusinessLabel,
             Self::SetupFutureUsage(_) => EuclidKey::SetupFutureUsage,
         }
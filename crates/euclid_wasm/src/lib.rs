@@ -237,6 +237,8 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::PaymentMethod => dir_enums::PaymentMethod::VARIANTS,
         dir::DirKeyKind::CardType => dir_enums::CardType::VARIANTS,
         dir::DirKeyKind::CardNetwork => dir_enums::CardNetwork::VARIANTS,
+        dir::DirKeyKind::CardCategory => dir_enums::CardCategory::VARIANTS,
+        dir::DirKeyKind::CardIssuingCountry => dir_enums::Country::VARIANTS,
         dir::DirKeyKind::PayLaterType => dir_enums::PayLaterType::VARIANTS,
         dir::DirKeyKind::WalletType => dir_enums::WalletType::VARIANTS,
         dir::DirKeyKind::BankRedirectType => dir_enums::BankRedirectType::VARIANTS,
@@ -247,6 +249,8 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::PaymentCurrency => dir_enums::PaymentCurrency::VARIANTS,
         dir::DirKeyKind::BusinessCountry => dir_enums::Country::VARIANTS,
         dir::DirKeyKind::BillingCountry => dir_enums::Country::VARIANTS,
+        dir::DirKeyKind::ShippingCountry => dir_enums::Country::VARIANTS,
+        dir::DirKeyKind::IpCountry => dir_enums::Country::VARIANTS,
         dir::DirKeyKind::BankTransferType => dir_enums::BankTransferType::VARIANTS,
         dir::DirKeyKind::UpiType => dir_enums::UpiType::VARIANTS,
         dir::DirKeyKind::SetupFutureUsage => dir_enums::SetupFutureUsage::VARIANTS,
@@ -63,10 +63,39 @@ pub trait FileStorageInterface: dyn_clone::DynClone + Sync + Send {
 
     /// Retrieves a file from the selected storage scheme.
     async fn retrieve_file(&self, file_key: &str) -> CustomResult<Vec<u8>, FileStorageError>;
+
+    /// Generates a URL from which the file can be downloaded directly from the selected storage
+    /// scheme until it expires. Returns `None` if the storage scheme does not support it.
+    async fn get_presigned_url(
+        &self,
+        file_key: &str,
+        expires_in: std::time::Duration,
+    ) -> CustomResult<Option<String>, FileStorageError>;
+
+    /// Starts uploading a file in parts to the selected storage scheme, so that large files need
+    /// not be held in memory in their entirety.
+    async fn start_multipart_upload(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Box<dyn MultipartUpload>, FileStorageError>;
 }
 
 dyn_clone::clone_trait_object!(FileStorageInterface);
 
+/// An upload of a file in parts, started with [`FileStorageInterface::start_multipart_upload`].
+/// The file is available once the upload has been completed.
+#[async_trait::async_trait]
+pub trait MultipartUpload: Send {
+    /// Appends the data to the file being uploaded.
+    async fn write_part(&mut self, data: Vec<u8>) -> CustomResult<(), FileStorageError>;
+
+    /// Completes the upload, making the file available.
+    async fn complete(self: Box<Self>) -> CustomResult<(), FileStorageError>;
+
+    /// Aborts the upload, discarding the data written so far.
+    async fn abort(self: Box<Self>) -> CustomResult<(), FileStorageError>;
+}
+
 /// Error thrown when the file storage config is invalid
 #[derive(Debug, Clone)]
 pub struct InvalidFileStorageConfig(&'static str);
@@ -1,8 +1,13 @@
 use aws_config::meta::region::RegionProviderChain;
 use aws_sdk_s3::{
     operation::{
-        delete_object::DeleteObjectError, get_object::GetObjectError, put_object::PutObjectError,
+        abort_multipart_upload::AbortMultipartUploadError,
+        complete_multipart_upload::CompleteMultipartUploadError,
+        create_multipart_upload::CreateMultipartUploadError, delete_object::DeleteObjectError,
+        get_object::GetObjectError, put_object::PutObjectError, upload_part::UploadPartError,
     },
+    presigning::{PresigningConfig, PresigningConfigError},
+    types::{CompletedMultipartUpload, CompletedPart},
     Client,
 };
 use aws_sdk_sts::config::Region;
@@ -10,7 +15,10 @@ use common_utils::{errors::CustomResult, ext_traits::ConfigExt};
 use error_stack::ResultExt;
 
 use super::InvalidFileStorageConfig;
-use crate::file_storage::{FileStorageError, FileStorageInterface};
+use crate::file_storage::{FileStorageError, FileStorageInterface, MultipartUpload};
+
+/// Minimum size of all but the last part of a multipart upload accepted by S3
+const MULTIPART_UPLOAD_MIN_PART_SIZE: usize = 5 * 1024 * 1024;
 
 /// Configuration for AWS S3 file storage.
 #[derive(Debug, serde::Deserialize, Clone, Default)]
@@ -104,6 +112,158 @@ impl AwsFileStorageClient {
             .map_err(AwsS3StorageError::UnknownError)?
             .to_vec())
     }
+
+    /// Generates a presigned URL to download a file from AWS S3.
+    async fn get_presigned_url(
+        &self,
+        file_key: &str,
+        expires_in: std::time::Duration,
+    ) -> CustomResult<String, AwsS3StorageError> {
+        let presigning_config = PresigningConfig::expires_in(expires_in)
+            .map_err(AwsS3StorageError::PresigningConfigFailure)?;
+        Ok(self
+            .inner_client
+            .get_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .presigned(presigning_config)
+            .await
+            .map_err(AwsS3StorageError::PresignFailure)?
+            .uri()
+            .to_string())
+    }
+
+    /// Starts a multipart upload of a file to AWS S3.
+    async fn start_multipart_upload(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<AwsS3MultipartUpload, AwsS3StorageError> {
+        let upload_id = self
+            .inner_client
+            .create_multipart_upload()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .send()
+            .await
+            .map_err(AwsS3StorageError::CreateMultipartUploadFailure)?
+            .upload_id()
+            .map(ToOwned::to_owned)
+            .ok_or(AwsS3StorageError::MissingUploadId)?;
+
+        Ok(AwsS3MultipartUpload {
+            inner_client: self.inner_client.clone(),
+            bucket_name: self.bucket_name.clone(),
+            file_key: file_key.to_owned(),
+            upload_id,
+            buffer: Vec::new(),
+            completed_parts: Vec::new(),
+        })
+    }
+}
+
+/// Multipart upload of a file to AWS S3. Since S3 requires all but the last part to be at least
+/// [`MULTIPART_UPLOAD_MIN_PART_SIZE`] bytes long, the data written is buffered until it reaches
+/// that size.
+struct AwsS3MultipartUpload {
+    inner_client: Client,
+    bucket_name: String,
+    file_key: String,
+    upload_id: String,
+    buffer: Vec<u8>,
+    completed_parts: Vec<CompletedPart>,
+}
+
+impl AwsS3MultipartUpload {
+    /// Uploads the buffered data as the next part of the file.
+    async fn upload_buffer(&mut self) -> CustomResult<(), AwsS3StorageError> {
+        let part_number = i32::try_from(self.completed_parts.len())
+            .ok()
+            .and_then(|part_count| part_count.checked_add(1))
+            .ok_or(AwsS3StorageError::TooManyParts)?;
+        let e_tag = self
+            .inner_client
+            .upload_part()
+            .bucket(&self.bucket_name)
+            .key(&self.file_key)
+            .upload_id(&self.upload_id)
+            .part_number(part_number)
+            .body(std::mem::take(&mut self.buffer).into())
+            .send()
+            .await
+            .map_err(AwsS3StorageError::UploadPartFailure)?
+            .e_tag()
+            .map(ToOwned::to_owned);
+
+        self.completed_parts.push(
+            CompletedPart::builder()
+                .set_e_tag(e_tag)
+                .part_number(part_number)
+                .build(),
+        );
+        Ok(())
+    }
+
+    /// Uploads the remaining buffered data and completes the upload.
+    async fn complete(mut self) -> CustomResult<(), AwsS3StorageError> {
+        if !self.buffer.is_empty() || self.completed_parts.is_empty() {
+            self.upload_buffer().await?;
+        }
+
+        self.inner_client
+            .complete_multipart_upload()
+            .bucket(&self.bucket_name)
+            .key(&self.file_key)
+            .upload_id(&self.upload_id)
+            .multipart_upload(
+                CompletedMultipartUpload::builder()
+                    .set_parts(Some(self.completed_parts))
+                    .build(),
+            )
+            .send()
+            .await
+            .map_err(AwsS3StorageError::CompleteMultipartUploadFailure)?;
+        Ok(())
+    }
+
+    /// Aborts the upload, deleting the parts uploaded so far.
+    async fn abort(self) -> CustomResult<(), AwsS3StorageError> {
+        self.inner_client
+            .abort_multipart_upload()
+            .bucket(&self.bucket_name)
+            .key(&self.file_key)
+            .upload_id(&self.upload_id)
+            .send()
+            .await
+            .map_err(AwsS3StorageError::AbortMultipartUploadFailure)?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl MultipartUpload for AwsS3MultipartUpload {
+    async fn write_part(&mut self, data: Vec<u8>) -> CustomResult<(), FileStorageError> {
+        self.buffer.extend(data);
+        if self.buffer.len() >= MULTIPART_UPLOAD_MIN_PART_SIZE {
+            self.upload_buffer()
+                .await
+                .change_context(FileStorageError::UploadFailed)?;
+        }
+        Ok(())
+    }
+
+    async fn complete(self: Box<Self>) -> CustomResult<(), FileStorageError> {
+        (*self)
+            .complete()
+            .await
+            .change_context(FileStorageError::UploadFailed)
+    }
+
+    async fn abort(self: Box<Self>) -> CustomResult<(), FileStorageError> {
+        (*self)
+            .abort()
+            .await
+            .change_context(FileStorageError::DeleteFailed)
+    }
 }
 
 #[async_trait::async_trait]
@@ -135,6 +295,31 @@ impl FileStorageInterface for AwsFileStorageClient {
             .await
             .change_context(FileStorageError::RetrieveFailed)?)
     }
+
+    /// Generates a presigned URL to download a file from AWS S3.
+    async fn get_presigned_url(
+        &self,
+        file_key: &str,
+        expires_in: std::time::Duration,
+    ) -> CustomResult<Option<String>, FileStorageError> {
+        Ok(Some(
+            self.get_presigned_url(file_key, expires_in)
+                .await
+                .change_context(FileStorageError::RetrieveFailed)?,
+        ))
+    }
+
+    /// Starts a multipart upload of a file to AWS S3.
+    async fn start_multipart_upload(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Box<dyn MultipartUpload>, FileStorageError> {
+        Ok(Box::new(
+            self.start_multipart_upload(file_key)
+                .await
+                .change_context(FileStorageError::UploadFailed)?,
+        ))
+    }
 }
 
 /// Enum representing errors that can occur during AWS S3 file storage operations.
@@ -152,6 +337,38 @@ enum AwsS3StorageError {
     #[error("File delete from S3 failed: {0:?}")]
     DeleteFailure(aws_smithy_client::SdkError<DeleteObjectError>),
 
+    /// Error indicating that the validity of a presigned URL is invalid.
+    #[error("Invalid presigned URL validity: {0:?}")]
+    PresigningConfigFailure(PresigningConfigError),
+
+    /// Error indicating that presigning a file retrieval from S3 failed.
+    #[error("File retrieve presigning for S3 failed: {0:?}")]
+    PresignFailure(aws_smithy_client::SdkError<GetObjectError>),
+
+    /// Error indicating that starting a multipart upload to S3 failed.
+    #[error("Multipart upload creation in S3 failed: {0:?}")]
+    CreateMultipartUploadFailure(aws_smithy_client::SdkError<CreateMultipartUploadError>),
+
+    /// Error indicating that S3 did not return the ID of a multipart upload.
+    #[error("Multipart upload ID not returned by S3")]
+    MissingUploadId,
+
+    /// Error indicating that uploading a part of a multipart upload to S3 failed.
+    #[error("Part upload to S3 failed: {0:?}")]
+    UploadPartFailure(aws_smithy_client::SdkError<UploadPartError>),
+
+    /// Error indicating that the multipart upload has more parts than supported.
+    #[error("Multipart upload has too many parts")]
+    TooManyParts,
+
+    /// Error indicating that completing a multipart upload to S3 failed.
+    #[error("Multipart upload completion in S3 failed: {0:?}")]
+    CompleteMultipartUploadFailure(aws_smithy_client::SdkError<CompleteMultipartUploadError>),
+
+    /// Error indicating that aborting a multipart upload to S3 failed.
+    #[error("Multipart upload abort in S3 failed: {0:?}")]
+    AbortMultipartUploadFailure(aws_smithy_client::SdkError<AbortMultipartUploadError>),
+
     /// Unknown error occurred.
     #[error("Unknown error occurred: {0:?}")]
     UnknownError(aws_sdk_s3::primitives::ByteStreamError),
@@ -3,7 +3,7 @@
 //!
 
 use std::{
-    fs::{remove_file, File},
+    fs::{remove_file, rename, File},
     io::{Read, Write},
     path::PathBuf,
 };
@@ -11,7 +11,7 @@ use std::{
 use common_utils::errors::CustomResult;
 use error_stack::ResultExt;
 
-use crate::file_storage::{FileStorageError, FileStorageInterface};
+use crate::file_storage::{FileStorageError, FileStorageInterface, MultipartUpload};
 
 /// Constructs the file path for a given file key within the file system.
 /// The file path is generated based on the workspace path and the provided file key.
@@ -27,6 +27,17 @@ fn get_file_path(file_key: impl AsRef<str>) -> PathBuf {
     file_path
 }
 
+/// Creates the directories in the path of the file, ignoring the file name, if they do not exist.
+fn create_parent_dir(file_path: &std::path::Path) -> CustomResult<(), FileSystemStorageError> {
+    std::fs::create_dir_all(
+        file_path
+            .parent()
+            .ok_or(FileSystemStorageError::CreateDirFailed)
+            .attach_printable("Failed to obtain parent directory")?,
+    )
+    .change_context(FileSystemStorageError::CreateDirFailed)
+}
+
 /// Represents a file system for storing and managing files locally.
 #[derive(Debug, Clone)]
 pub(super) struct FileSystem;
@@ -39,15 +50,7 @@ impl FileSystem {
         file: Vec<u8>,
     ) -> CustomResult<(), FileSystemStorageError> {
         let file_path = get_file_path(file_key);
-
-        // Ignore the file name and create directories in the `file_path` if not exists
-        std::fs::create_dir_all(
-            file_path
-                .parent()
-                .ok_or(FileSystemStorageError::CreateDirFailed)
-                .attach_printable("Failed to obtain parent directory")?,
-        )
-        .change_context(FileSystemStorageError::CreateDirFailed)?;
+        create_parent_dir(&file_path)?;
 
         let mut file_handler =
             File::create(file_path).change_context(FileSystemStorageError::CreateFailure)?;
@@ -74,6 +77,63 @@ impl FileSystem {
             .change_context(FileSystemStorageError::ReadFailure)?;
         Ok(received_data)
     }
+
+    /// Creates the temporary file that the parts of the file are written to.
+    fn start_multipart_upload(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<FileSystemMultipartUpload, FileSystemStorageError> {
+        let file_path = get_file_path(file_key);
+        create_parent_dir(&file_path)?;
+
+        let mut temporary_file_path = file_path.clone().into_os_string();
+        temporary_file_path.push(".part");
+        let temporary_file_path = PathBuf::from(temporary_file_path);
+        let file_handler = File::create(&temporary_file_path)
+            .change_context(FileSystemStorageError::CreateFailure)?;
+
+        Ok(FileSystemMultipartUpload {
+            file_path,
+            temporary_file_path,
+            file_handler,
+        })
+    }
+}
+
+/// Upload of a file to the file system in parts. The parts are written to a temporary file, which
+/// is moved to the path of the file when the upload is completed.
+struct FileSystemMultipartUpload {
+    file_path: PathBuf,
+    temporary_file_path: PathBuf,
+    file_handler: File,
+}
+
+#[async_trait::async_trait]
+impl MultipartUpload for FileSystemMultipartUpload {
+    async fn write_part(&mut self, data: Vec<u8>) -> CustomResult<(), FileStorageError> {
+        self.file_handler
+            .write_all(&data)
+            .change_context(FileSystemStorageError::WriteFailure)
+            .change_context(FileStorageError::UploadFailed)
+    }
+
+    async fn complete(self: Box<Self>) -> CustomResult<(), FileStorageError> {
+        let mut file_handler = self.file_handler;
+        file_handler
+            .flush()
+            .change_context(FileSystemStorageError::WriteFailure)
+            .change_context(FileStorageError::UploadFailed)?;
+        rename(&self.temporary_file_path, &self.file_path)
+            .change_context(FileSystemStorageError::RenameFailure)
+            .change_context(FileStorageError::UploadFailed)
+    }
+
+    async fn abort(self: Box<Self>) -> CustomResult<(), FileStorageError> {
+        drop(self.file_handler);
+        remove_file(&self.temporary_file_path)
+            .change_context(FileSystemStorageError::DeleteFailure)
+            .change_context(FileStorageError::DeleteFailed)
+    }
 }
 
 #[async_trait::async_trait]
@@ -105,6 +165,26 @@ impl FileStorageInterface for FileSystem {
             .await
             .change_context(FileStorageError::RetrieveFailed)?)
     }
+
+    /// Files stored in the file system cannot be downloaded directly.
+    async fn get_presigned_url(
+        &self,
+        _file_key: &str,
+        _expires_in: std::time::Duration,
+    ) -> CustomResult<Option<String>, FileStorageError> {
+        Ok(None)
+    }
+
+    /// Starts uploading a file in parts to the file system.
+    async fn start_multipart_upload(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Box<dyn MultipartUpload>, FileStorageError> {
+        Ok(Box::new(
+            self.start_multipart_upload(file_key)
+                .change_context(FileStorageError::UploadFailed)?,
+        ))
+    }
 }
 
 /// Represents an error that can occur during local file system storage operations.
@@ -130,6 +210,10 @@ enum FileSystemStorageError {
     #[error("Failed while deleting the file")]
     DeleteFailure,
 
+    /// Error indicating renaming a file failed.
+    #[error("Failed while renaming the file")]
+    RenameFailure,
+
     /// Error indicating directory creation failed
     #[error("Failed while creating a directory")]
     CreateDirFailed,
@@ -28,6 +28,8 @@ pub enum StorageError {
     KafkaError,
     #[error("Customer with this id is Redacted")]
     CustomerRedacted,
+    #[error("The record was modified by another request since it was read")]
+    ConcurrentModification,
     #[error("Deserialization failure")]
     DeserializationFailed,
     #[error("Error while encrypting data")]
@@ -166,6 +166,9 @@ pub struct PaymentAttempt {
     pub mandate_data: Option<MandateDetails>,
     pub payment_method_billing_address_id: Option<String>,
     pub fingerprint_id: Option<String>,
+    pub estimated_connector_fee: Option<i64>,
+    pub three_ds_decision_reason: Option<String>,
+    pub routing_decision: Option<serde_json::Value>,
 }
 
 impl PaymentAttempt {
@@ -249,6 +252,9 @@ pub struct PaymentAttemptNew {
     pub mandate_data: Option<MandateDetails>,
     pub payment_method_billing_address_id: Option<String>,
     pub fingerprint_id: Option<String>,
+    pub estimated_connector_fee: Option<i64>,
+    pub three_ds_decision_reason: Option<String>,
+    pub routing_decision: Option<serde_json::Value>,
 }
 
 impl PaymentAttemptNew {
@@ -293,6 +299,7 @@ pub enum PaymentAttemptUpdate {
         tax_amount: Option<i64>,
         updated_by: String,
         merchant_connector_id: Option<String>,
+        routing_decision: Option<serde_json::Value>,
     },
     AuthenticationTypeUpdate {
         authentication_type: storage_enums::AuthenticationType,
@@ -326,6 +333,9 @@ pub enum PaymentAttemptUpdate {
         payment_method_billing_address_id: Option<String>,
         fingerprint_id: Option<String>,
         payment_method_id: Option<String>,
+        estimated_connector_fee: Option<i64>,
+        three_ds_decision_reason: Option<String>,
+        routing_decision: Option<serde_json::Value>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -52,6 +52,7 @@ fn build_test_data<'a>(total_enabled: usize, total_pm_types: usize) -> graph::Kn
         connector_name: "stripe".to_string(),
         merchant_connector_id: "something".to_string(),
         connector_account_details: masking::Secret::new(serde_json::json!({})),
+        test_connector_account_details: None,
         test_mode: None,
         disabled: None,
         metadata: None,
@@ -66,6 +67,7 @@ fn build_test_data<'a>(total_enabled: usize, total_pm_types: usize) -> graph::Kn
         applepay_verified_domains: None,
         pm_auth_config: None,
         status: api_enums::ConnectorStatus::Inactive,
+        credentials_verified: None,
     };
 
     kgraph_utils::mca::make_mca_graph(vec![stripe_account]).expect("Failed graph construction")
@@ -360,6 +360,7 @@ mod tests {
             business_label: Some("food".to_string()),
             business_sub_label: None,
             connector_account_details: masking::Secret::new(serde_json::json!({})),
+            test_connector_account_details: None,
             test_mode: None,
             disabled: None,
             metadata: None,
@@ -408,6 +409,7 @@ mod tests {
             applepay_verified_domains: None,
             pm_auth_config: None,
             status: api_enums::ConnectorStatus::Inactive,
+            credentials_verified: None,
         };
 
         make_mca_graph(vec![stripe_account]).expect("Failed graph construction")
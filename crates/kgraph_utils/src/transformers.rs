@@ -43,6 +43,12 @@ impl IntoContext for BackendInput {
         if let Some(billing_country) = self.payment.billing_country {
             ctx.push(dir::DirValue::BillingCountry(billing_country));
         }
+        if let Some(shipping_country) = self.payment.shipping_country {
+            ctx.push(dir::DirValue::ShippingCountry(shipping_country));
+        }
+        if let Some(ip_country) = self.payment.ip_country {
+            ctx.push(dir::DirValue::IpCountry(ip_country));
+        }
 
         if let Some(payment_method) = self.payment_method.payment_method {
             ctx.push(dir::DirValue::PaymentMethod(payment_method));
@@ -58,6 +64,12 @@ impl IntoContext for BackendInput {
         if let Some(card_network) = self.payment_method.card_network {
             ctx.push(dir::DirValue::CardNetwork(card_network));
         }
+        if let Some(card_category) = self.payment_method.card_category {
+            ctx.push(dir::DirValue::CardCategory(card_category));
+        }
+        if let Some(card_issuing_country) = self.payment_method.card_issuing_country {
+            ctx.push(dir::DirValue::CardIssuingCountry(card_issuing_country));
+        }
         if let Some(setup_future_usage) = self.payment.setup_future_usage {
             ctx.push(dir::DirValue::SetupFutureUsage(setup_future_usage));
         }
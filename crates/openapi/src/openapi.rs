@@ -66,6 +66,7 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "payment link", description = "Create payment link"),
         (name = "Routing", description = "Create and manage routing configurations"),
         (name = "Event", description = "Manage events"),
+        (name = "Payment Exports", description = "Export payments to files"),
     ),
     // The paths will be displayed in the same order as they are registered here
     paths(
@@ -101,6 +102,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_connector_account::payment_connector_list,
         routes::merchant_connector_account::payment_connector_update,
         routes::merchant_connector_account::payment_connector_delete,
+        routes::merchant_connector_account::retrieve_connector_capabilities,
 
         //Routes for gsm
         routes::gsm::create_gsm_rule,
@@ -126,6 +128,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payment_method::list_customer_payment_method_api,
         routes::payment_method::list_customer_payment_method_api_client,
         routes::payment_method::default_payment_method_set_api,
+        routes::payment_method::payment_method_set_default_api,
         routes::payment_method::payment_method_retrieve_api,
         routes::payment_method::payment_method_update_api,
         routes::payment_method::payment_method_delete_api,
@@ -136,6 +139,12 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::business_profile::business_profiles_retrieve,
         routes::business_profile::business_profiles_update,
         routes::business_profile::business_profiles_delete,
+        routes::business_profile::webhook_endpoint_create,
+        routes::business_profile::webhook_endpoints_list,
+        routes::business_profile::webhook_endpoint_retrieve,
+        routes::business_profile::webhook_endpoint_update,
+        routes::business_profile::webhook_endpoint_test,
+        routes::business_profile::webhook_endpoint_delete,
 
         // Routes for disputes
         routes::disputes::retrieve_dispute,
@@ -152,6 +161,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::routing_retrieve_linked_config,
         routes::routing::routing_retrieve_default_config_for_profiles,
         routes::routing::routing_update_default_config_for_profile,
+        routes::routing::routing_simulate,
+        routes::routing::routing_update_volume_split,
 
         // Routes for blocklist
         routes::blocklist::remove_entry_from_blocklist,
@@ -159,6 +170,10 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::blocklist::add_entry_to_blocklist,
         routes::blocklist::toggle_blocklist_guard,
 
+        // Routes for payment exports
+        routes::payment_exports::create_payment_export,
+        routes::payment_exports::retrieve_payment_export,
+
         // Routes for payouts
         routes::payouts::payouts_create,
         routes::payouts::payouts_retrieve,
@@ -173,11 +188,14 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::api_keys::api_key_retrieve,
         routes::api_keys::api_key_update,
         routes::api_keys::api_key_revoke,
+        routes::api_keys::api_key_rotate,
 
         // Routes for events
         routes::webhook_events::list_initial_webhook_delivery_attempts,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::list_dead_lettered_webhook_events,
+        routes::webhook_events::requeue_dead_lettered_webhook_events,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
@@ -197,6 +215,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ExtendedCardInfoConfig,
         api_models::customers::CustomerRequest,
         api_models::customers::CustomerDeleteResponse,
+        api_models::customers::CustomerRedactionReport,
         api_models::payment_methods::PaymentMethodCreate,
         api_models::payment_methods::PaymentMethodResponse,
         api_models::payment_methods::PaymentMethodList,
@@ -213,6 +232,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::poll::PollResponse,
         api_models::poll::PollStatus,
+        api_models::connector_capabilities::ConnectorCapabilitiesResponse,
+        api_models::connector_capabilities::PaymentMethodCapability,
         api_models::customers::CustomerResponse,
         api_models::admin::AcceptedCountries,
         api_models::admin::AcceptedCurrencies,
@@ -259,6 +280,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorWebhookDetails,
         api_models::admin::BusinessProfileCreate,
         api_models::admin::BusinessProfileResponse,
+        api_models::admin::WebhookEndpointCreate,
+        api_models::admin::WebhookEndpointUpdate,
+        api_models::admin::WebhookEndpointResponse,
+        api_models::admin::WebhookEndpointTestResponse,
+        api_models::webhooks::WebhookEndpointTestEvent,
         api_models::admin::BusinessPaymentLinkConfig,
         api_models::admin::PaymentLinkConfigRequest,
         api_models::admin::PaymentLinkConfig,
@@ -453,6 +479,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::TransactionType,
         api_models::payments::FrmMessage,
         api_models::webhooks::OutgoingWebhook,
+        api_models::webhooks::OutgoingWebhookV2,
+        api_models::webhooks::OutgoingWebhookVersion,
         api_models::webhooks::OutgoingWebhookContent,
         api_models::enums::EventClass,
         api_models::enums::EventType,
@@ -470,6 +498,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
         api_models::api_keys::UpdateApiKeyRequest,
+        api_models::api_keys::RotateApiKeyRequest,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
@@ -486,6 +515,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::RoutingAlgorithmKind,
         api_models::routing::RoutingDictionary,
         api_models::routing::RoutingAlgorithm,
+        api_models::routing::RoutingSimulationRequest,
+        api_models::routing::RoutingSimulationResult,
+        api_models::routing::RoutingSimulationResponse,
+        api_models::routing::RoutingVolumeSplitUpdateRequest,
+        api_models::routing::ConnectorHealthResponse,
+        api_models::routing::ConnectorHealthStatus,
         api_models::routing::StraightThroughAlgorithm,
         api_models::routing::ConnectorVolumeSplit,
         api_models::routing::ConnectorSelection,
@@ -506,10 +541,16 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
         api_models::enums::BlocklistDataKind,
+        api_models::payment_exports::PaymentExportRequest,
+        api_models::payment_exports::PaymentExportResponse,
+        api_models::enums::PaymentExportStatus,
+        api_models::enums::PaymentExportFormat,
         api_models::webhook_events::EventListItemResponse,
         api_models::webhook_events::EventRetrieveResponse,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
+        api_models::webhook_events::DeadLetteredEventsRequeueRequest,
+        api_models::webhook_events::DeadLetteredEventsRequeueResponse,
         api_models::enums::WebhookDeliveryAttempt,
     )),
     modifiers(&SecurityAddon)
@@ -9,6 +9,7 @@ pub mod gsm;
 pub mod mandates;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod payment_exports;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payments;
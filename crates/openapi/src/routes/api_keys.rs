@@ -78,3 +78,28 @@ pub async fn api_key_update() {}
     security(("admin_api_key" = []))
 )]
 pub async fn api_key_revoke() {}
+
+/// API Key - Rotate
+///
+/// Rotate the specified API Key. A new API Key is created with the same name and description,
+/// while the API Key being rotated remains valid for a limited duration, so that it can be
+/// replaced without any downtime. The plaintext API Key will be displayed only once, so ensure
+/// you store it securely.
+#[utoipa::path(
+    post,
+    path = "/api_keys/{merchant_id}/{key_id}/rotate",
+    request_body = RotateApiKeyRequest,
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("key_id" = String, Path, description = "The unique identifier for the API Key")
+    ),
+    responses(
+        (status = 200, description = "API Key rotated", body = CreateApiKeyResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "API Key not found")
+    ),
+    tag = "API Key",
+    operation_id = "Rotate an API Key",
+    security(("admin_api_key" = []))
+)]
+pub async fn api_key_rotate() {}
@@ -122,3 +122,165 @@ pub async fn business_profiles_delete() {}
     security(("api_key" = []))
 )]
 pub async fn business_profiles_retrieve() {}
+
+/// Webhook Endpoint - Create
+///
+/// Creates a new *webhook endpoint* for a business profile. Outgoing webhooks are delivered to
+/// each active webhook endpoint, in addition to the webhook URL of the business profile. A signed
+/// test event is sent to the endpoint, and the endpoint is created only if it responds with a
+/// success status code.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    request_body(
+        content = WebhookEndpointCreate,
+        examples(
+            (
+                "Create a webhook endpoint receiving all event types" = (
+                    value = json!({
+                        "url": "https://erp.example.com/webhooks"
+                    })
+                )
+            ),
+            (
+                "Create a webhook endpoint receiving some event types" = (
+                    value = json!({
+                        "url": "https://erp.example.com/webhooks",
+                        "description": "ERP integration",
+                        "enabled_events": ["payment_succeeded", "refund_succeeded"]
+                    })
+                )
+            )
+        )
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoint Created", body = WebhookEndpointResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Business Profile",
+    operation_id = "Create a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_endpoint_create() {}
+
+/// Webhook Endpoint - List
+///
+/// Lists all the *webhook endpoints* of a business profile
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoints Retrieved", body = Vec<WebhookEndpointResponse>)
+    ),
+    tag = "Business Profile",
+    operation_id = "List Webhook Endpoints",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_endpoints_list() {}
+
+/// Webhook Endpoint - Retrieve
+///
+/// Retrieve an existing *webhook endpoint*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoint Retrieved", body = WebhookEndpointResponse),
+        (status = 404, description = "Webhook Endpoint not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Retrieve a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_endpoint_retrieve() {}
+
+/// Webhook Endpoint - Update
+///
+/// Update an existing *webhook endpoint*. Webhooks are not delivered to endpoints that have been
+/// deactivated.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    request_body(
+        content = WebhookEndpointUpdate,
+        examples(
+            (
+                "Deactivate a webhook endpoint" = (
+                    value = json!({
+                        "is_active": false
+                    })
+                )
+            )
+        )
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoint Updated", body = WebhookEndpointResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Webhook Endpoint not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Update a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_endpoint_update() {}
+
+/// Webhook Endpoint - Test
+///
+/// Sends a signed test event to the *webhook endpoint*, and reports the status code with which the
+/// endpoint responded and the time taken for it to respond
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}/test",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    responses(
+        (status = 200, description = "Test event sent to the Webhook Endpoint", body = WebhookEndpointTestResponse),
+        (status = 404, description = "Webhook Endpoint not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Test a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_endpoint_test() {}
+
+/// Webhook Endpoint - Delete
+///
+/// Delete an existing *webhook endpoint*
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoint Deleted", body = bool),
+        (status = 404, description = "Webhook Endpoint not found")
+    ),
+    tag = "Business Profile",
+    operation_id = "Delete a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_endpoint_delete() {}
@@ -87,10 +87,21 @@ pub async fn customers_delete() {}
 
 /// Customers - List
 ///
-/// Lists all the customers for a particular merchant id.
+/// Lists the customers for a particular merchant id, optionally filtered by email, phone, creation time or metadata key.
 #[utoipa::path(
-    post,
+    get,
     path = "/customers/list",
+    params(
+        ("limit" = Option<u16>, Query, description = "The maximum number of Customer Objects to include in the response"),
+        ("offset" = Option<u16>, Query, description = "The number of Customer Objects to skip when retrieving the list"),
+        ("starting_after" = Option<String>, Query, description = "A cursor for use in pagination, fetch the next list after the specified customer"),
+        ("ending_before" = Option<String>, Query, description = "A cursor for use in pagination, fetch the previous list before the specified customer"),
+        ("email" = Option<String>, Query, description = "Only return customers with this email address"),
+        ("phone" = Option<String>, Query, description = "Only return customers with this phone number"),
+        ("created_after" = Option<PrimitiveDateTime>, Query, description = "Only return customers created after the specified time"),
+        ("created_before" = Option<PrimitiveDateTime>, Query, description = "Only return customers created before the specified time"),
+        ("metadata_key" = Option<String>, Query, description = "Only return customers whose metadata contains this key"),
+    ),
     responses(
         (status = 200, description = "Customers retrieved", body = Vec<CustomerResponse>),
         (status = 400, description = "Invalid Data"),
@@ -168,3 +168,22 @@ pub async fn payment_connector_update() {}
     security(("admin_api_key" = []))
 )]
 pub async fn payment_connector_delete() {}
+
+/// Connector Capabilities - Retrieve
+///
+/// Retrieve the payment methods, currencies, countries and flows supported by a connector
+#[utoipa::path(
+    get,
+    path = "/connectors/{connector}/capabilities",
+    params(
+        ("connector" = Connector, Path, description = "The name of the connector")
+    ),
+    responses(
+        (status = 200, description = "Connector capabilities retrieved", body = ConnectorCapabilitiesResponse),
+        (status = 400, description = "Invalid connector name")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Retrieve Connector Capabilities",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_connector_capabilities() {}
@@ -0,0 +1,37 @@
+/// Payment Exports - Create
+///
+/// To request an export of the payments matching a set of filters. The export file is generated
+/// asynchronously; retrieve the export to obtain its download URL once it has succeeded.
+#[utoipa::path(
+    post,
+    path = "/exports/payments",
+    request_body = PaymentExportRequest,
+    responses(
+        (status = 200, description = "Payment export requested", body = PaymentExportResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payment Exports",
+    operation_id = "Create a Payment Export",
+    security(("api_key" = []))
+)]
+pub async fn create_payment_export() {}
+
+/// Payment Exports - Retrieve
+///
+/// To retrieve the status of a payment export, along with a signed download URL once it has
+/// succeeded
+#[utoipa::path(
+    get,
+    path = "/exports/payments/{export_id}",
+    params(
+        ("export_id" = String, Path, description = "The identifier for the payment export")
+    ),
+    responses(
+        (status = 200, description = "Payment export retrieved", body = PaymentExportResponse),
+        (status = 404, description = "Payment export not found")
+    ),
+    tag = "Payment Exports",
+    operation_id = "Retrieve a Payment Export",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_payment_export() {}
@@ -192,3 +192,24 @@ pub async fn payment_method_delete_api() {}
     security(("ephemeral_key" = []))
 )]
 pub async fn default_payment_method_set_api() {}
+
+/// Payment Method - Set Default
+///
+/// Set the Payment Method as Default for the Customer it belongs to. The default payment method is used
+/// for off-session payments created for the customer without any payment method details.
+#[utoipa::path(
+    post,
+    path = "/payment_methods/{payment_method_id}/default",
+    params (
+        ("payment_method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    responses(
+        (status = 200, description = "Payment Method has been set as default", body = CustomerDefaultPaymentMethodResponse),
+        (status = 400, description = "Payment Method has already been set as default for that customer"),
+        (status = 404, description = "Payment Method not found")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Set the Payment Method as Default for its Customer",
+    security(("api_key" = []))
+)]
+pub async fn payment_method_set_default_api() {}
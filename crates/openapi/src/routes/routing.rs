@@ -200,3 +200,47 @@ pub async fn routing_retrieve_default_config_for_profiles() {}
    security(("api_key" = []), ("jwt_key" = []))
 )]
 pub async fn routing_update_default_config_for_profile() {}
+
+/// Routing - Simulate
+///
+/// Simulate the active and draft routing configs for a hypothetical payment
+#[utoipa::path(
+    post,
+    path = "/routing/simulate",
+    request_body = RoutingSimulationRequest,
+    responses(
+        (status = 200, description = "Routing configs simulated", body = RoutingSimulationResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 400, description = "Malformed request"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Simulate routing configs",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_simulate() {}
+
+/// Routing - Update volume split
+///
+/// Update the splits of a volume split routing config. Changes to the active config take effect
+/// on all router instances without activating a new config.
+#[utoipa::path(
+    post,
+    path = "/routing/{algorithm_id}/volume_split",
+    params(
+        ("algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    request_body = RoutingVolumeSplitUpdateRequest,
+    responses(
+        (status = 200, description = "Routing config updated", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 400, description = "Malformed request"),
+        (status = 412, description = "Routing config is not a volume split config"),
+    ),
+   tag = "Routing",
+   operation_id = "Update the splits of a volume split routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_update_volume_split() {}
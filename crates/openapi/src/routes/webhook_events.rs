@@ -92,3 +92,42 @@ pub fn list_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - Dead Letter List
+///
+/// List all Events whose delivery could not be completed within the configured retries.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id_or_profile_id}/dead_letters",
+    params(
+        ("merchant_id_or_profile_id" = String, Path, description = "The unique identifier for the Merchant Account or Business Profile"),
+        ("limit" = Option<i64>, Query, description = "Include at most the specified number of Events."),
+        ("offset" = Option<i64>, Query, description = "Include Events after the specified offset."),
+    ),
+    responses(
+        (status = 200, description = "List of dead-lettered Events retrieved successfully", body = Vec<EventListItemResponse>),
+    ),
+    tag = "Event",
+    operation_id = "List all dead-lettered Events",
+    security(("admin_api_key" = []))
+)]
+pub fn list_dead_lettered_webhook_events() {}
+
+/// Events - Dead Letter Requeue
+///
+/// Requeue the specified dead-lettered Events, so that their delivery is retried again.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id_or_profile_id}/dead_letters/requeue",
+    params(
+        ("merchant_id_or_profile_id" = String, Path, description = "The unique identifier for the Merchant Account or Business Profile"),
+    ),
+    request_body = DeadLetteredEventsRequeueRequest,
+    responses(
+        (status = 200, description = "The Events were requeued for delivery", body = DeadLetteredEventsRequeueResponse),
+    ),
+    tag = "Event",
+    operation_id = "Requeue dead-lettered Events",
+    security(("admin_api_key" = []))
+)]
+pub fn requeue_dead_lettered_webhook_events() {}
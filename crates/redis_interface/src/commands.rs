@@ -15,7 +15,7 @@ use common_utils::{
 };
 use error_stack::{report, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, SetsInterface, StreamsInterface},
+    interfaces::{HashesInterface, KeysInterface, LuaInterface, SetsInterface, StreamsInterface},
     prelude::RedisErrorKind,
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
@@ -154,6 +154,63 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetExFailed)
     }
 
+    /// Sets the serialized value of the key only if the key still holds `current_value`, or does
+    /// not exist when `current_value` is empty, in a single atomic step. Returns whether the value
+    /// was set, so that callers doing a read-modify-write can retry when the key was changed
+    /// concurrently.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn serialize_and_set_key_if_unchanged_with_expiry<V>(
+        &self,
+        key: &str,
+        current_value: &[u8],
+        value: V,
+        seconds: i64,
+    ) -> CustomResult<bool, errors::RedisError>
+    where
+        V: serde::Serialize + Debug,
+    {
+        const SET_IF_UNCHANGED_SCRIPT: &str = r#"
+            local current = redis.call('GET', KEYS[1])
+            if (current or '') ~= ARGV[1] then
+                return 0
+            end
+            redis.call('SET', KEYS[1], ARGV[2], 'EX', ARGV[3])
+            return 1
+        "#;
+
+        let serialized = value
+            .encode_to_vec()
+            .change_context(errors::RedisError::JsonSerializationFailed)?;
+
+        self.evaluate_redis_script::<i64>(
+            SET_IF_UNCHANGED_SCRIPT,
+            vec![key.to_owned()],
+            vec![
+                RedisValue::from(current_value.to_vec()),
+                RedisValue::from(serialized),
+                RedisValue::from(seconds),
+            ],
+        )
+        .await
+        .map(|is_set| is_set == 1)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn evaluate_redis_script<T>(
+        &self,
+        lua_script: &'static str,
+        keys: Vec<String>,
+        values: Vec<RedisValue>,
+    ) -> CustomResult<T, errors::RedisError>
+    where
+        T: FromRedis + Unpin + Send + 'static,
+    {
+        self.pool
+            .eval(lua_script, keys, values)
+            .await
+            .change_context(errors::RedisError::ScriptExecutionFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn get_key<V>(&self, key: &str) -> CustomResult<V, errors::RedisError>
     where
@@ -244,6 +301,39 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key(&self, key: &str) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .incr(key)
+            .await
+            .change_context(errors::RedisError::IncrementFailed)
+    }
+
+    /// Increments the counter stored at the key, setting its expiry when the counter is created,
+    /// in a single atomic step, so that the counter cannot be left without an expiry.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key_with_expiry(
+        &self,
+        key: &str,
+        seconds: i64,
+    ) -> CustomResult<i64, errors::RedisError> {
+        const INCREMENT_WITH_EXPIRY_SCRIPT: &str = r#"
+            local count = redis.call('INCR', KEYS[1])
+            if count == 1 then
+                redis.call('EXPIRE', KEYS[1], ARGV[1])
+            end
+            return count
+        "#;
+
+        self.evaluate_redis_script::<i64>(
+            INCREMENT_WITH_EXPIRY_SCRIPT,
+            vec![key.to_owned()],
+            vec![RedisValue::from(seconds)],
+        )
+        .await
+        .change_context(errors::RedisError::IncrementFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_expiry(
         &self,
@@ -461,6 +551,71 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetAddMembersFailed)
     }
 
+    /// Increments the fields of the hash stored at the key by the given values and refreshes the
+    /// expiry of the hash, with all the commands sent to Redis in a single pipeline.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_hash_fields_with_expiry(
+        &self,
+        key: &str,
+        fields: &[(&str, i64)],
+        seconds: i64,
+    ) -> CustomResult<(), errors::RedisError> {
+        let pipeline = self.pool.next().pipeline();
+        for (field, increment) in fields {
+            pipeline
+                .hincrby::<(), _, _>(key, *field, *increment)
+                .await
+                .change_context(errors::RedisError::IncrementHashFieldFailed)?;
+        }
+        pipeline
+            .expire::<(), _>(key, seconds)
+            .await
+            .change_context(errors::RedisError::SetExpiryFailed)?;
+        pipeline
+            .all::<()>()
+            .await
+            .change_context(errors::RedisError::IncrementHashFieldFailed)
+    }
+
+    /// Adds the members to the set stored at the key and refreshes the expiry of the set, with
+    /// both the commands sent to Redis in a single pipeline.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn add_set_members_with_expiry<V>(
+        &self,
+        key: &str,
+        members: V,
+        seconds: i64,
+    ) -> CustomResult<(), errors::RedisError>
+    where
+        V: TryInto<MultipleValues> + Debug + Send,
+        V::Error: Into<fred::error::RedisError> + Send,
+    {
+        let pipeline = self.pool.next().pipeline();
+        pipeline
+            .sadd::<(), _, _>(key, members)
+            .await
+            .change_context(errors::RedisError::SetAddMembersFailed)?;
+        pipeline
+            .expire::<(), _>(key, seconds)
+            .await
+            .change_context(errors::RedisError::SetExpiryFailed)?;
+        pipeline
+            .all::<()>()
+            .await
+            .change_context(errors::RedisError::SetAddMembersFailed)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_set_members(
+        &self,
+        key: &str,
+    ) -> CustomResult<Vec<String>, errors::RedisError> {
+        self.pool
+            .smembers::<Vec<String>, _>(key)
+            .await
+            .change_context(errors::RedisError::GetSetMembersFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_append_entry<F>(
         &self,
@@ -14,6 +14,8 @@ pub enum RedisError {
     SetExFailed,
     #[error("Failed to set expiry for key value in Redis")]
     SetExpiryFailed,
+    #[error("Failed to increment key value in Redis")]
+    IncrementFailed,
     #[error("Failed to get key value in Redis")]
     GetFailed,
     #[error("Failed to delete key value in Redis")]
@@ -52,8 +54,14 @@ pub enum RedisError {
     SetHashFieldFailed,
     #[error("Failed to add members to set in Redis")]
     SetAddMembersFailed,
+    #[error("Failed to get members of set in Redis")]
+    GetSetMembersFailed,
     #[error("Failed to get hash field in Redis")]
     GetHashFieldFailed,
+    #[error("Failed to increment hash field in Redis")]
+    IncrementHashFieldFailed,
+    #[error("Failed to execute Lua script in Redis")]
+    ScriptExecutionFailed,
     #[error("The requested value was not found in Redis")]
     NotFound,
     #[error("Invalid RedisEntryId provided")]
@@ -1,6 +1,7 @@
 use router::{
     configs::settings::{CmdLineConf, Settings},
     core::errors::{ApplicationError, ApplicationResult},
+    db::StorageImpl,
     logger,
 };
 
@@ -27,8 +28,15 @@ async fn main() -> ApplicationResult<()> {
 
     logger::info!("Application started [{:?}] [{:?}]", conf.server, conf.log);
 
+    let storage_impl = if cmd_line.mock_db {
+        logger::warn!("Using the in-memory mock store, data will be lost when the router stops");
+        StorageImpl::Mock
+    } else {
+        StorageImpl::Postgresql
+    };
+
     #[allow(clippy::expect_used)]
-    let server = Box::pin(router::start_server(conf))
+    let server = Box::pin(router::start_server_with_storage(conf, storage_impl))
         .await
         .expect("Failed to create the server");
     let _ = server.await;
@@ -129,6 +129,9 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such event")]
     EventNotFound,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such webhook endpoint")]
+    WebhookEndpointNotFound,
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "token_already_used", message = "Duplicate payout request")]
     DuplicatePayout { payout_id: String },
 
@@ -264,6 +267,12 @@ pub enum StripeErrorCode {
     PaymentMethodDeleteFailed,
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "Extended card info does not exist")]
     ExtendedCardInfoNotFound,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests, please retry after {retry_after_secs} seconds")]
+    RateLimit { retry_after_secs: i64 },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "IR_29", message = "The API key cannot be used from this IP address")]
+    IpAddressNotAllowed,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "lock_timeout", message = "The resource was modified by another request, please retry the request")]
+    ConcurrentModification,
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -372,7 +381,6 @@ pub enum StripeErrorCode {
         PostalCodeInvalid,
         ProcessingError,
         ProductInactive,
-        RateLimit,
         ReferToCustomer,
         RefundDisputedPayment,
         ResourceAlreadyExists,
@@ -531,6 +539,9 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::ApiKeyNotFound => Self::ApiKeyNotFound,
             errors::ApiErrorResponse::PayoutNotFound => Self::PayoutNotFound,
             errors::ApiErrorResponse::EventNotFound => Self::EventNotFound,
+            errors::ApiErrorResponse::WebhookEndpointNotFound { .. } => {
+                Self::WebhookEndpointNotFound
+            }
             errors::ApiErrorResponse::MandateValidationFailed { reason } => {
                 Self::PaymentIntentMandateInvalid { message: reason }
             }
@@ -646,6 +657,11 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 Self::InvalidWalletToken { wallet_name }
             }
             errors::ApiErrorResponse::ExtendedCardInfoNotFound => Self::ExtendedCardInfoNotFound,
+            errors::ApiErrorResponse::RateLimitExceeded { retry_after_secs } => {
+                Self::RateLimit { retry_after_secs }
+            }
+            errors::ApiErrorResponse::IpAddressNotAllowed => Self::IpAddressNotAllowed,
+            errors::ApiErrorResponse::ConcurrentModification => Self::ConcurrentModification,
         }
     }
 }
@@ -682,6 +698,7 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::ApiKeyNotFound
             | Self::PayoutNotFound
             | Self::EventNotFound
+            | Self::WebhookEndpointNotFound
             | Self::DuplicateMerchantAccount
             | Self::DuplicateMerchantConnectorAccount { .. }
             | Self::DuplicatePaymentMethod
@@ -727,6 +744,9 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::CustomerRedacted
             | Self::WebhookProcessingError => StatusCode::INTERNAL_SERVER_ERROR,
             Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            Self::RateLimit { .. } => StatusCode::TOO_MANY_REQUESTS,
+            Self::IpAddressNotAllowed => StatusCode::FORBIDDEN,
+            Self::ConcurrentModification => StatusCode::CONFLICT,
             Self::ExternalConnectorError { status_code, .. } => {
                 StatusCode::from_u16(*status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
             }
@@ -800,6 +820,9 @@ impl ErrorSwitch<StripeErrorCode> for CustomersErrorResponse {
             Self::MandateActive => SC::MandateActive,
             Self::CustomerNotFound => SC::CustomerNotFound,
             Self::CustomerAlreadyExists => SC::DuplicateCustomer,
+            Self::InvalidRequestData { message } => SC::InvalidRequestData {
+                message: message.to_owned(),
+            },
         }
     }
 }
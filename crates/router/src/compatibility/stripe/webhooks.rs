@@ -81,6 +81,8 @@ pub enum StripeWebhookObject {
     Refund(StripeRefundResponse),
     Dispute(StripeDisputeResponse),
     Mandate(StripeMandateResponse),
+    PaymentMethod(StripePaymentMethodResponse),
+    ConnectorHealth(api_models::routing::ConnectorHealthResponse),
 }
 
 #[derive(Serialize, Debug)]
@@ -93,6 +95,14 @@ pub struct StripeDisputeResponse {
     pub status: StripeDisputeStatus,
 }
 
+#[derive(Serialize, Debug)]
+pub struct StripePaymentMethodResponse {
+    pub id: String,
+    pub customer: Option<String>,
+    #[serde(rename = "type")]
+    pub payment_method_type: Option<api_models::enums::PaymentMethod>,
+}
+
 #[derive(Serialize, Debug)]
 pub struct StripeMandateResponse {
     pub mandate_id: String,
@@ -146,6 +156,16 @@ impl From<api_models::mandates::MandateResponse> for StripeMandateResponse {
     }
 }
 
+impl From<api_models::payment_methods::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(res: api_models::payment_methods::PaymentMethodResponse) -> Self {
+        Self {
+            id: res.payment_method_id,
+            customer: res.customer_id,
+            payment_method_type: res.payment_method,
+        }
+    }
+}
+
 impl From<MandateStatus> for StripeMandateStatus {
     fn from(status: MandateStatus) -> Self {
         match status {
@@ -190,6 +210,21 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeLost => "dispute.lost",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
+        api_models::enums::EventType::PaymentMethodDeleted => "payment_method.detached",
+        api_models::enums::EventType::PaymentMethodUpdated
+        | api_models::enums::EventType::PaymentMethodClosed => {
+            "payment_method.automatically_updated"
+        }
+        // stripe does not have an equivalent event
+        api_models::enums::EventType::PaymentAuthorizationExpiring => {
+            "payment_intent.authorization_expiring"
+        }
+        // stripe does not have an equivalent event
+        api_models::enums::EventType::PaymentCaptureFailed => "payment_intent.capture_failed",
+        // stripe does not have an equivalent event
+        api_models::enums::EventType::ConnectorCircuitBreakerOpened => {
+            "connector.circuit_breaker_opened"
+        }
 
         // as per this doc https://stripe.com/docs/api/events/types#event_types-payment_intent.amount_capturable_updated
         api_models::enums::EventType::PaymentAuthorized => {
@@ -237,6 +272,12 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::MandateDetails(mandate) => {
                 Self::Mandate((*mandate).into())
             }
+            api::OutgoingWebhookContent::PaymentMethodDetails(payment_method) => {
+                Self::PaymentMethod((*payment_method).into())
+            }
+            api::OutgoingWebhookContent::ConnectorHealthDetails(connector_health) => {
+                Self::ConnectorHealth(*connector_health)
+            }
         }
     }
 }
@@ -44,6 +44,8 @@ impl Default for super::settings::Database {
             queue_strategy: Default::default(),
             min_idle: None,
             max_lifetime: None,
+            idle_timeout: None,
+            statement_timeout: None,
         }
     }
 }
@@ -101,6 +103,83 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::RateLimit {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_in_secs: 60,
+            payments_limit: 1000,
+            list_limit: 100,
+            default_limit: 500,
+        }
+    }
+}
+
+impl Default for super::settings::DataArchival {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            retention_period_in_days: 730,
+            batch_size: 1000,
+            interval_in_hours: 24,
+        }
+    }
+}
+
+impl Default for super::settings::EventOutbox {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            batch_size: 100,
+            grace_period_in_secs: 60,
+            interval_in_secs: 30,
+            max_retries: 5,
+        }
+    }
+}
+
+impl Default for super::settings::PaymentExport {
+    fn default() -> Self {
+        Self {
+            batch_size: 1000,
+            download_url_validity_in_secs: 3600,
+        }
+    }
+}
+
+impl Default for super::settings::AuthorizationValidity {
+    fn default() -> Self {
+        Self {
+            default_in_days: 7,
+            connectors: HashMap::new(),
+            card_networks: HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::PaymentStatusSync {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            batch_size: 100,
+            stale_after_in_secs: 900,
+            timeout_in_hours: 24,
+            interval_in_secs: 300,
+        }
+    }
+}
+
+impl Default for super::settings::Migrations {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            directory: "migrations".into(),
+            dry_run: false,
+            allow_destructive: false,
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
@@ -6650,6 +6729,9 @@ impl Default for super::settings::ApiKeys {
             // Specifies the number of days before API key expiry when email reminders should be sent
             #[cfg(feature = "email")]
             expiry_reminder_days: vec![7, 3, 1],
+
+            // CIDR blocks or IP addresses of the proxies trusted to set the `X-Forwarded-For` header
+            trusted_proxies: Vec::new(),
         }
     }
 }
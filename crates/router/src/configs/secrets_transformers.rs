@@ -114,10 +114,13 @@ impl SecretsHandler for settings::ApiKeys {
         #[cfg(feature = "email")]
         let expiry_reminder_days = api_keys.expiry_reminder_days.clone();
 
+        let trusted_proxies = api_keys.trusted_proxies.clone();
+
         Ok(value.transition_state(|_| Self {
             hash_key,
             #[cfg(feature = "email")]
             expiry_reminder_days,
+            trusted_proxies,
         }))
     }
 }
@@ -365,5 +368,15 @@ pub(crate) async fn fetch_raw_secrets(
         connector_onboarding,
         cors: conf.cors,
         unmasked_headers: conf.unmasked_headers,
+        card_bin_lookup: conf.card_bin_lookup,
+        card_account_updater: conf.card_account_updater,
+        geo_ip_lookup: conf.geo_ip_lookup,
+        rate_limit: conf.rate_limit,
+        data_archival: conf.data_archival,
+        event_outbox: conf.event_outbox,
+        payment_export: conf.payment_export,
+        migrations: conf.migrations,
+        payment_status_sync: conf.payment_status_sync,
+        authorization_validity: conf.authorization_validity,
     }
 }
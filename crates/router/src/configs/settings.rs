@@ -44,6 +44,11 @@ pub struct CmdLineConf {
     #[arg(short = 'f', long, value_name = "FILE")]
     pub config_path: Option<PathBuf>,
 
+    /// Use an in-memory store instead of the database.
+    /// Data is lost when the application stops, meant for local development only.
+    #[arg(long)]
+    pub mock_db: bool,
+
     #[command(subcommand)]
     pub subcommand: Option<Subcommand>,
 }
@@ -119,6 +124,46 @@ pub struct Settings<S: SecretState> {
     #[cfg(feature = "olap")]
     pub connector_onboarding: SecretStateContainer<ConnectorOnboarding, S>,
     pub unmasked_headers: UnmaskedHeaders,
+    pub card_bin_lookup: CardBinLookup,
+    pub card_account_updater: CardAccountUpdater,
+    pub geo_ip_lookup: GeoIpLookup,
+    pub rate_limit: RateLimit,
+    pub data_archival: DataArchival,
+    pub event_outbox: EventOutbox,
+    pub payment_export: PaymentExport,
+    pub migrations: Migrations,
+    pub payment_status_sync: PaymentStatusSync,
+    pub authorization_validity: AuthorizationValidity,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct CardAccountUpdater {
+    pub service: Option<CardAccountUpdaterService>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CardAccountUpdaterService {
+    pub base_url: String,
+    pub api_key: Secret<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct CardBinLookup {
+    pub external_provider: Option<ExternalLookupProvider>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct GeoIpLookup {
+    pub external_provider: Option<ExternalLookupProvider>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ExternalLookupProvider {
+    pub base_url: String,
+    pub api_key: Secret<String>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -425,6 +470,118 @@ pub struct Proxy {
     pub idle_pool_connection_timeout: Option<u64>,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DataArchival {
+    pub enabled: bool,
+    /// Payments created more than these many days ago are moved to the archive tables
+    pub retention_period_in_days: i64,
+    /// Maximum number of payments archived per database statement
+    pub batch_size: i64,
+    /// Interval between two runs of the archival task, in hours
+    pub interval_in_hours: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct EventOutbox {
+    /// Whether outbox entries are relayed as outgoing webhooks, they are discarded otherwise
+    pub enabled: bool,
+    /// Maximum number of outbox entries processed per batch
+    pub batch_size: i64,
+    /// Entries younger than these many seconds are left for the inline webhook flow to pick up
+    pub grace_period_in_secs: i64,
+    /// Interval between two runs of the relay task, in seconds
+    pub interval_in_secs: i64,
+    /// Number of runs in which relaying an entry may fail before the entry is discarded
+    pub max_retries: i32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PaymentExport {
+    /// Maximum number of payments read from the database per query while generating an export
+    pub batch_size: u32,
+    /// Duration for which the download URL of an export is valid, in seconds
+    pub download_url_validity_in_secs: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AuthorizationValidity {
+    /// Validity of authorizations for which no connector or card network specific validity is
+    /// configured, in days
+    pub default_in_days: i64,
+    /// Validity of authorizations made through each connector, in days
+    pub connectors: HashMap<String, i64>,
+    /// Validity of card authorizations for each card network, in days
+    pub card_networks: HashMap<enums::CardNetwork, i64>,
+}
+
+impl AuthorizationValidity {
+    /// The validity of an authorization is the shortest of the validities configured for its
+    /// connector and card network, or the default validity if neither is configured.
+    pub fn get_validity_in_days(
+        &self,
+        connector: Option<&str>,
+        card_network: Option<&enums::CardNetwork>,
+    ) -> i64 {
+        let connector_validity = connector.and_then(|connector| self.connectors.get(connector));
+        let card_network_validity =
+            card_network.and_then(|card_network| self.card_networks.get(card_network));
+
+        connector_validity
+            .into_iter()
+            .chain(card_network_validity)
+            .min()
+            .copied()
+            .unwrap_or(self.default_in_days)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PaymentStatusSync {
+    /// Whether the scheduler syncs pending payments, merchants' connectors are called on their
+    /// behalf so this has to be opted into
+    pub enabled: bool,
+    /// Maximum number of payments synced per run of the sync task
+    pub batch_size: i64,
+    /// Payments are synced once they have not been modified for these many seconds
+    pub stale_after_in_secs: i64,
+    /// Payments created more than these many hours ago are no longer synced
+    pub timeout_in_hours: i64,
+    /// Interval between two runs of the sync task, in seconds
+    pub interval_in_secs: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct Migrations {
+    /// Whether pending database migrations are run when the router starts
+    pub enabled: bool,
+    /// Directory containing the migrations, laid out the way the Diesel CLI expects
+    pub directory: PathBuf,
+    /// Only log the pending migrations, without running them
+    pub dry_run: bool,
+    /// Whether migrations that drop or delete data may be run, they are refused otherwise
+    pub allow_destructive: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RateLimit {
+    pub enabled: bool,
+    /// Duration of the fixed window over which requests are counted, in seconds
+    pub window_in_secs: i64,
+    /// Maximum number of requests per window for payment routes
+    pub payments_limit: i64,
+    /// Maximum number of requests per window for list routes
+    pub list_limit: i64,
+    /// Maximum number of requests per window for all other routes
+    pub default_limit: i64,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct Server {
@@ -449,6 +606,8 @@ pub struct Database {
     pub queue_strategy: QueueStrategy,
     pub min_idle: Option<u32>,
     pub max_lifetime: Option<u64>,
+    pub idle_timeout: Option<u64>,
+    pub statement_timeout: Option<u64>,
 }
 
 impl From<Database> for storage_impl::config::Database {
@@ -464,6 +623,8 @@ impl From<Database> for storage_impl::config::Database {
             queue_strategy: val.queue_strategy,
             min_idle: val.min_idle,
             max_lifetime: val.max_lifetime,
+            idle_timeout: val.idle_timeout,
+            statement_timeout: val.statement_timeout,
         }
     }
 }
@@ -526,6 +687,7 @@ pub struct Connectors {
     pub powertranz: ConnectorParams,
     pub prophetpay: ConnectorParams,
     pub rapyd: ConnectorParams,
+    pub razorpay: ConnectorParams,
     pub riskified: ConnectorParams,
     pub shift4: ConnectorParams,
     pub signifyd: ConnectorParams,
@@ -596,6 +758,10 @@ pub struct DrainerSettings {
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
+    /// Connectors whose incoming payment webhooks are not trusted to carry the status of the
+    /// payment, and always trigger a payment sync with the connector
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connectors_with_payment_sync_on_webhook: HashSet<api_models::enums::Connector>,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -615,6 +781,11 @@ pub struct ApiKeys {
     // Specifies the number of days before API key expiry when email reminders should be sent
     #[cfg(feature = "email")]
     pub expiry_reminder_days: Vec<u8>,
+
+    /// CIDR blocks or IP addresses of the proxies in front of the application, which are trusted
+    /// to append the client IP address to the `X-Forwarded-For` header
+    #[serde(default)]
+    pub trusted_proxies: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -729,6 +900,13 @@ impl Settings<SecuredSecret> {
 
         self.lock_settings.validate()?;
         self.events.validate()?;
+        self.rate_limit.validate()?;
+        self.data_archival.validate()?;
+        self.event_outbox.validate()?;
+        self.payment_export.validate()?;
+        self.migrations.validate()?;
+        self.payment_status_sync.validate()?;
+        self.authorization_validity.validate()?;
 
         #[cfg(feature = "olap")]
         self.opensearch.validate()?;
@@ -914,3 +1092,61 @@ mod hashset_deserialization_test {
         assert!(payment_methods.is_err());
     }
 }
+
+#[cfg(test)]
+mod authorization_validity_test {
+    use std::collections::HashMap;
+
+    use api_models::enums::CardNetwork;
+
+    use super::AuthorizationValidity;
+
+    fn get_authorization_validity() -> AuthorizationValidity {
+        AuthorizationValidity {
+            default_in_days: 7,
+            connectors: HashMap::from([("adyen".to_string(), 28), ("stripe".to_string(), 5)]),
+            card_networks: HashMap::from([(CardNetwork::Visa, 10), (CardNetwork::Mastercard, 30)]),
+        }
+    }
+
+    #[test]
+    fn test_validity_defaults_when_not_configured() {
+        let validity = get_authorization_validity();
+
+        assert_eq!(validity.get_validity_in_days(None, None), 7);
+        assert_eq!(
+            validity.get_validity_in_days(Some("checkout"), Some(&CardNetwork::AmericanExpress)),
+            7
+        );
+    }
+
+    #[test]
+    fn test_validity_of_connector_or_card_network() {
+        let validity = get_authorization_validity();
+
+        assert_eq!(validity.get_validity_in_days(Some("adyen"), None), 28);
+        assert_eq!(
+            validity.get_validity_in_days(None, Some(&CardNetwork::Mastercard)),
+            30
+        );
+        // A configured validity applies even if it is longer than the default
+        assert_eq!(
+            validity.get_validity_in_days(Some("checkout"), Some(&CardNetwork::Visa)),
+            10
+        );
+    }
+
+    #[test]
+    fn test_validity_is_shortest_of_connector_and_card_network() {
+        let validity = get_authorization_validity();
+
+        assert_eq!(
+            validity.get_validity_in_days(Some("adyen"), Some(&CardNetwork::Visa)),
+            10
+        );
+        assert_eq!(
+            validity.get_validity_in_days(Some("stripe"), Some(&CardNetwork::Mastercard)),
+            5
+        );
+    }
+}
@@ -65,6 +65,153 @@ impl super::settings::Server {
     }
 }
 
+impl super::settings::RateLimit {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.window_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rate limit window must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::DataArchival {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.retention_period_in_days <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "data archival retention period must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.batch_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "data archival batch size must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.interval_in_hours <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "data archival interval must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::EventOutbox {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.batch_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "event outbox batch size must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.grace_period_in_secs < 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "event outbox grace period must not be negative".into(),
+            ))
+        })?;
+
+        when(self.interval_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "event outbox interval must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.max_retries < 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "event outbox max retries must not be negative".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::PaymentExport {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.batch_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment export batch size must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.download_url_validity_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment export download URL validity must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::AuthorizationValidity {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            std::iter::once(&self.default_in_days)
+                .chain(self.connectors.values())
+                .chain(self.card_networks.values())
+                .any(|validity_in_days| *validity_in_days <= 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "authorization validity must be greater than 0".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::PaymentStatusSync {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.batch_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment status sync batch size must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.stale_after_in_secs < 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment status sync stale duration must not be negative".into(),
+            ))
+        })?;
+
+        when(self.timeout_in_hours <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment status sync timeout must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.interval_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment status sync interval must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::Migrations {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.enabled && self.directory.as_os_str().is_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "migrations directory must not be empty".into(),
+                ))
+            },
+        )
+    }
+}
+
 impl super::settings::Database {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -149,6 +296,16 @@ impl super::settings::ApiKeys {
             ))
         })?;
 
+        self.trusted_proxies.iter().try_for_each(|entry| {
+            crate::core::api_keys::parse_ip_allowlist_entry(entry)
+                .map(|_| ())
+                .map_err(|_| {
+                    ApplicationError::InvalidConfigurationValueError(format!(
+                        "Invalid API key trusted proxy entry: {entry}"
+                    ))
+                })
+        })?;
+
         Ok(())
     }
 }
@@ -1,7 +1,7 @@
 use bb8::PooledConnection;
 use diesel::PgConnection;
 use error_stack::ResultExt;
-use storage_impl::errors as storage_errors;
+use storage_impl::{database::store::get_pooled_connection, errors as storage_errors};
 
 use crate::errors;
 
@@ -30,7 +30,7 @@ pub async fn pg_connection_read<T: storage_impl::DatabaseStore>(
 > {
     // If only OLAP is enabled get replica pool.
     #[cfg(all(feature = "olap", not(feature = "oltp")))]
-    let pool = store.get_replica_pool();
+    let (pool, pool_name) = (store.get_replica_pool(), "replica");
 
     // If either one of these are true we need to get master pool.
     //  1. Only OLTP is enabled.
@@ -41,9 +41,23 @@ pub async fn pg_connection_read<T: storage_impl::DatabaseStore>(
         all(feature = "olap", feature = "oltp"),
         all(not(feature = "olap"), not(feature = "oltp"))
     ))]
-    let pool = store.get_master_pool();
+    let (pool, pool_name) = (store.get_master_pool(), "master");
 
-    pool.get()
+    get_pooled_connection(pool, pool_name)
+        .await
+        .change_context(storage_errors::StorageError::DatabaseConnectionError)
+}
+
+/// Gets a connection to the replica database, for the read-heavy queries of the list and filter
+/// endpoints which can tolerate replication lag. Reads that must observe a write made just before,
+/// such as a retrieve right after a create, should use [`pg_connection_read`] instead.
+pub async fn pg_connection_read_replica<T: storage_impl::DatabaseStore>(
+    store: &T,
+) -> errors::CustomResult<
+    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    storage_errors::StorageError,
+> {
+    get_pooled_connection(store.get_replica_pool(), "replica")
         .await
         .change_context(storage_errors::StorageError::DatabaseConnectionError)
 }
@@ -55,9 +69,7 @@ pub async fn pg_connection_write<T: storage_impl::DatabaseStore>(
     storage_errors::StorageError,
 > {
     // Since all writes should happen to master DB only choose master DB.
-    let pool = store.get_master_pool();
-
-    pool.get()
+    get_pooled_connection(store.get_master_pool(), "master")
         .await
         .change_context(storage_errors::StorageError::DatabaseConnectionError)
 }
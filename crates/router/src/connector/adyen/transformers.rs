@@ -1639,19 +1639,19 @@ fn get_recurring_processing_model(
 ) -> Result<RecurringDetails, Error> {
     match (item.request.setup_future_usage, item.request.off_session) {
         (Some(storage_enums::FutureUsage::OffSession), _) => {
-            let customer_id = item.get_customer_id()?;
-            let shopper_reference = format!("{}_{}", item.merchant_id, customer_id);
+            let shopper_reference =
+                build_shopper_reference(&Some(item.get_customer_id()?), item.merchant_id.clone());
             let store_payment_method = item.request.is_mandate_payment();
             Ok((
                 Some(AdyenRecurringModel::UnscheduledCardOnFile),
                 Some(store_payment_method),
-                Some(shopper_reference),
+                shopper_reference,
             ))
         }
         (_, Some(true)) => Ok((
             Some(AdyenRecurringModel::UnscheduledCardOnFile),
             None,
-            Some(format!("{}_{}", item.merchant_id, item.get_customer_id()?)),
+            build_shopper_reference(&Some(item.get_customer_id()?), item.merchant_id.clone()),
         )),
         _ => Ok((None, None, None)),
     }
@@ -1816,6 +1816,8 @@ fn get_social_security_number(voucher_data: &domain::VoucherData) -> Option<Secr
     }
 }
 
+/// Adyen stores the recurring details against the `shopperReference`, which is scoped to the
+/// merchant so that the same customer id across merchants does not share stored details.
 fn build_shopper_reference(customer_id: &Option<String>, merchant_id: String) -> Option<String> {
     customer_id
         .clone()
@@ -2475,7 +2477,7 @@ impl<'a> TryFrom<&domain::BankTransferData> for AdyenPaymentMethod<'a> {
                 last_name: billing_details.last_name.clone(),
                 shopper_email: billing_details.email.clone(),
             }))),
-            domain::BankTransferData::Pix {} => {
+            domain::BankTransferData::Pix { .. } => {
                 Ok(AdyenPaymentMethod::Pix(Box::new(PmdForPaymentType {
                     payment_type: PaymentType::Pix,
                 })))
@@ -46,7 +46,11 @@ impl TryFrom<&types::PaymentsInitRouterData> for AirwallexIntentRequest {
     fn try_from(item: &types::PaymentsInitRouterData) -> Result<Self, Self::Error> {
         Ok(Self {
             request_id: Uuid::new_v4().to_string(),
-            amount: utils::to_currency_base_unit(item.request.amount, item.request.currency)?,
+            amount: utils::convert_amount(
+                &utils::StringMajorUnit,
+                item.request.amount,
+                item.request.currency,
+            )?,
             currency: item.request.currency,
             merchant_order_id: item.connector_request_reference_id.clone(),
         })
@@ -341,7 +345,8 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for AirwallexPaymentsCaptureRequ
     fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
         Ok(Self {
             request_id: Uuid::new_v4().to_string(),
-            amount: Some(utils::to_currency_base_unit(
+            amount: Some(utils::convert_amount(
+                &utils::StringMajorUnit,
                 item.request.amount_to_capture,
                 item.request.currency,
             )?),
@@ -783,6 +788,10 @@ pub fn is_transaction_event(event_code: &AirwallexWebhookEventType) -> bool {
         event_code,
         AirwallexWebhookEventType::PaymentAttemptFailedToProcess
             | AirwallexWebhookEventType::PaymentAttemptAuthorized
+            | AirwallexWebhookEventType::PaymentAttemptAuthorizationFailed
+            | AirwallexWebhookEventType::PaymentAttemptRiskDeclined
+            | AirwallexWebhookEventType::PaymentAttemptCaptureFailed
+            | AirwallexWebhookEventType::PaymentAttemptCancelled
     )
 }
 
@@ -857,6 +866,14 @@ impl TryFrom<AirwallexWebhookEventType> for api_models::webhooks::IncomingWebhoo
         Ok(match value {
             AirwallexWebhookEventType::PaymentAttemptFailedToProcess => Self::PaymentIntentFailure,
             AirwallexWebhookEventType::PaymentAttemptAuthorized => Self::PaymentIntentSuccess,
+            AirwallexWebhookEventType::PaymentAttemptAuthorizationFailed
+            | AirwallexWebhookEventType::PaymentAttemptRiskDeclined => {
+                Self::PaymentIntentAuthorizationFailure
+            }
+            AirwallexWebhookEventType::PaymentAttemptCaptureFailed => {
+                Self::PaymentIntentCaptureFailure
+            }
+            AirwallexWebhookEventType::PaymentAttemptCancelled => Self::PaymentIntentCancelled,
             AirwallexWebhookEventType::RefundSucceeded => Self::RefundSuccess,
             AirwallexWebhookEventType::RefundFailed => Self::RefundFailure,
             AirwallexWebhookEventType::DisputeAccepted
@@ -873,14 +890,10 @@ impl TryFrom<AirwallexWebhookEventType> for api_models::webhooks::IncomingWebhoo
             | AirwallexWebhookEventType::PaymentIntentSucceeded
             | AirwallexWebhookEventType::PaymentIntentRequiresCapture
             | AirwallexWebhookEventType::PaymentIntentRequiresCustomerAction
-            | AirwallexWebhookEventType::PaymentAttemptAuthorizationFailed
             | AirwallexWebhookEventType::PaymentAttemptCaptureRequested
-            | AirwallexWebhookEventType::PaymentAttemptCaptureFailed
             | AirwallexWebhookEventType::PaymentAttemptAuthenticationRedirected
             | AirwallexWebhookEventType::PaymentAttemptAuthenticationFailed
-            | AirwallexWebhookEventType::PaymentAttemptCancelled
             | AirwallexWebhookEventType::PaymentAttemptExpired
-            | AirwallexWebhookEventType::PaymentAttemptRiskDeclined
             | AirwallexWebhookEventType::PaymentAttemptSettled
             | AirwallexWebhookEventType::PaymentAttemptPaid
             | AirwallexWebhookEventType::RefundReceived
@@ -19,7 +19,7 @@ use crate::{
         payments,
     },
     events::connector_api_logs::ConnectorEvent,
-    headers,
+    headers, routes,
     services::{self, request, ConnectorIntegration, ConnectorValidation},
     types::{
         self,
@@ -295,17 +295,7 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
         event_builder: Option<&mut ConnectorEvent>,
         res: types::Response,
     ) -> CustomResult<types::PaymentsSyncRouterData, errors::ConnectorError> {
-        use bytes::Buf;
-
-        // Handle the case where response bytes contains U+FEFF (BOM) character sent by connector
-        let encoding = encoding_rs::UTF_8;
-        let intermediate_response = encoding.decode_with_bom_removal(res.response.chunk());
-        let intermediate_response =
-            bytes::Bytes::copy_from_slice(intermediate_response.0.as_bytes());
-
-        let response: authorizedotnet::AuthorizedotnetSyncResponse = intermediate_response
-            .parse_struct("AuthorizedotnetSyncResponse")
-            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        let response = parse_sync_response(&res)?;
         event_builder.map(|i| i.set_response_body(&response));
         router_env::logger::info!(connector_response=?response);
         types::RouterData::try_from(types::ResponseRouterData {
@@ -324,6 +314,21 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
     }
 }
 
+fn parse_sync_response(
+    res: &types::Response,
+) -> CustomResult<authorizedotnet::AuthorizedotnetSyncResponse, errors::ConnectorError> {
+    use bytes::Buf;
+
+    // Handle the case where response bytes contains U+FEFF (BOM) character sent by connector
+    let encoding = encoding_rs::UTF_8;
+    let intermediate_response = encoding.decode_with_bom_removal(res.response.chunk());
+    let intermediate_response = bytes::Bytes::copy_from_slice(intermediate_response.0.as_bytes());
+
+    intermediate_response
+        .parse_struct("AuthorizedotnetSyncResponse")
+        .change_context(errors::ConnectorError::ResponseDeserializationFailed)
+}
+
 impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::PaymentsResponseData>
     for Authorizedotnet
 {
@@ -519,6 +524,7 @@ impl api::Refund for Authorizedotnet {}
 impl api::RefundExecute for Authorizedotnet {}
 impl api::RefundSync for Authorizedotnet {}
 
+#[async_trait::async_trait]
 impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData>
     for Authorizedotnet
 {
@@ -559,6 +565,57 @@ impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsRespon
         Ok(RequestContent::Json(Box::new(connector_req)))
     }
 
+    async fn execute_pretasks(
+        &self,
+        router_data: &mut types::RefundsRouterData<api::Execute>,
+        app_state: &routes::AppState,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        // Full refunds of transactions that are yet to be settled are performed as voids. The
+        // settlement state is fetched right before the refund, as it changes once the batch settles
+        if router_data.request.refund_amount != router_data.request.payment_amount {
+            return Ok(());
+        }
+        let connectors = &app_state.conf.connectors;
+        let sync_request =
+            authorizedotnet::AuthorizedotnetCreateSyncRequest::for_refunded_payment(router_data)?;
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(self.base_url(connectors))
+            .attach_default_headers()
+            .headers(self.build_headers(router_data, connectors)?)
+            .set_body(RequestContent::Json(Box::new(sync_request)))
+            .build();
+
+        let transaction_status = match services::call_connector_api(
+            app_state,
+            request,
+            "authorizedotnet_refund_transaction_details",
+        )
+        .await
+        {
+            Ok(Ok(res)) => parse_sync_response(&res)
+                .map_err(|error| {
+                    router_env::logger::warn!(transaction_details_parsing_error=?error);
+                })
+                .ok()
+                .and_then(|response| response.get_transaction_status()),
+            Ok(Err(res)) => {
+                router_env::logger::warn!(transaction_details_error_response=?res);
+                None
+            }
+            Err(error) => {
+                router_env::logger::warn!(transaction_details_request_error=?error);
+                None
+            }
+        };
+        router_data.request.connector_metadata =
+            authorizedotnet::set_transaction_status_in_metadata(
+                router_data.request.connector_metadata.take(),
+                transaction_status,
+            );
+        Ok(())
+    }
+
     fn build_request(
         &self,
         req: &types::RefundsRouterData<api::Execute>,
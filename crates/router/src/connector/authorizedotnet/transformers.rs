@@ -802,11 +802,53 @@ struct RefundTransactionRequest {
     reference_transaction_id: String,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum RefundOrVoidTransactionRequest {
+    Refund(RefundTransactionRequest),
+    Void(TransactionVoidOrCaptureRequest),
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AuthorizedotnetRefundRequest {
     merchant_authentication: AuthorizedotnetAuthType,
-    transaction_request: RefundTransactionRequest,
+    transaction_request: RefundOrVoidTransactionRequest,
+}
+
+/// Key under which the transaction status fetched right before a refund is passed to the refund
+/// request, alongside the payment details
+const TRANSACTION_STATUS_METADATA_KEY: &str = "transactionStatus";
+
+/// Replaces the transaction status passed to the refund request with the one that was just fetched
+pub fn set_transaction_status_in_metadata(
+    connector_metadata: Option<serde_json::Value>,
+    transaction_status: Option<SyncStatus>,
+) -> Option<serde_json::Value> {
+    let mut connector_metadata = connector_metadata?;
+    if let Some(metadata) = connector_metadata.as_object_mut() {
+        metadata.remove(TRANSACTION_STATUS_METADATA_KEY);
+        if let Some(transaction_status) = transaction_status
+            .as_ref()
+            .and_then(|transaction_status| serde_json::to_value(transaction_status).ok())
+        {
+            metadata.insert(
+                TRANSACTION_STATUS_METADATA_KEY.to_string(),
+                transaction_status,
+            );
+        }
+    }
+    Some(connector_metadata)
+}
+
+fn split_transaction_status_from_metadata(
+    mut connector_metadata: serde_json::Value,
+) -> (serde_json::Value, Option<SyncStatus>) {
+    let transaction_status = connector_metadata
+        .as_object_mut()
+        .and_then(|metadata| metadata.remove(TRANSACTION_STATUS_METADATA_KEY))
+        .and_then(|transaction_status| serde_json::from_value(transaction_status).ok());
+    (connector_metadata, transaction_status)
 }
 
 #[derive(Debug, Serialize)]
@@ -832,19 +874,39 @@ impl<F> TryFrom<&AuthorizedotnetRouterData<&types::RefundsRouterData<F>>> for Cr
             })?
             .clone();
 
+        let (payment_details, transaction_status) =
+            split_transaction_status_from_metadata(payment_details);
+
         let merchant_authentication =
             AuthorizedotnetAuthType::try_from(&item.router_data.connector_auth_type)?;
 
-        let transaction_request = RefundTransactionRequest {
-            transaction_type: TransactionType::Refund,
-            amount: item.amount,
-            payment: payment_details
-                .parse_value("PaymentDetails")
-                .change_context(errors::ConnectorError::MissingRequiredField {
-                    field_name: "payment_details",
-                })?,
-            currency_code: item.router_data.request.currency.to_string(),
-            reference_transaction_id: item.router_data.request.connector_transaction_id.clone(),
+        // Authorize.Net only accepts refunds for settled transactions, a full refund of a
+        // transaction that is yet to be settled has to be performed as a void instead
+        let is_unsettled = matches!(
+            transaction_status,
+            Some(SyncStatus::AuthorizedPendingCapture | SyncStatus::CapturedPendingSettlement)
+        );
+        let is_full_refund =
+            item.router_data.request.refund_amount == item.router_data.request.payment_amount;
+
+        let transaction_request = if is_unsettled && is_full_refund {
+            RefundOrVoidTransactionRequest::Void(TransactionVoidOrCaptureRequest {
+                transaction_type: TransactionType::Void,
+                amount: None,
+                ref_trans_id: item.router_data.request.connector_transaction_id.clone(),
+            })
+        } else {
+            RefundOrVoidTransactionRequest::Refund(RefundTransactionRequest {
+                transaction_type: TransactionType::Refund,
+                amount: item.amount,
+                payment: payment_details
+                    .parse_value("PaymentDetails")
+                    .change_context(errors::ConnectorError::MissingRequiredField {
+                        field_name: "payment_details",
+                    })?,
+                currency_code: item.router_data.request.currency.to_string(),
+                reference_transaction_id: item.router_data.request.connector_transaction_id.clone(),
+            })
         };
 
         Ok(Self {
@@ -943,6 +1005,22 @@ impl<F> TryFrom<&AuthorizedotnetRouterData<&types::RefundsRouterData<F>>>
     }
 }
 
+impl AuthorizedotnetCreateSyncRequest {
+    /// Request for the details of the payment against which a refund is issued
+    pub fn for_refunded_payment<F>(
+        item: &types::RefundsRouterData<F>,
+    ) -> Result<Self, error_stack::Report<errors::ConnectorError>> {
+        let merchant_authentication = AuthorizedotnetAuthType::try_from(&item.connector_auth_type)?;
+
+        Ok(Self {
+            get_transaction_details_request: TransactionDetails {
+                merchant_authentication,
+                transaction_id: Some(item.request.connector_transaction_id.clone()),
+            },
+        })
+    }
+}
+
 impl TryFrom<&types::PaymentsSyncRouterData> for AuthorizedotnetCreateSyncRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
 
@@ -988,6 +1066,8 @@ pub enum RSyncStatus {
     RefundPendingSettlement,
     Declined,
     GeneralError,
+    /// Full refunds of unsettled transactions are performed as voids
+    Voided,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -1004,6 +1084,13 @@ pub struct AuthorizedotnetSyncResponse {
     messages: ResponseMessages,
 }
 
+impl AuthorizedotnetSyncResponse {
+    pub fn get_transaction_status(self) -> Option<SyncStatus> {
+        self.transaction
+            .map(|transaction| transaction.transaction_status)
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RSyncTransactionResponse {
@@ -1038,7 +1125,7 @@ impl From<SyncStatus> for enums::AttemptStatus {
 impl From<RSyncStatus> for enums::RefundStatus {
     fn from(transaction_status: RSyncStatus) -> Self {
         match transaction_status {
-            RSyncStatus::RefundSettledSuccessfully => Self::Success,
+            RSyncStatus::RefundSettledSuccessfully | RSyncStatus::Voided => Self::Success,
             RSyncStatus::RefundPendingSettlement => Self::Pending,
             RSyncStatus::Declined | RSyncStatus::GeneralError => Self::Failure,
         }
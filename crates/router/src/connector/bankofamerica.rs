@@ -7,7 +7,6 @@ use common_utils::request::RequestContent;
 use diesel_models::enums;
 use error_stack::{report, ResultExt};
 use masking::{ExposeInterface, PeekInterface};
-use ring::{digest, hmac};
 use time::OffsetDateTime;
 use transformers as bankofamerica;
 use url::Url;
@@ -23,7 +22,7 @@ use crate::{
     services::{
         self,
         request::{self, Mask},
-        ConnectorIntegration, ConnectorValidation,
+        request_signing, ConnectorIntegration, ConnectorValidation,
     },
     types::{
         self,
@@ -55,8 +54,7 @@ impl api::PaymentsCompleteAuthorize for Bankofamerica {}
 
 impl Bankofamerica {
     pub fn generate_digest(&self, payload: &[u8]) -> String {
-        let payload_digest = digest::digest(&digest::SHA256, payload);
-        consts::BASE64_ENGINE.encode(payload_digest)
+        request_signing::generate_sha256_digest(payload)
     }
 
     pub fn generate_signature(
@@ -73,32 +71,24 @@ impl Bankofamerica {
             merchant_account,
             api_secret,
         } = auth;
-        let is_post_method = matches!(http_method, services::Method::Post);
-        let digest_str = if is_post_method { "digest " } else { "" };
-        let headers = format!("host date (request-target) {digest_str}{V_C_MERCHANT_ID}");
-        let request_target = if is_post_method {
-            format!("(request-target): post {resource}\ndigest: SHA-256={payload}\n")
-        } else {
-            format!("(request-target): get {resource}\n")
-        };
-        let signature_string = format!(
-            "host: {host}\ndate: {date}\n{request_target}{V_C_MERCHANT_ID}: {}",
-            merchant_account.peek()
-        );
         let key_value = consts::BASE64_ENGINE
             .decode(api_secret.expose())
             .change_context(errors::ConnectorError::InvalidConnectorConfig {
                 config: "connector_account_details.api_secret",
             })?;
-        let key = hmac::Key::new(hmac::HMAC_SHA256, &key_value);
-        let signature_value =
-            consts::BASE64_ENGINE.encode(hmac::sign(&key, signature_string.as_bytes()).as_ref());
-        let signature_header = format!(
-            r#"keyid="{}", algorithm="HmacSHA256", headers="{headers}", signature="{signature_value}""#,
-            api_key.peek()
-        );
+        let signature = request_signing::HttpMessageSignature::new(api_key.peek(), &key_value)
+            .component("host", host)
+            .component("date", date.to_string())
+            .request_target(http_method, resource);
+        let signature = if matches!(http_method, services::Method::Post) {
+            signature.component("digest", format!("SHA-256={payload}"))
+        } else {
+            signature
+        };
 
-        Ok(signature_header)
+        Ok(signature
+            .component(V_C_MERCHANT_ID, merchant_account.peek())
+            .sign())
     }
 }
 
@@ -1314,3 +1304,83 @@ impl api::IncomingWebhook for Bankofamerica {
         Err(report!(errors::ConnectorError::WebhooksNotImplemented))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use ring::{digest, hmac};
+    use time::macros::datetime;
+
+    use super::*;
+
+    const API_KEY: &str = "api_key";
+    const MERCHANT_ACCOUNT: &str = "merchant_account";
+    // The api secret is base64 encoded
+    const API_SECRET: &str = "c2VjcmV0X2tleQ==";
+    const HOST: &str = "apitest.merchant-services.bankofamerica.com";
+
+    fn get_auth() -> bankofamerica::BankOfAmericaAuthType {
+        bankofamerica::BankOfAmericaAuthType {
+            api_key: API_KEY.to_string().into(),
+            merchant_account: MERCHANT_ACCOUNT.to_string().into(),
+            api_secret: API_SECRET.to_string().into(),
+        }
+    }
+
+    /// The signature as it was built by hand, before `request_signing` was used
+    fn generate_legacy_signature(
+        resource: &str,
+        payload: &str,
+        date: OffsetDateTime,
+        http_method: services::Method,
+    ) -> String {
+        let is_post_method = matches!(http_method, services::Method::Post);
+        let digest_str = if is_post_method { "digest " } else { "" };
+        let headers = format!("host date (request-target) {digest_str}{V_C_MERCHANT_ID}");
+        let request_target = if is_post_method {
+            format!("(request-target): post {resource}\ndigest: SHA-256={payload}\n")
+        } else {
+            format!("(request-target): get {resource}\n")
+        };
+        let signature_string = format!(
+            "host: {HOST}\ndate: {date}\n{request_target}{V_C_MERCHANT_ID}: {MERCHANT_ACCOUNT}"
+        );
+        let key_value = consts::BASE64_ENGINE.decode(API_SECRET).unwrap();
+        let key = hmac::Key::new(hmac::HMAC_SHA256, &key_value);
+        let signature_value =
+            consts::BASE64_ENGINE.encode(hmac::sign(&key, signature_string.as_bytes()).as_ref());
+        format!(
+            r#"keyid="{API_KEY}", algorithm="HmacSHA256", headers="{headers}", signature="{signature_value}""#
+        )
+    }
+
+    #[test]
+    fn test_signature_matches_the_legacy_signature() {
+        let body = br#"{"clientReferenceInformation":{"code":"pay_1"}}"#;
+        let payload = Bankofamerica.generate_digest(body);
+        assert_eq!(
+            payload,
+            consts::BASE64_ENGINE.encode(digest::digest(&digest::SHA256, body))
+        );
+
+        let date = datetime!(2024-01-01 10:00:00 UTC);
+        for (http_method, resource) in [
+            (services::Method::Post, "/pts/v2/payments/"),
+            (services::Method::Get, "/tss/v2/transactions/1"),
+        ] {
+            assert_eq!(
+                Bankofamerica
+                    .generate_signature(
+                        get_auth(),
+                        HOST.to_string(),
+                        resource,
+                        &payload,
+                        date,
+                        http_method,
+                    )
+                    .unwrap(),
+                generate_legacy_signature(resource, &payload, date, http_method)
+            );
+        }
+    }
+}
@@ -1255,6 +1255,7 @@ impl ConnectorErrorTypeMapping for Bluesnap {
             ("14016", "NO_AVAILABLE_PROCESSORS") => ConnectorErrorType::TechnicalError,
             ("14034", "INVALID_PAYMENT_DETAILS") => ConnectorErrorType::UserError,
             ("15008", "SHOPPER_NOT_FOUND") => ConnectorErrorType::BusinessError,
+            ("15011", "FRAUD_DETECTED") => ConnectorErrorType::UserError,
             ("15012", "SHOPPER_COUNTRY_OFAC_SANCTIONED") => ConnectorErrorType::BusinessError,
             ("16003", "MULTIPLE_PAYMENT_METHODS_NON_SELECTED") => ConnectorErrorType::BusinessError,
             ("16001", "MISSING_ARGUMENTS") => ConnectorErrorType::BusinessError,
@@ -15,8 +15,8 @@ use crate::{
 
 pub const CLIENT_TOKEN_MUTATION: &str = "mutation createClientToken($input: CreateClientTokenInput!) { createClientToken(input: $input) { clientToken}}";
 pub const TOKENIZE_CREDIT_CARD: &str = "mutation  tokenizeCreditCard($input: TokenizeCreditCardInput!) { tokenizeCreditCard(input: $input) { clientMutationId paymentMethod { id } } }";
-pub const CHARGE_CREDIT_CARD_MUTATION: &str = "mutation ChargeCreditCard($input: ChargeCreditCardInput!) { chargeCreditCard(input: $input) { transaction { id legacyId createdAt amount { value currencyCode } status } } }";
-pub const AUTHORIZE_CREDIT_CARD_MUTATION: &str = "mutation authorizeCreditCard($input: AuthorizeCreditCardInput!) { authorizeCreditCard(input: $input) {  transaction { id legacyId amount { value currencyCode } status } } }";
+pub const CHARGE_CREDIT_CARD_MUTATION: &str = "mutation ChargeCreditCard($input: ChargeCreditCardInput!) { chargeCreditCard(input: $input) { transaction { id legacyId createdAt amount { value currencyCode } status paymentMethod { id } } } }";
+pub const AUTHORIZE_CREDIT_CARD_MUTATION: &str = "mutation authorizeCreditCard($input: AuthorizeCreditCardInput!) { authorizeCreditCard(input: $input) {  transaction { id legacyId amount { value currencyCode } status paymentMethod { id } } } }";
 pub const CAPTURE_TRANSACTION_MUTATION: &str = "mutation captureTransaction($input: CaptureTransactionInput!) { captureTransaction(input: $input) { clientMutationId transaction { id legacyId amount { value currencyCode } status } } }";
 pub const VOID_TRANSACTION_MUTATION: &str = "mutation voidTransaction($input:  ReverseTransactionInput!) { reverseTransaction(input: $input) { clientMutationId reversal { ...  on Transaction { id legacyId amount { value currencyCode } status } } } }";
 pub const REFUND_TRANSACTION_MUTATION: &str = "mutation refundTransaction($input:  RefundTransactionInput!) { refundTransaction(input: $input) {clientMutationId refund { id legacyId amount { value currencyCode } status } } }";
@@ -99,6 +99,46 @@ impl TryFrom<&Option<pii::SecretSerdeValue>> for BraintreeMeta {
 pub struct TransactionBody {
     amount: String,
     merchant_account_id: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vault_payment_method_after_transacting: Option<TransactionTiming>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransactionTiming {
+    when: VaultTiming,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VaultTiming {
+    OnSuccessfulTransaction,
+}
+
+fn is_payment_method_vaulted(
+    setup_future_usage: Option<enums::FutureUsage>,
+    is_mandate_payment: bool,
+    is_connector_mandate_payment: bool,
+) -> bool {
+    !is_connector_mandate_payment
+        && (setup_future_usage == Some(enums::FutureUsage::OffSession) || is_mandate_payment)
+}
+
+/// Braintree vaults the (single use) payment method used for the transaction only when asked to,
+/// the vaulted payment method id is then used as the connector mandate id for subsequent payments.
+/// A payment method charged with its connector mandate id is already vaulted.
+fn get_vault_payment_method_timing(
+    setup_future_usage: Option<enums::FutureUsage>,
+    is_mandate_payment: bool,
+    is_connector_mandate_payment: bool,
+) -> Option<TransactionTiming> {
+    is_payment_method_vaulted(
+        setup_future_usage,
+        is_mandate_payment,
+        is_connector_mandate_payment,
+    )
+    .then_some(TransactionTiming {
+        when: VaultTiming::OnSuccessfulTransaction,
+    })
 }
 
 impl TryFrom<&BraintreeRouterData<&types::PaymentsAuthorizeRouterData>>
@@ -128,6 +168,9 @@ impl TryFrom<&BraintreeRouterData<&types::PaymentsAuthorizeRouterData>>
                     Ok(Self::Card(CardPaymentRequest::try_from((item, metadata))?))
                 }
             }
+            domain::PaymentMethodData::MandatePayment => {
+                Ok(Self::Card(CardPaymentRequest::try_from((item, metadata))?))
+            }
             domain::PaymentMethodData::CardRedirect(_)
             | domain::PaymentMethodData::Wallet(_)
             | domain::PaymentMethodData::PayLater(_)
@@ -135,7 +178,6 @@ impl TryFrom<&BraintreeRouterData<&types::PaymentsAuthorizeRouterData>>
             | domain::PaymentMethodData::BankDebit(_)
             | domain::PaymentMethodData::BankTransfer(_)
             | domain::PaymentMethodData::Crypto(_)
-            | domain::PaymentMethodData::MandatePayment
             | domain::PaymentMethodData::Reward
             | domain::PaymentMethodData::Upi(_)
             | domain::PaymentMethodData::Voucher(_)
@@ -204,9 +246,41 @@ pub enum BraintreeCompleteAuthResponse {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TransactionAuthChargeResponseBody {
     id: String,
     status: BraintreePaymentStatus,
+    payment_method: Option<TransactionPaymentMethod>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TransactionPaymentMethod {
+    id: Secret<String>,
+}
+
+impl TransactionAuthChargeResponseBody {
+    /// The payment method id is a mandate reference only when the payment method was vaulted,
+    /// otherwise it is the single use token the transaction was made with.
+    fn get_mandate_reference(
+        &self,
+        setup_future_usage: Option<enums::FutureUsage>,
+        is_mandate_payment: bool,
+        is_connector_mandate_payment: bool,
+    ) -> Option<types::MandateReference> {
+        self.payment_method
+            .as_ref()
+            .filter(|_| {
+                is_payment_method_vaulted(
+                    setup_future_usage,
+                    is_mandate_payment,
+                    is_connector_mandate_payment,
+                )
+            })
+            .map(|payment_method| types::MandateReference {
+                connector_mandate_id: Some(payment_method.id.clone().expose()),
+                payment_method_id: None,
+            })
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -246,13 +320,18 @@ impl<F>
             }),
             BraintreeAuthResponse::AuthResponse(auth_response) => {
                 let transaction_data = auth_response.data.authorize_credit_card.transaction;
+                let mandate_reference = transaction_data.get_mandate_reference(
+                    item.data.request.setup_future_usage,
+                    item.data.request.setup_mandate_details.is_some(),
+                    item.data.request.connector_mandate_id().is_some(),
+                );
 
                 Ok(Self {
                     status: enums::AttemptStatus::from(transaction_data.status.clone()),
                     response: Ok(types::PaymentsResponseData::TransactionResponse {
                         resource_id: types::ResponseId::ConnectorTransactionId(transaction_data.id),
                         redirection_data: None,
-                        mandate_reference: None,
+                        mandate_reference,
                         connector_metadata: None,
                         network_txn_id: None,
                         connector_response_reference_id: None,
@@ -428,13 +507,18 @@ impl<F>
             }),
             BraintreePaymentsResponse::PaymentsResponse(payment_response) => {
                 let transaction_data = payment_response.data.charge_credit_card.transaction;
+                let mandate_reference = transaction_data.get_mandate_reference(
+                    item.data.request.setup_future_usage,
+                    item.data.request.setup_mandate_details.is_some(),
+                    item.data.request.connector_mandate_id().is_some(),
+                );
 
                 Ok(Self {
                     status: enums::AttemptStatus::from(transaction_data.status.clone()),
                     response: Ok(types::PaymentsResponseData::TransactionResponse {
                         resource_id: types::ResponseId::ConnectorTransactionId(transaction_data.id),
                         redirection_data: None,
-                        mandate_reference: None,
+                        mandate_reference,
                         connector_metadata: None,
                         network_txn_id: None,
                         connector_response_reference_id: None,
@@ -490,13 +574,18 @@ impl<F>
             }),
             BraintreeCompleteChargeResponse::PaymentsResponse(payment_response) => {
                 let transaction_data = payment_response.data.charge_credit_card.transaction;
+                let mandate_reference = transaction_data.get_mandate_reference(
+                    item.data.request.setup_future_usage,
+                    item.data.request.setup_mandate_details.is_some(),
+                    false,
+                );
 
                 Ok(Self {
                     status: enums::AttemptStatus::from(transaction_data.status.clone()),
                     response: Ok(types::PaymentsResponseData::TransactionResponse {
                         resource_id: types::ResponseId::ConnectorTransactionId(transaction_data.id),
                         redirection_data: None,
-                        mandate_reference: None,
+                        mandate_reference,
                         connector_metadata: None,
                         network_txn_id: None,
                         connector_response_reference_id: None,
@@ -535,13 +624,18 @@ impl<F>
             }),
             BraintreeCompleteAuthResponse::AuthResponse(auth_response) => {
                 let transaction_data = auth_response.data.authorize_credit_card.transaction;
+                let mandate_reference = transaction_data.get_mandate_reference(
+                    item.data.request.setup_future_usage,
+                    item.data.request.setup_mandate_details.is_some(),
+                    false,
+                );
 
                 Ok(Self {
                     status: enums::AttemptStatus::from(transaction_data.status.clone()),
                     response: Ok(types::PaymentsResponseData::TransactionResponse {
                         resource_id: types::ResponseId::ConnectorTransactionId(transaction_data.id),
                         redirection_data: None,
-                        mandate_reference: None,
+                        mandate_reference,
                         connector_metadata: None,
                         network_txn_id: None,
                         connector_response_reference_id: None,
@@ -1331,19 +1425,29 @@ impl
             true => CHARGE_CREDIT_CARD_MUTATION.to_string(),
             false => AUTHORIZE_CREDIT_CARD_MUTATION.to_string(),
         };
+        let payment_method_id = match item.router_data.request.connector_mandate_id() {
+            Some(connector_mandate_id) => connector_mandate_id.into(),
+            None => match item.router_data.get_payment_method_token()? {
+                types::PaymentMethodToken::Token(token) => token.into(),
+                types::PaymentMethodToken::ApplePayDecrypt(_) => Err(
+                    unimplemented_payment_method!("Apple Pay", "Simplified", "Braintree"),
+                )?,
+            },
+        };
+        let vault_payment_method_after_transacting = get_vault_payment_method_timing(
+            item.router_data.request.setup_future_usage,
+            item.router_data.request.setup_mandate_details.is_some(),
+            item.router_data.request.connector_mandate_id().is_some(),
+        );
         Ok(Self {
             query,
             variables: VariablePaymentInput {
                 input: PaymentInput {
-                    payment_method_id: match item.router_data.get_payment_method_token()? {
-                        types::PaymentMethodToken::Token(token) => token.into(),
-                        types::PaymentMethodToken::ApplePayDecrypt(_) => Err(
-                            unimplemented_payment_method!("Apple Pay", "Simplified", "Braintree"),
-                        )?,
-                    },
+                    payment_method_id,
                     transaction: TransactionBody {
                         amount: item.amount.to_owned(),
                         merchant_account_id: metadata.merchant_account_id,
+                        vault_payment_method_after_transacting,
                     },
                 },
             },
@@ -1398,6 +1502,11 @@ impl TryFrom<&BraintreeRouterData<&types::PaymentsCompleteAuthorizeRouterData>>
                     transaction: TransactionBody {
                         amount: item.amount.to_owned(),
                         merchant_account_id: metadata.merchant_account_id,
+                        vault_payment_method_after_transacting: get_vault_payment_method_timing(
+                            item.router_data.request.setup_future_usage,
+                            item.router_data.request.setup_mandate_details.is_some(),
+                            false,
+                        ),
                     },
                 },
             },
@@ -1517,3 +1626,78 @@ pub(crate) fn get_dispute_stage(code: &str) -> Result<enums::DisputeStage, error
         _ => Err(errors::ConnectorError::WebhookBodyDecodingFailed),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_transaction_body(
+        vault_payment_method_after_transacting: Option<TransactionTiming>,
+    ) -> serde_json::Value {
+        serde_json::to_value(TransactionBody {
+            amount: "10.00".to_string(),
+            merchant_account_id: Secret::new("merchant_account".to_string()),
+            vault_payment_method_after_transacting,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_payment_method_vaulted_for_future_usage() {
+        assert_eq!(
+            get_transaction_body(get_vault_payment_method_timing(
+                Some(enums::FutureUsage::OffSession),
+                false,
+                false
+            )),
+            serde_json::json!({
+                "amount": "10.00",
+                "merchantAccountId": "merchant_account",
+                "vaultPaymentMethodAfterTransacting": { "when": "ON_SUCCESSFUL_TRANSACTION" }
+            })
+        );
+        assert!(get_vault_payment_method_timing(None, true, false).is_some());
+    }
+
+    #[test]
+    fn test_payment_method_not_vaulted() {
+        assert_eq!(
+            get_transaction_body(get_vault_payment_method_timing(
+                Some(enums::FutureUsage::OnSession),
+                false,
+                false
+            )),
+            serde_json::json!({
+                "amount": "10.00",
+                "merchantAccountId": "merchant_account",
+            })
+        );
+        // The payment method charged with a connector mandate id is vaulted already
+        assert!(
+            get_vault_payment_method_timing(Some(enums::FutureUsage::OffSession), true, true)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_mandate_reference_of_vaulted_payment_method() {
+        let transaction: TransactionAuthChargeResponseBody = serde_json::from_str(
+            r#"{"id":"dHJhbnNhY3Rpb25fMQ","status":"SUBMITTED_FOR_SETTLEMENT","paymentMethod":{"id":"cGF5bWVudG1ldGhvZF8x"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            transaction
+                .get_mandate_reference(Some(enums::FutureUsage::OffSession), false, false)
+                .and_then(|mandate_reference| mandate_reference.connector_mandate_id),
+            Some("cGF5bWVudG1ldGhvZF8x".to_string())
+        );
+        assert!(transaction
+            .get_mandate_reference(Some(enums::FutureUsage::OnSession), false, false)
+            .is_none());
+        assert!(transaction
+            .get_mandate_reference(Some(enums::FutureUsage::OffSession), false, true)
+            .is_none());
+    }
+}
@@ -107,7 +107,11 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for BraintreePaymentsRequest {
             Some(enums::CaptureMethod::Automatic) | None
         );
         let merchant_account_id = metadata.merchant_account_id;
-        let amount = utils::to_currency_base_unit(item.request.amount, item.request.currency)?;
+        let amount = utils::convert_amount(
+            &utils::StringMajorUnit,
+            item.request.amount,
+            item.request.currency,
+        )?;
         let device_data = DeviceData {};
         let options = PaymentOptions {
             submit_for_settlement,
@@ -410,8 +414,11 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for BraintreeRefundRequest {
 
         utils::validate_currency(item.request.currency, metadata.merchant_config_currency)?;
 
-        let refund_amount =
-            utils::to_currency_base_unit(item.request.refund_amount, item.request.currency)?;
+        let refund_amount = utils::convert_amount(
+            &utils::StringMajorUnit,
+            item.request.refund_amount,
+            item.request.currency,
+        )?;
         Ok(Self {
             transaction: Amount {
                 amount: Some(refund_amount),
@@ -60,7 +60,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for CashtocodePaymentsRequest
         )?;
         match item.payment_method {
             diesel_models::enums::PaymentMethod::Reward => Ok(Self {
-                amount: utils::to_currency_base_unit_asf64(
+                amount: utils::convert_amount(
+                    &utils::FloatMajorUnit,
                     item.request.amount,
                     item.request.currency,
                 )?,
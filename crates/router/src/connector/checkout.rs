@@ -57,6 +57,27 @@ where
     }
 }
 
+impl Checkout {
+    /// Checkout.com replays the original response for a request retried with the same
+    /// idempotency key, so the key is derived from the identifier of the operation being performed.
+    fn build_headers_with_idempotency_key<Flow, Request, Response>(
+        &self,
+        req: &types::RouterData<Flow, Request, Response>,
+        connectors: &settings::Connectors,
+        idempotency_key: String,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError>
+    where
+        Self: ConnectorIntegration<Flow, Request, Response>,
+    {
+        let mut header = self.build_headers(req, connectors)?;
+        header.push((
+            headers::CKO_IDEMPOTENCY_KEY.to_string(),
+            idempotency_key.into(),
+        ));
+        Ok(header)
+    }
+}
+
 impl ConnectorCommon for Checkout {
     fn id(&self) -> &'static str {
         "checkout"
@@ -315,7 +336,13 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
         req: &types::PaymentsCaptureRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
-        self.build_headers(req, connectors)
+        let idempotency_key = req
+            .request
+            .multiple_capture_data
+            .as_ref()
+            .map(|multiple_capture_data| multiple_capture_data.capture_reference.clone())
+            .unwrap_or_else(|| format!("{}_capture", req.attempt_id));
+        self.build_headers_with_idempotency_key(req, connectors, idempotency_key)
     }
 
     fn get_url(
@@ -506,7 +533,9 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
         req: &types::PaymentsAuthorizeRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
-        self.build_headers(req, connectors)
+        // The request reference can be shared by the attempts of a payment, so the attempt id is
+        // used to keep a retried payment from replaying the response of a failed attempt
+        self.build_headers_with_idempotency_key(req, connectors, req.attempt_id.clone())
     }
 
     fn get_url(
@@ -594,7 +623,7 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
         req: &types::PaymentsCancelRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
-        self.build_headers(req, connectors)
+        self.build_headers_with_idempotency_key(req, connectors, format!("{}_void", req.attempt_id))
     }
 
     fn get_url(
@@ -679,7 +708,7 @@ impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsRespon
         req: &types::RefundsRouterData<api::Execute>,
         connectors: &settings::Connectors,
     ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
-        self.build_headers(req, connectors)
+        self.build_headers_with_idempotency_key(req, connectors, req.request.refund_id.clone())
     }
 
     fn get_content_type(&self) -> &'static str {
@@ -7,12 +7,13 @@ use common_utils::request::RequestContent;
 use diesel_models::enums;
 use error_stack::{report, Report, ResultExt};
 use masking::{ExposeInterface, PeekInterface};
-use ring::{digest, hmac};
 use time::OffsetDateTime;
 use transformers as cybersource;
 use url::Url;
 
-use super::utils::{PaymentsAuthorizeRequestData, RouterData};
+use super::utils::{
+    ConnectorErrorType, ConnectorErrorTypeMapping, PaymentsAuthorizeRequestData, RouterData,
+};
 use crate::{
     configs::settings,
     connector::{utils as connector_utils, utils::RefundsRequestData},
@@ -23,7 +24,7 @@ use crate::{
     services::{
         self,
         request::{self, Mask},
-        ConnectorIntegration, ConnectorValidation,
+        request_signing, ConnectorIntegration, ConnectorValidation,
     },
     types::{
         self,
@@ -37,8 +38,7 @@ pub struct Cybersource;
 
 impl Cybersource {
     pub fn generate_digest(&self, payload: &[u8]) -> String {
-        let payload_digest = digest::digest(&digest::SHA256, payload);
-        consts::BASE64_ENGINE.encode(payload_digest)
+        request_signing::generate_sha256_digest(payload)
     }
 
     pub fn generate_signature(
@@ -55,42 +55,27 @@ impl Cybersource {
             merchant_account,
             api_secret,
         } = auth;
-        let is_post_method = matches!(http_method, services::Method::Post);
-        let is_patch_method = matches!(http_method, services::Method::Patch);
-        let is_delete_method = matches!(http_method, services::Method::Delete);
-        let digest_str = if is_post_method || is_patch_method {
-            "digest "
-        } else {
-            ""
-        };
-        let headers = format!("host date (request-target) {digest_str}v-c-merchant-id");
-        let request_target = if is_post_method {
-            format!("(request-target): post {resource}\ndigest: SHA-256={payload}\n")
-        } else if is_patch_method {
-            format!("(request-target): patch {resource}\ndigest: SHA-256={payload}\n")
-        } else if is_delete_method {
-            format!("(request-target): delete {resource}\n")
-        } else {
-            format!("(request-target): get {resource}\n")
-        };
-        let signature_string = format!(
-            "host: {host}\ndate: {date}\n{request_target}v-c-merchant-id: {}",
-            merchant_account.peek()
-        );
         let key_value = consts::BASE64_ENGINE
             .decode(api_secret.expose())
             .change_context(errors::ConnectorError::InvalidConnectorConfig {
                 config: "connector_account_details.api_secret",
             })?;
-        let key = hmac::Key::new(hmac::HMAC_SHA256, &key_value);
-        let signature_value =
-            consts::BASE64_ENGINE.encode(hmac::sign(&key, signature_string.as_bytes()).as_ref());
-        let signature_header = format!(
-            r#"keyid="{}", algorithm="HmacSHA256", headers="{headers}", signature="{signature_value}""#,
-            api_key.peek()
-        );
+        let signature = request_signing::HttpMessageSignature::new(api_key.peek(), &key_value)
+            .component("host", host)
+            .component("date", date.to_string())
+            .request_target(http_method, resource);
+        let signature = if matches!(
+            http_method,
+            services::Method::Post | services::Method::Patch
+        ) {
+            signature.component("digest", format!("SHA-256={payload}"))
+        } else {
+            signature
+        };
 
-        Ok(signature_header)
+        Ok(signature
+            .component("v-c-merchant-id", merchant_account.peek())
+            .sign())
     }
 }
 
@@ -143,13 +128,22 @@ impl ConnectorCommon for Cybersource {
                             .map_or(error_message.to_string(), |message| message),
                     ),
                 };
-                let message = match response.details {
-                    Some(details) => details
+                let details = response.details.unwrap_or_default();
+                // Field level errors carry their own reason codes, the most relevant one of
+                // these and the top level reason is reported as the error code
+                let code = cybersource::get_reason_code_based_on_priority(
+                    std::iter::once(code.as_str())
+                        .chain(details.iter().map(|detail| detail.reason.as_str())),
+                )
+                .unwrap_or(code);
+                let message = if details.is_empty() {
+                    connector_reason.clone()
+                } else {
+                    details
                         .iter()
                         .map(|det| format!("{} : {}", det.field, det.reason))
                         .collect::<Vec<_>>()
-                        .join(", "),
-                    None => connector_reason.clone(),
+                        .join(", ")
                 };
 
                 Ok(types::ErrorResponse {
@@ -1507,3 +1501,105 @@ impl api::IncomingWebhook for Cybersource {
         Err(report!(errors::ConnectorError::WebhooksNotImplemented))
     }
 }
+
+impl ConnectorErrorTypeMapping for Cybersource {
+    fn get_connector_error_type(
+        &self,
+        error_code: String,
+        _error_message: String,
+    ) -> ConnectorErrorType {
+        cybersource::get_error_type(&error_code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use ring::{digest, hmac};
+    use time::macros::datetime;
+
+    use super::*;
+
+    const API_KEY: &str = "api_key";
+    const MERCHANT_ACCOUNT: &str = "merchant_account";
+    // The api secret is base64 encoded
+    const API_SECRET: &str = "c2VjcmV0X2tleQ==";
+    const HOST: &str = "apitest.cybersource.com";
+
+    fn get_auth() -> cybersource::CybersourceAuthType {
+        cybersource::CybersourceAuthType {
+            api_key: API_KEY.to_string().into(),
+            merchant_account: MERCHANT_ACCOUNT.to_string().into(),
+            api_secret: API_SECRET.to_string().into(),
+        }
+    }
+
+    /// The signature as it was built by hand, before `request_signing` was used
+    fn generate_legacy_signature(
+        resource: &str,
+        payload: &str,
+        date: OffsetDateTime,
+        http_method: services::Method,
+    ) -> String {
+        let is_post_method = matches!(http_method, services::Method::Post);
+        let is_patch_method = matches!(http_method, services::Method::Patch);
+        let is_delete_method = matches!(http_method, services::Method::Delete);
+        let digest_str = if is_post_method || is_patch_method {
+            "digest "
+        } else {
+            ""
+        };
+        let headers = format!("host date (request-target) {digest_str}v-c-merchant-id");
+        let request_target = if is_post_method {
+            format!("(request-target): post {resource}\ndigest: SHA-256={payload}\n")
+        } else if is_patch_method {
+            format!("(request-target): patch {resource}\ndigest: SHA-256={payload}\n")
+        } else if is_delete_method {
+            format!("(request-target): delete {resource}\n")
+        } else {
+            format!("(request-target): get {resource}\n")
+        };
+        let signature_string = format!(
+            "host: {HOST}\ndate: {date}\n{request_target}v-c-merchant-id: {MERCHANT_ACCOUNT}"
+        );
+        let key_value = consts::BASE64_ENGINE.decode(API_SECRET).unwrap();
+        let key = hmac::Key::new(hmac::HMAC_SHA256, &key_value);
+        let signature_value =
+            consts::BASE64_ENGINE.encode(hmac::sign(&key, signature_string.as_bytes()).as_ref());
+        format!(
+            r#"keyid="{API_KEY}", algorithm="HmacSHA256", headers="{headers}", signature="{signature_value}""#
+        )
+    }
+
+    #[test]
+    fn test_signature_matches_the_legacy_signature() {
+        let body = br#"{"clientReferenceInformation":{"code":"pay_1"}}"#;
+        let payload = Cybersource.generate_digest(body);
+        assert_eq!(
+            payload,
+            consts::BASE64_ENGINE.encode(digest::digest(&digest::SHA256, body))
+        );
+
+        let date = datetime!(2024-01-01 10:00:00 UTC);
+        for (http_method, resource) in [
+            (services::Method::Post, "/pts/v2/payments/"),
+            (services::Method::Patch, "/tms/v1/paymentinstruments/1"),
+            (services::Method::Delete, "/tms/v1/paymentinstruments/1"),
+            (services::Method::Get, "/tss/v2/transactions/1"),
+        ] {
+            assert_eq!(
+                Cybersource
+                    .generate_signature(
+                        get_auth(),
+                        HOST.to_string(),
+                        resource,
+                        &payload,
+                        date,
+                        http_method,
+                    )
+                    .unwrap(),
+                generate_legacy_signature(resource, &payload, date, http_method)
+            );
+        }
+    }
+}
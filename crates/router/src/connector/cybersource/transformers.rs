@@ -636,9 +636,13 @@ impl
                     };
 
                     let original_authorized_amount = match (original_amount, original_currency) {
-                        (Some(original_amount), Some(original_currency)) => Some(
-                            utils::to_currency_base_unit(original_amount, original_currency)?,
-                        ),
+                        (Some(original_amount), Some(original_currency)) => {
+                            Some(utils::convert_amount(
+                                &utils::StringMajorUnit,
+                                original_amount,
+                                original_currency,
+                            )?)
+                        }
                         _ => None,
                     };
                     commerce_indicator = "recurring".to_string();
@@ -1676,6 +1680,22 @@ pub struct CybersourceTokenInformation {
 pub struct CybersourceErrorInformation {
     reason: Option<String>,
     message: Option<String>,
+    details: Option<Vec<Details>>,
+}
+
+impl CybersourceErrorInformation {
+    /// Most relevant reason code of the decline, out of the reason of the decline and the reasons
+    /// of its field level errors
+    fn get_reason_code(&self) -> Option<String> {
+        get_reason_code_based_on_priority(
+            self.reason.iter().map(String::as_str).chain(
+                self.details
+                    .iter()
+                    .flatten()
+                    .map(|detail| detail.reason.as_str()),
+            ),
+        )
+    }
 }
 
 impl<F, T>
@@ -1702,7 +1722,7 @@ impl<F, T>
             .message
             .to_owned()
             .unwrap_or(consts::NO_ERROR_MESSAGE.to_string());
-        let error_message = error_response.error_information.reason.to_owned();
+        let error_message = error_response.error_information.get_reason_code();
         let response = Err(types::ErrorResponse {
             code: error_message
                 .clone()
@@ -1892,7 +1912,7 @@ impl<F>
                     .error_information
                     .message
                     .unwrap_or(consts::NO_ERROR_MESSAGE.to_string());
-                let error_message = error_response.error_information.reason;
+                let error_message = error_response.error_information.get_reason_code();
                 Ok(Self {
                     response: Err(types::ErrorResponse {
                         code: error_message
@@ -2262,7 +2282,7 @@ impl<F>
                     .message
                     .to_owned()
                     .unwrap_or(consts::NO_ERROR_MESSAGE.to_string());
-                let error_message = error_response.error_information.reason.to_owned();
+                let error_message = error_response.error_information.get_reason_code();
                 let response = Err(types::ErrorResponse {
                     code: error_message
                         .clone()
@@ -2501,7 +2521,7 @@ impl<F, T>
                     .message
                     .to_owned()
                     .unwrap_or(consts::NO_ERROR_MESSAGE.to_string());
-                let error_message = error_response.error_information.reason.to_owned();
+                let error_message = error_response.error_information.get_reason_code();
                 let response = Err(types::ErrorResponse {
                     code: error_message
                         .clone()
@@ -2551,7 +2571,7 @@ impl<F, T>
                 Some(error) => Ok(
                     types::PaymentsResponseData::IncrementalAuthorizationResponse {
                         status: common_enums::AuthorizationStatus::Failure,
-                        error_code: error.reason,
+                        error_code: error.get_reason_code(),
                         error_message: error.message,
                         connector_authorization_id: None,
                     },
@@ -2842,6 +2862,82 @@ pub struct ErrorInformation {
     pub reason: String,
 }
 
+/// Classification of the reason codes of Cybersource, of both the rejected requests and the
+/// declined payments
+pub fn get_error_type(reason: &str) -> utils::ConnectorErrorType {
+    match reason {
+        "MISSING_FIELD"
+        | "INVALID_DATA"
+        | "INVALID_CARD"
+        | "INVALID_CVN"
+        | "INVALID_AMOUNT"
+        | "INVALID_ACCOUNT"
+        | "EXPIRED_CARD"
+        | "CONSUMER_AUTHENTICATION_REQUIRED"
+        | "CONSUMER_AUTHENTICATION_FAILED"
+        | "ALLOWABLE_PIN_RETRIES_EXCEEDED" => utils::ConnectorErrorType::UserError,
+        "CARD_TYPE_NOT_ACCEPTED"
+        | "DUPLICATE_REQUEST"
+        | "AVS_FAILED"
+        | "CV_FAILED"
+        | "CVN_NOT_MATCH"
+        | "CONTACT_PROCESSOR"
+        | "PROCESSOR_DECLINED"
+        | "GENERAL_DECLINE"
+        | "DECLINED_CHECK"
+        | "INSUFFICIENT_FUND"
+        | "EXCEEDS_CREDIT_LIMIT"
+        | "STOLEN_LOST_CARD"
+        | "UNAUTHORIZED_CARD"
+        | "BLACKLISTED_CUSTOMER"
+        | "SUSPENDED_ACCOUNT"
+        | "PAYMENT_REFUSED"
+        | "ACH_VERIFICATION_FAILED"
+        | "DECISION_PROFILE_REJECT"
+        | "DECISION_PROFILE_REVIEW"
+        | "SCORE_EXCEEDS_THRESHOLD"
+        | "CUSTOMER_WATCHLIST_MATCH"
+        | "ADDRESS_COUNTRY_WATCHLIST_MATCH"
+        | "EMAIL_COUNTRY_WATCHLIST_MATCH"
+        | "IP_COUNTRY_WATCHLIST_MATCH"
+        | "INVALID_MERCHANT_CONFIGURATION"
+        | "NOT_SUPPORTED"
+        | "AUTH_ALREADY_REVERSED"
+        | "TRANSACTION_ALREADY_REVERSED_OR_SETTLED"
+        | "EXCEEDS_AUTH_AMOUNT"
+        | "AUTHORIZATION_ALREADY_REVERSED"
+        | "MISSING_AUTH" => utils::ConnectorErrorType::BusinessError,
+        "PROCESSOR_UNAVAILABLE"
+        | "PROCESSOR_ERROR"
+        | "ISSUER_UNAVAILABLE"
+        | "PROCESSOR_TIMEOUT"
+        | "SYSTEM_ERROR"
+        | "SERVER_TIMEOUT"
+        | "SERVICE_TIMEOUT" => utils::ConnectorErrorType::TechnicalError,
+        _ => utils::ConnectorErrorType::UnknownError,
+    }
+}
+
+/// Most relevant of the reason codes of an error, user errors first, then business and technical
+/// errors.
+///
+/// Unlike [`utils::get_error_code_error_message_based_on_priority`], a reason which is not
+/// classified is reported only when none of the reasons are, as the top level reason is often a
+/// generic one while the field level reasons are classified.
+pub fn get_reason_code_based_on_priority<'a>(
+    reasons: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    reasons
+        .into_iter()
+        .min_by_key(|reason| match get_error_type(reason) {
+            utils::ConnectorErrorType::UserError => 0,
+            utils::ConnectorErrorType::BusinessError => 1,
+            utils::ConnectorErrorType::TechnicalError => 2,
+            utils::ConnectorErrorType::UnknownError => 3,
+        })
+        .map(ToString::to_string)
+}
+
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct AuthenticationErrorInformation {
     pub rmsg: String,
@@ -2884,7 +2980,8 @@ impl
             .unwrap_or(consts::NO_ERROR_MESSAGE.to_string());
         let error_message = error_data
             .clone()
-            .and_then(|error_details| error_details.reason);
+            .as_ref()
+            .and_then(CybersourceErrorInformation::get_reason_code);
 
         Self {
             code: error_message
@@ -2900,3 +2997,56 @@ impl
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_classified_reason_preferred_over_unknown_reason() {
+        assert_eq!(
+            get_reason_code_based_on_priority(["INVALID_REQUEST", "MISSING_FIELD"]),
+            Some("MISSING_FIELD".to_string())
+        );
+        assert_eq!(
+            get_reason_code_based_on_priority(["SYSTEM_ERROR", "INVALID_DATA"]),
+            Some("INVALID_DATA".to_string())
+        );
+        assert_eq!(
+            get_reason_code_based_on_priority(["INVALID_REQUEST", "UNKNOWN_FIELD_ERROR"]),
+            Some("INVALID_REQUEST".to_string())
+        );
+        assert_eq!(get_reason_code_based_on_priority([]), None);
+    }
+
+    #[test]
+    fn test_decline_reason_code_of_created_payment() {
+        let error_information: CybersourceErrorInformation = serde_json::from_str(
+            r#"{"reason":"PROCESSOR_ERROR","message":"Decline - General decline by the processor.","details":[{"field":"paymentInformation.card.number","reason":"INVALID_DATA"}]}"#,
+        )
+        .unwrap();
+        let error_response = types::ErrorResponse::from((
+            &Some(error_information),
+            &None::<ClientRiskInformation>,
+            201,
+            "7041394532706789404951".to_string(),
+        ));
+
+        assert_eq!(error_response.code, "INVALID_DATA");
+        assert_eq!(error_response.status_code, 201);
+        assert_eq!(
+            error_response.attempt_status,
+            Some(enums::AttemptStatus::Failure)
+        );
+
+        let error_information: CybersourceErrorInformation = serde_json::from_str(
+            r#"{"reason":"INSUFFICIENT_FUND","message":"Decline - Insufficient funds in the account."}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            error_information.get_reason_code(),
+            Some("INSUFFICIENT_FUND".to_string())
+        );
+    }
+}
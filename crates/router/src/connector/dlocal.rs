@@ -5,12 +5,13 @@ use std::fmt::Debug;
 use common_utils::{
     crypto::{self, SignMessage},
     date_time,
+    ext_traits::ValueExt,
     request::RequestContent,
 };
 use diesel_models::enums;
-use error_stack::{report, ResultExt};
+use error_stack::ResultExt;
 use hex::encode;
-use masking::PeekInterface;
+use masking::{ExposeInterface, PeekInterface};
 use transformers as dlocal;
 
 use crate::{
@@ -676,26 +677,201 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+fn get_webhook_secrets(
+    connector_auth_type: &types::ConnectorAuthType,
+) -> CustomResult<api_models::webhooks::ConnectorWebhookSecrets, errors::ConnectorError> {
+    let auth = dlocal::DlocalAuthType::try_from(connector_auth_type)
+        .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+    Ok(api_models::webhooks::ConnectorWebhookSecrets {
+        secret: auth.secret.expose().into_bytes(),
+        additional_secret: None,
+    })
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Dlocal {
-    fn get_webhook_object_reference_id(
+    fn get_webhook_source_verification_algorithm(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
+        Ok(Box::new(crypto::HmacSha256))
+    }
+
+    /// Notifications are signed with the secret key of the dLocal account, as requests are, rather
+    /// than with a webhook secret
+    async fn get_webhook_source_verification_merchant_secret(
+        &self,
+        _merchant_account: &types::domain::MerchantAccount,
+        _connector_name: &str,
+        merchant_connector_account: types::domain::MerchantConnectorAccount,
+    ) -> CustomResult<api_models::webhooks::ConnectorWebhookSecrets, errors::ConnectorError> {
+        let connector_auth_type = merchant_connector_account
+            .connector_account_details
+            .parse_value::<types::ConnectorAuthType>("ConnectorAuthType")
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        get_webhook_secrets(&connector_auth_type)
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _connector_webhook_secrets: &api_models::webhooks::ConnectorWebhookSecrets,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let authorization =
+            connector_utils::get_header_key_value(headers::AUTHORIZATION, request.headers)
+                .change_context(errors::ConnectorError::WebhookSignatureNotFound)?;
+        // Notifications are signed the same way as requests: "V2-HMAC-SHA256, Signature: <hex>"
+        let signature = authorization
+            .split_once("Signature:")
+            .map(|(_, signature)| signature.trim())
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)?;
+        hex::decode(signature).change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        _connector_webhook_secrets: &api_models::webhooks::ConnectorWebhookSecrets,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let x_login = connector_utils::get_header_key_value("X-Login", request.headers)?;
+        let x_date = connector_utils::get_header_key_value("X-Date", request.headers)?;
+        Ok(format!(
+            "{}{}{}",
+            x_login,
+            x_date,
+            String::from_utf8_lossy(request.body)
+        )
+        .into_bytes())
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
-        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        let webhook: dlocal::DlocalWebhookBody = request
+            .body
+            .parse_struct("DlocalWebhookBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        Ok(api_models::webhooks::ObjectReferenceId::PaymentId(
+            api_models::payments::PaymentIdType::ConnectorTransactionId(webhook.id),
+        ))
     }
 
     fn get_webhook_event_type(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Ok(api::IncomingWebhookEvent::EventNotSupported)
+        let webhook: dlocal::DlocalWebhookBody = request
+            .body
+            .parse_struct("DlocalWebhookBody")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        Ok(api::IncomingWebhookEvent::from(webhook.status))
     }
 
     fn get_webhook_resource_object(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
-        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+        let webhook: dlocal::DlocalPaymentsSyncResponse = request
+            .body
+            .parse_struct("DlocalPaymentsSyncResponse")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        Ok(Box::new(webhook))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::types::api::IncomingWebhook;
+
+    const SECRET_KEY: &str = "dlocal_secret_key";
+    const X_LOGIN: &str = "dlocal_x_login";
+    const X_DATE: &str = "2024-01-01T00:00:00.000Z";
+    const WEBHOOK_BODY: &[u8] = br#"{"id":"D-1","status":"PAID"}"#;
+
+    fn get_auth_type(api_secret: &str) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::SignatureKey {
+            api_key: X_LOGIN.to_string().into(),
+            key1: "dlocal_x_trans_key".to_string().into(),
+            api_secret: api_secret.to_string().into(),
+        }
+    }
+
+    fn is_webhook_verified(signature: &str, body: &[u8]) -> bool {
+        let mut headers = actix_web::http::header::HeaderMap::new();
+        for (name, value) in [
+            (
+                "authorization",
+                format!("V2-HMAC-SHA256, Signature: {signature}"),
+            ),
+            ("x-login", X_LOGIN.to_string()),
+            ("x-date", X_DATE.to_string()),
+        ] {
+            headers.insert(
+                actix_web::http::header::HeaderName::from_static(name),
+                actix_web::http::header::HeaderValue::from_str(&value).unwrap(),
+            );
+        }
+        let request = api::IncomingWebhookRequestDetails {
+            method: actix_web::http::Method::POST,
+            uri: "/webhooks/merchant_1/dlocal".parse().unwrap(),
+            headers: &headers,
+            body,
+            query_params: String::new(),
+        };
+        let connector_webhook_secrets = get_webhook_secrets(&get_auth_type(SECRET_KEY)).unwrap();
+
+        let algorithm = Dlocal
+            .get_webhook_source_verification_algorithm(&request)
+            .unwrap();
+        let signature = Dlocal
+            .get_webhook_source_verification_signature(&request, &connector_webhook_secrets)
+            .unwrap();
+        let message = Dlocal
+            .get_webhook_source_verification_message(
+                &request,
+                "merchant_1",
+                &connector_webhook_secrets,
+            )
+            .unwrap();
+        algorithm
+            .verify_signature(&connector_webhook_secrets.secret, &signature, &message)
+            .unwrap()
+    }
+
+    fn sign(secret: &str, body: &[u8]) -> String {
+        let message = [X_LOGIN.as_bytes(), X_DATE.as_bytes(), body].concat();
+        encode(
+            crypto::HmacSha256
+                .sign_message(secret.as_bytes(), &message)
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_webhook_secret_is_the_secret_key() {
+        let secrets = get_webhook_secrets(&get_auth_type(SECRET_KEY)).unwrap();
+        assert_eq!(secrets.secret, SECRET_KEY.as_bytes());
+        assert!(get_webhook_secrets(&types::ConnectorAuthType::HeaderKey {
+            api_key: X_LOGIN.to_string().into(),
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_webhook_signature_verification() {
+        let signature = sign(SECRET_KEY, WEBHOOK_BODY);
+        assert!(is_webhook_verified(&signature, WEBHOOK_BODY));
+        // A tampered body does not match the signature
+        assert!(!is_webhook_verified(
+            &signature,
+            br#"{"id":"D-2","status":"PAID"}"#
+        ));
+        // Neither does a signature made with another key, such as a merchant webhook secret
+        let signature = sign("merchant_webhook_secret", WEBHOOK_BODY);
+        assert!(!is_webhook_verified(&signature, WEBHOOK_BODY));
     }
 }
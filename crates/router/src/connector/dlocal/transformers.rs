@@ -16,7 +16,8 @@ use crate::{
 pub struct Payer {
     pub name: Option<Secret<String>>,
     pub email: Option<Email>,
-    pub document: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document: Option<Secret<String>>,
 }
 
 #[derive(Debug, Default, Eq, Clone, PartialEq, Serialize, Deserialize)]
@@ -41,6 +42,11 @@ pub struct ThreeDSecureReqData {
 pub enum PaymentMethodId {
     #[default]
     Card,
+    Pix,
+    #[serde(rename = "OX")]
+    Oxxo,
+    #[serde(rename = "BL")]
+    Boleto,
 }
 
 #[derive(Debug, Serialize, Default, Deserialize, Clone, Eq, PartialEq)]
@@ -94,6 +100,7 @@ pub struct DlocalPaymentsRequest {
     pub order_id: String,
     pub three_dsecure: Option<ThreeDSecureReqData>,
     pub callback_url: Option<String>,
+    pub notification_url: Option<String>,
     pub description: Option<String>,
 }
 
@@ -122,7 +129,7 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
                         name,
                         email,
                         // [#589]: Allow securely collecting PII from customer in payments request
-                        document: get_doc_from_currency(country.to_string()),
+                        document: Some(get_doc_from_currency(country.to_string())),
                     },
                     card: Some(Card {
                         holder_name: item
@@ -156,21 +163,76 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
                         diesel_models::enums::AuthenticationType::NoThreeDs => None,
                     },
                     callback_url: Some(item.router_data.request.get_router_return_url()?),
+                    notification_url: item.router_data.request.webhook_url.clone(),
                     description: item.router_data.description.clone(),
                 };
                 Ok(payment_request)
             }
+            domain::PaymentMethodData::BankTransfer(ref bank_transfer_data) => {
+                match bank_transfer_data.as_ref() {
+                    domain::BankTransferData::Pix { cpf, cnpj } => get_local_payment_request(
+                        item,
+                        PaymentMethodId::Pix,
+                        Payer {
+                            name,
+                            email,
+                            document: Some(cpf.clone().or(cnpj.clone()).ok_or(
+                                errors::ConnectorError::MissingRequiredField {
+                                    field_name: "payment_method_data.bank_transfer.pix.cpf",
+                                },
+                            )?),
+                        },
+                        country.to_string(),
+                    ),
+                    _ => Err(errors::ConnectorError::NotImplemented(
+                        crate::connector::utils::get_unimplemented_payment_method_error_message(
+                            "Dlocal",
+                        ),
+                    ))?,
+                }
+            }
+            domain::PaymentMethodData::Voucher(ref voucher_data) => match voucher_data {
+                domain::VoucherData::Boleto(boleto_data) => get_local_payment_request(
+                    item,
+                    PaymentMethodId::Boleto,
+                    Payer {
+                        name,
+                        email,
+                        document: Some(boleto_data.social_security_number.clone().ok_or(
+                            errors::ConnectorError::MissingRequiredField {
+                                field_name:
+                                    "payment_method_data.voucher.boleto.social_security_number",
+                            },
+                        )?),
+                    },
+                    country.to_string(),
+                ),
+                domain::VoucherData::Oxxo => get_local_payment_request(
+                    item,
+                    PaymentMethodId::Oxxo,
+                    // The payer document is optional for OXXO payments
+                    Payer {
+                        name,
+                        email,
+                        document: None,
+                    },
+                    country.to_string(),
+                ),
+                _ => Err(errors::ConnectorError::NotImplemented(
+                    crate::connector::utils::get_unimplemented_payment_method_error_message(
+                        "Dlocal",
+                    ),
+                ))?,
+            },
             domain::PaymentMethodData::CardRedirect(_)
             | domain::PaymentMethodData::Wallet(_)
             | domain::PaymentMethodData::PayLater(_)
             | domain::PaymentMethodData::BankRedirect(_)
             | domain::PaymentMethodData::BankDebit(_)
-            | domain::PaymentMethodData::BankTransfer(_)
             | domain::PaymentMethodData::Crypto(_)
             | domain::PaymentMethodData::MandatePayment
             | domain::PaymentMethodData::Reward
             | domain::PaymentMethodData::Upi(_)
-            | domain::PaymentMethodData::Voucher(_)
             | domain::PaymentMethodData::GiftCard(_)
             | domain::PaymentMethodData::CardToken(_) => {
                 Err(errors::ConnectorError::NotImplemented(
@@ -183,6 +245,30 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
     }
 }
 
+/// Local payment methods are completed by the customer outside of the payment request, e.g. by
+/// paying a voucher, so their final status is only known once dLocal notifies us
+fn get_local_payment_request(
+    item: &DlocalRouterData<&types::PaymentsAuthorizeRouterData>,
+    payment_method_id: PaymentMethodId,
+    payer: Payer,
+    country: String,
+) -> Result<DlocalPaymentsRequest, error_stack::Report<errors::ConnectorError>> {
+    Ok(DlocalPaymentsRequest {
+        amount: item.amount,
+        currency: item.router_data.request.currency,
+        country,
+        payment_method_id,
+        payment_method_flow: PaymentMethodFlow::ReDirect,
+        payer,
+        card: None,
+        order_id: item.router_data.connector_request_reference_id.clone(),
+        three_dsecure: None,
+        callback_url: Some(item.router_data.request.get_router_return_url()?),
+        notification_url: item.router_data.request.webhook_url.clone(),
+        description: item.router_data.description.clone(),
+    })
+}
+
 fn get_payer_name(address: &AddressDetails) -> Option<Secret<String>> {
     let first_name = address
         .first_name
@@ -310,6 +396,7 @@ pub struct DlocalPaymentsResponse {
     status: DlocalPaymentStatus,
     id: String,
     three_dsecure: Option<ThreeDSecureResData>,
+    redirect_url: Option<Url>,
     order_id: Option<String>,
 }
 
@@ -325,6 +412,7 @@ impl<F, T>
             .response
             .three_dsecure
             .and_then(|three_secure_data| three_secure_data.redirect_url)
+            .or(item.response.redirect_url)
             .map(|redirect_url| {
                 services::RedirectForm::from((redirect_url, services::Method::Get))
             });
@@ -563,6 +651,26 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct DlocalWebhookBody {
+    pub id: String,
+    pub status: DlocalPaymentStatus,
+}
+
+impl From<DlocalPaymentStatus> for api::IncomingWebhookEvent {
+    fn from(status: DlocalPaymentStatus) -> Self {
+        match status {
+            DlocalPaymentStatus::Paid => Self::PaymentIntentSuccess,
+            DlocalPaymentStatus::Authorized | DlocalPaymentStatus::Verified => {
+                Self::PaymentIntentAuthorizationSuccess
+            }
+            DlocalPaymentStatus::Rejected => Self::PaymentIntentFailure,
+            DlocalPaymentStatus::Cancelled => Self::PaymentIntentCancelled,
+            DlocalPaymentStatus::Pending => Self::PaymentIntentProcessing,
+        }
+    }
+}
+
 #[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
 pub struct DlocalErrorResponse {
     pub code: i32,
@@ -211,7 +211,7 @@ impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
         match item {
             DummyConnectorPaymentStatus::Succeeded => Self::Charged,
             DummyConnectorPaymentStatus::Failed => Self::Failure,
-            DummyConnectorPaymentStatus::Processing => Self::AuthenticationPending,
+            DummyConnectorPaymentStatus::Processing => Self::Pending,
         }
     }
 }
@@ -249,8 +249,15 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentsResponse, T, types::Paym
             .map(|redirection_url| {
                 services::RedirectForm::from((redirection_url, services::Method::Get))
             });
+        // A payment that is processing with a redirection is waiting for the customer to authenticate
+        let status = match (&item.response.status, &redirection_data) {
+            (DummyConnectorPaymentStatus::Processing, Some(_)) => {
+                enums::AttemptStatus::AuthenticationPending
+            }
+            (status, _) => enums::AttemptStatus::from(status.to_owned()),
+        };
         Ok(Self {
-            status: enums::AttemptStatus::from(item.response.status),
+            status,
             response: Ok(types::PaymentsResponseData::TransactionResponse {
                 resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
                 redirection_data,
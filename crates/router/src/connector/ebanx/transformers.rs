@@ -31,7 +31,7 @@ impl<T> TryFrom<(&CurrencyUnit, Currency, i64, T)> for EbanxRouterData<T> {
         (_currency_unit, currency, amount, item): (&CurrencyUnit, Currency, i64, T),
     ) -> Result<Self, Self::Error> {
         Ok(Self {
-            amount: utils::to_currency_base_unit_asf64(amount, currency)?,
+            amount: utils::convert_amount(&utils::FloatMajorUnit, amount, currency)?,
             router_data: item,
         })
     }
@@ -7,6 +7,7 @@ use crate::{
         self, CardData as CardDataUtil, PaymentsCancelRequestData, PaymentsSyncRequestData,
         RouterData,
     },
+    consts,
     core::errors,
     pii::Secret,
     types::{self, api, domain, storage::enums},
@@ -329,6 +330,22 @@ impl From<FiservPaymentStatus> for enums::RefundStatus {
 #[serde(rename_all = "camelCase")]
 pub struct FiservPaymentsResponse {
     gateway_response: GatewayResponse,
+    payment_receipt: Option<PaymentReceipt>,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentReceipt {
+    processor_response_details: Option<ProcessorResponseDetails>,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessorResponseDetails {
+    response_code: Option<String>,
+    response_message: Option<String>,
+    host_response_code: Option<String>,
+    host_response_message: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -353,6 +370,50 @@ pub struct TransactionProcessingDetails {
     transaction_id: String,
 }
 
+/// Declined transactions are returned with a success http status, the reason for the decline is
+/// only present in the processor response details of the payment receipt
+fn get_error_response(
+    response: &FiservPaymentsResponse,
+    http_code: u16,
+) -> Option<types::ErrorResponse> {
+    match response.gateway_response.transaction_state {
+        FiservPaymentStatus::Declined | FiservPaymentStatus::Failed => {
+            let processor_response = response
+                .payment_receipt
+                .as_ref()
+                .and_then(|receipt| receipt.processor_response_details.clone())
+                .unwrap_or_default();
+            Some(types::ErrorResponse {
+                code: processor_response
+                    .response_code
+                    .or(processor_response.host_response_code)
+                    .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                message: processor_response
+                    .response_message
+                    .clone()
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                reason: processor_response
+                    .host_response_message
+                    .or(processor_response.response_message),
+                status_code: http_code,
+                attempt_status: None,
+                connector_transaction_id: Some(
+                    response
+                        .gateway_response
+                        .transaction_processing_details
+                        .transaction_id
+                        .clone(),
+                ),
+            })
+        }
+        FiservPaymentStatus::Succeeded
+        | FiservPaymentStatus::Captured
+        | FiservPaymentStatus::Voided
+        | FiservPaymentStatus::Authorized
+        | FiservPaymentStatus::Processing => None,
+    }
+}
+
 impl<F, T>
     TryFrom<types::ResponseRouterData<F, FiservPaymentsResponse, T, types::PaymentsResponseData>>
     for types::RouterData<F, T, types::PaymentsResponseData>
@@ -361,6 +422,15 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, FiservPaymentsResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
+        if let Some(error_response) = get_error_response(&item.response, item.http_code) {
+            return Ok(Self {
+                status: enums::AttemptStatus::from(
+                    item.response.gateway_response.transaction_state,
+                ),
+                response: Err(error_response),
+                ..item.data
+            });
+        }
         let gateway_resp = item.response.gateway_response;
 
         Ok(Self {
@@ -394,6 +464,15 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, FiservSyncResponse, T, types::Pa
             Some(gateway_response) => gateway_response,
             _ => Err(errors::ConnectorError::ResponseHandlingFailed)?,
         };
+        if let Some(error_response) = get_error_response(gateway_resp, item.http_code) {
+            return Ok(Self {
+                status: enums::AttemptStatus::from(
+                    gateway_resp.gateway_response.transaction_state.clone(),
+                ),
+                response: Err(error_response),
+                ..item.data
+            });
+        }
 
         Ok(Self {
             status: enums::AttemptStatus::from(
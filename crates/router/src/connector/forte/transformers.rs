@@ -93,8 +93,11 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for FortePaymentsRequest {
                     first_name: first_name.clone(),
                     last_name: address.get_last_name().unwrap_or(first_name).clone(),
                 };
-                let authorization_amount =
-                    utils::to_currency_base_unit_asf64(item.request.amount, item.request.currency)?;
+                let authorization_amount = utils::convert_amount(
+                    &utils::FloatMajorUnit,
+                    item.request.amount,
+                    item.request.currency,
+                )?;
                 Ok(Self {
                     action,
                     authorization_amount,
@@ -484,8 +487,11 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for ForteRefundRequest {
         let connector_auth_id: ForteMeta =
             utils::to_connector_meta(item.request.connector_metadata.clone())?;
         let auth_code = connector_auth_id.auth_id;
-        let authorization_amount =
-            utils::to_currency_base_unit_asf64(item.request.refund_amount, item.request.currency)?;
+        let authorization_amount = utils::convert_amount(
+            &utils::FloatMajorUnit,
+            item.request.refund_amount,
+            item.request.currency,
+        )?;
         Ok(Self {
             action: "reverse".to_string(),
             authorization_amount,
@@ -44,7 +44,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for GlobalpayPaymentsRequest {
             capture_mode: Some(requests::CaptureMode::from(item.request.capture_method)),
             payment_method: requests::PaymentMethod {
                 payment_method_data,
-                authentication: None,
+                authentication: get_authentication(item),
                 encryption: None,
                 entry_mode: Default::default(),
                 fingerprint_mode: None,
@@ -406,6 +406,29 @@ fn get_payment_method_data(
     }
 }
 
+/// Results of a 3DS authentication performed through an external authenticator, so that the
+/// authorization carries the liability shift obtained from the challenge.
+fn get_authentication(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Option<requests::Authentication> {
+    item.request
+        .authentication_data
+        .as_ref()
+        .map(|authentication_data| requests::Authentication {
+            three_ds: Some(requests::ThreeDs {
+                ds_trans_reference: None,
+                eci: authentication_data.eci.clone(),
+                exempt_status: None,
+                message_version: Some(authentication_data.message_version.clone()),
+                server_trans_reference: Some(
+                    authentication_data.threeds_server_transaction_id.clone(),
+                ),
+                value: Some(authentication_data.cavv.clone()),
+            }),
+            mac: None,
+        })
+}
+
 fn get_return_url(item: &types::PaymentsAuthorizeRouterData) -> Option<String> {
     match item.request.payment_method_data.clone() {
         domain::PaymentMethodData::Wallet(domain::WalletData::PaypalRedirect(_)) => {
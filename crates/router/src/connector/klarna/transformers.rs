@@ -4,8 +4,9 @@ use masking::{ExposeInterface, Secret};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    connector::utils::{PaymentsAuthorizeRequestData, PaymentsCaptureRequestData},
     core::errors,
-    types::{self, storage::enums},
+    types::{self, api, storage::enums},
 };
 
 #[derive(Debug, Serialize)]
@@ -46,6 +47,7 @@ pub struct KlarnaPaymentsRequest {
     purchase_country: String,
     purchase_currency: enums::Currency,
     merchant_reference1: String,
+    auto_capture: bool,
 }
 
 #[derive(Default, Debug, Deserialize, Serialize)]
@@ -142,6 +144,7 @@ impl TryFrom<&KlarnaRouterData<&types::PaymentsAuthorizeRouterData>> for KlarnaP
                     })
                     .collect(),
                 merchant_reference1: item.router_data.connector_request_reference_id.clone(),
+                auto_capture: request.is_auto_capture()?,
             }),
             None => Err(report!(errors::ConnectorError::MissingRequiredField {
                 field_name: "product_name"
@@ -157,6 +160,7 @@ impl TryFrom<types::PaymentsResponseRouterData<KlarnaPaymentsResponse>>
     fn try_from(
         item: types::PaymentsResponseRouterData<KlarnaPaymentsResponse>,
     ) -> Result<Self, Self::Error> {
+        let is_auto_capture = item.data.request.is_auto_capture()?;
         Ok(Self {
             response: Ok(types::PaymentsResponseData::TransactionResponse {
                 resource_id: types::ResponseId::ConnectorTransactionId(
@@ -169,7 +173,250 @@ impl TryFrom<types::PaymentsResponseRouterData<KlarnaPaymentsResponse>>
                 connector_response_reference_id: Some(item.response.order_id.clone()),
                 incremental_authorization_allowed: None,
             }),
-            status: item.response.fraud_status.into(),
+            status: get_fraud_status(item.response.fraud_status, is_auto_capture),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct KlarnaCaptureRequest {
+    captured_amount: i64,
+    reference: Option<String>,
+}
+
+impl TryFrom<&KlarnaRouterData<&types::PaymentsCaptureRouterData>> for KlarnaCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &KlarnaRouterData<&types::PaymentsCaptureRouterData>,
+    ) -> Result<Self, Self::Error> {
+        let reference = Some(
+            item.router_data
+                .request
+                .multiple_capture_data
+                .as_ref()
+                .map(|multiple_capture_data| multiple_capture_data.capture_reference.clone())
+                .unwrap_or(item.router_data.connector_request_reference_id.clone()),
+        );
+        Ok(Self {
+            reference,
+            captured_amount: item.amount.to_owned(),
+        })
+    }
+}
+
+/// Klarna responds to a capture with an empty body, the id of the capture is returned in the
+/// `Capture-Id` header
+#[derive(Debug, Serialize)]
+pub struct KlarnaCaptureResponse {
+    pub capture_id: Option<String>,
+}
+
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            KlarnaCaptureResponse,
+            types::PaymentsCaptureData,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, types::PaymentsCaptureData, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            KlarnaCaptureResponse,
+            types::PaymentsCaptureData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let connector_transaction_id = item.data.request.connector_transaction_id.clone();
+        let status = get_capture_status(item.http_code, &item.data.request);
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(connector_transaction_id),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: item.response.capture_id,
+                incremental_authorization_allowed: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+/// Klarna confirms a capture with a `201`. Each capture of an order captured multiple times is
+/// charged on its own, the status of the payment is then derived from all of its captures.
+fn get_capture_status(
+    http_code: u16,
+    request: &types::PaymentsCaptureData,
+) -> enums::AttemptStatus {
+    match http_code {
+        201 if request.is_multiple_capture()
+            || request.amount_to_capture >= request.payment_amount =>
+        {
+            enums::AttemptStatus::Charged
+        }
+        201 => enums::AttemptStatus::PartialCharged,
+        _ => enums::AttemptStatus::CaptureInitiated,
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct KlarnaPsyncResponse {
+    pub order_id: String,
+    pub status: KlarnaOrderStatus,
+    pub order_amount: i64,
+    #[serde(default)]
+    pub captured_amount: i64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum KlarnaOrderStatus {
+    Authorized,
+    PartCaptured,
+    Captured,
+    Cancelled,
+    Expired,
+    Closed,
+}
+
+impl From<&KlarnaPsyncResponse> for enums::AttemptStatus {
+    fn from(item: &KlarnaPsyncResponse) -> Self {
+        match item.status {
+            KlarnaOrderStatus::Authorized => Self::Authorized,
+            KlarnaOrderStatus::PartCaptured => Self::PartialCharged,
+            KlarnaOrderStatus::Captured => Self::Charged,
+            KlarnaOrderStatus::Cancelled => Self::Voided,
+            KlarnaOrderStatus::Expired => Self::Failure,
+            // An order is closed once no further captures are possible, whatever was captured
+            // until then stays captured and the remaining authorization is released
+            KlarnaOrderStatus::Closed => {
+                if item.captured_amount <= 0 {
+                    Self::Voided
+                } else if item.captured_amount < item.order_amount {
+                    Self::PartialCharged
+                } else {
+                    Self::Charged
+                }
+            }
+        }
+    }
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, KlarnaPsyncResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, KlarnaPsyncResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: enums::AttemptStatus::from(&item.response),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.response.order_id.clone(),
+                ),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: Some(item.response.order_id),
+                incremental_authorization_allowed: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct KlarnaRefundRequest {
+    refunded_amount: i64,
+    reference: Option<String>,
+}
+
+impl<F> TryFrom<&KlarnaRouterData<&types::RefundsRouterData<F>>> for KlarnaRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &KlarnaRouterData<&types::RefundsRouterData<F>>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            refunded_amount: item.amount,
+            reference: Some(item.router_data.request.refund_id.clone()),
+        })
+    }
+}
+
+/// Klarna responds to a refund with an empty body, the id of the refund is returned in the
+/// `Refund-Id` header
+#[derive(Debug, Serialize)]
+pub struct KlarnaRefundResponse {
+    pub refund_id: String,
+}
+
+impl TryFrom<types::RefundsResponseRouterData<api::Execute, KlarnaRefundResponse>>
+    for types::RefundsRouterData<api::Execute>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::Execute, KlarnaRefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.refund_id,
+                refund_status: get_refund_status(item.http_code),
+            }),
+            ..item.data
+        })
+    }
+}
+
+/// Klarna accepts a refund with a `201`, the refund is then confirmed by the refund sync once it
+/// has been performed
+fn get_refund_status(http_code: u16) -> enums::RefundStatus {
+    match http_code {
+        201 => enums::RefundStatus::Pending,
+        _ => enums::RefundStatus::Failure,
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct KlarnaRefundSyncResponse {
+    pub refund_id: String,
+    pub refunded_amount: i64,
+    /// The time at which the refund was performed, absent while it is being processed
+    pub refunded_at: Option<String>,
+}
+
+impl From<&KlarnaRefundSyncResponse> for enums::RefundStatus {
+    fn from(item: &KlarnaRefundSyncResponse) -> Self {
+        if item.refunded_at.is_some() {
+            Self::Success
+        } else {
+            Self::Pending
+        }
+    }
+}
+
+impl TryFrom<types::RefundsResponseRouterData<api::RSync, KlarnaRefundSyncResponse>>
+    for types::RefundsRouterData<api::RSync>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::RSync, KlarnaRefundSyncResponse>,
+    ) -> Result<Self, Self::Error> {
+        let refund_status = enums::RefundStatus::from(&item.response);
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.refund_id,
+                refund_status,
+            }),
             ..item.data
         })
     }
@@ -216,12 +463,19 @@ pub enum KlarnaFraudStatus {
     Pending,
 }
 
-impl From<KlarnaFraudStatus> for enums::AttemptStatus {
-    fn from(item: KlarnaFraudStatus) -> Self {
-        match item {
-            KlarnaFraudStatus::Accepted => Self::Charged,
-            KlarnaFraudStatus::Pending => Self::Authorizing,
+fn get_fraud_status(
+    klarna_status: KlarnaFraudStatus,
+    is_auto_capture: bool,
+) -> enums::AttemptStatus {
+    match klarna_status {
+        KlarnaFraudStatus::Accepted => {
+            if is_auto_capture {
+                enums::AttemptStatus::Charged
+            } else {
+                enums::AttemptStatus::Authorized
+            }
         }
+        KlarnaFraudStatus::Pending => enums::AttemptStatus::Authorizing,
     }
 }
 
@@ -231,3 +485,129 @@ pub struct KlarnaErrorResponse {
     pub error_messages: Option<Vec<String>>,
     pub error_message: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_order_status(response: &str) -> enums::AttemptStatus {
+        let response: KlarnaPsyncResponse = serde_json::from_str(response).unwrap();
+        enums::AttemptStatus::from(&response)
+    }
+
+    #[test]
+    fn test_order_status_mapping() {
+        assert_eq!(
+            get_order_status(
+                r#"{"order_id":"o1","status":"AUTHORIZED","order_amount":1000,"captured_amount":0}"#
+            ),
+            enums::AttemptStatus::Authorized
+        );
+        assert_eq!(
+            get_order_status(
+                r#"{"order_id":"o1","status":"CAPTURED","order_amount":1000,"captured_amount":1000}"#
+            ),
+            enums::AttemptStatus::Charged
+        );
+        assert_eq!(
+            get_order_status(r#"{"order_id":"o1","status":"CANCELLED","order_amount":1000}"#),
+            enums::AttemptStatus::Voided
+        );
+    }
+
+    #[test]
+    fn test_closed_order_status_depends_on_captured_amount() {
+        assert_eq!(
+            get_order_status(
+                r#"{"order_id":"o1","status":"CLOSED","order_amount":1000,"captured_amount":0}"#
+            ),
+            enums::AttemptStatus::Voided
+        );
+        assert_eq!(
+            get_order_status(
+                r#"{"order_id":"o1","status":"CLOSED","order_amount":1000,"captured_amount":400}"#
+            ),
+            enums::AttemptStatus::PartialCharged
+        );
+    }
+
+    #[test]
+    fn test_refund_sync_status_mapping() {
+        let pending: KlarnaRefundSyncResponse =
+            serde_json::from_str(r#"{"refund_id":"r1","refunded_amount":500}"#).unwrap();
+        let refunded: KlarnaRefundSyncResponse = serde_json::from_str(
+            r#"{"refund_id":"r1","refunded_amount":500,"refunded_at":"2024-01-01T00:00:00Z"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            enums::RefundStatus::from(&pending),
+            enums::RefundStatus::Pending
+        );
+        assert_eq!(
+            enums::RefundStatus::from(&refunded),
+            enums::RefundStatus::Success
+        );
+    }
+
+    #[test]
+    fn test_refund_status_mapping() {
+        assert_eq!(get_refund_status(201), enums::RefundStatus::Pending);
+        assert_eq!(get_refund_status(200), enums::RefundStatus::Failure);
+    }
+
+    fn capture_request(
+        amount_to_capture: i64,
+        capture_sequence: Option<i16>,
+    ) -> types::PaymentsCaptureData {
+        types::PaymentsCaptureData {
+            amount_to_capture,
+            currency: enums::Currency::EUR,
+            connector_transaction_id: "o1".to_string(),
+            payment_amount: 1000,
+            multiple_capture_data: capture_sequence.map(|capture_sequence| {
+                types::MultipleCaptureRequestData {
+                    capture_sequence,
+                    capture_reference: format!("pay_1_{capture_sequence}"),
+                }
+            }),
+            connector_meta: None,
+            browser_info: None,
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn test_capture_status_mapping() {
+        assert_eq!(
+            get_capture_status(201, &capture_request(1000, None)),
+            enums::AttemptStatus::Charged
+        );
+        assert_eq!(
+            get_capture_status(201, &capture_request(400, None)),
+            enums::AttemptStatus::PartialCharged
+        );
+        assert_eq!(
+            get_capture_status(202, &capture_request(1000, None)),
+            enums::AttemptStatus::CaptureInitiated
+        );
+    }
+
+    #[test]
+    fn test_multiple_partial_capture_status_mapping() {
+        // Every capture of the order is charged, whatever the amount left to capture
+        assert_eq!(
+            get_capture_status(201, &capture_request(400, Some(1))),
+            enums::AttemptStatus::Charged
+        );
+        assert_eq!(
+            get_capture_status(201, &capture_request(600, Some(2))),
+            enums::AttemptStatus::Charged
+        );
+        assert_eq!(
+            get_capture_status(202, &capture_request(600, Some(2))),
+            enums::AttemptStatus::CaptureInitiated
+        );
+    }
+}
@@ -216,9 +216,14 @@ impl TryFrom<&MollieRouterData<&types::PaymentsAuthorizeRouterData>> for MollieP
             description,
             redirect_url,
             cancel_url: None,
-            /* webhook_url is a mandatory field.
-            But we can't support webhook in our core hence keeping it as empty string */
-            webhook_url: "".to_string(),
+            /* webhook_url is a mandatory field,
+            keeping it as empty string when the merchant has no webhook endpoint */
+            webhook_url: item
+                .router_data
+                .request
+                .webhook_url
+                .clone()
+                .unwrap_or_default(),
             locale: None,
             payment_method_data,
             metadata: Some(MollieMetadata {
@@ -627,3 +632,10 @@ pub struct ErrorResponse {
     pub detail: String,
     pub field: Option<String>,
 }
+
+/// Mollie webhooks only carry the id of the payment whose status changed, the status itself has
+/// to be fetched from Mollie.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MollieWebhookBody {
+    pub id: String,
+}
@@ -80,7 +80,7 @@ impl<T>
         ),
     ) -> Result<Self, Self::Error> {
         Ok(Self {
-            amount: utils::to_currency_base_unit_asf64(amount, currency)?,
+            amount: utils::convert_amount(&utils::FloatMajorUnit, amount, currency)?,
             router_data,
         })
     }
@@ -206,7 +206,8 @@ impl
                 Ok(types::PaymentsResponseData::TransactionResponse {
                     resource_id: types::ResponseId::NoResponseId,
                     redirection_data: Some(services::RedirectForm::Nmi {
-                        amount: utils::to_currency_base_unit_asf64(
+                        amount: utils::convert_amount(
+                            &utils::FloatMajorUnit,
                             amount_data,
                             currency_data.to_owned(),
                         )?
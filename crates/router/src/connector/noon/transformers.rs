@@ -378,7 +378,11 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for NoonPaymentsRequest {
                     ))
                     | Some(hyperswitch_domain_models::mandates::MandateDataType::MultiUse(Some(
                         mandate,
-                    ))) => conn_utils::to_currency_base_unit(mandate.amount, mandate.currency),
+                    ))) => conn_utils::convert_amount(
+                        &conn_utils::StringMajorUnit,
+                        mandate.amount,
+                        mandate.currency,
+                    ),
                     Some(hyperswitch_domain_models::mandates::MandateDataType::MultiUse(None)) => {
                         Err(errors::ConnectorError::MissingRequiredField {
                             field_name:
@@ -405,7 +409,11 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for NoonPaymentsRequest {
         let tokenize_c_c = subscription.is_some().then_some(true);
 
         let order = NoonOrder {
-            amount: conn_utils::to_currency_base_unit(item.request.amount, item.request.currency)?,
+            amount: conn_utils::convert_amount(
+                &conn_utils::StringMajorUnit,
+                item.request.amount,
+                item.request.currency,
+            )?,
             currency,
             channel,
             category,
@@ -633,7 +641,8 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for NoonPaymentsActionRequest {
             id: item.request.connector_transaction_id.clone(),
         };
         let transaction = NoonActionTransaction {
-            amount: conn_utils::to_currency_base_unit(
+            amount: conn_utils::convert_amount(
+                &conn_utils::StringMajorUnit,
                 item.request.amount_to_capture,
                 item.request.currency,
             )?,
@@ -694,7 +703,8 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for NoonPaymentsActionRequest {
             id: item.request.connector_transaction_id.clone(),
         };
         let transaction = NoonActionTransaction {
-            amount: conn_utils::to_currency_base_unit(
+            amount: conn_utils::convert_amount(
+                &conn_utils::StringMajorUnit,
                 item.request.refund_amount,
                 item.request.currency,
             )?,
@@ -953,9 +953,19 @@ impl api::IncomingWebhook for Nuvei {
         let body =
             serde_urlencoded::from_str::<nuvei::NuveiWebhookTransactionId>(&request.query_params)
                 .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
-        Ok(api_models::webhooks::ObjectReferenceId::PaymentId(
-            types::api::PaymentIdType::ConnectorTransactionId(body.ppp_transaction_id),
-        ))
+        match body.transaction_type {
+            Some(nuvei::NuveiTransactionType::Credit) => {
+                Ok(api_models::webhooks::ObjectReferenceId::RefundId(
+                    api_models::webhooks::RefundIdType::ConnectorRefundId(
+                        body.transaction_id
+                            .ok_or(errors::ConnectorError::WebhookReferenceIdNotFound)?,
+                    ),
+                ))
+            }
+            _ => Ok(api_models::webhooks::ObjectReferenceId::PaymentId(
+                types::api::PaymentIdType::ConnectorTransactionId(body.ppp_transaction_id),
+            )),
+        }
     }
 
     fn get_webhook_event_type(
@@ -965,16 +975,29 @@ impl api::IncomingWebhook for Nuvei {
         let body =
             serde_urlencoded::from_str::<nuvei::NuveiWebhookDataStatus>(&request.query_params)
                 .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
-        match body.status {
-            nuvei::NuveiWebhookStatus::Approved => {
+        let is_refund = matches!(
+            body.transaction_type,
+            Some(nuvei::NuveiTransactionType::Credit)
+        );
+        match (body.status, is_refund) {
+            (nuvei::NuveiWebhookStatus::Approved, true) => {
+                Ok(api::IncomingWebhookEvent::RefundSuccess)
+            }
+            (nuvei::NuveiWebhookStatus::Declined, true) => {
+                Ok(api::IncomingWebhookEvent::RefundFailure)
+            }
+            (nuvei::NuveiWebhookStatus::Approved, false) => {
                 Ok(api::IncomingWebhookEvent::PaymentIntentSuccess)
             }
-            nuvei::NuveiWebhookStatus::Declined => {
+            (nuvei::NuveiWebhookStatus::Declined, false) => {
                 Ok(api::IncomingWebhookEvent::PaymentIntentFailure)
             }
-            nuvei::NuveiWebhookStatus::Unknown
-            | nuvei::NuveiWebhookStatus::Pending
-            | nuvei::NuveiWebhookStatus::Update => Ok(api::IncomingWebhookEvent::EventNotSupported),
+            (
+                nuvei::NuveiWebhookStatus::Unknown
+                | nuvei::NuveiWebhookStatus::Pending
+                | nuvei::NuveiWebhookStatus::Update,
+                _,
+            ) => Ok(api::IncomingWebhookEvent::EventNotSupported),
         }
     }
 
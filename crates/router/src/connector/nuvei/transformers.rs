@@ -868,7 +868,11 @@ impl<F>
             }
         }?;
         let request = Self::try_from(NuveiPaymentRequestData {
-            amount: utils::to_currency_base_unit(item.request.amount, item.request.currency)?,
+            amount: utils::convert_amount(
+                &utils::StringMajorUnit,
+                item.request.amount,
+                item.request.currency,
+            )?,
             currency: item.request.currency,
             connector_auth_type: item.connector_auth_type.clone(),
             client_request_id: item.connector_request_reference_id.clone(),
@@ -1062,7 +1066,11 @@ impl TryFrom<(&types::PaymentsCompleteAuthorizeRouterData, Secret<String>)>
             )),
         }?;
         let request = Self::try_from(NuveiPaymentRequestData {
-            amount: utils::to_currency_base_unit(item.request.amount, item.request.currency)?,
+            amount: utils::convert_amount(
+                &utils::StringMajorUnit,
+                item.request.amount,
+                item.request.currency,
+            )?,
             currency: item.request.currency,
             connector_auth_type: item.connector_auth_type.clone(),
             client_request_id: item.connector_request_reference_id.clone(),
@@ -1169,7 +1177,8 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for NuveiPaymentFlowRequest {
         Self::try_from(NuveiPaymentRequestData {
             client_request_id: item.connector_request_reference_id.clone(),
             connector_auth_type: item.connector_auth_type.clone(),
-            amount: utils::to_currency_base_unit(
+            amount: utils::convert_amount(
+                &utils::StringMajorUnit,
                 item.request.amount_to_capture,
                 item.request.currency,
             )?,
@@ -1185,7 +1194,8 @@ impl TryFrom<&types::RefundExecuteRouterData> for NuveiPaymentFlowRequest {
         Self::try_from(NuveiPaymentRequestData {
             client_request_id: item.connector_request_reference_id.clone(),
             connector_auth_type: item.connector_auth_type.clone(),
-            amount: utils::to_currency_base_unit(
+            amount: utils::convert_amount(
+                &utils::StringMajorUnit,
                 item.request.refund_amount,
                 item.request.currency,
             )?,
@@ -1212,7 +1222,8 @@ impl TryFrom<&types::PaymentsCancelRouterData> for NuveiPaymentFlowRequest {
         Self::try_from(NuveiPaymentRequestData {
             client_request_id: item.connector_request_reference_id.clone(),
             connector_auth_type: item.connector_auth_type.clone(),
-            amount: utils::to_currency_base_unit(
+            amount: utils::convert_amount(
+                &utils::StringMajorUnit,
                 item.request.get_amount()?,
                 item.request.get_currency()?,
             )?,
@@ -1323,6 +1334,8 @@ pub enum NuveiTransactionType {
     InitAuth3D,
     Settle,
     Void,
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1679,12 +1692,18 @@ pub struct NuveiWebhookDetails {
 pub struct NuveiWebhookTransactionId {
     #[serde(rename = "ppp_TransactionID")]
     pub ppp_transaction_id: String,
+    #[serde(rename = "TransactionId")]
+    pub transaction_id: Option<String>,
+    #[serde(rename = "transactionType")]
+    pub transaction_type: Option<NuveiTransactionType>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct NuveiWebhookDataStatus {
     #[serde(rename = "Status")]
     pub status: NuveiWebhookStatus,
+    #[serde(rename = "transactionType")]
+    pub transaction_type: Option<NuveiTransactionType>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -691,7 +691,7 @@ impl TryFrom<&domain::BankTransferData> for PaypalPaymentsRequest {
             | domain::BankTransferData::CimbVaBankTransfer { .. }
             | domain::BankTransferData::DanamonVaBankTransfer { .. }
             | domain::BankTransferData::MandiriVaBankTransfer { .. }
-            | domain::BankTransferData::Pix {}
+            | domain::BankTransferData::Pix { .. }
             | domain::BankTransferData::Pse {}
             | domain::BankTransferData::LocalBankTransfer { .. } => {
                 Err(errors::ConnectorError::NotImplemented(
@@ -2137,6 +2137,10 @@ impl ForeignFrom<(PaypalWebhookEventType, Option<OutcomeCode>)> for api::Incomin
             PaypalWebhookEventType::PaymentCapturePending
             | PaypalWebhookEventType::CheckoutOrderProcessed => Self::PaymentIntentProcessing,
             PaypalWebhookEventType::PaymentCaptureDeclined => Self::PaymentIntentFailure,
+            PaypalWebhookEventType::PaymentAuthorizationCreated => {
+                Self::PaymentIntentAuthorizationSuccess
+            }
+            PaypalWebhookEventType::PaymentAuthorizationVoided => Self::PaymentIntentCancelled,
             PaypalWebhookEventType::PaymentCaptureRefunded => Self::RefundSuccess,
             PaypalWebhookEventType::CustomerDisputeCreated => Self::DisputeOpened,
             PaypalWebhookEventType::RiskDisputeCreated => Self::DisputeAccepted,
@@ -2147,9 +2151,7 @@ impl ForeignFrom<(PaypalWebhookEventType, Option<OutcomeCode>)> for api::Incomin
                     Self::EventNotSupported
                 }
             }
-            PaypalWebhookEventType::PaymentAuthorizationCreated
-            | PaypalWebhookEventType::PaymentAuthorizationVoided
-            | PaypalWebhookEventType::CheckoutOrderApproved
+            PaypalWebhookEventType::CheckoutOrderApproved
             | PaypalWebhookEventType::CustomerDisputedUpdated
             | PaypalWebhookEventType::Unknown => Self::EventNotSupported,
         }
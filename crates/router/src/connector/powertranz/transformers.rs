@@ -133,7 +133,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PowertranzPaymentsRequest
         };
         Ok(Self {
             transaction_identifier: Uuid::new_v4().to_string(),
-            total_amount: utils::to_currency_base_unit_asf64(
+            total_amount: utils::convert_amount(
+                &utils::FloatMajorUnit,
                 item.request.amount,
                 item.request.currency,
             )?,
@@ -379,7 +380,8 @@ impl TryFrom<&types::PaymentsCancelData> for PowertranzBaseRequest {
 impl TryFrom<&types::PaymentsCaptureData> for PowertranzBaseRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsCaptureData) -> Result<Self, Self::Error> {
-        let total_amount = Some(utils::to_currency_base_unit_asf64(
+        let total_amount = Some(utils::convert_amount(
+            &utils::FloatMajorUnit,
             item.amount_to_capture,
             item.currency,
         )?);
@@ -394,7 +396,8 @@ impl TryFrom<&types::PaymentsCaptureData> for PowertranzBaseRequest {
 impl<F> TryFrom<&types::RefundsRouterData<F>> for PowertranzBaseRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
-        let total_amount = Some(utils::to_currency_base_unit_asf64(
+        let total_amount = Some(utils::convert_amount(
+            &utils::FloatMajorUnit,
             item.request.refund_amount,
             item.request.currency,
         )?);
@@ -4,7 +4,7 @@ use time::PrimitiveDateTime;
 use url::Url;
 
 use crate::{
-    connector::utils::{PaymentsAuthorizeRequestData, RouterData},
+    connector::utils::{self, CardData, PaymentsAuthorizeRequestData, RouterData},
     consts,
     core::errors,
     pii::Secret,
@@ -124,24 +124,25 @@ impl TryFrom<&RapydRouterData<&types::PaymentsAuthorizeRouterData>> for RapydPay
             _ => (None, None),
         };
         let payment_method = match item.router_data.request.payment_method_data {
-            domain::PaymentMethodData::Card(ref ccard) => {
-                Some(PaymentMethod {
-                    pm_type: "in_amex_card".to_owned(), //[#369] Map payment method type based on country
-                    fields: Some(PaymentFields {
-                        number: ccard.card_number.to_owned(),
-                        expiration_month: ccard.card_exp_month.to_owned(),
-                        expiration_year: ccard.card_exp_year.to_owned(),
-                        name: item
-                            .router_data
-                            .get_optional_billing_full_name()
-                            .to_owned()
-                            .unwrap_or(Secret::new("".to_string())),
-                        cvv: ccard.card_cvc.to_owned(),
-                    }),
-                    address: None,
-                    digital_wallet: None,
-                })
-            }
+            domain::PaymentMethodData::Card(ref ccard) => Some(PaymentMethod {
+                pm_type: get_card_payment_method_type(
+                    item.router_data.get_billing_country()?,
+                    ccard.get_card_issuer().ok(),
+                )?,
+                fields: Some(PaymentFields {
+                    number: ccard.card_number.to_owned(),
+                    expiration_month: ccard.card_exp_month.to_owned(),
+                    expiration_year: ccard.card_exp_year.to_owned(),
+                    name: item
+                        .router_data
+                        .get_optional_billing_full_name()
+                        .to_owned()
+                        .unwrap_or(Secret::new("".to_string())),
+                    cvv: ccard.card_cvc.to_owned(),
+                }),
+                address: None,
+                digital_wallet: None,
+            }),
             domain::PaymentMethodData::Wallet(ref wallet_data) => {
                 let digital_wallet = match wallet_data {
                     domain::WalletData::GooglePay(data) => Some(RapydWallet {
@@ -181,6 +182,33 @@ impl TryFrom<&RapydRouterData<&types::PaymentsAuthorizeRouterData>> for RapydPay
     }
 }
 
+/// Rapyd identifies card payment methods per country and network, e.g. `us_visa_card`
+fn get_card_payment_method_type(
+    country: enums::CountryAlpha2,
+    card_issuer: Option<utils::CardIssuer>,
+) -> Result<String, error_stack::Report<errors::ConnectorError>> {
+    let network = match card_issuer {
+        Some(utils::CardIssuer::Visa) => "visa",
+        Some(utils::CardIssuer::Master) => "mastercard",
+        Some(utils::CardIssuer::AmericanExpress) => "amex",
+        Some(utils::CardIssuer::Discover) => "discover",
+        Some(utils::CardIssuer::DinersClub) => "diners",
+        Some(utils::CardIssuer::JCB) => "jcb",
+        Some(utils::CardIssuer::Maestro) => "maestro",
+        Some(utils::CardIssuer::CarteBlanche) | None => {
+            Err(errors::ConnectorError::NotSupported {
+                message: "Card network".to_string(),
+                connector: "rapyd",
+            })?
+        }
+    };
+    Ok(format!(
+        "{}_{}_card",
+        country.to_string().to_lowercase(),
+        network
+    ))
+}
+
 #[derive(Debug, Deserialize)]
 pub struct RapydAuthType {
     pub access_key: Secret<String>,
@@ -607,3 +635,46 @@ impl From<RefundResponseData> for RefundResponse {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_card_payment_method_type() {
+        assert_eq!(
+            get_card_payment_method_type(enums::CountryAlpha2::US, Some(utils::CardIssuer::Visa))
+                .unwrap(),
+            "us_visa_card"
+        );
+        assert_eq!(
+            get_card_payment_method_type(enums::CountryAlpha2::GB, Some(utils::CardIssuer::Master))
+                .unwrap(),
+            "gb_mastercard_card"
+        );
+        assert_eq!(
+            get_card_payment_method_type(
+                enums::CountryAlpha2::IN,
+                Some(utils::CardIssuer::AmericanExpress)
+            )
+            .unwrap(),
+            "in_amex_card"
+        );
+    }
+
+    #[test]
+    fn test_unknown_card_network_not_supported() {
+        for card_issuer in [None, Some(utils::CardIssuer::CarteBlanche)] {
+            let error =
+                get_card_payment_method_type(enums::CountryAlpha2::US, card_issuer).unwrap_err();
+            assert!(matches!(
+                error.current_context(),
+                errors::ConnectorError::NotSupported {
+                    connector: "rapyd",
+                    ..
+                }
+            ));
+        }
+    }
+}
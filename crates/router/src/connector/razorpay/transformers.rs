@@ -0,0 +1,677 @@
+use api_models::payments;
+use common_utils::{ext_traits::Encode, pii::Email};
+use error_stack::ResultExt;
+use masking::{Secret, SwitchStrategy};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    connector::utils::{
+        self, CardData, PaymentsAuthorizeRequestData, PaymentsPreProcessingData, RouterData,
+    },
+    consts,
+    core::errors,
+    services,
+    types::{self, api, domain, storage::enums},
+    utils as crate_utils,
+};
+
+pub struct RazorpayRouterData<T> {
+    pub amount: i64,
+    pub router_data: T,
+}
+
+impl<T>
+    TryFrom<(
+        &types::api::CurrencyUnit,
+        types::storage::enums::Currency,
+        i64,
+        T,
+    )> for RazorpayRouterData<T>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (_currency_unit, _currency, amount, item): (
+            &types::api::CurrencyUnit,
+            types::storage::enums::Currency,
+            i64,
+            T,
+        ),
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount,
+            router_data: item,
+        })
+    }
+}
+
+// Auth Struct
+pub struct RazorpayAuthType {
+    pub(super) key_id: Secret<String>,
+    pub(super) key_secret: Secret<String>,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for RazorpayAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::BodyKey { api_key, key1 } => Ok(Self {
+                key_id: api_key.to_owned(),
+                key_secret: key1.to_owned(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+// Every payment on Razorpay has to be made against an order, the order is created in the
+// pre-processing step and its id is carried over to the payment request
+#[derive(Debug, Serialize)]
+pub struct RazorpayOrderRequest {
+    amount: i64,
+    currency: enums::Currency,
+    receipt: String,
+    payment_capture: bool,
+}
+
+impl TryFrom<&RazorpayRouterData<&types::PaymentsPreProcessingRouterData>>
+    for RazorpayOrderRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &RazorpayRouterData<&types::PaymentsPreProcessingRouterData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.amount,
+            currency: item.router_data.request.get_currency()?,
+            receipt: item.router_data.connector_request_reference_id.clone(),
+            payment_capture: item.router_data.request.is_auto_capture()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RazorpayOrderResponse {
+    id: String,
+}
+
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            RazorpayOrderResponse,
+            types::PaymentsPreProcessingData,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, types::PaymentsPreProcessingData, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            RazorpayOrderResponse,
+            types::PaymentsPreProcessingData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: enums::AttemptStatus::Pending,
+            preprocessing_id: Some(item.response.id.clone()),
+            response: Ok(types::PaymentsResponseData::PreProcessingResponse {
+                pre_processing_id: types::PreprocessingResponseId::PreProcessingId(
+                    item.response.id,
+                ),
+                connector_metadata: None,
+                session_token: None,
+                connector_response_reference_id: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RazorpayPaymentMethod {
+    Card,
+    Upi,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RazorpayCard {
+    number: cards::CardNumber,
+    name: Option<Secret<String>>,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    cvv: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RazorpayUpiFlow {
+    Collect,
+    Intent,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RazorpayUpi {
+    flow: RazorpayUpiFlow,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vpa: Option<Secret<String>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RazorpayPaymentsRequest {
+    amount: i64,
+    currency: enums::Currency,
+    order_id: String,
+    email: Email,
+    contact: Secret<String>,
+    method: RazorpayPaymentMethod,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    card: Option<RazorpayCard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    upi: Option<RazorpayUpi>,
+    callback_url: String,
+    ip: Option<Secret<String>>,
+    user_agent: Option<String>,
+}
+
+impl TryFrom<&RazorpayRouterData<&types::PaymentsAuthorizeRouterData>> for RazorpayPaymentsRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &RazorpayRouterData<&types::PaymentsAuthorizeRouterData>,
+    ) -> Result<Self, Self::Error> {
+        let (method, card, upi) = match item.router_data.request.payment_method_data.clone() {
+            domain::PaymentMethodData::Card(req_card) => (
+                RazorpayPaymentMethod::Card,
+                Some(RazorpayCard {
+                    number: req_card.card_number.clone(),
+                    name: item.router_data.get_optional_billing_full_name(),
+                    expiry_month: req_card.card_exp_month.clone(),
+                    expiry_year: req_card.get_expiry_year_4_digit(),
+                    cvv: req_card.card_cvc,
+                }),
+                None,
+            ),
+            domain::PaymentMethodData::Upi(upi_data) => {
+                // A payment without a VPA is raised as an intent to be completed in a UPI app
+                let upi = match upi_data.vpa_id {
+                    Some(vpa) => RazorpayUpi {
+                        flow: RazorpayUpiFlow::Collect,
+                        vpa: Some(vpa.switch_strategy()),
+                    },
+                    None => RazorpayUpi {
+                        flow: RazorpayUpiFlow::Intent,
+                        vpa: None,
+                    },
+                };
+                (RazorpayPaymentMethod::Upi, None, Some(upi))
+            }
+            domain::PaymentMethodData::CardRedirect(_)
+            | domain::PaymentMethodData::Wallet(_)
+            | domain::PaymentMethodData::PayLater(_)
+            | domain::PaymentMethodData::BankRedirect(_)
+            | domain::PaymentMethodData::BankDebit(_)
+            | domain::PaymentMethodData::BankTransfer(_)
+            | domain::PaymentMethodData::Crypto(_)
+            | domain::PaymentMethodData::MandatePayment
+            | domain::PaymentMethodData::Reward
+            | domain::PaymentMethodData::Voucher(_)
+            | domain::PaymentMethodData::GiftCard(_)
+            | domain::PaymentMethodData::CardToken(_) => {
+                Err(errors::ConnectorError::NotImplemented(
+                    utils::get_unimplemented_payment_method_error_message("razorpay"),
+                ))?
+            }
+        };
+        let browser_info = item.router_data.request.browser_info.clone();
+        Ok(Self {
+            amount: item.amount,
+            currency: item.router_data.request.currency,
+            order_id: item.router_data.get_preprocessing_id()?,
+            email: item.router_data.request.get_email()?,
+            contact: item.router_data.get_billing_phone_number()?,
+            method,
+            card,
+            upi,
+            callback_url: item.router_data.request.get_router_return_url()?,
+            ip: browser_info
+                .as_ref()
+                .and_then(|info| info.ip_address)
+                .map(|ip| Secret::new(ip.to_string())),
+            user_agent: browser_info.and_then(|info| info.user_agent),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RazorpayNextActionType {
+    Redirect,
+    Intent,
+    Poll,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RazorpayNextAction {
+    action: RazorpayNextActionType,
+    url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RazorpayAuthorizeResponse {
+    razorpay_payment_id: String,
+    next: Option<Vec<RazorpayNextAction>>,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, RazorpayAuthorizeResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            RazorpayAuthorizeResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        // The customer either has to be redirected (card 3DS), has to open the UPI intent in
+        // their UPI app or has to approve the collect request in their UPI app, the outcome is
+        // known only on sync or webhook
+        let (redirection_data, connector_metadata) =
+            get_next_action_data(item.response.next.unwrap_or_default())?;
+        Ok(Self {
+            status: enums::AttemptStatus::AuthenticationPending,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.response.razorpay_payment_id.clone(),
+                ),
+                redirection_data,
+                mandate_reference: None,
+                connector_metadata,
+                network_txn_id: None,
+                connector_response_reference_id: Some(item.response.razorpay_payment_id),
+                incremental_authorization_allowed: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+fn get_next_action_data(
+    next_actions: Vec<RazorpayNextAction>,
+) -> errors::CustomResult<
+    (Option<services::RedirectForm>, Option<serde_json::Value>),
+    errors::ConnectorError,
+> {
+    for next_action in next_actions {
+        match (next_action.action, next_action.url) {
+            (RazorpayNextActionType::Redirect, Some(url)) => {
+                return Ok((
+                    Some(services::RedirectForm::from((url, services::Method::Get))),
+                    None,
+                ))
+            }
+            (RazorpayNextActionType::Intent, Some(url)) => {
+                return Ok((None, Some(get_upi_intent_qr_metadata(&url)?)))
+            }
+            _ => {}
+        }
+    }
+    Ok((None, None))
+}
+
+/// The UPI intent is a `upi://` link which browsers can't follow, it is returned as a QR code to
+/// be scanned with a UPI app, along with the link itself for the SDK to open the UPI app with
+fn get_upi_intent_qr_metadata(
+    intent_url: &str,
+) -> errors::CustomResult<serde_json::Value, errors::ConnectorError> {
+    let qr_code_url = url::Url::parse(intent_url)
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+        .attach_printable("Invalid UPI intent url")?;
+    let image_data = crate_utils::QrImage::new_from_data(intent_url.to_string())
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+    let image_data_url = url::Url::parse(&image_data.data)
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+
+    payments::QrCodeInformation::QrCodeUrl {
+        image_data_url,
+        qr_code_url,
+        display_to_timestamp: None,
+    }
+    .encode_to_value()
+    .change_context(errors::ConnectorError::ResponseHandlingFailed)
+}
+
+#[derive(Debug, Serialize)]
+pub struct RazorpayCaptureRequest {
+    amount: i64,
+    currency: enums::Currency,
+}
+
+impl TryFrom<&RazorpayRouterData<&types::PaymentsCaptureRouterData>> for RazorpayCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &RazorpayRouterData<&types::PaymentsCaptureRouterData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.amount,
+            currency: item.router_data.request.currency,
+        })
+    }
+}
+
+// PaymentsResponse
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RazorpayPaymentStatus {
+    Created,
+    Authorized,
+    Captured,
+    Refunded,
+    Failed,
+}
+
+impl From<RazorpayPaymentStatus> for enums::AttemptStatus {
+    fn from(item: RazorpayPaymentStatus) -> Self {
+        match item {
+            RazorpayPaymentStatus::Created => Self::AuthenticationPending,
+            RazorpayPaymentStatus::Authorized => Self::Authorized,
+            // A refunded payment has been captured before being refunded
+            RazorpayPaymentStatus::Captured | RazorpayPaymentStatus::Refunded => Self::Charged,
+            RazorpayPaymentStatus::Failed => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RazorpayPaymentsResponse {
+    pub id: String,
+    pub status: RazorpayPaymentStatus,
+    pub order_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_description: Option<String>,
+    pub error_reason: Option<String>,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, RazorpayPaymentsResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            RazorpayPaymentsResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let status = enums::AttemptStatus::from(item.response.status.clone());
+        let response = if status == enums::AttemptStatus::Failure {
+            Err(types::ErrorResponse {
+                status_code: item.http_code,
+                code: item
+                    .response
+                    .error_code
+                    .unwrap_or(consts::NO_ERROR_CODE.to_string()),
+                message: item
+                    .response
+                    .error_description
+                    .clone()
+                    .unwrap_or(consts::NO_ERROR_MESSAGE.to_string()),
+                reason: item
+                    .response
+                    .error_reason
+                    .or(item.response.error_description),
+                attempt_status: None,
+                connector_transaction_id: Some(item.response.id),
+            })
+        } else {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id.clone()),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: Some(item.response.id),
+                incremental_authorization_allowed: None,
+            })
+        };
+        Ok(Self {
+            status,
+            response,
+            ..item.data
+        })
+    }
+}
+
+// REFUND :
+// Type definition for RefundRequest
+#[derive(Debug, Serialize)]
+pub struct RazorpayRefundRequest {
+    amount: i64,
+    receipt: String,
+}
+
+impl<F> TryFrom<&RazorpayRouterData<&types::RefundsRouterData<F>>> for RazorpayRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &RazorpayRouterData<&types::RefundsRouterData<F>>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.amount,
+            receipt: item.router_data.request.refund_id.clone(),
+        })
+    }
+}
+
+// Type definition for Refund Response
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum RefundStatus {
+    Pending,
+    Processed,
+    Failed,
+}
+
+impl From<RefundStatus> for enums::RefundStatus {
+    fn from(item: RefundStatus) -> Self {
+        match item {
+            RefundStatus::Processed => Self::Success,
+            RefundStatus::Failed => Self::Failure,
+            RefundStatus::Pending => Self::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefundResponse {
+    id: String,
+    status: RefundStatus,
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, RefundResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, RefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.id,
+                refund_status: enums::RefundStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RazorpayErrorDetails {
+    pub code: String,
+    pub description: String,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RazorpayErrorResponse {
+    pub error: RazorpayErrorDetails,
+}
+
+// WEBHOOKS :
+#[derive(Debug, Deserialize)]
+pub enum RazorpayWebhookEventType {
+    #[serde(rename = "payment.authorized")]
+    PaymentAuthorized,
+    #[serde(rename = "payment.captured")]
+    PaymentCaptured,
+    #[serde(rename = "payment.failed")]
+    PaymentFailed,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayWebhookEvent {
+    pub event: RazorpayWebhookEventType,
+}
+
+impl From<RazorpayWebhookEventType> for api::IncomingWebhookEvent {
+    fn from(event: RazorpayWebhookEventType) -> Self {
+        match event {
+            RazorpayWebhookEventType::PaymentAuthorized => Self::PaymentIntentAuthorizationSuccess,
+            RazorpayWebhookEventType::PaymentCaptured => Self::PaymentIntentSuccess,
+            RazorpayWebhookEventType::PaymentFailed => Self::PaymentIntentFailure,
+            RazorpayWebhookEventType::Unknown => Self::EventNotSupported,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayWebhookPaymentEntity {
+    pub entity: RazorpayPaymentsResponse,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayWebhookPayload {
+    pub payment: RazorpayWebhookPaymentEntity,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayPaymentWebhook {
+    pub payload: RazorpayWebhookPayload,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_payment_status_mapping() {
+        let status = |status: &str| {
+            let response: RazorpayPaymentsResponse = serde_json::from_value(serde_json::json!({
+                "id": "pay_1",
+                "status": status,
+            }))
+            .unwrap();
+            enums::AttemptStatus::from(response.status)
+        };
+
+        assert_eq!(
+            status("created"),
+            enums::AttemptStatus::AuthenticationPending
+        );
+        assert_eq!(status("authorized"), enums::AttemptStatus::Authorized);
+        assert_eq!(status("captured"), enums::AttemptStatus::Charged);
+        assert_eq!(status("refunded"), enums::AttemptStatus::Charged);
+        assert_eq!(status("failed"), enums::AttemptStatus::Failure);
+    }
+
+    #[test]
+    fn test_refund_status_mapping() {
+        assert_eq!(
+            enums::RefundStatus::from(RefundStatus::Pending),
+            enums::RefundStatus::Pending
+        );
+        assert_eq!(
+            enums::RefundStatus::from(RefundStatus::Processed),
+            enums::RefundStatus::Success
+        );
+        assert_eq!(
+            enums::RefundStatus::from(RefundStatus::Failed),
+            enums::RefundStatus::Failure
+        );
+    }
+
+    #[test]
+    fn test_upi_intent_returned_as_qr_code() {
+        let response: RazorpayAuthorizeResponse = serde_json::from_str(
+            r#"{"razorpay_payment_id":"pay_1","next":[{"action":"intent","url":"upi://pay?pa=merchant@razorpay&am=100.00&cu=INR&tr=pay_1"},{"action":"poll","url":"https://api.razorpay.com/v1/payments/pay_1"}]}"#,
+        )
+        .unwrap();
+
+        let (redirection_data, connector_metadata) =
+            get_next_action_data(response.next.unwrap()).unwrap();
+        let connector_metadata = connector_metadata.unwrap();
+
+        assert!(redirection_data.is_none());
+        assert_eq!(
+            connector_metadata["qr_code_url"],
+            "upi://pay?pa=merchant@razorpay&am=100.00&cu=INR&tr=pay_1"
+        );
+        assert!(connector_metadata["image_data_url"]
+            .as_str()
+            .unwrap()
+            .starts_with("data:image/png;base64,"));
+        // The metadata is read back as the QR code next action of the payment
+        assert!(serde_json::from_value::<payments::QrCodeInformation>(connector_metadata).is_ok());
+    }
+
+    #[test]
+    fn test_card_redirect_returned_as_redirect_form() {
+        let response: RazorpayAuthorizeResponse = serde_json::from_str(
+            r#"{"razorpay_payment_id":"pay_1","next":[{"action":"redirect","url":"https://api.razorpay.com/v1/payments/pay_1/authenticate"}]}"#,
+        )
+        .unwrap();
+
+        let (redirection_data, connector_metadata) =
+            get_next_action_data(response.next.unwrap()).unwrap();
+
+        assert!(redirection_data.is_some());
+        assert!(connector_metadata.is_none());
+        assert_eq!(get_next_action_data(Vec::new()).unwrap(), (None, None));
+    }
+
+    #[test]
+    fn test_webhook_event_and_payment() {
+        let body = r#"{"entity":"event","event":"payment.captured","payload":{"payment":{"entity":{"id":"pay_1","status":"captured","order_id":"order_1"}}}}"#;
+        let event: RazorpayWebhookEvent = serde_json::from_str(body).unwrap();
+        let webhook: RazorpayPaymentWebhook = serde_json::from_str(body).unwrap();
+
+        assert_eq!(
+            api::IncomingWebhookEvent::from(event.event),
+            api::IncomingWebhookEvent::PaymentIntentSuccess
+        );
+        assert_eq!(webhook.payload.payment.entity.id, "pay_1");
+
+        let event: RazorpayWebhookEvent =
+            serde_json::from_str(r#"{"event":"payment.failed"}"#).unwrap();
+        assert_eq!(
+            api::IncomingWebhookEvent::from(event.event),
+            api::IncomingWebhookEvent::PaymentIntentFailure
+        );
+        let event: RazorpayWebhookEvent =
+            serde_json::from_str(r#"{"event":"order.paid"}"#).unwrap();
+        assert_eq!(
+            api::IncomingWebhookEvent::from(event.event),
+            api::IncomingWebhookEvent::EventNotSupported
+        );
+    }
+}
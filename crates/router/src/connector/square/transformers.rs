@@ -515,9 +515,8 @@ impl From<SquareWebhookObject> for api::IncomingWebhookEvent {
                 SquarePaymentStatus::Completed => Self::PaymentIntentSuccess,
                 SquarePaymentStatus::Failed => Self::PaymentIntentFailure,
                 SquarePaymentStatus::Pending => Self::PaymentIntentProcessing,
-                SquarePaymentStatus::Approved | SquarePaymentStatus::Canceled => {
-                    Self::EventNotSupported
-                }
+                SquarePaymentStatus::Approved => Self::PaymentIntentAuthorizationSuccess,
+                SquarePaymentStatus::Canceled => Self::PaymentIntentCancelled,
             },
             SquareWebhookObject::Refund(refund_data) => match refund_data.status {
                 RefundStatus::Completed => Self::RefundSuccess,
@@ -430,7 +430,8 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
     fn try_from(
         item: types::RefundsResponseRouterData<api::Execute, RefundResponse>,
     ) -> Result<Self, Self::Error> {
-        let refund_amount = utils::to_currency_base_unit_asf64(
+        let refund_amount = utils::convert_amount(
+            &utils::FloatMajorUnit,
             item.data.request.refund_amount,
             item.data.request.currency,
         )
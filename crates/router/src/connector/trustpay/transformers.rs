@@ -87,6 +87,7 @@ pub enum TrustpayPaymentMethod {
     IDeal,
     Sofort,
     Blik,
+    Trustly,
 }
 
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -237,6 +238,7 @@ impl TryFrom<&domain::BankRedirectData> for TrustpayPaymentMethod {
             domain::BankRedirectData::Ideal { .. } => Ok(Self::IDeal),
             domain::BankRedirectData::Sofort { .. } => Ok(Self::Sofort),
             domain::BankRedirectData::Blik { .. } => Ok(Self::Blik),
+            domain::BankRedirectData::Trustly { .. } => Ok(Self::Trustly),
             domain::BankRedirectData::BancontactCard { .. }
             | domain::BankRedirectData::Bizum {}
             | domain::BankRedirectData::Interac { .. }
@@ -246,7 +248,6 @@ impl TryFrom<&domain::BankRedirectData> for TrustpayPaymentMethod {
             | domain::BankRedirectData::OnlineBankingSlovakia { .. }
             | domain::BankRedirectData::OpenBankingUk { .. }
             | domain::BankRedirectData::Przelewy24 { .. }
-            | domain::BankRedirectData::Trustly { .. }
             | domain::BankRedirectData::OnlineBankingFpx { .. }
             | domain::BankRedirectData::OnlineBankingThailand { .. } => {
                 Err(errors::ConnectorError::NotImplemented(
@@ -351,7 +352,8 @@ fn get_debtor_info(
         TrustpayPaymentMethod::Eps
         | TrustpayPaymentMethod::Giropay
         | TrustpayPaymentMethod::IDeal
-        | TrustpayPaymentMethod::Sofort => None,
+        | TrustpayPaymentMethod::Sofort
+        | TrustpayPaymentMethod::Trustly => None,
     })
 }
 
@@ -1574,7 +1574,7 @@ pub fn to_currency_base_unit_from_optional_amount(
     currency: diesel_models::enums::Currency,
 ) -> Result<String, error_stack::Report<errors::ConnectorError>> {
     match amount {
-        Some(a) => to_currency_base_unit(a, currency),
+        Some(a) => convert_amount(&StringMajorUnit, a, currency),
         _ => Err(errors::ConnectorError::MissingRequiredField {
             field_name: "amount",
         }
@@ -1582,16 +1582,159 @@ pub fn to_currency_base_unit_from_optional_amount(
     }
 }
 
+/// Converts amounts between the minor unit used internally and the representation expected by a
+/// connector, taking the number of decimal places of the currency into account.
+pub trait AmountConvertor: Send {
+    type Output;
+
+    fn convert(
+        &self,
+        amount: i64,
+        currency: enums::Currency,
+    ) -> Result<Self::Output, error_stack::Report<errors::ConnectorError>>;
+
+    fn convert_back(
+        &self,
+        amount: Self::Output,
+        currency: enums::Currency,
+    ) -> Result<i64, error_stack::Report<errors::ConnectorError>>;
+}
+
+/// Amount in the minor unit of the currency, e.g. `1050` for 10.50 USD
+#[derive(Debug, Clone, Copy)]
+pub struct MinorUnit;
+
+/// Amount in the minor unit of the currency as a string, e.g. `"1050"` for 10.50 USD
+#[derive(Debug, Clone, Copy)]
+pub struct StringMinorUnit;
+
+/// Amount in the base unit of the currency as a float, e.g. `10.5` for 10.50 USD
+#[derive(Debug, Clone, Copy)]
+pub struct FloatMajorUnit;
+
+/// Amount in the base unit of the currency as a string with as many decimal places as the
+/// currency has, e.g. `"10.50"` for 10.50 USD, `"1050"` for 1050 JPY and `"1.050"` for 1.050 KWD
+#[derive(Debug, Clone, Copy)]
+pub struct StringMajorUnit;
+
+fn to_major_unit_decimal(amount: i64, currency: enums::Currency) -> rust_decimal::Decimal {
+    rust_decimal::Decimal::new(
+        amount,
+        u32::from(currency.number_of_digits_after_decimal_point()),
+    )
+}
+
+fn from_major_unit_decimal(
+    amount: rust_decimal::Decimal,
+    currency: enums::Currency,
+) -> Result<i64, error_stack::Report<errors::ConnectorError>> {
+    use rust_decimal::prelude::ToPrimitive;
+
+    let scale = u32::from(currency.number_of_digits_after_decimal_point());
+    let minor_unit_amount = amount
+        .checked_mul(rust_decimal::Decimal::from(10_i64.pow(scale)))
+        .ok_or(errors::ConnectorError::ParsingFailed)?;
+    if !minor_unit_amount.fract().is_zero() {
+        return Err(report!(errors::ConnectorError::ParsingFailed)
+            .attach_printable("Amount has more decimal places than supported by the currency"));
+    }
+    minor_unit_amount
+        .to_i64()
+        .ok_or(errors::ConnectorError::ParsingFailed.into())
+}
+
+impl AmountConvertor for MinorUnit {
+    type Output = i64;
+
+    fn convert(&self, amount: i64, _currency: enums::Currency) -> Result<i64, Error> {
+        Ok(amount)
+    }
+
+    fn convert_back(&self, amount: i64, _currency: enums::Currency) -> Result<i64, Error> {
+        Ok(amount)
+    }
+}
+
+impl AmountConvertor for StringMinorUnit {
+    type Output = String;
+
+    fn convert(&self, amount: i64, _currency: enums::Currency) -> Result<String, Error> {
+        Ok(amount.to_string())
+    }
+
+    fn convert_back(&self, amount: String, _currency: enums::Currency) -> Result<i64, Error> {
+        amount
+            .parse::<i64>()
+            .change_context(errors::ConnectorError::ParsingFailed)
+    }
+}
+
+impl AmountConvertor for FloatMajorUnit {
+    type Output = f64;
+
+    fn convert(&self, amount: i64, currency: enums::Currency) -> Result<f64, Error> {
+        use rust_decimal::prelude::ToPrimitive;
+
+        to_major_unit_decimal(amount, currency)
+            .to_f64()
+            .ok_or(errors::ConnectorError::ParsingFailed.into())
+    }
+
+    fn convert_back(&self, amount: f64, currency: enums::Currency) -> Result<i64, Error> {
+        use rust_decimal::prelude::FromPrimitive;
+
+        let amount = rust_decimal::Decimal::from_f64(amount)
+            .ok_or(errors::ConnectorError::ParsingFailed)?
+            .round_dp(u32::from(currency.number_of_digits_after_decimal_point()));
+        from_major_unit_decimal(amount, currency)
+    }
+}
+
+impl AmountConvertor for StringMajorUnit {
+    type Output = String;
+
+    fn convert(&self, amount: i64, currency: enums::Currency) -> Result<String, Error> {
+        let decimal_places = usize::from(currency.number_of_digits_after_decimal_point());
+        Ok(format!(
+            "{:.decimal_places$}",
+            to_major_unit_decimal(amount, currency)
+        ))
+    }
+
+    fn convert_back(&self, amount: String, currency: enums::Currency) -> Result<i64, Error> {
+        let amount = amount
+            .trim()
+            .parse::<rust_decimal::Decimal>()
+            .change_context(errors::ConnectorError::ParsingFailed)?;
+        from_major_unit_decimal(amount, currency)
+    }
+}
+
+pub fn convert_amount<T>(
+    amount_convertor: &dyn AmountConvertor<Output = T>,
+    amount: i64,
+    currency: enums::Currency,
+) -> Result<T, error_stack::Report<errors::ConnectorError>> {
+    amount_convertor.convert(amount, currency)
+}
+
+pub fn convert_back_amount<T>(
+    amount_convertor: &dyn AmountConvertor<Output = T>,
+    amount: T,
+    currency: enums::Currency,
+) -> Result<i64, error_stack::Report<errors::ConnectorError>> {
+    amount_convertor.convert_back(amount, currency)
+}
+
 pub fn get_amount_as_string(
     currency_unit: &types::api::CurrencyUnit,
     amount: i64,
     currency: diesel_models::enums::Currency,
 ) -> Result<String, error_stack::Report<errors::ConnectorError>> {
-    let amount = match currency_unit {
-        types::api::CurrencyUnit::Minor => amount.to_string(),
-        types::api::CurrencyUnit::Base => to_currency_base_unit(amount, currency)?,
-    };
-    Ok(amount)
+    match currency_unit {
+        types::api::CurrencyUnit::Minor => convert_amount(&StringMinorUnit, amount, currency),
+        types::api::CurrencyUnit::Base => convert_amount(&StringMajorUnit, amount, currency),
+    }
 }
 
 pub fn get_amount_as_f64(
@@ -1599,30 +1742,23 @@ pub fn get_amount_as_f64(
     amount: i64,
     currency: diesel_models::enums::Currency,
 ) -> Result<f64, error_stack::Report<errors::ConnectorError>> {
-    let amount = match currency_unit {
-        types::api::CurrencyUnit::Base => to_currency_base_unit_asf64(amount, currency)?,
-        types::api::CurrencyUnit::Minor => u32::try_from(amount)
-            .change_context(errors::ConnectorError::ParsingFailed)?
-            .into(),
-    };
-    Ok(amount)
-}
-
-pub fn to_currency_base_unit(
-    amount: i64,
-    currency: diesel_models::enums::Currency,
-) -> Result<String, error_stack::Report<errors::ConnectorError>> {
-    currency
-        .to_currency_base_unit(amount)
-        .change_context(errors::ConnectorError::ParsingFailed)
+    match currency_unit {
+        types::api::CurrencyUnit::Base => convert_amount(&FloatMajorUnit, amount, currency),
+        types::api::CurrencyUnit::Minor => {
+            let amount = convert_amount(&MinorUnit, amount, currency)?;
+            Ok(u32::try_from(amount)
+                .change_context(errors::ConnectorError::ParsingFailed)?
+                .into())
+        }
+    }
 }
 
 pub fn to_currency_lower_unit(
     amount: String,
     currency: diesel_models::enums::Currency,
 ) -> Result<String, error_stack::Report<errors::ConnectorError>> {
-    currency
-        .to_currency_lower_unit(amount)
+    convert_back_amount(&StringMajorUnit, amount, currency)
+        .map(|amount| amount.to_string())
         .change_context(errors::ConnectorError::ResponseHandlingFailed)
 }
 
@@ -1654,15 +1790,6 @@ pub fn to_currency_base_unit_with_zero_decimal_check(
         .change_context(errors::ConnectorError::RequestEncodingFailed)
 }
 
-pub fn to_currency_base_unit_asf64(
-    amount: i64,
-    currency: diesel_models::enums::Currency,
-) -> Result<f64, error_stack::Report<errors::ConnectorError>> {
-    currency
-        .to_currency_base_unit_asf64(amount)
-        .change_context(errors::ConnectorError::ParsingFailed)
-}
-
 pub fn str_to_f32<S>(value: &str, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -2229,3 +2356,44 @@ mod error_code_error_message_tests {
         assert_eq!(error_code_error_message_none, None);
     }
 }
+
+#[cfg(test)]
+mod amount_conversion_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_string_major_unit_conversion() {
+        let two_decimal = convert_amount(&StringMajorUnit, 1050, enums::Currency::USD).unwrap();
+        let zero_decimal = convert_amount(&StringMajorUnit, 1050, enums::Currency::JPY).unwrap();
+        let three_decimal = convert_amount(&StringMajorUnit, 1050, enums::Currency::KWD).unwrap();
+        assert_eq!(two_decimal, "10.50");
+        assert_eq!(zero_decimal, "1050");
+        assert_eq!(three_decimal, "1.050");
+        assert_eq!(
+            convert_amount(&StringMajorUnit, 1005, enums::Currency::KWD).unwrap(),
+            "1.005"
+        );
+        assert_eq!(
+            convert_amount(&StringMajorUnit, 1000, enums::Currency::USD).unwrap(),
+            "10.00"
+        );
+
+        let converted_back =
+            convert_back_amount(&StringMajorUnit, "19.99".to_string(), enums::Currency::USD)
+                .unwrap();
+        assert_eq!(converted_back, 1999);
+        assert!(
+            convert_back_amount(&StringMajorUnit, "19.999".to_string(), enums::Currency::USD)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_float_major_unit_conversion() {
+        let converted = convert_amount(&FloatMajorUnit, 1999, enums::Currency::USD).unwrap();
+        assert_eq!(converted, 19.99);
+        let converted_back = convert_back_amount(&FloatMajorUnit, 19.99, enums::Currency::USD);
+        assert_eq!(converted_back.unwrap(), 1999);
+    }
+}
@@ -181,9 +181,11 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
         req: &types::PaymentsCancelRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
+        // Only authorizations which are yet to be sent for settlement can be cancelled, settled
+        // payments have to be refunded instead
         let connector_payment_id = req.request.connector_transaction_id.clone();
         Ok(format!(
-            "{}payments/settlements/{}",
+            "{}payments/authorizations/cancellations/{}",
             self.base_url(connectors),
             connector_payment_id
         ))
@@ -567,9 +569,15 @@ impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsRespon
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
         let connector_payment_id = req.request.connector_transaction_id.clone();
+        let refund_type = if is_full_refund(req) {
+            "full"
+        } else {
+            "partials"
+        };
         Ok(format!(
-            "{}payments/settlements/refunds/partials/{}",
+            "{}payments/settlements/refunds/{}/{}",
             self.base_url(connectors),
+            refund_type,
             connector_payment_id
         ))
     }
@@ -579,17 +587,23 @@ impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsRespon
         req: &types::RefundsRouterData<api::Execute>,
         connectors: &settings::Connectors,
     ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
-        let request = services::RequestBuilder::new()
+        let request_builder = services::RequestBuilder::new()
             .method(services::Method::Post)
             .url(&types::RefundExecuteType::get_url(self, req, connectors)?)
             .attach_default_headers()
             .headers(types::RefundExecuteType::get_headers(
                 self, req, connectors,
-            )?)
-            .set_body(types::RefundExecuteType::get_request_body(
-                self, req, connectors,
-            )?)
-            .build();
+            )?);
+        // Full refunds are requested without a body, the whole settled amount is refunded
+        let request = if is_full_refund(req) {
+            request_builder.build()
+        } else {
+            request_builder
+                .set_body(types::RefundExecuteType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build()
+        };
         Ok(Some(request))
     }
 
@@ -768,16 +782,17 @@ impl api::IncomingWebhook for Worldpay {
             EventType::SentForSettlement | EventType::Charged => {
                 Ok(api::IncomingWebhookEvent::PaymentIntentSuccess)
             }
-            EventType::Error | EventType::Expired => {
+            EventType::Error | EventType::Expired | EventType::Refused => {
                 Ok(api::IncomingWebhookEvent::PaymentIntentFailure)
             }
+            EventType::Authorized => {
+                Ok(api::IncomingWebhookEvent::PaymentIntentAuthorizationSuccess)
+            }
+            EventType::Cancelled => Ok(api::IncomingWebhookEvent::PaymentIntentCancelled),
+            EventType::CaptureFailed => Ok(api::IncomingWebhookEvent::PaymentIntentCaptureFailure),
             EventType::Unknown
-            | EventType::Authorized
-            | EventType::Cancelled
-            | EventType::Refused
             | EventType::Refunded
             | EventType::SentForRefund
-            | EventType::CaptureFailed
             | EventType::RefundFailed => Ok(api::IncomingWebhookEvent::EventNotSupported),
         }
     }
@@ -794,3 +809,7 @@ impl api::IncomingWebhook for Worldpay {
         Ok(Box::new(psync_body))
     }
 }
+
+fn is_full_refund(req: &types::RefundsRouterData<api::Execute>) -> bool {
+    req.request.refund_amount == req.request.payment_amount
+}
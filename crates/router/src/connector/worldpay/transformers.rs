@@ -199,8 +199,8 @@ impl From<EventType> for enums::AttemptStatus {
             EventType::CaptureFailed => Self::CaptureFailed,
             EventType::Refused => Self::Failure,
             EventType::Charged | EventType::SentForSettlement => Self::Charged,
-            EventType::Cancelled
-            | EventType::SentForRefund
+            EventType::Cancelled => Self::Voided,
+            EventType::SentForRefund
             | EventType::RefundFailed
             | EventType::Refunded
             | EventType::Error
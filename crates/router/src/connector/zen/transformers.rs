@@ -372,7 +372,7 @@ impl TryFrom<(&types::PaymentsAuthorizeRouterData, &GooglePayWalletData)> for Ze
     fn try_from(
         (item, gpay_pay_redirect_data): (&types::PaymentsAuthorizeRouterData, &GooglePayWalletData),
     ) -> Result<Self, Self::Error> {
-        let amount = utils::to_currency_base_unit(item.request.amount, item.request.currency)?;
+        let amount = utils::convert_amount(&utils::StringMajorUnit, item.request.amount, item.request.currency)?;
         let browser_info = item.request.get_browser_info()?;
         let browser_details = get_browser_details(&browser_info)?;
         let ip = browser_info.get_ip_address()?;
@@ -414,7 +414,7 @@ impl
             &Box<ApplePayRedirectData>,
         ),
     ) -> Result<Self, Self::Error> {
-        let amount = utils::to_currency_base_unit(item.request.amount, item.request.currency)?;
+        let amount = utils::convert_amount(&utils::StringMajorUnit, item.request.amount, item.request.currency)?;
         let connector_meta = item.get_connector_meta()?;
         let session: SessionObject = connector_meta
             .parse_value("SessionObject")
@@ -627,7 +627,11 @@ fn get_item_object(
                     item.request.currency,
                 )?,
                 line_amount_total: (f64::from(data.quantity)
-                    * utils::to_currency_base_unit_asf64(data.amount, item.request.currency)?)
+                    * utils::convert_amount(
+                        &utils::FloatMajorUnit,
+                        data.amount,
+                        item.request.currency,
+                    )?)
                 .to_string(),
             })
         })
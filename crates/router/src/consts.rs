@@ -48,6 +48,16 @@ pub(crate) const BASE64_ENGINE_URL_SAFE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::URL_SAFE;
 
 pub(crate) const API_KEY_LENGTH: usize = 64;
+/// Prefix of publishable keys, which can only be used to access client-safe routes
+pub(crate) const PUBLISHABLE_KEY_PREFIX: &str = "pk_";
+/// Minimum interval between two updates of the `last_used` timestamp of an API key
+pub(crate) const API_KEY_LAST_USED_UPDATE_INTERVAL_IN_SECS: i64 = 60;
+/// Prefix of the Redis key used to throttle updates of the `last_used` timestamp of an API key
+pub(crate) const API_KEY_LAST_USED_THROTTLE_KEY_PREFIX: &str = "API_KEY_LAST_USED";
+/// Default duration for which an API key remains valid after it has been rotated
+pub(crate) const API_KEY_ROTATION_DEFAULT_VALIDITY_IN_HOURS: u16 = 24;
+/// Maximum duration for which an API key can remain valid after it has been rotated
+pub(crate) const API_KEY_ROTATION_MAX_VALIDITY_IN_HOURS: u16 = 720;
 pub(crate) const PUB_SUB_CHANNEL: &str = "hyperswitch_invalidate";
 
 // Apple Pay validation url
@@ -110,6 +120,67 @@ pub const OUTGOING_CALL_URL: &str = "https://api.stripe.com/healthcheck";
 // 15 minutes = 900 seconds
 pub const POLL_ID_TTL: i64 = 900;
 
+// 5 minutes = 300 seconds
+pub const CONNECTOR_PAYLOADS_CONFIG_CACHE_TTL: i64 = 300;
+
+/// Config key under which storing connector payloads is enabled, globally or per merchant
+pub const STORE_CONNECTOR_PAYLOADS_CONFIG_KEY: &str = "store_connector_payloads";
+
+// 1 day = 86400 seconds
+pub const GEO_IP_CACHE_TTL: i64 = 86400;
+
+// 1 day = 86400 seconds
+pub const CARD_BIN_CACHE_TTL: i64 = 86400;
+
+/// Duration of the time buckets in which authorization outcomes are counted, the success rate of
+/// a connector is computed over the current and the previous bucket
+pub const SUCCESS_RATE_BUCKET_DURATION_IN_SECS: i64 = 3600;
+
+// 2 hours = 7200 seconds, so that the previous bucket is retained while the current one is filled
+pub const SUCCESS_RATE_TTL: i64 = 7200;
+
+/// Minimum number of authorization outcomes required before a success rate is considered
+pub const SUCCESS_RATE_MIN_SAMPLES: i64 = 10;
+
+/// Percentage of payments routed in the order decided by the routing algorithm, irrespective of
+/// success rates
+pub const SUCCESS_RATE_EXPLORATION_PERCENTAGE: u8 = 10;
+
+// 1 day = 86400 seconds
+pub const CIRCUIT_BREAKER_TTL: i64 = 86400;
+
+/// Number of most recent connector calls considered for the failure rate of a connector
+pub const CIRCUIT_BREAKER_WINDOW_SIZE: usize = 20;
+
+/// Minimum number of connector calls required before the circuit breaker can open
+pub const CIRCUIT_BREAKER_MIN_SAMPLES: usize = 10;
+
+/// Failure rate of the recent connector calls at which the circuit breaker opens
+pub const CIRCUIT_BREAKER_FAILURE_RATE_THRESHOLD: f64 = 0.5;
+
+/// Time (in seconds) for which an open circuit breaker keeps the connector out of routing, before
+/// the connector is probed again
+pub const CIRCUIT_BREAKER_OPEN_DURATION: i64 = 60;
+
+/// Percentage of payments routed to a connector whose circuit breaker is half-open, to probe
+/// whether it recovered
+pub const CIRCUIT_BREAKER_PROBE_PERCENTAGE: u8 = 10;
+
+/// Number of times the circuit breaker of a connector is read and stored again when it was changed
+/// by a concurrent payment in between
+pub const CIRCUIT_BREAKER_MAX_UPDATE_ATTEMPTS: u8 = 5;
+
+/// Number of times an update of a payment intent with the outcome of a connector call is applied
+/// again on the latest payment intent, when the payment intent was modified by a concurrent request
+pub const PAYMENT_INTENT_MAX_UPDATE_ATTEMPTS: u8 = 3;
+
+/// Time (in seconds) for which outgoing webhooks are also signed with the previous secret of a
+/// business profile, after the secret is rotated
+pub const WEBHOOK_SECRET_ROTATION_GRACE_PERIOD: i64 = 86400;
+
+/// Number of dead-lettered outgoing webhook events of a merchant beyond which operators are alerted
+pub const WEBHOOK_DEAD_LETTER_QUEUE_ALERT_THRESHOLD: i64 = 100;
+
 // Default Poll Config
 pub const DEFAULT_POLL_DELAY_IN_SECS: i8 = 2;
 pub const DEFAULT_POLL_FREQUENCY: i8 = 5;
@@ -1,12 +1,15 @@
 pub mod admin;
 pub mod api_keys;
 pub mod api_locking;
+pub mod audit_log;
 pub mod authentication;
 pub mod blocklist;
 pub mod cache;
+pub mod card_bin;
 pub mod cards_info;
 pub mod conditional_config;
 pub mod configs;
+pub mod connector_capabilities;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -14,14 +17,18 @@ pub mod currency;
 pub mod customers;
 pub mod disputes;
 pub mod errors;
+pub mod external_lookup;
 pub mod files;
 #[cfg(feature = "frm")]
 pub mod fraud_check;
+pub mod geo_ip;
 pub mod gsm;
 pub mod health_check;
 pub mod locker_migration;
 pub mod mandate;
 pub mod metrics;
+#[cfg(feature = "olap")]
+pub mod payment_exports;
 pub mod payment_link;
 pub mod payment_methods;
 pub mod payments;
@@ -39,6 +46,5 @@ pub mod user_role;
 pub mod utils;
 #[cfg(feature = "olap")]
 pub mod verification;
-#[cfg(feature = "olap")]
 pub mod verify_connector;
 pub mod webhooks;
@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
 use api_models::{
     admin::{self as admin_types},
@@ -20,10 +20,11 @@ use uuid::Uuid;
 use crate::{
     consts,
     core::{
+        audit_log,
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
         payments::helpers,
         routing::helpers as routing_helpers,
-        utils as core_utils,
+        utils as core_utils, verify_connector,
     },
     db::StorageInterface,
     routes::{metrics, AppState},
@@ -43,7 +44,8 @@ use crate::{
 #[inline]
 pub fn create_merchant_publishable_key() -> String {
     format!(
-        "pk_{}_{}",
+        "{}{}_{}",
+        consts::PUBLISHABLE_KEY_PREFIX,
         router_env::env::prefix_for_env(),
         Uuid::new_v4().simple()
     )
@@ -238,6 +240,7 @@ pub async fn create_merchant_account(
                 db,
                 business_profile_create_request,
                 merchant_account.clone(),
+                &key_store,
             )
             .await
             .map_err(|business_profile_insert_error| {
@@ -256,6 +259,7 @@ pub async fn create_merchant_account(
             db,
             api_models::admin::BusinessProfileCreate::default(),
             merchant_account.clone(),
+            &key_store,
         )
         .await?;
 
@@ -278,11 +282,22 @@ pub async fn create_merchant_account(
     })
     .ok();
 
-    Ok(service_api::ApplicationResponse::Json(
-        api::MerchantAccountResponse::try_from(merchant_account)
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while generating response")?,
-    ))
+    let response = api::MerchantAccountResponse::try_from(merchant_account)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
+
+    audit_log::record_audit_log(
+        &state,
+        &response.merchant_id,
+        storage::enums::AuditLogEntityType::MerchantAccount,
+        &response.merchant_id,
+        storage::enums::AuditLogAction::Create,
+        None,
+        Some(&response),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 #[cfg(feature = "olap")]
@@ -375,6 +390,7 @@ pub async fn create_business_profile_from_business_labels(
             db,
             business_profile_create_request,
             merchant_account.clone(),
+            key_store,
         )
         .await
         .map_err(|business_profile_insert_error| {
@@ -440,6 +456,8 @@ pub async fn update_business_profile_cascade(
             session_expiry: None,
             authentication_connector_details: None,
             extended_card_info_config: None,
+            outgoing_webhook_custom_http_headers: None,
+            statement_descriptor_name: None,
         };
 
         let update_futures = business_profiles.iter().map(|business_profile| async {
@@ -474,6 +492,11 @@ pub async fn merchant_account_update(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
     if &req.merchant_id != merchant_id {
         Err(report!(errors::ValidationError::IncorrectValueProvided {
             field_name: "parent_merchant_id"
@@ -607,11 +630,25 @@ pub async fn merchant_account_update(
 
     // If there are any new business labels generated, create business profile
 
-    Ok(service_api::ApplicationResponse::Json(
-        api::MerchantAccountResponse::try_from(response)
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while generating response")?,
-    ))
+    let old_response = api::MerchantAccountResponse::try_from(merchant_account)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
+    let response = api::MerchantAccountResponse::try_from(response)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
+
+    audit_log::record_audit_log(
+        &state,
+        merchant_id,
+        storage::enums::AuditLogEntityType::MerchantAccount,
+        merchant_id,
+        storage::enums::AuditLogAction::Update,
+        Some(&old_response),
+        Some(&response),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub async fn merchant_account_delete(
@@ -620,6 +657,15 @@ pub async fn merchant_account_delete(
 ) -> RouterResponse<api::MerchantAccountDeleteResponse> {
     let mut is_deleted = false;
     let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
     let is_merchant_account_deleted = db
         .delete_merchant_account_by_merchant_id(&merchant_id)
         .await
@@ -650,6 +696,23 @@ pub async fn merchant_account_delete(
     }
     .ok();
 
+    if is_deleted {
+        let old_response = api::MerchantAccountResponse::try_from(merchant_account)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while generating response")?;
+
+        audit_log::record_audit_log(
+            &state,
+            &merchant_id,
+            storage::enums::AuditLogEntityType::MerchantAccount,
+            &merchant_id,
+            storage::enums::AuditLogAction::Delete,
+            Some(&old_response),
+            None,
+        )
+        .await;
+    }
+
     let response = api::MerchantAccountDeleteResponse {
         merchant_id,
         deleted: is_deleted,
@@ -878,6 +941,12 @@ pub async fn create_payment_connector(
         }
     })?;
 
+    validate_test_connector_account_details(
+        req.connector_name,
+        req.test_connector_account_details.as_ref(),
+        &req.metadata,
+    )?;
+
     let frm_configs = get_frm_config_as_secret(req.frm_configs);
 
     // The purpose of this merchant account update is just to update the
@@ -896,7 +965,7 @@ pub async fn create_payment_connector(
     let (connector_status, disabled) = validate_status_and_disabled(
         req.status,
         req.disabled,
-        auth,
+        auth.clone(),
         // The validate_status_and_disabled function will use this value only
         // when the status can be active. So we are passing this as fallback.
         api_enums::ConnectorStatus::Active,
@@ -959,6 +1028,12 @@ pub async fn create_payment_connector(
         applepay_verified_domains: None,
         pm_auth_config: req.pm_auth_config.clone(),
         status: connector_status,
+        test_connector_account_details: req
+            .test_connector_account_details
+            .async_lift(|inner| domain_types::encrypt_optional(inner, key_store.key.peek()))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to encrypt test connector account details")?,
     };
 
     let transaction_type = match req.connector_type {
@@ -1031,7 +1106,28 @@ pub async fn create_payment_connector(
         ],
     );
 
-    let mca_response = mca.try_into()?;
+    let mut mca_response: api_models::admin::MerchantConnectorResponse = mca.try_into()?;
+
+    audit_log::record_audit_log(
+        &state,
+        merchant_id,
+        storage::enums::AuditLogEntityType::MerchantConnectorAccount,
+        &mca_response.merchant_connector_id,
+        storage::enums::AuditLogAction::Create,
+        None,
+        Some(&mca_response),
+    )
+    .await;
+
+    if req.verify_credentials.unwrap_or(false) {
+        mca_response.credentials_verified = verify_connector::check_merchant_connector_credentials(
+            &state,
+            req.connector_name,
+            auth,
+        )
+        .await;
+    }
+
     Ok(service_api::ApplicationResponse::Json(mca_response))
 }
 
@@ -1226,8 +1322,14 @@ pub async fn update_payment_connector(
         }),
     })?;
 
+    validate_test_connector_account_details(
+        connector_enum,
+        req.test_connector_account_details.as_ref(),
+        &metadata,
+    )?;
+
     let (connector_status, disabled) =
-        validate_status_and_disabled(req.status, req.disabled, auth, mca.status)?;
+        validate_status_and_disabled(req.status, req.disabled, auth.clone(), mca.status)?;
 
     if req.connector_type != api_enums::ConnectorType::PaymentMethodAuth {
         if let Some(val) = req.pm_auth_config.clone() {
@@ -1284,6 +1386,14 @@ pub async fn update_payment_connector(
         applepay_verified_domains: None,
         pm_auth_config: req.pm_auth_config,
         status: Some(connector_status),
+        test_connector_account_details: req
+            .test_connector_account_details
+            .async_lift(|inner| {
+                domain_types::encrypt_optional(inner, key_store.key.get_inner().peek())
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while encrypting data")?,
     };
 
     // Profile id should always be present
@@ -1295,6 +1405,8 @@ pub async fn update_payment_connector(
 
     let request_connector_label = req.connector_label;
 
+    let old_response: api_models::admin::MerchantConnectorResponse = mca.clone().try_into()?;
+
     let updated_mca = db
         .update_merchant_connector_account(mca, payment_connector.into(), &key_store)
         .await
@@ -1308,7 +1420,24 @@ pub async fn update_payment_connector(
             format!("Failed while updating MerchantConnectorAccount: id: {merchant_connector_id}")
         })?;
 
-    let response = updated_mca.try_into()?;
+    let mut response: api_models::admin::MerchantConnectorResponse = updated_mca.try_into()?;
+
+    audit_log::record_audit_log(
+        &state,
+        merchant_id,
+        storage::enums::AuditLogEntityType::MerchantConnectorAccount,
+        merchant_connector_id,
+        storage::enums::AuditLogAction::Update,
+        Some(&old_response),
+        Some(&response),
+    )
+    .await;
+
+    if req.verify_credentials.unwrap_or(false) {
+        response.credentials_verified =
+            verify_connector::check_merchant_connector_credentials(&state, connector_enum, auth)
+                .await;
+    }
 
     Ok(service_api::ApplicationResponse::Json(response))
 }
@@ -1329,7 +1458,7 @@ pub async fn delete_payment_connector(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
-    let _mca = db
+    let mca = db
         .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
             &merchant_id,
             &merchant_connector_id,
@@ -1350,6 +1479,29 @@ pub async fn delete_payment_connector(
             id: merchant_connector_id.clone(),
         })?;
 
+    // The purpose of this merchant account update is just to update the
+    // merchant account `modified_at` field for KGraph cache invalidation
+    db.update_specific_fields_in_merchant(
+        &merchant_id,
+        storage::MerchantAccountUpdate::ModifiedAtUpdate,
+        &key_store,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("error updating the merchant account when deleting payment connector")?;
+
+    let old_response: api_models::admin::MerchantConnectorResponse = mca.try_into()?;
+    audit_log::record_audit_log(
+        &state,
+        &merchant_id,
+        storage::enums::AuditLogEntityType::MerchantConnectorAccount,
+        &merchant_connector_id,
+        storage::enums::AuditLogAction::Delete,
+        Some(&old_response),
+        None,
+    )
+    .await;
+
     let response = api::MerchantConnectorDeleteResponse {
         merchant_id,
         merchant_connector_id,
@@ -1468,15 +1620,51 @@ pub fn get_frm_config_as_secret(
     }
 }
 
+async fn encrypt_outgoing_webhook_custom_http_headers(
+    headers: HashMap<String, Secret<String>>,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<diesel_models::encryption::Encryption> {
+    for (name, value) in &headers {
+        if reqwest::header::HeaderName::from_bytes(name.as_bytes()).is_err()
+            || reqwest::header::HeaderValue::from_str(value.peek()).is_err()
+        {
+            return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "outgoing_webhook_custom_http_headers",
+            })
+            .attach_printable(format!("Invalid outgoing webhook header `{name}`")));
+        }
+    }
+
+    let headers = headers
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to convert outgoing webhook custom http headers to a value")?;
+
+    domain_types::encrypt(Secret::new(headers), key_store.key.get_inner().peek())
+        .await
+        .map(Into::into)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encrypt outgoing webhook custom http headers")
+}
+
 pub async fn create_and_insert_business_profile(
     db: &dyn StorageInterface,
     request: api::BusinessProfileCreate,
     merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
 ) -> RouterResult<storage::business_profile::BusinessProfile> {
-    let business_profile_new = storage::business_profile::BusinessProfileNew::foreign_try_from((
-        merchant_account,
-        request,
-    ))?;
+    let outgoing_webhook_custom_http_headers = request
+        .outgoing_webhook_custom_http_headers
+        .clone()
+        .async_map(|headers| encrypt_outgoing_webhook_custom_http_headers(headers, key_store))
+        .await
+        .transpose()?;
+
+    let mut business_profile_new = storage::business_profile::BusinessProfileNew::foreign_try_from(
+        (merchant_account, request),
+    )?;
+    business_profile_new.outgoing_webhook_custom_http_headers =
+        outgoing_webhook_custom_http_headers;
 
     let profile_name = business_profile_new.profile_name.clone();
 
@@ -1522,7 +1710,8 @@ pub async fn create_business_profile(
     }
 
     let business_profile =
-        create_and_insert_business_profile(db, request, merchant_account.clone()).await?;
+        create_and_insert_business_profile(db, request, merchant_account.clone(), &key_store)
+            .await?;
 
     if merchant_account.default_profile.is_some() {
         let unset_default_profile = domain::MerchantAccountUpdate::UnsetDefaultProfile;
@@ -1531,10 +1720,21 @@ pub async fn create_business_profile(
             .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
     }
 
-    Ok(service_api::ApplicationResponse::Json(
-        api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile)
-            .change_context(errors::ApiErrorResponse::InternalServerError)?,
-    ))
+    let response = api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile)
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    audit_log::record_audit_log(
+        &state,
+        merchant_id,
+        storage::enums::AuditLogEntityType::BusinessProfile,
+        &response.profile_id,
+        storage::enums::AuditLogAction::Create,
+        None,
+        Some(&response),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub async fn list_business_profile(
@@ -1581,13 +1781,29 @@ pub async fn delete_business_profile(
     merchant_id: &str,
 ) -> RouterResponse<bool> {
     let db = state.store.as_ref();
+    let business_profile = find_business_profile_for_merchant(db, merchant_id, &profile_id).await?;
+
     let delete_result = db
         .delete_business_profile_by_profile_id_merchant_id(&profile_id, merchant_id)
         .await
         .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
-            id: profile_id,
+            id: profile_id.clone(),
         })?;
 
+    let old_response =
+        api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile)
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    audit_log::record_audit_log(
+        &state,
+        merchant_id,
+        storage::enums::AuditLogEntityType::BusinessProfile,
+        &profile_id,
+        storage::enums::AuditLogAction::Delete,
+        Some(&old_response),
+        None,
+    )
+    .await;
+
     Ok(service_api::ApplicationResponse::Json(delete_result))
 }
 
@@ -1662,6 +1878,35 @@ pub async fn update_business_profile(
         .transpose()?
         .map(Secret::new);
 
+    let outgoing_webhook_custom_http_headers = match request.outgoing_webhook_custom_http_headers {
+        Some(headers) => {
+            let key_store = db
+                .get_merchant_key_store_by_merchant_id(
+                    merchant_id,
+                    &db.get_master_key().to_vec().into(),
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+            Some(encrypt_outgoing_webhook_custom_http_headers(headers, &key_store).await?)
+        }
+        None => None,
+    };
+
+    // Outgoing webhooks keep being signed with the previous secret for a while after it is
+    // rotated, so that the merchant can switch to the new secret without rejecting any webhooks
+    let (previous_payment_response_hash_key, previous_payment_response_hash_key_expires_at) = match (
+        &request.payment_response_hash_key,
+        &business_profile.payment_response_hash_key,
+    ) {
+        (Some(new_key), Some(current_key)) if new_key != current_key => (
+            Some(current_key.clone()),
+            Some(date_time::now().saturating_add(time::Duration::seconds(
+                consts::WEBHOOK_SECRET_ROTATION_GRACE_PERIOD,
+            ))),
+        ),
+        _ => (None, None),
+    };
+
     let business_profile_update = storage::business_profile::BusinessProfileUpdate::Update {
         profile_name: request.profile_name,
         modified_at: Some(date_time::now()),
@@ -1691,8 +1936,16 @@ pub async fn update_business_profile(
                 field_name: "authentication_connector_details",
             })?,
         extended_card_info_config,
+        previous_payment_response_hash_key,
+        previous_payment_response_hash_key_expires_at,
+        outgoing_webhook_custom_http_headers,
+        statement_descriptor_name: request.statement_descriptor_name,
     };
 
+    let old_response =
+        api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile.clone())
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
     let updated_business_profile = db
         .update_business_profile_by_profile_id(business_profile, business_profile_update)
         .await
@@ -1700,10 +1953,22 @@ pub async fn update_business_profile(
             id: profile_id.to_owned(),
         })?;
 
-    Ok(service_api::ApplicationResponse::Json(
+    let response =
         api_models::admin::BusinessProfileResponse::foreign_try_from(updated_business_profile)
-            .change_context(errors::ApiErrorResponse::InternalServerError)?,
-    ))
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    audit_log::record_audit_log(
+        &state,
+        merchant_id,
+        storage::enums::AuditLogEntityType::BusinessProfile,
+        profile_id,
+        storage::enums::AuditLogAction::Update,
+        Some(&old_response),
+        Some(&response),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub async fn extended_card_info_toggle(
@@ -1729,16 +1994,392 @@ pub async fn extended_card_info_toggle(
                 is_extended_card_info_enabled: Some(ext_card_info_choice.enabled),
             };
 
+        let merchant_id = business_profile.merchant_id.clone();
+        let old_choice = business_profile
+            .is_extended_card_info_enabled
+            .map(|enabled| admin_types::ExtendedCardInfoChoice { enabled });
+
         db.update_business_profile_by_profile_id(business_profile, business_profile_update)
             .await
             .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
                 id: profile_id.to_owned(),
             })?;
+
+        audit_log::record_audit_log(
+            &state,
+            &merchant_id,
+            storage::enums::AuditLogEntityType::BusinessProfile,
+            profile_id,
+            storage::enums::AuditLogAction::Update,
+            old_choice.as_ref(),
+            Some(&ext_card_info_choice),
+        )
+        .await;
     }
 
     Ok(service_api::ApplicationResponse::Json(ext_card_info_choice))
 }
 
+fn validate_webhook_endpoint_url(url: &Secret<String>) -> RouterResult<()> {
+    url::Url::parse(url.peek())
+        .ok()
+        .filter(|url| matches!(url.scheme(), "http" | "https"))
+        .map(|_| ())
+        .ok_or(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`url` must be a valid http or https URL".to_string(),
+        }))
+}
+
+async fn find_business_profile_for_merchant(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    profile_id: &str,
+) -> RouterResult<storage::business_profile::BusinessProfile> {
+    let business_profile = db
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?;
+
+    if business_profile.merchant_id != merchant_id {
+        Err(errors::ApiErrorResponse::AccessForbidden {
+            resource: profile_id.to_string(),
+        })?
+    }
+
+    Ok(business_profile)
+}
+
+async fn find_webhook_endpoint_for_business_profile(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    profile_id: &str,
+    endpoint_id: &str,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<domain::WebhookEndpoint> {
+    db.find_webhook_endpoint_by_merchant_id_endpoint_id(merchant_id, endpoint_id, key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookEndpointNotFound {
+            id: endpoint_id.to_owned(),
+        })
+        .and_then(|webhook_endpoint| {
+            (webhook_endpoint.profile_id == profile_id)
+                .then_some(webhook_endpoint)
+                .ok_or(report!(errors::ApiErrorResponse::WebhookEndpointNotFound {
+                    id: endpoint_id.to_owned(),
+                }))
+        })
+}
+
+fn get_webhook_endpoint_response(
+    webhook_endpoint: domain::WebhookEndpoint,
+) -> RouterResult<admin_types::WebhookEndpointResponse> {
+    admin_types::WebhookEndpointResponse::foreign_try_from(webhook_endpoint)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook endpoint details")
+}
+
+pub async fn create_webhook_endpoint(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    request: admin_types::WebhookEndpointCreate,
+) -> RouterResponse<admin_types::WebhookEndpointResponse> {
+    let db = state.store.as_ref();
+    let business_profile = find_business_profile_for_merchant(db, merchant_id, profile_id).await?;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    validate_webhook_endpoint_url(&request.url)?;
+
+    let enabled_events = request
+        .enabled_events
+        .map(|enabled_events| enabled_events.encode_to_value())
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "enabled_events",
+        })?;
+
+    let endpoint_id = common_utils::generate_id_with_default_len("whe");
+    let secret = request
+        .secret
+        .unwrap_or_else(|| Secret::new(generate_cryptographically_secure_random_string(64)));
+
+    // Misconfigured URLs are caught before the endpoint starts receiving webhooks
+    let verification_result = super::webhooks::send_webhook_endpoint_test_event(
+        &state,
+        &business_profile.merchant_id,
+        &endpoint_id,
+        &request.url,
+        &secret,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to send verification event to webhook endpoint")?;
+
+    if !verification_result.is_successful {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: verification_result.status_code.map_or_else(
+                || "Webhook endpoint could not be reached".to_string(),
+                |status_code| format!("Webhook endpoint responded with status code {status_code}"),
+            ),
+        })?
+    }
+
+    let now = date_time::now();
+    let webhook_endpoint = domain::WebhookEndpoint {
+        endpoint_id,
+        merchant_id: business_profile.merchant_id,
+        profile_id: business_profile.profile_id,
+        url: request.url,
+        description: request.description,
+        secret: domain_types::encrypt(secret.clone(), key_store.key.get_inner().peek())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt webhook endpoint secret")?,
+        enabled_events,
+        is_active: true,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let webhook_endpoint = db
+        .insert_webhook_endpoint(webhook_endpoint, &key_store)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Webhook endpoint already exists".to_string(),
+        })?;
+
+    let response = get_webhook_endpoint_response(webhook_endpoint)?;
+
+    audit_log::record_audit_log(
+        &state,
+        merchant_id,
+        storage::enums::AuditLogEntityType::WebhookEndpoint,
+        &response.endpoint_id,
+        storage::enums::AuditLogAction::Create,
+        None,
+        Some(&response),
+    )
+    .await;
+
+    // The secret is returned once, so that the merchant can verify the signatures of the webhook
+    // messages, and is left out of the audit log above
+    Ok(service_api::ApplicationResponse::Json(
+        admin_types::WebhookEndpointResponse {
+            secret: Some(secret),
+            ..response
+        },
+    ))
+}
+
+pub async fn list_webhook_endpoints(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+) -> RouterResponse<Vec<admin_types::WebhookEndpointResponse>> {
+    let db = state.store.as_ref();
+    let business_profile = find_business_profile_for_merchant(db, merchant_id, profile_id).await?;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let webhook_endpoints = db
+        .list_webhook_endpoints_by_merchant_id_profile_id(
+            &business_profile.merchant_id,
+            &business_profile.profile_id,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints")?
+        .into_iter()
+        .map(admin_types::WebhookEndpointResponse::foreign_try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook endpoint details")?;
+
+    Ok(service_api::ApplicationResponse::Json(webhook_endpoints))
+}
+
+pub async fn retrieve_webhook_endpoint(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    endpoint_id: &str,
+) -> RouterResponse<admin_types::WebhookEndpointResponse> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let webhook_endpoint = find_webhook_endpoint_for_business_profile(
+        db,
+        merchant_id,
+        profile_id,
+        endpoint_id,
+        &key_store,
+    )
+    .await?;
+
+    get_webhook_endpoint_response(webhook_endpoint).map(service_api::ApplicationResponse::Json)
+}
+
+pub async fn test_webhook_endpoint(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    endpoint_id: &str,
+) -> RouterResponse<admin_types::WebhookEndpointTestResponse> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let webhook_endpoint = find_webhook_endpoint_for_business_profile(
+        db,
+        merchant_id,
+        profile_id,
+        endpoint_id,
+        &key_store,
+    )
+    .await?;
+
+    let test_result = super::webhooks::send_webhook_endpoint_test_event(
+        &state,
+        &webhook_endpoint.merchant_id,
+        &webhook_endpoint.endpoint_id,
+        &webhook_endpoint.url,
+        webhook_endpoint.secret.get_inner(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to send test event to webhook endpoint")?;
+
+    Ok(service_api::ApplicationResponse::Json(test_result))
+}
+
+pub async fn update_webhook_endpoint(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    endpoint_id: &str,
+    request: admin_types::WebhookEndpointUpdate,
+) -> RouterResponse<admin_types::WebhookEndpointResponse> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let old_webhook_endpoint = find_webhook_endpoint_for_business_profile(
+        db,
+        merchant_id,
+        profile_id,
+        endpoint_id,
+        &key_store,
+    )
+    .await?;
+
+    if let Some(url) = &request.url {
+        validate_webhook_endpoint_url(url)?;
+    }
+
+    let enabled_events = request
+        .enabled_events
+        .map(|enabled_events| enabled_events.encode_to_value())
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "enabled_events",
+        })?;
+
+    let secret = request
+        .secret
+        .async_lift(|inner| domain_types::encrypt_optional(inner, key_store.key.get_inner().peek()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt webhook endpoint secret")?;
+
+    let webhook_endpoint = db
+        .update_webhook_endpoint_by_merchant_id_endpoint_id(
+            merchant_id,
+            endpoint_id,
+            domain::WebhookEndpointUpdate::Update {
+                url: request.url,
+                description: request.description,
+                secret,
+                enabled_events,
+                is_active: request.is_active,
+            },
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookEndpointNotFound {
+            id: endpoint_id.to_owned(),
+        })?;
+
+    let old_response = get_webhook_endpoint_response(old_webhook_endpoint)?;
+    let response = get_webhook_endpoint_response(webhook_endpoint)?;
+
+    audit_log::record_audit_log(
+        &state,
+        merchant_id,
+        storage::enums::AuditLogEntityType::WebhookEndpoint,
+        endpoint_id,
+        storage::enums::AuditLogAction::Update,
+        Some(&old_response),
+        Some(&response),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+pub async fn delete_webhook_endpoint(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    endpoint_id: &str,
+) -> RouterResponse<bool> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let webhook_endpoint = find_webhook_endpoint_for_business_profile(
+        db,
+        merchant_id,
+        profile_id,
+        endpoint_id,
+        &key_store,
+    )
+    .await?;
+
+    let delete_result = db
+        .delete_webhook_endpoint_by_merchant_id_endpoint_id(merchant_id, endpoint_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookEndpointNotFound {
+            id: endpoint_id.to_owned(),
+        })?;
+
+    let old_response = get_webhook_endpoint_response(webhook_endpoint)?;
+    audit_log::record_audit_log(
+        &state,
+        merchant_id,
+        storage::enums::AuditLogEntityType::WebhookEndpoint,
+        endpoint_id,
+        storage::enums::AuditLogAction::Delete,
+        Some(&old_response),
+        None,
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(delete_result))
+}
+
 pub(crate) fn validate_auth_and_metadata_type(
     connector_name: api_models::enums::Connector,
     val: &types::ConnectorAuthType,
@@ -1929,6 +2570,10 @@ pub(crate) fn validate_auth_and_metadata_type(
             rapyd::transformers::RapydAuthType::try_from(val)?;
             Ok(())
         }
+        api_enums::Connector::Razorpay => {
+            razorpay::transformers::RazorpayAuthType::try_from(val)?;
+            Ok(())
+        }
         api_enums::Connector::Shift4 => {
             shift4::transformers::Shift4AuthType::try_from(val)?;
             Ok(())
@@ -2054,3 +2699,29 @@ pub fn validate_status_and_disabled(
 
     Ok((connector_status, disabled))
 }
+
+/// The test connector account details must be in the same format as the live connector account
+/// details of the connector.
+fn validate_test_connector_account_details(
+    connector_name: api_enums::Connector,
+    test_connector_account_details: Option<&pii::SecretSerdeValue>,
+    connector_meta_data: &Option<pii::SecretSerdeValue>,
+) -> RouterResult<()> {
+    if let Some(test_connector_account_details) = test_connector_account_details {
+        let auth: types::ConnectorAuthType = test_connector_account_details
+            .clone()
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "test_connector_account_details".to_string(),
+                expected_format: "auth_type and api_key".to_string(),
+            })?;
+
+        validate_auth_and_metadata_type(connector_name, &auth, connector_meta_data)
+            .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The test_connector_account_details are invalid for the connector"
+                    .to_string(),
+            })?;
+    }
+
+    Ok(())
+}
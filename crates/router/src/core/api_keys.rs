@@ -1,16 +1,24 @@
 use common_utils::date_time;
 #[cfg(feature = "email")]
-use diesel_models::{api_keys::ApiKey, enums as storage_enums};
+use diesel_models::api_keys::ApiKey;
+use diesel_models::enums as storage_enums;
 use error_stack::{report, ResultExt};
 use masking::{PeekInterface, StrongSecret};
-use router_env::{instrument, tracing};
+use router_env::{
+    instrument, logger,
+    tracing::{self, Instrument},
+};
 
 use crate::{
     configs::settings,
     consts,
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::{
+        audit_log,
+        errors::{self, RouterResponse, StorageErrorExt},
+    },
+    db::StorageInterface,
     routes::{metrics, AppState},
-    services::ApplicationResponse,
+    services::{authorization::roles, ApplicationResponse},
     types::{api, storage, transformers::ForeignInto},
     utils,
 };
@@ -108,6 +116,69 @@ impl PlaintextApiKey {
     }
 }
 
+/// Parses an entry of the IP allowlist of an API key, which is either a CIDR block or a single IP
+/// address.
+pub fn parse_ip_allowlist_entry(entry: &str) -> Result<ipnet::IpNet, std::net::AddrParseError> {
+    entry
+        .parse::<ipnet::IpNet>()
+        .or_else(|_| entry.parse::<std::net::IpAddr>().map(ipnet::IpNet::from))
+}
+
+/// Records the usage of an API key in the background, so that authentication is not delayed by
+/// the database write. The `last_used` timestamp is updated at most once every
+/// [`consts::API_KEY_LAST_USED_UPDATE_INTERVAL_IN_SECS`] seconds per API key, which is tracked
+/// in Redis since the API key being authenticated may be served from the cache.
+pub fn record_api_key_usage(store: Box<dyn StorageInterface>, merchant_id: String, key_id: String) {
+    tokio::spawn(
+        async move {
+            let redis_conn = match store.get_redis_conn() {
+                Ok(redis_conn) => redis_conn,
+                Err(error) => {
+                    logger::error!(?error, "Failed to obtain Redis connection");
+                    return;
+                }
+            };
+
+            let redis_key = format!("{}_{key_id}", consts::API_KEY_LAST_USED_THROTTLE_KEY_PREFIX);
+            match redis_conn
+                .set_key_if_not_exists_with_expiry(
+                    &redis_key,
+                    "true",
+                    Some(consts::API_KEY_LAST_USED_UPDATE_INTERVAL_IN_SECS),
+                )
+                .await
+            {
+                Ok(redis_interface::SetnxReply::KeySet) => {
+                    store
+                        .update_api_key_last_used(&merchant_id, &key_id, date_time::now())
+                        .await
+                        .map_err(|error| {
+                            logger::error!(?error, "Failed to update API key last used")
+                        })
+                        .ok();
+                }
+                // The usage of the API key was recorded recently
+                Ok(redis_interface::SetnxReply::KeyNotSet) => {}
+                Err(error) => {
+                    logger::error!(?error, "Failed to throttle API key last used update")
+                }
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+fn validate_ip_allowlist(ip_allowlist: &[String]) -> errors::RouterResult<()> {
+    ip_allowlist.iter().try_for_each(|entry| {
+        parse_ip_allowlist_entry(entry)
+            .map(|_| ())
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "ip_allowlist",
+            })
+            .attach_printable_lazy(|| format!("Invalid IP allowlist entry: {entry}"))
+    })
+}
+
 #[instrument(skip_all)]
 pub async fn create_api_key(
     state: AppState,
@@ -120,7 +191,7 @@ pub async fn create_api_key(
     // merchant account.
     // Instead, we're only fetching merchant key store, as it is sufficient to identify
     // non-existence of a merchant account.
-    store
+    let key_store = store
         .get_merchant_key_store_by_merchant_id(
             merchant_id.as_str(),
             &store.get_master_key().to_vec().into(),
@@ -128,6 +199,35 @@ pub async fn create_api_key(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
+    if let Some(ip_allowlist) = api_key.ip_allowlist.as_deref() {
+        validate_ip_allowlist(ip_allowlist)?;
+    }
+
+    if let Some(role_id) = api_key.role_id.as_deref() {
+        let merchant_account = store
+            .find_merchant_account_by_merchant_id(merchant_id.as_str(), &key_store)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+        let role_info = roles::RoleInfo::from_role_id(
+            &state,
+            role_id,
+            merchant_id.as_str(),
+            &merchant_account.organization_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "role_id",
+        })?;
+
+        if role_info.is_internal() {
+            return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "role_id",
+            }))
+            .attach_printable("Internal roles cannot be assigned to API keys");
+        }
+    }
+
     let hash_key = api_key_config.get_hash_key()?;
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
@@ -140,6 +240,9 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        test_mode: api_key.test_mode,
+        ip_allowlist: api_key.ip_allowlist,
+        role_id: api_key.role_id,
     };
 
     let api_key = store
@@ -148,6 +251,17 @@ pub async fn create_api_key(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to insert new API key")?;
 
+    audit_log::record_audit_log::<api::RetrieveApiKeyResponse>(
+        &state,
+        &merchant_id,
+        storage_enums::AuditLogEntityType::ApiKey,
+        &api_key.key_id,
+        storage_enums::AuditLogAction::Create,
+        None,
+        Some(&api_key.clone().foreign_into()),
+    )
+    .await;
+
     metrics::API_KEY_CREATED.add(
         &metrics::CONTEXT,
         1,
@@ -268,6 +382,17 @@ pub async fn update_api_key(
     let key_id = api_key.key_id.clone();
     let store = state.store.as_ref();
 
+    if let Some(ip_allowlist) = api_key.ip_allowlist.as_deref() {
+        validate_ip_allowlist(ip_allowlist)?;
+    }
+
+    let previous_api_key = store
+        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?; // If retrieve returned `None`
+
     let api_key = store
         .update_api_key(
             merchant_id.to_owned(),
@@ -277,6 +402,17 @@ pub async fn update_api_key(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
 
+    audit_log::record_audit_log::<api::RetrieveApiKeyResponse>(
+        &state,
+        &merchant_id,
+        storage_enums::AuditLogEntityType::ApiKey,
+        &key_id,
+        storage_enums::AuditLogAction::Update,
+        Some(&previous_api_key.foreign_into()),
+        Some(&api_key.clone().foreign_into()),
+    )
+    .await;
+
     #[cfg(feature = "email")]
     {
         let expiry_reminder_days = state.conf.api_keys.get_inner().expiry_reminder_days.clone();
@@ -393,6 +529,97 @@ pub async fn update_api_key_expiry_task(
     Ok(())
 }
 
+#[instrument(skip_all)]
+pub async fn rotate_api_key(
+    state: AppState,
+    api_key: api::RotateApiKeyRequest,
+) -> RouterResponse<api::CreateApiKeyResponse> {
+    let merchant_id = api_key.merchant_id;
+    let key_id = api_key.key_id;
+    let store = state.store.as_ref();
+    let current_time = date_time::now();
+
+    let previous_key_validity_hours = api_key
+        .previous_key_validity_hours
+        .unwrap_or(consts::API_KEY_ROTATION_DEFAULT_VALIDITY_IN_HOURS);
+    utils::when(
+        previous_key_validity_hours > consts::API_KEY_ROTATION_MAX_VALIDITY_IN_HOURS,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`previous_key_validity_hours` must not exceed {}",
+                    consts::API_KEY_ROTATION_MAX_VALIDITY_IN_HOURS
+                ),
+            })
+        },
+    )?;
+
+    let previous_api_key = store
+        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?; // If retrieve returned `None`
+
+    utils::when(
+        previous_api_key
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= current_time),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The API key to be rotated has already expired".to_string(),
+            })
+        },
+    )?;
+
+    // The new API key retains the name and description of the API key being rotated, so that
+    // both keys can be identified as the same integration until the previous key expires.
+    let new_api_key = create_api_key(
+        state.clone(),
+        api::CreateApiKeyRequest {
+            name: previous_api_key.name,
+            description: previous_api_key.description,
+            expiration: api_key
+                .expiration
+                .unwrap_or_else(|| previous_api_key.expires_at.into()),
+            test_mode: previous_api_key.test_mode,
+            ip_allowlist: previous_api_key.ip_allowlist,
+            role_id: previous_api_key.role_id,
+        },
+        merchant_id.clone(),
+    )
+    .await?;
+
+    // The previous API key remains valid for the requested duration, unless it was already set
+    // to expire earlier.
+    let previous_key_expires_at = current_time.saturating_add(time::Duration::hours(i64::from(
+        previous_key_validity_hours,
+    )));
+    let previous_key_expires_at = previous_api_key
+        .expires_at
+        .map_or(previous_key_expires_at, |expires_at| {
+            expires_at.min(previous_key_expires_at)
+        });
+
+    update_api_key(
+        state,
+        api::UpdateApiKeyRequest {
+            name: None,
+            description: None,
+            expiration: Some(api::ApiKeyExpiration::DateTime(previous_key_expires_at)),
+            ip_allowlist: None,
+            key_id,
+            merchant_id,
+        },
+    )
+    .await
+    .attach_printable("Failed to update expiry of the rotated API key")?;
+
+    metrics::API_KEY_ROTATED.add(&metrics::CONTEXT, 1, &[]);
+
+    Ok(new_api_key)
+}
+
 #[instrument(skip_all)]
 pub async fn revoke_api_key(
     state: AppState,
@@ -400,11 +627,31 @@ pub async fn revoke_api_key(
     key_id: &str,
 ) -> RouterResponse<api::RevokeApiKeyResponse> {
     let store = state.store.as_ref();
+    let api_key = store
+        .find_api_key_by_merchant_id_key_id_optional(merchant_id, key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?; // If retrieve returned `None`
+
     let revoked = store
         .revoke_api_key(merchant_id, key_id)
         .await
         .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
 
+    if revoked {
+        audit_log::record_audit_log::<api::RetrieveApiKeyResponse>(
+            &state,
+            merchant_id,
+            storage_enums::AuditLogEntityType::ApiKey,
+            key_id,
+            storage_enums::AuditLogAction::Delete,
+            Some(&api_key.foreign_into()),
+            None,
+        )
+        .await;
+    }
+
     metrics::API_KEY_REVOKED.add(&metrics::CONTEXT, 1, &[]);
 
     #[cfg(feature = "email")]
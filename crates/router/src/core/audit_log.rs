@@ -0,0 +1,151 @@
+use common_utils::date_time;
+use error_stack::ResultExt;
+use masking::Secret;
+use router_env::{instrument, logger, tracing};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    core::errors::{self, RouterResponse},
+    routes::AppState,
+    services::ApplicationResponse,
+    types::{
+        api::audit_log as audit_log_types,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const AUDIT_LOGS_LIST_MAX_LIMIT: i64 = 100;
+
+/// Fields of the admin configurations that hold secrets as plain strings rather than as `Secret`s,
+/// and hence need to be masked explicitly.
+const PLAIN_SECRET_FIELDS: [&str; 4] = [
+    "payment_response_hash_key",
+    "webhook_password",
+    "merchant_secret",
+    "additional_secret",
+];
+
+/// The credential that a change recorded in the audit log was made with
+#[derive(Clone, Debug)]
+pub struct AuditLogActor {
+    pub actor_type: enums::AuditLogActorType,
+    pub actor_id: Option<String>,
+}
+
+impl Default for AuditLogActor {
+    fn default() -> Self {
+        Self {
+            actor_type: enums::AuditLogActorType::System,
+            actor_id: None,
+        }
+    }
+}
+
+/// Records a change to an admin configuration in the audit log, attributed to the actor of the
+/// current request. The old and new values are serialized with their secrets masked.
+///
+/// The change has already been made when this is called, so a failure to record it is logged
+/// instead of failing the request.
+#[instrument(skip(state, old_value, new_value))]
+pub async fn record_audit_log<T: Serialize>(
+    state: &AppState,
+    merchant_id: &str,
+    entity_type: enums::AuditLogEntityType,
+    entity_id: &str,
+    action: enums::AuditLogAction,
+    old_value: Option<&T>,
+    new_value: Option<&T>,
+) {
+    let audit_log = match (
+        old_value.map(masked_serialize).transpose(),
+        new_value.map(masked_serialize).transpose(),
+    ) {
+        (Ok(old_value), Ok(new_value)) => storage::AuditLogNew {
+            audit_log_id: common_utils::generate_time_ordered_id("audit"),
+            merchant_id: merchant_id.to_owned(),
+            entity_type,
+            entity_id: entity_id.to_owned(),
+            action,
+            actor_type: state.audit_log_actor.actor_type,
+            actor_id: state.audit_log_actor.actor_id.clone(),
+            old_value,
+            new_value,
+            created_at: date_time::now(),
+        },
+        (Err(error), _) | (_, Err(error)) => {
+            logger::error!(?error, "Failed to serialize the audit log values");
+            return;
+        }
+    };
+
+    if let Err(error) = state.store.insert_audit_log(audit_log).await {
+        logger::error!(?error, "Failed to insert the audit log");
+    }
+}
+
+fn masked_serialize<T: Serialize>(value: &T) -> Result<Value, serde_json::Error> {
+    masking::masked_serialize(value).map(mask_plain_secret_fields)
+}
+
+fn mask_plain_secret_fields(value: Value) -> Value {
+    match value {
+        Value::Object(fields) => Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| match value {
+                    Value::String(secret) if PLAIN_SECRET_FIELDS.contains(&key.as_str()) => {
+                        (key, Value::String(format!("{:?}", Secret::new(secret))))
+                    }
+                    value => (key, mask_plain_secret_fields(value)),
+                })
+                .collect(),
+        ),
+        Value::Array(values) => {
+            Value::Array(values.into_iter().map(mask_plain_secret_fields).collect())
+        }
+        value => value,
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn list_audit_logs(
+    state: AppState,
+    merchant_id: String,
+    constraints: audit_log_types::AuditLogListConstraints,
+) -> RouterResponse<Vec<audit_log_types::AuditLogResponse>> {
+    let limit = match constraints.limit.map(i64::from) {
+        Some(limit) if limit <= AUDIT_LOGS_LIST_MAX_LIMIT => Ok(limit),
+        Some(_) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`limit` must be a number less than {AUDIT_LOGS_LIST_MAX_LIMIT}"),
+        }),
+        None => Ok(AUDIT_LOGS_LIST_MAX_LIMIT),
+    }?;
+    let offset = constraints
+        .offset
+        .map(i64::from)
+        .filter(|offset| *offset > 0);
+
+    let audit_logs = state
+        .store
+        .list_audit_logs_by_merchant_id_constraints(
+            &merchant_id,
+            constraints.entity_type,
+            constraints.entity_id,
+            constraints.created_after,
+            constraints.created_before,
+            Some(limit),
+            offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list audit logs")?;
+
+    Ok(ApplicationResponse::Json(
+        audit_logs
+            .into_iter()
+            .map(audit_log_types::AuditLogResponse::foreign_from)
+            .collect(),
+    ))
+}
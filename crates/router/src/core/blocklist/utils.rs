@@ -12,6 +12,7 @@ use crate::{
         errors::{RouterResult, StorageErrorExt},
         payments::PaymentData,
     },
+    db::StorageInterface,
     logger,
     types::{domain, storage, transformers::ForeignInto},
     utils,
@@ -207,11 +208,11 @@ pub async fn insert_entry_into_blocklist(
 }
 
 pub async fn get_merchant_fingerprint_secret(
-    state: &AppState,
+    db: &dyn StorageInterface,
     merchant_id: &str,
 ) -> RouterResult<String> {
     let key = get_merchant_fingerprint_secret_key(merchant_id);
-    let config_fetch_result = state.store.find_config_by_key(&key).await;
+    let config_fetch_result = db.find_config_by_key(&key).await;
 
     match config_fetch_result {
         Ok(config) => Ok(config.config),
@@ -224,9 +225,7 @@ pub async fn get_merchant_fingerprint_secret(
                 config: new_fingerprint_secret.clone(),
             };
 
-            state
-                .store
-                .insert_config(new_config)
+            db.insert_config(new_config)
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("unable to create new fingerprint secret for merchant")?;
@@ -311,7 +310,7 @@ where
     let db = &state.store;
     let merchant_id = &merchant_account.merchant_id;
     let merchant_fingerprint_secret =
-        get_merchant_fingerprint_secret(state, merchant_id.as_str()).await?;
+        get_merchant_fingerprint_secret(&*state.store, merchant_id.as_str()).await?;
 
     // Hashed Fingerprint to check whether or not this payment should be blocked.
     let card_number_fingerprint = if let Some(api_models::payments::PaymentMethodData::Card(card)) =
@@ -456,7 +455,8 @@ pub async fn generate_payment_fingerprint(
     merchant_id: String,
     payment_method_data: Option<crate::types::api::PaymentMethodData>,
 ) -> CustomResult<Option<String>, errors::ApiErrorResponse> {
-    let merchant_fingerprint_secret = get_merchant_fingerprint_secret(state, &merchant_id).await?;
+    let merchant_fingerprint_secret =
+        get_merchant_fingerprint_secret(&*state.store, &merchant_id).await?;
 
     Ok(
         if let Some(api_models::payments::PaymentMethodData::Card(card)) =
@@ -0,0 +1,276 @@
+use std::str::FromStr;
+
+use common_enums::CardNetwork;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings::ExternalLookupProvider,
+    consts,
+    core::{
+        errors::{self, RouterResult},
+        external_lookup,
+    },
+    routes::AppState,
+    types::storage::cards_info::CardInfo,
+};
+
+/// Timeout (in seconds) for the requests made to the external BIN provider, kept short as the
+/// lookup is made while routing the payment
+const EXTERNAL_CARD_BIN_PROVIDER_TIMEOUT: u64 = 1;
+
+/// A source of card BIN (IIN) information.
+///
+/// Sources are consulted in order by [`get_card_bin_info`], the first one to return a match wins.
+#[async_trait::async_trait]
+pub trait CardBinDataSource: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    async fn get_card_bin_info(
+        &self,
+        state: &AppState,
+        card_iin: &str,
+    ) -> RouterResult<Option<CardInfo>>;
+}
+
+/// BIN information stored in the `cards_info` table.
+pub struct StoredCardBinDataSource;
+
+#[async_trait::async_trait]
+impl CardBinDataSource for StoredCardBinDataSource {
+    fn name(&self) -> &'static str {
+        "stored"
+    }
+
+    async fn get_card_bin_info(
+        &self,
+        state: &AppState,
+        card_iin: &str,
+    ) -> RouterResult<Option<CardInfo>> {
+        state
+            .store
+            .get_card_info(card_iin)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve card information")
+    }
+}
+
+/// BIN information fetched from an external provider configured under `card_bin_lookup`, cached
+/// in redis.
+pub struct ExternalCardBinDataSource<'a> {
+    provider: &'a ExternalLookupProvider,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+struct ExternalCardBinResponse {
+    card_issuer: Option<String>,
+    card_network: Option<String>,
+    card_type: Option<String>,
+    card_sub_type: Option<String>,
+    card_issuing_country: Option<String>,
+}
+
+fn get_card_bin_cache_key(card_iin: &str) -> String {
+    format!("card_bin_{card_iin}")
+}
+
+impl ExternalCardBinDataSource<'_> {
+    async fn fetch_card_bin_info(
+        &self,
+        state: &AppState,
+        card_iin: &str,
+    ) -> RouterResult<Option<CardInfo>> {
+        let response: Option<ExternalCardBinResponse> =
+            external_lookup::cached_fetch_from_external_provider(
+                state,
+                self.provider,
+                card_iin,
+                EXTERNAL_CARD_BIN_PROVIDER_TIMEOUT,
+                &get_card_bin_cache_key(card_iin),
+                consts::CARD_BIN_CACHE_TTL,
+            )
+            .await?;
+
+        let now = common_utils::date_time::now();
+        Ok(response.map(|response| CardInfo {
+            card_iin: card_iin.to_string(),
+            card_issuer: response.card_issuer,
+            card_network: response
+                .card_network
+                .and_then(|network| CardNetwork::from_str(&network).ok()),
+            card_type: response.card_type,
+            card_subtype: response.card_sub_type,
+            card_issuing_country: response.card_issuing_country,
+            bank_code_id: None,
+            bank_code: None,
+            country_code: None,
+            date_created: now,
+            last_updated: Some(now),
+            last_updated_provider: Some(self.name().to_string()),
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl CardBinDataSource for ExternalCardBinDataSource<'_> {
+    fn name(&self) -> &'static str {
+        "external"
+    }
+
+    /// Failures of the external provider are logged and treated as a miss, so that the lookup
+    /// falls through to the next source.
+    async fn get_card_bin_info(
+        &self,
+        state: &AppState,
+        card_iin: &str,
+    ) -> RouterResult<Option<CardInfo>> {
+        Ok(self
+            .fetch_card_bin_info(state, card_iin)
+            .await
+            .map_err(|error| logger::warn!(card_bin_lookup_error=?error))
+            .ok()
+            .flatten())
+    }
+}
+
+/// Card network detection from the well known BIN ranges of each network.
+pub struct BundledCardBinDataSource;
+
+/// BIN ranges as `(prefix length, range start, range end, network)`.
+///
+/// The table is ordered from the most specific to the least specific range, the first match wins.
+const BUNDLED_CARD_BIN_RANGES: &[(usize, u32, u32, CardNetwork)] = &[
+    (4, 5018, 5018, CardNetwork::Maestro),
+    (4, 5020, 5020, CardNetwork::Maestro),
+    (4, 5038, 5038, CardNetwork::Maestro),
+    (4, 5893, 5893, CardNetwork::Maestro),
+    (4, 6304, 6304, CardNetwork::Maestro),
+    (4, 6759, 6759, CardNetwork::Maestro),
+    (4, 6761, 6763, CardNetwork::Maestro),
+    (4, 6521, 6522, CardNetwork::RuPay),
+    (4, 2221, 2720, CardNetwork::Mastercard),
+    (4, 3528, 3589, CardNetwork::JCB),
+    (4, 6011, 6011, CardNetwork::Discover),
+    (3, 300, 305, CardNetwork::DinersClub),
+    (3, 644, 649, CardNetwork::Discover),
+    (2, 34, 34, CardNetwork::AmericanExpress),
+    (2, 37, 37, CardNetwork::AmericanExpress),
+    (2, 36, 36, CardNetwork::DinersClub),
+    (2, 38, 39, CardNetwork::DinersClub),
+    (2, 51, 55, CardNetwork::Mastercard),
+    (2, 60, 60, CardNetwork::RuPay),
+    (2, 62, 62, CardNetwork::UnionPay),
+    (2, 65, 65, CardNetwork::Discover),
+    (1, 4, 4, CardNetwork::Visa),
+];
+
+pub fn get_bundled_card_network(card_iin: &str) -> Option<CardNetwork> {
+    if !card_iin.chars().all(|digit| digit.is_ascii_digit()) {
+        return None;
+    }
+
+    BUNDLED_CARD_BIN_RANGES
+        .iter()
+        .find(|(prefix_length, start, end, _)| {
+            card_iin
+                .get(..*prefix_length)
+                .and_then(|prefix| prefix.parse::<u32>().ok())
+                .is_some_and(|prefix| (*start..=*end).contains(&prefix))
+        })
+        .map(|(_, _, _, network)| network.clone())
+}
+
+pub fn get_bundled_card_bin_info(card_iin: &str) -> Option<CardInfo> {
+    get_bundled_card_network(card_iin).map(|card_network| CardInfo {
+        card_iin: card_iin.to_string(),
+        card_issuer: None,
+        card_network: Some(card_network),
+        card_type: None,
+        card_subtype: None,
+        card_issuing_country: None,
+        bank_code_id: None,
+        bank_code: None,
+        country_code: None,
+        date_created: common_utils::date_time::now(),
+        last_updated: None,
+        last_updated_provider: Some("bundled".to_string()),
+    })
+}
+
+#[async_trait::async_trait]
+impl CardBinDataSource for BundledCardBinDataSource {
+    fn name(&self) -> &'static str {
+        "bundled"
+    }
+
+    async fn get_card_bin_info(
+        &self,
+        _state: &AppState,
+        card_iin: &str,
+    ) -> RouterResult<Option<CardInfo>> {
+        Ok(get_bundled_card_bin_info(card_iin))
+    }
+}
+
+fn get_card_bin_data_sources(state: &AppState) -> Vec<Box<dyn CardBinDataSource + '_>> {
+    let mut sources: Vec<Box<dyn CardBinDataSource + '_>> = vec![Box::new(StoredCardBinDataSource)];
+    if let Some(provider) = state.conf.card_bin_lookup.external_provider.as_ref() {
+        sources.push(Box::new(ExternalCardBinDataSource { provider }));
+    }
+    sources.push(Box::new(BundledCardBinDataSource));
+    sources
+}
+
+/// Look up the card BIN across all the configured data sources.
+#[instrument(skip_all)]
+pub async fn get_card_bin_info(state: &AppState, card_iin: &str) -> RouterResult<Option<CardInfo>> {
+    for source in get_card_bin_data_sources(state) {
+        if let Some(card_info) = source.get_card_bin_info(state, card_iin).await? {
+            logger::debug!(
+                card_bin_source = source.name(),
+                "Card BIN information found"
+            );
+            return Ok(Some(card_info));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_card_network_detection() {
+        assert_eq!(get_bundled_card_network("424242"), Some(CardNetwork::Visa));
+        assert_eq!(
+            get_bundled_card_network("555555"),
+            Some(CardNetwork::Mastercard)
+        );
+        assert_eq!(
+            get_bundled_card_network("222300"),
+            Some(CardNetwork::Mastercard)
+        );
+        assert_eq!(
+            get_bundled_card_network("378282"),
+            Some(CardNetwork::AmericanExpress)
+        );
+        assert_eq!(
+            get_bundled_card_network("601111"),
+            Some(CardNetwork::Discover)
+        );
+        assert_eq!(get_bundled_card_network("353011"), Some(CardNetwork::JCB));
+        assert_eq!(
+            get_bundled_card_network("501800"),
+            Some(CardNetwork::Maestro)
+        );
+        assert_eq!(
+            get_bundled_card_network("620000"),
+            Some(CardNetwork::UnionPay)
+        );
+        assert_eq!(get_bundled_card_network("999999"), None);
+        assert_eq!(get_bundled_card_network("42a242"), None);
+    }
+}
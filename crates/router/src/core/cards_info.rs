@@ -1,9 +1,10 @@
 use common_utils::fp_utils::when;
-use error_stack::{report, ResultExt};
+use error_stack::report;
 use router_env::{instrument, tracing};
 
 use crate::{
     core::{
+        card_bin,
         errors::{self, RouterResponse},
         payments::helpers,
     },
@@ -35,11 +36,8 @@ pub async fn retrieve_card_info(
     )
     .await?;
 
-    let card_info = db
-        .get_card_info(&request.card_iin)
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to retrieve card information")?
+    let card_info = card_bin::get_card_bin_info(&state, &request.card_iin)
+        .await?
         .ok_or(report!(errors::ApiErrorResponse::InvalidCardIin))?;
 
     Ok(ApplicationResponse::Json(
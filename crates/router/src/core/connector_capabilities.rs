@@ -0,0 +1,117 @@
+use api_models::{
+    connector_capabilities::{
+        ConnectorCapabilitiesRequest, ConnectorCapabilitiesResponse, PaymentMethodCapability,
+    },
+    enums as api_enums,
+};
+use router_env::{instrument, tracing};
+use strum::IntoEnumIterator;
+
+use crate::{
+    configs::settings::PaymentMethodFilterKey,
+    core::errors::RouterResponse,
+    routes::AppState,
+    services::{ApplicationResponse, ConnectorValidation},
+    types::api,
+};
+
+/// The capture methods accepted by the connector implementation for the payment method type,
+/// excluding the one marked as not available in the payment method filters.
+fn get_supported_capture_methods(
+    connector: &(dyn api::Connector + Sync),
+    payment_method_type: Option<api_enums::PaymentMethodType>,
+    unavailable_capture_method: Option<api_enums::CaptureMethod>,
+) -> Vec<api_enums::CaptureMethod> {
+    api_enums::CaptureMethod::iter()
+        .filter(|capture_method| Some(*capture_method) != unavailable_capture_method)
+        .filter(|capture_method| {
+            connector
+                .validate_capture_method(Some(*capture_method), payment_method_type)
+                .is_ok()
+        })
+        .collect()
+}
+
+/// Builds the capabilities of a connector from its implementation, narrowed down by the payment
+/// method filters and mandate configuration the application was started with.
+#[instrument(skip_all)]
+pub async fn retrieve_connector_capabilities(
+    state: AppState,
+    req: ConnectorCapabilitiesRequest,
+) -> RouterResponse<ConnectorCapabilitiesResponse> {
+    let connector = req.connector;
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector.to_string(),
+        api::GetToken::Connector,
+        None,
+    )?;
+    let connector_implementation = *connector_data.connector;
+
+    let payment_methods = state
+        .conf
+        .pm_filters
+        .0
+        .get(&connector.to_string())
+        .map(|filters| {
+            filters
+                .0
+                .iter()
+                .map(|(key, filter)| {
+                    let (payment_method_type, card_network) = match key {
+                        PaymentMethodFilterKey::PaymentMethodType(payment_method_type) => {
+                            (Some(*payment_method_type), None)
+                        }
+                        PaymentMethodFilterKey::CardNetwork(card_network) => {
+                            (None, Some(card_network.clone()))
+                        }
+                    };
+                    PaymentMethodCapability {
+                        payment_method_type,
+                        card_network,
+                        currencies: filter
+                            .currency
+                            .as_ref()
+                            .map(|currencies| currencies.iter().copied().collect()),
+                        countries: filter
+                            .country
+                            .as_ref()
+                            .map(|countries| countries.iter().copied().collect()),
+                        supported_capture_methods: get_supported_capture_methods(
+                            connector_implementation,
+                            payment_method_type,
+                            filter
+                                .not_available_flows
+                                .and_then(|flows| flows.capture_method),
+                        ),
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mandate_payment_method_types = state
+        .conf
+        .mandates
+        .supported_payment_methods
+        .0
+        .values()
+        .flat_map(|payment_method_types| payment_method_types.0.iter())
+        .filter(|(_, connectors)| connectors.connector_list.contains(&connector))
+        .map(|(payment_method_type, _)| *payment_method_type)
+        .collect();
+
+    Ok(ApplicationResponse::Json(ConnectorCapabilitiesResponse {
+        connector,
+        payment_methods,
+        supported_capture_methods: get_supported_capture_methods(
+            connector_implementation,
+            None,
+            None,
+        ),
+        mandate_payment_method_types,
+        separate_authentication_supported: connector.is_separate_authentication_supported(),
+        webhook_source_verification_mandatory: connector_implementation
+            .is_webhook_source_verification_mandatory(),
+    }))
+}
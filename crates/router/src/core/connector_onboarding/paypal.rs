@@ -152,6 +152,7 @@ pub async fn update_mca(
     let request = MerchantConnectorUpdate {
         connector_type: common_enums::ConnectorType::PaymentProcessor,
         connector_account_details: Some(Secret::new(connector_auth_json)),
+        test_connector_account_details: None,
         disabled: Some(false),
         status: Some(common_enums::ConnectorStatus::Active),
         test_mode: None,
@@ -161,6 +162,7 @@ pub async fn update_mca(
         frm_configs: None,
         connector_webhook_details: None,
         pm_auth_config: None,
+        verify_credentials: None,
     };
     let mca_response =
         admin::update_payment_connector(state.clone(), &merchant_id, &connector_id, request)
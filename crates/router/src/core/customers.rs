@@ -1,16 +1,21 @@
 use common_utils::{
+    consts::{
+        CUSTOMERS_LIST_FILTER_BATCH_SIZE, CUSTOMERS_LIST_FILTER_MAX_BATCHES,
+        CUSTOMERS_LIST_MAX_LIMIT,
+    },
     crypto::{Encryptable, GcmAes256},
     errors::ReportSwitchExt,
 };
 use error_stack::{report, ResultExt};
 use masking::ExposeInterface;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 use crate::{
     core::{
         errors::{self, StorageErrorExt},
         payment_methods::cards,
     },
+    db::StorageInterface,
     pii::PeekInterface,
     routes::{metrics, AppState},
     services,
@@ -169,13 +174,47 @@ pub async fn list_customers(
     state: AppState,
     merchant_id: String,
     key_store: domain::MerchantKeyStore,
+    constraints: customers::CustomerListConstraints,
 ) -> errors::CustomerResponse<Vec<customers::CustomerResponse>> {
     let db = state.store.as_ref();
 
-    let domain_customers = db
-        .list_customers_by_merchant_id(&merchant_id, &key_store)
-        .await
-        .switch()?;
+    let limit = match constraints.limit {
+        Some(limit) if limit <= CUSTOMERS_LIST_MAX_LIMIT => Ok(limit),
+        Some(_) => Err(errors::CustomersErrorResponse::InvalidRequestData {
+            message: format!(
+                "`limit` must be a number less than or equal to {CUSTOMERS_LIST_MAX_LIMIT}"
+            ),
+        }),
+        None => Ok(CUSTOMERS_LIST_MAX_LIMIT),
+    }?;
+    let offset = constraints.offset.unwrap_or(0);
+
+    // Email, phone and metadata are stored encrypted, so these filters can only be applied after
+    // the records are fetched and decrypted, which is done in bounded batches.
+    let domain_customers = if constraints.has_decrypted_field_filters() {
+        list_customers_matching_decrypted_filters(
+            db,
+            &merchant_id,
+            &key_store,
+            &constraints,
+            limit,
+            offset,
+        )
+        .await?
+    } else {
+        let query_constraints = storage::CustomerListConstraints {
+            created_after: constraints.created_after,
+            created_before: constraints.created_before,
+            starting_after: constraints.starting_after.clone(),
+            ending_before: constraints.ending_before.clone(),
+            limit: Some(i64::from(limit)),
+            offset: Some(i64::from(offset)),
+        };
+
+        db.list_customers_by_merchant_id_constraints(&merchant_id, &query_constraints, &key_store)
+            .await
+            .switch()?
+    };
 
     let customers = domain_customers
         .into_iter()
@@ -185,6 +224,112 @@ pub async fn list_customers(
     Ok(services::ApplicationResponse::Json(customers))
 }
 
+/// Reads and decrypts customers in batches of `CUSTOMERS_LIST_FILTER_BATCH_SIZE` until the
+/// requested page is filled, so that a filter matching few customers does not decrypt every
+/// customer of the merchant. At most `CUSTOMERS_LIST_FILTER_MAX_BATCHES` batches are scanned; if
+/// the page is still not filled by then, an error asking to narrow down the request is returned,
+/// instead of a page that silently misses matching customers.
+async fn list_customers_matching_decrypted_filters(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    key_store: &domain::MerchantKeyStore,
+    constraints: &customers::CustomerListConstraints,
+    limit: u16,
+    offset: u16,
+) -> errors::CustomerResponse<Vec<domain::Customer>> {
+    // A page requested with only an `ending_before` cursor is the one closest to the cursor
+    let is_reversed = constraints.ending_before.is_some() && constraints.starting_after.is_none();
+    let required_count = usize::from(offset) + usize::from(limit);
+    let mut matching_customers = Vec::new();
+    let mut is_scan_complete = false;
+
+    for batch_number in 0..CUSTOMERS_LIST_FILTER_MAX_BATCHES {
+        let query_constraints = storage::CustomerListConstraints {
+            created_after: constraints.created_after,
+            created_before: constraints.created_before,
+            starting_after: constraints.starting_after.clone(),
+            ending_before: constraints.ending_before.clone(),
+            limit: Some(i64::from(CUSTOMERS_LIST_FILTER_BATCH_SIZE)),
+            offset: Some(i64::from(batch_number) * i64::from(CUSTOMERS_LIST_FILTER_BATCH_SIZE)),
+        };
+
+        let mut batch = db
+            .list_customers_by_merchant_id_constraints(merchant_id, &query_constraints, key_store)
+            .await
+            .switch()?;
+        let is_last_batch = batch.len() < usize::from(CUSTOMERS_LIST_FILTER_BATCH_SIZE);
+
+        // The batches are read away from the cursor, while each batch is in descending order
+        if is_reversed {
+            batch.reverse();
+        }
+
+        matching_customers.extend(
+            batch
+                .into_iter()
+                .filter(|customer| customer_matches_constraints(customer, constraints)),
+        );
+
+        if is_last_batch || matching_customers.len() >= required_count {
+            is_scan_complete = true;
+            break;
+        }
+    }
+
+    if !is_scan_complete {
+        logger::warn!(
+            merchant_id,
+            "Stopped filtering customers after scanning the maximum number of batches"
+        );
+        return Err(errors::CustomersErrorResponse::InvalidRequestData {
+            message: format!(
+                "Too many customers to filter by `email`, `phone` or `metadata_key`, narrow down \
+                the request to at most {} customers with `created_after`, `created_before`, \
+                `starting_after` or `ending_before`",
+                u32::from(CUSTOMERS_LIST_FILTER_MAX_BATCHES)
+                    * u32::from(CUSTOMERS_LIST_FILTER_BATCH_SIZE)
+            ),
+        });
+    }
+
+    let mut page = matching_customers
+        .into_iter()
+        .skip(usize::from(offset))
+        .take(usize::from(limit))
+        .collect::<Vec<_>>();
+    if is_reversed {
+        page.reverse();
+    }
+
+    Ok(page)
+}
+
+fn customer_matches_constraints(
+    customer: &domain::Customer,
+    constraints: &customers::CustomerListConstraints,
+) -> bool {
+    let email_matches = constraints.email.as_ref().map_or(true, |email| {
+        customer
+            .email
+            .as_ref()
+            .is_some_and(|customer_email| customer_email.get_inner().peek() == email.peek())
+    });
+    let phone_matches = constraints.phone.as_ref().map_or(true, |phone| {
+        customer
+            .phone
+            .as_ref()
+            .is_some_and(|customer_phone| customer_phone.get_inner().peek() == phone.peek())
+    });
+    let metadata_key_matches = constraints.metadata_key.as_ref().map_or(true, |key| {
+        customer
+            .metadata
+            .as_ref()
+            .is_some_and(|metadata| metadata.peek().get(key).is_some())
+    });
+
+    email_matches && phone_matches && metadata_key_matches
+}
+
 #[instrument(skip_all)]
 pub async fn delete_customer(
     state: AppState,
@@ -215,7 +360,22 @@ pub async fn delete_customer(
         }
     }
 
-    match db
+    let key = key_store.key.get_inner().peek();
+
+    let redacted_encrypted_value: Encryptable<masking::Secret<_>> =
+        Encryptable::encrypt(REDACTED.to_string().into(), key, GcmAes256)
+            .await
+            .switch()?;
+
+    let redacted_encrypted_email: Encryptable<
+        masking::Secret<_, common_utils::pii::EmailStrategy>,
+    > = Encryptable::encrypt(REDACTED.to_string().into(), key, GcmAes256)
+        .await
+        .switch()?;
+
+    // The payment methods are retained so that the payments made with them can still be
+    // resolved, only the stored card data and the details identifying the customer are removed
+    let payment_methods_redacted = match db
         .find_payment_method_by_customer_id_merchant_id_list(
             &req.customer_id,
             &merchant_account.merchant_id,
@@ -223,8 +383,17 @@ pub async fn delete_customer(
         )
         .await
     {
-        // check this in review
         Ok(customer_payment_methods) => {
+            let redacted_payment_method_data = types::encrypt(
+                masking::Secret::<_, masking::WithType>::new(serde_json::Value::String(
+                    REDACTED.to_string(),
+                )),
+                key,
+            )
+            .await
+            .switch()?;
+            let payment_methods_count = customer_payment_methods.len();
+
             for pm in customer_payment_methods.into_iter() {
                 if pm.payment_method == Some(enums::PaymentMethod::Card) {
                     cards::delete_card_from_locker(
@@ -236,17 +405,21 @@ pub async fn delete_customer(
                     .await
                     .switch()?;
                 }
-                db.delete_payment_method_by_merchant_id_payment_method_id(
-                    &merchant_account.merchant_id,
-                    &pm.payment_method_id,
-                )
-                .await
-                .switch()?;
+                let pm_update = storage::PaymentMethodUpdate::AdditionalDataUpdate {
+                    payment_method_data: Some(redacted_payment_method_data.clone().into()),
+                    status: Some(enums::PaymentMethodStatus::Inactive),
+                    locker_id: None,
+                    payment_method: None,
+                };
+                db.update_payment_method(pm, pm_update, merchant_account.storage_scheme)
+                    .await
+                    .switch()?;
             }
+            payment_methods_count
         }
         Err(error) => {
             if error.current_context().is_db_not_found() {
-                Ok(())
+                Ok(0)
             } else {
                 Err(error)
                     .change_context(errors::CustomersErrorResponse::InternalServerError)
@@ -255,19 +428,6 @@ pub async fn delete_customer(
         }
     };
 
-    let key = key_store.key.get_inner().peek();
-
-    let redacted_encrypted_value: Encryptable<masking::Secret<_>> =
-        Encryptable::encrypt(REDACTED.to_string().into(), key, GcmAes256)
-            .await
-            .switch()?;
-
-    let redacted_encrypted_email: Encryptable<
-        masking::Secret<_, common_utils::pii::EmailStrategy>,
-    > = Encryptable::encrypt(REDACTED.to_string().into(), key, GcmAes256)
-        .await
-        .switch()?;
-
     let update_address = storage::AddressUpdate::Update {
         city: Some(REDACTED.to_string()),
         country: None,
@@ -284,19 +444,19 @@ pub async fn delete_customer(
         email: Some(redacted_encrypted_email),
     };
 
-    match db
+    let addresses_redacted = match db
         .update_address_by_merchant_id_customer_id(
             &req.customer_id,
             &merchant_account.merchant_id,
-            update_address,
+            update_address.clone(),
             &key_store,
         )
         .await
     {
-        Ok(_) => Ok(()),
+        Ok(addresses) => Ok(addresses.len()),
         Err(error) => {
             if error.current_context().is_db_not_found() {
-                Ok(())
+                Ok(0)
             } else {
                 Err(error)
                     .change_context(errors::CustomersErrorResponse::InternalServerError)
@@ -305,6 +465,15 @@ pub async fn delete_customer(
         }
     }?;
 
+    let payments_redacted = redact_customer_payment_addresses(
+        &state,
+        &merchant_account,
+        &key_store,
+        &req.customer_id,
+        update_address,
+    )
+    .await?;
+
     let updated_customer = storage::CustomerUpdate::Update {
         name: Some(redacted_encrypted_value.clone()),
         email: Some(
@@ -334,12 +503,97 @@ pub async fn delete_customer(
         customer_id: req.customer_id,
         customer_deleted: true,
         address_deleted: true,
+        // The payment methods are retained, but as they are deactivated and their data is
+        // redacted, they are deleted as far as the API is concerned
         payment_methods_deleted: true,
+        redaction_report: customers::CustomerRedactionReport {
+            payment_methods_redacted,
+            addresses_redacted,
+            payments_redacted,
+        },
     };
     metrics::CUSTOMER_REDACTED.add(&metrics::CONTEXT, 1, &[]);
     Ok(services::ApplicationResponse::Json(response))
 }
 
+/// Redact the billing and shipping addresses of the payments made by the customer, including the
+/// payments that have been archived, the payments themselves are retained as is. Returns the number
+/// of payments which had an address redacted.
+async fn redact_customer_payment_addresses(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &str,
+    update_address: storage::AddressUpdate,
+) -> errors::CustomResult<usize, errors::CustomersErrorResponse> {
+    let db = state.store.as_ref();
+    let payment_intents = db
+        .find_payment_intents_by_merchant_id_customer_id(
+            &merchant_account.merchant_id,
+            customer_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::CustomersErrorResponse::InternalServerError)
+        .attach_printable("failed find_payment_intents_by_merchant_id_customer_id")?;
+    // Archived payments keep their addresses in the address table
+    let archived_payment_intents = db
+        .find_archived_payment_intents_by_merchant_id_customer_id(
+            &merchant_account.merchant_id,
+            customer_id,
+        )
+        .await
+        .change_context(errors::CustomersErrorResponse::InternalServerError)
+        .attach_printable("failed find_archived_payment_intents_by_merchant_id_customer_id")?;
+
+    let mut payments_redacted = 0;
+    for payment_intent in payment_intents.into_iter().chain(archived_payment_intents) {
+        let mut is_address_redacted = false;
+        for address_id in [
+            payment_intent.billing_address_id.as_ref(),
+            payment_intent.shipping_address_id.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            let address = match db
+                .find_address_by_merchant_id_payment_id_address_id(
+                    &merchant_account.merchant_id,
+                    &payment_intent.payment_id,
+                    address_id,
+                    key_store,
+                    merchant_account.storage_scheme,
+                )
+                .await
+            {
+                Ok(address) => address,
+                Err(error) if error.current_context().is_db_not_found() => continue,
+                Err(error) => Err(error)
+                    .change_context(errors::CustomersErrorResponse::InternalServerError)
+                    .attach_printable("failed find_address_by_merchant_id_payment_id_address_id")?,
+            };
+
+            db.update_address_for_payments(
+                address,
+                update_address.clone(),
+                payment_intent.payment_id.clone(),
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::CustomersErrorResponse::InternalServerError)
+            .attach_printable("failed update_address_for_payments")?;
+            is_address_redacted = true;
+        }
+
+        if is_address_redacted {
+            payments_redacted += 1;
+        }
+    }
+
+    Ok(payments_redacted)
+}
+
 #[instrument(skip(state))]
 pub async fn update_customer(
     state: AppState,
@@ -294,6 +294,10 @@ pub enum WebhooksFlowError {
     OutgoingWebhookRetrySchedulingFailed,
     #[error("Outgoing webhook response encoding failed")]
     OutgoingWebhookResponseEncodingFailed,
+    #[error("Failed to decrypt the custom http headers of the outgoing webhook")]
+    OutgoingWebhookHeadersDecryptionFailed,
+    #[error("Webhook endpoint not found or disabled")]
+    WebhookEndpointNotFound,
 }
 
 impl WebhooksFlowError {
@@ -302,7 +306,9 @@ impl WebhooksFlowError {
             Self::MerchantConfigNotFound
             | Self::MerchantWebhookDetailsNotFound
             | Self::MerchantWebhookUrlNotConfigured
-            | Self::OutgoingWebhookResponseEncodingFailed => false,
+            | Self::OutgoingWebhookResponseEncodingFailed
+            | Self::OutgoingWebhookHeadersDecryptionFailed
+            | Self::WebhookEndpointNotFound => false,
 
             Self::WebhookEventUpdationFailed
             | Self::OutgoingWebhookSigningFailed
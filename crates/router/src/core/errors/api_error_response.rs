@@ -182,6 +182,8 @@ pub enum ApiErrorResponse {
     PayoutNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Event does not exist in our records")]
     EventNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Webhook endpoint with the given id '{id}' does not exist in our records")]
+    WebhookEndpointNotFound { id: String },
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Invalid mandate id passed from connector")]
     MandateSerializationFailed,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Unable to parse the mandate identifier passed from connector")]
@@ -271,6 +273,12 @@ pub enum ApiErrorResponse {
     InvalidCookie,
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_27", message = "Extended card info does not exist")]
     ExtendedCardInfoNotFound,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_28", message = "Too many requests, please retry after {retry_after_secs} seconds")]
+    RateLimitExceeded { retry_after_secs: i64 },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_29", message = "The API key cannot be used from this IP address")]
+    IpAddressNotAllowed,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_30", message = "The resource was modified by another request, please retry the request")]
+    ConcurrentModification,
 }
 
 impl PTError for ApiErrorResponse {
@@ -190,6 +190,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::EventNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "Event does not exist in our records", None))
             }
+            Self::WebhookEndpointNotFound { id } => {
+                AER::NotFound(ApiError::new("HE", 2, format!("Webhook endpoint with the given id '{id}' does not exist in our records"), None))
+            }
             Self::ReturnUrlUnavailable => AER::NotFound(ApiError::new("HE", 3, "Return URL is not configured and not passed in payments request", None)),
             Self::RefundNotPossible { connector } => {
                 AER::BadRequest(ApiError::new("HE", 3, format!("This refund is not possible through Hyperswitch. Please raise the refund through {connector} dashboard"), None))
@@ -304,6 +307,15 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::ExtendedCardInfoNotFound => {
                 AER::NotFound(ApiError::new("IR", 27, "Extended card info does not exist", None))
             }
+            Self::RateLimitExceeded { retry_after_secs } => {
+                AER::TooManyRequests(ApiError::new("IR", 28, format!("Too many requests, please retry after {retry_after_secs} seconds"), None))
+            }
+            Self::IpAddressNotAllowed => {
+                AER::ForbiddenCommonResource(ApiError::new("IR", 29, "The API key cannot be used from this IP address", None))
+            }
+            Self::ConcurrentModification => {
+                AER::Conflict(ApiError::new("IR", 30, "The resource was modified by another request, please retry the request", None))
+            }
         }
     }
 }
@@ -357,6 +369,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for CustomersError
                 "Customer with the given `customer_id` already exists",
                 None,
             )),
+            Self::InvalidRequestData { message } => {
+                AER::BadRequest(ApiError::new("IR", 6, message.to_string(), None))
+            }
         }
     }
 }
@@ -57,6 +57,9 @@ impl<T> StorageErrorExt<T, errors::ApiErrorResponse>
                 hyperswitch_domain_models::errors::StorageError::CustomerRedacted => {
                     errors::ApiErrorResponse::CustomerRedacted
                 }
+                hyperswitch_domain_models::errors::StorageError::ConcurrentModification => {
+                    errors::ApiErrorResponse::ConcurrentModification
+                }
                 _ => errors::ApiErrorResponse::InternalServerError,
             };
             err.change_context(new_err)
@@ -0,0 +1,94 @@
+//! Lookups against the external providers configured for enriching payments, such as the GeoIP and
+//! card BIN providers.
+
+use std::fmt::Debug;
+
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::logger;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    configs::settings::ExternalLookupProvider,
+    core::errors::{self, RouterResult},
+    routes::AppState,
+    services,
+};
+
+/// Fetch the details of the key from `{base_url}/{lookup_key}` of the provider. A `404 Not Found`
+/// response is treated as a miss.
+pub async fn fetch_from_external_provider<T>(
+    state: &AppState,
+    provider: &ExternalLookupProvider,
+    lookup_key: &str,
+    timeout_in_secs: u64,
+) -> RouterResult<Option<T>>
+where
+    T: DeserializeOwned,
+{
+    let url = format!("{}/{}", provider.base_url.trim_end_matches('/'), lookup_key);
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Get)
+        .url(&url)
+        .header("api-key", provider.api_key.peek())
+        .build();
+
+    let response = state
+        .api_client
+        .send_request(state, request, Some(timeout_in_secs), false)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call the external lookup provider")?;
+
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+
+    response
+        .error_for_status()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("External lookup provider returned an error response")?
+        .json::<T>()
+        .await
+        .map(Some)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the external lookup provider response")
+}
+
+/// Look the key up in redis first, and with the provider when it is not cached. Misses are cached
+/// as well, so that the provider is called at most once per key within the TTL.
+pub async fn cached_fetch_from_external_provider<T>(
+    state: &AppState,
+    provider: &ExternalLookupProvider,
+    lookup_key: &str,
+    timeout_in_secs: u64,
+    cache_key: &str,
+    cache_ttl: i64,
+) -> RouterResult<Option<T>>
+where
+    T: DeserializeOwned + Serialize + Debug,
+{
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    if let Ok(cached_value) = redis_conn
+        .get_and_deserialize_key::<Option<T>>(cache_key, std::any::type_name::<T>())
+        .await
+    {
+        return Ok(cached_value);
+    }
+
+    let value = fetch_from_external_provider(state, provider, lookup_key, timeout_in_secs).await?;
+
+    if let Err(error) = redis_conn
+        .serialize_and_set_key_with_expiry(cache_key, &value, cache_ttl)
+        .await
+    {
+        logger::error!(?error, "Failed to cache the external lookup result");
+    }
+
+    Ok(value)
+}
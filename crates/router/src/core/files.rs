@@ -37,6 +37,7 @@ pub async fn files_create_core(
         connector_label: None,
         profile_id: None,
         merchant_connector_id: None,
+        test_mode: Some(state.test_mode),
     };
 
     let file_metadata_object = state
@@ -48,6 +48,7 @@ pub async fn construct_fulfillment_router_data<'a>(
         &profile_id,
         &connector,
         None,
+        payment_intent.test_mode.unwrap_or_default(),
     )
     .await?;
 
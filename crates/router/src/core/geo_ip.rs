@@ -0,0 +1,116 @@
+use std::net::IpAddr;
+
+use common_enums::CountryAlpha2;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings::ExternalLookupProvider,
+    consts,
+    core::{errors::RouterResult, external_lookup},
+    routes::AppState,
+};
+
+/// Timeout (in seconds) for the requests made to the external GeoIP provider
+const EXTERNAL_GEO_IP_PROVIDER_TIMEOUT: u64 = 2;
+
+/// A source of IP address geolocation.
+///
+/// Sources are consulted in order by [`get_ip_country`], the first one to return a match wins.
+#[async_trait::async_trait]
+pub trait GeoIpDataSource: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    async fn get_ip_country(
+        &self,
+        state: &AppState,
+        ip_address: IpAddr,
+    ) -> RouterResult<Option<CountryAlpha2>>;
+}
+
+fn get_geo_ip_cache_key(ip_address: IpAddr) -> String {
+    format!("geo_ip_{ip_address}")
+}
+
+/// Countries fetched from an external provider configured under `geo_ip_lookup`, cached in redis.
+pub struct ExternalGeoIpDataSource<'a> {
+    provider: &'a ExternalLookupProvider,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+struct ExternalGeoIpResponse {
+    country_code: Option<CountryAlpha2>,
+}
+
+#[async_trait::async_trait]
+impl GeoIpDataSource for ExternalGeoIpDataSource<'_> {
+    fn name(&self) -> &'static str {
+        "external"
+    }
+
+    /// Failures of the external provider are logged and treated as a miss, so that the lookup
+    /// falls through to the next source.
+    async fn get_ip_country(
+        &self,
+        state: &AppState,
+        ip_address: IpAddr,
+    ) -> RouterResult<Option<CountryAlpha2>> {
+        let response: Option<ExternalGeoIpResponse> =
+            external_lookup::cached_fetch_from_external_provider(
+                state,
+                self.provider,
+                &ip_address.to_string(),
+                EXTERNAL_GEO_IP_PROVIDER_TIMEOUT,
+                &get_geo_ip_cache_key(ip_address),
+                consts::GEO_IP_CACHE_TTL,
+            )
+            .await
+            .map_err(|error| logger::warn!(geo_ip_lookup_error=?error))
+            .ok()
+            .flatten();
+
+        Ok(response.and_then(|response| response.country_code))
+    }
+}
+
+fn get_geo_ip_data_sources(state: &AppState) -> Vec<Box<dyn GeoIpDataSource + '_>> {
+    let mut sources: Vec<Box<dyn GeoIpDataSource + '_>> = Vec::new();
+    if let Some(provider) = state.conf.geo_ip_lookup.external_provider.as_ref() {
+        sources.push(Box::new(ExternalGeoIpDataSource { provider }));
+    }
+    sources
+}
+
+fn is_public_ip_address(ip_address: IpAddr) -> bool {
+    match ip_address {
+        IpAddr::V4(ip) => {
+            !(ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast())
+        }
+        IpAddr::V6(ip) => !(ip.is_loopback() || ip.is_unspecified()),
+    }
+}
+
+/// Look up the country of the IP address across all the configured data sources. Failures are
+/// only logged, as the lookup must not affect the processing of the payment.
+#[instrument(skip_all)]
+pub async fn get_ip_country(state: &AppState, ip_address: IpAddr) -> Option<CountryAlpha2> {
+    if !is_public_ip_address(ip_address) {
+        return None;
+    }
+
+    for source in get_geo_ip_data_sources(state) {
+        match source.get_ip_country(state, ip_address).await {
+            Ok(Some(country)) => {
+                logger::debug!(geo_ip_source = source.name(), "IP address country found");
+                return Some(country);
+            }
+            Ok(None) => {}
+            Err(error) => logger::warn!(geo_ip_source = source.name(), ?error),
+        }
+    }
+
+    None
+}
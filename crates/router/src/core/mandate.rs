@@ -90,6 +90,7 @@ pub async fn revoke_mandate(
                 &profile_id,
                 &mandate.connector.clone(),
                 mandate.merchant_connector_id.as_ref(),
+                mandate.test_mode.unwrap_or_default(),
             )
             .await?;
 
@@ -445,6 +446,7 @@ where
                 get_insensitive_payment_method_data_if_exists(resp),
                 mandate_reference,
                 merchant_connector_id,
+                resp.test_mode,
             )?
             else {
                 return Ok(None);
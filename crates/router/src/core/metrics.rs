@@ -39,7 +39,13 @@ counter_metric!(WEBHOOK_SOURCE_VERIFIED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_RECEIVED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT, GLOBAL_METER);
+counter_metric!(WEBHOOK_OUTGOING_DEAD_LETTERED_COUNT, GLOBAL_METER);
+counter_metric!(
+    WEBHOOK_DEAD_LETTER_QUEUE_THRESHOLD_EXCEEDED_COUNT,
+    GLOBAL_METER
+);
 counter_metric!(WEBHOOK_PAYMENT_NOT_FOUND, GLOBAL_METER);
+counter_metric!(WEBHOOK_PAYMENT_SYNC_TRIGGERED_COUNT, GLOBAL_METER);
 counter_metric!(
     WEBHOOK_EVENT_TYPE_IDENTIFICATION_FAILURE_COUNT,
     GLOBAL_METER
@@ -63,6 +69,10 @@ counter_metric!(
 );
 counter_metric!(ROUTING_RETRIEVE_LINK_CONFIG, GLOBAL_METER);
 counter_metric!(ROUTING_RETRIEVE_LINK_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_SIMULATE, GLOBAL_METER);
+counter_metric!(ROUTING_SIMULATE_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_UPDATE_VOLUME_SPLIT, GLOBAL_METER);
+counter_metric!(ROUTING_UPDATE_VOLUME_SPLIT_SUCCESS_RESPONSE, GLOBAL_METER);
 counter_metric!(ROUTING_UNLINK_CONFIG, GLOBAL_METER);
 counter_metric!(ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
 counter_metric!(ROUTING_UPDATE_CONFIG, GLOBAL_METER);
@@ -0,0 +1,562 @@
+use api_models::payment_exports as api_payment_exports;
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::ResultExt;
+use external_services::file_storage::MultipartUpload;
+use hyperswitch_domain_models::payments::{
+    payment_attempt::PaymentAttempt,
+    payment_intent::{PaymentIntentFetchConstraints, PaymentIntentListParams},
+    PaymentIntent,
+};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::AppState,
+    services::{self, authentication as auth, jwt},
+    types::{domain, storage},
+    utils,
+};
+
+const PAYMENT_EXPORT_NAME: &str = "PAYMENT_EXPORT";
+const PAYMENT_EXPORT_TAG: &str = "PAYMENT_EXPORT";
+
+const PAYMENT_EXPORT_CSV_HEADER: [&str; 12] = [
+    "payment_id",
+    "status",
+    "amount",
+    "currency",
+    "amount_captured",
+    "customer_id",
+    "connector",
+    "payment_method",
+    "payment_method_type",
+    "profile_id",
+    "created_at",
+    "modified_at",
+];
+
+/// Audience of the payment export download tokens, which prevents other tokens signed with the
+/// same secret from being used to download exports and vice versa
+const PAYMENT_EXPORT_DOWNLOAD_TOKEN_AUDIENCE: &str = "payment_export_download";
+
+/// Validity of the presigned URLs to which export downloads are redirected, which only needs to
+/// cover the redirect itself
+const PAYMENT_EXPORT_PRESIGNED_URL_VALIDITY: std::time::Duration =
+    std::time::Duration::from_secs(60);
+
+/// Characters which make spreadsheet applications interpret a field as a formula when it starts
+/// with one of them
+const CSV_FORMULA_PREFIXES: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+/// Claims of the token in the download URL of an export, which lets the export file be downloaded
+/// without authenticating until the token expires.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct PaymentExportDownloadToken {
+    export_id: String,
+    merchant_id: String,
+    aud: String,
+    exp: u64,
+}
+
+#[instrument(skip_all)]
+pub async fn create_payment_export(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    mut request: api_payment_exports::PaymentExportRequest,
+) -> RouterResponse<api_payment_exports::PaymentExportResponse> {
+    let now = common_utils::date_time::now();
+    let end_time = *request.time_range.end_time.get_or_insert(now);
+    if end_time < request.time_range.start_time {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "end_time must not be earlier than start_time".to_string(),
+        }
+        .into());
+    }
+    if request.format == storage::enums::PaymentExportFormat::Parquet {
+        return Err(errors::ApiErrorResponse::NotSupported {
+            message: "Payment exports in the parquet format are not supported, use the csv format"
+                .to_string(),
+        }
+        .into());
+    }
+
+    let filters = request
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize payment export filters")?;
+    let payment_export = state
+        .store
+        .insert_payment_export(storage::PaymentExportNew {
+            export_id: utils::generate_id(consts::ID_LENGTH, "export"),
+            merchant_id: merchant_account.merchant_id.clone(),
+            status: storage::enums::PaymentExportStatus::Pending,
+            format: request.format,
+            filters,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payment export")?;
+
+    add_payment_export_task(&*state.store, &payment_export)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add payment export task to process tracker")?;
+
+    get_payment_export_response(&state, payment_export)
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payment_export(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    request: api_payment_exports::PaymentExportId,
+) -> RouterResponse<api_payment_exports::PaymentExportResponse> {
+    let payment_export = state
+        .store
+        .find_payment_export_by_merchant_id_export_id(
+            &merchant_account.merchant_id,
+            &request.export_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payment export does not exist in our records".to_string(),
+        })?;
+
+    get_payment_export_response(&state, payment_export)
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn download_payment_export(
+    state: AppState,
+    request: api_payment_exports::PaymentExportDownloadRequest,
+) -> RouterResponse<serde_json::Value> {
+    let token: PaymentExportDownloadToken = auth::decode_jwt_with_audience(
+        &request.token,
+        &state,
+        PAYMENT_EXPORT_DOWNLOAD_TOKEN_AUDIENCE,
+    )
+    .await?;
+    if token.export_id != request.export_id {
+        return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
+    }
+
+    let payment_export = state
+        .store
+        .find_payment_export_by_merchant_id_export_id(&token.merchant_id, &token.export_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payment export does not exist in our records".to_string(),
+        })?;
+    let file_key = payment_export
+        .file_key
+        .ok_or(errors::ApiErrorResponse::FileNotAvailable)
+        .attach_printable("Payment export file has not been generated")?;
+
+    // Exports can be large, so they are downloaded directly from the file storage when it
+    // supports it, instead of being loaded into memory and sent through the router
+    let presigned_url = state
+        .file_storage_client
+        .get_presigned_url(&file_key, PAYMENT_EXPORT_PRESIGNED_URL_VALIDITY)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate payment export download URL")?;
+    if let Some(presigned_url) = presigned_url {
+        return Ok(services::ApplicationResponse::JsonForRedirection(
+            api_models::payments::RedirectionResponse {
+                return_url: String::new(),
+                params: vec![],
+                return_url_with_query_params: presigned_url,
+                http_method: "GET".to_string(),
+                headers: vec![],
+            },
+        ));
+    }
+
+    let file_data = state
+        .file_storage_client
+        .retrieve_file(&file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve payment export file")?;
+
+    let content_type = match payment_export.format {
+        storage::enums::PaymentExportFormat::Csv => mime::TEXT_CSV,
+        storage::enums::PaymentExportFormat::Parquet => mime::APPLICATION_OCTET_STREAM,
+    };
+    Ok(services::ApplicationResponse::FileData((
+        file_data,
+        content_type,
+    )))
+}
+
+/// Generates the file of a pending export and stores it with the configured file storage backend.
+/// Exports that are no longer pending are left untouched.
+#[instrument(skip_all)]
+pub async fn generate_payment_export(
+    state: &AppState,
+    tracking_data: &storage::PaymentExportTrackingData,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let payment_export = db
+        .find_payment_export_by_merchant_id_export_id(
+            &tracking_data.merchant_id,
+            &tracking_data.export_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payment export")?;
+    if payment_export.status != storage::enums::PaymentExportStatus::Pending {
+        return Ok(());
+    }
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &payment_export.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant key store")?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&payment_export.merchant_id, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant account")?;
+    let request: api_payment_exports::PaymentExportRequest = payment_export
+        .filters
+        .clone()
+        .parse_value("PaymentExportRequest")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let file_key = format!(
+        "exports/{}/{}.{}",
+        payment_export.merchant_id, payment_export.export_id, payment_export.format
+    );
+    let mut upload = state
+        .file_storage_client
+        .start_multipart_upload(&file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to start payment export file upload")?;
+
+    let row_count = match write_payment_export_file(
+        state,
+        &merchant_account,
+        &request,
+        upload.as_mut(),
+    )
+    .await
+    {
+        Ok(row_count) => row_count,
+        Err(error) => {
+            upload
+                .abort()
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to abort payment export file upload")
+                })
+                .ok();
+            return Err(error);
+        }
+    };
+    upload
+        .complete()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload payment export file")?;
+
+    db.update_payment_export_by_merchant_id_export_id(
+        &payment_export.merchant_id,
+        &payment_export.export_id,
+        storage::PaymentExportUpdate::Succeeded {
+            file_key,
+            row_count,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update payment export")?;
+
+    Ok(())
+}
+
+/// Writes the payments matching the filters of the export to the file one page at a time, so that
+/// the export is never held in memory in its entirety, and returns the number of payments written.
+async fn write_payment_export_file(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    request: &api_payment_exports::PaymentExportRequest,
+    upload: &mut dyn MultipartUpload,
+) -> RouterResult<i64> {
+    let batch_size = state.conf.payment_export.batch_size;
+    let mut page = Vec::new();
+    write_csv_record(&mut page, PAYMENT_EXPORT_CSV_HEADER.map(String::from));
+    let mut row_count: i64 = 0;
+    let mut starting_after_id = None;
+
+    loop {
+        let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+            offset: 0,
+            starting_at: Some(request.time_range.start_time),
+            ending_at: request.time_range.end_time,
+            amount_filter: None,
+            connector: request.connector.clone(),
+            currency: None,
+            status: request.status.clone(),
+            payment_method: None,
+            payment_method_type: None,
+            authentication_type: None,
+            merchant_connector_id: None,
+            profile_id: None,
+            customer_id: None,
+            starting_after_id: starting_after_id.take(),
+            ending_before_id: None,
+            limit: Some(batch_size),
+        }));
+        let payments = state
+            .store
+            .get_filtered_payment_intents_attempt(
+                &merchant_account.merchant_id,
+                &constraints,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch payments for export")?;
+        let is_last_batch =
+            u32::try_from(payments.len()).map_or(true, |payment_count| payment_count < batch_size);
+        starting_after_id = payments
+            .last()
+            .map(|(payment_intent, _)| payment_intent.payment_id.clone());
+
+        for (payment_intent, payment_attempt) in payments {
+            write_csv_record(
+                &mut page,
+                get_payment_export_record(&payment_intent, &payment_attempt)?,
+            );
+            row_count = row_count.saturating_add(1);
+        }
+
+        upload
+            .write_part(std::mem::take(&mut page))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to upload payment export file")?;
+
+        if is_last_batch {
+            break;
+        }
+    }
+
+    Ok(row_count)
+}
+
+#[instrument(skip_all)]
+pub async fn fail_payment_export(
+    state: &AppState,
+    tracking_data: &storage::PaymentExportTrackingData,
+) -> RouterResult<()> {
+    state
+        .store
+        .update_payment_export_by_merchant_id_export_id(
+            &tracking_data.merchant_id,
+            &tracking_data.export_id,
+            storage::PaymentExportUpdate::Failed,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payment export")?;
+
+    Ok(())
+}
+
+async fn add_payment_export_task(
+    db: &dyn StorageInterface,
+    payment_export: &storage::PaymentExport,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = storage::PaymentExportTrackingData {
+        export_id: payment_export.export_id.clone(),
+        merchant_id: payment_export.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        format!("{PAYMENT_EXPORT_NAME}_{}", payment_export.export_id),
+        PAYMENT_EXPORT_NAME,
+        storage::ProcessTrackerRunner::PaymentExportWorkflow,
+        [PAYMENT_EXPORT_TAG],
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+    .attach_printable("Failed to construct payment export process tracker task")?;
+
+    db.insert_process(process_tracker_entry).await?;
+
+    Ok(())
+}
+
+async fn get_payment_export_response(
+    state: &AppState,
+    payment_export: storage::PaymentExport,
+) -> RouterResult<api_payment_exports::PaymentExportResponse> {
+    let (download_url, download_url_expires_at) = match payment_export.status {
+        storage::enums::PaymentExportStatus::Succeeded => {
+            let expires_at = common_utils::date_time::now().saturating_add(
+                time::Duration::seconds(state.conf.payment_export.download_url_validity_in_secs),
+            );
+            let exp = u64::try_from(expires_at.assume_utc().unix_timestamp())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid payment export download URL expiry")?;
+            let token = jwt::generate_jwt(
+                &PaymentExportDownloadToken {
+                    export_id: payment_export.export_id.clone(),
+                    merchant_id: payment_export.merchant_id.clone(),
+                    aud: PAYMENT_EXPORT_DOWNLOAD_TOKEN_AUDIENCE.to_owned(),
+                    exp,
+                },
+                &state.conf,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to generate payment export download token")?;
+
+            (
+                Some(format!(
+                    "{}/exports/payments/{}/download?token={token}",
+                    state.conf.server.base_url, payment_export.export_id
+                )),
+                Some(expires_at),
+            )
+        }
+        storage::enums::PaymentExportStatus::Pending
+        | storage::enums::PaymentExportStatus::Failed => (None, None),
+    };
+
+    Ok(api_payment_exports::PaymentExportResponse {
+        export_id: payment_export.export_id,
+        status: payment_export.status,
+        format: payment_export.format,
+        row_count: payment_export.row_count,
+        download_url,
+        download_url_expires_at,
+        created_at: payment_export.created_at,
+    })
+}
+
+fn get_payment_export_record(
+    payment_intent: &PaymentIntent,
+    payment_attempt: &PaymentAttempt,
+) -> RouterResult<[String; 12]> {
+    let format_timestamp = |timestamp: time::PrimitiveDateTime| {
+        timestamp
+            .assume_utc()
+            .format(&time::format_description::well_known::Rfc3339)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to format payment timestamp")
+    };
+
+    Ok([
+        payment_intent.payment_id.clone(),
+        payment_intent.status.to_string(),
+        payment_intent.amount.to_string(),
+        payment_intent
+            .currency
+            .map(|currency| currency.to_string())
+            .unwrap_or_default(),
+        payment_intent
+            .amount_captured
+            .map(|amount_captured| amount_captured.to_string())
+            .unwrap_or_default(),
+        payment_intent.customer_id.clone().unwrap_or_default(),
+        payment_attempt.connector.clone().unwrap_or_default(),
+        payment_attempt
+            .payment_method
+            .map(|payment_method| payment_method.to_string())
+            .unwrap_or_default(),
+        payment_attempt
+            .payment_method_type
+            .map(|payment_method_type| payment_method_type.to_string())
+            .unwrap_or_default(),
+        payment_intent.profile_id.clone().unwrap_or_default(),
+        format_timestamp(payment_intent.created_at)?,
+        format_timestamp(payment_intent.modified_at)?,
+    ])
+}
+
+/// Appends a record to a CSV file, quoting the fields that contain separators or quotes. Fields
+/// that spreadsheet applications would interpret as formulas are prefixed with a single quote, so
+/// that they are displayed as text instead.
+fn write_csv_record<const N: usize>(file: &mut Vec<u8>, fields: [String; N]) {
+    let record = fields
+        .into_iter()
+        .map(|field| {
+            if field.starts_with(CSV_FORMULA_PREFIXES) {
+                format!("'{field}")
+            } else {
+                field
+            }
+        })
+        .map(|field| {
+            if field.contains([',', '"', '\r', '\n']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    file.extend_from_slice(record.as_bytes());
+    file.extend_from_slice(b"\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_csv_record<const N: usize>(fields: [&str; N]) -> String {
+        let mut file = Vec::new();
+        write_csv_record(&mut file, fields.map(String::from));
+        String::from_utf8(file).unwrap()
+    }
+
+    #[test]
+    fn test_write_csv_record_quotes_special_characters() {
+        assert_eq!(
+            get_csv_record(["pay_123", "succeeded"]),
+            "pay_123,succeeded\r\n"
+        );
+        assert_eq!(get_csv_record(["a,b", "c"]), "\"a,b\",c\r\n");
+        assert_eq!(
+            get_csv_record(["say \"hi\"", "line\nbreak"]),
+            "\"say \"\"hi\"\"\",\"line\nbreak\"\r\n"
+        );
+    }
+
+    #[test]
+    fn test_write_csv_record_escapes_formulas() {
+        assert_eq!(
+            get_csv_record(["=SUM(A1:A2)", "+1", "-1", "@cmd", "1-1"]),
+            "'=SUM(A1:A2),'+1,'-1,'@cmd,1-1\r\n"
+        );
+        assert_eq!(
+            get_csv_record(["=HYPERLINK(\"x\",\"y\")"]),
+            "\"'=HYPERLINK(\"\"x\"\",\"\"y\"\")\"\r\n"
+        );
+    }
+
+    #[test]
+    fn test_write_csv_record_appends_records() {
+        let mut file = Vec::new();
+        write_csv_record(&mut file, ["a".to_string()]);
+        write_csv_record(&mut file, ["b".to_string()]);
+        assert_eq!(String::from_utf8(file).unwrap(), "a\r\nb\r\n");
+    }
+}
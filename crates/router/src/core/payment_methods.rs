@@ -1,3 +1,4 @@
+pub mod account_updater;
 pub mod cards;
 pub mod surcharge_decision_configs;
 pub mod transformers;
@@ -0,0 +1,568 @@
+use api_models::payment_methods::{Card, CardDetailsPaymentMethod, PaymentMethodsData};
+use cards::CardNumber;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings::CardAccountUpdaterService,
+    core::{
+        card_bin,
+        errors::{self, RouterResult, StorageErrorExt},
+        payment_methods::{cards as pm_cards, transformers as payment_methods},
+    },
+    db::StorageInterface,
+    routes::{metrics, AppState},
+    services,
+    types::{api, domain, storage},
+};
+
+const CARD_ACCOUNT_UPDATER_TAG: &str = "PAYMENT_METHOD";
+const CARD_ACCOUNT_UPDATER_NAME: &str = "CARD_ACCOUNT_UPDATER";
+const CARD_ACCOUNT_UPDATER_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::CardAccountUpdaterWorkflow;
+
+/// Number of days between two submissions of a saved card to the account updater service
+pub const CARD_ACCOUNT_UPDATER_INTERVAL_IN_DAYS: i64 = 30;
+
+/// Timeout (in seconds) for the requests made to the account updater service
+const CARD_ACCOUNT_UPDATER_TIMEOUT: u64 = 10;
+
+/// Merchants opt in to the account updater by setting this config to `true`
+pub fn get_card_account_updater_config_key(merchant_id: &str) -> String {
+    format!("{merchant_id}_card_account_updater_enabled")
+}
+
+fn generate_task_id_for_card_account_updater_workflow(payment_method_id: &str) -> String {
+    format!("{CARD_ACCOUNT_UPDATER_RUNNER}_{CARD_ACCOUNT_UPDATER_NAME}_{payment_method_id}")
+}
+
+/// Schedule the periodic account updater task for a newly saved card, if the merchant has opted in
+#[instrument(skip_all)]
+pub async fn add_card_account_updater_task(
+    db: &dyn StorageInterface,
+    payment_method: &storage::PaymentMethod,
+) -> RouterResult<()> {
+    let is_card = payment_method.payment_method == Some(storage::enums::PaymentMethod::Card);
+    if !is_card || payment_method.status != storage::enums::PaymentMethodStatus::Active {
+        return Ok(());
+    }
+
+    let is_account_updater_enabled = db
+        .find_config_by_key_unwrap_or(
+            &get_card_account_updater_config_key(&payment_method.merchant_id),
+            Some("false".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the card account updater config")?
+        .config
+        == "true";
+
+    if !is_account_updater_enabled {
+        return Ok(());
+    }
+
+    let tracking_data = storage::CardAccountUpdaterTrackingData {
+        payment_method_id: payment_method.payment_method_id.clone(),
+        merchant_id: payment_method.merchant_id.clone(),
+    };
+    let schedule_time = common_utils::date_time::now()
+        .saturating_add(time::Duration::days(CARD_ACCOUNT_UPDATER_INTERVAL_IN_DAYS));
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        generate_task_id_for_card_account_updater_workflow(&payment_method.payment_method_id),
+        CARD_ACCOUNT_UPDATER_NAME,
+        CARD_ACCOUNT_UPDATER_RUNNER,
+        [CARD_ACCOUNT_UPDATER_TAG],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct card account updater process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting card account updater task to process_tracker: payment_method_id: {}",
+                payment_method.payment_method_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "flow",
+            "CardAccountUpdater",
+        )],
+    );
+
+    Ok(())
+}
+
+#[derive(Debug, serde::Serialize)]
+struct CardAccountUpdaterRequest {
+    merchant_id: String,
+    payment_method_id: String,
+    card_number: CardNumber,
+    card_exp_month: Secret<String>,
+    card_exp_year: Secret<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum CardAccountUpdaterResponse {
+    NoChange,
+    Updated {
+        card_number: Option<CardNumber>,
+        card_exp_month: Option<Secret<String>>,
+        card_exp_year: Option<Secret<String>>,
+    },
+    Closed,
+}
+
+async fn call_card_account_updater_service(
+    state: &AppState,
+    service: &CardAccountUpdaterService,
+    request: CardAccountUpdaterRequest,
+) -> RouterResult<CardAccountUpdaterResponse> {
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(&format!(
+            "{}/cards/update",
+            service.base_url.trim_end_matches('/')
+        ))
+        .attach_default_headers()
+        .header("api-key", service.api_key.peek())
+        .set_body(common_utils::request::RequestContent::Json(Box::new(
+            request,
+        )))
+        .build();
+
+    state
+        .api_client
+        .send_request(state, request, Some(CARD_ACCOUNT_UPDATER_TIMEOUT), false)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call the card account updater service")?
+        .error_for_status()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Card account updater service returned an error response")?
+        .json::<CardAccountUpdaterResponse>()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the card account updater service response")
+}
+
+/// Submit a saved card to the account updater service and apply the result to the payment method.
+///
+/// Returns whether the card should be submitted again after the next interval.
+#[instrument(skip_all)]
+pub async fn execute_card_account_update(
+    state: &AppState,
+    tracking_data: &storage::CardAccountUpdaterTrackingData,
+) -> RouterResult<bool> {
+    let Some(service) = state.conf.card_account_updater.service.as_ref() else {
+        logger::warn!("Card account updater service is not configured");
+        return Ok(false);
+    };
+    let db = &*state.store;
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let payment_method = match db
+        .find_payment_method(
+            &tracking_data.payment_method_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_method) => payment_method,
+        Err(error) if error.current_context().is_db_not_found() => {
+            logger::info!("Payment method has been deleted, stopping card account updates");
+            return Ok(false);
+        }
+        Err(error) => {
+            return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+        }
+    };
+
+    if payment_method.status != storage::enums::PaymentMethodStatus::Active {
+        logger::info!("Payment method is no longer active, stopping card account updates");
+        return Ok(false);
+    }
+
+    let locker_id = payment_method
+        .locker_id
+        .clone()
+        .unwrap_or(payment_method.payment_method_id.clone());
+    let card_from_locker = pm_cards::get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        &locker_id,
+    )
+    .await?;
+
+    let response = call_card_account_updater_service(
+        state,
+        service,
+        CardAccountUpdaterRequest {
+            merchant_id: merchant_account.merchant_id.clone(),
+            payment_method_id: payment_method.payment_method_id.clone(),
+            card_number: card_from_locker.card_number.clone(),
+            card_exp_month: card_from_locker.card_exp_month.clone(),
+            card_exp_year: card_from_locker.card_exp_year.clone(),
+        },
+    )
+    .await?;
+
+    match response {
+        CardAccountUpdaterResponse::NoChange => Ok(true),
+        CardAccountUpdaterResponse::Closed => {
+            let payment_method = db
+                .update_payment_method(
+                    payment_method,
+                    storage::PaymentMethodUpdate::StatusUpdate {
+                        status: Some(storage::enums::PaymentMethodStatus::Inactive),
+                    },
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to mark the closed card as inactive")?;
+
+            pm_cards::trigger_payment_method_webhook(
+                state,
+                &merchant_account,
+                &key_store,
+                &payment_method,
+                None,
+                storage::enums::EventType::PaymentMethodClosed,
+            )
+            .await?;
+
+            Ok(false)
+        }
+        CardAccountUpdaterResponse::Updated {
+            card_number,
+            card_exp_month,
+            card_exp_year,
+        } => {
+            let updated_card = build_updated_card(
+                &card_from_locker,
+                card_number,
+                card_exp_month,
+                card_exp_year,
+            );
+            let previous_card = build_updated_card(&card_from_locker, None, None, None);
+
+            apply_card_account_update(
+                state,
+                &merchant_account,
+                &key_store,
+                payment_method,
+                &locker_id,
+                previous_card,
+                updated_card,
+            )
+            .await?;
+
+            Ok(true)
+        }
+    }
+}
+
+/// Details of the updated card, falling back to the stored card for the fields the account updater
+/// service did not return
+fn build_updated_card(
+    card_from_locker: &Card,
+    card_number: Option<CardNumber>,
+    card_exp_month: Option<Secret<String>>,
+    card_exp_year: Option<Secret<String>>,
+) -> api::CardDetail {
+    api::CardDetail {
+        card_number: card_number.unwrap_or(card_from_locker.card_number.clone()),
+        card_exp_month: card_exp_month.unwrap_or(card_from_locker.card_exp_month.clone()),
+        card_exp_year: card_exp_year.unwrap_or(card_from_locker.card_exp_year.clone()),
+        card_holder_name: card_from_locker.name_on_card.clone(),
+        nick_name: card_from_locker.nick_name.clone().map(Secret::new),
+        card_issuing_country: None,
+        card_network: None,
+        card_issuer: None,
+        card_type: None,
+    }
+}
+
+/// The locker reference of the previous card, if the payment method no longer points to it once
+/// the updated card is stored under `new_locker_id`
+fn get_replaced_locker_id<'a>(previous_locker_id: &'a str, new_locker_id: &str) -> Option<&'a str> {
+    (previous_locker_id != new_locker_id).then_some(previous_locker_id)
+}
+
+async fn apply_card_account_update(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: storage::PaymentMethod,
+    locker_id: &str,
+    previous_card: api::CardDetail,
+    updated_card: api::CardDetail,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let existing_card = pm_cards::get_card_details_without_locker_fallback(
+        &payment_method,
+        key_store.key.get_inner().peek(),
+        state,
+    )
+    .await?;
+
+    let card_isin = updated_card.card_number.clone().get_card_isin();
+    let card_info = card_bin::get_card_bin_info(state, &card_isin).await?;
+
+    let new_pm = api::PaymentMethodCreate {
+        payment_method: payment_method.payment_method,
+        payment_method_type: payment_method.payment_method_type,
+        payment_method_issuer: payment_method.payment_method_issuer.clone(),
+        payment_method_issuer_code: payment_method.payment_method_issuer_code,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card: Some(updated_card.clone()),
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        metadata: None,
+        customer_id: Some(payment_method.customer_id.clone()),
+        client_secret: None,
+        payment_method_data: None,
+        card_network: None,
+    };
+
+    // The updated card is stored before the previous one is deleted, so that the payment method
+    // always points to a card in the locker
+    let (locker_response, duplication_check) = pm_cards::add_card_to_locker(
+        state,
+        new_pm.clone(),
+        &updated_card,
+        &payment_method.customer_id,
+        merchant_account,
+        None,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to add the updated card to locker")?;
+    let new_locker_id = locker_response.payment_method_id;
+
+    // The locker identifies cards by their number and can't update a stored card, so when only the
+    // expiry has changed the card has to be replaced under the same reference. The previous card
+    // is stored back if the updated one can't be, so that the payment method never points to a
+    // card that is missing from the locker.
+    if duplication_check == Some(payment_methods::DataDuplicationCheck::MetaDataChanged) {
+        pm_cards::delete_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            &new_locker_id,
+        )
+        .await?;
+
+        if let Err(error) = pm_cards::add_card_to_locker(
+            state,
+            new_pm.clone(),
+            &updated_card,
+            &payment_method.customer_id,
+            merchant_account,
+            Some(&new_locker_id),
+        )
+        .await
+        {
+            pm_cards::add_card_to_locker(
+                state,
+                api::PaymentMethodCreate {
+                    card: Some(previous_card.clone()),
+                    ..new_pm
+                },
+                &previous_card,
+                &payment_method.customer_id,
+                merchant_account,
+                Some(&new_locker_id),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to restore the previous card in locker")?;
+
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to replace the card in locker");
+        }
+    }
+
+    let card = api::CardDetailFromLocker {
+        last4_digits: Some(updated_card.card_number.clone().get_last4()),
+        expiry_month: Some(updated_card.card_exp_month.clone()),
+        expiry_year: Some(updated_card.card_exp_year.clone()),
+        card_isin: Some(card_isin),
+        card_network: card_info
+            .as_ref()
+            .and_then(|card_info| card_info.card_network.clone())
+            .or(existing_card.card_network.clone()),
+        card_issuer: card_info
+            .as_ref()
+            .and_then(|card_info| card_info.card_issuer.clone())
+            .or(existing_card.card_issuer.clone()),
+        card_type: card_info
+            .as_ref()
+            .and_then(|card_info| card_info.card_type.clone())
+            .or(existing_card.card_type.clone()),
+        issuer_country: card_info
+            .and_then(|card_info| card_info.card_issuing_country)
+            .or(existing_card.issuer_country.clone()),
+        saved_to_locker: true,
+        ..existing_card
+    };
+
+    let payment_method_data = pm_cards::create_encrypted_payment_method_data(
+        key_store,
+        Some(PaymentMethodsData::Card(CardDetailsPaymentMethod::from(
+            card.clone(),
+        ))),
+    )
+    .await;
+
+    let payment_method = db
+        .update_payment_method(
+            payment_method,
+            storage::PaymentMethodUpdate::AdditionalDataUpdate {
+                payment_method_data,
+                status: None,
+                locker_id: Some(new_locker_id.clone()),
+                payment_method: None,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the payment method with the updated card")?;
+
+    if let Some(replaced_locker_id) = get_replaced_locker_id(locker_id, &new_locker_id) {
+        pm_cards::delete_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            replaced_locker_id,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to delete the replaced card from locker"))
+        .ok();
+    }
+
+    pm_cards::trigger_payment_method_webhook(
+        state,
+        merchant_account,
+        key_store,
+        &payment_method,
+        Some(card),
+        storage::enums::EventType::PaymentMethodUpdated,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn card_from_locker() -> Card {
+        Card {
+            card_number: CardNumber::from_str("4111111111111111").unwrap(),
+            name_on_card: Some(Secret::new("John Doe".to_string())),
+            card_exp_month: Secret::new("10".to_string()),
+            card_exp_year: Secret::new("2025".to_string()),
+            card_brand: None,
+            card_isin: None,
+            nick_name: Some("Personal".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_card_account_updater_response_deserialization() {
+        let response: CardAccountUpdaterResponse =
+            serde_json::from_str(r#"{"status":"no_change"}"#).unwrap();
+        assert!(matches!(response, CardAccountUpdaterResponse::NoChange));
+
+        let response: CardAccountUpdaterResponse =
+            serde_json::from_str(r#"{"status":"closed"}"#).unwrap();
+        assert!(matches!(response, CardAccountUpdaterResponse::Closed));
+
+        let response: CardAccountUpdaterResponse =
+            serde_json::from_str(r#"{"status":"updated","card_exp_year":"2028"}"#).unwrap();
+        assert!(matches!(
+            response,
+            CardAccountUpdaterResponse::Updated {
+                card_number: None,
+                card_exp_month: None,
+                card_exp_year: Some(ref card_exp_year),
+            } if card_exp_year.peek() == "2028"
+        ));
+    }
+
+    #[test]
+    fn test_build_updated_card_with_new_expiry() {
+        let updated_card = build_updated_card(
+            &card_from_locker(),
+            None,
+            Some(Secret::new("12".to_string())),
+            Some(Secret::new("2028".to_string())),
+        );
+
+        assert_eq!(
+            updated_card.card_number.get_card_no(),
+            "4111111111111111".to_string()
+        );
+        assert_eq!(updated_card.card_exp_month.peek(), "12");
+        assert_eq!(updated_card.card_exp_year.peek(), "2028");
+        assert_eq!(updated_card.card_holder_name.unwrap().peek(), "John Doe");
+        assert_eq!(updated_card.nick_name.unwrap().peek(), "Personal");
+    }
+
+    #[test]
+    fn test_build_updated_card_with_new_number() {
+        let updated_card = build_updated_card(
+            &card_from_locker(),
+            Some(CardNumber::from_str("4242424242424242").unwrap()),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            updated_card.card_number.get_card_no(),
+            "4242424242424242".to_string()
+        );
+        assert_eq!(updated_card.card_exp_month.peek(), "10");
+        assert_eq!(updated_card.card_exp_year.peek(), "2025");
+    }
+
+    #[test]
+    fn test_get_replaced_locker_id() {
+        // A new card number is stored under a new locker reference
+        assert_eq!(
+            get_replaced_locker_id("card_old", "card_new"),
+            Some("card_old")
+        );
+        // The same card number is stored under the existing locker reference
+        assert_eq!(get_replaced_locker_id("card_old", "card_old"), None);
+    }
+}
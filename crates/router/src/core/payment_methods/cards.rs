@@ -21,6 +21,7 @@ use api_models::{
 use common_enums::enums::MerchantStorageScheme;
 use common_utils::{
     consts,
+    crypto::{self, SignMessage},
     ext_traits::{AsyncExt, Encode, StringExt, ValueExt},
     generate_id,
 };
@@ -43,15 +44,19 @@ use crate::core::utils::get_connector_label;
 use crate::{
     configs::settings,
     core::{
+        blocklist::utils as blocklist_utils,
+        card_bin,
         errors::{self, StorageErrorExt},
-        payment_methods::{transformers as payment_methods, vault},
+        payment_methods::{account_updater, transformers as payment_methods, vault},
         payments::{
             helpers,
             routing::{self, SessionFlowRoutingInput},
         },
-        utils as core_utils,
+        utils as core_utils, webhooks as webhooks_core,
     },
-    db, logger,
+    db,
+    events::audit_events::{AuditEvent, AuditEventType},
+    logger,
     pii::prelude::*,
     routes::{
         self,
@@ -99,6 +104,8 @@ pub async fn create_payment_method(
         .await
         .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
 
+    let fingerprint_id = generate_payment_method_fingerprint(db, merchant_id, req).await?;
+
     let client_secret = generate_id(
         consts::ID_LENGTH,
         format!("{payment_method_id}_secret").as_str(),
@@ -122,6 +129,7 @@ pub async fn create_payment_method(
                 client_secret: Some(client_secret),
                 status: status.unwrap_or(enums::PaymentMethodStatus::Active),
                 network_transaction_id: network_transaction_id.to_owned(),
+                fingerprint_id,
                 ..storage::PaymentMethodNew::default()
             },
             storage_scheme,
@@ -130,6 +138,11 @@ pub async fn create_payment_method(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to add payment method in db")?;
 
+    account_updater::add_card_account_updater_task(db, &response)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to add card account updater task"))
+        .ok();
+
     if customer.default_payment_method_id.is_none() && req.payment_method.is_some() {
         let _ = set_default_payment_method(
             db,
@@ -145,6 +158,109 @@ pub async fn create_payment_method(
     Ok(response)
 }
 
+/// Generate a stable fingerprint of the card number or the bank account number of the payment
+/// method, keyed with the merchant's fingerprint secret
+pub async fn generate_payment_method_fingerprint(
+    db: &dyn db::StorageInterface,
+    merchant_id: &str,
+    req: &api::PaymentMethodCreate,
+) -> errors::RouterResult<Option<String>> {
+    #[cfg(feature = "payouts")]
+    let bank_account_number = req.bank_transfer.as_ref().and_then(|bank| match bank {
+        api_models::payouts::Bank::Sepa(sepa) => {
+            Some(sepa.iban.peek().replace(' ', "").to_uppercase())
+        }
+        api_models::payouts::Bank::Ach(_)
+        | api_models::payouts::Bank::Bacs(_)
+        | api_models::payouts::Bank::Pix(_) => None,
+    });
+    #[cfg(not(feature = "payouts"))]
+    let bank_account_number = None;
+
+    let Some(fingerprint_data) = req
+        .card
+        .as_ref()
+        .map(|card| card.card_number.clone().get_card_no())
+        .or(bank_account_number)
+    else {
+        return Ok(None);
+    };
+
+    let merchant_fingerprint_secret =
+        blocklist_utils::get_merchant_fingerprint_secret(db, merchant_id).await?;
+    let fingerprint = crypto::HmacSha256
+        .sign_message(
+            merchant_fingerprint_secret.as_bytes(),
+            fingerprint_data.as_bytes(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate the payment method fingerprint")?;
+
+    Ok(Some(hex::encode(fingerprint)))
+}
+
+/// The locker only reports a duplicate when the details are stored under the same locker
+/// reference. An active payment method of the customer with the same fingerprint is reported as
+/// a duplicate too, in which case the locker entry just added for the details is deleted and the
+/// response points to the existing payment method.
+#[instrument(skip_all)]
+pub async fn check_duplicate_payment_method_by_fingerprint(
+    state: &routes::AppState,
+    req: &api::PaymentMethodCreate,
+    resp: &mut api::PaymentMethodResponse,
+    duplication_check: Option<payment_methods::DataDuplicationCheck>,
+    locker_id: Option<&str>,
+    merchant_account: &domain::MerchantAccount,
+    customer_id: &str,
+) -> errors::RouterResult<Option<payment_methods::DataDuplicationCheck>> {
+    if duplication_check.is_some() {
+        return Ok(duplication_check);
+    }
+
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let Some(fingerprint_id) = generate_payment_method_fingerprint(db, merchant_id, req).await?
+    else {
+        return Ok(None);
+    };
+
+    let Some(existing_payment_method) = db
+        .find_payment_method_by_customer_id_merchant_id_fingerprint_id(
+            customer_id,
+            merchant_id,
+            &fingerprint_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find payment methods by fingerprint")?
+        .into_iter()
+        .find(|payment_method| payment_method.status == enums::PaymentMethodStatus::Active)
+    else {
+        return Ok(None);
+    };
+
+    logger::info!(
+        payment_method_id = %existing_payment_method.payment_method_id,
+        "Payment method with the same fingerprint already exists for the customer"
+    );
+
+    if let Some(locker_id) = locker_id.filter(|locker_id| {
+        existing_payment_method.locker_id.as_deref() != Some(*locker_id)
+            && existing_payment_method.payment_method_id != *locker_id
+    }) {
+        delete_card_from_locker(state, customer_id, merchant_id, locker_id)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to delete duplicate locker entry"))
+            .ok();
+    }
+
+    resp.payment_method_id = existing_payment_method.payment_method_id;
+    resp.fingerprint_id = existing_payment_method.fingerprint_id;
+
+    Ok(Some(payment_methods::DataDuplicationCheck::Duplicated))
+}
+
 pub fn store_default_payment_method(
     req: &api::PaymentMethodCreate,
     customer_id: &str,
@@ -170,6 +286,7 @@ pub fn store_default_payment_method(
         payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
         last_used_at: Some(common_utils::date_time::now()),
         client_secret: None,
+        fingerprint_id: None,
     };
 
     (payment_method_response, None)
@@ -410,11 +527,8 @@ pub async fn add_payment_method_data(
 
                         let card_isin = card.card_number.clone().get_card_isin();
 
-                        let card_info = db
-                            .get_card_info(card_isin.as_str())
-                            .await
-                            .change_context(errors::ApiErrorResponse::InternalServerError)
-                            .attach_printable("Failed to get card info")?;
+                        let card_info =
+                            card_bin::get_card_bin_info(&state, card_isin.as_str()).await?;
 
                         let updated_card = CardDetailsPaymentMethod {
                             issuer_country: card_info
@@ -552,6 +666,20 @@ pub async fn add_payment_method(
 
     let (mut resp, duplication_check) = response?;
 
+    let locker_id = (resp.payment_method == Some(api_enums::PaymentMethod::Card)
+        || resp.payment_method == Some(api_enums::PaymentMethod::BankTransfer))
+    .then(|| resp.payment_method_id.clone());
+    let duplication_check = check_duplicate_payment_method_by_fingerprint(
+        &state,
+        &req,
+        &mut resp,
+        duplication_check,
+        locker_id.as_deref(),
+        merchant_account,
+        &customer_id,
+    )
+    .await?;
+
     match duplication_check {
         Some(duplication_check) => match duplication_check {
             payment_methods::DataDuplicationCheck::Duplicated => {
@@ -667,7 +795,7 @@ pub async fn add_payment_method(
                 None
             };
             resp.payment_method_id = generate_id(consts::ID_LENGTH, "pm");
-            insert_payment_method(
+            let payment_method = insert_payment_method(
                 db,
                 &resp,
                 req,
@@ -682,6 +810,9 @@ pub async fn add_payment_method(
                 merchant_account.storage_scheme,
             )
             .await?;
+
+            resp.payment_method_id = payment_method.payment_method_id;
+            resp.fingerprint_id = payment_method.fingerprint_id;
         }
     }
 
@@ -902,6 +1033,7 @@ pub async fn update_customer_payment_method(
                 payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
                 last_used_at: Some(common_utils::date_time::now()),
                 client_secret: None,
+                fingerprint_id: pm.fingerprint_id,
             }
         };
 
@@ -2088,6 +2220,7 @@ pub async fn list_payment_methods(
             merchant_connector_id: None,
             surcharge_amount: None,
             tax_amount: None,
+            routing_decision: None,
         };
 
         state
@@ -2835,7 +2968,7 @@ fn filter_pm_based_on_supported_payments_for_mandate(
         .unwrap_or(false)
 }
 
-fn filter_pm_based_on_config<'a>(
+pub fn filter_pm_based_on_config<'a>(
     config: &'a crate::configs::settings::ConnectorFilters,
     connector: &'a str,
     payment_method_type: &'a api_enums::PaymentMethodType,
@@ -3287,7 +3420,11 @@ pub async fn list_customer_payment_method(
             enums::PaymentMethod::Card => {
                 let card_details = get_card_details_with_locker_fallback(&pm, key, state).await?;
 
-                if card_details.is_some() {
+                // Expired cards cannot be charged, so their tokens are not handed out
+                if card_details
+                    .as_ref()
+                    .is_some_and(|card| !is_saved_card_expired(card))
+                {
                     PaymentMethodListContext {
                         card_details,
                         #[cfg(feature = "payouts")]
@@ -3388,7 +3525,8 @@ pub async fn list_customer_payment_method(
             card: payment_method_retrieval_context.card_details,
             metadata: pm.metadata,
             payment_method_issuer_code: pm.payment_method_issuer_code,
-            recurring_enabled: false,
+            recurring_enabled: pm.connector_mandate_details.is_some()
+                || pm.network_transaction_id.is_some(),
             installment_payment_enabled: false,
             payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
             created: Some(pm.created_at),
@@ -3399,12 +3537,20 @@ pub async fn list_customer_payment_method(
             requires_cvv: requires_cvv
                 && !(off_session_payment_flag && pm.connector_mandate_details.is_some()),
             last_used_at: Some(pm.last_used_at),
+            fingerprint_id: pm.fingerprint_id.clone(),
             default_payment_method_set: customer.default_payment_method_id.is_some()
                 && customer.default_payment_method_id == Some(pm.payment_method_id),
         };
         customer_pms.push(pma.to_owned());
 
         let intent_created = payment_intent.as_ref().map(|intent| intent.created_at);
+        // Tokens listed for a payment are tied to it, whereas the ones listed by the merchant
+        // for a customer can be reused across payments until they expire
+        let token_scope = if payment_intent.is_some() {
+            storage::PaymentTokenScope::SingleUse
+        } else {
+            storage::PaymentTokenScope::MultiUse
+        };
 
         let redis_conn = state
             .store
@@ -3418,6 +3564,10 @@ pub async fn list_customer_payment_method(
         .insert(
             intent_created,
             payment_method_retrieval_context.hyperswitch_token_data,
+            token_scope,
+            payment_intent
+                .as_ref()
+                .map(|intent| intent.payment_id.clone()),
             state,
         )
         .await?;
@@ -3435,17 +3585,12 @@ pub async fn list_customer_payment_method(
                     "pm_token_{}_{}_{}",
                     parent_payment_method_token, pma.payment_method, pm_metadata.0
                 );
-                let current_datetime_utc = common_utils::date_time::now();
-                let time_elapsed = current_datetime_utc
-                    - payment_intent
-                        .as_ref()
-                        .map(|intent| intent.created_at)
-                        .unwrap_or_else(|| current_datetime_utc);
                 redis_conn
                     .set_key_with_expiry(
                         &key,
                         pm_metadata.1,
-                        consts::TOKEN_TTL - time_elapsed.whole_seconds(),
+                        token_scope
+                            .get_ttl_in_seconds(intent_created, common_utils::date_time::now()),
                     )
                     .await
                     .change_context(errors::StorageError::KVError)
@@ -3518,6 +3663,17 @@ pub async fn list_customer_payment_method(
     Ok(services::ApplicationResponse::Json(response))
 }
 
+/// A card without an expiry is assumed to be usable, a card whose expiry is invalid or in the past
+/// is treated as expired.
+fn is_saved_card_expired(card: &api::CardDetailFromLocker) -> bool {
+    card.expiry_month
+        .as_ref()
+        .zip(card.expiry_year.as_ref())
+        .map_or(false, |(expiry_month, expiry_year)| {
+            helpers::validate_card_expiry(expiry_month, expiry_year).is_err()
+        })
+}
+
 pub async fn get_card_details_with_locker_fallback(
     pm: &payment_method::PaymentMethod,
     key: &[u8],
@@ -3703,6 +3859,34 @@ async fn get_bank_account_connector_details(
         None => Ok(None),
     }
 }
+pub async fn set_default_payment_method_by_payment_method_id(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_method_id: String,
+) -> errors::RouterResponse<CustomerDefaultPaymentMethodResponse> {
+    let db = &*state.store;
+    let payment_method = db
+        .find_payment_method(&payment_method_id, merchant_account.storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    utils::when(
+        payment_method.merchant_id != merchant_account.merchant_id,
+        || Err(errors::ApiErrorResponse::PaymentMethodNotFound),
+    )?;
+
+    set_default_payment_method(
+        db,
+        merchant_account.merchant_id,
+        key_store,
+        &payment_method.customer_id,
+        payment_method_id,
+        merchant_account.storage_scheme,
+    )
+    .await
+}
+
 pub async fn set_default_payment_method(
     db: &dyn db::StorageInterface,
     merchant_id: String,
@@ -3990,6 +4174,7 @@ pub async fn retrieve_payment_method(
             payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
             last_used_at: Some(pm.last_used_at),
             client_secret: pm.client_secret,
+            fingerprint_id: pm.fingerprint_id,
         },
     ))
 }
@@ -3997,6 +4182,7 @@ pub async fn retrieve_payment_method(
 #[instrument(skip_all)]
 pub async fn delete_payment_method(
     state: routes::AppState,
+    req_state: routes::app::ReqState,
     merchant_account: domain::MerchantAccount,
     pm_id: api::PaymentMethodId,
     key_store: domain::MerchantKeyStore,
@@ -4061,6 +4247,29 @@ pub async fn delete_payment_method(
     .await
     .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
 
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::PaymentMethodDeleted {
+            payment_method_id: key.payment_method_id.clone(),
+            customer_id: key.customer_id.clone(),
+        }))
+        .emit();
+
+    // The payment method data has been redacted from the vault and is not sent out
+    trigger_payment_method_webhook(
+        &state,
+        &merchant_account,
+        &key_store,
+        &key,
+        None,
+        enums::EventType::PaymentMethodDeleted,
+    )
+    .await
+    .map_err(|error| {
+        logger::error!(?error, "Failed to trigger payment method deleted webhook");
+    })
+    .ok();
+
     if customer.default_payment_method_id.as_ref() == Some(&pm_id.payment_method_id) {
         let customer_update = CustomerUpdate::UpdateDefaultPaymentMethod {
             default_payment_method_id: Some(None),
@@ -4087,6 +4296,75 @@ pub async fn delete_payment_method(
     ))
 }
 
+pub(crate) async fn trigger_payment_method_webhook(
+    state: &routes::AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: &storage::PaymentMethod,
+    card: Option<api::CardDetailFromLocker>,
+    event_type: enums::EventType,
+) -> errors::RouterResult<()> {
+    // Payment methods are not scoped to a business profile, so the webhook is delivered using
+    // the merchant's default profile
+    let Some(profile_id) = merchant_account.default_profile.as_ref() else {
+        logger::debug!(
+            ?event_type,
+            "Merchant does not have a default business profile, skipping payment method webhook"
+        );
+        return Ok(());
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?;
+
+    let payment_method_response = get_payment_method_webhook_content(payment_method, card);
+
+    webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        event_type,
+        enums::EventClass::PaymentMethods,
+        payment_method.payment_method_id.clone(),
+        enums::EventObjectType::PaymentMethodDetails,
+        api::OutgoingWebhookContent::PaymentMethodDetails(Box::new(payment_method_response)),
+        Some(payment_method.created_at),
+        None,
+    )
+    .await
+}
+
+/// Builds the payment method details sent in payment method webhooks.
+pub(crate) fn get_payment_method_webhook_content(
+    payment_method: &storage::PaymentMethod,
+    card: Option<api::CardDetailFromLocker>,
+) -> api::PaymentMethodResponse {
+    api::PaymentMethodResponse {
+        merchant_id: payment_method.merchant_id.clone(),
+        customer_id: Some(payment_method.customer_id.clone()),
+        payment_method_id: payment_method.payment_method_id.clone(),
+        payment_method: payment_method.payment_method,
+        payment_method_type: payment_method.payment_method_type,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card,
+        metadata: None,
+        created: Some(payment_method.created_at),
+        recurring_enabled: false,
+        installment_payment_enabled: false,
+        payment_experience: None,
+        last_used_at: Some(payment_method.last_used_at),
+        client_secret: None,
+        fingerprint_id: payment_method.fingerprint_id.clone(),
+    }
+}
+
 pub async fn create_encrypted_payment_method_data(
     key_store: &domain::MerchantKeyStore,
     pm_data: Option<PaymentMethodsData>,
@@ -4172,3 +4450,74 @@ pub async fn list_countries_currencies_for_connector_payment_method_util(
             .collect(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn saved_card(
+        expiry_month: Option<&str>,
+        expiry_year: Option<&str>,
+    ) -> api::CardDetailFromLocker {
+        api::CardDetailFromLocker {
+            scheme: None,
+            issuer_country: None,
+            last4_digits: Some("1111".to_string()),
+            card_number: None,
+            expiry_month: expiry_month.map(|month| Secret::new(month.to_string())),
+            expiry_year: expiry_year.map(|year| Secret::new(year.to_string())),
+            card_token: None,
+            card_holder_name: None,
+            card_fingerprint: None,
+            nick_name: None,
+            card_network: None,
+            card_isin: None,
+            card_issuer: None,
+            card_type: None,
+            saved_to_locker: true,
+        }
+    }
+
+    #[test]
+    fn test_saved_card_without_expiry_is_not_expired() {
+        assert!(!is_saved_card_expired(&saved_card(None, None)));
+        assert!(!is_saved_card_expired(&saved_card(Some("10"), None)));
+    }
+
+    #[test]
+    fn test_saved_card_with_past_expiry_is_expired() {
+        assert!(is_saved_card_expired(&saved_card(Some("01"), Some("2020"))));
+        assert!(is_saved_card_expired(&saved_card(Some("01"), Some("20"))));
+    }
+
+    #[test]
+    fn test_saved_card_with_future_expiry_is_not_expired() {
+        let next_year = common_utils::date_time::now().year() + 1;
+
+        assert!(!is_saved_card_expired(&saved_card(
+            Some("01"),
+            Some(&next_year.to_string())
+        )));
+        // The locker may hold the expiry year in two digit format
+        assert!(!is_saved_card_expired(&saved_card(
+            Some("01"),
+            Some(&(next_year % 100).to_string())
+        )));
+    }
+
+    #[test]
+    fn test_saved_card_is_valid_until_the_end_of_its_expiry_month() {
+        let now = common_utils::date_time::now();
+
+        assert!(!is_saved_card_expired(&saved_card(
+            Some(&u8::from(now.month()).to_string()),
+            Some(&now.year().to_string())
+        )));
+    }
+
+    #[test]
+    fn test_saved_card_with_invalid_expiry_is_expired() {
+        assert!(is_saved_card_expired(&saved_card(Some("13"), Some("2099"))));
+        assert!(is_saved_card_expired(&saved_card(Some("ab"), Some("2099"))));
+    }
+}
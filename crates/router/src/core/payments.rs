@@ -1,5 +1,7 @@
 pub mod access_token;
+pub mod authorization_expiry;
 pub mod conditional_configs;
+pub mod connector_payloads;
 pub mod customers;
 pub mod flows;
 pub mod helpers;
@@ -7,6 +9,7 @@ pub mod operations;
 #[cfg(feature = "retry")]
 pub mod retry;
 pub mod routing;
+pub mod routing_decisions;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
@@ -502,6 +505,10 @@ where
             .async_map(|key_for_hyperswitch_token| async move {
                 if key_for_hyperswitch_token
                     .should_delete_payment_method_token(payment_data.payment_intent.status)
+                    && !matches!(
+                        key_for_hyperswitch_token.get_scope(state).await,
+                        Ok(storage::PaymentTokenScope::MultiUse)
+                    )
                 {
                     let _ = key_for_hyperswitch_token.delete(state).await;
                 }
@@ -586,10 +593,24 @@ where
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("Could not decode the conditional config")?;
+    // The authentication type of the request is only overridden if the merchant enforces it
+    let requested_authentication_type = payment_data.payment_attempt.authentication_type;
+    let override_3ds = output
+        .override_3ds
+        .filter(|_| output.enforce_3ds || requested_authentication_type.is_none());
+    if let Some(override_3ds) = override_3ds {
+        logger::debug!(
+            ?requested_authentication_type,
+            ?override_3ds,
+            decision_reason = ?output.decision_reason,
+            "Authentication type decided by the conditional config"
+        );
+        payment_data.payment_attempt.authentication_type = Some(override_3ds.foreign_into());
+        payment_data.payment_attempt.three_ds_decision_reason = output.decision_reason;
+    }
     payment_data.payment_attempt.authentication_type = payment_data
         .payment_attempt
         .authentication_type
-        .or(output.override_3ds.map(ForeignInto::foreign_into))
         .or(Some(storage_enums::AuthenticationType::NoThreeDs));
     Ok(())
 }
@@ -1200,6 +1221,7 @@ impl<Ctx: PaymentMethodRetrieve> PaymentRedirectFlow<Ctx> for PaymentAuthenticat
                 .ok_or(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("missing authentication connector in payment_intent")?,
             None,
+            payment_intent.test_mode.unwrap_or_default(),
         )
         .await?;
         let is_pull_mechanism_enabled =
@@ -1934,6 +1956,12 @@ where
                 router_data = router_data.preprocessing_steps(state, connector).await?;
 
                 (router_data, false)
+            } else if connector.connector_name == router_types::Connector::Razorpay {
+                router_data = router_data.preprocessing_steps(state, connector).await?;
+
+                let is_error_in_response = router_data.response.is_err();
+                // If is_error_in_response is true, should_continue_payment should be false, we should throw the error
+                (router_data, !is_error_in_response)
             } else if (connector.connector_name == router_types::Connector::Cybersource
                 || connector.connector_name == router_types::Connector::Bankofamerica)
                 && is_operation_complete_authorize(&operation)
@@ -1966,6 +1994,16 @@ where
                 (router_data, should_continue_payment)
             }
         }
+        Some(api_models::payments::PaymentMethodData::Upi(_)) => {
+            if connector.connector_name == router_types::Connector::Razorpay {
+                router_data = router_data.preprocessing_steps(state, connector).await?;
+                let is_error_in_response = router_data.response.is_err();
+                // If is_error_in_response is true, should_continue_payment should be false, we should throw the error
+                (router_data, !is_error_in_response)
+            } else {
+                (router_data, should_continue_payment)
+            }
+        }
         Some(api_models::payments::PaymentMethodData::BankDebit(_)) => {
             if connector.connector_name == router_types::Connector::Gocardless {
                 router_data = router_data.preprocessing_steps(state, connector).await?;
@@ -2033,6 +2071,7 @@ where
         &profile_id,
         connector_name,
         merchant_connector_id,
+        payment_data.payment_intent.test_mode.unwrap_or_default(),
     )
     .await?;
 
@@ -2960,7 +2999,10 @@ pub async fn add_process_sync_task(
     };
     let runner = storage::ProcessTrackerRunner::PaymentsSyncWorkflow;
     let task = "PAYMENTS_SYNC";
-    let tag = ["SYNC", "PAYMENT"];
+    let tag = pt_utils::get_tags_with_connector(
+        ["SYNC", "PAYMENT"],
+        payment_attempt.connector.as_deref(),
+    );
     let process_tracker_id = pt_utils::get_process_tracker_id(
         runner,
         task,
@@ -2981,6 +3023,43 @@ pub async fn add_process_sync_task(
     Ok(())
 }
 
+/// Adds a task which captures the payment at `capture_on`, for payments with manual capture that
+/// specify when they are to be captured.
+pub async fn add_scheduled_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    capture_on: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let tracking_data = storage::ScheduledCaptureTrackingData {
+        payment_id: payment_attempt.payment_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+    };
+    let runner = storage::ProcessTrackerRunner::ScheduledCaptureWorkflow;
+    let task = "SCHEDULED_CAPTURE";
+    let tag = pt_utils::get_tags_with_connector(
+        ["CAPTURE", "PAYMENT"],
+        payment_attempt.connector.as_deref(),
+    );
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        capture_on,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
 pub async fn reset_process_sync_task(
     db: &dyn StorageInterface,
     payment_attempt: &storage::PaymentAttempt,
@@ -3173,10 +3252,58 @@ where
         payment_data.payment_attempt.business_sub_label = routing_data.business_sub_label;
     }
     payment_data.payment_attempt.straight_through_algorithm = Some(encoded_info);
+    payment_data.payment_attempt.estimated_connector_fee =
+        routing::cost_based::estimate_connector_fee(
+            state,
+            &merchant_account.merchant_id,
+            payment_data,
+        )
+        .await;
 
     Ok(decided_connector)
 }
 
+/// Follow-up operations on a payment and merchant initiated transactions are processed by the
+/// connector that processed the original authorization or mandate. Requests which explicitly ask
+/// for other connectors are rejected, instead of silently being processed by a different connector
+/// than the requested one.
+fn validate_requested_connectors(
+    sticky_connector: &str,
+    processed_by: &str,
+    request_straight_through: Option<&api::routing::StraightThroughAlgorithm>,
+    eligible_connectors: Option<&[api_models::enums::RoutableConnectors]>,
+) -> RouterResult<()> {
+    let straight_through_connectors = request_straight_through.map(|algorithm| match algorithm {
+        api::routing::StraightThroughAlgorithm::Single(choice) => vec![choice.connector],
+        api::routing::StraightThroughAlgorithm::Priority(choices) => {
+            choices.iter().map(|choice| choice.connector).collect()
+        }
+        api::routing::StraightThroughAlgorithm::VolumeSplit(splits) => splits
+            .iter()
+            .map(|split| split.connector.connector)
+            .collect::<Vec<_>>(),
+    });
+    let includes_sticky_connector = |connectors: &[api_models::enums::RoutableConnectors]| {
+        connectors
+            .iter()
+            .any(|connector| connector.to_string() == sticky_connector)
+    };
+
+    if straight_through_connectors
+        .as_deref()
+        .map_or(true, includes_sticky_connector)
+        && eligible_connectors.map_or(true, includes_sticky_connector)
+    {
+        Ok(())
+    } else {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "This operation can only be processed by the connector `{sticky_connector}`, which processed the {processed_by}"
+            ),
+        }))
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn decide_connector<F>(
     state: AppState,
@@ -3196,6 +3323,19 @@ where
     // This is in case of flows like payments_sync, payments_cancel where the successive operations
     // with the connector have to be made using the same connector account.
     if let Some(ref connector_name) = payment_data.payment_attempt.connector {
+        if payment_data
+            .payment_attempt
+            .connector_transaction_id
+            .is_some()
+        {
+            validate_requested_connectors(
+                connector_name,
+                "original authorization",
+                request_straight_through.as_ref(),
+                eligible_connectors.as_deref(),
+            )?;
+        }
+
         // Connector was already decided previously, use the same connector
         let connector_data = api::ConnectorData::get_connector_by_name(
             &state.conf.connectors,
@@ -3211,6 +3351,13 @@ where
     }
 
     if let Some(mandate_connector_details) = payment_data.mandate_connector.as_ref() {
+        validate_requested_connectors(
+            &mandate_connector_details.connector,
+            "mandate",
+            request_straight_through.as_ref(),
+            eligible_connectors.as_deref(),
+        )?;
+
         let connector_data = api::ConnectorData::get_connector_by_name(
             &state.conf.connectors,
             &mandate_connector_details.connector,
@@ -3271,6 +3418,7 @@ where
         let (mut connectors, check_eligibility) = routing::perform_straight_through_routing(
             &routing_algorithm,
             payment_data.creds_identifier.clone(),
+            &payment_data.payment_intent.payment_id,
         )
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed execution of straight through routing")?;
@@ -3328,6 +3476,7 @@ where
         let (mut connectors, check_eligibility) = routing::perform_straight_through_routing(
             routing_algorithm,
             payment_data.creds_identifier.clone(),
+            &payment_data.payment_intent.payment_id,
         )
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed execution of straight through routing")?;
@@ -3732,7 +3881,7 @@ pub async fn route_connector_v1<F>(
     merchant_account: &domain::MerchantAccount,
     business_profile: &storage::business_profile::BusinessProfile,
     key_store: &domain::MerchantKeyStore,
-    transaction_data: TransactionData<'_, F>,
+    mut transaction_data: TransactionData<'_, F>,
     routing_data: &mut storage::RoutingData,
     eligible_connectors: Option<Vec<api_models::enums::RoutableConnectors>>,
     mandate_type: Option<api::MandateTransactionType>,
@@ -3772,14 +3921,17 @@ where
         .attach_printable("Could not decode merchant routing algorithm ref")?
         .unwrap_or_default();
 
+    let mut routing_decision = api::routing::RoutingDecision::default();
     let connectors = routing::perform_static_routing_v1(
         state,
         &merchant_account.merchant_id,
         algorithm_ref,
         &transaction_data,
+        &mut routing_decision,
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    routing_decision.routed_connectors = connectors.clone();
 
     let connectors = routing::perform_eligibility_analysis_with_fallback(
         &state.clone(),
@@ -3787,7 +3939,7 @@ where
         merchant_account.modified_at.assume_utc().unix_timestamp(),
         connectors,
         &transaction_data,
-        eligible_connectors,
+        eligible_connectors.clone(),
         #[cfg(feature = "business_profile_routing")]
         profile_id,
     )
@@ -3795,6 +3947,67 @@ where
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("failed eligibility analysis and fallback")?;
 
+    let eligible_with_fallback = connectors.clone();
+    let connectors = match &transaction_data {
+        TransactionData::Payment(payment_data) => {
+            let connectors = routing::circuit_breaker::filter_connectors_by_circuit_breaker(
+                state,
+                &merchant_account.merchant_id,
+                connectors,
+            )
+            .await;
+            let connectors = routing::cost_based::order_connectors_by_estimated_fee(
+                state,
+                &merchant_account.merchant_id,
+                payment_data,
+                connectors,
+            )
+            .await;
+            routing::success_rate::order_connectors_by_success_rate(
+                state,
+                &merchant_account.merchant_id,
+                payment_data,
+                connectors,
+            )
+            .await
+        }
+        #[cfg(feature = "payouts")]
+        TransactionData::Payout(_) => connectors,
+    };
+
+    match &mut transaction_data {
+        TransactionData::Payment(payment_data) => {
+            routing_decision.filtered_connectors = routing_decision
+                .routed_connectors
+                .iter()
+                .filter(|choice| !connectors.contains(choice))
+                .map(|choice| api::routing::FilteredConnector {
+                    connector: choice.clone(),
+                    reason: if eligible_connectors
+                        .as_ref()
+                        .is_some_and(|eligible| !eligible.contains(&choice.connector))
+                    {
+                        api::routing::ConnectorFilterReason::NotRequested
+                    } else if eligible_with_fallback.contains(choice) {
+                        api::routing::ConnectorFilterReason::CircuitBreakerOpen
+                    } else {
+                        api::routing::ConnectorFilterReason::NotEligible
+                    },
+                })
+                .collect();
+            routing_decision.eligible_connectors = connectors.clone();
+
+            payment_data.payment_attempt.routing_decision = Some(
+                routing_decision
+                    .encode_to_value()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("error serializing the routing decision")?,
+            );
+        }
+        #[cfg(feature = "payouts")]
+        TransactionData::Payout(_) => {}
+    }
+
     #[cfg(feature = "payouts")]
     let first_connector_choice = connectors
         .first()
@@ -3946,6 +4159,7 @@ pub async fn payment_external_authentication(
         profile_id,
         authentication_connector.as_str(),
         None,
+        payment_intent.test_mode.unwrap_or_default(),
     )
     .await?;
     let authentication = db
@@ -0,0 +1,132 @@
+//! Expiry of uncaptured authorizations
+//!
+//! Authorizations which are not captured expire after a validity that depends on the connector
+//! and the card network. For merchants who opt in through the `authorization_expiry_{merchant_id}`
+//! config, a task is scheduled ahead of the expiry, which either voids the payment or warns the
+//! merchant with a `payment_authorization_expiring` webhook.
+
+use common_utils::ext_traits::{StringExt, ValueExt};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use scheduler::utils as pt_utils;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    routes::AppState,
+    types::storage::{self, enums},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorizationExpiryAction {
+    /// Void the payment before its authorization expires
+    Void,
+    /// Send a `payment_authorization_expiring` webhook before the authorization expires
+    Notify,
+}
+
+/// Merchant level settings for uncaptured authorizations nearing their expiry, configured as a
+/// JSON value under the `authorization_expiry_{merchant_id}` config key.
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuthorizationExpiryConfig {
+    pub action: AuthorizationExpiryAction,
+    /// How long before the authorization expires the action is taken, in seconds
+    #[serde(default)]
+    pub lead_time_in_secs: u32,
+}
+
+/// Returns the authorization expiry settings of the merchant, or `None` if the merchant has not
+/// opted in. The config is read for every authorized payment, so merchants who have not opted in
+/// are given a `null` config, which is cached like any other.
+pub async fn get_authorization_expiry_config(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Option<AuthorizationExpiryConfig>> {
+    let key = format!("authorization_expiry_{merchant_id}");
+    db.find_config_by_key_unwrap_or(&key, Some("null".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch authorization expiry config")?
+        .config
+        .parse_struct::<Option<AuthorizationExpiryConfig>>("AuthorizationExpiryConfig")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse authorization expiry config")
+}
+
+/// Schedules the action configured by the merchant ahead of the expiry of the authorization made
+/// by the payment attempt. Nothing is scheduled if the merchant has not opted in.
+#[instrument(skip_all)]
+pub async fn add_authorization_expiry_task(
+    state: &AppState,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let Some(config) = get_authorization_expiry_config(db, &payment_attempt.merchant_id).await?
+    else {
+        return Ok(());
+    };
+
+    let card_network = get_card_network(payment_attempt);
+    let validity_in_days = state
+        .conf
+        .authorization_validity
+        .get_validity_in_days(payment_attempt.connector.as_deref(), card_network.as_ref());
+    let now = common_utils::date_time::now();
+    let expires_at = now.saturating_add(time::Duration::days(validity_in_days));
+    let schedule_time = expires_at
+        .saturating_sub(time::Duration::seconds(i64::from(config.lead_time_in_secs)))
+        .max(now);
+
+    let tracking_data = storage::AuthorizationExpiryTrackingData {
+        payment_id: payment_attempt.payment_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        expires_at,
+    };
+    let runner = storage::ProcessTrackerRunner::AuthorizationExpiryWorkflow;
+    let task = "AUTHORIZATION_EXPIRY";
+    let tag = pt_utils::get_tags_with_connector(
+        ["AUTHORIZATION_EXPIRY", "PAYMENT"],
+        payment_attempt.connector.as_deref(),
+    );
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct authorization expiry process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add authorization expiry task to process tracker")?;
+
+    Ok(())
+}
+
+fn get_card_network(payment_attempt: &storage::PaymentAttempt) -> Option<enums::CardNetwork> {
+    payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|payment_method_data| {
+            payment_method_data
+                .parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|payment_method_data| match payment_method_data {
+            api_models::payments::AdditionalPaymentData::Card(card) => card.card_network,
+            _ => None,
+        })
+}
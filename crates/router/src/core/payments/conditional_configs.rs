@@ -9,7 +9,7 @@ use error_stack::ResultExt;
 use euclid::backend::{self, inputs as dsl_inputs, EuclidBackend};
 use router_env::{instrument, tracing};
 
-use super::routing::make_dsl_input;
+use super::routing::{get_ip_country, make_dsl_input, populate_card_bin_attributes};
 use crate::{
     core::{errors, errors::ConditionalConfigError as ConfigError, payments},
     routes,
@@ -43,8 +43,10 @@ pub async fn perform_decision_management<F: Clone>(
         .retrieve(&key)
         .change_context(ConfigError::CacheMiss)
         .attach_printable("Unable to retrieve cached routing algorithm even after refresh")?;
-    let backend_input =
+    let mut backend_input =
         make_dsl_input(payment_data).change_context(ConfigError::InputConstructionError)?;
+    backend_input.payment.ip_country = get_ip_country(state, payment_data).await;
+    populate_card_bin_attributes(state, payment_data, &mut backend_input.payment_method).await;
     let interpreter = cached_algo.as_ref();
     execute_dsl_and_get_conditional_config(backend_input, interpreter).await
 }
@@ -0,0 +1,211 @@
+use std::{collections::HashMap, sync::Arc};
+
+use api_models::payments::{ConnectorPayload, ConnectorPayloadsRequest, ConnectorPayloadsResponse};
+use common_utils::{date_time, ext_traits::StringExt, static_cache::StaticCache};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    db::StorageInterface,
+    routes::AppState,
+    services,
+    types::{domain, storage},
+};
+
+/// The parsed config, so that it is neither fetched nor parsed for every connector call
+static CONNECTOR_PAYLOADS_CONFIG_CACHE: StaticCache<ConnectorPayloadsConfig> = StaticCache::new();
+
+/// Storing connector payloads is configured through the single `store_connector_payloads` config,
+/// which enables it for all merchants or only for the merchants it is overridden for, e.g.
+/// `{"enabled": false, "merchant_overrides": {"merchant_123": true}}`
+#[derive(Debug, Default, serde::Deserialize)]
+struct ConnectorPayloadsConfig {
+    #[serde(default)]
+    enabled: bool,
+    #[serde(default)]
+    merchant_overrides: HashMap<String, bool>,
+}
+
+impl ConnectorPayloadsConfig {
+    fn is_enabled_for(&self, merchant_id: &str) -> bool {
+        self.merchant_overrides
+            .get(merchant_id)
+            .copied()
+            .unwrap_or(self.enabled)
+    }
+}
+
+async fn get_connector_payloads_config(db: &dyn StorageInterface) -> Arc<ConnectorPayloadsConfig> {
+    let key = consts::STORE_CONNECTOR_PAYLOADS_CONFIG_KEY.to_string();
+    let now = date_time::now_unix_timestamp();
+    let is_expired = CONNECTOR_PAYLOADS_CONFIG_CACHE
+        .expired(&key, now - consts::CONNECTOR_PAYLOADS_CONFIG_CACHE_TTL)
+        .unwrap_or(true);
+    if !is_expired {
+        if let Ok(config) = CONNECTOR_PAYLOADS_CONFIG_CACHE.retrieve(&key) {
+            return config;
+        }
+    }
+
+    // The config is created when it does not exist, so that it is always served from the cache
+    let config = db
+        .find_config_by_key_unwrap_or(
+            consts::STORE_CONNECTOR_PAYLOADS_CONFIG_KEY,
+            Some("{}".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|config| {
+            config
+                .config
+                .parse_struct::<ConnectorPayloadsConfig>("ConnectorPayloadsConfig")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        })
+        .unwrap_or_else(|err| {
+            logger::error!("{err:?}");
+            ConnectorPayloadsConfig::default()
+        });
+
+    if let Err(error) = CONNECTOR_PAYLOADS_CONFIG_CACHE.save(key.clone(), config, now) {
+        logger::error!(?error, "Failed to cache the connector payloads config");
+    }
+    CONNECTOR_PAYLOADS_CONFIG_CACHE
+        .retrieve(&key)
+        .unwrap_or_default()
+}
+
+pub async fn config_should_store_connector_payloads(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> bool {
+    get_connector_payloads_config(db)
+        .await
+        .is_enabled_for(merchant_id)
+}
+
+/// Stores the payload with the payment attempt, if storing the payloads is enabled for the
+/// merchant. Failures are only logged, as storing the payload must not affect the processing of
+/// the payment.
+#[instrument(skip_all)]
+pub async fn store_connector_payload(
+    state: &AppState,
+    connector_payload: storage::ConnectorPayloadNew,
+) {
+    if !config_should_store_connector_payloads(&*state.store, &connector_payload.merchant_id).await
+    {
+        return;
+    }
+
+    if let Err(error) = state
+        .store
+        .insert_connector_payload(connector_payload)
+        .await
+    {
+        logger::error!(?error, "Failed to store connector payload");
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_connector_payloads(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: ConnectorPayloadsRequest,
+) -> RouterResponse<ConnectorPayloadsResponse> {
+    let db = state.store.as_ref();
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &req.payment_id,
+            &merchant_account.merchant_id,
+            &req.attempt_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payloads = db
+        .find_connector_payloads_by_merchant_id_attempt_id(
+            &merchant_account.merchant_id,
+            &payment_attempt.attempt_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch connector payloads")?
+        .into_iter()
+        .map(|connector_payload| ConnectorPayload {
+            connector: connector_payload.connector,
+            flow: connector_payload.flow,
+            url: connector_payload.url,
+            method: connector_payload.method,
+            status_code: u16::try_from(connector_payload.status_code).unwrap_or_default(),
+            request: connector_payload.request,
+            response: connector_payload.response,
+            error: connector_payload.error,
+            created_at: connector_payload.created_at,
+        })
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        ConnectorPayloadsResponse {
+            payment_id: payment_attempt.payment_id,
+            attempt_id: payment_attempt.attempt_id,
+            payloads,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use storage_impl::MockDb;
+
+    use super::*;
+    use crate::db::configs::ConfigInterface;
+
+    #[test]
+    fn test_merchant_overrides_take_precedence() {
+        let config = r#"{"enabled": false, "merchant_overrides": {"merchant_1": true}}"#
+            .to_string()
+            .parse_struct::<ConnectorPayloadsConfig>("ConnectorPayloadsConfig")
+            .unwrap();
+        assert!(config.is_enabled_for("merchant_1"));
+        assert!(!config.is_enabled_for("merchant_2"));
+
+        let config = r#"{"enabled": true, "merchant_overrides": {"merchant_1": false}}"#
+            .to_string()
+            .parse_struct::<ConnectorPayloadsConfig>("ConnectorPayloadsConfig")
+            .unwrap();
+        assert!(!config.is_enabled_for("merchant_1"));
+        assert!(config.is_enabled_for("merchant_2"));
+
+        assert!(!ConnectorPayloadsConfig::default().is_enabled_for("merchant_1"));
+    }
+
+    #[tokio::test]
+    async fn test_config_is_cached() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        mockdb
+            .insert_config(storage::ConfigNew {
+                key: consts::STORE_CONNECTOR_PAYLOADS_CONFIG_KEY.to_string(),
+                config: r#"{"enabled": true}"#.to_string(),
+            })
+            .await
+            .unwrap();
+        assert!(config_should_store_connector_payloads(&mockdb, "merchant_1").await);
+
+        // The updated config is only read once the cached one expires
+        mockdb
+            .update_config_by_key(
+                consts::STORE_CONNECTOR_PAYLOADS_CONFIG_KEY,
+                storage::ConfigUpdate::Update {
+                    config: Some(r#"{"enabled": false}"#.to_string()),
+                },
+            )
+            .await
+            .unwrap();
+        assert!(config_should_store_connector_payloads(&mockdb, "merchant_1").await);
+    }
+}
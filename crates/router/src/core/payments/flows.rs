@@ -170,6 +170,7 @@ default_imp_for_complete_authorize!(
     connector::Payu,
     connector::Placetopay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Signifyd,
     connector::Square,
@@ -254,6 +255,7 @@ default_imp_for_webhook_source_verification!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Shift4,
     connector::Signifyd,
@@ -341,6 +343,7 @@ default_imp_for_create_customer!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Shift4,
     connector::Signifyd,
@@ -416,6 +419,7 @@ default_imp_for_connector_redirect_response!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Shift4,
     connector::Signifyd,
@@ -484,6 +488,7 @@ default_imp_for_connector_request_id!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Shift4,
     connector::Signifyd,
@@ -573,6 +578,7 @@ default_imp_for_accept_dispute!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Shift4,
     connector::Signifyd,
@@ -681,6 +687,7 @@ default_imp_for_file_upload!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Shift4,
     connector::Signifyd,
@@ -767,6 +774,7 @@ default_imp_for_submit_evidence!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Shift4,
     connector::Signifyd,
@@ -853,6 +861,7 @@ default_imp_for_defend_dispute!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Shift4,
     connector::Signifyd,
@@ -1002,6 +1011,7 @@ default_imp_for_payouts!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Signifyd,
     connector::Square,
@@ -1086,6 +1096,7 @@ default_imp_for_payouts_create!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Signifyd,
     connector::Square,
@@ -1174,6 +1185,7 @@ default_imp_for_payouts_eligibility!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Signifyd,
     connector::Square,
@@ -1259,6 +1271,7 @@ default_imp_for_payouts_fulfill!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Signifyd,
     connector::Square,
@@ -1344,6 +1357,7 @@ default_imp_for_payouts_cancel!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Signifyd,
     connector::Square,
@@ -1430,6 +1444,7 @@ default_imp_for_payouts_quote!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Signifyd,
     connector::Square,
@@ -1517,6 +1532,7 @@ default_imp_for_payouts_recipient!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Signifyd,
     connector::Square,
@@ -1607,6 +1623,7 @@ default_imp_for_payouts_recipient_account!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Signifyd,
     connector::Square,
@@ -1694,6 +1711,7 @@ default_imp_for_approve!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Signifyd,
     connector::Square,
@@ -1782,6 +1800,7 @@ default_imp_for_reject!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Signifyd,
     connector::Square,
@@ -1854,6 +1873,7 @@ default_imp_for_fraud_check!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Square,
     connector::Stax,
     connector::Stripe,
@@ -1942,6 +1962,7 @@ default_imp_for_frm_sale!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Square,
     connector::Stax,
     connector::Stripe,
@@ -2030,6 +2051,7 @@ default_imp_for_frm_checkout!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Square,
     connector::Stax,
     connector::Stripe,
@@ -2118,6 +2140,7 @@ default_imp_for_frm_transaction!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Square,
     connector::Stax,
     connector::Stripe,
@@ -2206,6 +2229,7 @@ default_imp_for_frm_fulfillment!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Square,
     connector::Stax,
     connector::Stripe,
@@ -2294,6 +2318,7 @@ default_imp_for_frm_record_return!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Square,
     connector::Stax,
     connector::Stripe,
@@ -2379,6 +2404,7 @@ default_imp_for_incremental_authorization!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Signifyd,
     connector::Square,
@@ -2463,6 +2489,7 @@ default_imp_for_revoking_mandates!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Signifyd,
     connector::Square,
@@ -2590,6 +2617,7 @@ default_imp_for_connector_authentication!(
     connector::Powertranz,
     connector::Prophetpay,
     connector::Rapyd,
+    connector::Razorpay,
     connector::Riskified,
     connector::Signifyd,
     connector::Square,
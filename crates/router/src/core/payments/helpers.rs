@@ -6,7 +6,7 @@ use api_models::{
 };
 use base64::Engine;
 use common_utils::{
-    ext_traits::{AsyncExt, ByteSliceExt, Encode, ValueExt},
+    ext_traits::{AsyncExt, ByteSliceExt, Encode, StringExt, ValueExt},
     fp_utils, generate_id, pii,
 };
 use diesel_models::enums;
@@ -36,6 +36,7 @@ use crate::{
     connector,
     consts::{self, BASE64_ENGINE},
     core::{
+        card_bin,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers::MandateGenericData,
         payment_methods::{cards, vault, PaymentMethodRetrieve},
@@ -101,6 +102,168 @@ pub fn filter_mca_based_on_business_profile(
     }
 }
 
+/// Ensure that the payment method type is enabled on at least one of the connector accounts of the
+/// profile, for the currency, billing country and card network of the payment.
+///
+/// The check is skipped when none of the connector accounts of the profile have any payment
+/// methods configured.
+#[instrument(skip_all)]
+#[allow(clippy::too_many_arguments)]
+pub async fn validate_payment_method_type_enabled(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &str,
+    payment_method: Option<api_enums::PaymentMethod>,
+    payment_method_type: Option<api_enums::PaymentMethodType>,
+    currency: Option<api_enums::Currency>,
+    country: Option<api_enums::CountryAlpha2>,
+    card_networks: &[api_enums::CardNetwork],
+) -> RouterResult<()> {
+    let Some(payment_method_type) = payment_method_type else {
+        return Ok(());
+    };
+
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_account.merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let payment_methods_enabled = filter_mca_based_on_business_profile(
+        merchant_connector_accounts,
+        Some(profile_id.to_string()),
+    )
+    .into_iter()
+    .filter(|mca| mca.connector_type == enums::ConnectorType::PaymentProcessor)
+    .filter_map(|mca| mca.payment_methods_enabled)
+    .flatten()
+    .filter_map(|payment_method_enabled| {
+        payment_method_enabled
+            .parse_value::<admin::PaymentMethodsEnabled>("PaymentMethodsEnabled")
+            .map_err(
+                |error| logger::error!(%error, "Failed to deserialize payment_methods_enabled"),
+            )
+            .ok()
+    })
+    .collect::<Vec<_>>();
+
+    if payment_methods_enabled.is_empty() {
+        return Ok(());
+    }
+
+    fp_utils::when(
+        !is_payment_method_type_enabled(
+            &payment_methods_enabled,
+            payment_method,
+            payment_method_type,
+            currency,
+            country,
+            card_networks,
+        ),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Payment method type {payment_method_type} is not enabled for this payment"
+                ),
+            }))
+        },
+    )
+}
+
+/// Whether any of the enabled payment methods accepts the payment method type for the currency,
+/// country and card networks of the payment.
+///
+/// A co-badged card can be processed on any of its networks, so the card is accepted as soon as
+/// one of them is enabled.
+fn is_payment_method_type_enabled(
+    payment_methods_enabled: &[admin::PaymentMethodsEnabled],
+    payment_method: Option<api_enums::PaymentMethod>,
+    payment_method_type: api_enums::PaymentMethodType,
+    currency: Option<api_enums::Currency>,
+    country: Option<api_enums::CountryAlpha2>,
+    card_networks: &[api_enums::CardNetwork],
+) -> bool {
+    payment_methods_enabled
+        .iter()
+        .filter(|payment_method_enabled| {
+            payment_method.map_or(true, |payment_method| {
+                payment_method_enabled.payment_method == payment_method
+            })
+        })
+        .flat_map(|payment_method_enabled| payment_method_enabled.payment_method_types.iter())
+        .flatten()
+        .filter(|enabled_type| enabled_type.payment_method_type == payment_method_type)
+        .any(|enabled_type| {
+            let is_currency_accepted = currency
+                .zip(enabled_type.accepted_currencies.as_ref())
+                .map_or(
+                    true,
+                    |(currency, accepted_currencies)| match accepted_currencies {
+                        api_models::admin::AcceptedCurrencies::EnableOnly(enabled) => {
+                            enabled.contains(&currency)
+                        }
+                        api_models::admin::AcceptedCurrencies::DisableOnly(disabled) => {
+                            !disabled.contains(&currency)
+                        }
+                        api_models::admin::AcceptedCurrencies::AllAccepted => true,
+                    },
+                );
+            let is_country_accepted = country
+                .zip(enabled_type.accepted_countries.as_ref())
+                .map_or(
+                    true,
+                    |(country, accepted_countries)| match accepted_countries {
+                        api_models::admin::AcceptedCountries::EnableOnly(enabled) => {
+                            enabled.contains(&country)
+                        }
+                        api_models::admin::AcceptedCountries::DisableOnly(disabled) => {
+                            !disabled.contains(&country)
+                        }
+                        api_models::admin::AcceptedCountries::AllAccepted => true,
+                    },
+                );
+            let is_card_network_accepted = enabled_type
+                .card_networks
+                .as_ref()
+                .filter(|_| !card_networks.is_empty())
+                .map_or(true, |enabled_card_networks| {
+                    card_networks
+                        .iter()
+                        .any(|card_network| enabled_card_networks.contains(card_network))
+                });
+
+            is_currency_accepted && is_country_accepted && is_card_network_accepted
+        })
+}
+
+/// Card networks of the card in the payment method data.
+///
+/// A co-badged card carries the network chosen in the request besides the network detected from
+/// its card number, and can be processed on either of them.
+pub fn get_card_networks_from_payment_method_data(
+    payment_method_data: Option<&api::PaymentMethodData>,
+) -> Vec<api_enums::CardNetwork> {
+    match payment_method_data {
+        Some(api::PaymentMethodData::Card(card)) => {
+            let detected_card_network = card_bin::get_bundled_card_network(
+                card.card_number.clone().get_card_isin().as_str(),
+            );
+            let mut card_networks = card.card_network.iter().cloned().collect::<Vec<_>>();
+            card_networks
+                .extend(detected_card_network.filter(|detected_card_network| {
+                    !card_networks.contains(detected_card_network)
+                }));
+            card_networks
+        }
+        _ => Vec::new(),
+    }
+}
+
 #[instrument(skip_all)]
 #[allow(clippy::too_many_arguments)]
 pub async fn create_or_update_address_for_payment_by_request(
@@ -411,6 +574,123 @@ pub async fn get_address_by_id(
     }
 }
 
+/// For off-session payments created against a customer without any payment method details,
+/// fall back to the customer's default payment method as the recurring details of the payment.
+#[instrument(skip_all)]
+pub async fn populate_default_payment_method_for_off_session_payment<'a>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    request: &'a api_models::payments::PaymentsRequest,
+) -> RouterResult<Cow<'a, api_models::payments::PaymentsRequest>> {
+    let has_payment_method_details = request.payment_method_data.is_some()
+        || request.payment_token.is_some()
+        || request.mandate_id.is_some()
+        || request.mandate_data.is_some()
+        || request.recurring_details.is_some();
+
+    if request.off_session != Some(true)
+        || request.setup_future_usage.is_some()
+        || has_payment_method_details
+    {
+        return Ok(Cow::Borrowed(request));
+    }
+
+    let Some(customer_id) = request
+        .customer_id
+        .as_ref()
+        .or_else(|| request.customer.as_ref().map(|customer| &customer.id))
+    else {
+        return Ok(Cow::Borrowed(request));
+    };
+
+    let default_payment_method_id = state
+        .store
+        .find_customer_optional_by_customer_id_merchant_id(
+            customer_id,
+            &merchant_account.merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the customer")?
+        .and_then(|customer| customer.default_payment_method_id);
+
+    Ok(match default_payment_method_id {
+        Some(payment_method_id) => {
+            logger::debug!(
+                "Using default payment method {} of customer for off-session payment",
+                payment_method_id
+            );
+            Cow::Owned(api_models::payments::PaymentsRequest {
+                recurring_details: Some(RecurringDetails::PaymentMethodId(payment_method_id)),
+                ..request.clone()
+            })
+        }
+        None => Cow::Borrowed(request),
+    })
+}
+
+/// Merchant level defaults for payments, configured as a JSON value under the
+/// `payment_defaults_{merchant_id}` config key.
+#[derive(Debug, Default, PartialEq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantPaymentDefaults {
+    pub capture_method: Option<api_enums::CaptureMethod>,
+    pub authentication_type: Option<api_enums::AuthenticationType>,
+    pub currency: Option<api_enums::Currency>,
+    /// Delay after which payments with manual capture are captured automatically, unless the
+    /// payment specifies `capture_on`
+    pub auto_capture_delay_in_secs: Option<u32>,
+}
+
+/// Fill in the fields that are not provided in a payment create request from the merchant level
+/// payment defaults. The return URL needs no handling here, since the return URL configured on the
+/// business profile or merchant account is already used when the payment does not specify one.
+#[instrument(skip_all)]
+pub async fn populate_merchant_payment_defaults<'a>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    request: &'a api_models::payments::PaymentsRequest,
+) -> RouterResult<Cow<'a, api_models::payments::PaymentsRequest>> {
+    let key = format!("payment_defaults_{}", merchant_account.merchant_id);
+
+    // Merchants without payment defaults get an empty config, so that the lookup is served from
+    // the config cache instead of querying the database on every payment
+    let payment_defaults = state
+        .store
+        .find_config_by_key_unwrap_or(&key, Some("{}".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant payment defaults")?
+        .config
+        .parse_struct::<MerchantPaymentDefaults>("MerchantPaymentDefaults")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse merchant payment defaults")?;
+
+    if payment_defaults == MerchantPaymentDefaults::default() {
+        return Ok(Cow::Borrowed(request));
+    }
+
+    let mut request = request.clone();
+    request.capture_method = request.capture_method.or(payment_defaults.capture_method);
+    request.authentication_type = request
+        .authentication_type
+        .or(payment_defaults.authentication_type);
+    request.currency = request.currency.or(payment_defaults.currency);
+
+    if request.capture_method == Some(api_enums::CaptureMethod::Manual)
+        && request.capture_on.is_none()
+    {
+        request.capture_on = payment_defaults.auto_capture_delay_in_secs.map(|delay| {
+            common_utils::date_time::now().saturating_add(time::Duration::seconds(i64::from(delay)))
+        });
+    }
+
+    Ok(Cow::Owned(request))
+}
+
 pub async fn get_token_pm_type_mandate_details(
     state: &AppState,
     request: &api::PaymentsRequest,
@@ -691,6 +971,89 @@ pub async fn get_token_for_recurring_mandate(
     }
 }
 
+/// Updates the payment intent with the outcome of a connector call, connector webhook or status
+/// sync. Unlike API initiated updates, these outcomes have already happened at the connector, so
+/// they are applied again on the latest payment intent if a concurrent request modified it since it
+/// was read, instead of being rejected. An outcome is dropped if the concurrent update already moved
+/// the payment intent to a terminal status.
+#[instrument(skip_all)]
+pub async fn update_payment_intent_with_connector_outcome(
+    db: &dyn StorageInterface,
+    payment_intent: PaymentIntent,
+    payment_intent_update: storage::PaymentIntentUpdate,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> CustomResult<PaymentIntent, errors::StorageError> {
+    let mut payment_intent = payment_intent;
+    let mut attempts = 1;
+    loop {
+        match db
+            .update_payment_intent(
+                payment_intent.clone(),
+                payment_intent_update.clone(),
+                storage_scheme,
+            )
+            .await
+        {
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    errors::StorageError::ConcurrentModification
+                ) && attempts < consts::PAYMENT_INTENT_MAX_UPDATE_ATTEMPTS =>
+            {
+                logger::info!(
+                    payment_id = %payment_intent.payment_id,
+                    "Payment intent was modified concurrently, applying the update on the latest payment intent"
+                );
+                payment_intent = db
+                    .find_payment_intent_by_payment_id_merchant_id(
+                        &payment_intent.payment_id,
+                        &payment_intent.merchant_id,
+                        storage_scheme,
+                    )
+                    .await?;
+                if is_outdated_connector_outcome(payment_intent.status, &payment_intent_update) {
+                    logger::info!(
+                        payment_id = %payment_intent.payment_id,
+                        status = ?payment_intent.status,
+                        "Dropping outdated update for a payment intent that was concurrently moved to a terminal status"
+                    );
+                    return Ok(payment_intent);
+                }
+                attempts += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// An outcome that raced with another update is outdated if the other update already moved the
+/// payment intent to a terminal status, for example a late `processing` response arriving after a
+/// webhook marked the payment as succeeded.
+fn is_outdated_connector_outcome(
+    latest_status: storage_enums::IntentStatus,
+    payment_intent_update: &storage::PaymentIntentUpdate,
+) -> bool {
+    let is_terminal = matches!(
+        latest_status,
+        storage_enums::IntentStatus::Succeeded
+            | storage_enums::IntentStatus::Failed
+            | storage_enums::IntentStatus::Cancelled
+            | storage_enums::IntentStatus::PartiallyCaptured
+    );
+    let updated_status = match payment_intent_update {
+        storage::PaymentIntentUpdate::ResponseUpdate { status, .. }
+        | storage::PaymentIntentUpdate::MerchantStatusUpdate { status, .. }
+        | storage::PaymentIntentUpdate::PGStatusUpdate { status, .. }
+        | storage::PaymentIntentUpdate::Update { status, .. }
+        | storage::PaymentIntentUpdate::StatusAndAttemptUpdate { status, .. }
+        | storage::PaymentIntentUpdate::ApproveUpdate { status, .. }
+        | storage::PaymentIntentUpdate::RejectUpdate { status, .. } => Some(*status),
+        storage::PaymentIntentUpdate::ReturnUrlUpdate { status, .. } => *status,
+        _ => None,
+    };
+    is_terminal && updated_status.is_some_and(|status| status != latest_status)
+}
+
 #[instrument(skip_all)]
 /// Check weather the merchant id in the request
 /// and merchant id in the merchant account are same.
@@ -1837,6 +2200,7 @@ pub async fn retrieve_payment_token_data(
     state: &AppState,
     token: String,
     payment_method: Option<storage_enums::PaymentMethod>,
+    payment_id: &str,
 ) -> RouterResult<storage::PaymentTokenData> {
     let redis_conn = state
         .store
@@ -1863,12 +2227,19 @@ pub async fn retrieve_payment_token_data(
 
     let token_data_result = token_data_string
         .clone()
-        .parse_struct("PaymentTokenData")
+        .parse_struct::<storage::PaymentTokenRecord>("PaymentTokenRecord")
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("failed to deserialize hyperswitch token data");
 
     let token_data = match token_data_result {
-        Ok(data) => data,
+        Ok(token_record) => {
+            utils::when(!token_record.is_usable_for_payment(payment_id), || {
+                Err(report!(errors::ApiErrorResponse::UnprocessableEntity {
+                    message: "Token is invalid or expired".to_owned(),
+                }))
+            })?;
+            token_record.token_data
+        }
         Err(e) => {
             // The purpose of this logic is backwards compatibility to support tokens
             // in redis that might be following the old format.
@@ -2010,6 +2381,8 @@ pub async fn store_in_vault_and_generate_ppmt(
             .insert(
                 Some(payment_intent.created_at),
                 storage::PaymentTokenData::temporary_generic(router_token),
+                storage::PaymentTokenScope::SingleUse,
+                Some(payment_intent.payment_id.clone()),
                 state,
             )
             .await?;
@@ -2638,6 +3011,7 @@ pub fn generate_mandate(
     payment_method_data_option: Option<domain::payments::PaymentMethodData>,
     mandate_reference: Option<MandateReference>,
     merchant_connector_id: Option<String>,
+    test_mode: Option<bool>,
 ) -> CustomResult<Option<storage::MandateNew>, errors::ApiErrorResponse> {
     match (setup_mandate_details, customer_id) {
         (Some(data), Some(cus_id)) => {
@@ -2674,7 +3048,8 @@ pub fn generate_mandate(
                 .set_connector_mandate_id(
                     mandate_reference.and_then(|reference| reference.connector_mandate_id),
                 )
-                .set_merchant_connector_id(merchant_connector_id);
+                .set_merchant_connector_id(merchant_connector_id)
+                .set_test_mode(test_mode);
 
             Ok(Some(
                 match data.mandate_type.get_required_value("mandate_type")? {
@@ -2947,12 +3322,208 @@ mod tests {
                     .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
             ),
             request_external_three_ds_authentication: None,
+            test_mode: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_ok());
         // Check if the result is an Ok variant
     }
 
+    #[allow(clippy::expect_used)]
+    #[tokio::test]
+    async fn test_connector_outcome_is_applied_on_concurrently_modified_payment_intent() {
+        let mockdb = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock store");
+        let storage_scheme = storage_enums::MerchantStorageScheme::PostgresOnly;
+        let payment_intent = PaymentIntent {
+            id: 1,
+            payment_id: "pay_concurrent".to_string(),
+            merchant_id: "merchant_concurrent".to_string(),
+            status: storage_enums::IntentStatus::Processing,
+            amount: 200,
+            currency: Some(storage_enums::Currency::USD),
+            amount_captured: None,
+            customer_id: None,
+            description: None,
+            return_url: None,
+            metadata: None,
+            connector_id: None,
+            shipping_address_id: None,
+            billing_address_id: None,
+            statement_descriptor_name: None,
+            statement_descriptor_suffix: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            last_synced: None,
+            setup_future_usage: None,
+            fingerprint_id: None,
+            off_session: None,
+            client_secret: None,
+            active_attempt: hyperswitch_domain_models::RemoteStorageObject::ForeignID(
+                "pay_concurrent_1".to_string(),
+            ),
+            business_country: None,
+            business_label: None,
+            order_details: None,
+            allowed_payment_method_types: None,
+            connector_metadata: None,
+            feature_metadata: None,
+            attempt_count: 1,
+            payment_link_id: None,
+            profile_id: None,
+            merchant_decision: None,
+            payment_confirm_source: None,
+            surcharge_applicable: None,
+            updated_by: storage_scheme.to_string(),
+            request_incremental_authorization: None,
+            incremental_authorization_allowed: None,
+            authorization_count: None,
+            session_expiry: None,
+            request_external_three_ds_authentication: None,
+            test_mode: None,
+        };
+        mockdb
+            .payment_intents
+            .lock()
+            .await
+            .push(payment_intent.clone());
+
+        // Another request modifies the payment intent after it was read
+        let mut stale_payment_intent = payment_intent.clone();
+        stale_payment_intent.modified_at = payment_intent.modified_at - time::Duration::seconds(1);
+        let payment_intent_update = storage::PaymentIntentUpdate::PGStatusUpdate {
+            status: storage_enums::IntentStatus::Succeeded,
+            updated_by: storage_scheme.to_string(),
+            incremental_authorization_allowed: None,
+        };
+
+        let db: &dyn StorageInterface = &mockdb;
+        let api_update_error = db
+            .update_payment_intent(
+                stale_payment_intent.clone(),
+                payment_intent_update.clone(),
+                storage_scheme,
+            )
+            .await
+            .expect_err("Stale API initiated update should be rejected");
+        assert!(matches!(
+            api_update_error.current_context(),
+            errors::StorageError::ConcurrentModification
+        ));
+
+        let updated_payment_intent = update_payment_intent_with_connector_outcome(
+            db,
+            stale_payment_intent,
+            payment_intent_update,
+            storage_scheme,
+        )
+        .await
+        .expect("Connector outcome should be applied on the latest payment intent");
+        assert_eq!(
+            updated_payment_intent.status,
+            storage_enums::IntentStatus::Succeeded
+        );
+    }
+
+    #[allow(clippy::expect_used)]
+    #[tokio::test]
+    async fn test_outdated_connector_outcome_is_dropped_on_concurrently_modified_payment_intent() {
+        let mockdb = crate::db::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock store");
+        let storage_scheme = storage_enums::MerchantStorageScheme::PostgresOnly;
+        let payment_intent = PaymentIntent {
+            id: 1,
+            payment_id: "pay_outdated".to_string(),
+            merchant_id: "merchant_outdated".to_string(),
+            status: storage_enums::IntentStatus::Succeeded,
+            amount: 200,
+            currency: Some(storage_enums::Currency::USD),
+            amount_captured: None,
+            customer_id: None,
+            description: None,
+            return_url: None,
+            metadata: None,
+            connector_id: None,
+            shipping_address_id: None,
+            billing_address_id: None,
+            statement_descriptor_name: None,
+            statement_descriptor_suffix: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            last_synced: None,
+            setup_future_usage: None,
+            fingerprint_id: None,
+            off_session: None,
+            client_secret: None,
+            active_attempt: hyperswitch_domain_models::RemoteStorageObject::ForeignID(
+                "pay_outdated_1".to_string(),
+            ),
+            business_country: None,
+            business_label: None,
+            order_details: None,
+            allowed_payment_method_types: None,
+            connector_metadata: None,
+            feature_metadata: None,
+            attempt_count: 1,
+            payment_link_id: None,
+            profile_id: None,
+            merchant_decision: None,
+            payment_confirm_source: None,
+            surcharge_applicable: None,
+            updated_by: storage_scheme.to_string(),
+            request_incremental_authorization: None,
+            incremental_authorization_allowed: None,
+            authorization_count: None,
+            session_expiry: None,
+            request_external_three_ds_authentication: None,
+            test_mode: None,
+        };
+        mockdb
+            .payment_intents
+            .lock()
+            .await
+            .push(payment_intent.clone());
+
+        // A webhook marked the payment as succeeded after the failure response was read
+        let mut stale_payment_intent = payment_intent.clone();
+        stale_payment_intent.status = storage_enums::IntentStatus::Processing;
+        stale_payment_intent.modified_at = payment_intent.modified_at - time::Duration::seconds(1);
+        let payment_intent_update = storage::PaymentIntentUpdate::PGStatusUpdate {
+            status: storage_enums::IntentStatus::Failed,
+            updated_by: storage_scheme.to_string(),
+            incremental_authorization_allowed: None,
+        };
+
+        let db: &dyn StorageInterface = &mockdb;
+        let updated_payment_intent = update_payment_intent_with_connector_outcome(
+            db,
+            stale_payment_intent,
+            payment_intent_update,
+            storage_scheme,
+        )
+        .await
+        .expect("Outdated connector outcome should be dropped");
+        assert_eq!(
+            updated_payment_intent.status,
+            storage_enums::IntentStatus::Succeeded
+        );
+
+        let stored_payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &payment_intent.payment_id,
+                &payment_intent.merchant_id,
+                storage_scheme,
+            )
+            .await
+            .expect("Payment intent should exist");
+        assert_eq!(
+            stored_payment_intent.status,
+            storage_enums::IntentStatus::Succeeded
+        );
+    }
+
     #[test]
     fn test_authenticate_client_secret_fulfillment_time_expired() {
         let payment_intent = PaymentIntent {
@@ -3005,6 +3576,7 @@ mod tests {
                     .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
             ),
             request_external_three_ds_authentication: None,
+            test_mode: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent,).is_err())
@@ -3062,10 +3634,119 @@ mod tests {
                     .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
             ),
             request_external_three_ds_authentication: None,
+            test_mode: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
     }
+
+    fn credit_card_enabled_on(
+        card_networks: Option<Vec<api_enums::CardNetwork>>,
+    ) -> Vec<admin::PaymentMethodsEnabled> {
+        vec![admin::PaymentMethodsEnabled {
+            payment_method: api_enums::PaymentMethod::Card,
+            payment_method_types: Some(vec![
+                api_models::payment_methods::RequestPaymentMethodTypes {
+                    payment_method_type: api_enums::PaymentMethodType::Credit,
+                    payment_experience: None,
+                    card_networks,
+                    accepted_currencies: None,
+                    accepted_countries: None,
+                    minimum_amount: None,
+                    maximum_amount: None,
+                    recurring_enabled: true,
+                    installment_payment_enabled: true,
+                },
+            ]),
+        }]
+    }
+
+    fn is_credit_card_enabled(
+        payment_methods_enabled: &[admin::PaymentMethodsEnabled],
+        card_networks: &[api_enums::CardNetwork],
+    ) -> bool {
+        is_payment_method_type_enabled(
+            payment_methods_enabled,
+            Some(api_enums::PaymentMethod::Card),
+            api_enums::PaymentMethodType::Credit,
+            Some(api_enums::Currency::EUR),
+            Some(api_enums::CountryAlpha2::FR),
+            card_networks,
+        )
+    }
+
+    #[test]
+    fn test_co_badged_card_accepted_on_any_of_its_networks() {
+        let co_badged_card = [
+            api_enums::CardNetwork::CartesBancaires,
+            api_enums::CardNetwork::Visa,
+        ];
+
+        assert!(is_credit_card_enabled(
+            &credit_card_enabled_on(Some(vec![api_enums::CardNetwork::Visa])),
+            &co_badged_card,
+        ));
+        assert!(is_credit_card_enabled(
+            &credit_card_enabled_on(Some(vec![api_enums::CardNetwork::CartesBancaires])),
+            &co_badged_card,
+        ));
+        assert!(!is_credit_card_enabled(
+            &credit_card_enabled_on(Some(vec![api_enums::CardNetwork::Mastercard])),
+            &co_badged_card,
+        ));
+    }
+
+    #[test]
+    fn test_card_networks_not_restricted() {
+        assert!(is_credit_card_enabled(
+            &credit_card_enabled_on(None),
+            &[api_enums::CardNetwork::Visa],
+        ));
+        assert!(is_credit_card_enabled(
+            &credit_card_enabled_on(Some(vec![api_enums::CardNetwork::Mastercard])),
+            &[],
+        ));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_card_networks_of_co_badged_card() {
+        let card = |card_network| {
+            api::PaymentMethodData::Card(api::Card {
+                card_number: "4000056655665556".parse().unwrap(),
+                card_exp_month: "12".to_string().into(),
+                card_exp_year: "2030".to_string().into(),
+                card_holder_name: None,
+                card_cvc: "123".to_string().into(),
+                card_issuer: None,
+                card_network,
+                card_type: None,
+                card_issuing_country: None,
+                bank_code: None,
+                nick_name: None,
+            })
+        };
+
+        assert_eq!(
+            get_card_networks_from_payment_method_data(Some(&card(Some(
+                api_enums::CardNetwork::CartesBancaires
+            )))),
+            vec![
+                api_enums::CardNetwork::CartesBancaires,
+                api_enums::CardNetwork::Visa
+            ]
+        );
+        assert_eq!(
+            get_card_networks_from_payment_method_data(Some(&card(Some(
+                api_enums::CardNetwork::Visa
+            )))),
+            vec![api_enums::CardNetwork::Visa]
+        );
+        assert_eq!(
+            get_card_networks_from_payment_method_data(Some(&card(None))),
+            vec![api_enums::CardNetwork::Visa]
+        );
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -3156,6 +3837,7 @@ impl MerchantConnectorAccountType {
 
 /// Query for merchant connector account either by business label or profile id
 /// If profile_id is passed use it, or use connector_label to query merchant connector account
+/// In test mode, the test credentials of the connector account are used instead of the live ones
 #[instrument(skip_all)]
 pub async fn get_merchant_connector_account(
     state: &AppState,
@@ -3165,6 +3847,7 @@ pub async fn get_merchant_connector_account(
     profile_id: &String,
     connector_name: &str,
     merchant_connector_id: Option<&String>,
+    test_mode: bool,
 ) -> RouterResult<MerchantConnectorAccountType> {
     let db = &*state.store;
     match creds_identifier {
@@ -3230,10 +3913,39 @@ pub async fn get_merchant_connector_account(
                 )
             }
         }
+        .and_then(|merchant_connector_account| {
+            if test_mode {
+                use_test_connector_account_details(merchant_connector_account)
+            } else {
+                Ok(merchant_connector_account)
+            }
+        })
         .map(MerchantConnectorAccountType::DbVal),
     }
 }
 
+/// Replace the connector account details of the merchant connector account with its test
+/// connector account details, for requests authenticated with a test mode API key. Live
+/// credentials are never used for such requests.
+pub fn use_test_connector_account_details(
+    mut merchant_connector_account: domain::MerchantConnectorAccount,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    let test_connector_account_details = merchant_connector_account
+        .test_connector_account_details
+        .take()
+        .ok_or_else(|| errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Test connector account details are not configured for the merchant connector account {}",
+                merchant_connector_account.merchant_connector_id
+            ),
+        })?;
+
+    merchant_connector_account.connector_account_details = test_connector_account_details;
+    merchant_connector_account.test_mode = Some(true);
+
+    Ok(merchant_connector_account)
+}
+
 /// This function replaces the request and response type of routerdata with the
 /// request and response type passed
 /// # Arguments
@@ -3494,6 +4206,9 @@ impl AttemptType {
             // New payment method billing address can be passed for a retry
             payment_method_billing_address_id: None,
             fingerprint_id: None,
+            estimated_connector_fee: None,
+            three_ds_decision_reason: None,
+            routing_decision: None,
         }
     }
 
@@ -3643,6 +4358,79 @@ mod test {
             super::get_payment_id_from_client_secret(client_secret3).unwrap()
         );
     }
+
+    fn get_merchant_connector_account(
+        test_connector_account_details: Option<serde_json::Value>,
+    ) -> super::domain::MerchantConnectorAccount {
+        let encryptable = |value: serde_json::Value| {
+            common_utils::crypto::Encryptable::new(
+                masking::Secret::new(value),
+                masking::Secret::new(Vec::new()),
+            )
+        };
+        super::domain::MerchantConnectorAccount {
+            id: None,
+            merchant_id: "merchant_1".to_string(),
+            connector_name: "stripe".to_string(),
+            connector_account_details: encryptable(
+                serde_json::json!({ "auth_type": "HeaderKey", "api_key": "sk_live" }),
+            ),
+            test_mode: None,
+            disabled: None,
+            merchant_connector_id: "mca_1".to_string(),
+            payment_methods_enabled: None,
+            connector_type: common_enums::ConnectorType::PaymentProcessor,
+            metadata: None,
+            frm_configs: None,
+            connector_label: None,
+            business_country: None,
+            business_label: None,
+            business_sub_label: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            connector_webhook_details: None,
+            profile_id: None,
+            applepay_verified_domains: None,
+            pm_auth_config: None,
+            status: common_enums::ConnectorStatus::Active,
+            test_connector_account_details: test_connector_account_details.map(encryptable),
+        }
+    }
+
+    #[test]
+    fn test_use_test_connector_account_details() {
+        use masking::PeekInterface;
+
+        let merchant_connector_account =
+            super::use_test_connector_account_details(get_merchant_connector_account(Some(
+                serde_json::json!({ "auth_type": "HeaderKey", "api_key": "sk_test" }),
+            )))
+            .unwrap();
+
+        assert_eq!(
+            merchant_connector_account
+                .connector_account_details
+                .peek()
+                .get("api_key"),
+            Some(&serde_json::json!("sk_test"))
+        );
+        assert_eq!(merchant_connector_account.test_mode, Some(true));
+        assert!(merchant_connector_account
+            .test_connector_account_details
+            .is_none());
+    }
+
+    #[test]
+    fn test_use_test_connector_account_details_without_test_details() {
+        let error = super::use_test_connector_account_details(get_merchant_connector_account(None))
+            .unwrap_err();
+
+        // A test mode request must never fall back to the live credentials
+        assert!(matches!(
+            error.current_context(),
+            super::errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+    }
 }
 
 #[instrument(skip_all)]
@@ -3702,6 +4490,11 @@ pub async fn get_additional_payment_data(
                     })
                     .await
                     .flatten()
+                    .or_else(|| {
+                        card_isin
+                            .as_deref()
+                            .and_then(card_bin::get_bundled_card_bin_info)
+                    })
                     .map(|card_info| {
                         api_models::payments::AdditionalPaymentData::Card(Box::new(
                             api_models::payments::AdditionalCardInfo {
@@ -151,8 +151,13 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .zip(payment_method.or(payment_attempt.payment_method))
         {
             Some(
-                helpers::retrieve_payment_token_data(state, token.clone(), Some(payment_method))
-                    .await?,
+                helpers::retrieve_payment_token_data(
+                    state,
+                    token.clone(),
+                    Some(payment_method),
+                    &payment_intent.payment_id,
+                )
+                .await?,
             )
         } else {
             None
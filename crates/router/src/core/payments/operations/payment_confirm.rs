@@ -53,6 +53,15 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         auth_flow: services::AuthFlow,
         payment_confirm_source: Option<common_enums::PaymentSource>,
     ) -> RouterResult<operations::GetTrackerResponse<'a, F, api::PaymentsRequest, Ctx>> {
+        let request = helpers::populate_default_payment_method_for_off_session_payment(
+            state,
+            merchant_account,
+            key_store,
+            request,
+        )
+        .await?;
+        let request = request.as_ref();
+
         let merchant_id = &merchant_account.merchant_id;
         let storage_scheme = merchant_account.storage_scheme;
         let (currency, amount);
@@ -527,6 +536,26 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 .as_ref()
                 .and_then(|pm_info| pm_info.payment_method_type));
 
+        helpers::validate_payment_method_type_enabled(
+            state,
+            merchant_account,
+            key_store,
+            &business_profile.profile_id,
+            payment_attempt.payment_method,
+            payment_attempt.payment_method_type,
+            payment_attempt.currency.or(payment_intent.currency),
+            billing_address
+                .as_ref()
+                .and_then(|billing_address| billing_address.country),
+            &helpers::get_card_networks_from_payment_method_data(
+                request
+                    .payment_method_data
+                    .as_ref()
+                    .and_then(|pmd| pmd.payment_method_data.as_ref()),
+            ),
+        )
+        .await?;
+
         let token = token.or_else(|| payment_attempt.payment_token.clone());
 
         helpers::validate_pm_or_token_given(
@@ -545,6 +574,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 state,
                 token,
                 payment_method.or(payment_attempt.payment_method),
+                &payment_intent.payment_id,
             )
             .await?;
 
@@ -840,6 +870,7 @@ impl<F: Clone + Send, Ctx: PaymentMethodRetrieve> Domain<F, api::PaymentsRequest
                 profile_id,
                 &authentication_connector_name,
                 None,
+                payment_data.payment_intent.test_mode.unwrap_or_default(),
             )
             .await?;
             if let Some(authentication) = authentication {
@@ -865,6 +896,7 @@ impl<F: Clone + Send, Ctx: PaymentMethodRetrieve> Domain<F, api::PaymentsRequest
                     profile_id,
                     &payment_connector,
                     None,
+                    payment_data.payment_intent.test_mode.unwrap_or_default(),
                 )
                 .await?;
                 // call pre authn service
@@ -1062,6 +1094,12 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
 
         let connector = payment_data.payment_attempt.connector.clone();
         let merchant_connector_id = payment_data.payment_attempt.merchant_connector_id.clone();
+        let estimated_connector_fee = payment_data.payment_attempt.estimated_connector_fee;
+        let three_ds_decision_reason = payment_data
+            .payment_attempt
+            .three_ds_decision_reason
+            .clone();
+        let routing_decision = payment_data.payment_attempt.routing_decision.clone();
 
         let straight_through_algorithm = payment_data
             .payment_attempt
@@ -1189,6 +1227,9 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                         payment_method_billing_address_id,
                         fingerprint_id: m_fingerprint_id,
                         payment_method_id: m_payment_method_id,
+                        estimated_connector_fee,
+                        three_ds_decision_reason,
+                        routing_decision,
                     },
                     storage_scheme,
                 )
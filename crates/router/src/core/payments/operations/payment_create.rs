@@ -63,6 +63,17 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         _auth_flow: services::AuthFlow,
         _payment_confirm_source: Option<common_enums::PaymentSource>,
     ) -> RouterResult<operations::GetTrackerResponse<'a, F, api::PaymentsRequest, Ctx>> {
+        let request =
+            helpers::populate_merchant_payment_defaults(state, merchant_account, request).await?;
+        let request = helpers::populate_default_payment_method_for_off_session_payment(
+            state,
+            merchant_account,
+            merchant_key_store,
+            &request,
+        )
+        .await?;
+        let request = request.as_ref();
+
         let db = &*state.store;
         let ephemeral_key = Self::get_ephemeral_key(request, state, merchant_account).await;
         let merchant_id = &merchant_account.merchant_id;
@@ -138,6 +149,28 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         )
         .await?;
 
+        helpers::validate_payment_method_type_enabled(
+            state,
+            merchant_account,
+            merchant_key_store,
+            &profile_id,
+            payment_method,
+            payment_method_type,
+            Some(currency),
+            request
+                .billing
+                .as_ref()
+                .and_then(|billing| billing.address.as_ref())
+                .and_then(|address| address.country),
+            &helpers::get_card_networks_from_payment_method_data(
+                request
+                    .payment_method_data
+                    .as_ref()
+                    .and_then(|pmd| pmd.payment_method_data.as_ref()),
+            ),
+        )
+        .await?;
+
         let customer_details = helpers::get_customer_details_from_request(request);
 
         let shipping_address = helpers::create_or_find_address_for_payment_by_request(
@@ -208,6 +241,11 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 ),
             ));
 
+        let statement_descriptor_name = request
+            .statement_descriptor_name
+            .clone()
+            .or_else(|| business_profile.statement_descriptor_name.clone());
+
         let payment_link_data = if let Some(payment_link_create) = request.payment_link {
             if payment_link_create {
                 let merchant_name = merchant_account
@@ -260,6 +298,8 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             attempt_id,
             profile_id.clone(),
             session_expiry,
+            statement_descriptor_name,
+            state.test_mode,
         )
         .await?;
 
@@ -601,6 +641,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
             .clone();
         let authorized_amount = payment_data.payment_attempt.amount;
         let merchant_connector_id = payment_data.payment_attempt.merchant_connector_id.clone();
+        let routing_decision = payment_data.payment_attempt.routing_decision.clone();
 
         let surcharge_amount = payment_data
             .surcharge_details
@@ -627,6 +668,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                     tax_amount,
                     updated_by: storage_scheme.to_string(),
                     merchant_connector_id,
+                    routing_decision,
                 },
                 storage_scheme,
             )
@@ -940,6 +982,9 @@ impl PaymentCreate {
                 fingerprint_id: None,
                 authentication_connector: None,
                 authentication_id: None,
+                estimated_connector_fee: None,
+                three_ds_decision_reason: None,
+                routing_decision: None,
             },
             additional_pm_data,
         ))
@@ -958,6 +1003,8 @@ impl PaymentCreate {
         active_attempt_id: String,
         profile_id: String,
         session_expiry: PrimitiveDateTime,
+        statement_descriptor_name: Option<String>,
+        test_mode: bool,
     ) -> RouterResult<storage::PaymentIntentNew> {
         let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
 
@@ -1018,7 +1065,7 @@ impl PaymentCreate {
             return_url: request.return_url.as_ref().map(|a| a.to_string()),
             shipping_address_id,
             billing_address_id,
-            statement_descriptor_name: request.statement_descriptor_name.clone(),
+            statement_descriptor_name,
             statement_descriptor_suffix: request.statement_descriptor_suffix.clone(),
             metadata: request.metadata.clone(),
             business_country: request.business_country,
@@ -1047,6 +1094,7 @@ impl PaymentCreate {
             session_expiry: Some(session_expiry),
             request_external_three_ds_authentication: request
                 .request_external_three_ds_authentication,
+            test_mode: Some(test_mode),
         })
     }
 
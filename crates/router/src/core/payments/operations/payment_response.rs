@@ -70,6 +70,23 @@ impl<F: Send + Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthor
             .mandate_id
             .or_else(|| router_data.request.mandate_id.clone());
 
+        payments::routing::success_rate::record_authorization_outcome(
+            db,
+            &router_data,
+            router_data.request.payment_method_type,
+            Some(&router_data.request.payment_method_data),
+        )
+        .await;
+        payments::routing::circuit_breaker::record_connector_outcome(
+            db,
+            &router_data,
+            payment_data
+                .payment_attempt
+                .merchant_connector_id
+                .as_deref(),
+        )
+        .await;
+
         payment_data = Box::pin(payment_response_update_tracker(
             db,
             payment_id,
@@ -294,9 +311,9 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsIncrementalAu
         }
         // payment_intent update
         if let Some(payment_intent_update) = option_payment_intent_update {
-            payment_data.payment_intent = db
-                .store
-                .update_payment_intent(
+            payment_data.payment_intent =
+                payments_helpers::update_payment_intent_with_connector_outcome(
+                    &*db.store,
                     payment_data.payment_intent.clone(),
                     payment_intent_update,
                     storage_scheme,
@@ -657,6 +674,14 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::CompleteAuthorizeData
     where
         F: 'b + Send,
     {
+        payments::routing::success_rate::record_authorization_outcome(
+            db,
+            &response,
+            payment_data.payment_attempt.payment_method_type,
+            response.request.payment_method_data.as_ref(),
+        )
+        .await;
+
         Box::pin(payment_response_update_tracker(
             db,
             payment_id,
@@ -1116,12 +1141,14 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         },
     };
 
+    let previous_intent_status = payment_data.payment_intent.status;
     let m_db = state.clone().store;
     let m_payment_data_payment_intent = payment_data.payment_intent.clone();
     let m_payment_intent_update = payment_intent_update.clone();
     let payment_intent_fut = tokio::spawn(
         async move {
-            m_db.update_payment_intent(
+            payments_helpers::update_payment_intent_with_connector_outcome(
+                &*m_db,
                 m_payment_data_payment_intent,
                 m_payment_intent_update,
                 storage_scheme,
@@ -1209,6 +1236,33 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     )?;
 
     payment_data.payment_intent = payment_intent;
+
+    if payment_data.payment_intent.status == enums::IntentStatus::RequiresCapture
+        && previous_intent_status != enums::IntentStatus::RequiresCapture
+    {
+        // Payments which specify `capture_on` are captured by the scheduler once they are authorized
+        if let Some(capture_on) = payment_data.payment_attempt.capture_on.filter(|_| {
+            payment_data.payment_attempt.capture_method == Some(enums::CaptureMethod::Manual)
+        }) {
+            payments::add_scheduled_capture_task(
+                &*state.store,
+                &payment_data.payment_attempt,
+                capture_on,
+            )
+            .await
+            .map_err(|error| logger::error!(scheduled_capture_error=?error))
+            .ok();
+        }
+
+        payments::authorization_expiry::add_authorization_expiry_task(
+            state,
+            &payment_data.payment_attempt,
+        )
+        .await
+        .map_err(|error| logger::error!(authorization_expiry_error=?error))
+        .ok();
+    }
+
     router_data.payment_method_status.and_then(|status| {
         payment_data
             .payment_method_info
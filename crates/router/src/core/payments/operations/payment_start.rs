@@ -115,8 +115,13 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
 
         let token_data = if let Some(token) = payment_attempt.payment_token.clone() {
             Some(
-                helpers::retrieve_payment_token_data(state, token, payment_attempt.payment_method)
-                    .await?,
+                helpers::retrieve_payment_token_data(
+                    state,
+                    token,
+                    payment_attempt.payment_method,
+                    &payment_intent.payment_id,
+                )
+                .await?,
             )
         } else {
             None
@@ -48,6 +48,15 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         auth_flow: services::AuthFlow,
         _payment_confirm_source: Option<common_enums::PaymentSource>,
     ) -> RouterResult<operations::GetTrackerResponse<'a, F, api::PaymentsRequest, Ctx>> {
+        let request = helpers::populate_default_payment_method_for_off_session_payment(
+            state,
+            merchant_account,
+            key_store,
+            request,
+        )
+        .await?;
+        let request = request.as_ref();
+
         let (mut payment_intent, mut payment_attempt, currency): (_, _, storage_enums::Currency);
 
         let payment_id = payment_id
@@ -273,7 +282,15 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         }
 
         let token_data = if let Some(token) = token.clone() {
-            Some(helpers::retrieve_payment_token_data(state, token, payment_method).await?)
+            Some(
+                helpers::retrieve_payment_token_data(
+                    state,
+                    token,
+                    payment_method,
+                    &payment_intent.payment_id,
+                )
+                .await?,
+            )
         } else {
             None
         };
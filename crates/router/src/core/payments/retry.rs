@@ -9,6 +9,7 @@ use router_env::{
 };
 
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
         payment_methods::PaymentMethodRetrieve,
@@ -109,7 +110,12 @@ where
                 None => get_gsm(state, &router_data).await?,
             };
 
-            match get_gsm_decision(gsm) {
+            let gsm_decision = match gsm {
+                Some(gsm) => get_gsm_decision(Some(gsm)),
+                None => get_decision_for_unmapped_error(&router_data),
+            };
+
+            match gsm_decision {
                 api_models::gsm::GsmDecision::Retry => {
                     retries = get_retries(state, retries, &merchant_account.merchant_id).await;
 
@@ -254,6 +260,25 @@ pub fn get_gsm_decision(
     option_gsm_decision.unwrap_or_default()
 }
 
+/// Errors which are not configured in GSM are retried only when they are known not to have reached
+/// the processor. A connector timeout or an internal server error may have been processed already,
+/// retrying those on another connector could charge the customer twice.
+fn get_decision_for_unmapped_error<F, FData>(
+    router_data: &types::RouterData<F, FData, types::PaymentsResponseData>,
+) -> api_models::gsm::GsmDecision {
+    match router_data.response.as_ref() {
+        Err(error_response) if is_error_not_processed_by_connector(error_response) => {
+            api_models::gsm::GsmDecision::Retry
+        }
+        _ => api_models::gsm::GsmDecision::DoDefault,
+    }
+}
+
+fn is_error_not_processed_by_connector(error_response: &types::ErrorResponse) -> bool {
+    error_response.code != consts::REQUEST_TIMEOUT_ERROR_CODE
+        && matches!(error_response.status_code, 502 | 503)
+}
+
 #[inline]
 fn get_flow_name<F>() -> RouterResult<String> {
     Ok(std::any::type_name::<F>()
@@ -450,18 +475,18 @@ where
     // update payment_attempt, connector_response and payment_intent in payment_data
     payment_data.payment_attempt = payment_attempt;
 
-    payment_data.payment_intent = db
-        .update_payment_intent(
-            payment_data.payment_intent.clone(),
-            storage::PaymentIntentUpdate::PaymentAttemptAndAttemptCountUpdate {
-                active_attempt_id: payment_data.payment_attempt.attempt_id.clone(),
-                attempt_count: new_attempt_count,
-                updated_by: storage_scheme.to_string(),
-            },
-            storage_scheme,
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    payment_data.payment_intent = payments::helpers::update_payment_intent_with_connector_outcome(
+        db,
+        payment_data.payment_intent.clone(),
+        storage::PaymentIntentUpdate::PaymentAttemptAndAttemptCountUpdate {
+            active_attempt_id: payment_data.payment_attempt.attempt_id.clone(),
+            attempt_count: new_attempt_count,
+            updated_by: storage_scheme.to_string(),
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
     Ok(())
 }
@@ -574,3 +599,51 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_response(code: &str, status_code: u16) -> types::ErrorResponse {
+        types::ErrorResponse {
+            code: code.to_string(),
+            message: "error".to_string(),
+            reason: None,
+            status_code,
+            attempt_status: None,
+            connector_transaction_id: None,
+        }
+    }
+
+    #[test]
+    fn test_errors_not_reaching_the_processor_are_retried() {
+        assert!(is_error_not_processed_by_connector(&error_response(
+            "bad_gateway",
+            502
+        )));
+        assert!(is_error_not_processed_by_connector(&error_response(
+            "service_unavailable",
+            503
+        )));
+    }
+
+    #[test]
+    fn test_errors_possibly_processed_by_the_processor_are_not_retried() {
+        assert!(!is_error_not_processed_by_connector(&error_response(
+            consts::REQUEST_TIMEOUT_ERROR_CODE,
+            504
+        )));
+        assert!(!is_error_not_processed_by_connector(&error_response(
+            "gateway_timeout",
+            504
+        )));
+        assert!(!is_error_not_processed_by_connector(&error_response(
+            "internal_server_error",
+            500
+        )));
+        assert!(!is_error_not_processed_by_connector(&error_response(
+            "card_declined",
+            402
+        )));
+    }
+}
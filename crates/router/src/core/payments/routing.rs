@@ -1,8 +1,12 @@
+pub mod circuit_breaker;
+pub mod cost_based;
+pub mod success_rate;
 mod transformers;
 
 use std::{
     collections::hash_map,
     hash::{Hash, Hasher},
+    str::FromStr,
     sync::Arc,
 };
 
@@ -20,6 +24,7 @@ use euclid::{
     dssa::graph::{self as euclid_graph, Memoization},
     enums as euclid_enums,
     frontend::ast,
+    types::EuclidKey,
 };
 use kgraph_utils::{
     mca as mca_graph,
@@ -31,6 +36,7 @@ use rand::{
     SeedableRng,
 };
 use rustc_hash::FxHashMap;
+use strum::IntoEnumIterator;
 
 #[cfg(feature = "payouts")]
 use crate::core::payouts;
@@ -38,12 +44,14 @@ use crate::core::payouts;
 use crate::utils::StringExt;
 use crate::{
     core::{
-        errors, errors as oss_errors, payments as payments_oss,
+        card_bin, errors, errors as oss_errors, geo_ip,
+        payment_methods::cards,
+        payments as payments_oss,
         routing::{self, helpers as routing_helpers},
     },
     logger,
     types::{
-        api, api::routing as routing_types, domain, storage as oss_storage,
+        self, api, api::routing as routing_types, domain, storage as oss_storage,
         transformers::ForeignInto,
     },
     utils::{OptionExt, ValueExt},
@@ -71,7 +79,7 @@ pub struct SessionRoutingPmTypeInput<'a> {
     state: &'a AppState,
     key_store: &'a domain::MerchantKeyStore,
     merchant_last_modified: i64,
-    attempt_id: &'a str,
+    payment_id: &'a str,
     routing_algorithm: &'a MerchantAccountRoutingAlgorithm,
     backend_input: dsl_inputs::BackendInput,
     allowed_connectors: FxHashMap<String, api::GetToken>,
@@ -135,6 +143,8 @@ pub fn make_dsl_input_for_payouts(
             .as_ref()
             .and_then(|bic| bic.country)
             .map(api_enums::Country::from_alpha2),
+        shipping_country: None,
+        ip_country: None,
         business_label: payout_data.payout_attempt.business_label.clone(),
         setup_future_usage: None,
     };
@@ -147,6 +157,8 @@ pub fn make_dsl_input_for_payouts(
             .clone()
             .map(api_enums::PaymentMethodType::foreign_from),
         card_network: None,
+        card_category: None,
+        card_issuing_country: None,
     };
     Ok(dsl_inputs::BackendInput {
         mandate,
@@ -156,6 +168,104 @@ pub fn make_dsl_input_for_payouts(
     })
 }
 
+/// The country of the customer's IP address, for the `ip_country` routing condition
+pub(super) async fn get_ip_country<F: Clone>(
+    state: &AppState,
+    payment_data: &payments_oss::PaymentData<F>,
+) -> Option<api_enums::Country> {
+    let ip_address = payment_data
+        .payment_attempt
+        .browser_info
+        .clone()?
+        .parse_value::<types::BrowserInformation>("BrowserInformation")
+        .map_err(|error| logger::error!(?error, "Failed to parse browser information"))
+        .ok()?
+        .ip_address?;
+    geo_ip::get_ip_country(state, ip_address)
+        .await
+        .map(api_enums::Country::from_alpha2)
+}
+
+/// Card issuing countries are stored either as alpha-2 codes or as country names, e.g.
+/// `UNITED KINGDOM`
+fn parse_card_issuing_country(country: &str) -> Option<api_enums::Country> {
+    CountryAlpha2::from_str(country.trim())
+        .map(api_enums::Country::from_alpha2)
+        .ok()
+        .or_else(|| {
+            let country_name = country
+                .chars()
+                .filter(char::is_ascii_alphanumeric)
+                .collect::<String>();
+            api_enums::Country::iter()
+                .find(|country| country.to_string().eq_ignore_ascii_case(&country_name))
+        })
+}
+
+fn get_card_category(card_subtype: &str) -> euclid_enums::CardCategory {
+    let card_subtype = card_subtype.to_lowercase();
+    if ["commercial", "business", "corporate", "purchasing", "fleet"]
+        .iter()
+        .any(|commercial_subtype| card_subtype.contains(commercial_subtype))
+    {
+        euclid_enums::CardCategory::Commercial
+    } else {
+        euclid_enums::CardCategory::Consumer
+    }
+}
+
+/// Fill the card attributes from the BIN of the card, for the `card_type`, `card_network`,
+/// `card_category` and `card_issuing_country` routing conditions. Attributes already present on
+/// the payment are left untouched.
+pub(super) async fn populate_card_bin_attributes<F: Clone>(
+    state: &AppState,
+    payment_data: &payments_oss::PaymentData<F>,
+    payment_method_input: &mut dsl_inputs::PaymentMethodInput,
+) {
+    let Some(api::PaymentMethodData::Card(card)) = payment_data.payment_method_data.as_ref() else {
+        return;
+    };
+    let card_isin = card.card_number.clone().get_card_isin();
+    let Some(card_info) = card_bin::get_card_bin_info(state, &card_isin)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to look up the card BIN"))
+        .ok()
+        .flatten()
+    else {
+        return;
+    };
+
+    payment_method_input.payment_method_type =
+        payment_method_input.payment_method_type.or_else(|| {
+            card_info.card_type.as_deref().and_then(|card_type| {
+                match card_type.to_lowercase().as_str() {
+                    "credit" => Some(api_enums::PaymentMethodType::Credit),
+                    "debit" => Some(api_enums::PaymentMethodType::Debit),
+                    _ => None,
+                }
+            })
+        });
+    payment_method_input.card_network = payment_method_input
+        .card_network
+        .take()
+        .or(card_info.card_network);
+    payment_method_input.card_category = card_info.card_subtype.as_deref().map(get_card_category);
+    payment_method_input.card_issuing_country = payment_method_input
+        .card_issuing_country
+        .or_else(|| {
+            card_info
+                .card_issuing_country
+                .as_deref()
+                .and_then(parse_card_issuing_country)
+        })
+        .or_else(|| {
+            card_info
+                .country_code
+                .as_deref()
+                .and_then(parse_card_issuing_country)
+        });
+}
+
 pub fn make_dsl_input<F>(
     payment_data: &payments_oss::PaymentData<F>,
 ) -> RoutingResult<dsl_inputs::BackendInput>
@@ -208,6 +318,17 @@ where
 
                 _ => None,
             }),
+        card_category: None,
+        card_issuing_country: payment_data
+            .payment_method_data
+            .as_ref()
+            .and_then(|pm_data| match pm_data {
+                api::PaymentMethodData::Card(card) => card
+                    .card_issuing_country
+                    .as_deref()
+                    .and_then(parse_card_issuing_country),
+                _ => None,
+            }),
     };
 
     let payment_input = dsl_inputs::PaymentInput {
@@ -237,6 +358,13 @@ where
             .and_then(|bic| bic.address.as_ref())
             .and_then(|add| add.country)
             .map(api_enums::Country::from_alpha2),
+        shipping_country: payment_data
+            .address
+            .get_shipping()
+            .and_then(|shipping| shipping.address.as_ref())
+            .and_then(|add| add.country)
+            .map(api_enums::Country::from_alpha2),
+        ip_country: None,
         business_label: payment_data.payment_intent.business_label.clone(),
         setup_future_usage: payment_data.payment_intent.setup_future_usage,
     };
@@ -267,6 +395,7 @@ pub async fn perform_static_routing_v1<F: Clone>(
     merchant_id: &str,
     algorithm_ref: routing_types::RoutingAlgorithmRef,
     transaction_data: &routing::TransactionData<'_, F>,
+    routing_decision: &mut routing_types::RoutingDecision,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
     #[cfg(any(
         feature = "profile_specific_fallback_routing",
@@ -298,6 +427,18 @@ pub async fn perform_static_routing_v1<F: Clone>(
 
         return Ok(fallback_config);
     };
+    routing_decision.algorithm_id = Some(algorithm_id.clone());
+    routing_decision.algorithm_version = Some(algorithm_ref.timestamp);
+
+    // Seeding the volume split with the payment id keeps all attempts of a payment on the same
+    // connector
+    let volume_split_seed = match transaction_data {
+        routing::TransactionData::Payment(payment_data) => {
+            payment_data.payment_intent.payment_id.as_str()
+        }
+        #[cfg(feature = "payouts")]
+        routing::TransactionData::Payout(payout_data) => payout_data.payouts.payout_id.as_str(),
+    };
     let key = ensure_algorithm_cached_v1(
         state,
         merchant_id,
@@ -318,19 +459,39 @@ pub async fn perform_static_routing_v1<F: Clone>(
 
         CachedAlgorithm::Priority(plist) => plist.clone(),
 
-        CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec(), None)
-            .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+        CachedAlgorithm::VolumeSplit(splits) => {
+            perform_volume_split(splits.to_vec(), Some(volume_split_seed))
+                .change_context(errors::RoutingError::ConnectorSelectionFailed)?
+        }
 
         CachedAlgorithm::Advanced(interpreter) => {
             let backend_input = match transaction_data {
-                routing::TransactionData::Payment(payment_data) => make_dsl_input(payment_data)?,
+                routing::TransactionData::Payment(payment_data) => {
+                    let mut backend_input = make_dsl_input(payment_data)?;
+                    if interpreter.references_key(&EuclidKey::IpCountry) {
+                        backend_input.payment.ip_country =
+                            get_ip_country(state, payment_data).await;
+                    }
+                    populate_card_bin_attributes(
+                        state,
+                        payment_data,
+                        &mut backend_input.payment_method,
+                    )
+                    .await;
+                    backend_input
+                }
                 #[cfg(feature = "payouts")]
                 routing::TransactionData::Payout(payout_data) => {
                     make_dsl_input_for_payouts(payout_data)?
                 }
             };
 
-            execute_dsl_and_get_connector_v1(backend_input, interpreter)?
+            execute_dsl_and_get_connector_v1(
+                backend_input,
+                interpreter,
+                volume_split_seed,
+                Some(routing_decision),
+            )?
         }
     })
 }
@@ -400,6 +561,7 @@ async fn ensure_algorithm_cached_v1(
 pub fn perform_straight_through_routing(
     algorithm: &routing_types::StraightThroughAlgorithm,
     creds_identifier: Option<String>,
+    volume_split_seed: &str,
 ) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, bool)> {
     Ok(match algorithm {
         routing_types::StraightThroughAlgorithm::Single(conn) => {
@@ -409,7 +571,7 @@ pub fn perform_straight_through_routing(
         routing_types::StraightThroughAlgorithm::Priority(conns) => (conns.clone(), true),
 
         routing_types::StraightThroughAlgorithm::VolumeSplit(splits) => (
-            perform_volume_split(splits.to_vec(), None)
+            perform_volume_split(splits.to_vec(), Some(volume_split_seed))
                 .change_context(errors::RoutingError::ConnectorSelectionFailed)
                 .attach_printable(
                     "Volume Split connector selection error in straight through routing",
@@ -422,23 +584,79 @@ pub fn perform_straight_through_routing(
 fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+    volume_split_seed: &str,
+    routing_decision: Option<&mut routing_types::RoutingDecision>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let routing_output: routing_types::RoutingAlgorithm = interpreter
-        .execute(backend_input)
-        .map(|out| out.connector_selection.foreign_into())
-        .change_context(errors::RoutingError::DslExecutionError)?;
+    let routing_output: routing_types::RoutingAlgorithm = match routing_decision {
+        Some(routing_decision) => {
+            let (output, rule_evaluations) = interpreter.execute_with_trace(backend_input);
+            routing_decision.matched_rule = output.rule_name;
+            routing_decision.rule_evaluations = rule_evaluations;
+            output.connector_selection.foreign_into()
+        }
+        None => interpreter
+            .execute(backend_input)
+            .map(|out| out.connector_selection.foreign_into())
+            .change_context(errors::RoutingError::DslExecutionError)?,
+    };
 
     Ok(match routing_output {
         routing_types::RoutingAlgorithm::Priority(plist) => plist,
 
-        routing_types::RoutingAlgorithm::VolumeSplit(splits) => perform_volume_split(splits, None)
-            .change_context(errors::RoutingError::DslFinalConnectorSelectionFailed)?,
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
+            perform_volume_split(splits, Some(volume_split_seed))
+                .change_context(errors::RoutingError::DslFinalConnectorSelectionFailed)?
+        }
 
         _ => Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
             .attach_printable("Unsupported algorithm received as a result of static routing")?,
     })
 }
 
+/// Evaluates a routing algorithm for a hypothetical payment. Volume splits are drawn without a
+/// seed, so that repeated simulations reflect the configured split.
+pub fn simulate_routing_algorithm(
+    algorithm: routing_types::MerchantRoutingAlgorithm,
+    is_active: bool,
+    backend_input: dsl_inputs::BackendInput,
+) -> RoutingResult<routing_types::RoutingSimulationResult> {
+    let kind = algorithm.algorithm.get_kind();
+    let (connectors, matched_rule, rule_evaluations) = match algorithm.algorithm {
+        routing_types::RoutingAlgorithm::Single(connector) => (vec![*connector], None, Vec::new()),
+        routing_types::RoutingAlgorithm::Priority(connectors) => (connectors, None, Vec::new()),
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) => (
+            perform_volume_split(splits, None)
+                .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+            None,
+            Vec::new(),
+        ),
+        routing_types::RoutingAlgorithm::Advanced(program) => {
+            let interpreter = backend::VirInterpreterBackend::with_program(program)
+                .change_context(errors::RoutingError::DslBackendInitError)
+                .attach_printable("Error initializing DSL interpreter backend")?;
+            let (output, rule_evaluations) = interpreter.execute_with_trace(backend_input);
+            let connectors = match output.connector_selection {
+                ConnectorSelection::Priority(connectors) => connectors,
+                ConnectorSelection::VolumeSplit(splits) => perform_volume_split(splits, None)
+                    .change_context(errors::RoutingError::DslFinalConnectorSelectionFailed)?,
+            };
+            (connectors, output.rule_name, rule_evaluations)
+        }
+    };
+
+    Ok(routing_types::RoutingSimulationResult {
+        algorithm_id: algorithm.id,
+        #[cfg(feature = "business_profile_routing")]
+        profile_id: algorithm.profile_id,
+        name: algorithm.name,
+        kind,
+        is_active,
+        connectors,
+        matched_rule,
+        rule_evaluations,
+    })
+}
+
 pub async fn refresh_routing_cache_v1(
     state: &AppState,
     key: String,
@@ -733,6 +951,50 @@ pub async fn perform_eligibility_analysis<F: Clone>(
     .await
 }
 
+/// Filters out the connectors which do not support the currency or billing country of the payment
+/// for its payment method type, as configured in the payment method filters of the application
+fn perform_config_based_filtering<F: Clone>(
+    state: &AppState,
+    chosen: Vec<routing_types::RoutableConnectorChoice>,
+    transaction_data: &routing::TransactionData<'_, F>,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    let payment_data = match transaction_data {
+        routing::TransactionData::Payment(payment_data) => payment_data,
+        #[cfg(feature = "payouts")]
+        routing::TransactionData::Payout(_) => return chosen,
+    };
+    let Some(payment_method_type) = payment_data.payment_attempt.payment_method_type else {
+        return chosen;
+    };
+    let country = payment_data
+        .address
+        .get_payment_method_billing()
+        .and_then(|billing| billing.address.as_ref())
+        .and_then(|address| address.country);
+
+    chosen
+        .into_iter()
+        .filter(|choice| {
+            let is_eligible = cards::filter_pm_based_on_config(
+                &state.conf.pm_filters,
+                &choice.connector.to_string(),
+                &payment_method_type,
+                Some(&payment_data.payment_attempt),
+                &mut None,
+                &country,
+                Some(payment_data.currency),
+            );
+            if !is_eligible {
+                logger::debug!(
+                    connector = %choice.connector,
+                    "Connector filtered out by the payment method filters"
+                );
+            }
+            is_eligible
+        })
+        .collect()
+}
+
 pub async fn perform_fallback_routing<F: Clone>(
     state: &AppState,
     key_store: &domain::MerchantKeyStore,
@@ -824,6 +1086,8 @@ pub async fn perform_eligibility_analysis_with_fallback<F: Clone>(
             .collect::<Vec<_>>(),
     );
 
+    let final_selection = perform_config_based_filtering(state, final_selection, transaction_data);
+
     let final_selected_connectors = final_selection
         .iter()
         .map(|item| item.connector)
@@ -886,6 +1150,8 @@ pub async fn perform_session_flow_routing(
         payment_method: None,
         payment_method_type: None,
         card_network: None,
+        card_category: None,
+        card_issuing_country: None,
     };
 
     let payment_input = dsl_inputs::PaymentInput {
@@ -910,6 +1176,8 @@ pub async fn perform_session_flow_routing(
         billing_country: session_input
             .country
             .map(storage_enums::Country::from_alpha2),
+        shipping_country: None,
+        ip_country: None,
         business_label: session_input.payment_intent.business_label.clone(),
         setup_future_usage: session_input.payment_intent.setup_future_usage,
     };
@@ -962,7 +1230,7 @@ pub async fn perform_session_flow_routing(
             state: session_input.state,
             key_store: session_input.key_store,
             merchant_last_modified,
-            attempt_id: &session_input.payment_attempt.attempt_id,
+            payment_id: &session_input.payment_intent.payment_id,
             routing_algorithm: &routing_algorithm,
             backend_input: backend_input.clone(),
             allowed_connectors,
@@ -1021,12 +1289,14 @@ async fn perform_session_routing_for_pm_type(
                     CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
                     CachedAlgorithm::Priority(plist) => plist.clone(),
                     CachedAlgorithm::VolumeSplit(splits) => {
-                        perform_volume_split(splits.to_vec(), Some(session_pm_input.attempt_id))
+                        perform_volume_split(splits.to_vec(), Some(session_pm_input.payment_id))
                             .change_context(errors::RoutingError::ConnectorSelectionFailed)?
                     }
                     CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
                         session_pm_input.backend_input.clone(),
                         interpreter,
+                        session_pm_input.payment_id,
+                        None,
                     )?,
                 }
             } else {
@@ -1152,6 +1422,8 @@ pub fn make_dsl_input_for_surcharge(
             .and_then(|bic| bic.address)
             .and_then(|add| add.country)
             .map(api_enums::Country::from_alpha2),
+        shipping_country: None,
+        ip_country: None,
         business_label: payment_intent.business_label.clone(),
         setup_future_usage: payment_intent.setup_future_usage,
     };
@@ -1170,6 +1442,8 @@ pub fn make_dsl_input_for_surcharge(
         payment_method: None,
         payment_method_type: None,
         card_network: None,
+        card_category: None,
+        card_issuing_country: None,
     };
     let backend_input = dsl_inputs::BackendInput {
         metadata,
@@ -1179,3 +1453,40 @@ pub fn make_dsl_input_for_surcharge(
     };
     Ok(backend_input)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn get_splits() -> Vec<routing_types::ConnectorVolumeSplit> {
+        [("stripe", 50), ("adyen", 30), ("checkout", 20)]
+            .into_iter()
+            .map(|(connector, split)| routing_types::ConnectorVolumeSplit {
+                connector: serde_json::from_value(serde_json::json!({ "connector": connector }))
+                    .unwrap(),
+                split,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_volume_split_is_stable_for_a_payment_id() {
+        let mut chosen_connectors = HashSet::new();
+        for payment_id in (1..=20).map(|index| format!("pay_{index}")) {
+            let connectors = perform_volume_split(get_splits(), Some(&payment_id)).unwrap();
+            // Retries, confirms and session calls of the payment are routed the same way
+            for _ in 0..10 {
+                assert_eq!(
+                    perform_volume_split(get_splits(), Some(&payment_id)).unwrap(),
+                    connectors
+                );
+            }
+            chosen_connectors.insert(connectors[0].to_string());
+        }
+        // While payments are still split across the connectors
+        assert!(chosen_connectors.len() > 1);
+    }
+}
@@ -0,0 +1,542 @@
+//! Connector circuit breaker
+//!
+//! The outcomes of the most recent authorization calls to each connector account of a merchant are
+//! tracked in redis. Once too many of them failed or timed out, the circuit breaker of the
+//! connector account opens and it is left out of routing, so that payments are routed to the
+//! next eligible or fallback connector. After a while the breaker becomes half-open and a share
+//! of the payments is routed to the connector again: the breaker closes on the first successful
+//! call, and opens again on the first failed one.
+
+use std::collections::VecDeque;
+
+use common_utils::ext_traits::ByteSliceExt;
+use error_stack::ResultExt;
+use rand::Rng;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::metrics,
+    types::{self, api, api::routing as routing_types, storage::enums},
+    AppState,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CircuitTransition {
+    Opened,
+    Reopened,
+    Closed,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CircuitBreaker {
+    /// Whether each of the most recent calls failed
+    failures: VecDeque<bool>,
+    /// The time at which the breaker last opened, `None` while the breaker is closed
+    opened_at: Option<i64>,
+}
+
+impl CircuitBreaker {
+    fn state(&self, now: i64) -> CircuitState {
+        match self.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if now - opened_at < consts::CIRCUIT_BREAKER_OPEN_DURATION => {
+                CircuitState::Open
+            }
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    fn failure_rate(&self) -> f64 {
+        let failed = self
+            .failures
+            .iter()
+            .filter(|is_failure| **is_failure)
+            .count();
+        match (u32::try_from(failed), u32::try_from(self.failures.len())) {
+            (Ok(failed), Ok(total)) if total > 0 => f64::from(failed) / f64::from(total),
+            _ => 0.0,
+        }
+    }
+
+    fn record(&mut self, is_failure: bool, now: i64) -> Option<CircuitTransition> {
+        match self.state(now) {
+            CircuitState::Closed => {
+                while self.failures.len() >= consts::CIRCUIT_BREAKER_WINDOW_SIZE {
+                    self.failures.pop_front();
+                }
+                self.failures.push_back(is_failure);
+
+                (self.failures.len() >= consts::CIRCUIT_BREAKER_MIN_SAMPLES
+                    && self.failure_rate() >= consts::CIRCUIT_BREAKER_FAILURE_RATE_THRESHOLD)
+                    .then(|| {
+                        self.opened_at = Some(now);
+                        CircuitTransition::Opened
+                    })
+            }
+            // Outcomes of calls made while the breaker is open are of payments that had no other
+            // connector to go to, they do not tell whether the connector recovered
+            CircuitState::Open => None,
+            CircuitState::HalfOpen if is_failure => {
+                self.opened_at = Some(now);
+                Some(CircuitTransition::Reopened)
+            }
+            CircuitState::HalfOpen => {
+                *self = Self::default();
+                Some(CircuitTransition::Closed)
+            }
+        }
+    }
+}
+
+/// Breakers are kept per connector account, as accounts of the same connector can be set up with
+/// different credentials or regions and fail independently. Connectors chosen without an account
+/// share the breaker of the connector.
+fn get_redis_key_for_circuit_breaker(
+    merchant_id: &str,
+    connector: &str,
+    merchant_connector_id: Option<&str>,
+) -> String {
+    format!(
+        "circuit_breaker_{merchant_id}_{}",
+        merchant_connector_id.unwrap_or(connector)
+    )
+}
+
+/// Whether the merchant has turned on the circuit breaker, through the
+/// `connector_circuit_breaker_{merchant_id}` config. It is off by default.
+pub async fn config_should_use_circuit_breaker(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> bool {
+    let config = db
+        .find_config_by_key_unwrap_or(
+            format!("connector_circuit_breaker_{}", merchant_id).as_str(),
+            Some("false".to_string()),
+        )
+        .await;
+    match config {
+        Ok(conf) => conf.config == "true",
+        Err(err) => {
+            logger::error!("{err}");
+            false
+        }
+    }
+}
+
+async fn get_circuit_state(
+    state: &AppState,
+    merchant_id: &str,
+    connector: &str,
+    merchant_connector_id: Option<&str>,
+) -> CircuitState {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return CircuitState::Closed;
+        }
+    };
+    redis_conn
+        .get_and_deserialize_key::<CircuitBreaker>(
+            &get_redis_key_for_circuit_breaker(merchant_id, connector, merchant_connector_id),
+            "CircuitBreaker",
+        )
+        .await
+        .map(|circuit_breaker| circuit_breaker.state(common_utils::date_time::now_unix_timestamp()))
+        .unwrap_or(CircuitState::Closed)
+}
+
+/// Leaves the connectors whose circuit breaker is open out of routing, if the merchant has opted
+/// in. Connectors whose breaker is half-open are kept for a share of the payments, to probe
+/// whether they recovered. If every connector would be left out the connectors are returned as
+/// they are, as the payment is better attempted with an unhealthy connector than failed outright.
+#[instrument(skip_all)]
+pub async fn filter_connectors_by_circuit_breaker(
+    state: &AppState,
+    merchant_id: &str,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    if connectors.is_empty() || !config_should_use_circuit_breaker(&*state.store, merchant_id).await
+    {
+        return connectors;
+    }
+
+    let mut healthy_connectors = Vec::with_capacity(connectors.len());
+    for connector in &connectors {
+        let connector_name = connector.connector.to_string();
+        let circuit_state = get_circuit_state(
+            state,
+            merchant_id,
+            &connector_name,
+            connector.merchant_connector_id.as_deref(),
+        )
+        .await;
+        let is_routable = match circuit_state {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen => {
+                rand::thread_rng().gen_range(0..100) < consts::CIRCUIT_BREAKER_PROBE_PERCENTAGE
+            }
+        };
+
+        if is_routable {
+            healthy_connectors.push(connector.clone());
+        } else {
+            logger::debug!(connector = %connector_name, "Circuit breaker is open, skipping connector");
+            metrics::CONNECTOR_CIRCUIT_BREAKER_SKIPPED.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes(
+                    "connector",
+                    connector_name,
+                )],
+            );
+        }
+    }
+
+    if healthy_connectors.is_empty() {
+        logger::warn!("Circuit breakers of all the eligible connectors are open");
+        return connectors;
+    }
+
+    healthy_connectors
+}
+
+/// Records whether the authorization call to the connector account failed, for merchants that
+/// have turned on the circuit breaker. Only errors of the connector itself count as failures
+/// (server errors and timeouts), declines and other error responses show that the connector is up.
+/// A payment is never failed because its outcome could not be recorded, errors are logged instead.
+#[instrument(skip_all)]
+pub async fn record_connector_outcome<F>(
+    state: &AppState,
+    router_data: &types::RouterData<F, types::PaymentsAuthorizeData, types::PaymentsResponseData>,
+    merchant_connector_id: Option<&str>,
+) {
+    let is_failure = match &router_data.response {
+        Ok(_) => false,
+        Err(error) => error.status_code >= 500 || error.code == consts::REQUEST_TIMEOUT_ERROR_CODE,
+    };
+
+    if !config_should_use_circuit_breaker(&*state.store, &router_data.merchant_id).await {
+        return;
+    }
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return;
+        }
+    };
+
+    let key = get_redis_key_for_circuit_breaker(
+        &router_data.merchant_id,
+        &router_data.connector,
+        merchant_connector_id,
+    );
+
+    // Concurrent payments update the same breaker, the update is retried whenever the breaker was
+    // changed between reading and storing it, so that no outcome is lost
+    let mut attempts = 0;
+    let (circuit_breaker, transition) = loop {
+        attempts += 1;
+        let stored_value = match redis_conn.get_key::<Vec<u8>>(&key).await {
+            Ok(stored_value) => stored_value,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to read connector circuit breaker from redis"
+                );
+                return;
+            }
+        };
+        let mut circuit_breaker = if stored_value.is_empty() {
+            CircuitBreaker::default()
+        } else {
+            stored_value
+                .parse_struct::<CircuitBreaker>("CircuitBreaker")
+                .unwrap_or_default()
+        };
+        let transition =
+            circuit_breaker.record(is_failure, common_utils::date_time::now_unix_timestamp());
+
+        match redis_conn
+            .serialize_and_set_key_if_unchanged_with_expiry(
+                &key,
+                &stored_value,
+                &circuit_breaker,
+                consts::CIRCUIT_BREAKER_TTL,
+            )
+            .await
+        {
+            Ok(true) => break (circuit_breaker, transition),
+            Ok(false) if attempts < consts::CIRCUIT_BREAKER_MAX_UPDATE_ATTEMPTS => {}
+            Ok(false) => {
+                logger::warn!("Connector circuit breaker kept changing, dropping the outcome");
+                return;
+            }
+            Err(error) => {
+                logger::error!(?error, "Failed to store connector circuit breaker in redis");
+                return;
+            }
+        }
+    };
+
+    let connector_health = routing_types::ConnectorHealthResponse {
+        merchant_id: router_data.merchant_id.clone(),
+        connector: router_data.connector.clone(),
+        merchant_connector_id: merchant_connector_id.map(ToOwned::to_owned),
+        status: routing_types::ConnectorHealthStatus::Unhealthy,
+        failure_rate: circuit_breaker.failure_rate(),
+        opened_at: circuit_breaker.opened_at,
+    };
+
+    let connector_attribute =
+        metrics::request::add_attributes("connector", router_data.connector.clone());
+    match transition {
+        Some(CircuitTransition::Opened) => {
+            logger::warn!(connector = %router_data.connector, "Connector circuit breaker opened");
+            metrics::CONNECTOR_CIRCUIT_BREAKER_OPENED.add(
+                &metrics::CONTEXT,
+                1,
+                &[connector_attribute],
+            );
+            trigger_circuit_breaker_opened_webhook(state, connector_health)
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to trigger circuit breaker opened webhook");
+                })
+                .ok();
+        }
+        Some(CircuitTransition::Reopened) => {
+            logger::warn!(connector = %router_data.connector, "Connector circuit breaker reopened");
+            metrics::CONNECTOR_CIRCUIT_BREAKER_OPENED.add(
+                &metrics::CONTEXT,
+                1,
+                &[connector_attribute],
+            );
+        }
+        Some(CircuitTransition::Closed) => {
+            logger::info!(connector = %router_data.connector, "Connector circuit breaker closed");
+            metrics::CONNECTOR_CIRCUIT_BREAKER_CLOSED.add(
+                &metrics::CONTEXT,
+                1,
+                &[connector_attribute],
+            );
+        }
+        None => {}
+    }
+}
+
+/// Notifies the merchant that the circuit breaker of a connector account opened. Connector accounts
+/// can be shared across business profiles, the webhook goes to the endpoint of the default profile
+/// of the merchant. A half-open breaker that reopens belongs to the same outage, so no webhook is
+/// sent for it.
+async fn trigger_circuit_breaker_opened_webhook(
+    state: &AppState,
+    connector_health: routing_types::ConnectorHealthResponse,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &connector_health.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&connector_health.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let Some(profile_id) = merchant_account.default_profile.as_ref() else {
+        logger::debug!(
+            "Merchant does not have a default business profile, skipping circuit breaker opened webhook"
+        );
+        return Ok(());
+    };
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?;
+
+    let primary_object_id = format!(
+        "{}_{}",
+        connector_health
+            .merchant_connector_id
+            .as_deref()
+            .unwrap_or(&connector_health.connector),
+        connector_health.opened_at.unwrap_or_default()
+    );
+
+    webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile,
+        &key_store,
+        enums::EventType::ConnectorCircuitBreakerOpened,
+        enums::EventClass::Connectors,
+        primary_object_id,
+        enums::EventObjectType::ConnectorHealthDetails,
+        api::OutgoingWebhookContent::ConnectorHealthDetails(Box::new(connector_health)),
+        None,
+        None,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to trigger circuit breaker opened webhook")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record_outcomes(circuit_breaker: &mut CircuitBreaker, failures: &[bool], now: i64) {
+        for is_failure in failures {
+            circuit_breaker.record(*is_failure, now);
+        }
+    }
+
+    #[test]
+    fn test_breaker_opens_once_failure_rate_reaches_threshold() {
+        let mut circuit_breaker = CircuitBreaker::default();
+        let now = 1_000;
+
+        record_outcomes(
+            &mut circuit_breaker,
+            &[true; consts::CIRCUIT_BREAKER_MIN_SAMPLES - 1],
+            now,
+        );
+        assert_eq!(circuit_breaker.state(now), CircuitState::Closed);
+
+        assert_eq!(
+            circuit_breaker.record(true, now),
+            Some(CircuitTransition::Opened)
+        );
+        assert_eq!(circuit_breaker.state(now), CircuitState::Open);
+        assert_eq!(circuit_breaker.opened_at, Some(now));
+    }
+
+    #[test]
+    fn test_breaker_stays_closed_below_threshold() {
+        let mut circuit_breaker = CircuitBreaker::default();
+        let now = 1_000;
+
+        let outcomes = [false, false, true]
+            .repeat(consts::CIRCUIT_BREAKER_WINDOW_SIZE)
+            .into_iter()
+            .take(consts::CIRCUIT_BREAKER_WINDOW_SIZE * 2)
+            .collect::<Vec<_>>();
+        record_outcomes(&mut circuit_breaker, &outcomes, now);
+
+        assert_eq!(circuit_breaker.state(now), CircuitState::Closed);
+        assert_eq!(
+            circuit_breaker.failures.len(),
+            consts::CIRCUIT_BREAKER_WINDOW_SIZE
+        );
+    }
+
+    #[test]
+    fn test_breaker_only_considers_most_recent_calls() {
+        let mut circuit_breaker = CircuitBreaker::default();
+        let now = 1_000;
+
+        record_outcomes(
+            &mut circuit_breaker,
+            &[false; consts::CIRCUIT_BREAKER_WINDOW_SIZE],
+            now,
+        );
+        assert!(circuit_breaker.failure_rate() < f64::EPSILON);
+
+        record_outcomes(
+            &mut circuit_breaker,
+            &[true; consts::CIRCUIT_BREAKER_WINDOW_SIZE / 2 - 1],
+            now,
+        );
+        assert_eq!(circuit_breaker.state(now), CircuitState::Closed);
+
+        // The oldest successful call leaves the window, half of the window has now failed
+        assert_eq!(
+            circuit_breaker.record(true, now),
+            Some(CircuitTransition::Opened)
+        );
+    }
+
+    #[test]
+    fn test_open_breaker_ignores_outcomes_until_half_open() {
+        let mut circuit_breaker = CircuitBreaker {
+            failures: VecDeque::new(),
+            opened_at: Some(1_000),
+        };
+        let now = 1_000 + consts::CIRCUIT_BREAKER_OPEN_DURATION - 1;
+
+        assert_eq!(circuit_breaker.record(false, now), None);
+        assert_eq!(circuit_breaker.record(true, now), None);
+        assert_eq!(circuit_breaker.state(now), CircuitState::Open);
+        assert_eq!(
+            circuit_breaker.state(1_000 + consts::CIRCUIT_BREAKER_OPEN_DURATION),
+            CircuitState::HalfOpen
+        );
+    }
+
+    #[test]
+    fn test_half_open_breaker_closes_on_success() {
+        let mut circuit_breaker = CircuitBreaker {
+            failures: VecDeque::from(vec![true; consts::CIRCUIT_BREAKER_WINDOW_SIZE]),
+            opened_at: Some(1_000),
+        };
+        let now = 1_000 + consts::CIRCUIT_BREAKER_OPEN_DURATION;
+
+        assert_eq!(
+            circuit_breaker.record(false, now),
+            Some(CircuitTransition::Closed)
+        );
+        assert_eq!(circuit_breaker.state(now), CircuitState::Closed);
+        assert!(circuit_breaker.failures.is_empty());
+    }
+
+    #[test]
+    fn test_half_open_breaker_reopens_on_failure() {
+        let mut circuit_breaker = CircuitBreaker {
+            failures: VecDeque::from(vec![true; consts::CIRCUIT_BREAKER_WINDOW_SIZE]),
+            opened_at: Some(1_000),
+        };
+        let now = 1_000 + consts::CIRCUIT_BREAKER_OPEN_DURATION;
+
+        assert_eq!(
+            circuit_breaker.record(true, now),
+            Some(CircuitTransition::Reopened)
+        );
+        assert_eq!(circuit_breaker.opened_at, Some(now));
+        assert_eq!(circuit_breaker.state(now), CircuitState::Open);
+    }
+
+    #[test]
+    fn test_breaker_is_kept_per_connector_account() {
+        assert_eq!(
+            get_redis_key_for_circuit_breaker("merchant", "stripe", Some("mca_1")),
+            "circuit_breaker_merchant_mca_1"
+        );
+        assert_eq!(
+            get_redis_key_for_circuit_breaker("merchant", "stripe", None),
+            "circuit_breaker_merchant_stripe"
+        );
+    }
+}
@@ -0,0 +1,226 @@
+//! Cost based routing
+//!
+//! Merchants upload the fees charged by each of their connectors as a fee schedule, stored in the
+//! `fee_schedule_{merchant_id}` config. When a fee schedule is present, the eligible connectors are
+//! ordered so that the connector with the lowest estimated fee for the payment is tried first, and
+//! the estimated fee of the connector the payment is routed to is recorded on the payment attempt.
+
+use std::collections::HashMap;
+
+use common_utils::ext_traits::StringExt;
+use router_env::{instrument, logger, tracing};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::Deserialize;
+
+use crate::{
+    core::payments::PaymentData,
+    db::StorageInterface,
+    types::{api::routing as routing_types, storage::enums},
+    AppState,
+};
+
+/// The fee rules of each connector, keyed by the connector name
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct FeeSchedule(HashMap<String, Vec<FeeRule>>);
+
+/// The fee charged by a connector for the payments matching the rule. The rules of a connector are
+/// evaluated in order and the first rule matching the payment is used, rules without any filters
+/// match all payments.
+#[derive(Debug, Deserialize)]
+pub struct FeeRule {
+    pub payment_method: Option<enums::PaymentMethod>,
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+    /// Billing countries the rule applies to
+    pub countries: Option<Vec<enums::CountryAlpha2>>,
+    pub currency: Option<enums::Currency>,
+    /// Fixed fee in the lowest denomination of the payment currency
+    #[serde(default)]
+    pub fixed_fee: i64,
+    /// Fee as a percentage of the payment amount
+    #[serde(default)]
+    pub percentage_fee: Decimal,
+}
+
+/// The details of a payment that the fee depends on
+#[derive(Debug)]
+pub struct FeeParams {
+    pub amount: i64,
+    pub currency: enums::Currency,
+    pub payment_method: Option<enums::PaymentMethod>,
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+    pub country: Option<enums::CountryAlpha2>,
+}
+
+impl<F: Clone> From<&PaymentData<F>> for FeeParams {
+    fn from(payment_data: &PaymentData<F>) -> Self {
+        Self {
+            amount: payment_data.payment_attempt.amount,
+            currency: payment_data.currency,
+            payment_method: payment_data.payment_attempt.payment_method,
+            payment_method_type: payment_data.payment_attempt.payment_method_type,
+            country: payment_data
+                .address
+                .get_payment_method_billing()
+                .and_then(|billing| billing.address.as_ref())
+                .and_then(|address| address.country),
+        }
+    }
+}
+
+impl FeeRule {
+    fn matches(&self, params: &FeeParams) -> bool {
+        let matches_payment_method = self.payment_method.map_or(true, |payment_method| {
+            params.payment_method == Some(payment_method)
+        });
+        let matches_payment_method_type = self
+            .payment_method_type
+            .map_or(true, |payment_method_type| {
+                params.payment_method_type == Some(payment_method_type)
+            });
+        let matches_country = self.countries.as_ref().map_or(true, |countries| {
+            params
+                .country
+                .map_or(false, |country| countries.contains(&country))
+        });
+        let matches_currency = self
+            .currency
+            .map_or(true, |currency| params.currency == currency);
+
+        matches_payment_method && matches_payment_method_type && matches_country && matches_currency
+    }
+
+    fn fee(&self, amount: i64) -> Option<i64> {
+        let percentage_fee = Decimal::from(amount) * self.percentage_fee / Decimal::ONE_HUNDRED;
+        percentage_fee
+            .round()
+            .to_i64()
+            .and_then(|percentage_fee| percentage_fee.checked_add(self.fixed_fee))
+    }
+}
+
+impl FeeSchedule {
+    /// The estimated fee of the connector for the payment, `None` if no rule of the connector
+    /// matches the payment
+    pub fn estimate_fee(&self, connector: &str, params: &FeeParams) -> Option<i64> {
+        self.0
+            .get(connector)?
+            .iter()
+            .find(|rule| rule.matches(params))?
+            .fee(params.amount)
+    }
+}
+
+/// Cost based routing is enabled for the merchants that have uploaded a non empty fee schedule.
+/// The schedule is read through the in-memory config cache, an empty schedule is stored for the
+/// merchants without one so that the lookup is cached for them as well.
+pub async fn get_fee_schedule(db: &dyn StorageInterface, merchant_id: &str) -> Option<FeeSchedule> {
+    let config = db
+        .find_config_by_key_unwrap_or(
+            &format!("fee_schedule_{merchant_id}"),
+            Some("{}".to_string()),
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to fetch fee schedule"))
+        .ok()?;
+    config
+        .config
+        .parse_struct::<FeeSchedule>("FeeSchedule")
+        .map_err(|error| logger::error!(?error, "Failed to parse fee schedule"))
+        .ok()
+        .filter(|fee_schedule| !fee_schedule.0.is_empty())
+}
+
+/// Orders the connectors by their estimated fee for the payment, if the merchant has uploaded a
+/// fee schedule. Connectors without a matching fee rule are tried after the ones with a known
+/// fee, ties keep the order decided by the routing algorithm.
+#[instrument(skip_all)]
+pub async fn order_connectors_by_estimated_fee<F: Clone>(
+    state: &AppState,
+    merchant_id: &str,
+    payment_data: &PaymentData<F>,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    if connectors.len() < 2 {
+        return connectors;
+    }
+    let Some(fee_schedule) = get_fee_schedule(&*state.store, merchant_id).await else {
+        return connectors;
+    };
+
+    let params = FeeParams::from(payment_data);
+    let mut connectors_with_fee = connectors
+        .into_iter()
+        .map(|connector| {
+            let fee = fee_schedule.estimate_fee(&connector.connector.to_string(), &params);
+            (connector, fee)
+        })
+        .collect::<Vec<_>>();
+
+    connectors_with_fee.sort_by_key(|(_, fee)| fee.unwrap_or(i64::MAX));
+    logger::debug!(
+        estimated_fees = ?connectors_with_fee
+            .iter()
+            .map(|(connector, fee)| (connector.connector.to_string(), *fee))
+            .collect::<Vec<_>>()
+    );
+
+    connectors_with_fee
+        .into_iter()
+        .map(|(connector, _)| connector)
+        .collect()
+}
+
+/// The estimated fee of the connector the payment is routed to, to be recorded on the attempt
+#[instrument(skip_all)]
+pub async fn estimate_connector_fee<F: Clone>(
+    state: &AppState,
+    merchant_id: &str,
+    payment_data: &PaymentData<F>,
+) -> Option<i64> {
+    let connector = payment_data.payment_attempt.connector.as_deref()?;
+    get_fee_schedule(&*state.store, merchant_id)
+        .await?
+        .estimate_fee(connector, &FeeParams::from(payment_data))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_fee_schedule_uses_first_matching_rule() {
+        let fee_schedule = r#"{
+            "stripe": [
+                { "payment_method_type": "credit", "countries": ["US"], "fixed_fee": 30, "percentage_fee": 2.9 },
+                { "fixed_fee": 30, "percentage_fee": 3.9 }
+            ],
+            "adyen": [{ "currency": "EUR", "fixed_fee": 10 }]
+        }"#
+        .to_string()
+        .parse_struct::<FeeSchedule>("FeeSchedule")
+        .unwrap();
+        let params = FeeParams {
+            amount: 10000,
+            currency: enums::Currency::USD,
+            payment_method: Some(enums::PaymentMethod::Card),
+            payment_method_type: Some(enums::PaymentMethodType::Credit),
+            country: Some(enums::CountryAlpha2::US),
+        };
+
+        assert_eq!(fee_schedule.estimate_fee("stripe", &params), Some(320));
+        assert_eq!(
+            fee_schedule.estimate_fee(
+                "stripe",
+                &FeeParams {
+                    country: Some(enums::CountryAlpha2::DE),
+                    ..params
+                }
+            ),
+            Some(420)
+        );
+        assert_eq!(fee_schedule.estimate_fee("adyen", &params), None);
+        assert_eq!(fee_schedule.estimate_fee("checkout", &params), None);
+    }
+}
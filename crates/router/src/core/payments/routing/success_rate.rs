@@ -0,0 +1,243 @@
+//! Success rate based routing
+//!
+//! Rolling authorization success rates are tracked in redis per merchant, connector, payment
+//! method type and card issuing country. For merchants that have opted in, the eligible
+//! connectors are ordered so that the connector with the best success rate is tried first. A
+//! share of the payments is still routed in the order decided by the routing algorithm, so that
+//! connectors with a poor success rate keep receiving traffic and are picked again once their
+//! success rate recovers.
+
+use rand::Rng;
+use redis_interface::RedisConnectionPool;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::payments::PaymentData,
+    db::StorageInterface,
+    types::{self, api, api::routing as routing_types, domain, storage::enums},
+    AppState,
+};
+
+const TOTAL_COUNT_FIELD: &str = "total";
+const SUCCESS_COUNT_FIELD: &str = "success";
+
+/// Authorization outcomes counted over the current and the previous time bucket
+#[derive(Debug, Default)]
+struct AuthorizationCounts {
+    total: i64,
+    successful: i64,
+}
+
+impl AuthorizationCounts {
+    fn success_rate(&self) -> Option<f64> {
+        if self.total < consts::SUCCESS_RATE_MIN_SAMPLES {
+            return None;
+        }
+        let successful = u32::try_from(self.successful).ok()?;
+        let total = u32::try_from(self.total).ok()?;
+        Some(f64::from(successful) / f64::from(total))
+    }
+}
+
+fn get_current_bucket() -> i64 {
+    common_utils::date_time::now_unix_timestamp() / consts::SUCCESS_RATE_BUCKET_DURATION_IN_SECS
+}
+
+fn get_redis_key_for_success_rate(
+    merchant_id: &str,
+    connector: &str,
+    payment_method_type: Option<enums::PaymentMethodType>,
+    card_issuing_country: Option<&str>,
+    bucket: i64,
+) -> String {
+    let payment_method_type = payment_method_type
+        .map(|payment_method_type| payment_method_type.to_string())
+        .unwrap_or_else(|| "any".to_string());
+    let card_issuing_country = card_issuing_country.unwrap_or("any");
+    format!(
+        "success_rate_{merchant_id}_{connector}_{payment_method_type}_{card_issuing_country}_{bucket}"
+    )
+}
+
+/// Success rate based routing is opt-in, merchants are enabled through the
+/// `success_rate_routing_{merchant_id}` config
+pub async fn config_should_perform_success_rate_routing(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> bool {
+    let config = db
+        .find_config_by_key_unwrap_or(
+            format!("success_rate_routing_{}", merchant_id).as_str(),
+            Some("false".to_string()),
+        )
+        .await;
+    match config {
+        Ok(conf) => conf.config == "true",
+        Err(err) => {
+            logger::error!("{err}");
+            false
+        }
+    }
+}
+
+fn get_card_issuing_country<F: Clone>(payment_data: &PaymentData<F>) -> Option<&str> {
+    match payment_data.payment_method_data.as_ref() {
+        Some(api::PaymentMethodData::Card(card)) => card.card_issuing_country.as_deref(),
+        _ => None,
+    }
+}
+
+async fn get_count(redis_conn: &RedisConnectionPool, key: &str, field: &str) -> i64 {
+    redis_conn
+        .get_hash_field::<Option<i64>>(key, field)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to get authorization count from redis"))
+        .ok()
+        .flatten()
+        .unwrap_or(0)
+}
+
+async fn get_success_rate(
+    state: &AppState,
+    merchant_id: &str,
+    connector: &str,
+    payment_method_type: Option<enums::PaymentMethodType>,
+    card_issuing_country: Option<&str>,
+) -> Option<f64> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+        .ok()?;
+
+    let current_bucket = get_current_bucket();
+    let mut counts = AuthorizationCounts::default();
+    for bucket in [current_bucket - 1, current_bucket] {
+        let key = get_redis_key_for_success_rate(
+            merchant_id,
+            connector,
+            payment_method_type,
+            card_issuing_country,
+            bucket,
+        );
+        counts.total += get_count(&redis_conn, &key, TOTAL_COUNT_FIELD).await;
+        counts.successful += get_count(&redis_conn, &key, SUCCESS_COUNT_FIELD).await;
+    }
+
+    counts.success_rate()
+}
+
+/// Orders the connectors by their success rate for the payment, if the merchant has opted in.
+/// Connectors without enough recent authorizations are tried first so that their success rate
+/// can be established, ties keep the order decided by the routing algorithm.
+#[instrument(skip_all)]
+pub async fn order_connectors_by_success_rate<F: Clone>(
+    state: &AppState,
+    merchant_id: &str,
+    payment_data: &PaymentData<F>,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    if connectors.len() < 2
+        || !config_should_perform_success_rate_routing(&*state.store, merchant_id).await
+    {
+        return connectors;
+    }
+
+    if rand::thread_rng().gen_range(0..100) < consts::SUCCESS_RATE_EXPLORATION_PERCENTAGE {
+        logger::debug!("Routing payment for exploration, success rates are not considered");
+        return connectors;
+    }
+
+    let payment_method_type = payment_data.payment_attempt.payment_method_type;
+    let card_issuing_country = get_card_issuing_country(payment_data);
+
+    let mut connectors_with_success_rate = Vec::with_capacity(connectors.len());
+    for connector in connectors {
+        let success_rate = get_success_rate(
+            state,
+            merchant_id,
+            &connector.connector.to_string(),
+            payment_method_type,
+            card_issuing_country,
+        )
+        .await
+        .unwrap_or(1.0);
+        connectors_with_success_rate.push((connector, success_rate));
+    }
+
+    connectors_with_success_rate.sort_by(|(_, first), (_, second)| second.total_cmp(first));
+    logger::debug!(
+        success_rates = ?connectors_with_success_rate
+            .iter()
+            .map(|(connector, success_rate)| (connector.connector.to_string(), *success_rate))
+            .collect::<Vec<_>>()
+    );
+
+    connectors_with_success_rate
+        .into_iter()
+        .map(|(connector, _)| connector)
+        .collect()
+}
+
+/// Records whether the authorization succeeded, for merchants that have opted in to success rate
+/// based routing. Authorizations which have not reached a final outcome yet are not recorded, the
+/// outcome of payments requiring 3DS is recorded once the authorization is completed. Failures are
+/// only logged, as tracking must not affect the processing of the payment.
+#[instrument(skip_all)]
+pub async fn record_authorization_outcome<F, T>(
+    state: &AppState,
+    router_data: &types::RouterData<F, T, types::PaymentsResponseData>,
+    payment_method_type: Option<enums::PaymentMethodType>,
+    payment_method_data: Option<&domain::PaymentMethodData>,
+) {
+    let is_success = match (&router_data.response, router_data.status) {
+        (Err(_), _) => false,
+        (
+            Ok(_),
+            enums::AttemptStatus::Authorized
+            | enums::AttemptStatus::Charged
+            | enums::AttemptStatus::PartialCharged,
+        ) => true,
+        (Ok(_), enums::AttemptStatus::Failure | enums::AttemptStatus::AuthorizationFailed) => false,
+        (Ok(_), _) => return,
+    };
+
+    if !config_should_perform_success_rate_routing(&*state.store, &router_data.merchant_id).await {
+        return;
+    }
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return;
+        }
+    };
+
+    let card_issuing_country = match payment_method_data {
+        Some(domain::PaymentMethodData::Card(card)) => card.card_issuing_country.as_deref(),
+        _ => None,
+    };
+    let key = get_redis_key_for_success_rate(
+        &router_data.merchant_id,
+        &router_data.connector,
+        payment_method_type,
+        card_issuing_country,
+        get_current_bucket(),
+    );
+
+    if let Err(error) = redis_conn
+        .increment_hash_fields_with_expiry(
+            &key,
+            &[
+                (TOTAL_COUNT_FIELD, 1),
+                (SUCCESS_COUNT_FIELD, i64::from(is_success)),
+            ],
+            consts::SUCCESS_RATE_TTL,
+        )
+        .await
+    {
+        logger::error!(?error, "Failed to record authorization outcome in redis");
+    }
+}
@@ -0,0 +1,62 @@
+use api_models::{
+    payments::{
+        PaymentAttemptRoutingDecision, PaymentRoutingDecisionsRequest,
+        PaymentRoutingDecisionsResponse,
+    },
+    routing::RoutingDecision,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    routes::AppState,
+    services,
+    types::domain,
+    utils::ValueExt,
+};
+
+#[instrument(skip_all)]
+pub async fn retrieve_routing_decisions(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: PaymentRoutingDecisionsRequest,
+) -> RouterResponse<PaymentRoutingDecisionsResponse> {
+    let db = state.store.as_ref();
+    let mut payment_attempts = db
+        .find_attempts_by_merchant_id_payment_id(
+            &merchant_account.merchant_id,
+            &req.payment_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    payment_attempts.sort_by_key(|payment_attempt| payment_attempt.created_at);
+
+    let attempts = payment_attempts
+        .into_iter()
+        .map(|payment_attempt| {
+            let routing_decision = payment_attempt
+                .routing_decision
+                .map(|value| value.parse_value::<RoutingDecision>("RoutingDecision"))
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to deserialize the routing decision")?;
+
+            Ok(PaymentAttemptRoutingDecision {
+                attempt_id: payment_attempt.attempt_id,
+                connector: payment_attempt.connector,
+                status: payment_attempt.status,
+                created_at: payment_attempt.created_at,
+                routing_decision,
+            })
+        })
+        .collect::<errors::RouterResult<Vec<_>>>()?;
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentRoutingDecisionsResponse {
+            payment_id: req.payment_id,
+            attempts,
+        },
+    ))
+}
@@ -231,6 +231,21 @@ where
                     )
                     .await;
 
+                let duplicate_locker_id = (state.conf.locker.locker_enabled
+                    && resp.payment_method == Some(PaymentMethod::Card))
+                .then(|| resp.payment_method_id.clone());
+                let duplication_check =
+                    payment_methods::cards::check_duplicate_payment_method_by_fingerprint(
+                        state,
+                        &payment_method_create_request,
+                        &mut resp,
+                        duplication_check,
+                        duplicate_locker_id.as_deref(),
+                        merchant_account,
+                        &customer_id,
+                    )
+                    .await?;
+
                 let mut payment_method_id = resp.payment_method_id.clone();
                 let mut locker_id = None;
 
@@ -294,6 +309,21 @@ where
                                         )
                                         .attach_printable("Failed to add payment method in db")?;
                                     };
+                                    if network_transaction_id.is_some() {
+                                        db.update_payment_method(
+                                            pm.clone(),
+                                            storage::PaymentMethodUpdate::NetworkTransactionIdAndStatusUpdate {
+                                                network_transaction_id,
+                                                status: None,
+                                            },
+                                            merchant_account.storage_scheme,
+                                        )
+                                        .await
+                                        .change_context(
+                                            errors::ApiErrorResponse::InternalServerError,
+                                        )
+                                        .attach_printable("Failed to update payment method in db")?;
+                                    }
                                     // update if its a off-session mit payment
                                     if check_for_mit_mandates {
                                         let connector_mandate_details =
@@ -625,6 +655,7 @@ async fn skip_saving_card_in_locker(
                 bank_transfer: None,
                 last_used_at: Some(common_utils::date_time::now()),
                 client_secret: None,
+                fingerprint_id: None,
             };
 
             Ok((pm_resp, None))
@@ -647,6 +678,7 @@ async fn skip_saving_card_in_locker(
                 bank_transfer: None,
                 last_used_at: Some(common_utils::date_time::now()),
                 client_secret: None,
+                fingerprint_id: None,
             };
             Ok((payment_method_response, None))
         }
@@ -697,6 +729,7 @@ pub async fn save_in_locker(
                 payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]), //[#219]
                 last_used_at: Some(common_utils::date_time::now()),
                 client_secret: None,
+                fingerprint_id: None,
             };
             Ok((payment_method_response, None))
         }
@@ -1984,6 +1984,7 @@ pub async fn payout_create_db_entries(
         attempt_count: 1,
         metadata: req.metadata.clone(),
         confirm: req.confirm,
+        test_mode: Some(state.test_mode),
         ..Default::default()
     };
     let payouts = db
@@ -2134,7 +2135,10 @@ pub async fn add_external_account_addition_task(
 ) -> CustomResult<(), errors::StorageError> {
     let runner = storage::ProcessTrackerRunner::AttachPayoutAccountWorkflow;
     let task = "STRPE_ATTACH_EXTERNAL_ACCOUNT";
-    let tag = ["PAYOUTS", "STRIPE", "ACCOUNT", "CREATE"];
+    let tag = pt_utils::get_tags_with_connector(
+        ["PAYOUTS", "STRIPE", "ACCOUNT", "CREATE"],
+        payout_data.payout_attempt.connector.as_deref(),
+    );
     let process_tracker_id = pt_utils::get_process_tracker_id(
         runner,
         task,
@@ -193,6 +193,10 @@ pub async fn trigger_refund_to_gateway(
             types::RefundsData,
             types::RefundsResponseData,
         > = connector.connector.get_connector_integration();
+        connector_integration
+            .execute_pretasks(&mut router_data, state)
+            .await
+            .to_refund_failed_response()?;
         let router_data_res = services::execute_connector_processing_step(
             state,
             connector_integration,
@@ -1079,7 +1083,7 @@ pub async fn add_refund_sync_task(
     let process_tracker_id = format!("{runner}_{task}_{}", refund.internal_reference_id);
     let schedule_time = common_utils::date_time::now();
     let refund_workflow_tracking_data = refund_to_refund_core_workflow_model(refund);
-    let tag = ["REFUND"];
+    let tag = process_tracker_utils::get_tags_with_connector(["REFUND"], Some(&refund.connector));
     let process_tracker_entry = storage::ProcessTrackerNew::new(
         process_tracker_id,
         task,
@@ -1118,7 +1122,7 @@ pub async fn add_refund_execute_task(
 ) -> RouterResult<storage::ProcessTracker> {
     let task = "EXECUTE_REFUND";
     let process_tracker_id = format!("{runner}_{task}_{}", refund.internal_reference_id);
-    let tag = ["REFUND"];
+    let tag = process_tracker_utils::get_tags_with_connector(["REFUND"], Some(&refund.connector));
     let schedule_time = common_utils::date_time::now();
     let refund_workflow_tracking_data = refund_to_refund_core_workflow_model(refund);
     let process_tracker_entry = storage::ProcessTrackerNew::new(
@@ -23,6 +23,7 @@ use crate::types::transformers::{ForeignInto, ForeignTryFrom};
 use crate::{
     consts,
     core::{
+        audit_log,
         errors::{RouterResponse, StorageErrorExt},
         metrics, utils as core_utils,
     },
@@ -33,7 +34,7 @@ use crate::{
 #[cfg(not(feature = "business_profile_routing"))]
 use crate::{core::errors, services::api as service_api, types::storage};
 #[cfg(feature = "business_profile_routing")]
-use crate::{errors, services::api as service_api};
+use crate::{errors, services::api as service_api, types::storage};
 
 pub enum TransactionData<'a, F>
 where
@@ -44,6 +45,24 @@ where
     Payout(&'a payouts::PayoutData),
 }
 
+async fn record_routing_audit_log(
+    state: &AppState,
+    merchant_id: &str,
+    record: &routing_types::RoutingDictionaryRecord,
+    action: enums::AuditLogAction,
+) {
+    audit_log::record_audit_log(
+        state,
+        merchant_id,
+        enums::AuditLogEntityType::RoutingAlgorithm,
+        &record.id,
+        action,
+        None,
+        Some(record),
+    )
+    .await;
+}
+
 pub async fn retrieve_merchant_routing_dictionary(
     state: AppState,
     merchant_account: domain::MerchantAccount,
@@ -158,7 +177,7 @@ pub async fn create_routing_config(
         let algo = RoutingAlgorithm {
             algorithm_id: algorithm_id.clone(),
             profile_id,
-            merchant_id: merchant_account.merchant_id,
+            merchant_id: merchant_account.merchant_id.clone(),
             name: name.clone(),
             description: Some(description.clone()),
             kind: algorithm.get_kind().foreign_into(),
@@ -174,6 +193,14 @@ pub async fn create_routing_config(
 
         let new_record = record.foreign_into();
 
+        record_routing_audit_log(
+            &state,
+            &merchant_account.merchant_id,
+            &new_record,
+            enums::AuditLogAction::Create,
+        )
+        .await;
+
         metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(new_record))
     }
@@ -241,6 +268,14 @@ pub async fn create_routing_config(
         )
         .await?;
 
+        record_routing_audit_log(
+            &state,
+            &merchant_account.merchant_id,
+            &new_record,
+            enums::AuditLogAction::Create,
+        )
+        .await;
+
         metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(new_record))
     }
@@ -314,10 +349,17 @@ pub async fn link_routing_config(
         )
         .await?;
 
+        let response = routing_algorithm.foreign_into();
+        record_routing_audit_log(
+            &state,
+            &merchant_account.merchant_id,
+            &response,
+            enums::AuditLogAction::Activate,
+        )
+        .await;
+
         metrics::ROUTING_LINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
-        Ok(service_api::ApplicationResponse::Json(
-            routing_algorithm.foreign_into(),
-        ))
+        Ok(service_api::ApplicationResponse::Json(response))
     }
 
     #[cfg(not(feature = "business_profile_routing"))]
@@ -364,11 +406,192 @@ pub async fn link_routing_config(
         .await?;
         helpers::update_merchant_active_algorithm_ref(db, &key_store, routing_ref).await?;
 
+        record_routing_audit_log(
+            &state,
+            &merchant_account.merchant_id,
+            &response,
+            enums::AuditLogAction::Activate,
+        )
+        .await;
+
         metrics::ROUTING_LINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(response))
     }
 }
 
+/// Replaces the splits of a volume split config in place. If the config is active, its reference
+/// is re-stamped so that every router instance reloads the config on its next payment, without
+/// having to activate a new config.
+pub async fn update_volume_split_routing_config(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    algorithm_id: RoutingAlgorithmId,
+    request: routing_types::RoutingVolumeSplitUpdateRequest,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_UPDATE_VOLUME_SPLIT.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+
+    helpers::validate_volume_splits(&request.splits)?;
+    let algorithm = routing_types::RoutingAlgorithm::VolumeSplit(request.splits);
+
+    #[cfg(feature = "business_profile_routing")]
+    {
+        let routing_algorithm = db
+            .find_routing_algorithm_by_algorithm_id_merchant_id(
+                &algorithm_id.0,
+                &merchant_account.merchant_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+        utils::when(
+            !matches!(
+                routing_algorithm.kind,
+                storage::enums::RoutingAlgorithmKind::VolumeSplit
+            ),
+            || {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Only the splits of a volume split routing config can be updated"
+                        .to_string(),
+                })
+            },
+        )?;
+
+        let business_profile = core_utils::validate_and_get_business_profile(
+            db,
+            Some(&routing_algorithm.profile_id),
+            &merchant_account.merchant_id,
+        )
+        .await?
+        .get_required_value("BusinessProfile")
+        .change_context(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: routing_algorithm.profile_id.clone(),
+        })?;
+
+        helpers::validate_connectors_in_routing_config(
+            db,
+            &key_store,
+            &merchant_account.merchant_id,
+            &business_profile.profile_id,
+            &algorithm,
+        )
+        .await?;
+
+        let record = db
+            .update_routing_algorithm_by_algorithm_id_merchant_id(
+                &algorithm_id.0,
+                &merchant_account.merchant_id,
+                storage::RoutingAlgorithmUpdate::AlgorithmDataUpdate {
+                    algorithm_data: serde_json::json!(algorithm),
+                    modified_at: common_utils::date_time::now(),
+                },
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+        let routing_ref: Option<routing_types::RoutingAlgorithmRef> = match record.algorithm_for {
+            enums::TransactionType::Payment => business_profile.routing_algorithm.clone(),
+            #[cfg(feature = "payouts")]
+            enums::TransactionType::Payout => business_profile.payout_routing_algorithm.clone(),
+        }
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm ref from business profile")?;
+
+        if let Some(mut routing_ref) = routing_ref.filter(|routing_ref| {
+            routing_ref.algorithm_id.as_deref() == Some(algorithm_id.0.as_str())
+        }) {
+            routing_ref.update_algorithm_id(algorithm_id.0);
+            helpers::update_business_profile_active_algorithm_ref(
+                db,
+                business_profile,
+                routing_ref,
+                &record.algorithm_for,
+            )
+            .await?;
+        }
+
+        let response = record.foreign_into();
+        record_routing_audit_log(
+            &state,
+            &merchant_account.merchant_id,
+            &response,
+            enums::AuditLogAction::Update,
+        )
+        .await;
+
+        metrics::ROUTING_UPDATE_VOLUME_SPLIT_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+        Ok(service_api::ApplicationResponse::Json(response))
+    }
+
+    #[cfg(not(feature = "business_profile_routing"))]
+    {
+        let mut merchant_dictionary =
+            helpers::get_merchant_routing_dictionary(db, &merchant_account.merchant_id).await?;
+
+        let record = merchant_dictionary
+            .records
+            .iter_mut()
+            .find(|rec| rec.id == algorithm_id.0)
+            .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)
+            .attach_printable("Record with given ID not found for routing config update")?;
+
+        utils::when(
+            !matches!(
+                record.kind,
+                routing_types::RoutingAlgorithmKind::VolumeSplit
+            ),
+            || {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Only the splits of a volume split routing config can be updated"
+                        .to_string(),
+                })
+            },
+        )?;
+
+        record.modified_at = common_utils::date_time::now_unix_timestamp();
+        let response = record.clone();
+
+        helpers::update_routing_algorithm(db, algorithm_id.0.clone(), algorithm).await?;
+        helpers::update_merchant_routing_dictionary(
+            db,
+            &merchant_account.merchant_id,
+            merchant_dictionary,
+        )
+        .await?;
+
+        let routing_ref: Option<routing_types::RoutingAlgorithmRef> = merchant_account
+            .routing_algorithm
+            .clone()
+            .map(|val| val.parse_value("RoutingAlgorithmRef"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "unable to deserialize routing algorithm ref from merchant account",
+            )?;
+
+        if let Some(mut routing_ref) = routing_ref.filter(|routing_ref| {
+            routing_ref.algorithm_id.as_deref() == Some(algorithm_id.0.as_str())
+        }) {
+            routing_ref.update_algorithm_id(algorithm_id.0);
+            helpers::update_merchant_active_algorithm_ref(db, &key_store, routing_ref).await?;
+        }
+
+        record_routing_audit_log(
+            &state,
+            &merchant_account.merchant_id,
+            &response,
+            enums::AuditLogAction::Update,
+        )
+        .await;
+
+        metrics::ROUTING_UPDATE_VOLUME_SPLIT_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+        Ok(service_api::ApplicationResponse::Json(response))
+    }
+}
+
 pub async fn retrieve_routing_config(
     state: AppState,
     merchant_account: domain::MerchantAccount,
@@ -512,6 +735,14 @@ pub async fn unlink_routing_config(
                         )
                         .await?;
 
+                        record_routing_audit_log(
+                            &state,
+                            &merchant_account.merchant_id,
+                            &response,
+                            enums::AuditLogAction::Deactivate,
+                        )
+                        .await;
+
                         metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(
                             &metrics::CONTEXT,
                             1,
@@ -623,6 +854,14 @@ pub async fn unlink_routing_config(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to update routing algorithm ref in merchant account")?;
 
+        record_routing_audit_log(
+            &state,
+            &merchant_account.merchant_id,
+            &response,
+            enums::AuditLogAction::Deactivate,
+        )
+        .await;
+
         metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(response))
     }
@@ -806,6 +1045,135 @@ pub async fn retrieve_linked_routing_config(
     }
 }
 
+/// Maximum number of routing configs of a business profile that are simulated
+#[cfg(feature = "business_profile_routing")]
+const ROUTING_SIMULATION_CONFIGS_LIMIT: i64 = 100;
+
+pub async fn simulate_routing(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    request: routing_types::RoutingSimulationRequest,
+) -> RouterResponse<routing_types::RoutingSimulationResponse> {
+    metrics::ROUTING_SIMULATE.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+    let mut results = Vec::new();
+
+    #[cfg(feature = "business_profile_routing")]
+    {
+        let business_profiles = if let Some(profile_id) = request.profile_id {
+            core_utils::validate_and_get_business_profile(
+                db,
+                Some(&profile_id),
+                &merchant_account.merchant_id,
+            )
+            .await?
+            .map(|profile| vec![profile])
+            .get_required_value("BusinessProfile")
+            .change_context(errors::ApiErrorResponse::BusinessProfileNotFound { id: profile_id })?
+        } else {
+            db.list_business_profile_by_merchant_id(&merchant_account.merchant_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?
+        };
+
+        for business_profile in business_profiles {
+            let routing_ref: routing_types::RoutingAlgorithmRef = business_profile
+                .routing_algorithm
+                .clone()
+                .map(|val| val.parse_value("RoutingAlgorithmRef"))
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("unable to deserialize routing algorithm ref")?
+                .unwrap_or_default();
+            let active_id = routing_ref.algorithm_id;
+
+            let records = db
+                .list_routing_algorithm_metadata_by_profile_id(
+                    &business_profile.profile_id,
+                    ROUTING_SIMULATION_CONFIGS_LIMIT,
+                    0,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+            for record in records
+                .into_iter()
+                .filter(|record| record.algorithm_for == enums::TransactionType::Payment)
+            {
+                let routing_algorithm = db
+                    .find_routing_algorithm_by_profile_id_algorithm_id(
+                        &business_profile.profile_id,
+                        &record.algorithm_id,
+                    )
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+                let algorithm =
+                    routing_types::MerchantRoutingAlgorithm::foreign_try_from(routing_algorithm)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("unable to parse routing algorithm")?;
+                let is_active = active_id.as_deref() == Some(algorithm.id.as_str());
+
+                results.push(
+                    payments::routing::simulate_routing_algorithm(
+                        algorithm,
+                        is_active,
+                        request.payment.clone(),
+                    )
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("failed to simulate routing config")?,
+                );
+            }
+        }
+    }
+    #[cfg(not(feature = "business_profile_routing"))]
+    {
+        let merchant_dictionary =
+            helpers::get_merchant_routing_dictionary(db, &merchant_account.merchant_id).await?;
+
+        for record in merchant_dictionary.records.into_iter().filter(|record| {
+            record.algorithm_for.map_or(true, |algorithm_for| {
+                algorithm_for == enums::TransactionType::Payment
+            })
+        }) {
+            let config = db
+                .find_config_by_key(&record.id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("error finding routing config in db")?;
+            let algorithm: routing_types::RoutingAlgorithm = config
+                .config
+                .parse_struct("RoutingAlgorithm")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("unable to parse routing algorithm")?;
+            let is_active = merchant_dictionary.active_id.as_deref() == Some(record.id.as_str());
+
+            let algorithm = routing_types::MerchantRoutingAlgorithm {
+                id: record.id,
+                name: record.name,
+                description: record.description,
+                algorithm,
+                created_at: record.created_at,
+                modified_at: record.modified_at,
+                algorithm_for: enums::TransactionType::Payment,
+            };
+            results.push(
+                payments::routing::simulate_routing_algorithm(
+                    algorithm,
+                    is_active,
+                    request.payment.clone(),
+                )
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("failed to simulate routing config")?,
+            );
+        }
+    }
+
+    metrics::ROUTING_SIMULATE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingSimulationResponse { results },
+    ))
+}
+
 pub async fn upsert_connector_agnostic_mandate_config(
     state: AppState,
     business_profile_id: &str,
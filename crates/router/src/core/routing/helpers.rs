@@ -264,6 +264,10 @@ pub async fn update_business_profile_active_algorithm_ref(
         session_expiry: None,
         authentication_connector_details: None,
         extended_card_info_config: None,
+        previous_payment_response_hash_key: None,
+        previous_payment_response_hash_key_expires_at: None,
+        outgoing_webhook_custom_http_headers: None,
+        statement_descriptor_name: None,
     };
     db.update_business_profile_by_profile_id(current_business_profile, business_profile_update)
         .await
@@ -466,3 +470,17 @@ pub fn get_payment_config_routing_id(merchant_id: &str) -> String {
 pub fn get_payment_method_surcharge_routing_id(merchant_id: &str) -> String {
     format!("payment_method_surcharge_id_{merchant_id}")
 }
+
+/// Volume splits are percentages of the traffic, so they must add up to 100
+pub fn validate_volume_splits(splits: &[routing_types::ConnectorVolumeSplit]) -> RouterResult<()> {
+    let total: u16 = splits.iter().map(|split| u16::from(split.split)).sum();
+
+    error_stack::ensure!(
+        !splits.is_empty() && total == 100,
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("volume splits must add up to 100, found {total}"),
+        }
+    );
+
+    Ok(())
+}
@@ -61,6 +61,7 @@ pub async fn get_mca_for_payout<'a>(
                 &payout_data.profile_id,
                 connector_id,
                 payout_data.payout_attempt.merchant_connector_id.as_ref(),
+                payout_data.payouts.test_mode.unwrap_or_default(),
             )
             .await?;
             Ok(merchant_connector_account)
@@ -243,6 +244,7 @@ pub async fn construct_refund_router_data<'a, F>(
         &profile_id,
         connector_id,
         payment_attempt.merchant_connector_id.as_ref(),
+        payment_intent.test_mode.unwrap_or_default(),
     )
     .await?;
 
@@ -533,6 +535,7 @@ pub async fn construct_accept_dispute_router_data<'a>(
         &profile_id,
         &dispute.connector,
         payment_attempt.merchant_connector_id.as_ref(),
+        payment_intent.test_mode.unwrap_or_default(),
     )
     .await?;
 
@@ -627,6 +630,7 @@ pub async fn construct_submit_evidence_router_data<'a>(
         &profile_id,
         connector_id,
         payment_attempt.merchant_connector_id.as_ref(),
+        payment_intent.test_mode.unwrap_or_default(),
     )
     .await?;
 
@@ -719,6 +723,7 @@ pub async fn construct_upload_file_router_data<'a>(
         &profile_id,
         connector_id,
         payment_attempt.merchant_connector_id.as_ref(),
+        payment_intent.test_mode.unwrap_or_default(),
     )
     .await?;
 
@@ -815,6 +820,7 @@ pub async fn construct_defend_dispute_router_data<'a>(
         &profile_id,
         connector_id,
         payment_attempt.merchant_connector_id.as_ref(),
+        payment_intent.test_mode.unwrap_or_default(),
     )
     .await?;
 
@@ -903,6 +909,7 @@ pub async fn construct_retrieve_file_router_data<'a>(
         profile_id,
         connector_id,
         file_metadata.merchant_connector_id.as_ref(),
+        file_metadata.test_mode.unwrap_or_default(),
     )
     .await?;
 
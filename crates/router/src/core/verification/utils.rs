@@ -61,6 +61,7 @@ pub async fn check_existence_and_add_domain_to_db(
         pm_auth_config: None,
         connector_label: None,
         status: None,
+        test_connector_account_details: None,
     };
     state
         .store
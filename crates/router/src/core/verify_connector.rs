@@ -1,5 +1,6 @@
 use api_models::{enums::Connector, verify_connector::VerifyConnectorRequest};
 use error_stack::ResultExt;
+use router_env::logger;
 
 use crate::{
     connector,
@@ -8,6 +9,7 @@ use crate::{
     types::{
         api,
         api::verify_connector::{self as types, VerifyConnector},
+        ConnectorAuthType,
     },
     utils::verify_connector as utils,
     AppState,
@@ -16,39 +18,75 @@ use crate::{
 pub async fn verify_connector_credentials(
     state: AppState,
     req: VerifyConnectorRequest,
+) -> errors::RouterResponse<()> {
+    verify_connector_auth(
+        &state,
+        req.connector_name,
+        req.connector_account_details.into(),
+    )
+    .await
+}
+
+/// Checks the credentials of a merchant connector account against the connector, returning
+/// whether the connector accepted them, or `None` if they could not be checked.
+pub async fn check_merchant_connector_credentials(
+    state: &AppState,
+    connector_name: Connector,
+    connector_auth: ConnectorAuthType,
+) -> Option<bool> {
+    match verify_connector_auth(state, connector_name, connector_auth).await {
+        Ok(_) => Some(true),
+        Err(error) => match error.current_context() {
+            errors::ApiErrorResponse::InvalidRequestData { .. } => {
+                logger::info!(?error, "Connector rejected the credentials");
+                Some(false)
+            }
+            errors::ApiErrorResponse::FlowNotSupported { .. } => None,
+            _ => {
+                logger::error!(?error, "Failed to verify connector credentials");
+                None
+            }
+        },
+    }
+}
+
+async fn verify_connector_auth(
+    state: &AppState,
+    connector_name: Connector,
+    connector_auth: ConnectorAuthType,
 ) -> errors::RouterResponse<()> {
     let boxed_connector = api::ConnectorData::get_connector_by_name(
         &state.conf.connectors,
-        &req.connector_name.to_string(),
+        &connector_name.to_string(),
         api::GetToken::Connector,
         None,
     )
     .change_context(errors::ApiErrorResponse::IncorrectConnectorNameGiven)?;
 
-    let card_details = utils::get_test_card_details(req.connector_name)?.ok_or(
+    let card_details = utils::get_test_card_details(connector_name)?.ok_or(
         errors::ApiErrorResponse::FlowNotSupported {
             flow: "Verify credentials".to_string(),
-            connector: req.connector_name.to_string(),
+            connector: connector_name.to_string(),
         },
     )?;
 
-    match req.connector_name {
+    match connector_name {
         Connector::Stripe => {
             connector::Stripe::verify(
-                &state,
+                state,
                 types::VerifyConnectorData {
                     connector: *boxed_connector.connector,
-                    connector_auth: req.connector_account_details.into(),
+                    connector_auth,
                     card_details,
                 },
             )
             .await
         }
         Connector::Paypal => connector::Paypal::get_access_token(
-            &state,
+            state,
             types::VerifyConnectorData {
                 connector: *boxed_connector.connector,
-                connector_auth: req.connector_account_details.into(),
+                connector_auth,
                 card_details,
             },
         )
@@ -56,7 +94,7 @@ pub async fn verify_connector_credentials(
         .map(|_| services::ApplicationResponse::StatusOk),
         _ => Err(errors::ApiErrorResponse::FlowNotSupported {
             flow: "Verify credentials".to_string(),
-            connector: req.connector_name.to_string(),
+            connector: connector_name.to_string(),
         }
         .into()),
     }
@@ -3,7 +3,7 @@ pub mod utils;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
 
-use std::{str::FromStr, time::Instant};
+use std::{collections::HashMap, str::FromStr, time::Instant};
 
 use actix_web::FromRequest;
 use api_models::{
@@ -59,6 +59,7 @@ use crate::{
 const OUTGOING_WEBHOOK_TIMEOUT_SECS: u64 = 5;
 const MERCHANT_ID: &str = "merchant_id";
 
+#[allow(clippy::too_many_arguments)]
 pub async fn payments_incoming_webhook_flow<Ctx: PaymentMethodRetrieve>(
     state: AppState,
     req_state: ReqState,
@@ -67,10 +68,21 @@ pub async fn payments_incoming_webhook_flow<Ctx: PaymentMethodRetrieve>(
     key_store: domain::MerchantKeyStore,
     webhook_details: api::IncomingWebhookDetails,
     source_verified: bool,
+    is_payment_sync_required: bool,
 ) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
-    let consume_or_trigger_flow = if source_verified {
+    // The payment is updated from the webhook only if the webhook can be trusted and carries the
+    // status of the payment, otherwise the status of the payment is synced with the connector
+    let consume_or_trigger_flow = if source_verified && !is_payment_sync_required {
         payments::CallConnectorAction::HandleResponse(webhook_details.resource_object)
     } else {
+        metrics::WEBHOOK_PAYMENT_SYNC_TRIGGERED_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &[metrics::KeyValue::new(
+                MERCHANT_ID,
+                merchant_account.merchant_id.clone(),
+            )],
+        );
         payments::CallConnectorAction::Trigger
     };
     let payments_response = match webhook_details.object_reference_id {
@@ -178,6 +190,8 @@ pub async fn payments_incoming_webhook_flow<Ctx: PaymentMethodRetrieve>(
             // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
             if let Some(outgoing_event_type) = event_type {
                 let primary_object_created_at = payments_response.created;
+                let triggering_attempt_id =
+                    get_active_attempt_id(&state, &merchant_account, &payment_id).await;
                 create_event_and_trigger_outgoing_webhook(
                     state,
                     merchant_account,
@@ -189,6 +203,7 @@ pub async fn payments_incoming_webhook_flow<Ctx: PaymentMethodRetrieve>(
                     enums::EventObjectType::PaymentDetails,
                     api::OutgoingWebhookContent::PaymentDetails(payments_response),
                     primary_object_created_at,
+                    triggering_attempt_id,
                 )
                 .await?;
             };
@@ -292,6 +307,7 @@ pub async fn refunds_incoming_webhook_flow(
             enums::EventObjectType::RefundDetails,
             api::OutgoingWebhookContent::RefundDetails(refund_response),
             Some(updated_refund.created_at),
+            Some(updated_refund.attempt_id.clone()),
         )
         .await?;
     }
@@ -303,6 +319,25 @@ pub async fn refunds_incoming_webhook_flow(
     })
 }
 
+/// The active attempt of the payment is the attempt whose update triggered the outgoing webhook
+async fn get_active_attempt_id(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_id: &str,
+) -> Option<String> {
+    state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map(|payment_intent| payment_intent.active_attempt.get_id())
+        .map_err(|error| logger::error!(?error, "Failed to obtain the active payment attempt"))
+        .ok()
+}
+
 pub async fn get_payment_attempt_from_object_reference_id(
     state: &AppState,
     object_reference_id: api_models::webhooks::ObjectReferenceId,
@@ -557,6 +592,8 @@ pub async fn external_authentication_incoming_webhook_flow<Ctx: PaymentMethodRet
                         // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
                         if let Some(outgoing_event_type) = event_type {
                             let primary_object_created_at = payments_response.created;
+                            let triggering_attempt_id =
+                                get_active_attempt_id(&state, &merchant_account, &payment_id).await;
                             create_event_and_trigger_outgoing_webhook(
                                 state,
                                 merchant_account,
@@ -568,6 +605,7 @@ pub async fn external_authentication_incoming_webhook_flow<Ctx: PaymentMethodRet
                                 enums::EventObjectType::PaymentDetails,
                                 api::OutgoingWebhookContent::PaymentDetails(payments_response),
                                 primary_object_created_at,
+                                triggering_attempt_id,
                             )
                             .await?;
                         };
@@ -665,6 +703,7 @@ pub async fn mandates_incoming_webhook_flow(
                 enums::EventObjectType::MandateDetails,
                 api::OutgoingWebhookContent::MandateDetails(mandates_response),
                 Some(updated_mandate.created_at),
+                None,
             )
             .await?;
         }
@@ -736,6 +775,7 @@ pub async fn disputes_incoming_webhook_flow(
             enums::EventObjectType::DisputeDetails,
             api::OutgoingWebhookContent::DisputeDetails(disputes_response),
             Some(dispute_object.created_at),
+            Some(dispute_object.attempt_id.clone()),
         )
         .await?;
         metrics::INCOMING_DISPUTE_WEBHOOK_MERCHANT_NOTIFIED_METRIC.add(&metrics::CONTEXT, 1, &[]);
@@ -817,6 +857,8 @@ async fn bank_transfer_webhook_flow<Ctx: PaymentMethodRetrieve>(
             // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
             if let Some(outgoing_event_type) = event_type {
                 let primary_object_created_at = payments_response.created;
+                let triggering_attempt_id =
+                    get_active_attempt_id(&state, &merchant_account, &payment_id).await;
                 create_event_and_trigger_outgoing_webhook(
                     state,
                     merchant_account,
@@ -828,6 +870,7 @@ async fn bank_transfer_webhook_flow<Ctx: PaymentMethodRetrieve>(
                     enums::EventObjectType::PaymentDetails,
                     api::OutgoingWebhookContent::PaymentDetails(payments_response),
                     primary_object_created_at,
+                    triggering_attempt_id,
                 )
                 .await?;
             }
@@ -853,25 +896,153 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
+    triggering_attempt_id: Option<String>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
     let idempotent_event_id =
         utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
 
-    if !state.conf.webhooks.outgoing_enabled
-        || webhook_url_result.is_err()
-        || webhook_url_result.as_ref().is_ok_and(String::is_empty)
-    {
+    if !state.conf.webhooks.outgoing_enabled {
         logger::debug!(
             business_profile_id=%business_profile.profile_id,
             %idempotent_event_id,
-            "Outgoing webhooks are disabled in application configuration, or merchant webhook URL \
-             could not be obtained; skipping outgoing webhooks for event"
+            "Outgoing webhooks are disabled in application configuration; skipping outgoing \
+             webhooks for event"
         );
         return Ok(());
     }
 
+    // Webhook endpoints are looked up before delivering to the business profile, since the
+    // business profile is moved into the delivery task
+    let webhook_endpoints = state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(
+            &business_profile.merchant_id,
+            &business_profile.profile_id,
+            merchant_key_store,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to list webhook endpoints"))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|webhook_endpoint| {
+            webhook_endpoint.is_active
+                && is_event_type_enabled_for_webhook_endpoint(webhook_endpoint, event_type)
+        })
+        .collect::<Vec<_>>();
+
+    // The events created for the business profile and the webhook endpoints share the sequence
+    // number, since they are deliveries of the same event to different destinations
+    let sequence_number = get_next_event_sequence_number(&state).await;
+
+    let is_webhook_url_configured = get_webhook_url_from_business_profile(&business_profile)
+        .is_ok_and(|webhook_url| !webhook_url.is_empty());
+    let business_profile_delivery_result = if !is_webhook_url_configured {
+        logger::debug!(
+            business_profile_id=%business_profile.profile_id,
+            %idempotent_event_id,
+            "Merchant webhook URL could not be obtained; skipping outgoing webhooks to the \
+             business profile for event"
+        );
+        Ok(())
+    } else if !is_event_type_enabled_for_business_profile(&business_profile, event_type) {
+        logger::debug!(
+            business_profile_id=%business_profile.profile_id,
+            %idempotent_event_id,
+            "Merchant has not subscribed to the event type; skipping outgoing webhooks to the \
+             business profile for event"
+        );
+        Ok(())
+    } else {
+        create_event_and_trigger_delivery(
+            state.clone(),
+            &merchant_account,
+            business_profile.clone(),
+            merchant_key_store,
+            None,
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+            idempotent_event_id.clone(),
+            sequence_number,
+            triggering_attempt_id.clone(),
+        )
+        .await
+    };
+
+    for webhook_endpoint in webhook_endpoints {
+        let endpoint_id = webhook_endpoint.endpoint_id.clone();
+        create_event_and_trigger_delivery(
+            state.clone(),
+            &merchant_account,
+            business_profile.clone(),
+            merchant_key_store,
+            Some(webhook_endpoint),
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+            format!("{idempotent_event_id}_{endpoint_id}"),
+            sequence_number,
+            triggering_attempt_id.clone(),
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                %endpoint_id,
+                "Failed to trigger outgoing webhook to webhook endpoint"
+            )
+        })
+        .ok();
+    }
+
+    business_profile_delivery_result
+}
+
+/// Sequence numbers are drawn from a database sequence, so that every event raised for an object
+/// is assigned a sequence number larger than those of the events raised before it, even when the
+/// events are raised concurrently.
+async fn get_next_event_sequence_number(state: &AppState) -> Option<i64> {
+    state
+        .store
+        .get_next_event_sequence_number()
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to obtain the sequence number for outgoing webhook event"
+            )
+        })
+        .ok()
+}
+
+/// Creates the event for a single destination of the outgoing webhook (either the webhook URL of
+/// the business profile, or one of its webhook endpoints) and triggers its delivery
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn create_event_and_trigger_delivery(
+    state: AppState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: diesel_models::business_profile::BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    webhook_endpoint: Option<domain::WebhookEndpoint>,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    idempotent_event_id: String,
+    sequence_number: Option<i64>,
+    triggering_attempt_id: Option<String>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
@@ -882,12 +1053,15 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         event_type,
         content: content.clone(),
         timestamp: now,
+        sequence_number,
+        triggering_attempt_id: triggering_attempt_id.clone(),
     };
 
     let request_content = get_outgoing_webhook_request(
-        &merchant_account,
+        merchant_account,
+        &business_profile,
+        webhook_endpoint.as_ref(),
         outgoing_webhook,
-        business_profile.payment_response_hash_key.as_deref(),
     )
     .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
     .attach_printable("Failed to construct outgoing webhook request content")?;
@@ -920,6 +1094,10 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         ),
         response: None,
         delivery_attempt: Some(delivery_attempt),
+        dead_lettered_at: None,
+        webhook_endpoint_id: webhook_endpoint.map(|webhook_endpoint| webhook_endpoint.endpoint_id),
+        sequence_number,
+        triggering_attempt_id,
     };
 
     let event_insert_result = state
@@ -1023,11 +1201,11 @@ async fn trigger_webhook_to_merchant(
     delivery_attempt: enums::WebhookDeliveryAttempt,
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+    let (webhook_url, webhook_endpoint) = match (
+        get_webhook_url_and_endpoint(&state, &business_profile, merchant_key_store, &event).await,
         process_tracker.clone(),
     ) {
-        (Ok(webhook_url), _) => Ok(webhook_url),
+        (Ok(webhook_url_and_endpoint), _) => Ok(webhook_url_and_endpoint),
         (Err(error), Some(process_tracker)) => {
             if !error
                 .current_context()
@@ -1050,11 +1228,23 @@ async fn trigger_webhook_to_merchant(
 
     let event_id = event.event_id;
 
-    let headers = request_content
+    let mut headers: Vec<_> = request_content
         .headers
         .into_iter()
         .map(|(name, value)| (name, value.into_masked()))
         .collect();
+    // The custom headers may carry credentials meant for the webhook URL of the business profile,
+    // and are not sent to the webhook endpoints
+    if webhook_endpoint.is_none() {
+        headers.extend(
+            get_outgoing_webhook_custom_http_headers(&business_profile, merchant_key_store).await?,
+        );
+    }
+    headers.extend(types::get_timestamped_signature_headers(
+        &business_profile,
+        webhook_endpoint.as_ref(),
+        request_content.body.peek(),
+    )?);
     let request = services::RequestBuilder::new()
         .method(services::Method::Post)
         .url(&webhook_url)
@@ -1629,224 +1819,226 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetr
 
     let flow_type: api::WebhookFlow = event_type.into();
     let mut event_object: Box<dyn masking::ErasedMaskSerialize> = Box::new(serde_json::Value::Null);
-    let webhook_effect = if process_webhook_further
-        && !matches!(flow_type, api::WebhookFlow::ReturnResponse)
-    {
-        let object_ref_id = connector
-            .get_webhook_object_reference_id(&request_details)
-            .switch()
-            .attach_printable("Could not find object reference id in incoming webhook body")?;
-        let connector_enum = api_models::enums::Connector::from_str(&connector_name)
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "connector",
-            })
-            .attach_printable_lazy(|| {
-                format!("unable to parse connector name {connector_name:?}")
-            })?;
-        let connectors_with_source_verification_call = &state.conf.webhook_source_verification_call;
-
-        let merchant_connector_account = match merchant_connector_account {
-            Some(merchant_connector_account) => merchant_connector_account,
-            None => {
-                helper_utils::get_mca_from_object_reference_id(
-                    &*state.clone().store,
-                    object_ref_id.clone(),
-                    &merchant_account,
-                    &connector_name,
-                    &key_store,
-                )
-                .await?
-            }
-        };
+    let webhook_effect =
+        if process_webhook_further && !matches!(flow_type, api::WebhookFlow::ReturnResponse) {
+            let object_ref_id = connector
+                .get_webhook_object_reference_id(&request_details)
+                .switch()
+                .attach_printable("Could not find object reference id in incoming webhook body")?;
+            let connector_enum = api_models::enums::Connector::from_str(&connector_name)
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "connector",
+                })
+                .attach_printable_lazy(|| {
+                    format!("unable to parse connector name {connector_name:?}")
+                })?;
+            let merchant_connector_account = match merchant_connector_account {
+                Some(merchant_connector_account) => merchant_connector_account,
+                None => {
+                    helper_utils::get_mca_from_object_reference_id(
+                        &*state.clone().store,
+                        object_ref_id.clone(),
+                        &merchant_account,
+                        &connector_name,
+                        &key_store,
+                    )
+                    .await?
+                }
+            };
+
+            let source_verified = verify_webhook_source(
+                &state,
+                connector,
+                &merchant_account,
+                merchant_connector_account.clone(),
+                &connector_name,
+                &connector_enum,
+                &request_details,
+            )
+            .await?;
 
-        let source_verified = if connectors_with_source_verification_call
-            .connectors_with_webhook_source_verification_call
-            .contains(&connector_enum)
-        {
-            connector
-                .verify_webhook_source_verification_call(
+            // Webhooks of payments made with a test mode API key are signed with the test credentials
+            let source_verified = if !source_verified
+                && merchant_connector_account
+                    .test_connector_account_details
+                    .is_some()
+            {
+                verify_webhook_source(
                     &state,
+                    connector,
                     &merchant_account,
-                    merchant_connector_account.clone(),
+                    payments::helpers::use_test_connector_account_details(
+                        merchant_connector_account.clone(),
+                    )?,
                     &connector_name,
+                    &connector_enum,
                     &request_details,
                 )
-                .await
-                .or_else(|error| match error.current_context() {
-                    errors::ConnectorError::WebhookSourceVerificationFailed => {
-                        logger::error!(?error, "Source Verification Failed");
-                        Ok(false)
-                    }
-                    _ => Err(error),
-                })
-                .switch()
-                .attach_printable("There was an issue in incoming webhook source verification")?
-        } else {
-            connector
-                .verify_webhook_source(
-                    &request_details,
-                    &merchant_account,
-                    merchant_connector_account.clone(),
-                    connector_name.as_str(),
-                )
-                .await
-                .or_else(|error| match error.current_context() {
-                    errors::ConnectorError::WebhookSourceVerificationFailed => {
-                        logger::error!(?error, "Source Verification Failed");
-                        Ok(false)
-                    }
-                    _ => Err(error),
-                })
-                .switch()
-                .attach_printable("There was an issue in incoming webhook source verification")?
-        };
-
-        if source_verified {
-            metrics::WEBHOOK_SOURCE_VERIFIED_COUNT.add(
-                &metrics::CONTEXT,
-                1,
-                &[metrics::KeyValue::new(
-                    MERCHANT_ID,
-                    merchant_account.merchant_id.clone(),
-                )],
-            );
-        } else if connector.is_webhook_source_verification_mandatory() {
-            // if webhook consumption is mandatory for connector, fail webhook
-            // so that merchant can retrigger it after updating merchant_secret
-            return Err(errors::ApiErrorResponse::WebhookAuthenticationFailed.into());
-        }
+                .await?
+            } else {
+                source_verified
+            };
 
-        logger::info!(source_verified=?source_verified);
+            if source_verified {
+                metrics::WEBHOOK_SOURCE_VERIFIED_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[metrics::KeyValue::new(
+                        MERCHANT_ID,
+                        merchant_account.merchant_id.clone(),
+                    )],
+                );
+            } else if connector.is_webhook_source_verification_mandatory() {
+                // if webhook consumption is mandatory for connector, fail webhook
+                // so that merchant can retrigger it after updating merchant_secret
+                return Err(errors::ApiErrorResponse::WebhookAuthenticationFailed.into());
+            }
 
-        event_object = connector
-            .get_webhook_resource_object(&request_details)
-            .switch()
-            .attach_printable("Could not find resource object in incoming webhook body")?;
+            logger::info!(source_verified=?source_verified);
 
-        let webhook_details = api::IncomingWebhookDetails {
-            object_reference_id: object_ref_id.clone(),
-            resource_object: serde_json::to_vec(&event_object)
-                .change_context(errors::ParsingError::EncodeError("byte-vec"))
-                .attach_printable("Unable to convert webhook payload to a value")
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable(
-                    "There was an issue when encoding the incoming webhook body to bytes",
-                )?,
-        };
+            let is_payment_sync_required = state
+                .conf
+                .webhooks
+                .connectors_with_payment_sync_on_webhook
+                .contains(&connector_enum)
+                || connector
+                    .is_payment_sync_required_for_webhook(&request_details)
+                    .switch()
+                    .attach_printable(
+                        "There was an issue in determining if the payment must be synced with the \
+                     connector",
+                    )?;
 
-        let profile_id = merchant_connector_account
-            .profile_id
-            .as_ref()
-            .get_required_value("profile_id")
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Could not find profile_id in merchant connector account")?;
+            event_object = connector
+                .get_webhook_resource_object(&request_details)
+                .switch()
+                .attach_printable("Could not find resource object in incoming webhook body")?;
+
+            let webhook_details = api::IncomingWebhookDetails {
+                object_reference_id: object_ref_id.clone(),
+                resource_object: serde_json::to_vec(&event_object)
+                    .change_context(errors::ParsingError::EncodeError("byte-vec"))
+                    .attach_printable("Unable to convert webhook payload to a value")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(
+                        "There was an issue when encoding the incoming webhook body to bytes",
+                    )?,
+            };
 
-        let business_profile = state
-            .store
-            .find_business_profile_by_profile_id(profile_id)
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
-                id: profile_id.to_string(),
-            })?;
+            let profile_id = merchant_connector_account
+                .profile_id
+                .as_ref()
+                .get_required_value("profile_id")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Could not find profile_id in merchant connector account")?;
 
-        match flow_type {
-            api::WebhookFlow::Payment => Box::pin(payments_incoming_webhook_flow::<Ctx>(
-                state.clone(),
-                req_state,
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                source_verified,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for payments failed")?,
+            let business_profile = state
+                .store
+                .find_business_profile_by_profile_id(profile_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                    id: profile_id.to_string(),
+                })?;
 
-            api::WebhookFlow::Refund => Box::pin(refunds_incoming_webhook_flow(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                connector_name.as_str(),
-                source_verified,
-                event_type,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for refunds failed")?,
+            match flow_type {
+                api::WebhookFlow::Payment => Box::pin(payments_incoming_webhook_flow::<Ctx>(
+                    state.clone(),
+                    req_state,
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    source_verified,
+                    is_payment_sync_required,
+                ))
+                .await
+                .attach_printable("Incoming webhook flow for payments failed")?,
 
-            api::WebhookFlow::Dispute => Box::pin(disputes_incoming_webhook_flow(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                source_verified,
-                connector,
-                &request_details,
-                event_type,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for disputes failed")?,
+                api::WebhookFlow::Refund => Box::pin(refunds_incoming_webhook_flow(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    connector_name.as_str(),
+                    source_verified,
+                    event_type,
+                ))
+                .await
+                .attach_printable("Incoming webhook flow for refunds failed")?,
 
-            api::WebhookFlow::BankTransfer => Box::pin(bank_transfer_webhook_flow::<Ctx>(
-                state.clone(),
-                req_state,
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                source_verified,
-            ))
-            .await
-            .attach_printable("Incoming bank-transfer webhook flow failed")?,
+                api::WebhookFlow::Dispute => Box::pin(disputes_incoming_webhook_flow(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    source_verified,
+                    connector,
+                    &request_details,
+                    event_type,
+                ))
+                .await
+                .attach_printable("Incoming webhook flow for disputes failed")?,
 
-            api::WebhookFlow::ReturnResponse => WebhookResponseTracker::NoEffect,
+                api::WebhookFlow::BankTransfer => Box::pin(bank_transfer_webhook_flow::<Ctx>(
+                    state.clone(),
+                    req_state,
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    source_verified,
+                ))
+                .await
+                .attach_printable("Incoming bank-transfer webhook flow failed")?,
 
-            api::WebhookFlow::Mandate => Box::pin(mandates_incoming_webhook_flow(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                source_verified,
-                event_type,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for mandates failed")?,
+                api::WebhookFlow::ReturnResponse => WebhookResponseTracker::NoEffect,
 
-            api::WebhookFlow::ExternalAuthentication => {
-                Box::pin(external_authentication_incoming_webhook_flow::<Ctx>(
+                api::WebhookFlow::Mandate => Box::pin(mandates_incoming_webhook_flow(
                     state.clone(),
-                    req_state,
                     merchant_account,
+                    business_profile,
                     key_store,
+                    webhook_details,
                     source_verified,
                     event_type,
-                    &request_details,
-                    connector,
-                    object_ref_id,
-                    business_profile,
-                    merchant_connector_account,
                 ))
                 .await
-                .attach_printable("Incoming webhook flow for external authentication failed")?
-            }
+                .attach_printable("Incoming webhook flow for mandates failed")?,
 
-            _ => Err(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
-        }
-    } else {
-        metrics::WEBHOOK_INCOMING_FILTERED_COUNT.add(
-            &metrics::CONTEXT,
-            1,
-            &[metrics::KeyValue::new(
-                MERCHANT_ID,
-                merchant_account.merchant_id.clone(),
-            )],
-        );
-        WebhookResponseTracker::NoEffect
-    };
+                api::WebhookFlow::ExternalAuthentication => {
+                    Box::pin(external_authentication_incoming_webhook_flow::<Ctx>(
+                        state.clone(),
+                        req_state,
+                        merchant_account,
+                        key_store,
+                        source_verified,
+                        event_type,
+                        &request_details,
+                        connector,
+                        object_ref_id,
+                        business_profile,
+                        merchant_connector_account,
+                    ))
+                    .await
+                    .attach_printable("Incoming webhook flow for external authentication failed")?
+                }
+
+                _ => Err(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
+            }
+        } else {
+            metrics::WEBHOOK_INCOMING_FILTERED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::KeyValue::new(
+                    MERCHANT_ID,
+                    merchant_account.merchant_id.clone(),
+                )],
+            );
+            WebhookResponseTracker::NoEffect
+        };
 
     let response = connector
         .get_webhook_api_response(&request_details)
@@ -1860,6 +2052,61 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetr
     Ok((response, webhook_effect, serialized_request))
 }
 
+/// Verifies the source of the incoming webhook with the secrets of the merchant connector account
+async fn verify_webhook_source(
+    state: &AppState,
+    connector: &(dyn api::Connector + Sync),
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_account: domain::MerchantConnectorAccount,
+    connector_name: &str,
+    connector_enum: &api_models::enums::Connector,
+    request_details: &api::IncomingWebhookRequestDetails<'_>,
+) -> errors::RouterResult<bool> {
+    if state
+        .conf
+        .webhook_source_verification_call
+        .connectors_with_webhook_source_verification_call
+        .contains(connector_enum)
+    {
+        connector
+            .verify_webhook_source_verification_call(
+                state,
+                merchant_account,
+                merchant_connector_account,
+                connector_name,
+                request_details,
+            )
+            .await
+            .or_else(|error| match error.current_context() {
+                errors::ConnectorError::WebhookSourceVerificationFailed => {
+                    logger::error!(?error, "Source Verification Failed");
+                    Ok(false)
+                }
+                _ => Err(error),
+            })
+            .switch()
+            .attach_printable("There was an issue in incoming webhook source verification")
+    } else {
+        connector
+            .verify_webhook_source(
+                request_details,
+                merchant_account,
+                merchant_connector_account,
+                connector_name,
+            )
+            .await
+            .or_else(|error| match error.current_context() {
+                errors::ConnectorError::WebhookSourceVerificationFailed => {
+                    logger::error!(?error, "Source Verification Failed");
+                    Ok(false)
+                }
+                _ => Err(error),
+            })
+            .switch()
+            .attach_printable("There was an issue in incoming webhook source verification")
+    }
+}
+
 #[inline]
 pub async fn get_payment_id(
     db: &dyn StorageInterface,
@@ -2041,6 +2288,95 @@ pub async fn add_outgoing_webhook_retry_task_to_process_tracker(
     }
 }
 
+/// The custom headers are attached when the webhook is delivered, so that they are not stored
+/// along with the request of the event
+async fn get_outgoing_webhook_custom_http_headers(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+) -> CustomResult<Vec<(String, services::request::Maskable<String>)>, errors::WebhooksFlowError> {
+    let custom_http_headers = domain_types::decrypt::<serde_json::Value, masking::WithType>(
+        business_profile
+            .outgoing_webhook_custom_http_headers
+            .clone(),
+        merchant_key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(errors::WebhooksFlowError::OutgoingWebhookHeadersDecryptionFailed)?
+    .map(|headers| {
+        headers
+            .into_inner()
+            .expose()
+            .parse_value::<HashMap<String, Secret<String>>>("OutgoingWebhookCustomHttpHeaders")
+    })
+    .transpose()
+    .change_context(errors::WebhooksFlowError::OutgoingWebhookHeadersDecryptionFailed)?
+    .unwrap_or_default();
+
+    Ok(custom_http_headers
+        .into_iter()
+        .map(|(name, value)| (name, value.into_masked()))
+        .collect())
+}
+
+/// Webhooks are posted for every event type, unless the merchant has listed the event types they
+/// are interested in
+fn is_event_type_enabled_for_business_profile(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    event_type: enums::EventType,
+) -> bool {
+    get_webhook_details_from_business_profile(business_profile)
+        .and_then(|webhook_details| webhook_details.enabled_events)
+        .map_or(true, |enabled_events| enabled_events.contains(&event_type))
+}
+
+/// Webhook endpoints receive every event type, unless the merchant has listed the event types the
+/// endpoint is interested in
+fn is_event_type_enabled_for_webhook_endpoint(
+    webhook_endpoint: &domain::WebhookEndpoint,
+    event_type: enums::EventType,
+) -> bool {
+    webhook_endpoint
+        .enabled_events
+        .clone()
+        .and_then(|enabled_events| {
+            enabled_events
+                .parse_value::<Vec<enums::EventType>>("EnabledEvents")
+                .map_err(|error| logger::error!(?error, "Failed to parse enabled events"))
+                .ok()
+        })
+        .map_or(true, |enabled_events| enabled_events.contains(&event_type))
+}
+
+/// Merchants that have not pinned a version, or that have pinned an unknown version, receive
+/// version 1 of the payload
+fn get_outgoing_webhook_version(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+) -> webhooks::OutgoingWebhookVersion {
+    get_webhook_details_from_business_profile(business_profile)
+        .and_then(|webhook_details| webhook_details.webhook_version)
+        .map(|version| {
+            webhooks::OutgoingWebhookVersion::from_pinned_version(&version).unwrap_or_else(|| {
+                logger::warn!(%version, "Unknown outgoing webhook version, using version 1");
+                webhooks::OutgoingWebhookVersion::default()
+            })
+        })
+        .unwrap_or_default()
+}
+
+fn get_webhook_details_from_business_profile(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+) -> Option<api::WebhookDetails> {
+    business_profile
+        .webhook_details
+        .clone()
+        .and_then(|webhook_details| {
+            webhook_details
+                .parse_value::<api::WebhookDetails>("WebhookDetails")
+                .map_err(|error| logger::error!(?error, "Failed to parse webhook details"))
+                .ok()
+        })
+}
+
 fn get_webhook_url_from_business_profile(
     business_profile: &diesel_models::business_profile::BusinessProfile,
 ) -> CustomResult<String, errors::WebhooksFlowError> {
@@ -2062,10 +2398,122 @@ fn get_webhook_url_from_business_profile(
         .map(ExposeInterface::expose)
 }
 
+/// Posts a signed test event to the webhook endpoint, and reports whether the endpoint responded
+/// with a success status code, along with the time taken for the endpoint to respond. The test
+/// event is not stored, and is not retried.
+pub(crate) async fn send_webhook_endpoint_test_event(
+    state: &AppState,
+    merchant_id: &str,
+    endpoint_id: &str,
+    url: &Secret<String>,
+    secret: &Secret<String>,
+) -> CustomResult<api_models::admin::WebhookEndpointTestResponse, errors::WebhooksFlowError> {
+    let test_event = webhooks::WebhookEndpointTestEvent {
+        merchant_id: merchant_id.to_owned(),
+        event_id: utils::generate_event_id(),
+        event_type: "webhook_endpoint_test".to_string(),
+        endpoint_id: endpoint_id.to_owned(),
+        timestamp: common_utils::date_time::now(),
+    };
+
+    let signed_payload =
+        types::get_hmac_sha512_signed_payload(&test_event, Some(secret.peek().as_bytes()))?;
+
+    let mut headers: Vec<(String, services::request::Maskable<String>)> = vec![(
+        reqwest::header::CONTENT_TYPE.to_string(),
+        mime::APPLICATION_JSON.essence_str().into(),
+    )];
+    if let Some(signature) = signed_payload.signature {
+        headers.push((
+            crate::headers::X_WEBHOOK_SIGNATURE.to_string(),
+            signature.into(),
+        ));
+    }
+    headers.extend(types::get_timestamped_signature_headers_for_keys(
+        vec![secret.peek().as_str()],
+        signed_payload.payload.peek(),
+    )?);
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(url.peek())
+        .attach_default_headers()
+        .headers(headers)
+        .set_body(RequestContent::RawBytes(
+            signed_payload.payload.expose().into_bytes(),
+        ))
+        .build();
+
+    let start_time = Instant::now();
+    let response = state
+        .api_client
+        .send_request(state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS), false)
+        .await;
+    let latency_ms = u64::try_from(start_time.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+    Ok(match response {
+        Ok(response) => api_models::admin::WebhookEndpointTestResponse {
+            endpoint_id: endpoint_id.to_owned(),
+            is_successful: response.status().is_success(),
+            status_code: Some(response.status().as_u16()),
+            latency_ms,
+            error_message: None,
+        },
+        Err(error) => {
+            logger::info!(?error, "Failed to send test event to webhook endpoint");
+            // Not including detailed error message in response information since it contains too
+            // much of diagnostic information to be exposed to the merchant.
+            api_models::admin::WebhookEndpointTestResponse {
+                endpoint_id: endpoint_id.to_owned(),
+                is_successful: false,
+                status_code: None,
+                latency_ms,
+                error_message: Some("Unable to send request to merchant server".to_string()),
+            }
+        }
+    })
+}
+
+/// Events created for a webhook endpoint are delivered to the URL of that endpoint, and events
+/// created for the business profile are delivered to the webhook URL of the business profile
+async fn get_webhook_url_and_endpoint(
+    state: &AppState,
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    event: &domain::Event,
+) -> CustomResult<(String, Option<domain::WebhookEndpoint>), errors::WebhooksFlowError> {
+    match event.webhook_endpoint_id.as_deref() {
+        Some(webhook_endpoint_id) => {
+            let webhook_endpoint = state
+                .store
+                .find_webhook_endpoint_by_merchant_id_endpoint_id(
+                    &business_profile.merchant_id,
+                    webhook_endpoint_id,
+                    merchant_key_store,
+                )
+                .await
+                .change_context(errors::WebhooksFlowError::WebhookEndpointNotFound)?;
+
+            if !webhook_endpoint.is_active {
+                return Err(report!(errors::WebhooksFlowError::WebhookEndpointNotFound))
+                    .attach_printable("Webhook endpoint has been disabled");
+            }
+
+            Ok((
+                webhook_endpoint.url.clone().expose(),
+                Some(webhook_endpoint),
+            ))
+        }
+        None => get_webhook_url_from_business_profile(business_profile)
+            .map(|webhook_url| (webhook_url, None)),
+    }
+}
+
 pub(crate) fn get_outgoing_webhook_request(
     merchant_account: &domain::MerchantAccount,
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    webhook_endpoint: Option<&domain::WebhookEndpoint>,
     outgoing_webhook: api::OutgoingWebhook,
-    payment_response_hash_key: Option<&str>,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
@@ -2095,6 +2543,11 @@ pub(crate) fn get_outgoing_webhook_request(
         })
     }
 
+    let payment_response_hash_key = match webhook_endpoint {
+        Some(webhook_endpoint) => Some(webhook_endpoint.secret.peek().as_str()),
+        None => business_profile.payment_response_hash_key.as_deref(),
+    };
+
     match merchant_account.get_compatible_connector() {
         #[cfg(feature = "stripe")]
         Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
@@ -2102,9 +2555,17 @@ pub(crate) fn get_outgoing_webhook_request(
         >(
             outgoing_webhook, payment_response_hash_key
         ),
-        _ => get_outgoing_webhook_request_inner::<api_models::webhooks::OutgoingWebhook>(
-            outgoing_webhook,
-            payment_response_hash_key,
-        ),
+        _ => match get_outgoing_webhook_version(business_profile) {
+            webhooks::OutgoingWebhookVersion::V1 => get_outgoing_webhook_request_inner::<
+                webhooks::OutgoingWebhook,
+            >(
+                outgoing_webhook, payment_response_hash_key
+            ),
+            webhooks::OutgoingWebhookVersion::V2 => get_outgoing_webhook_request_inner::<
+                webhooks::OutgoingWebhookV2,
+            >(
+                outgoing_webhook, payment_response_hash_key
+            ),
+        },
     }
 }
@@ -1,10 +1,15 @@
 use api_models::webhooks;
 use common_utils::{crypto::SignMessage, ext_traits::Encode};
 use error_stack::ResultExt;
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 use serde::Serialize;
 
-use crate::{core::errors, headers, services::request::Maskable, types::storage::enums};
+use crate::{
+    core::errors,
+    headers,
+    services::request::Maskable,
+    types::{domain, storage::enums},
+};
 
 pub struct OutgoingWebhookPayloadWithSignature {
     pub payload: Secret<String>,
@@ -22,33 +27,53 @@ pub trait OutgoingWebhookType:
     fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String);
 }
 
+pub(crate) fn get_hmac_sha512_signed_payload(
+    webhook: &impl Serialize,
+    payment_response_hash_key: Option<impl AsRef<[u8]>>,
+) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
+    let webhook_signature_payload = webhook
+        .encode_to_string_of_json()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+        .attach_printable("failed encoding outgoing webhook payload")?;
+
+    let signature = payment_response_hash_key
+        .map(|key| {
+            common_utils::crypto::HmacSha512::sign_message(
+                &common_utils::crypto::HmacSha512,
+                key.as_ref(),
+                webhook_signature_payload.as_bytes(),
+            )
+        })
+        .transpose()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the message")?
+        .map(hex::encode);
+
+    Ok(OutgoingWebhookPayloadWithSignature {
+        payload: webhook_signature_payload.into(),
+        signature,
+    })
+}
+
 impl OutgoingWebhookType for webhooks::OutgoingWebhook {
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
-        let webhook_signature_payload = self
-            .encode_to_string_of_json()
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
-            .attach_printable("failed encoding outgoing webhook payload")?;
-
-        let signature = payment_response_hash_key
-            .map(|key| {
-                common_utils::crypto::HmacSha512::sign_message(
-                    &common_utils::crypto::HmacSha512,
-                    key.as_ref(),
-                    webhook_signature_payload.as_bytes(),
-                )
-            })
-            .transpose()
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
-            .attach_printable("Failed to sign the message")?
-            .map(hex::encode);
-
-        Ok(OutgoingWebhookPayloadWithSignature {
-            payload: webhook_signature_payload.into(),
-            signature,
-        })
+        get_hmac_sha512_signed_payload(self, payment_response_hash_key)
+    }
+
+    fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
+        header.push((headers::X_WEBHOOK_SIGNATURE.to_string(), signature.into()))
+    }
+}
+
+impl OutgoingWebhookType for webhooks::OutgoingWebhookV2 {
+    fn get_outgoing_webhooks_signature(
+        &self,
+        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+    ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
+        get_hmac_sha512_signed_payload(self, payment_response_hash_key)
     }
 
     fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
@@ -56,6 +81,80 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
     }
 }
 
+/// Signs the outgoing webhook payload along with the time of delivery, so that merchants can
+/// reject replayed webhooks. The signature is computed when the webhook is delivered (and not when
+/// the event is created), so that retried deliveries carry a fresh timestamp.
+///
+/// For a while after the secret of the business profile is rotated, the payload is signed with
+/// both the current and the previous secret, and the signatures are comma separated. Webhooks
+/// delivered to a webhook endpoint are signed with the secret of that endpoint alone.
+pub(crate) fn get_timestamped_signature_headers(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    webhook_endpoint: Option<&domain::WebhookEndpoint>,
+    payload: &str,
+) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::WebhooksFlowError> {
+    let now = common_utils::date_time::now();
+    let keys = match webhook_endpoint {
+        Some(webhook_endpoint) => vec![webhook_endpoint.secret.peek().as_str()],
+        None => {
+            let previous_key = business_profile
+                .previous_payment_response_hash_key_expires_at
+                .filter(|expires_at| *expires_at > now)
+                .and(
+                    business_profile
+                        .previous_payment_response_hash_key
+                        .as_deref(),
+                );
+            business_profile
+                .payment_response_hash_key
+                .as_deref()
+                .into_iter()
+                .chain(previous_key)
+                .collect::<Vec<_>>()
+        }
+    };
+
+    get_timestamped_signature_headers_for_keys(keys, payload)
+}
+
+/// Signs the payload along with the current time using each of the keys, see
+/// [`get_timestamped_signature_headers`]
+pub(crate) fn get_timestamped_signature_headers_for_keys(
+    keys: Vec<&str>,
+    payload: &str,
+) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::WebhooksFlowError> {
+    if keys.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let timestamp = common_utils::date_time::now().assume_utc().unix_timestamp();
+    let signature_payload = format!("{timestamp}.{payload}");
+    let signatures = keys
+        .into_iter()
+        .map(|key| {
+            common_utils::crypto::HmacSha512::sign_message(
+                &common_utils::crypto::HmacSha512,
+                key.as_bytes(),
+                signature_payload.as_bytes(),
+            )
+            .map(hex::encode)
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the message")?;
+
+    Ok(vec![
+        (
+            headers::X_WEBHOOK_TIMESTAMP.to_string(),
+            timestamp.to_string().into(),
+        ),
+        (
+            headers::X_WEBHOOK_SIGNATURE_TIMESTAMPED.to_string(),
+            signatures.join(",").into(),
+        ),
+    ])
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) merchant_id: String,
@@ -11,6 +11,8 @@ use crate::{
 };
 
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT: i64 = 100;
+const DEAD_LETTERED_EVENTS_LIST_MAX_LIMIT: i64 = 100;
+const DEAD_LETTERED_EVENTS_REQUEUE_MAX_COUNT: usize = 100;
 
 #[derive(Debug)]
 enum MerchantAccountOrBusinessProfile {
@@ -212,6 +214,10 @@ pub async fn retry_delivery_attempt(
         request: event_to_retry.request,
         response: None,
         delivery_attempt: Some(delivery_attempt),
+        dead_lettered_at: None,
+        webhook_endpoint_id: event_to_retry.webhook_endpoint_id,
+        sequence_number: event_to_retry.sequence_number,
+        triggering_attempt_id: event_to_retry.triggering_attempt_id,
     };
 
     let event = store
@@ -253,6 +259,164 @@ pub async fn retry_delivery_attempt(
     ))
 }
 
+#[instrument(skip(state))]
+pub async fn list_dead_lettered_events(
+    state: AppState,
+    merchant_id_or_profile_id: String,
+    constraints: api::webhook_events::DeadLetteredEventListConstraints,
+) -> RouterResponse<Vec<api::webhook_events::EventListItemResponse>> {
+    let limit = match constraints.limit.map(i64::from) {
+        Some(limit) if limit > DEAD_LETTERED_EVENTS_LIST_MAX_LIMIT => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`limit` must be a number less than {DEAD_LETTERED_EVENTS_LIST_MAX_LIMIT}"
+                ),
+            })
+        }
+        Some(limit) => Ok(limit),
+        None => Ok(DEAD_LETTERED_EVENTS_LIST_MAX_LIMIT),
+    }?;
+    let offset = constraints
+        .offset
+        .map(i64::from)
+        .filter(|offset| *offset > 0);
+
+    let store = state.store.as_ref();
+
+    let (account, key_store) =
+        determine_identifier_and_get_key_store(state.clone(), merchant_id_or_profile_id).await?;
+
+    let events = match account {
+        MerchantAccountOrBusinessProfile::MerchantAccount(merchant_account) => {
+            store
+                .list_dead_lettered_events_by_merchant_id(
+                    &merchant_account.merchant_id,
+                    Some(limit),
+                    offset,
+                    &key_store,
+                )
+                .await
+        }
+        MerchantAccountOrBusinessProfile::BusinessProfile(business_profile) => {
+            store
+                .list_dead_lettered_events_by_profile_id(
+                    &business_profile.profile_id,
+                    Some(limit),
+                    offset,
+                    &key_store,
+                )
+                .await
+        }
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to list dead-lettered events")?;
+
+    Ok(ApplicationResponse::Json(
+        events
+            .into_iter()
+            .map(api::webhook_events::EventListItemResponse::try_from)
+            .collect::<Result<Vec<_>, _>>()?,
+    ))
+}
+
+/// Requeues dead-lettered events for delivery, by resetting the delivery retry tasks of the events
+/// so that the configured retries are attempted again. All the specified events are validated
+/// before any of them is requeued.
+#[instrument(skip(state))]
+pub async fn requeue_dead_lettered_events(
+    state: AppState,
+    merchant_id_or_profile_id: String,
+    request: api::webhook_events::DeadLetteredEventsRequeueRequest,
+) -> RouterResponse<api::webhook_events::DeadLetteredEventsRequeueResponse> {
+    if request.event_ids.is_empty()
+        || request.event_ids.len() > DEAD_LETTERED_EVENTS_REQUEUE_MAX_COUNT
+    {
+        return Err(error_stack::report!(
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`event_ids` must contain between 1 and {DEAD_LETTERED_EVENTS_REQUEUE_MAX_COUNT} events"
+                ),
+            }
+        ));
+    }
+
+    let store = state.store.as_ref();
+
+    let (account, key_store) =
+        determine_identifier_and_get_key_store(state.clone(), merchant_id_or_profile_id).await?;
+
+    let mut processes = Vec::with_capacity(request.event_ids.len());
+    for event_id in &request.event_ids {
+        let event = store
+            .find_event_by_merchant_id_event_id(&key_store.merchant_id, event_id, &key_store)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+        if let MerchantAccountOrBusinessProfile::BusinessProfile(business_profile) = &account {
+            if event.business_profile_id.as_ref() != Some(&business_profile.profile_id) {
+                return Err(error_stack::report!(
+                    errors::ApiErrorResponse::EventNotFound
+                ));
+            }
+        }
+
+        if event.dead_lettered_at.is_none() {
+            return Err(error_stack::report!(
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("Event `{event_id}` is not dead-lettered"),
+                }
+            ));
+        }
+
+        let process_tracker_id = scheduler::utils::get_process_tracker_id(
+            storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+            "OUTGOING_WEBHOOK_RETRY",
+            &event.event_id,
+            &key_store.merchant_id,
+        );
+        let process = store
+            .find_process_by_id(&process_tracker_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find webhook delivery retry task")?
+            .get_required_value("process_tracker")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Webhook delivery retry task not found for event")?;
+
+        processes.push((event.event_id, process));
+    }
+
+    let now = common_utils::date_time::now();
+    let mut requeued_event_ids = Vec::with_capacity(processes.len());
+    for (event_id, process) in processes {
+        store
+            .as_scheduler()
+            .reset_process(process, now)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reset webhook delivery retry task")?;
+
+        store
+            .update_event_by_merchant_id_event_id(
+                &key_store.merchant_id,
+                &event_id,
+                domain::EventUpdate::DeadLetterUpdate {
+                    dead_lettered_at: None,
+                },
+                &key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to remove event from dead-letter queue")?;
+
+        requeued_event_ids.push(event_id);
+    }
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::DeadLetteredEventsRequeueResponse { requeued_event_ids },
+    ))
+}
+
 async fn determine_identifier_and_get_key_store(
     state: AppState,
     merchant_id_or_profile_id: String,
@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
@@ -10,10 +11,12 @@ pub mod cache;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_payload;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
 pub mod ephemeral_key;
+pub mod event_outbox;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
@@ -26,14 +29,18 @@ pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod organization;
+pub mod payment_archive;
+pub mod payment_export;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_status_sync;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
 pub mod user;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 use diesel_models::{
     fraud_check::{FraudCheck, FraudCheckNew, FraudCheckUpdate},
@@ -70,6 +77,13 @@ pub enum StorageImpl {
     Mock,
 }
 
+/// Wraps the store built from the application configuration before the application uses it.
+///
+/// This allows a deployment to serve some of the storage interfaces from an alternative backend,
+/// while delegating the remaining ones to the wrapped store, the same way [`KafkaStore`] does.
+pub type StoreLayer =
+    Box<dyn FnOnce(Box<dyn StorageInterface>) -> Box<dyn StorageInterface> + Send>;
+
 #[async_trait::async_trait]
 pub trait StorageInterface:
     Send
@@ -79,6 +93,7 @@ pub trait StorageInterface:
     + api_keys::ApiKeyInterface
     + blocklist_lookup::BlocklistLookupInterface
     + configs::ConfigInterface
+    + connector_payload::ConnectorPayloadInterface
     + capture::CaptureInterface
     + customers::CustomerInterface
     + dashboard_metadata::DashboardMetadataInterface
@@ -119,6 +134,12 @@ pub trait StorageInterface:
     + health_check::HealthCheckDbInterface
     + role::RoleInterface
     + authentication::AuthenticationInterface
+    + webhook_endpoint::WebhookEndpointInterface
+    + audit_log::AuditLogInterface
+    + payment_archive::PaymentArchiveInterface
+    + event_outbox::EventOutboxInterface
+    + payment_export::PaymentExportInterface
+    + payment_status_sync::PaymentStatusSyncInterface
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
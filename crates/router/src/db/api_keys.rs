@@ -26,6 +26,16 @@ pub trait ApiKeyInterface {
         api_key: storage::ApiKeyUpdate,
     ) -> CustomResult<storage::ApiKey, errors::StorageError>;
 
+    /// Records the time at which the API key was last used. Unlike other updates, this does not
+    /// invalidate the cached API key, since the `last_used` timestamp is not used for
+    /// authentication.
+    async fn update_api_key_last_used(
+        &self,
+        merchant_id: &str,
+        key_id: &str,
+        last_used: time::PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError>;
+
     async fn revoke_api_key(
         &self,
         merchant_id: &str,
@@ -113,6 +123,25 @@ impl ApiKeyInterface for Store {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn update_api_key_last_used(
+        &self,
+        merchant_id: &str,
+        key_id: &str,
+        last_used: time::PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ApiKey::update_by_merchant_id_key_id(
+            &conn,
+            merchant_id.to_owned(),
+            key_id.to_owned(),
+            storage::ApiKeyUpdate::LastUsedUpdate { last_used },
+        )
+        .await
+        .map(|_| ())
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn revoke_api_key(
         &self,
@@ -233,6 +262,9 @@ impl ApiKeyInterface for MockDb {
             created_at: api_key.created_at,
             expires_at: api_key.expires_at,
             last_used: api_key.last_used,
+            test_mode: api_key.test_mode,
+            ip_allowlist: api_key.ip_allowlist,
+            role_id: api_key.role_id,
         };
         locked_api_keys.push(stored_key.clone());
 
@@ -258,6 +290,7 @@ impl ApiKeyInterface for MockDb {
                 description,
                 expires_at,
                 last_used,
+                ip_allowlist,
             } => {
                 if let Some(name) = name {
                     key_to_update.name = name;
@@ -272,6 +305,9 @@ impl ApiKeyInterface for MockDb {
                 if last_used.is_some() {
                     key_to_update.last_used = last_used
                 }
+                if ip_allowlist.is_some() {
+                    key_to_update.ip_allowlist = ip_allowlist;
+                }
             }
             storage::ApiKeyUpdate::LastUsedUpdate { last_used } => {
                 key_to_update.last_used = Some(last_used);
@@ -281,6 +317,22 @@ impl ApiKeyInterface for MockDb {
         Ok(key_to_update.clone())
     }
 
+    async fn update_api_key_last_used(
+        &self,
+        merchant_id: &str,
+        key_id: &str,
+        last_used: time::PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError> {
+        let mut locked_api_keys = self.api_keys.lock().await;
+        let key_to_update = locked_api_keys
+            .iter_mut()
+            .find(|k| k.merchant_id == merchant_id && k.key_id == key_id)
+            .ok_or(errors::StorageError::MockDbError)?;
+        key_to_update.last_used = Some(last_used);
+
+        Ok(())
+    }
+
     async fn revoke_api_key(
         &self,
         merchant_id: &str,
@@ -405,6 +457,9 @@ mod tests {
                 created_at: datetime!(2023-02-01 0:00),
                 expires_at: Some(datetime!(2023-03-01 0:00)),
                 last_used: None,
+                test_mode: false,
+                ip_allowlist: None,
+                role_id: None,
             })
             .await
             .unwrap();
@@ -420,6 +475,9 @@ mod tests {
                 created_at: datetime!(2023-03-01 0:00),
                 expires_at: None,
                 last_used: None,
+                test_mode: false,
+                ip_allowlist: None,
+                role_id: None,
             })
             .await
             .unwrap();
@@ -497,6 +555,9 @@ mod tests {
             created_at: datetime!(2023-06-01 0:00),
             expires_at: None,
             last_used: None,
+            test_mode: false,
+            ip_allowlist: None,
+            role_id: None,
         };
 
         let api = db.insert_api_key(api).await.unwrap();
@@ -0,0 +1,179 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait AuditLogInterface {
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLogNew,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        entity_type: Option<enums::AuditLogEntityType>,
+        entity_id: Option<String>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLogNew,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        audit_log
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        entity_type: Option<enums::AuditLogEntityType>,
+        entity_id: Option<String>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        let conn = connection::pg_connection_read_replica(self).await?;
+        storage::AuditLog::list_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            entity_type,
+            entity_id,
+            created_after,
+            created_before,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for MockDb {
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLogNew,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError> {
+        let mut audit_logs = self.audit_logs.lock().await;
+        if audit_logs
+            .iter()
+            .any(|log| log.audit_log_id == audit_log.audit_log_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "audit_log_id",
+                key: Some(audit_log.audit_log_id.clone()),
+            })?
+        }
+
+        let audit_log = storage::AuditLog {
+            audit_log_id: audit_log.audit_log_id,
+            merchant_id: audit_log.merchant_id,
+            entity_type: audit_log.entity_type,
+            entity_id: audit_log.entity_id,
+            action: audit_log.action,
+            actor_type: audit_log.actor_type,
+            actor_id: audit_log.actor_id,
+            old_value: audit_log.old_value,
+            new_value: audit_log.new_value,
+            created_at: audit_log.created_at,
+        };
+        audit_logs.push(audit_log.clone());
+        Ok(audit_log)
+    }
+
+    async fn list_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        entity_type: Option<enums::AuditLogEntityType>,
+        entity_id: Option<String>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        let audit_logs = self.audit_logs.lock().await;
+        let mut audit_logs = audit_logs
+            .iter()
+            .filter(|log| {
+                log.merchant_id == merchant_id
+                    && entity_type.map_or(true, |entity_type| log.entity_type == entity_type)
+                    && entity_id
+                        .as_ref()
+                        .map_or(true, |entity_id| &log.entity_id == entity_id)
+                    && created_after.map_or(true, |created_after| log.created_at >= created_after)
+                    && created_before
+                        .map_or(true, |created_before| log.created_at <= created_before)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        audit_logs.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        let offset = offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(audit_logs.len());
+
+        Ok(audit_logs.into_iter().skip(offset).take(limit).collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLogNew,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError> {
+        self.diesel_store.insert_audit_log(audit_log).await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        entity_type: Option<enums::AuditLogEntityType>,
+        entity_id: Option<String>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        self.diesel_store
+            .list_audit_logs_by_merchant_id_constraints(
+                merchant_id,
+                entity_type,
+                entity_id,
+                created_after,
+                created_before,
+                limit,
+                offset,
+            )
+            .await
+    }
+}
@@ -1,5 +1,7 @@
 use error_stack::report;
 use router_env::{instrument, tracing};
+#[cfg(feature = "accounts_cache")]
+use storage_impl::redis::cache::{CacheKind, ACCOUNTS_CACHE};
 
 use super::Store;
 use crate::{
@@ -64,10 +66,28 @@ impl BusinessProfileInterface for Store {
         &self,
         profile_id: &str,
     ) -> CustomResult<business_profile::BusinessProfile, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::business_profile::BusinessProfile::find_by_profile_id(&conn, profile_id)
+        let find_call = || async {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::business_profile::BusinessProfile::find_by_profile_id(&conn, profile_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            find_call().await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            super::cache::get_or_populate_in_memory(
+                self,
+                &format!("business_profile_{}", profile_id),
+                find_call,
+                &ACCOUNTS_CACHE,
+            )
             .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 
     #[instrument(skip_all)]
@@ -92,14 +112,34 @@ impl BusinessProfileInterface for Store {
         current_state: business_profile::BusinessProfile,
         business_profile_update: business_profile::BusinessProfileUpdate,
     ) -> CustomResult<business_profile::BusinessProfile, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::business_profile::BusinessProfile::update_by_profile_id(
-            current_state,
-            &conn,
-            business_profile_update,
-        )
-        .await
-        .map_err(|error| report!(errors::StorageError::from(error)))
+        #[cfg(feature = "accounts_cache")]
+        let business_profile_cache_key = format!("business_profile_{}", current_state.profile_id);
+
+        let update_call = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::business_profile::BusinessProfile::update_by_profile_id(
+                current_state,
+                &conn,
+                business_profile_update,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            update_call().await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            super::cache::publish_and_redact(
+                self,
+                CacheKind::Accounts(business_profile_cache_key.into()),
+                update_call,
+            )
+            .await
+        }
     }
 
     #[instrument(skip_all)]
@@ -108,14 +148,31 @@ impl BusinessProfileInterface for Store {
         profile_id: &str,
         merchant_id: &str,
     ) -> CustomResult<bool, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::business_profile::BusinessProfile::delete_by_profile_id_merchant_id(
-            &conn,
-            profile_id,
-            merchant_id,
-        )
-        .await
-        .map_err(|error| report!(errors::StorageError::from(error)))
+        let delete_call = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::business_profile::BusinessProfile::delete_by_profile_id_merchant_id(
+                &conn,
+                profile_id,
+                merchant_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            delete_call().await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            super::cache::publish_and_redact(
+                self,
+                CacheKind::Accounts(format!("business_profile_{}", profile_id).into()),
+                delete_call,
+            )
+            .await
+        }
     }
 
     #[instrument(skip_all)]
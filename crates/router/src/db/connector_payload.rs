@@ -0,0 +1,182 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ConnectorPayloadInterface {
+    async fn insert_connector_payload(
+        &self,
+        connector_payload: storage::ConnectorPayloadNew,
+    ) -> CustomResult<storage::ConnectorPayload, errors::StorageError>;
+
+    /// Lists the payloads stored for the payment attempt, in the order they were stored
+    async fn find_connector_payloads_by_merchant_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorPayload>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ConnectorPayloadInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_connector_payload(
+        &self,
+        connector_payload: storage::ConnectorPayloadNew,
+    ) -> CustomResult<storage::ConnectorPayload, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        connector_payload
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_connector_payloads_by_merchant_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorPayload>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ConnectorPayload::find_by_merchant_id_attempt_id(&conn, merchant_id, attempt_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorPayloadInterface for MockDb {
+    async fn insert_connector_payload(
+        &self,
+        connector_payload: storage::ConnectorPayloadNew,
+    ) -> CustomResult<storage::ConnectorPayload, errors::StorageError> {
+        let mut connector_payloads = self.connector_payloads.lock().await;
+        let connector_payload = storage::ConnectorPayload {
+            id: i32::try_from(connector_payloads.len())
+                .map_err(|_| errors::StorageError::MockDbError)?
+                + 1,
+            merchant_id: connector_payload.merchant_id,
+            payment_id: connector_payload.payment_id,
+            attempt_id: connector_payload.attempt_id,
+            connector: connector_payload.connector,
+            flow: connector_payload.flow,
+            url: connector_payload.url,
+            method: connector_payload.method,
+            status_code: connector_payload.status_code,
+            request: connector_payload.request,
+            response: connector_payload.response,
+            error: connector_payload.error,
+            created_at: connector_payload.created_at,
+        };
+        connector_payloads.push(connector_payload.clone());
+        Ok(connector_payload)
+    }
+
+    async fn find_connector_payloads_by_merchant_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorPayload>, errors::StorageError> {
+        Ok(self
+            .connector_payloads
+            .lock()
+            .await
+            .iter()
+            .filter(|connector_payload| {
+                connector_payload.merchant_id == merchant_id
+                    && connector_payload.attempt_id == attempt_id
+            })
+            .cloned()
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorPayloadInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_connector_payload(
+        &self,
+        connector_payload: storage::ConnectorPayloadNew,
+    ) -> CustomResult<storage::ConnectorPayload, errors::StorageError> {
+        self.diesel_store
+            .insert_connector_payload(connector_payload)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_connector_payloads_by_merchant_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorPayload>, errors::StorageError> {
+        self.diesel_store
+            .find_connector_payloads_by_merchant_id_attempt_id(merchant_id, attempt_id)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_connector_payload(attempt_id: &str, flow: &str) -> storage::ConnectorPayloadNew {
+        storage::ConnectorPayloadNew {
+            merchant_id: "merchant_1".to_string(),
+            payment_id: "pay_1".to_string(),
+            attempt_id: attempt_id.to_string(),
+            connector: "stripe".to_string(),
+            flow: flow.to_string(),
+            url: "https://api.stripe.com/v1/payment_intents".to_string(),
+            method: "POST".to_string(),
+            status_code: 200,
+            request: r#"{"amount":"*** alloc::string::String ***"}"#.to_string(),
+            response: Some(r#"{"status":"succeeded"}"#.to_string()),
+            error: None,
+            created_at: common_utils::date_time::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mockdb_connector_payload_interface() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+
+        for (attempt_id, flow) in [
+            ("pay_1_1", "Authorize"),
+            ("pay_1_2", "Authorize"),
+            ("pay_1_1", "PSync"),
+        ] {
+            mockdb
+                .insert_connector_payload(get_connector_payload(attempt_id, flow))
+                .await
+                .unwrap();
+        }
+
+        let connector_payloads = mockdb
+            .find_connector_payloads_by_merchant_id_attempt_id("merchant_1", "pay_1_1")
+            .await
+            .unwrap();
+        assert_eq!(
+            connector_payloads
+                .iter()
+                .map(|connector_payload| connector_payload.flow.as_str())
+                .collect::<Vec<_>>(),
+            ["Authorize", "PSync"]
+        );
+        assert!(mockdb
+            .find_connector_payloads_by_merchant_id_attempt_id("merchant_2", "pay_1_1")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
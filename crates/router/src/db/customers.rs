@@ -59,6 +59,13 @@ where
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Customer>, errors::StorageError>;
 
+    async fn list_customers_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: &storage_types::CustomerListConstraints,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError>;
+
     async fn insert_customer(
         &self,
         customer_data: domain::Customer,
@@ -315,6 +322,36 @@ mod storage {
             Ok(customers)
         }
 
+        #[instrument(skip_all)]
+        async fn list_customers_by_merchant_id_constraints(
+            &self,
+            merchant_id: &str,
+            constraints: &storage_types::CustomerListConstraints,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+            let conn = connection::pg_connection_read_replica(self).await?;
+
+            let encrypted_customers = storage_types::Customer::list_by_merchant_id_constraints(
+                &conn,
+                merchant_id,
+                constraints,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+            let customers = try_join_all(encrypted_customers.into_iter().map(
+                |encrypted_customer| async {
+                    encrypted_customer
+                        .convert(key_store.key.get_inner())
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                },
+            ))
+            .await?;
+
+            Ok(customers)
+        }
+
         #[instrument(skip_all)]
         async fn insert_customer(
             &self,
@@ -547,6 +584,36 @@ mod storage {
             Ok(customers)
         }
 
+        #[instrument(skip_all)]
+        async fn list_customers_by_merchant_id_constraints(
+            &self,
+            merchant_id: &str,
+            constraints: &storage_types::CustomerListConstraints,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+            let conn = connection::pg_connection_read_replica(self).await?;
+
+            let encrypted_customers = storage_types::Customer::list_by_merchant_id_constraints(
+                &conn,
+                merchant_id,
+                constraints,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+            let customers = try_join_all(encrypted_customers.into_iter().map(
+                |encrypted_customer| async {
+                    encrypted_customer
+                        .convert(key_store.key.get_inner())
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                },
+            ))
+            .await?;
+
+            Ok(customers)
+        }
+
         #[instrument(skip_all)]
         async fn insert_customer(
             &self,
@@ -639,29 +706,138 @@ impl CustomerInterface for MockDb {
         Ok(customers)
     }
 
+    async fn list_customers_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: &storage_types::CustomerListConstraints,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        let customers = self.customers.lock().await;
+
+        let offset: usize = constraints
+            .offset
+            .unwrap_or(0)
+            .try_into()
+            .map_err(|_| errors::StorageError::MockDbError)?;
+        let limit: usize = constraints
+            .limit
+            .map(usize::try_from)
+            .transpose()
+            .map_err(|_| errors::StorageError::MockDbError)?
+            .unwrap_or(usize::MAX);
+
+        let cursor_of = |customer_id: &String| {
+            customers
+                .iter()
+                .find(|customer| {
+                    customer.merchant_id == merchant_id && &customer.customer_id == customer_id
+                })
+                .map(|customer| (customer.created_at, customer.customer_id.clone()))
+        };
+        let starting_after = constraints.starting_after.as_ref().map(cursor_of);
+        let ending_before = constraints.ending_before.as_ref().map(cursor_of);
+
+        let mut filtered_customers = customers
+            .iter()
+            .filter(|customer| {
+                let key = (customer.created_at, customer.customer_id.clone());
+                customer.merchant_id == merchant_id
+                    && constraints
+                        .created_after
+                        .map_or(true, |time| customer.created_at >= time)
+                    && constraints
+                        .created_before
+                        .map_or(true, |time| customer.created_at <= time)
+                    && starting_after
+                        .as_ref()
+                        .map_or(true, |cursor| cursor.as_ref().map_or(false, |c| &key < c))
+                    && ending_before
+                        .as_ref()
+                        .map_or(true, |cursor| cursor.as_ref().map_or(false, |c| &key > c))
+            })
+            .collect::<Vec<_>>();
+        filtered_customers
+            .sort_by(|a, b| (b.created_at, &b.customer_id).cmp(&(a.created_at, &a.customer_id)));
+
+        // Like the database, take the page closest to the `ending_before` cursor.
+        let page = if ending_before.is_some() && starting_after.is_none() {
+            let mut page = filtered_customers
+                .into_iter()
+                .rev()
+                .skip(offset)
+                .take(limit)
+                .collect::<Vec<_>>();
+            page.reverse();
+            page
+        } else {
+            filtered_customers
+                .into_iter()
+                .skip(offset)
+                .take(limit)
+                .collect()
+        };
+
+        let customers = try_join_all(page.into_iter().map(|customer| async {
+            customer
+                .to_owned()
+                .convert(key_store.key.get_inner())
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        }))
+        .await?;
+
+        Ok(customers)
+    }
+
     #[instrument(skip_all)]
     async fn update_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: String,
-        _merchant_id: String,
+        customer_id: String,
+        merchant_id: String,
         _customer: domain::Customer,
-        _customer_update: storage_types::CustomerUpdate,
-        _key_store: &domain::MerchantKeyStore,
+        customer_update: storage_types::CustomerUpdate,
+        key_store: &domain::MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut customers = self.customers.lock().await;
+        let customer = customers
+            .iter_mut()
+            .find(|customer| {
+                customer.customer_id == customer_id && customer.merchant_id == merchant_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No customer found for customer_id = {customer_id}"
+            )))?;
+
+        *customer = diesel_models::CustomerUpdateInternal::from(customer_update)
+            .apply_changeset(customer.clone());
+
+        customer
+            .clone()
+            .convert(key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 
     async fn find_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: &str,
-        _merchant_id: &str,
-        _key_store: &domain::MerchantKeyStore,
-        _storage_scheme: MerchantStorageScheme,
+        customer_id: &str,
+        merchant_id: &str,
+        key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let customer = self
+            .find_customer_optional_by_customer_id_merchant_id(
+                customer_id,
+                merchant_id,
+                key_store,
+                storage_scheme,
+            )
+            .await?;
+
+        Ok(customer.ok_or(errors::StorageError::ValueNotFound(format!(
+            "No customer found for customer_id = {customer_id}"
+        )))?)
     }
 
     #[allow(clippy::panic)]
@@ -687,10 +863,136 @@ impl CustomerInterface for MockDb {
 
     async fn delete_customer_by_customer_id_merchant_id(
         &self,
-        _customer_id: &str,
-        _merchant_id: &str,
+        customer_id: &str,
+        merchant_id: &str,
     ) -> CustomResult<bool, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut customers = self.customers.lock().await;
+        let customers_count = customers.len();
+        customers.retain(|customer| {
+            customer.customer_id != customer_id || customer.merchant_id != merchant_id
+        });
+
+        if customers.len() == customers_count {
+            Err(errors::StorageError::ValueNotFound(format!(
+                "No customer found for customer_id = {customer_id}"
+            )))?
+        }
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::{db::MasterKeyInterface, services};
+
+    fn get_customer(customer_id: &str, merchant_id: &str) -> domain::Customer {
+        domain::Customer {
+            id: None,
+            customer_id: customer_id.to_string(),
+            merchant_id: merchant_id.to_string(),
+            name: None,
+            email: None,
+            phone: None,
+            phone_country_code: None,
+            description: Some("First customer".to_string()),
+            created_at: common_utils::date_time::now(),
+            metadata: None,
+            modified_at: common_utils::date_time::now(),
+            connector_customer: None,
+            address_id: None,
+            default_payment_method_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mockdb_customer_interface() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let key_store = domain::MerchantKeyStore {
+            merchant_id: "merchant_1".to_string(),
+            key: domain::types::encrypt(
+                services::generate_aes256_key().unwrap().to_vec().into(),
+                mockdb.get_master_key(),
+            )
+            .await
+            .unwrap(),
+            created_at: datetime!(2023-02-01 0:00),
+        };
+        let storage_scheme = MerchantStorageScheme::PostgresOnly;
+
+        for (customer_id, merchant_id) in [
+            ("cus_1", "merchant_1"),
+            ("cus_2", "merchant_1"),
+            ("cus_1", "merchant_2"),
+        ] {
+            mockdb
+                .insert_customer(
+                    get_customer(customer_id, merchant_id),
+                    &key_store,
+                    storage_scheme,
+                )
+                .await
+                .unwrap();
+        }
+
+        let customer = mockdb
+            .update_customer_by_customer_id_merchant_id(
+                "cus_1".to_string(),
+                "merchant_1".to_string(),
+                get_customer("cus_1", "merchant_1"),
+                storage_types::CustomerUpdate::UpdateDefaultPaymentMethod {
+                    default_payment_method_id: Some(Some("pm_1".to_string())),
+                },
+                &key_store,
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        assert_eq!(customer.default_payment_method_id.as_deref(), Some("pm_1"));
+        assert_eq!(customer.description.as_deref(), Some("First customer"));
+
+        let customer = mockdb
+            .find_customer_by_customer_id_merchant_id(
+                "cus_1",
+                "merchant_2",
+                &key_store,
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        assert!(customer.default_payment_method_id.is_none());
+
+        assert!(mockdb
+            .delete_customer_by_customer_id_merchant_id("cus_1", "merchant_1")
+            .await
+            .unwrap());
+        assert!(mockdb
+            .delete_customer_by_customer_id_merchant_id("cus_1", "merchant_1")
+            .await
+            .is_err());
+        assert!(mockdb
+            .find_customer_optional_by_customer_id_merchant_id(
+                "cus_1",
+                "merchant_1",
+                &key_store,
+                storage_scheme,
+            )
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            mockdb
+                .list_customers_by_merchant_id("merchant_1", &key_store)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
     }
 }
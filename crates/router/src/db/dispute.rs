@@ -97,7 +97,7 @@ impl DisputeInterface for Store {
         merchant_id: &str,
         dispute_constraints: api_models::disputes::DisputeListConstraints,
     ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
+        let conn = connection::pg_connection_read_replica(self).await?;
         storage::Dispute::filter_by_constraints(&conn, merchant_id, dispute_constraints)
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
@@ -0,0 +1,135 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait EventOutboxInterface {
+    /// Finds up to `limit` outbox entries recorded before `created_before` whose ID is greater than
+    /// `after_id`, oldest first.
+    async fn find_event_outbox_entries_created_before(
+        &self,
+        created_before: time::PrimitiveDateTime,
+        after_id: i64,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::EventOutbox>, errors::StorageError>;
+
+    async fn delete_event_outbox_entries_by_ids(
+        &self,
+        ids: Vec<i64>,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn increment_event_outbox_entries_retry_count_by_ids(
+        &self,
+        ids: Vec<i64>,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl EventOutboxInterface for Store {
+    #[instrument(skip_all)]
+    async fn find_event_outbox_entries_created_before(
+        &self,
+        created_before: time::PrimitiveDateTime,
+        after_id: i64,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::EventOutbox>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::EventOutbox::find_created_before(&conn, created_before, after_id, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_event_outbox_entries_by_ids(
+        &self,
+        ids: Vec<i64>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::EventOutbox::delete_by_ids(&conn, ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn increment_event_outbox_entries_retry_count_by_ids(
+        &self,
+        ids: Vec<i64>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::EventOutbox::increment_retry_count_by_ids(&conn, ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl EventOutboxInterface for MockDb {
+    async fn find_event_outbox_entries_created_before(
+        &self,
+        _created_before: time::PrimitiveDateTime,
+        _after_id: i64,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::EventOutbox>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_event_outbox_entries_by_ids(
+        &self,
+        _ids: Vec<i64>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn increment_event_outbox_entries_retry_count_by_ids(
+        &self,
+        _ids: Vec<i64>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl EventOutboxInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn find_event_outbox_entries_created_before(
+        &self,
+        created_before: time::PrimitiveDateTime,
+        after_id: i64,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::EventOutbox>, errors::StorageError> {
+        self.diesel_store
+            .find_event_outbox_entries_created_before(created_before, after_id, limit)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_event_outbox_entries_by_ids(
+        &self,
+        ids: Vec<i64>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_event_outbox_entries_by_ids(ids)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn increment_event_outbox_entries_retry_count_by_ids(
+        &self,
+        ids: Vec<i64>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .increment_event_outbox_entries_retry_count_by_ids(ids)
+            .await
+    }
+}
@@ -82,6 +82,27 @@ where
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
+    async fn list_dead_lettered_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
+
+    async fn count_dead_lettered_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<i64, errors::StorageError>;
+
+    async fn list_dead_lettered_events_by_profile_id(
+        &self,
+        profile_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
+
     async fn update_event_by_merchant_id_event_id(
         &self,
         merchant_id: &str,
@@ -89,6 +110,8 @@ where
         event: domain::EventUpdate,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::Event, errors::StorageError>;
+
+    async fn get_next_event_sequence_number(&self) -> CustomResult<i64, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -168,7 +191,7 @@ impl EventInterface for Store {
         offset: Option<i64>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
+        let conn = connection::pg_connection_read_replica(self).await?;
         storage::Event::list_initial_attempts_by_merchant_id_constraints(
             &conn,
             merchant_id,
@@ -264,7 +287,7 @@ impl EventInterface for Store {
         offset: Option<i64>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
+        let conn = connection::pg_connection_read_replica(self).await?;
         storage::Event::list_initial_attempts_by_profile_id_constraints(
             &conn,
             profile_id,
@@ -316,6 +339,71 @@ impl EventInterface for Store {
             .await
     }
 
+    #[instrument(skip_all)]
+    async fn list_dead_lettered_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read_replica(self).await?;
+        storage::Event::list_dead_lettered_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+            .async_and_then(|events| async {
+                let mut domain_events = Vec::with_capacity(events.len());
+                for event in events.into_iter() {
+                    domain_events.push(
+                        event
+                            .convert(merchant_key_store.key.get_inner())
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)?,
+                    );
+                }
+                Ok(domain_events)
+            })
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn count_dead_lettered_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read_replica(self).await?;
+        storage::Event::count_dead_lettered_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_dead_lettered_events_by_profile_id(
+        &self,
+        profile_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read_replica(self).await?;
+        storage::Event::list_dead_lettered_by_profile_id(&conn, profile_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+            .async_and_then(|events| async {
+                let mut domain_events = Vec::with_capacity(events.len());
+                for event in events.into_iter() {
+                    domain_events.push(
+                        event
+                            .convert(merchant_key_store.key.get_inner())
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)?,
+                    );
+                }
+                Ok(domain_events)
+            })
+            .await
+    }
+
     #[instrument(skip_all)]
     async fn update_event_by_merchant_id_event_id(
         &self,
@@ -332,6 +420,14 @@ impl EventInterface for Store {
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
+
+    #[instrument(skip_all)]
+    async fn get_next_event_sequence_number(&self) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Event::get_next_sequence_number(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -630,6 +726,104 @@ impl EventInterface for MockDb {
         Ok(domain_events)
     }
 
+    async fn list_dead_lettered_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let mut events = locked_events
+            .iter()
+            .filter(|event| {
+                event.merchant_id == Some(merchant_id.to_owned())
+                    && event.dead_lettered_at.is_some()
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        events.sort_by(|a, b| b.dead_lettered_at.cmp(&a.dead_lettered_at));
+
+        let offset = offset
+            .map(usize::try_from)
+            .transpose()
+            .map_err(|_| errors::StorageError::MockDbError)?
+            .unwrap_or(0);
+        let limit = limit
+            .map(usize::try_from)
+            .transpose()
+            .map_err(|_| errors::StorageError::MockDbError)?
+            .unwrap_or(usize::MAX);
+
+        let mut domain_events = Vec::with_capacity(events.len());
+        for event in events.into_iter().skip(offset).take(limit) {
+            let domain_event = event
+                .convert(merchant_key_store.key.get_inner())
+                .await
+                .change_context(errors::StorageError::DecryptionError)?;
+            domain_events.push(domain_event);
+        }
+
+        Ok(domain_events)
+    }
+
+    async fn count_dead_lettered_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let count = locked_events
+            .iter()
+            .filter(|event| {
+                event.merchant_id == Some(merchant_id.to_owned())
+                    && event.dead_lettered_at.is_some()
+            })
+            .count();
+
+        i64::try_from(count).map_err(|_| report!(errors::StorageError::MockDbError))
+    }
+
+    async fn list_dead_lettered_events_by_profile_id(
+        &self,
+        profile_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let mut events = locked_events
+            .iter()
+            .filter(|event| {
+                event.business_profile_id == Some(profile_id.to_owned())
+                    && event.dead_lettered_at.is_some()
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        events.sort_by(|a, b| b.dead_lettered_at.cmp(&a.dead_lettered_at));
+
+        let offset = offset
+            .map(usize::try_from)
+            .transpose()
+            .map_err(|_| errors::StorageError::MockDbError)?
+            .unwrap_or(0);
+        let limit = limit
+            .map(usize::try_from)
+            .transpose()
+            .map_err(|_| errors::StorageError::MockDbError)?
+            .unwrap_or(usize::MAX);
+
+        let mut domain_events = Vec::with_capacity(events.len());
+        for event in events.into_iter().skip(offset).take(limit) {
+            let domain_event = event
+                .convert(merchant_key_store.key.get_inner())
+                .await
+                .change_context(errors::StorageError::DecryptionError)?;
+            domain_events.push(domain_event);
+        }
+
+        Ok(domain_events)
+    }
+
     async fn update_event_by_merchant_id_event_id(
         &self,
         merchant_id: &str,
@@ -653,6 +847,9 @@ impl EventInterface for MockDb {
                 event_to_update.is_webhook_notified = is_webhook_notified;
                 event_to_update.response = response.map(Into::into);
             }
+            domain::EventUpdate::DeadLetterUpdate { dead_lettered_at } => {
+                event_to_update.dead_lettered_at = dead_lettered_at;
+            }
         }
 
         event_to_update
@@ -661,6 +858,16 @@ impl EventInterface for MockDb {
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
+
+    async fn get_next_event_sequence_number(&self) -> CustomResult<i64, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        Ok(locked_events
+            .iter()
+            .filter_map(|event| event.sequence_number)
+            .max()
+            .unwrap_or_default()
+            .saturating_add(1))
+    }
 }
 
 #[cfg(test)]
@@ -728,6 +935,10 @@ mod tests {
                     request: None,
                     response: None,
                     delivery_attempt: Some(enums::WebhookDeliveryAttempt::InitialAttempt),
+                    dead_lettered_at: None,
+                    webhook_endpoint_id: None,
+                    sequence_number: None,
+                    triggering_attempt_id: None,
                 },
                 &merchant_key_store,
             )
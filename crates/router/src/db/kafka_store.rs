@@ -199,6 +199,17 @@ impl ApiKeyInterface for KafkaStore {
             .await
     }
 
+    async fn update_api_key_last_used(
+        &self,
+        merchant_id: &str,
+        key_id: &str,
+        last_used: PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .update_api_key_last_used(merchant_id, key_id, last_used)
+            .await
+    }
+
     async fn revoke_api_key(
         &self,
         merchant_id: &str,
@@ -369,6 +380,17 @@ impl CustomerInterface for KafkaStore {
             .await
     }
 
+    async fn list_customers_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: &storage::CustomerListConstraints,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        self.diesel_store
+            .list_customers_by_merchant_id_constraints(merchant_id, constraints, key_store)
+            .await
+    }
+
     async fn find_customer_by_customer_id_merchant_id(
         &self,
         customer_id: &str,
@@ -627,6 +649,44 @@ impl EventInterface for KafkaStore {
             .await
     }
 
+    async fn list_dead_lettered_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        self.diesel_store
+            .list_dead_lettered_events_by_merchant_id(
+                merchant_id,
+                limit,
+                offset,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn count_dead_lettered_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .count_dead_lettered_events_by_merchant_id(merchant_id)
+            .await
+    }
+
+    async fn list_dead_lettered_events_by_profile_id(
+        &self,
+        profile_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        self.diesel_store
+            .list_dead_lettered_events_by_profile_id(profile_id, limit, offset, merchant_key_store)
+            .await
+    }
+
     async fn update_event_by_merchant_id_event_id(
         &self,
         merchant_id: &str,
@@ -638,6 +698,10 @@ impl EventInterface for KafkaStore {
             .update_event_by_merchant_id_event_id(merchant_id, event_id, event, merchant_key_store)
             .await
     }
+
+    async fn get_next_event_sequence_number(&self) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store.get_next_event_sequence_number().await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1086,6 +1150,14 @@ impl QueueInterface for KafkaStore {
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError> {
         self.diesel_store.get_key(key).await
     }
+
+    async fn increment_key_with_expiry(
+        &self,
+        key: &str,
+        ttl: i64,
+    ) -> CustomResult<i64, RedisError> {
+        self.diesel_store.increment_key_with_expiry(key, ttl).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1413,6 +1485,21 @@ impl PaymentIntentInterface for KafkaStore {
             .get_active_payment_attempt(payment, storage_scheme)
             .await
     }
+
+    async fn find_payment_intents_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<storage::PaymentIntent>, errors::DataStorageError> {
+        self.diesel_store
+            .find_payment_intents_by_merchant_id_customer_id(
+                merchant_id,
+                customer_id,
+                storage_scheme,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1457,6 +1544,23 @@ impl PaymentMethodInterface for KafkaStore {
             .await
     }
 
+    async fn find_payment_method_by_customer_id_merchant_id_fingerprint_id(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        fingerprint_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_by_customer_id_merchant_id_fingerprint_id(
+                customer_id,
+                merchant_id,
+                fingerprint_id,
+                storage_scheme,
+            )
+            .await
+    }
+
     async fn get_payment_method_count_by_customer_id_merchant_id_status(
         &self,
         customer_id: &str,
@@ -2132,6 +2236,21 @@ impl RoutingAlgorithmInterface for KafkaStore {
             .await
     }
 
+    async fn update_routing_algorithm_by_algorithm_id_merchant_id(
+        &self,
+        algorithm_id: &str,
+        merchant_id: &str,
+        routing_algorithm_update: storage::RoutingAlgorithmUpdate,
+    ) -> CustomResult<storage::RoutingAlgorithm, errors::StorageError> {
+        self.diesel_store
+            .update_routing_algorithm_by_algorithm_id_merchant_id(
+                algorithm_id,
+                merchant_id,
+                routing_algorithm_update,
+            )
+            .await
+    }
+
     async fn find_routing_algorithm_metadata_by_algorithm_id_profile_id(
         &self,
         algorithm_id: &str,
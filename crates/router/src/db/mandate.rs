@@ -634,6 +634,7 @@ impl MandateInterface for MockDb {
             metadata: mandate_new.metadata,
             connector_mandate_ids: mandate_new.connector_mandate_ids,
             merchant_connector_id: mandate_new.merchant_connector_id,
+            test_mode: mandate_new.test_mode,
         };
         mandates.push(mandate.clone());
         Ok(mandate)
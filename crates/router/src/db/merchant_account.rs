@@ -398,63 +398,161 @@ impl MerchantAccountInterface for MockDb {
             .await
             .transpose()?;
 
-        match account {
-            Some(account) => Ok(account),
-            // [#172]: Implement function for `MockDb`
-            None => Err(errors::StorageError::MockDbError)?,
-        }
+        Ok(account.ok_or(errors::StorageError::ValueNotFound(format!(
+            "No merchant account found for merchant_id = {merchant_id}"
+        )))?)
     }
 
     async fn update_merchant(
         &self,
-        _this: domain::MerchantAccount,
-        _merchant_account: storage::MerchantAccountUpdate,
-        _merchant_key_store: &domain::MerchantKeyStore,
+        this: domain::MerchantAccount,
+        merchant_account: storage::MerchantAccountUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::MerchantAccount, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.update_specific_fields_in_merchant(
+            &this.merchant_id,
+            merchant_account,
+            merchant_key_store,
+        )
+        .await
     }
 
     async fn update_specific_fields_in_merchant(
         &self,
-        _merchant_id: &str,
-        _merchant_account: storage::MerchantAccountUpdate,
-        _merchant_key_store: &domain::MerchantKeyStore,
+        merchant_id: &str,
+        merchant_account: storage::MerchantAccountUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::MerchantAccount, errors::StorageError> {
-        // [#TODO]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut accounts = self.merchant_accounts.lock().await;
+        let account = accounts
+            .iter_mut()
+            .find(|account| account.merchant_id == merchant_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No merchant account found for merchant_id = {merchant_id}"
+            )))?;
+
+        *account = storage::MerchantAccountUpdateInternal::from(merchant_account)
+            .apply_changeset(account.clone());
+
+        account
+            .clone()
+            .convert(merchant_key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
     }
 
     async fn find_merchant_account_by_publishable_key(
         &self,
-        _publishable_key: &str,
+        publishable_key: &str,
     ) -> CustomResult<authentication::AuthenticationData, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let merchant_account = self
+            .merchant_accounts
+            .lock()
+            .await
+            .iter()
+            .find(|account| account.publishable_key.as_deref() == Some(publishable_key))
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No merchant account found for publishable_key = {publishable_key}"
+            )))?;
+        let key_store = self
+            .get_merchant_key_store_by_merchant_id(
+                &merchant_account.merchant_id,
+                &self.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        Ok(authentication::AuthenticationData {
+            merchant_account: merchant_account
+                .convert(key_store.key.get_inner())
+                .await
+                .change_context(errors::StorageError::DecryptionError)?,
+
+            key_store,
+        })
     }
 
     async fn delete_merchant_account_by_merchant_id(
         &self,
-        _merchant_id: &str,
+        merchant_id: &str,
     ) -> CustomResult<bool, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut accounts = self.merchant_accounts.lock().await;
+        let accounts_count = accounts.len();
+        accounts.retain(|account| account.merchant_id != merchant_id);
+
+        if accounts.len() == accounts_count {
+            Err(errors::StorageError::ValueNotFound(format!(
+                "No merchant account found for merchant_id = {merchant_id}"
+            )))?
+        }
+
+        Ok(true)
     }
 
     #[cfg(feature = "olap")]
     async fn list_merchant_accounts_by_organization_id(
         &self,
-        _organization_id: &str,
+        organization_id: &str,
     ) -> CustomResult<Vec<domain::MerchantAccount>, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let encrypted_merchant_accounts = self
+            .merchant_accounts
+            .lock()
+            .await
+            .iter()
+            .filter(|account| account.organization_id == organization_id)
+            .cloned()
+            .collect();
+
+        self.decrypt_merchant_accounts(encrypted_merchant_accounts)
+            .await
     }
 
     #[cfg(feature = "olap")]
     async fn list_multiple_merchant_accounts(
         &self,
-        _merchant_ids: Vec<String>,
+        merchant_ids: Vec<String>,
     ) -> CustomResult<Vec<domain::MerchantAccount>, errors::StorageError> {
-        Err(errors::StorageError::MockDbError)?
+        let encrypted_merchant_accounts = self
+            .merchant_accounts
+            .lock()
+            .await
+            .iter()
+            .filter(|account| merchant_ids.contains(&account.merchant_id))
+            .cloned()
+            .collect();
+
+        self.decrypt_merchant_accounts(encrypted_merchant_accounts)
+            .await
+    }
+}
+
+#[cfg(feature = "olap")]
+impl MockDb {
+    async fn decrypt_merchant_accounts(
+        &self,
+        encrypted_merchant_accounts: Vec<storage::MerchantAccount>,
+    ) -> CustomResult<Vec<domain::MerchantAccount>, errors::StorageError> {
+        use futures::future::try_join_all;
+
+        let db_master_key = self.get_master_key().to_vec().into();
+
+        try_join_all(
+            encrypted_merchant_accounts
+                .into_iter()
+                .map(|merchant_account| async {
+                    let key_store = self
+                        .get_merchant_key_store_by_merchant_id(
+                            &merchant_account.merchant_id,
+                            &db_master_key,
+                        )
+                        .await?;
+                    merchant_account
+                        .convert(key_store.key.get_inner())
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                }),
+        )
+        .await
     }
 }
 
@@ -341,19 +341,41 @@ impl MerchantConnectorAccountInterface for Store {
         t: domain::MerchantConnectorAccount,
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::MerchantConnectorAccount, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        t.construct_new()
-            .await
-            .change_context(errors::StorageError::EncryptionError)?
-            .insert(&conn)
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
-            .async_and_then(|item| async {
-                item.convert(key_store.key.get_inner())
-                    .await
-                    .change_context(errors::StorageError::DecryptionError)
-            })
+        #[cfg(feature = "accounts_cache")]
+        let _merchant_id = t.merchant_id.clone();
+
+        let insert_call = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            t.construct_new()
+                .await
+                .change_context(errors::StorageError::EncryptionError)?
+                .insert(&conn)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+                .async_and_then(|item| async {
+                    item.convert(key_store.key.get_inner())
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                })
+                .await
+        };
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            // The new merchant connector account is not present in any of the lists of merchant
+            // connector accounts of the merchant that have been cached so far
+            super::cache::publish_and_redact_multiple(
+                self,
+                get_merchant_connector_account_list_cache_keys(&_merchant_id),
+                insert_call,
+            )
             .await
+        }
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            insert_call().await
+        }
     }
 
     #[instrument(skip_all)]
@@ -363,22 +385,35 @@ impl MerchantConnectorAccountInterface for Store {
         get_disabled: bool,
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::MerchantConnectorAccount>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::MerchantConnectorAccount::find_by_merchant_id(&conn, merchant_id, get_disabled)
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
-            .async_and_then(|items| async {
-                let mut output = Vec::with_capacity(items.len());
-                for item in items.into_iter() {
-                    output.push(
-                        item.convert(key_store.key.get_inner())
-                            .await
-                            .change_context(errors::StorageError::DecryptionError)?,
-                    )
-                }
-                Ok(output)
-            })
-            .await
+        let find_call = || async {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::MerchantConnectorAccount::find_by_merchant_id(&conn, merchant_id, get_disabled)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        let items = find_call().await?;
+
+        #[cfg(feature = "accounts_cache")]
+        let items: Vec<storage::MerchantConnectorAccount> =
+            super::cache::get_or_populate_in_memory(
+                self,
+                &get_merchant_connector_account_list_cache_key(merchant_id, get_disabled),
+                find_call,
+                &cache::ACCOUNTS_CACHE,
+            )
+            .await?;
+
+        let mut output = Vec::with_capacity(items.len());
+        for item in items.into_iter() {
+            output.push(
+                item.convert(key_store.key.get_inner())
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            )
+        }
+        Ok(output)
     }
 
     #[instrument(skip_all)]
@@ -398,6 +433,7 @@ impl MerchantConnectorAccountInterface for Store {
 
         let _merchant_id = this.merchant_id.clone();
         let _merchant_connector_id = this.merchant_connector_id.clone();
+        let _connector_label = this.connector_label.clone();
 
         let update_call = || async {
             let conn = connection::pg_connection_write(self).await?;
@@ -417,17 +453,16 @@ impl MerchantConnectorAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            // Redact both the caches as any one or both might be used because of backwards compatibility
+            // Redact all the caches as any of them might be used because of backwards compatibility
             super::cache::publish_and_redact_multiple(
                 self,
-                [
-                    cache::CacheKind::Accounts(
-                        format!("{}_{}", _profile_id, _connector_name).into(),
-                    ),
-                    cache::CacheKind::Accounts(
-                        format!("{}_{}", _merchant_id, _merchant_connector_id).into(),
-                    ),
-                ],
+                get_merchant_connector_account_cache_keys(
+                    &_merchant_id,
+                    &_merchant_connector_id,
+                    &_profile_id,
+                    &_connector_name,
+                    _connector_label.as_deref(),
+                ),
                 update_call,
             )
             .await
@@ -458,8 +493,8 @@ impl MerchantConnectorAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            // We need to fetch mca here because the key that's saved in cache in
-            // {merchant_id}_{connector_label}.
+            // We need to fetch mca here because the keys that are saved in cache are derived from
+            // its profile id, connector name and connector label.
             // Used function from storage model to reuse the connection that made here instead of
             // creating new.
 
@@ -475,9 +510,15 @@ impl MerchantConnectorAccountInterface for Store {
                 "profile_id".to_string(),
             ))?;
 
-            super::cache::publish_and_redact(
+            super::cache::publish_and_redact_multiple(
                 self,
-                cache::CacheKind::Accounts(format!("{}_{}", mca.merchant_id, _profile_id).into()),
+                get_merchant_connector_account_cache_keys(
+                    &mca.merchant_id,
+                    &mca.merchant_connector_id,
+                    &_profile_id,
+                    &mca.connector_name,
+                    mca.connector_label.as_deref(),
+                ),
                 delete_call,
             )
             .await
@@ -490,6 +531,49 @@ impl MerchantConnectorAccountInterface for Store {
     }
 }
 
+/// The keys under which a merchant connector account can be present in the accounts cache, which
+/// must all be redacted when it is updated or deleted so that no router instance keeps serving
+/// the stale merchant connector account.
+#[cfg(feature = "accounts_cache")]
+fn get_merchant_connector_account_cache_keys(
+    merchant_id: &str,
+    merchant_connector_id: &str,
+    profile_id: &str,
+    connector_name: &str,
+    connector_label: Option<&str>,
+) -> Vec<cache::CacheKind<'static>> {
+    let mut cache_keys = vec![
+        cache::CacheKind::Accounts(format!("{profile_id}_{connector_name}").into()),
+        cache::CacheKind::Accounts(format!("{merchant_id}_{merchant_connector_id}").into()),
+    ];
+    if let Some(connector_label) = connector_label {
+        cache_keys.push(cache::CacheKind::Accounts(
+            format!("{merchant_id}_{connector_label}").into(),
+        ));
+    }
+    cache_keys.extend(get_merchant_connector_account_list_cache_keys(merchant_id));
+    cache_keys
+}
+
+#[cfg(feature = "accounts_cache")]
+fn get_merchant_connector_account_list_cache_key(merchant_id: &str, get_disabled: bool) -> String {
+    format!("merchant_connector_accounts_{merchant_id}_{get_disabled}")
+}
+
+/// The keys under which the lists of merchant connector accounts of a merchant can be present in
+/// the accounts cache, which must be redacted whenever any of its merchant connector accounts is
+/// created, updated or deleted.
+#[cfg(feature = "accounts_cache")]
+fn get_merchant_connector_account_list_cache_keys(
+    merchant_id: &str,
+) -> [cache::CacheKind<'static>; 2] {
+    [true, false].map(|get_disabled| {
+        cache::CacheKind::Accounts(
+            get_merchant_connector_account_list_cache_key(merchant_id, get_disabled).into(),
+        )
+    })
+}
+
 #[async_trait::async_trait]
 impl MerchantConnectorAccountInterface for MockDb {
     async fn find_merchant_connector_account_by_merchant_id_connector_label(
@@ -648,6 +732,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             applepay_verified_domains: t.applepay_verified_domains,
             pm_auth_config: t.pm_auth_config,
             status: t.status,
+            test_connector_account_details: t.test_connector_account_details.map(Into::into),
         };
         accounts.push(account.clone());
         account
@@ -846,6 +931,7 @@ mod merchant_connector_account_cache_tests {
             applepay_verified_domains: None,
             pm_auth_config: None,
             status: common_enums::ConnectorStatus::Inactive,
+            test_connector_account_details: None,
         };
 
         db.insert_merchant_connector_account(mca.clone(), &merchant_key)
@@ -0,0 +1,112 @@
+use error_stack::report;
+use hyperswitch_domain_models::payments::PaymentIntent;
+use router_env::{instrument, tracing};
+use storage_impl::{DataModelExt, MockDb};
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentArchiveInterface {
+    /// Moves up to `limit` payments in a terminal status that were created before
+    /// `created_before`, along with their attempts, into the archive tables.
+    async fn archive_payments_created_before(
+        &self,
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    /// Finds the archived payments made by the customer.
+    async fn find_archived_payment_intents_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentArchiveInterface for Store {
+    #[instrument(skip_all)]
+    async fn archive_payments_created_before(
+        &self,
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        diesel_models::PaymentIntent::archive_created_before(&conn, created_before, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_archived_payment_intents_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel_models::PaymentIntent::find_archived_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+        )
+        .await
+        .map(|payment_intents| {
+            payment_intents
+                .into_iter()
+                .map(PaymentIntent::from_storage_model)
+                .collect()
+        })
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentArchiveInterface for MockDb {
+    async fn archive_payments_created_before(
+        &self,
+        _created_before: time::PrimitiveDateTime,
+        _limit: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_archived_payment_intents_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &str,
+        _customer_id: &str,
+    ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+        // Payments are never archived in the `MockDb`
+        Ok(Vec::new())
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentArchiveInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn archive_payments_created_before(
+        &self,
+        created_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .archive_payments_created_before(created_before, limit)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_archived_payment_intents_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+        self.diesel_store
+            .find_archived_payment_intents_by_merchant_id_customer_id(merchant_id, customer_id)
+            .await
+    }
+}
@@ -0,0 +1,144 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentExportInterface {
+    async fn insert_payment_export(
+        &self,
+        payment_export: storage::PaymentExportNew,
+    ) -> CustomResult<storage::PaymentExport, errors::StorageError>;
+
+    async fn find_payment_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &str,
+        export_id: &str,
+    ) -> CustomResult<storage::PaymentExport, errors::StorageError>;
+
+    async fn update_payment_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &str,
+        export_id: &str,
+        payment_export_update: storage::PaymentExportUpdate,
+    ) -> CustomResult<storage::PaymentExport, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentExportInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_export(
+        &self,
+        payment_export: storage::PaymentExportNew,
+    ) -> CustomResult<storage::PaymentExport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_export
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &str,
+        export_id: &str,
+    ) -> CustomResult<storage::PaymentExport, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentExport::find_by_merchant_id_export_id(&conn, merchant_id, export_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &str,
+        export_id: &str,
+        payment_export_update: storage::PaymentExportUpdate,
+    ) -> CustomResult<storage::PaymentExport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentExport::update_by_merchant_id_export_id(
+            &conn,
+            merchant_id,
+            export_id,
+            payment_export_update.into(),
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentExportInterface for MockDb {
+    async fn insert_payment_export(
+        &self,
+        _payment_export: storage::PaymentExportNew,
+    ) -> CustomResult<storage::PaymentExport, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_export_by_merchant_id_export_id(
+        &self,
+        _merchant_id: &str,
+        _export_id: &str,
+    ) -> CustomResult<storage::PaymentExport, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payment_export_by_merchant_id_export_id(
+        &self,
+        _merchant_id: &str,
+        _export_id: &str,
+        _payment_export_update: storage::PaymentExportUpdate,
+    ) -> CustomResult<storage::PaymentExport, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentExportInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payment_export(
+        &self,
+        payment_export: storage::PaymentExportNew,
+    ) -> CustomResult<storage::PaymentExport, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_export(payment_export)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &str,
+        export_id: &str,
+    ) -> CustomResult<storage::PaymentExport, errors::StorageError> {
+        self.diesel_store
+            .find_payment_export_by_merchant_id_export_id(merchant_id, export_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &str,
+        export_id: &str,
+        payment_export_update: storage::PaymentExportUpdate,
+    ) -> CustomResult<storage::PaymentExport, errors::StorageError> {
+        self.diesel_store
+            .update_payment_export_by_merchant_id_export_id(
+                merchant_id,
+                export_id,
+                payment_export_update,
+            )
+            .await
+    }
+}
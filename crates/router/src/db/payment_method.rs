@@ -37,6 +37,14 @@ pub trait PaymentMethodInterface {
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError>;
 
+    async fn find_payment_method_by_customer_id_merchant_id_fingerprint_id(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        fingerprint_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError>;
+
     async fn get_payment_method_count_by_customer_id_merchant_id_status(
         &self,
         customer_id: &str,
@@ -408,6 +416,62 @@ mod storage {
             }
         }
 
+        #[instrument(skip_all)]
+        async fn find_payment_method_by_customer_id_merchant_id_fingerprint_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            fingerprint_id: &str,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            let database_call = || async {
+                storage_types::PaymentMethod::find_by_customer_id_merchant_id_fingerprint_id(
+                    &conn,
+                    customer_id,
+                    merchant_id,
+                    fingerprint_id,
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+            };
+
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::RedisKv => {
+                    let key = PartitionKey::MerchantIdCustomerId {
+                        merchant_id,
+                        customer_id,
+                    };
+
+                    let pattern = "payment_method_id_*";
+
+                    let redis_fut = async {
+                        let kv_result = kv_wrapper::<storage_types::PaymentMethod, _, _>(
+                            self,
+                            KvOperation::<storage_types::PaymentMethod>::Scan(pattern),
+                            key,
+                        )
+                        .await?
+                        .try_into_scan();
+                        kv_result.map(|payment_methods| {
+                            payment_methods
+                                .into_iter()
+                                .filter(|pm| pm.fingerprint_id.as_deref() == Some(fingerprint_id))
+                                .collect()
+                        })
+                    };
+
+                    Box::pin(db_utils::find_all_combined_kv_database(
+                        redis_fut,
+                        database_call,
+                        None,
+                    ))
+                    .await
+                }
+            }
+        }
+
         async fn delete_payment_method_by_merchant_id_payment_method_id(
             &self,
             merchant_id: &str,
@@ -547,6 +611,25 @@ mod storage {
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[instrument(skip_all)]
+        async fn find_payment_method_by_customer_id_merchant_id_fingerprint_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            fingerprint_id: &str,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PaymentMethod::find_by_customer_id_merchant_id_fingerprint_id(
+                &conn,
+                customer_id,
+                merchant_id,
+                fingerprint_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         async fn delete_payment_method_by_merchant_id_payment_method_id(
             &self,
             merchant_id: &str,
@@ -661,6 +744,7 @@ impl PaymentMethodInterface for MockDb {
             customer_acceptance: payment_method_new.customer_acceptance,
             status: payment_method_new.status,
             client_secret: payment_method_new.client_secret,
+            fingerprint_id: payment_method_new.fingerprint_id,
             network_transaction_id: payment_method_new.network_transaction_id,
         };
         payment_methods.push(payment_method.clone());
@@ -719,6 +803,25 @@ impl PaymentMethodInterface for MockDb {
         }
     }
 
+    async fn find_payment_method_by_customer_id_merchant_id_fingerprint_id(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        fingerprint_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        Ok(payment_methods
+            .iter()
+            .filter(|pm| {
+                pm.customer_id == customer_id
+                    && pm.merchant_id == merchant_id
+                    && pm.fingerprint_id.as_deref() == Some(fingerprint_id)
+            })
+            .cloned()
+            .collect())
+    }
+
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
         merchant_id: &str,
@@ -0,0 +1,112 @@
+use diesel_models::enums as storage_enums;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::{DataModelExt, MockDb};
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentStatusSyncInterface {
+    /// Finds up to `limit` payments in one of `statuses` that were created after `created_after`
+    /// and have not been modified since `modified_before`, least recently modified first, starting
+    /// after the payment identified by the `modified_at` and `payment_id` in `after`.
+    async fn find_payments_pending_status_sync(
+        &self,
+        statuses: Vec<storage_enums::IntentStatus>,
+        created_after: time::PrimitiveDateTime,
+        modified_before: time::PrimitiveDateTime,
+        after: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentStatusSyncInterface for Store {
+    #[instrument(skip_all)]
+    async fn find_payments_pending_status_sync(
+        &self,
+        statuses: Vec<storage_enums::IntentStatus>,
+        created_after: time::PrimitiveDateTime,
+        modified_before: time::PrimitiveDateTime,
+        after: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel_models::PaymentIntent::find_by_statuses_modified_before(
+            &conn,
+            statuses,
+            created_after,
+            modified_before,
+            after,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentStatusSyncInterface for MockDb {
+    async fn find_payments_pending_status_sync(
+        &self,
+        statuses: Vec<storage_enums::IntentStatus>,
+        created_after: time::PrimitiveDateTime,
+        modified_before: time::PrimitiveDateTime,
+        after: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        let limit = usize::try_from(limit).map_err(|_| errors::StorageError::MockDbError)?;
+        let mut payment_intents = self
+            .payment_intents
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_intent| {
+                statuses.contains(&payment_intent.status)
+                    && payment_intent.created_at > created_after
+                    && payment_intent.modified_at < modified_before
+                    && after.as_ref().map_or(true, |(modified_at, payment_id)| {
+                        (payment_intent.modified_at, &payment_intent.payment_id)
+                            > (*modified_at, payment_id)
+                    })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        payment_intents
+            .sort_by(|a, b| (a.modified_at, &a.payment_id).cmp(&(b.modified_at, &b.payment_id)));
+
+        Ok(payment_intents
+            .into_iter()
+            .take(limit)
+            .map(DataModelExt::to_storage_model)
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentStatusSyncInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn find_payments_pending_status_sync(
+        &self,
+        statuses: Vec<storage_enums::IntentStatus>,
+        created_after: time::PrimitiveDateTime,
+        modified_before: time::PrimitiveDateTime,
+        after: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        self.diesel_store
+            .find_payments_pending_status_sync(
+                statuses,
+                created_after,
+                modified_before,
+                after,
+                limit,
+            )
+            .await
+    }
+}
@@ -220,7 +220,7 @@ mod storage {
             limit: i64,
             offset: i64,
         ) -> CustomResult<Vec<diesel_models::refund::Refund>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_constraints(
                 &conn,
                 merchant_id,
@@ -240,7 +240,7 @@ mod storage {
             refund_details: &api_models::payments::TimeRange,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<api_models::refunds::RefundListMetaData, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_meta_constraints(
                 &conn,
                 merchant_id,
@@ -257,7 +257,7 @@ mod storage {
             refund_details: &api_models::refunds::RefundListRequest,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<i64, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::get_refunds_count(
                 &conn,
                 merchant_id,
@@ -502,7 +502,7 @@ mod storage {
 
                     let pattern = db_utils::generate_hscan_pattern_for_refund(&lookup.sk_id);
 
-                    Box::pin(db_utils::try_redis_get_else_try_database_get(
+                    Box::pin(db_utils::find_all_combined_kv_database(
                         async {
                             kv_wrapper(
                                 self,
@@ -513,6 +513,7 @@ mod storage {
                             .try_into_scan()
                         },
                         database_call,
+                        None,
                     ))
                     .await
                 }
@@ -692,7 +693,9 @@ mod storage {
                         merchant_id,
                         payment_id,
                     };
-                    Box::pin(db_utils::try_redis_get_else_try_database_get(
+                    // Refunds created before the merchant moved to the KV storage scheme are only
+                    // present in the database, so the refunds in Redis are merged with them
+                    Box::pin(db_utils::find_all_combined_kv_database(
                         async {
                             kv_wrapper(
                                 self,
@@ -703,6 +706,7 @@ mod storage {
                             .try_into_scan()
                         },
                         database_call,
+                        None,
                     ))
                     .await
                 }
@@ -719,7 +723,7 @@ mod storage {
             limit: i64,
             offset: i64,
         ) -> CustomResult<Vec<diesel_models::refund::Refund>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_constraints(
                 &conn,
                 merchant_id,
@@ -739,7 +743,7 @@ mod storage {
             refund_details: &api_models::payments::TimeRange,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<api_models::refunds::RefundListMetaData, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_meta_constraints(&conn, merchant_id, refund_details)
                         .await
                         .map_err(|error|report!(errors::StorageError::from(error)))
@@ -753,7 +757,7 @@ mod storage {
             refund_details: &api_models::refunds::RefundListRequest,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<i64, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_replica(self).await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::get_refunds_count(
                 &conn,
                 merchant_id,
@@ -953,7 +957,16 @@ impl RefundInterface for MockDb {
         }
 
         let refunds = self.refunds.lock().await;
-        let filtered_refunds = refunds
+        let cursor_of = |refund_id: &String| {
+            refunds
+                .iter()
+                .find(|refund| refund.merchant_id == merchant_id && &refund.refund_id == refund_id)
+                .map(|refund| (refund.created_at, refund.refund_id.clone()))
+        };
+        let starting_after = refund_details.starting_after.as_ref().map(cursor_of);
+        let ending_before = refund_details.ending_before.as_ref().map(cursor_of);
+
+        let mut filtered_refunds = refunds
             .iter()
             .filter(|refund| refund.merchant_id == merchant_id)
             .filter(|refund| {
@@ -991,12 +1004,39 @@ impl RefundInterface for MockDb {
             .filter(|refund| {
                 unique_statuses.is_empty() || unique_statuses.contains(&refund.refund_status)
             })
-            .skip(usize::try_from(offset).unwrap_or_default())
-            .take(usize::try_from(limit).unwrap_or(MAX_LIMIT))
+            .filter(|refund| {
+                let key = (refund.created_at, refund.refund_id.clone());
+                starting_after
+                    .as_ref()
+                    .map_or(true, |cursor| cursor.as_ref().map_or(false, |c| &key < c))
+                    && ending_before
+                        .as_ref()
+                        .map_or(true, |cursor| cursor.as_ref().map_or(false, |c| &key > c))
+            })
             .cloned()
             .collect::<Vec<_>>();
-
-        Ok(filtered_refunds)
+        filtered_refunds
+            .sort_by(|a, b| (b.created_at, &b.refund_id).cmp(&(a.created_at, &a.refund_id)));
+
+        let offset = usize::try_from(offset).unwrap_or_default();
+        let limit = usize::try_from(limit).unwrap_or(MAX_LIMIT);
+        if ending_before.is_some() && starting_after.is_none() {
+            // Like the database, take the page closest to the `ending_before` cursor.
+            let mut page = filtered_refunds
+                .into_iter()
+                .rev()
+                .skip(offset)
+                .take(limit)
+                .collect::<Vec<_>>();
+            page.reverse();
+            Ok(page)
+        } else {
+            Ok(filtered_refunds
+                .into_iter()
+                .skip(offset)
+                .take(limit)
+                .collect())
+        }
     }
 
     #[cfg(feature = "olap")]
@@ -1,7 +1,10 @@
 use diesel_models::routing_algorithm as routing_storage;
 use error_stack::report;
 use router_env::{instrument, tracing};
-use storage_impl::mock_db::MockDb;
+use storage_impl::{
+    mock_db::MockDb,
+    redis::cache::{CacheKind, CONFIG_CACHE},
+};
 
 use crate::{
     connection,
@@ -30,6 +33,13 @@ pub trait RoutingAlgorithmInterface {
         merchant_id: &str,
     ) -> StorageResult<routing_storage::RoutingAlgorithm>;
 
+    async fn update_routing_algorithm_by_algorithm_id_merchant_id(
+        &self,
+        algorithm_id: &str,
+        merchant_id: &str,
+        routing_algorithm_update: routing_storage::RoutingAlgorithmUpdate,
+    ) -> StorageResult<routing_storage::RoutingAlgorithm>;
+
     async fn find_routing_algorithm_metadata_by_algorithm_id_profile_id(
         &self,
         algorithm_id: &str,
@@ -79,14 +89,24 @@ impl RoutingAlgorithmInterface for Store {
         profile_id: &str,
         algorithm_id: &str,
     ) -> StorageResult<routing_storage::RoutingAlgorithm> {
-        let conn = connection::pg_connection_write(self).await?;
-        routing_storage::RoutingAlgorithm::find_by_algorithm_id_profile_id(
-            &conn,
-            algorithm_id,
-            profile_id,
+        let find_call = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            routing_storage::RoutingAlgorithm::find_by_algorithm_id_profile_id(
+                &conn,
+                algorithm_id,
+                profile_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        };
+
+        super::cache::get_or_populate_in_memory(
+            self,
+            &get_routing_algorithm_cache_key(profile_id, algorithm_id),
+            find_call,
+            &CONFIG_CACHE,
         )
         .await
-        .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
     #[instrument(skip_all)]
@@ -105,6 +125,40 @@ impl RoutingAlgorithmInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn update_routing_algorithm_by_algorithm_id_merchant_id(
+        &self,
+        algorithm_id: &str,
+        merchant_id: &str,
+        routing_algorithm_update: routing_storage::RoutingAlgorithmUpdate,
+    ) -> StorageResult<routing_storage::RoutingAlgorithm> {
+        let conn = connection::pg_connection_write(self).await?;
+        let routing_algorithm =
+            routing_storage::RoutingAlgorithm::update_by_algorithm_id_merchant_id(
+                &conn,
+                algorithm_id,
+                merchant_id,
+                routing_algorithm_update,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+        // The profile of the routing algorithm is only known once it has been updated
+        super::cache::publish_into_redact_channel(
+            self,
+            [CacheKind::Config(
+                get_routing_algorithm_cache_key(
+                    &routing_algorithm.profile_id,
+                    &routing_algorithm.algorithm_id,
+                )
+                .into(),
+            )],
+        )
+        .await?;
+
+        Ok(routing_algorithm)
+    }
+
     #[instrument(skip_all)]
     async fn find_routing_algorithm_metadata_by_algorithm_id_profile_id(
         &self,
@@ -174,6 +228,10 @@ impl RoutingAlgorithmInterface for Store {
     }
 }
 
+fn get_routing_algorithm_cache_key(profile_id: &str, algorithm_id: &str) -> String {
+    format!("routing_algorithm_{profile_id}_{algorithm_id}")
+}
+
 #[async_trait::async_trait]
 impl RoutingAlgorithmInterface for MockDb {
     async fn insert_routing_algorithm(
@@ -199,6 +257,15 @@ impl RoutingAlgorithmInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn update_routing_algorithm_by_algorithm_id_merchant_id(
+        &self,
+        _algorithm_id: &str,
+        _merchant_id: &str,
+        _routing_algorithm_update: routing_storage::RoutingAlgorithmUpdate,
+    ) -> StorageResult<routing_storage::RoutingAlgorithm> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn find_routing_algorithm_metadata_by_algorithm_id_profile_id(
         &self,
         _algorithm_id: &str,
@@ -0,0 +1,365 @@
+use common_utils::ext_traits::AsyncExt;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::{
+        domain::{
+            self,
+            behaviour::{Conversion, ReverseConversion},
+        },
+        storage,
+    },
+};
+
+#[async_trait::async_trait]
+pub trait WebhookEndpointInterface
+where
+    domain::WebhookEndpoint:
+        Conversion<DstType = storage::WebhookEndpoint, NewDstType = storage::WebhookEndpointNew>,
+{
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: domain::WebhookEndpoint,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError>;
+
+    async fn find_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &str,
+        endpoint_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError>;
+
+    async fn list_webhook_endpoints_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &str,
+        profile_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::WebhookEndpoint>, errors::StorageError>;
+
+    async fn update_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &str,
+        endpoint_id: &str,
+        webhook_endpoint_update: domain::WebhookEndpointUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError>;
+
+    async fn delete_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &str,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: domain::WebhookEndpoint,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        webhook_endpoint
+            .construct_new()
+            .await
+            .change_context(errors::StorageError::EncryptionError)?
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .convert(merchant_key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &str,
+        endpoint_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpoint::find_by_merchant_id_endpoint_id(&conn, merchant_id, endpoint_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .convert(merchant_key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_endpoints_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &str,
+        profile_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::WebhookEndpoint>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpoint::list_by_merchant_id_profile_id(&conn, merchant_id, profile_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+            .async_and_then(|webhook_endpoints| async {
+                let mut domain_webhook_endpoints = Vec::with_capacity(webhook_endpoints.len());
+                for webhook_endpoint in webhook_endpoints.into_iter() {
+                    domain_webhook_endpoints.push(
+                        webhook_endpoint
+                            .convert(merchant_key_store.key.get_inner())
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)?,
+                    );
+                }
+                Ok(domain_webhook_endpoints)
+            })
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &str,
+        endpoint_id: &str,
+        webhook_endpoint_update: domain::WebhookEndpointUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::update_by_merchant_id_endpoint_id(
+            &conn,
+            merchant_id,
+            endpoint_id,
+            webhook_endpoint_update.into(),
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?
+        .convert(merchant_key_store.key.get_inner())
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &str,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::delete_by_merchant_id_endpoint_id(&conn, merchant_id, endpoint_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for MockDb {
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: domain::WebhookEndpoint,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let mut webhook_endpoints = self.webhook_endpoints.lock().await;
+        if webhook_endpoints
+            .iter()
+            .any(|endpoint| endpoint.endpoint_id == webhook_endpoint.endpoint_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "endpoint_id",
+                key: Some(webhook_endpoint.endpoint_id.clone()),
+            })?
+        }
+
+        let stored_webhook_endpoint = Conversion::convert(webhook_endpoint)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+        webhook_endpoints.push(stored_webhook_endpoint.clone());
+
+        stored_webhook_endpoint
+            .convert(merchant_key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn find_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &str,
+        endpoint_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        self.webhook_endpoints
+            .lock()
+            .await
+            .iter()
+            .find(|endpoint| {
+                endpoint.merchant_id == merchant_id && endpoint.endpoint_id == endpoint_id
+            })
+            .cloned()
+            .async_map(|webhook_endpoint| async {
+                webhook_endpoint
+                    .convert(merchant_key_store.key.get_inner())
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)
+            })
+            .await
+            .transpose()?
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No webhook endpoint available for endpoint_id = {endpoint_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_webhook_endpoints_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &str,
+        profile_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::WebhookEndpoint>, errors::StorageError> {
+        let webhook_endpoints = self
+            .webhook_endpoints
+            .lock()
+            .await
+            .iter()
+            .filter(|endpoint| {
+                endpoint.merchant_id == merchant_id && endpoint.profile_id == profile_id
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut domain_webhook_endpoints = Vec::with_capacity(webhook_endpoints.len());
+        for webhook_endpoint in webhook_endpoints {
+            domain_webhook_endpoints.push(
+                webhook_endpoint
+                    .convert(merchant_key_store.key.get_inner())
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            );
+        }
+        Ok(domain_webhook_endpoints)
+    }
+
+    async fn update_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &str,
+        endpoint_id: &str,
+        webhook_endpoint_update: domain::WebhookEndpointUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        let mut webhook_endpoints = self.webhook_endpoints.lock().await;
+        let webhook_endpoint = webhook_endpoints
+            .iter_mut()
+            .find(|endpoint| {
+                endpoint.merchant_id == merchant_id && endpoint.endpoint_id == endpoint_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No webhook endpoint available for endpoint_id = {endpoint_id}"
+            )))?;
+
+        *webhook_endpoint = storage::WebhookEndpointUpdateInternal::from(webhook_endpoint_update)
+            .apply_changeset(webhook_endpoint.clone());
+        webhook_endpoint
+            .clone()
+            .convert(merchant_key_store.key.get_inner())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    async fn delete_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &str,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut webhook_endpoints = self.webhook_endpoints.lock().await;
+        let index = webhook_endpoints
+            .iter()
+            .position(|endpoint| {
+                endpoint.merchant_id == merchant_id && endpoint.endpoint_id == endpoint_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No webhook endpoint available for endpoint_id = {endpoint_id}"
+            )))?;
+        webhook_endpoints.remove(index);
+        Ok(true)
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: domain::WebhookEndpoint,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .insert_webhook_endpoint(webhook_endpoint, merchant_key_store)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &str,
+        endpoint_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .find_webhook_endpoint_by_merchant_id_endpoint_id(
+                merchant_id,
+                endpoint_id,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_endpoints_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &str,
+        profile_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::WebhookEndpoint>, errors::StorageError> {
+        self.diesel_store
+            .list_webhook_endpoints_by_merchant_id_profile_id(
+                merchant_id,
+                profile_id,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &str,
+        endpoint_id: &str,
+        webhook_endpoint_update: domain::WebhookEndpointUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .update_webhook_endpoint_by_merchant_id_endpoint_id(
+                merchant_id,
+                endpoint_id,
+                webhook_endpoint_update,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &str,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_webhook_endpoint_by_merchant_id_endpoint_id(merchant_id, endpoint_id)
+            .await
+    }
+}
@@ -5,14 +5,22 @@ use time::PrimitiveDateTime;
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "event_type")]
 pub enum AuditEventType {
-    Error { error_message: String },
+    Error {
+        error_message: String,
+    },
     PaymentCreated,
     ConnectorDecided,
     ConnectorCalled,
     RefundCreated,
     RefundSuccess,
     RefundFail,
-    PaymentCancelled { cancellation_reason: Option<String> },
+    PaymentCancelled {
+        cancellation_reason: Option<String>,
+    },
+    PaymentMethodDeleted {
+        payment_method_id: String,
+        customer_id: String,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -49,6 +57,7 @@ impl Event for AuditEvent {
             AuditEventType::RefundSuccess => "refund_success",
             AuditEventType::RefundFail => "refund_fail",
             AuditEventType::PaymentCancelled { .. } => "payment_cancelled",
+            AuditEventType::PaymentMethodDeleted { .. } => "payment_method_deleted",
         };
         format!(
             "{event_type}-{}",
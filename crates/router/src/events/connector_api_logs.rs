@@ -2,10 +2,10 @@ use common_utils::request::Method;
 use router_env::tracing_actix_web::RequestId;
 use serde::Serialize;
 use serde_json::json;
-use time::OffsetDateTime;
+use time::{OffsetDateTime, PrimitiveDateTime};
 
 use super::EventType;
-use crate::services::kafka::KafkaMessage;
+use crate::{services::kafka::KafkaMessage, types::storage};
 
 #[derive(Debug, Serialize)]
 pub struct ConnectorEvent {
@@ -90,6 +90,29 @@ impl ConnectorEvent {
     }
 }
 
+impl ConnectorEvent {
+    /// Builds the payload to be stored with the payment attempt for which the connector was called
+    pub fn to_connector_payload(&self, attempt_id: &str) -> storage::ConnectorPayloadNew {
+        let created_at = OffsetDateTime::from_unix_timestamp_nanos(self.created_at * 1_000_000)
+            .map(|created_at| PrimitiveDateTime::new(created_at.date(), created_at.time()))
+            .unwrap_or_else(|_| common_utils::date_time::now());
+        storage::ConnectorPayloadNew {
+            merchant_id: self.merchant_id.clone(),
+            payment_id: self.payment_id.clone(),
+            attempt_id: attempt_id.to_string(),
+            connector: self.connector_name.clone(),
+            flow: self.flow.clone(),
+            url: self.url.clone(),
+            method: self.method.clone(),
+            status_code: i32::from(self.status_code),
+            request: self.request.clone(),
+            response: self.masked_response.clone(),
+            error: self.error.clone(),
+            created_at,
+        }
+    }
+}
+
 impl KafkaMessage for ConnectorEvent {
     fn event_type(&self) -> EventType {
         EventType::ConnectorApiLogs
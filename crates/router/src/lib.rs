@@ -13,6 +13,7 @@ pub mod db;
 pub mod env;
 pub(crate) mod macros;
 
+pub mod process_tracker;
 pub mod routes;
 pub mod workflows;
 
@@ -52,6 +53,7 @@ pub mod headers {
     pub const X_CC_API_KEY: &str = "X-CC-Api-Key";
     pub const API_TOKEN: &str = "Api-Token";
     pub const AUTHORIZATION: &str = "Authorization";
+    pub const CKO_IDEMPOTENCY_KEY: &str = "Cko-Idempotency-Key";
     pub const CONTENT_TYPE: &str = "Content-Type";
     pub const DATE: &str = "Date";
     pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
@@ -62,6 +64,7 @@ pub mod headers {
     pub const X_API_VERSION: &str = "X-ApiVersion";
     pub const X_FORWARDED_FOR: &str = "X-Forwarded-For";
     pub const X_MERCHANT_ID: &str = "X-Merchant-Id";
+    pub const X_PEER_ADDRESS: &str = "X-Peer-Address";
     pub const X_LOGIN: &str = "X-Login";
     pub const X_TRANS_KEY: &str = "X-Trans-Key";
     pub const X_VERSION: &str = "X-Version";
@@ -69,6 +72,8 @@ pub mod headers {
     pub const X_ACCEPT_VERSION: &str = "X-Accept-Version";
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
+    pub const X_WEBHOOK_SIGNATURE_TIMESTAMPED: &str = "X-Webhook-Signature-512-Timestamped";
+    pub const X_WEBHOOK_TIMESTAMP: &str = "X-Webhook-Timestamp";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
     pub const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
@@ -94,7 +99,11 @@ pub fn mk_app(
         InitError = (),
     >,
 > {
-    let mut server_app = get_application_builder(request_body_limit, state.conf.cors.clone());
+    let mut server_app = get_application_builder(
+        request_body_limit,
+        state.conf.cors.clone(),
+        middleware::RateLimiter::new(state.clone()),
+    );
 
     #[cfg(feature = "dummy_connector")]
     {
@@ -135,6 +144,7 @@ pub fn mk_app(
             .service(routes::MerchantAccount::server(state.clone()))
             .service(routes::ApiKeys::server(state.clone()))
             .service(routes::Files::server(state.clone()))
+            .service(routes::PaymentExports::server(state.clone()))
             .service(routes::Disputes::server(state.clone()))
             .service(routes::Analytics::server(state.clone()))
             .service(routes::Routing::server(state.clone()))
@@ -163,6 +173,7 @@ pub fn mk_app(
     }
 
     server_app = server_app.service(routes::Cards::server(state.clone()));
+    server_app = server_app.service(routes::ConnectorCapabilities::server(state.clone()));
     server_app = server_app.service(routes::Cache::server(state.clone()));
     server_app = server_app.service(routes::Health::server(state));
 
@@ -176,9 +187,27 @@ pub fn mk_app(
 ///  Unwrap used because without the value we can't start the server
 #[allow(clippy::expect_used, clippy::unwrap_used)]
 pub async fn start_server(conf: settings::Settings<SecuredSecret>) -> ApplicationResult<Server> {
+    start_server_with_storage(conf, db::StorageImpl::Postgresql).await
+}
+
+pub async fn start_server_with_storage(
+    conf: settings::Settings<SecuredSecret>,
+    storage_impl: db::StorageImpl,
+) -> ApplicationResult<Server> {
+    start_server_with_store_layer(conf, storage_impl, Box::new(|store| store)).await
+}
+
+/// Starts the server with the configured store wrapped by `store_layer`, so that deployments can
+/// plug in alternative implementations of the storage interfaces without modifying the router.
+pub async fn start_server_with_store_layer(
+    conf: settings::Settings<SecuredSecret>,
+    storage_impl: db::StorageImpl,
+    store_layer: db::StoreLayer,
+) -> ApplicationResult<Server> {
     logger::debug!(startup_config=?conf);
     let server = conf.server.clone();
     let (tx, rx) = oneshot::channel();
+    let run_migrations = storage_impl == db::StorageImpl::Postgresql;
     let api_client = Box::new(
         services::ProxyClient::new(
             conf.proxy.clone(),
@@ -188,7 +217,21 @@ pub async fn start_server(conf: settings::Settings<SecuredSecret>) -> Applicatio
             errors::ApplicationError::ApiClientError(error.current_context().clone())
         })?,
     );
-    let state = Box::pin(routes::AppState::new(conf, tx, api_client)).await;
+    let state = Box::pin(routes::AppState::with_store_layer(
+        conf,
+        storage_impl,
+        store_layer,
+        tx,
+        api_client,
+    ))
+    .await;
+    if run_migrations && state.conf.migrations.enabled {
+        services::migrations::run_pending_migrations(
+            &state.conf.migrations,
+            state.conf.master_database.get_inner(),
+        )
+        .await?;
+    }
     let request_body_limit = server.request_body_limit;
     let server = actix_web::HttpServer::new(move || mk_app(state.clone(), request_body_limit))
         .bind((server.host.as_str(), server.port))?
@@ -232,6 +275,7 @@ impl Stop for mpsc::Sender<()> {
 pub fn get_application_builder(
     request_body_limit: usize,
     cors: settings::CorsSettings,
+    rate_limiter: middleware::RateLimiter,
 ) -> actix_web::App<
     impl ServiceFactory<
         ServiceRequest,
@@ -256,8 +300,10 @@ pub fn get_application_builder(
             StatusCode::METHOD_NOT_ALLOWED,
             errors::error_handlers::custom_error_handlers,
         ))
+        .wrap(rate_limiter)
         .wrap(middleware::default_response_headers())
         .wrap(middleware::RequestId)
+        .wrap(middleware::PeerAddress)
         .wrap(cors::cors(cors))
         // this middleware works only for Http1.1 requests
         .wrap(middleware::Http400RequestDetailsLogger)
@@ -73,6 +73,68 @@ where
     }
 }
 
+/// Middleware to record the address of the peer the request was received from in the
+/// `X-Peer-Address` header, replacing any value sent by the client.
+pub struct PeerAddress;
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for PeerAddress
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = PeerAddressMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(PeerAddressMiddleware { service }))
+    }
+}
+
+pub struct PeerAddressMiddleware<S> {
+    service: S,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest> for PeerAddressMiddleware<S>
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = S::Future;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, mut req: actix_web::dev::ServiceRequest) -> Self::Future {
+        let peer_address = req
+            .peer_addr()
+            .and_then(|address| http::HeaderValue::from_str(&address.ip().to_string()).ok());
+        let headers = req.headers_mut();
+        headers.remove(crate::headers::X_PEER_ADDRESS);
+        if let Some(peer_address) = peer_address {
+            headers.insert(
+                http::header::HeaderName::from_static("x-peer-address"),
+                peer_address,
+            );
+        }
+
+        self.service.call(req)
+    }
+}
+
 /// Middleware for attaching default response headers. Headers with the same key already set in a
 /// response will not be overwritten.
 pub fn default_response_headers() -> actix_web::middleware::DefaultHeaders {
@@ -284,3 +346,175 @@ where
         })
     }
 }
+
+/// Middleware to limit the number of requests made using an API key. Requests are counted in
+/// Redis over fixed windows, separately for each API key and route group, and requests
+/// exceeding the configured limit are rejected with a `429 Too Many Requests` response carrying
+/// a `Retry-After` header.
+pub struct RateLimiter {
+    state: crate::routes::AppState,
+}
+
+impl RateLimiter {
+    pub fn new(state: crate::routes::AppState) -> Self {
+        Self { state }
+    }
+}
+
+impl<S: 'static, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for RateLimiter
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<actix_web::body::EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Transform = RateLimiterMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(RateLimiterMiddleware {
+            service: std::rc::Rc::new(service),
+            state: self.state.clone(),
+        }))
+    }
+}
+
+pub struct RateLimiterMiddleware<S> {
+    service: std::rc::Rc<S>,
+    state: crate::routes::AppState,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest> for RateLimiterMiddleware<S>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<actix_web::body::EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        let svc = self.service.clone();
+        let state = self.state.clone();
+
+        Box::pin(
+            async move {
+                if let Some(retry_after_secs) = get_rate_limit_retry_after(&state, &req).await {
+                    let mut response = actix_web::ResponseError::error_response(
+                        &crate::core::errors::ApiErrorResponse::RateLimitExceeded {
+                            retry_after_secs,
+                        },
+                    );
+                    response.headers_mut().insert(
+                        actix_web::http::header::RETRY_AFTER,
+                        actix_web::http::header::HeaderValue::from(retry_after_secs),
+                    );
+                    return Ok(req.into_response(response).map_into_right_body());
+                }
+
+                svc.call(req)
+                    .await
+                    .map(actix_web::dev::ServiceResponse::map_into_left_body)
+            }
+            .in_current_span(),
+        )
+    }
+}
+
+/// Groups of routes that are rate limited independently of each other.
+#[derive(Clone, Copy, Debug, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+enum RateLimitRouteGroup {
+    Payments,
+    List,
+    Default,
+}
+
+impl RateLimitRouteGroup {
+    fn from_path(path: &str) -> Self {
+        if path.ends_with("/list") || path.contains("/list/") {
+            Self::List
+        } else if path.starts_with("/payments") {
+            Self::Payments
+        } else {
+            Self::Default
+        }
+    }
+
+    fn get_limit(self, config: &crate::configs::settings::RateLimit) -> i64 {
+        match self {
+            Self::Payments => config.payments_limit,
+            Self::List => config.list_limit,
+            Self::Default => config.default_limit,
+        }
+    }
+}
+
+/// Counts the request against the rate limit of the API key used, and returns the number of
+/// seconds after which the request can be retried if the limit has been exceeded. Requests are
+/// counted against the keyed hash of the API key, the same value that authentication looks the key
+/// up by, so that the check needs no database read and re-sending an API key with surrounding
+/// whitespace does not reset the count. Requests made with an invalid API key are counted too, and
+/// are rejected during authentication unless they are over the limit. Failures in counting requests
+/// do not block them.
+async fn get_rate_limit_retry_after(
+    state: &crate::routes::AppState,
+    req: &actix_web::dev::ServiceRequest,
+) -> Option<i64> {
+    use masking::PeekInterface;
+
+    let config = &state.conf.rate_limit;
+    if !config.enabled {
+        return None;
+    }
+
+    let api_key = req
+        .headers()
+        .get("api-key")
+        .and_then(|api_key| api_key.to_str().ok())?;
+    let hash_key = state
+        .conf
+        .api_keys
+        .get_inner()
+        .get_hash_key()
+        .map_err(|error| logger::error!(?error, "Failed to obtain API key hash key"))
+        .ok()?;
+    let hashed_api_key = crate::types::storage::HashedApiKey::from(
+        crate::core::api_keys::PlaintextApiKey::from(api_key.trim()).keyed_hash(hash_key.peek()),
+    );
+
+    let route_group = RateLimitRouteGroup::from_path(req.path());
+    let current_timestamp = common_utils::date_time::now_unix_timestamp();
+    let window = current_timestamp / config.window_in_secs;
+    let key = format!(
+        "rate_limit_{route_group}_{}_{window}",
+        hashed_api_key.into_inner()
+    );
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+        .ok()?;
+    let request_count = redis_conn
+        .increment_key_with_expiry(&key, config.window_in_secs)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to count request for rate limiting"))
+        .ok()?;
+
+    (request_count > route_group.get_limit(config))
+        .then(|| config.window_in_secs - current_timestamp % config.window_in_secs)
+}
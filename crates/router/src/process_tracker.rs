@@ -0,0 +1,383 @@
+//! Entry point of the scheduler (producer and consumer) processes, which run the process tracker
+//! workflows of the application.
+
+use std::sync::Arc;
+
+use actix_web::{dev::Server, web, Scope};
+use api_models::health_check::SchedulerHealthCheckResponse;
+use common_utils::ext_traits::{OptionExt, StringExt};
+use diesel_models::process_tracker as storage;
+use error_stack::ResultExt;
+use hyperswitch_interfaces::secrets_interface::secret_state::SecuredSecret;
+use router_env::{
+    instrument,
+    tracing::{self, Instrument},
+};
+use scheduler::{
+    consumer::workflows::ProcessTrackerWorkflow, errors::ProcessTrackerError,
+    workflows::ProcessTrackerWorkflows, SchedulerAppState,
+};
+use storage_impl::errors::ApplicationError;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{
+    configs::settings::Settings,
+    core::{
+        errors::{self, CustomResult},
+        health_check::HealthCheckInterface,
+    },
+    db, logger, routes,
+    services::{self, api},
+    workflows,
+};
+
+/// Starts the scheduler flow
+pub async fn start_scheduler(
+    conf: Settings<SecuredSecret>,
+    scheduler_flow: scheduler::SchedulerFlow,
+) -> CustomResult<(), ProcessTrackerError> {
+    start_scheduler_with_store_layer(conf, scheduler_flow, Box::new(|store| store)).await
+}
+
+/// Starts the scheduler flow with the configured store wrapped by `store_layer`, so that the
+/// workflows use the same storage implementations as the server started with
+/// [`crate::start_server_with_store_layer`].
+pub async fn start_scheduler_with_store_layer(
+    conf: Settings<SecuredSecret>,
+    scheduler_flow: scheduler::SchedulerFlow,
+    store_layer: db::StoreLayer,
+) -> CustomResult<(), ProcessTrackerError> {
+    let api_client = Box::new(
+        services::ProxyClient::new(
+            conf.proxy.clone(),
+            services::proxy_bypass_urls(&conf.locker),
+        )
+        .change_context(errors::ProcessTrackerError::ConfigurationError)?,
+    );
+    // channel for listening to redis disconnect events
+    let (redis_shutdown_signal_tx, redis_shutdown_signal_rx) = oneshot::channel();
+    let state = Box::pin(routes::AppState::with_store_layer(
+        conf,
+        db::StorageImpl::Postgresql,
+        store_layer,
+        redis_shutdown_signal_tx,
+        api_client,
+    ))
+    .await;
+    // channel to shutdown scheduler gracefully
+    let (tx, rx) = mpsc::channel(1);
+    let _task_handle = tokio::spawn(
+        crate::receiver_for_error(redis_shutdown_signal_rx, tx.clone()).in_current_span(),
+    );
+
+    logger::info!("Starting {scheduler_flow}");
+
+    let web_server = Box::pin(start_web_server(state.clone(), scheduler_flow.to_string()))
+        .await
+        .change_context(errors::ProcessTrackerError::ConfigurationError)
+        .attach_printable("Failed to create the scheduler health check server")?;
+
+    let _task_handle = tokio::spawn(
+        async move {
+            let _ = web_server.await;
+            logger::error!("The health check probe stopped working!");
+        }
+        .in_current_span(),
+    );
+
+    logger::debug!(startup_config=?state.conf);
+
+    start_process_tracker(&state, scheduler_flow, (tx, rx)).await?;
+
+    logger::info!("Scheduler shut down");
+    Ok(())
+}
+
+pub async fn start_web_server(
+    state: routes::AppState,
+    service: String,
+) -> errors::ApplicationResult<Server> {
+    let server = state
+        .conf
+        .scheduler
+        .as_ref()
+        .ok_or(ApplicationError::InvalidConfigurationValueError(
+            "Scheduler server is invalidly configured".into(),
+        ))?
+        .server
+        .clone();
+
+    let web_server = actix_web::HttpServer::new(move || {
+        actix_web::App::new().service(Health::server(state.clone(), service.clone()))
+    })
+    .bind((server.host.as_str(), server.port))?
+    .workers(server.workers)
+    .run();
+    let _ = web_server.handle();
+
+    Ok(web_server)
+}
+
+pub struct Health;
+
+impl Health {
+    pub fn server(state: routes::AppState, service: String) -> Scope {
+        web::scope("health")
+            .app_data(web::Data::new(state))
+            .app_data(web::Data::new(service))
+            .service(web::resource("").route(web::get().to(health)))
+            .service(web::resource("/ready").route(web::get().to(deep_health_check)))
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn health() -> impl actix_web::Responder {
+    logger::info!("Scheduler health was called");
+    actix_web::HttpResponse::Ok().body("Scheduler health is good")
+}
+#[instrument(skip_all)]
+pub async fn deep_health_check(
+    state: web::Data<routes::AppState>,
+    service: web::Data<String>,
+) -> impl actix_web::Responder {
+    let report = deep_health_check_func(state, service).await;
+    match report {
+        Ok(response) => services::http_response_json(
+            serde_json::to_string(&response)
+                .map_err(|err| {
+                    logger::error!(serialization_error=?err);
+                })
+                .unwrap_or_default(),
+        ),
+        Err(err) => api::log_and_return_error_response(err),
+    }
+}
+#[instrument(skip_all)]
+pub async fn deep_health_check_func(
+    state: web::Data<routes::AppState>,
+    service: web::Data<String>,
+) -> errors::RouterResult<SchedulerHealthCheckResponse> {
+    logger::info!("{} deep health check was called", service.into_inner());
+
+    logger::debug!("Database health check begin");
+
+    let db_status = state.health_check_db().await.map(|_| true).map_err(|err| {
+        error_stack::report!(errors::ApiErrorResponse::HealthCheckError {
+            component: "Database",
+            message: err.to_string()
+        })
+    })?;
+
+    logger::debug!("Database health check end");
+
+    logger::debug!("Redis health check begin");
+
+    let redis_status = state
+        .health_check_redis()
+        .await
+        .map(|_| true)
+        .map_err(|err| {
+            error_stack::report!(errors::ApiErrorResponse::HealthCheckError {
+                component: "Redis",
+                message: err.to_string()
+            })
+        })?;
+
+    let outgoing_req_check = state
+        .health_check_outgoing()
+        .await
+        .map(|_| true)
+        .map_err(|err| {
+            error_stack::report!(errors::ApiErrorResponse::HealthCheckError {
+                component: "Outgoing Request",
+                message: err.to_string()
+            })
+        })?;
+
+    logger::debug!("Redis health check end");
+
+    let response = SchedulerHealthCheckResponse {
+        database: db_status,
+        redis: redis_status,
+        outgoing_request: outgoing_req_check,
+    };
+
+    Ok(response)
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct WorkflowRunner;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflows<routes::AppState> for WorkflowRunner {
+    async fn trigger_workflow<'a>(
+        &'a self,
+        state: &'a routes::AppState,
+        process: storage::ProcessTracker,
+    ) -> CustomResult<(), ProcessTrackerError> {
+        let runner = process
+            .runner
+            .clone()
+            .get_required_value("runner")
+            .change_context(ProcessTrackerError::MissingRequiredField)
+            .attach_printable("Missing runner field in process information")?;
+        let runner: storage::ProcessTrackerRunner = runner
+            .parse_enum("ProcessTrackerRunner")
+            .change_context(ProcessTrackerError::UnexpectedFlow)
+            .attach_printable("Failed to parse workflow runner name")?;
+
+        let get_operation = |runner: storage::ProcessTrackerRunner| -> CustomResult<
+            Box<dyn ProcessTrackerWorkflow<routes::AppState>>,
+            ProcessTrackerError,
+        > {
+            match runner {
+                storage::ProcessTrackerRunner::PaymentsSyncWorkflow => {
+                    Ok(Box::new(workflows::payment_sync::PaymentsSyncWorkflow))
+                }
+                storage::ProcessTrackerRunner::RefundWorkflowRouter => {
+                    Ok(Box::new(workflows::refund_router::RefundWorkflowRouter))
+                }
+                storage::ProcessTrackerRunner::DeleteTokenizeDataWorkflow => Ok(Box::new(
+                    workflows::tokenized_data::DeleteTokenizeDataWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ApiKeyExpiryWorkflow => {
+                    #[cfg(feature = "email")]
+                    {
+                        Ok(Box::new(workflows::api_key_expiry::ApiKeyExpiryWorkflow))
+                    }
+
+                    #[cfg(not(feature = "email"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run API key expiry workflow when email feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow => Ok(Box::new(
+                    workflows::outgoing_webhook_retry::OutgoingWebhookRetryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::CardAccountUpdaterWorkflow => Ok(Box::new(
+                    workflows::card_account_updater::CardAccountUpdaterWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentDataArchivalWorkflow => Ok(Box::new(
+                    workflows::payment_data_archival::PaymentDataArchivalWorkflow,
+                )),
+                storage::ProcessTrackerRunner::EventOutboxRelayWorkflow => Ok(Box::new(
+                    workflows::event_outbox_relay::EventOutboxRelayWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentStatusSyncWorkflow => Ok(Box::new(
+                    workflows::payment_status_sync::PaymentStatusSyncWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ScheduledCaptureWorkflow => Ok(Box::new(
+                    workflows::scheduled_capture::ScheduledCaptureWorkflow,
+                )),
+                storage::ProcessTrackerRunner::AuthorizationExpiryWorkflow => Ok(Box::new(
+                    workflows::authorization_expiry::AuthorizationExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentExportWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(workflows::payment_export::PaymentExportWorkflow))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payment export workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::AttachPayoutAccountWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(
+                            workflows::attach_payout_account_workflow::AttachPayoutAccountWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(
+                            error_stack::report!(ProcessTrackerError::UnexpectedFlow),
+                        )
+                        .attach_printable(
+                            "Cannot run Stripe external account workflow when payouts feature is disabled",
+                        )
+                    }
+                }
+            }
+        };
+
+        let operation = get_operation(runner)?;
+
+        let app_state = &state.clone();
+        let output = operation.execute_workflow(app_state, process.clone()).await;
+        match output {
+            Ok(_) => operation.success_handler(app_state, process).await,
+            Err(error) => match operation
+                .error_handler(app_state, process.clone(), error)
+                .await
+            {
+                Ok(_) => (),
+                Err(error) => {
+                    logger::error!(%error, "Failed while handling error");
+                    let status = state
+                        .get_db()
+                        .as_scheduler()
+                        .finish_process_with_business_status(process, "GLOBAL_FAILURE".to_string())
+                        .await;
+                    if let Err(err) = status {
+                        logger::error!(%err, "Failed while performing database operation: GLOBAL_FAILURE");
+                    }
+                }
+            },
+        };
+        Ok(())
+    }
+}
+
+async fn start_process_tracker(
+    state: &routes::AppState,
+    scheduler_flow: scheduler::SchedulerFlow,
+    channel: (mpsc::Sender<()>, mpsc::Receiver<()>),
+) -> CustomResult<(), ProcessTrackerError> {
+    let scheduler_settings = state
+        .conf
+        .scheduler
+        .clone()
+        .ok_or(errors::ProcessTrackerError::ConfigurationError)?;
+
+    if state.conf.data_archival.enabled {
+        if let Err(error) =
+            workflows::payment_data_archival::add_payment_data_archival_task(state).await
+        {
+            logger::error!(?error, "Failed to add payment data archival task");
+        }
+    }
+
+    if state.conf.payment_status_sync.enabled {
+        if let Err(error) =
+            workflows::payment_status_sync::add_payment_status_sync_task(state).await
+        {
+            logger::error!(?error, "Failed to add payment status sync task");
+        }
+    }
+
+    // Once added, the relay task keeps running when relaying is disabled again, discarding the
+    // entries recorded in the meantime
+    if state.conf.event_outbox.enabled {
+        if let Err(error) = workflows::event_outbox_relay::add_event_outbox_relay_task(state).await
+        {
+            logger::error!(?error, "Failed to add event outbox relay task");
+        }
+    }
+
+    scheduler::start_process_tracker(
+        state,
+        scheduler_flow,
+        Arc::new(scheduler_settings),
+        channel,
+        WorkflowRunner {},
+    )
+    .await
+}
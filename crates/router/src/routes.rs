@@ -6,6 +6,7 @@ pub mod blocklist;
 pub mod cache;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_capabilities;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -24,6 +25,8 @@ pub mod lock_utils;
 pub mod locker_migration;
 pub mod mandates;
 pub mod metrics;
+#[cfg(feature = "olap")]
+pub mod payment_exports;
 pub mod payment_link;
 pub mod payment_methods;
 pub mod payments;
@@ -58,12 +61,13 @@ pub use self::app::Payouts;
 #[cfg(all(feature = "olap", feature = "recon"))]
 pub use self::app::Recon;
 pub use self::app::{
-    ApiKeys, AppState, BusinessProfile, Cache, Cards, Configs, ConnectorOnboarding, Customers,
-    Disputes, EphemeralKey, Files, Gsm, Health, Mandates, MerchantAccount,
-    MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments, Poll, Refunds, User, Webhooks,
+    ApiKeys, AppState, BusinessProfile, Cache, Cards, Configs, ConnectorCapabilities,
+    ConnectorOnboarding, Customers, Disputes, EphemeralKey, Files, Gsm, Health, Mandates,
+    MerchantAccount, MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments, Poll,
+    Refunds, User, Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Routing, Verify, WebhookEvents};
+pub use self::app::{Blocklist, PaymentExports, Routing, Verify, WebhookEvents};
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
 #[cfg(feature = "olap")]
@@ -197,6 +197,60 @@ pub async fn api_key_revoke(
     )
     .await
 }
+/// API Key - Rotate
+///
+/// Rotate the specified API Key. A new API Key is created with the same name and description,
+/// while the API Key being rotated remains valid for a limited duration, so that it can be
+/// replaced without any downtime. The plaintext API Key will be displayed only once, so ensure
+/// you store it securely.
+#[utoipa::path(
+    post,
+    path = "/api_keys/{merchant_id}/{key_id}/rotate",
+    request_body = RotateApiKeyRequest,
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("key_id" = String, Path, description = "The unique identifier for the API Key")
+    ),
+    responses(
+        (status = 200, description = "API Key rotated", body = CreateApiKeyResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "API Key not found")
+    ),
+    tag = "API Key",
+    operation_id = "Rotate an API Key",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ApiKeyRotate))]
+pub async fn api_key_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_types::RotateApiKeyRequest>,
+) -> impl Responder {
+    let flow = Flow::ApiKeyRotate;
+    let (merchant_id, key_id) = path.into_inner();
+    let mut payload = json_payload.into_inner();
+    payload.key_id = key_id;
+    payload.merchant_id = merchant_id.clone();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| api_keys::rotate_api_key(state, payload),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::ApiKeyWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// API Key - List
 ///
 /// List all API Keys associated with your merchant account.
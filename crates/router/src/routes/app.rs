@@ -21,6 +21,8 @@ use tokio::sync::oneshot;
 use super::blocklist;
 #[cfg(feature = "dummy_connector")]
 use super::dummy_connector::*;
+#[cfg(feature = "olap")]
+use super::payment_exports;
 #[cfg(feature = "payouts")]
 use super::payouts::*;
 #[cfg(feature = "olap")]
@@ -32,7 +34,7 @@ use super::{
     admin::*, api_keys::*, connector_onboarding::*, disputes::*, files::*, gsm::*, payment_link::*,
     user::*, user_role::*, webhook_events::*,
 };
-use super::{cache::*, health::*};
+use super::{cache::*, connector_capabilities::retrieve_connector_capabilities, health::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, mandates::*, payments::*, refunds::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -53,7 +55,7 @@ use crate::routes::verify_connector::payment_connector_verify;
 pub use crate::{
     configs::settings,
     core::routing,
-    db::{StorageImpl, StorageInterface},
+    db::{StorageImpl, StorageInterface, StoreLayer},
     events::EventsHandler,
     routes::cards_info::card_iin_info,
     services::get_store,
@@ -78,6 +80,10 @@ pub struct AppState {
     #[cfg(feature = "olap")]
     pub opensearch_client: OpenSearchClient,
     pub request_id: Option<RequestId>,
+    /// Whether the request was authenticated with a test mode API key
+    pub test_mode: bool,
+    /// The actor that changes made in the request are recorded against in the audit log
+    pub audit_log_actor: crate::core::audit_log::AuditLogActor,
     pub file_storage_client: Box<dyn FileStorageInterface>,
     pub encryption_client: Box<dyn EncryptionManagementInterface>,
 }
@@ -97,6 +103,7 @@ pub trait AppStateInfo {
     fn add_request_id(&mut self, request_id: RequestId);
     fn add_merchant_id(&mut self, merchant_id: Option<String>);
     fn add_flow_name(&mut self, flow_name: String);
+    fn get_flow_name(&self) -> &str;
     fn get_request_id(&self) -> Option<String>;
 }
 
@@ -124,7 +131,11 @@ impl AppStateInfo for AppState {
         self.api_client.add_merchant_id(merchant_id);
     }
     fn add_flow_name(&mut self, flow_name: String) {
-        self.api_client.add_flow_name(flow_name);
+        self.api_client.add_flow_name(flow_name.clone());
+        self.flow_name = flow_name;
+    }
+    fn get_flow_name(&self) -> &str {
+        &self.flow_name
     }
     fn get_request_id(&self) -> Option<String> {
         self.api_client.get_request_id()
@@ -155,6 +166,28 @@ impl AppState {
         storage_impl: StorageImpl,
         shut_down_signal: oneshot::Sender<()>,
         api_client: Box<dyn crate::services::ApiClient>,
+    ) -> Self {
+        Box::pin(Self::with_store_layer(
+            conf,
+            storage_impl,
+            Box::new(|store| store),
+            shut_down_signal,
+            api_client,
+        ))
+        .await
+    }
+
+    /// Same as [`Self::with_storage`], with the configured store wrapped by `store_layer`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if Store can't be created or JWE decryption fails
+    pub async fn with_store_layer(
+        conf: settings::Settings<SecuredSecret>,
+        storage_impl: StorageImpl,
+        store_layer: StoreLayer,
+        shut_down_signal: oneshot::Sender<()>,
+        api_client: Box<dyn crate::services::ApiClient>,
     ) -> Self {
         #[allow(clippy::expect_used)]
         let secret_management_client = conf
@@ -215,6 +248,7 @@ impl AppState {
                         .expect("Failed to create mock store"),
                 ),
             };
+            let store = store_layer(store);
 
             #[cfg(feature = "olap")]
             let pool =
@@ -238,6 +272,8 @@ impl AppState {
                 #[cfg(feature = "olap")]
                 opensearch_client,
                 request_id: None,
+                test_mode: false,
+                audit_log_actor: Default::default(),
                 file_storage_client,
                 encryption_client,
             }
@@ -399,6 +435,14 @@ impl Payments {
                 )
                 .service(
                     web::resource("/{payment_id}/extended_card_info").route(web::get().to(retrieve_extended_card_info)),
+                )
+                .service(
+                    web::resource("/{payment_id}/attempts/{attempt_id}/connector_payloads")
+                        .route(web::get().to(retrieve_connector_payloads)),
+                )
+                .service(
+                    web::resource("/{payment_id}/routing_decisions")
+                        .route(web::get().to(retrieve_routing_decisions)),
                 );
         }
         route
@@ -466,6 +510,9 @@ impl Routing {
                         )
                     })),
             )
+            .service(
+                web::resource("/simulate").route(web::post().to(cloud_routing::routing_simulate)),
+            )
             .service(
                 web::resource("/business_profile/{business_profile_id}/configs/pg_agnostic_mit")
                     .route(web::post().to(cloud_routing::upsert_connector_agnostic_mandate_config)),
@@ -658,6 +705,10 @@ impl Routing {
                         )
                     },
                 )),
+            )
+            .service(
+                web::resource("/{algorithm_id}/volume_split")
+                    .route(web::post().to(cloud_routing::routing_update_volume_split)),
             );
         route
     }
@@ -803,6 +854,10 @@ impl PaymentMethods {
                     web::resource("/{payment_method_id}/save")
                         .route(web::post().to(save_payment_method_api)),
                 )
+                .service(
+                    web::resource("/{payment_method_id}/default")
+                        .route(web::post().to(payment_method_set_default_api)),
+                )
                 .service(
                     web::resource("/auth/link").route(web::post().to(pm_auth::link_token_create)),
                 )
@@ -863,6 +918,7 @@ impl MerchantAccount {
             .app_data(web::Data::new(state))
             .service(web::resource("").route(web::post().to(merchant_account_create)))
             .service(web::resource("/list").route(web::get().to(merchant_account_list)))
+            .service(web::resource("/{id}/audit_logs").route(web::get().to(audit_logs_list)))
             .service(
                 web::resource("/{id}/kv")
                     .route(web::post().to(merchant_account_toggle_kv))
@@ -1025,6 +1081,7 @@ impl ApiKeys {
                     .route(web::post().to(api_key_update))
                     .route(web::delete().to(api_key_revoke)),
             )
+            .service(web::resource("/{key_id}/rotate").route(web::post().to(api_key_rotate)))
     }
 }
 
@@ -1061,6 +1118,19 @@ impl Cards {
     }
 }
 
+pub struct ConnectorCapabilities;
+
+impl ConnectorCapabilities {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connectors")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{connector}/capabilities")
+                    .route(web::get().to(retrieve_connector_capabilities)),
+            )
+    }
+}
+
 pub struct Files;
 
 #[cfg(feature = "olap")]
@@ -1077,6 +1147,27 @@ impl Files {
     }
 }
 
+pub struct PaymentExports;
+
+#[cfg(feature = "olap")]
+impl PaymentExports {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/exports/payments")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("").route(web::post().to(payment_exports::create_payment_export)),
+            )
+            .service(
+                web::resource("/{export_id}")
+                    .route(web::get().to(payment_exports::retrieve_payment_export)),
+            )
+            .service(
+                web::resource("/{export_id}/download")
+                    .route(web::get().to(payment_exports::download_payment_export)),
+            )
+    }
+}
+
 pub struct Cache;
 
 impl Cache {
@@ -1131,6 +1222,21 @@ impl BusinessProfile {
                     .service(
                         web::resource("/toggle_extended_card_info")
                             .route(web::post().to(toggle_extended_card_info)),
+                    )
+                    .service(
+                        web::resource("/webhook_endpoints")
+                            .route(web::post().to(webhook_endpoint_create))
+                            .route(web::get().to(webhook_endpoints_list)),
+                    )
+                    .service(
+                        web::resource("/webhook_endpoints/{endpoint_id}")
+                            .route(web::get().to(webhook_endpoint_retrieve))
+                            .route(web::post().to(webhook_endpoint_update))
+                            .route(web::delete().to(webhook_endpoint_delete)),
+                    )
+                    .service(
+                        web::resource("/webhook_endpoints/{endpoint_id}/test")
+                            .route(web::post().to(webhook_endpoint_test)),
                     ),
             )
     }
@@ -1295,6 +1401,16 @@ impl WebhookEvents {
         web::scope("/events/{merchant_id_or_profile_id}")
             .app_data(web::Data::new(config))
             .service(web::resource("").route(web::get().to(list_initial_webhook_delivery_attempts)))
+            .service(
+                web::scope("/dead_letters")
+                    .service(
+                        web::resource("").route(web::get().to(list_dead_lettered_webhook_events)),
+                    )
+                    .service(
+                        web::resource("requeue")
+                            .route(web::post().to(requeue_dead_lettered_webhook_events)),
+                    ),
+            )
             .service(
                 web::scope("/{event_id}")
                     .service(
@@ -0,0 +1,48 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, connector_capabilities},
+    services::{api, authentication as auth},
+    types::api::enums as api_enums,
+};
+
+/// Connector Capabilities - Retrieve
+///
+/// Retrieve the payment methods, currencies, countries and flows supported by a connector
+#[utoipa::path(
+    get,
+    path = "/connectors/{connector}/capabilities",
+    params(
+        ("connector" = Connector, Path, description = "The name of the connector")
+    ),
+    responses(
+        (status = 200, description = "Connector capabilities retrieved", body = ConnectorCapabilitiesResponse),
+        (status = 400, description = "Invalid connector name")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Retrieve Connector Capabilities",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RetrieveConnectorCapabilities))]
+pub async fn retrieve_connector_capabilities(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<api_enums::Connector>,
+) -> HttpResponse {
+    let flow = Flow::RetrieveConnectorCapabilities;
+    let payload = api_models::connector_capabilities::ConnectorCapabilitiesRequest {
+        connector: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| connector_capabilities::retrieve_connector_capabilities(state, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
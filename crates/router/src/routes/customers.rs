@@ -65,16 +65,26 @@ pub async fn customers_retrieve(
 }
 
 #[instrument(skip_all, fields(flow = ?Flow::CustomersList))]
-pub async fn customers_list(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+pub async fn customers_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<customers::CustomerListConstraints>,
+) -> HttpResponse {
     let flow = Flow::CustomersList;
+    let payload = query.into_inner();
 
     api::server_wrap(
         flow,
         state,
         &req,
-        (),
-        |state, auth, _, _| {
-            list_customers(state, auth.merchant_account.merchant_id, auth.key_store)
+        payload,
+        |state, auth, constraints, _| {
+            list_customers(
+                state,
+                auth.merchant_account.merchant_id,
+                auth.key_store,
+                constraints,
+            )
         },
         auth::auth_type(
             &auth::ApiKeyAuth,
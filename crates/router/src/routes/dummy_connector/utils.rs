@@ -276,6 +276,11 @@ impl types::DummyConnectorCard {
                     message: "Stolen card",
                 }),
             )),
+            // Leaves the payment pending, to exercise sync and webhook driven updates
+            "4000000000000077" => Ok(types::DummyConnectorCardFlow::NoThreeDS(
+                types::DummyConnectorStatus::Processing,
+                None,
+            )),
             "4000003800000446" => Ok(types::DummyConnectorCardFlow::ThreeDS(
                 types::DummyConnectorStatus::Succeeded,
                 None,
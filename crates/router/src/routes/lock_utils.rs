@@ -44,7 +44,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountRetrieve
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
-            | Flow::MerchantAccountList => Self::MerchantAccount,
+            | Flow::MerchantAccountList
+            | Flow::AuditLogList => Self::MerchantAccount,
 
             Flow::RoutingCreateConfig
             | Flow::RoutingLinkConfig
@@ -56,6 +57,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingUpdateConfig
             | Flow::RoutingUpdateDefaultConfig
             | Flow::RoutingDeleteConfig
+            | Flow::RoutingSimulate
+            | Flow::RoutingUpdateVolumeSplit
             | Flow::DecisionManagerDeleteConfig
             | Flow::DecisionManagerRetrieveConfig
             | Flow::DecisionManagerUpsertConfig => Self::Routing,
@@ -121,7 +124,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsIncrementalAuthorization
             | Flow::PaymentsExternalAuthentication
             | Flow::PaymentsAuthorize
-            | Flow::GetExtendedCardInfo => Self::Payments,
+            | Flow::GetExtendedCardInfo
+            | Flow::PaymentsConnectorPayloads
+            | Flow::PaymentsRoutingDecisions
+            | Flow::PaymentExportCreate
+            | Flow::PaymentExportRetrieve
+            | Flow::PaymentExportDownload => Self::Payments,
 
             Flow::PayoutsCreate
             | Flow::PayoutsRetrieve
@@ -142,12 +150,15 @@ impl From<Flow> for ApiIdentifier {
             | Flow::IncomingWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
-            | Flow::WebhookEventDeliveryRetry => Self::Webhooks,
+            | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventDeadLetterList
+            | Flow::WebhookEventDeadLetterRequeue => Self::Webhooks,
 
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
             | Flow::ApiKeyUpdate
             | Flow::ApiKeyRevoke
+            | Flow::ApiKeyRotate
             | Flow::ApiKeyList => Self::ApiKeys,
 
             Flow::DisputesRetrieve
@@ -168,6 +179,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::BusinessProfileRetrieve
             | Flow::BusinessProfileDelete
             | Flow::BusinessProfileList
+            | Flow::WebhookEndpointCreate
+            | Flow::WebhookEndpointList
+            | Flow::WebhookEndpointRetrieve
+            | Flow::WebhookEndpointUpdate
+            | Flow::WebhookEndpointDelete
+            | Flow::WebhookEndpointTest
             | Flow::ToggleExtendedCardInfo => Self::Business,
 
             Flow::PaymentLinkRetrieve
@@ -232,6 +249,8 @@ impl From<Flow> for ApiIdentifier {
             Flow::CreateConnectorAgnosticMandateConfig => Self::Routing,
 
             Flow::RetrievePollStatus => Self::Poll,
+
+            Flow::RetrieveConnectorCapabilities => Self::MerchantConnector,
         }
     }
 }
@@ -47,6 +47,7 @@ counter_metric!(CUSTOMER_REDACTED, GLOBAL_METER);
 
 counter_metric!(API_KEY_CREATED, GLOBAL_METER);
 counter_metric!(API_KEY_REVOKED, GLOBAL_METER);
+counter_metric!(API_KEY_ROTATED, GLOBAL_METER);
 
 counter_metric!(MCA_CREATE, GLOBAL_METER);
 
@@ -118,6 +119,11 @@ counter_metric!(AUTO_PAYOUT_RETRY_GSM_MATCH_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_PAYOUT_RETRY_EXHAUSTED_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_PAYOUT_COUNT, GLOBAL_METER);
 
+// Metrics for the connector circuit breaker
+counter_metric!(CONNECTOR_CIRCUIT_BREAKER_OPENED, GLOBAL_METER);
+counter_metric!(CONNECTOR_CIRCUIT_BREAKER_CLOSED, GLOBAL_METER);
+counter_metric!(CONNECTOR_CIRCUIT_BREAKER_SKIPPED, GLOBAL_METER); // Connectors left out of routing
+
 // Scheduler / Process Tracker related metrics
 counter_metric!(TASKS_ADDED_COUNT, GLOBAL_METER); // Tasks added to process tracker
 counter_metric!(TASK_ADDITION_FAILURES_COUNT, GLOBAL_METER); // Failures in task addition to process tracker
@@ -0,0 +1,127 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::payment_exports as api_payment_exports;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, payment_exports},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// Payment Exports - Create
+///
+/// To request an export of the payments matching a set of filters. The export file is generated
+/// asynchronously; retrieve the export to obtain its download URL once it has succeeded.
+#[utoipa::path(
+    post,
+    path = "/exports/payments",
+    request_body = PaymentExportRequest,
+    responses(
+        (status = 200, description = "Payment export requested", body = PaymentExportResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payment Exports",
+    operation_id = "Create a Payment Export",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentExportCreate))]
+pub async fn create_payment_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_payment_exports::PaymentExportRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentExportCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            payment_exports::create_payment_export(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Exports - Retrieve
+///
+/// To retrieve the status of a payment export, along with a signed download URL once it has
+/// succeeded
+#[utoipa::path(
+    get,
+    path = "/exports/payments/{export_id}",
+    params(
+        ("export_id" = String, Path, description = "The identifier for the payment export")
+    ),
+    responses(
+        (status = 200, description = "Payment export retrieved", body = PaymentExportResponse),
+        (status = 404, description = "Payment export not found")
+    ),
+    tag = "Payment Exports",
+    operation_id = "Retrieve a Payment Export",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentExportRetrieve))]
+pub async fn retrieve_payment_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PaymentExportRetrieve;
+    let payload = api_payment_exports::PaymentExportId {
+        export_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payment_exports::retrieve_payment_export(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct PaymentExportDownloadQuery {
+    token: String,
+}
+
+/// The download URL returned for a payment export is signed, so this route is not authenticated.
+/// When the file storage supports it, the request is redirected to a presigned URL of the file.
+#[instrument(skip_all, fields(flow = ?Flow::PaymentExportDownload))]
+pub async fn download_payment_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<PaymentExportDownloadQuery>,
+) -> HttpResponse {
+    let flow = Flow::PaymentExportDownload;
+    let payload = api_payment_exports::PaymentExportDownloadRequest {
+        export_id: path.into_inner(),
+        token: query.into_inner().token,
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| payment_exports::download_payment_export(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
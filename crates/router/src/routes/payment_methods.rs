@@ -1,5 +1,5 @@
 use actix_web::{web, HttpRequest, HttpResponse};
-use common_utils::{consts::TOKEN_TTL, errors::CustomResult};
+use common_utils::errors::CustomResult;
 use diesel_models::enums::IntentStatus;
 use error_stack::ResultExt;
 use router_env::{instrument, logger, tracing, Flow};
@@ -11,9 +11,9 @@ use crate::{
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::{
         api::payment_methods::{self, PaymentMethodId},
-        storage::payment_method::PaymentTokenData,
+        storage::payment_method::{PaymentTokenData, PaymentTokenRecord, PaymentTokenScope},
     },
-    utils::Encode,
+    utils::{Encode, StringExt},
 };
 
 #[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsCreate))]
@@ -292,13 +292,48 @@ pub async fn payment_method_delete_api(
     let pm = PaymentMethodId {
         payment_method_id: payment_method_id.into_inner().0,
     };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        pm,
+        |state, auth, req, req_state| {
+            cards::delete_payment_method(
+                state,
+                req_state,
+                auth.merchant_account,
+                req,
+                auth.key_store,
+            )
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DefaultPaymentMethodsSet))]
+pub async fn payment_method_set_default_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payment_method_id: web::Path<(String,)>,
+) -> HttpResponse {
+    let flow = Flow::DefaultPaymentMethodsSet;
+    let pm = PaymentMethodId {
+        payment_method_id: payment_method_id.into_inner().0,
+    };
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         pm,
         |state, auth, req, _| {
-            cards::delete_payment_method(state, auth.merchant_account, req, auth.key_store)
+            cards::set_default_payment_method_by_payment_method_id(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req.payment_method_id,
+            )
         },
         &auth::ApiKeyAuth,
         api_locking::LockAction::NotApplicable,
@@ -416,24 +451,28 @@ impl ParentPaymentMethodToken {
         &self,
         intent_created_at: Option<PrimitiveDateTime>,
         token: PaymentTokenData,
+        scope: PaymentTokenScope,
+        payment_id: Option<String>,
         state: &AppState,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
-        let token_json_str = token
-            .encode_to_string_of_json()
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("failed to serialize hyperswitch token to json")?;
+        let token_json_str = PaymentTokenRecord {
+            scope,
+            payment_id,
+            token_data: token,
+        }
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to serialize hyperswitch token to json")?;
         let redis_conn = state
             .store
             .get_redis_conn()
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to get redis connection")?;
-        let current_datetime_utc = common_utils::date_time::now();
-        let time_elapsed = current_datetime_utc - intent_created_at.unwrap_or(current_datetime_utc);
         redis_conn
             .set_key_with_expiry(
                 &self.key_for_token,
                 token_json_str,
-                TOKEN_TTL - time_elapsed.whole_seconds(),
+                scope.get_ttl_in_seconds(intent_created_at, common_utils::date_time::now()),
             )
             .await
             .change_context(errors::StorageError::KVError)
@@ -452,6 +491,33 @@ impl ParentPaymentMethodToken {
         .contains(&status)
     }
 
+    /// Fetch the scope the token was issued with, tokens that are missing or were stored in an
+    /// older format are treated as single use.
+    pub async fn get_scope(
+        &self,
+        state: &AppState,
+    ) -> CustomResult<PaymentTokenScope, errors::ApiErrorResponse> {
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get redis connection")?;
+        let scope = redis_conn
+            .get_key::<Option<String>>(&self.key_for_token)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the token from redis")?
+            .and_then(|token_record| {
+                token_record
+                    .parse_struct::<PaymentTokenRecord>("PaymentTokenRecord")
+                    .ok()
+            })
+            .map(|token_record| token_record.scope)
+            .unwrap_or_default();
+
+        Ok(scope)
+    }
+
     pub async fn delete(&self, state: &AppState) -> CustomResult<(), errors::ApiErrorResponse> {
         let redis_conn = state
             .store
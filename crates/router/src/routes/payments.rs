@@ -1381,6 +1381,79 @@ pub async fn retrieve_extended_card_info(
     .await
 }
 
+/// Retrieve endpoint for merchant to fetch the masked connector request and response payloads of a
+/// payment attempt
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsConnectorPayloads, payment_id))]
+pub async fn retrieve_connector_payloads(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::PaymentsConnectorPayloads;
+    let (payment_id, attempt_id) = path.into_inner();
+    tracing::Span::current().record("payment_id", &payment_id);
+    let payload = payment_types::ConnectorPayloadsRequest {
+        payment_id,
+        attempt_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            payments::connector_payloads::retrieve_connector_payloads(
+                state,
+                auth.merchant_account,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Retrieve endpoint for merchant to fetch the routing decisions made for the attempts of a
+/// payment
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRoutingDecisions, payment_id))]
+pub async fn retrieve_routing_decisions(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsRoutingDecisions;
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", &payment_id);
+    let payload = payment_types::PaymentRoutingDecisionsRequest { payment_id };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            payments::routing_decisions::retrieve_routing_decisions(
+                state,
+                auth.merchant_account,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub fn get_or_generate_payment_id(
     payload: &mut payment_types::PaymentsRequest,
 ) -> errors::RouterResult<()> {
@@ -6,6 +6,8 @@ use crate::{
     routes::AppState,
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::webhook_events::{
+        DeadLetteredEventListConstraints, DeadLetteredEventListRequestInternal,
+        DeadLetteredEventsRequeueRequest, DeadLetteredEventsRequeueRequestInternal,
         EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
         WebhookDeliveryRetryRequestInternal,
     },
@@ -129,3 +131,84 @@ pub async fn retry_webhook_delivery_attempt(
     )
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeadLetterList))]
+pub async fn list_dead_lettered_webhook_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<DeadLetteredEventListConstraints>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventDeadLetterList;
+    let merchant_id_or_profile_id = path.into_inner();
+    let constraints = query.into_inner();
+
+    let request_internal = DeadLetteredEventListRequestInternal {
+        merchant_id_or_profile_id: merchant_id_or_profile_id.clone(),
+        constraints,
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::list_dead_lettered_events(
+                state,
+                request_internal.merchant_id_or_profile_id,
+                request_internal.constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantOrProfileFromRoute {
+                merchant_id_or_profile_id,
+                required_permission: Permission::WebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeadLetterRequeue))]
+pub async fn requeue_dead_lettered_webhook_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<DeadLetteredEventsRequeueRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventDeadLetterRequeue;
+    let merchant_id_or_profile_id = path.into_inner();
+
+    let request_internal = DeadLetteredEventsRequeueRequestInternal {
+        merchant_id_or_profile_id: merchant_id_or_profile_id.clone(),
+        request: json_payload.into_inner(),
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::requeue_dead_lettered_events(
+                state,
+                request_internal.merchant_id_or_profile_id,
+                request_internal.request,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantOrProfileFromRoute {
+                merchant_id_or_profile_id,
+                required_permission: Permission::WebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
@@ -1,5 +1,6 @@
 pub mod client;
 pub mod request;
+pub mod request_signing;
 use std::{
     collections::HashMap,
     error::Error,
@@ -433,6 +434,12 @@ where
                                     match handle_response_result {
                                         Ok(mut data) => {
                                             state.event_handler().log_event(&connector_event);
+                                            payments::connector_payloads::store_connector_payload(
+                                                state,
+                                                connector_event
+                                                    .to_connector_payload(&req.attempt_id),
+                                            )
+                                            .await;
                                             data.connector_http_status_code =
                                                 connector_http_status_code;
                                             // Add up multiple external latencies in case of multiple external calls within the same request.
@@ -449,6 +456,12 @@ where
                                                 .set_error(json!({"error": err.to_string()}));
 
                                             state.event_handler().log_event(&connector_event);
+                                            payments::connector_payloads::store_connector_payload(
+                                                state,
+                                                connector_event
+                                                    .to_connector_payload(&req.attempt_id),
+                                            )
+                                            .await;
                                             Err(err)
                                         }
                                     }?
@@ -477,6 +490,12 @@ where
                                                     Some(&mut connector_event),
                                                 )?;
                                             state.event_handler().log_event(&connector_event);
+                                            payments::connector_payloads::store_connector_payload(
+                                                state,
+                                                connector_event
+                                                    .to_connector_payload(&req.attempt_id),
+                                            )
+                                            .await;
                                             error_res
                                         }
                                         _ => {
@@ -488,6 +507,12 @@ where
                                             if let Some(status) = error_res.attempt_status {
                                                 router_data.status = status;
                                             };
+                                            payments::connector_payloads::store_connector_payload(
+                                                state,
+                                                connector_event
+                                                    .to_connector_payload(&req.attempt_id),
+                                            )
+                                            .await;
                                             error_res
                                         }
                                     };
@@ -502,6 +527,11 @@ where
                         Err(error) => {
                             connector_event.set_error(json!({"error": error.to_string()}));
                             state.event_handler().log_event(&connector_event);
+                            payments::connector_payloads::store_connector_payload(
+                                state,
+                                connector_event.to_connector_payload(&req.attempt_id),
+                            )
+                            .await;
                             if error.current_context().is_upstream_timeout() {
                                 let error_response = ErrorResponse {
                                     code: consts::REQUEST_TIMEOUT_ERROR_CODE.to_string(),
@@ -989,6 +1019,9 @@ where
 
     let mut event_type = payload.get_api_event_type();
 
+    // The flow is required to authorize API keys that have a role assigned
+    app_state.add_flow_name(flow.to_string());
+
     // Currently auth failures are not recorded as API events
     let (auth_out, auth_type) = api_auth
         .authenticate_and_fetch(request.headers(), &app_state)
@@ -1004,7 +1037,9 @@ where
 
     app_state.add_merchant_id(Some(merchant_id.clone()));
 
-    app_state.add_flow_name(flow.to_string());
+    app_state.test_mode = auth_type.is_test_mode();
+
+    app_state.audit_log_actor = auth_type.get_audit_log_actor();
 
     tracing::Span::current().record("merchant_id", &merchant_id);
 
@@ -0,0 +1,382 @@
+use base64::Engine;
+use common_utils::{date_time, request::Method};
+use error_stack::ResultExt;
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use ring::{digest, hmac};
+
+use crate::{
+    consts,
+    core::errors::{self, CustomResult},
+};
+
+/// Time for which JWT client assertions are valid, unless overridden
+const JWT_CLIENT_ASSERTION_VALIDITY_IN_SECS: i64 = 300;
+
+/// Base64 encoded SHA-256 digest of the request body, as sent in the `Digest` header
+pub fn generate_sha256_digest(payload: &[u8]) -> String {
+    consts::BASE64_ENGINE.encode(digest::digest(&digest::SHA256, payload))
+}
+
+/// Builds the `Signature` header of the HTTP Message Signatures scheme with the `HmacSHA256`
+/// algorithm. The signed components are added in the order they must appear in the signing
+/// string, e.g. `host`, `date`, `(request-target)` and `digest`.
+#[derive(Debug)]
+pub struct HttpMessageSignature<'a> {
+    key_id: &'a str,
+    secret: &'a [u8],
+    components: Vec<(&'a str, String)>,
+}
+
+impl<'a> HttpMessageSignature<'a> {
+    pub fn new(key_id: &'a str, secret: &'a [u8]) -> Self {
+        Self {
+            key_id,
+            secret,
+            components: Vec::new(),
+        }
+    }
+
+    pub fn component(mut self, name: &'a str, value: impl Into<String>) -> Self {
+        self.components.push((name, value.into()));
+        self
+    }
+
+    /// Adds the `(request-target)` component, formed by the lowercase http method and the path
+    pub fn request_target(self, http_method: common_utils::request::Method, path: &str) -> Self {
+        let method = http_method.to_string().to_lowercase();
+        self.component("(request-target)", format!("{method} {path}"))
+    }
+
+    pub fn sign(&self) -> String {
+        let headers = self
+            .components
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(" ");
+        let signing_string = self
+            .components
+            .iter()
+            .map(|(name, value)| format!("{name}: {value}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let key = hmac::Key::new(hmac::HMAC_SHA256, self.secret);
+        let signature =
+            consts::BASE64_ENGINE.encode(hmac::sign(&key, signing_string.as_bytes()).as_ref());
+        format!(
+            r#"keyid="{}", algorithm="HmacSHA256", headers="{headers}", signature="{signature}""#,
+            self.key_id
+        )
+    }
+}
+
+/// Serializes the JSON value with the keys of objects sorted and without any whitespace, so that
+/// the signature of a body does not depend on the order in which its fields were serialized
+pub fn canonicalize_json(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_by(|(key1, _), (key2, _)| key1.cmp(key2));
+            let entries = entries
+                .into_iter()
+                .map(|(key, value)| {
+                    format!(
+                        "{}:{}",
+                        serde_json::Value::String(key.to_owned()),
+                        canonicalize_json(value)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            format!("{{{entries}}}")
+        }
+        serde_json::Value::Array(values) => {
+            let values = values
+                .iter()
+                .map(canonicalize_json)
+                .collect::<Vec<_>>()
+                .join(",");
+            format!("[{values}]")
+        }
+        value => value.to_string(),
+    }
+}
+
+/// HMAC-SHA256 of the canonicalized JSON body, for connectors which sign the body of requests
+pub fn hmac_sha256_of_canonicalized_body(secret: &[u8], body: &serde_json::Value) -> Vec<u8> {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret);
+    hmac::sign(&key, canonicalize_json(body).as_bytes())
+        .as_ref()
+        .to_vec()
+}
+
+/// Percent encodes all the characters except the unreserved ones, as required by OAuth 1.0a
+fn oauth1_percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                char::from(byte).to_string()
+            }
+            byte => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// Builds the `Authorization` header of OAuth 1.0a requests with the `HMAC-SHA1` signature method.
+/// The nonce and timestamp are generated, and only need to be set to reproduce a signature.
+#[derive(Debug)]
+pub struct OAuth1Signature<'a> {
+    consumer_key: &'a str,
+    consumer_secret: &'a str,
+    token: Option<(&'a str, &'a str)>,
+    nonce: String,
+    timestamp: i64,
+}
+
+impl<'a> OAuth1Signature<'a> {
+    pub fn new(consumer_key: &'a str, consumer_secret: &'a str) -> Self {
+        Self {
+            consumer_key,
+            consumer_secret,
+            token: None,
+            nonce: uuid::Uuid::new_v4().simple().to_string(),
+            timestamp: date_time::now_unix_timestamp(),
+        }
+    }
+
+    pub fn token(mut self, token: &'a str, token_secret: &'a str) -> Self {
+        self.token = Some((token, token_secret));
+        self
+    }
+
+    pub fn nonce(mut self, nonce: impl Into<String>) -> Self {
+        self.nonce = nonce.into();
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: i64) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Signs the request to the url, which must not contain a query. The query parameters and the
+    /// parameters of form encoded bodies are signed as well, and must be passed as `params`.
+    pub fn sign(&self, http_method: Method, url: &str, params: &[(&str, &str)]) -> String {
+        let timestamp = self.timestamp.to_string();
+        let mut oauth_params = vec![
+            ("oauth_consumer_key", self.consumer_key),
+            ("oauth_nonce", self.nonce.as_str()),
+            ("oauth_signature_method", "HMAC-SHA1"),
+            ("oauth_timestamp", timestamp.as_str()),
+            ("oauth_version", "1.0"),
+        ];
+        if let Some((token, _)) = self.token {
+            oauth_params.push(("oauth_token", token));
+        }
+
+        let mut signed_params = oauth_params
+            .iter()
+            .chain(params)
+            .map(|(name, value)| (oauth1_percent_encode(name), oauth1_percent_encode(value)))
+            .collect::<Vec<_>>();
+        signed_params.sort();
+        let signed_params = signed_params
+            .into_iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join("&");
+        let signature_base_string = format!(
+            "{}&{}&{}",
+            http_method,
+            oauth1_percent_encode(url),
+            oauth1_percent_encode(&signed_params)
+        );
+        let signing_key = format!(
+            "{}&{}",
+            oauth1_percent_encode(self.consumer_secret),
+            oauth1_percent_encode(self.token.map(|(_, secret)| secret).unwrap_or_default())
+        );
+        let key = hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, signing_key.as_bytes());
+        let signature = consts::BASE64_ENGINE
+            .encode(hmac::sign(&key, signature_base_string.as_bytes()).as_ref());
+
+        oauth_params.push(("oauth_signature", signature.as_str()));
+        oauth_params.sort();
+        let header_params = oauth_params
+            .into_iter()
+            .map(|(name, value)| format!(r#"{name}="{}""#, oauth1_percent_encode(value)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("OAuth {header_params}")
+    }
+}
+
+/// Claims of a JWT client assertion (RFC 7523)
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct JwtClientAssertionClaims {
+    pub iss: String,
+    pub sub: String,
+    pub aud: String,
+    pub jti: String,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+/// Builds the JWT with which a client authenticates to the token endpoint of an authorization
+/// server, in place of a client secret
+#[derive(Debug)]
+pub struct JwtClientAssertion<'a> {
+    client_id: &'a str,
+    audience: &'a str,
+    key_id: Option<&'a str>,
+    validity_in_secs: i64,
+}
+
+impl<'a> JwtClientAssertion<'a> {
+    pub fn new(client_id: &'a str, audience: &'a str) -> Self {
+        Self {
+            client_id,
+            audience,
+            key_id: None,
+            validity_in_secs: JWT_CLIENT_ASSERTION_VALIDITY_IN_SECS,
+        }
+    }
+
+    pub fn key_id(mut self, key_id: &'a str) -> Self {
+        self.key_id = Some(key_id);
+        self
+    }
+
+    pub fn validity_in_secs(mut self, validity_in_secs: i64) -> Self {
+        self.validity_in_secs = validity_in_secs;
+        self
+    }
+
+    pub fn sign(
+        &self,
+        algorithm: Algorithm,
+        key: &EncodingKey,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let issued_at = date_time::now_unix_timestamp();
+        let claims = JwtClientAssertionClaims {
+            iss: self.client_id.to_string(),
+            sub: self.client_id.to_string(),
+            aud: self.audience.to_string(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            iat: issued_at,
+            exp: issued_at + self.validity_in_secs,
+        };
+        let header = Header {
+            kid: self.key_id.map(ToString::to_string),
+            ..Header::new(algorithm)
+        };
+        encode(&header, &claims, key).change_context(errors::ConnectorError::RequestEncodingFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_http_message_signature_header() {
+        let signature = HttpMessageSignature::new("key_id", b"secret")
+            .component("host", "apitest.cybersource.com")
+            .request_target(common_utils::request::Method::Post, "/pts/v2/payments/")
+            .component(
+                "digest",
+                format!("SHA-256={}", generate_sha256_digest(b"{}")),
+            )
+            .sign();
+        assert!(signature.starts_with(
+            r#"keyid="key_id", algorithm="HmacSHA256", headers="host (request-target) digest", signature=""#
+        ));
+    }
+
+    #[test]
+    fn test_canonicalized_body_signature() {
+        let body = serde_json::json!({"b": {"f": null, "c": [1, {"f": null, "d": "e"}]}, "a": 1});
+        let reordered_body =
+            serde_json::json!({"a": 1, "b": {"c": [1, {"d": "e", "f": null}], "f": null}});
+        assert_eq!(
+            canonicalize_json(&body),
+            r#"{"a":1,"b":{"c":[1,{"d":"e","f":null}],"f":null}}"#
+        );
+        assert_eq!(
+            hmac_sha256_of_canonicalized_body(b"secret", &body),
+            hmac_sha256_of_canonicalized_body(b"secret", &reordered_body)
+        );
+        assert_eq!(
+            hex::encode(hmac_sha256_of_canonicalized_body(
+                b"secret",
+                &serde_json::json!({"b": {"c": [1, {"f": null, "d": "e"}]}, "a": 1})
+            )),
+            "993a037bb52745bbf89e34a3a8435130460b5a043df588ea8b00d086ebd7ab07"
+        );
+    }
+
+    #[test]
+    fn test_oauth1_signature() {
+        // The example of the Twitter documentation on creating OAuth 1.0a signatures
+        let header = OAuth1Signature::new(
+            "xvz1evFS4wEEPTGEFPHBog",
+            "kAcSOqF21Fu85e7zjz7ZN2U4ZRhfV3WpwPAoE3Z7kBw",
+        )
+        .token(
+            "370773112-GmHxMAgYyLbNEtIKZeRNFsMKPR9EyMZeS9weJAEb",
+            "LswwdoUaIvS8ltyTt5jkRh4J50vUPVVHtR2YPi5kE",
+        )
+        .nonce("kYjzVBB8Y0ZFabxSWbWovY3uYSQ2pTgmZeNu2VS4cg")
+        .timestamp(1318622958)
+        .sign(
+            Method::Post,
+            "https://api.twitter.com/1.1/statuses/update.json",
+            &[
+                ("include_entities", "true"),
+                (
+                    "status",
+                    "Hello Ladies + Gentlemen, a signed OAuth request!",
+                ),
+            ],
+        );
+        assert_eq!(
+            header,
+            "OAuth oauth_consumer_key=\"xvz1evFS4wEEPTGEFPHBog\", \
+             oauth_nonce=\"kYjzVBB8Y0ZFabxSWbWovY3uYSQ2pTgmZeNu2VS4cg\", \
+             oauth_signature=\"hCtSmYh%2BiHYCEqBWrE7C7hYmtUk%3D\", \
+             oauth_signature_method=\"HMAC-SHA1\", oauth_timestamp=\"1318622958\", \
+             oauth_token=\"370773112-GmHxMAgYyLbNEtIKZeRNFsMKPR9EyMZeS9weJAEb\", \
+             oauth_version=\"1.0\""
+        );
+    }
+
+    #[test]
+    fn test_jwt_client_assertion() {
+        let assertion = JwtClientAssertion::new("client_1", "https://auth.example.com/token")
+            .key_id("key_1")
+            .validity_in_secs(60)
+            .sign(Algorithm::HS256, &EncodingKey::from_secret(b"secret"))
+            .unwrap();
+
+        let mut validation = jsonwebtoken::Validation::new(Algorithm::HS256);
+        validation.set_audience(&["https://auth.example.com/token"]);
+        let token = jsonwebtoken::decode::<JwtClientAssertionClaims>(
+            &assertion,
+            &jsonwebtoken::DecodingKey::from_secret(b"secret"),
+            &validation,
+        )
+        .unwrap();
+        assert_eq!(token.header.kid.as_deref(), Some("key_1"));
+        assert_eq!(token.claims.iss, "client_1");
+        assert_eq!(token.claims.sub, "client_1");
+        assert_eq!(token.claims.exp - token.claims.iat, 60);
+        assert!(jsonwebtoken::decode::<JwtClientAssertionClaims>(
+            &assertion,
+            &jsonwebtoken::DecodingKey::from_secret(b"another_secret"),
+            &validation,
+        )
+        .is_err());
+    }
+}
@@ -4,7 +4,7 @@ use api_models::{
     payments,
 };
 use async_trait::async_trait;
-use common_enums::TokenPurpose;
+use common_enums::{AuditLogActorType, TokenPurpose};
 use common_utils::date_time;
 use error_stack::{report, ResultExt};
 use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
@@ -28,7 +28,7 @@ use crate::core::errors::UserResult;
 use crate::routes::AppState;
 use crate::{
     core::{
-        api_keys,
+        api_keys, audit_log,
         errors::{self, utils::StorageErrorExt, RouterResult},
     },
     db::StorageInterface,
@@ -40,6 +40,27 @@ use crate::{
 pub mod blacklist;
 pub mod cookies;
 
+/// The scope of the key provided in the `api-key` header of a request.
+///
+/// Publishable keys are meant to be exposed on the client side and are restricted to client-safe
+/// routes (such as confirming a payment using its `client_secret`, listing payment methods or
+/// creating session tokens), while secret keys can access every route available to the merchant.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeyScope {
+    Publishable,
+    Secret,
+}
+
+impl KeyScope {
+    pub fn from_api_key(api_key: &str) -> Self {
+        if api_key.starts_with(crate::consts::PUBLISHABLE_KEY_PREFIX) {
+            Self::Publishable
+        } else {
+            Self::Secret
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct AuthenticationData {
     pub merchant_account: domain::MerchantAccount,
@@ -56,6 +77,13 @@ pub enum AuthenticationType {
     ApiKey {
         merchant_id: String,
         key_id: String,
+        test_mode: bool,
+    },
+    PlatformApiKey {
+        platform_merchant_id: String,
+        merchant_id: String,
+        key_id: String,
+        test_mode: bool,
     },
     AdminApiKey,
     MerchantJwt {
@@ -98,6 +126,13 @@ impl AuthenticationType {
             Self::ApiKey {
                 merchant_id,
                 key_id: _,
+                test_mode: _,
+            }
+            | Self::PlatformApiKey {
+                platform_merchant_id: _,
+                merchant_id,
+                key_id: _,
+                test_mode: _,
             }
             | Self::MerchantId { merchant_id }
             | Self::PublishableKey { merchant_id }
@@ -112,6 +147,44 @@ impl AuthenticationType {
             | Self::NoAuth => None,
         }
     }
+
+    /// Whether the request was authenticated with a test mode API key, in which case the test
+    /// credentials of the merchant connector accounts are used.
+    pub fn is_test_mode(&self) -> bool {
+        match self {
+            Self::ApiKey { test_mode, .. } | Self::PlatformApiKey { test_mode, .. } => *test_mode,
+            Self::AdminApiKey
+            | Self::MerchantJwt { .. }
+            | Self::UserJwt { .. }
+            | Self::SinglePurposeJWT { .. }
+            | Self::MerchantId { .. }
+            | Self::PublishableKey { .. }
+            | Self::WebhookAuth { .. }
+            | Self::NoAuth => false,
+        }
+    }
+
+    /// The actor that the changes made in the request are attributed to in the audit log.
+    pub fn get_audit_log_actor(&self) -> audit_log::AuditLogActor {
+        let (actor_type, actor_id) = match self {
+            Self::ApiKey { key_id, .. } | Self::PlatformApiKey { key_id, .. } => {
+                (AuditLogActorType::ApiKey, Some(key_id.clone()))
+            }
+            Self::AdminApiKey => (AuditLogActorType::AdminApiKey, None),
+            Self::MerchantJwt { user_id, .. } => (AuditLogActorType::User, user_id.clone()),
+            Self::UserJwt { user_id } | Self::SinglePurposeJWT { user_id, .. } => {
+                (AuditLogActorType::User, Some(user_id.clone()))
+            }
+            Self::MerchantId { .. }
+            | Self::PublishableKey { .. }
+            | Self::WebhookAuth { .. }
+            | Self::NoAuth => (AuditLogActorType::System, None),
+        };
+        audit_log::AuditLogActor {
+            actor_type,
+            actor_id,
+        }
+    }
 }
 
 #[cfg(feature = "olap")]
@@ -247,67 +320,231 @@ where
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
-        let api_key = get_api_key(request_headers)
-            .change_context(errors::ApiErrorResponse::Unauthorized)?
-            .trim();
-        if api_key.is_empty() {
-            return Err(errors::ApiErrorResponse::Unauthorized)
-                .attach_printable("API key is empty");
+        authenticate_api_key(request_headers, state).await
+    }
+}
+
+/// Authenticates the request using the API key in the request headers. API keys that have a role
+/// assigned can only call the flows that their role has the permission for.
+async fn authenticate_api_key<A>(
+    request_headers: &HeaderMap,
+    state: &A,
+) -> RouterResult<(AuthenticationData, AuthenticationType)>
+where
+    A: AppStateInfo + Sync,
+{
+    let api_key = get_api_key(request_headers)
+        .change_context(errors::ApiErrorResponse::Unauthorized)?
+        .trim();
+    if api_key.is_empty() {
+        return Err(errors::ApiErrorResponse::Unauthorized).attach_printable("API key is empty");
+    }
+    if KeyScope::from_api_key(api_key) == KeyScope::Publishable {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("Publishable key cannot be used to access this route");
+    }
+
+    let api_key = api_keys::PlaintextApiKey::from(api_key);
+    let hash_key = {
+        let config = state.conf();
+        config.api_keys.get_inner().get_hash_key()?
+    };
+    let hashed_api_key = api_key.keyed_hash(hash_key.peek());
+
+    let stored_api_key = state
+        .store()
+        .find_api_key_by_hash_optional(hashed_api_key.into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
+        .attach_printable("Merchant not authenticated")?;
+
+    if stored_api_key
+        .expires_at
+        .map(|expires_at| expires_at < date_time::now())
+        .unwrap_or(false)
+    {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("API key has expired");
+    }
+
+    if let Some(ip_allowlist) = stored_api_key
+        .ip_allowlist
+        .as_ref()
+        .filter(|ip_allowlist| !ip_allowlist.is_empty())
+    {
+        let trusted_proxies = state
+            .conf()
+            .api_keys
+            .get_inner()
+            .trusted_proxies
+            .iter()
+            .filter_map(|entry| api_keys::parse_ip_allowlist_entry(entry).ok())
+            .collect::<Vec<_>>();
+        let is_ip_address_allowed = get_client_ip_address(request_headers, &trusted_proxies)
+            .is_some_and(|ip_address| {
+                ip_allowlist
+                    .iter()
+                    .filter_map(|entry| api_keys::parse_ip_allowlist_entry(entry).ok())
+                    .any(|ip_network| ip_network.contains(&ip_address))
+            });
+
+        if !is_ip_address_allowed {
+            return Err(report!(errors::ApiErrorResponse::IpAddressNotAllowed))
+                .attach_printable("Request IP address is not in the API key IP allowlist");
         }
+    }
 
-        let api_key = api_keys::PlaintextApiKey::from(api_key);
-        let hash_key = {
-            let config = state.conf();
-            config.api_keys.get_inner().get_hash_key()?
+    api_keys::record_api_key_usage(
+        state.store(),
+        stored_api_key.merchant_id.clone(),
+        stored_api_key.key_id.clone(),
+    );
+
+    let key_store = state
+        .store()
+        .get_merchant_key_store_by_merchant_id(
+            &stored_api_key.merchant_id,
+            &state.store().get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::Unauthorized)
+        .attach_printable("Failed to fetch merchant key store for the merchant id")?;
+
+    let merchant = state
+        .store()
+        .find_merchant_account_by_merchant_id(&stored_api_key.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+
+    if let Some(role_id) = stored_api_key.role_id.as_deref() {
+        let permissions = authorization::get_permissions_for_api_key(
+            state,
+            role_id,
+            &stored_api_key.merchant_id,
+            &merchant.organization_id,
+        )
+        .await?;
+        authorization::check_api_key_authorization(state.get_flow_name(), &permissions)?;
+    }
+
+    // Platform merchants can act on behalf of their sub-merchants by specifying the
+    // sub-merchant in the `X-Merchant-Id` header
+    let sub_merchant_id =
+        get_header_value_by_key(crate::headers::X_MERCHANT_ID.to_string(), request_headers)?
+            .filter(|sub_merchant_id| *sub_merchant_id != merchant.merchant_id);
+
+    if let Some(sub_merchant_id) = sub_merchant_id {
+        let auth = get_sub_merchant_auth_data(state, &merchant, sub_merchant_id).await?;
+        let auth_type = AuthenticationType::PlatformApiKey {
+            platform_merchant_id: merchant.merchant_id,
+            merchant_id: auth.merchant_account.merchant_id.clone(),
+            key_id: stored_api_key.key_id,
+            test_mode: stored_api_key.test_mode,
         };
-        let hashed_api_key = api_key.keyed_hash(hash_key.peek());
+        return Ok((auth, auth_type));
+    }
 
-        let stored_api_key = state
-            .store()
-            .find_api_key_by_hash_optional(hashed_api_key.into())
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
-            .attach_printable("Failed to retrieve API key")?
-            .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
-            .attach_printable("Merchant not authenticated")?;
-
-        if stored_api_key
-            .expires_at
-            .map(|expires_at| expires_at < date_time::now())
-            .unwrap_or(false)
-        {
-            return Err(report!(errors::ApiErrorResponse::Unauthorized))
-                .attach_printable("API key has expired");
+    let auth = AuthenticationData {
+        merchant_account: merchant,
+        key_store,
+    };
+    let auth_type = AuthenticationType::ApiKey {
+        merchant_id: auth.merchant_account.merchant_id.clone(),
+        key_id: stored_api_key.key_id,
+        test_mode: stored_api_key.test_mode,
+    };
+    Ok((auth, auth_type))
+}
+
+/// Obtains the IP address of the client, starting from the address of the peer the request was
+/// received from. Only when that peer is one of the trusted proxies is the `X-Forwarded-For`
+/// header consulted, walking it from the right and skipping the addresses of trusted proxies, so
+/// that the entries prepended by the client are never used.
+fn get_client_ip_address(
+    headers: &HeaderMap,
+    trusted_proxies: &[ipnet::IpNet],
+) -> Option<std::net::IpAddr> {
+    let is_trusted_proxy = |ip_address: &std::net::IpAddr| {
+        trusted_proxies
+            .iter()
+            .any(|ip_network| ip_network.contains(ip_address))
+    };
+
+    let mut client_ip_address = headers
+        .get(crate::headers::X_PEER_ADDRESS)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|ip_address| ip_address.trim().parse().ok())?;
+
+    let forwarded_ip_addresses = headers
+        .get(crate::headers::X_FORWARDED_FOR)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    for ip_address in forwarded_ip_addresses
+        .split(',')
+        .map(str::trim)
+        .filter(|ip_address| !ip_address.is_empty())
+        .rev()
+    {
+        if !is_trusted_proxy(&client_ip_address) {
+            break;
         }
+        client_ip_address = ip_address.parse().ok()?;
+    }
 
-        let key_store = state
-            .store()
-            .get_merchant_key_store_by_merchant_id(
-                &stored_api_key.merchant_id,
-                &state.store().get_master_key().to_vec().into(),
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::Unauthorized)
-            .attach_printable("Failed to fetch merchant key store for the merchant id")?;
+    Some(client_ip_address)
+}
 
-        let merchant = state
-            .store()
-            .find_merchant_account_by_merchant_id(&stored_api_key.merchant_id, &key_store)
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+/// Fetches the merchant account of a sub-merchant on whose behalf the platform merchant is
+/// acting. The sub-merchant must have sub-merchants enabled, have the platform merchant as its
+/// parent and belong to the same organization.
+async fn get_sub_merchant_auth_data<A>(
+    state: &A,
+    platform_merchant_account: &domain::MerchantAccount,
+    sub_merchant_id: &str,
+) -> RouterResult<AuthenticationData>
+where
+    A: AppStateInfo + Sync,
+{
+    let access_forbidden = || errors::ApiErrorResponse::AccessForbidden {
+        resource: sub_merchant_id.to_string(),
+    };
 
-        let auth = AuthenticationData {
-            merchant_account: merchant,
-            key_store,
-        };
-        Ok((
-            auth.clone(),
-            AuthenticationType::ApiKey {
-                merchant_id: auth.merchant_account.merchant_id.clone(),
-                key_id: stored_api_key.key_id,
-            },
-        ))
+    let key_store = state
+        .store()
+        .get_merchant_key_store_by_merchant_id(
+            sub_merchant_id,
+            &state.store().get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context_lazy(access_forbidden)
+        .attach_printable("Failed to fetch merchant key store for the sub-merchant")?;
+
+    let sub_merchant_account = state
+        .store()
+        .find_merchant_account_by_merchant_id(sub_merchant_id, &key_store)
+        .await
+        .change_context_lazy(access_forbidden)
+        .attach_printable("Failed to fetch merchant account for the sub-merchant")?;
+
+    let is_sub_merchant_of_platform = sub_merchant_account.sub_merchants_enabled == Some(true)
+        && sub_merchant_account.parent_merchant_id.as_deref()
+            == Some(platform_merchant_account.merchant_id.as_str())
+        && sub_merchant_account.organization_id == platform_merchant_account.organization_id;
+
+    if !is_sub_merchant_of_platform {
+        return Err(report!(access_forbidden())).attach_printable(format!(
+            "Merchant {} is not a platform merchant of {sub_merchant_id}",
+            platform_merchant_account.merchant_id
+        ));
     }
+
+    Ok(AuthenticationData {
+        merchant_account: sub_merchant_account,
+        key_store,
+    })
 }
 
 #[cfg(feature = "olap")]
@@ -444,6 +681,10 @@ where
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
         let publishable_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
+        if KeyScope::from_api_key(publishable_key) != KeyScope::Publishable {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("Only publishable keys can be used to access this route");
+        }
 
         state
             .store()
@@ -914,7 +1155,7 @@ pub fn get_auth_type_and_flow<A: AppStateInfo + Sync>(
 )> {
     let api_key = get_api_key(headers)?;
 
-    if api_key.starts_with("pk_") {
+    if KeyScope::from_api_key(api_key) == KeyScope::Publishable {
         return Ok((Box::new(PublishableKeyAuth), api::AuthFlow::Client));
     }
     Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant))
@@ -934,7 +1175,7 @@ where
 {
     let api_key = get_api_key(headers)?;
 
-    if api_key.starts_with("pk_") {
+    if KeyScope::from_api_key(api_key) == KeyScope::Publishable {
         payload
             .get_client_secret()
             .check_value_present("client_secret")
@@ -996,6 +1237,28 @@ where
         .change_context(errors::ApiErrorResponse::InvalidJwtToken)
 }
 
+/// Decodes a JWT that was issued for the given audience. Tokens without the audience are
+/// rejected, and tokens with an audience cannot be decoded using [`decode_jwt`].
+pub async fn decode_jwt_with_audience<T>(
+    token: &str,
+    state: &impl AppStateInfo,
+    audience: &str,
+) -> RouterResult<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let conf = state.conf();
+    let secret = conf.secrets.get_inner().jwt_secret.peek().as_bytes();
+
+    let key = DecodingKey::from_secret(secret);
+    let mut validation = Validation::new(Algorithm::HS256);
+    validation.set_audience(&[audience]);
+    validation.set_required_spec_claims(&["exp", "aud"]);
+    decode::<T>(token, &key, &validation)
+        .map(|decoded| decoded.claims)
+        .change_context(errors::ApiErrorResponse::InvalidJwtToken)
+}
+
 pub fn get_api_key(headers: &HeaderMap) -> RouterResult<&str> {
     get_header_value_by_key("api-key".into(), headers)?.get_required_value("api_key")
 }
@@ -1111,3 +1374,58 @@ impl AuthenticateAndFetch<ReconUser, AppState> for ReconJWT {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use actix_web::http::header::{HeaderName, HeaderValue};
+
+    use super::*;
+
+    fn headers(peer_address: &str, forwarded_for: Option<&str>) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static("x-peer-address"),
+            HeaderValue::from_str(peer_address).unwrap(),
+        );
+        if let Some(forwarded_for) = forwarded_for {
+            headers.insert(
+                HeaderName::from_static("x-forwarded-for"),
+                HeaderValue::from_str(forwarded_for).unwrap(),
+            );
+        }
+        headers
+    }
+
+    #[test]
+    fn test_client_ip_address_ignores_forwarded_for_from_untrusted_peer() {
+        let trusted_proxies = vec!["10.0.0.0/8".parse().unwrap()];
+        let headers = headers("203.0.113.7", Some("198.51.100.1"));
+
+        assert_eq!(
+            get_client_ip_address(&headers, &trusted_proxies),
+            Some("203.0.113.7".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_client_ip_address_skips_trusted_proxies() {
+        let trusted_proxies = vec!["10.0.0.0/8".parse().unwrap()];
+        let headers = headers("10.0.0.2", Some("198.51.100.1, 203.0.113.7, 10.0.0.1"));
+
+        assert_eq!(
+            get_client_ip_address(&headers, &trusted_proxies),
+            Some("203.0.113.7".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_client_ip_address_without_trusted_proxies_uses_peer_address() {
+        let headers = headers("10.0.0.2", Some("198.51.100.1"));
+
+        assert_eq!(
+            get_client_ip_address(&headers, &[]),
+            Some("10.0.0.2".parse().unwrap())
+        );
+    }
+}
@@ -1,9 +1,9 @@
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
 
 use common_enums::PermissionGroup;
 use error_stack::ResultExt;
 use redis_interface::RedisConnectionPool;
-use router_env::logger;
+use router_env::{logger, Flow};
 
 use super::authentication::AuthToken;
 use crate::{
@@ -92,6 +92,29 @@ where
         .to_not_found_response(ApiErrorResponse::InvalidJwtToken)
 }
 
+/// Obtains the permissions of the role assigned to an API key. The role is either a predefined
+/// role or a custom role in the scope of the merchant that owns the API key.
+pub async fn get_permissions_for_api_key<A>(
+    state: &A,
+    role_id: &str,
+    merchant_id: &str,
+    org_id: &str,
+) -> RouterResult<Vec<permissions::Permission>>
+where
+    A: AppStateInfo + Sync,
+{
+    if let Some(permissions) = get_permissions_from_predefined_roles(role_id) {
+        return Ok(permissions);
+    }
+
+    state
+        .store()
+        .find_role_by_role_id_in_merchant_scope(role_id, merchant_id, org_id)
+        .await
+        .map(|role| get_permissions_from_groups(&role.groups))
+        .to_not_found_response(ApiErrorResponse::Unauthorized)
+}
+
 pub async fn set_permissions_in_cache<A>(
     state: &A,
     role_id: &str,
@@ -139,6 +162,23 @@ pub fn check_authorization(
         )
 }
 
+/// Checks that an API key with a role assigned has the permission required by the flow being
+/// called. Flows that do not have a permission for API keys are forbidden.
+pub fn check_api_key_authorization(
+    flow_name: &str,
+    permissions: &[permissions::Permission],
+) -> RouterResult<()> {
+    let required_permission = Flow::from_str(flow_name)
+        .ok()
+        .as_ref()
+        .and_then(permissions::get_permission_for_api_key_flow)
+        .ok_or(ApiErrorResponse::AccessForbidden {
+            resource: flow_name.to_string(),
+        })?;
+
+    check_authorization(&required_permission, permissions)
+}
+
 fn get_redis_connection<A: AppStateInfo>(state: &A) -> RouterResult<Arc<RedisConnectionPool>> {
     state
         .store()
@@ -146,3 +186,84 @@ fn get_redis_connection<A: AppStateInfo>(state: &A) -> RouterResult<Arc<RedisCon
         .change_context(ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to get redis connection")
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_predefined_role_permissions(role_id: &str) -> Vec<permissions::Permission> {
+        get_permissions_from_predefined_roles(role_id).unwrap()
+    }
+
+    #[test]
+    fn test_api_key_with_role_can_call_permitted_flows() {
+        let view_only_permissions =
+            get_predefined_role_permissions(consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY);
+        let operator_permissions =
+            get_predefined_role_permissions(consts::user_role::ROLE_ID_MERCHANT_OPERATOR);
+
+        assert!(check_api_key_authorization(
+            &Flow::RefundsRetrieve.to_string(),
+            &view_only_permissions
+        )
+        .is_ok());
+        assert!(check_api_key_authorization(
+            &Flow::PayoutsList.to_string(),
+            &view_only_permissions
+        )
+        .is_ok());
+        assert!(check_api_key_authorization(
+            &Flow::RefundsCreate.to_string(),
+            &operator_permissions
+        )
+        .is_ok());
+        assert!(check_api_key_authorization(
+            &Flow::PayoutsCreate.to_string(),
+            &operator_permissions
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_api_key_with_role_cannot_call_flows_outside_its_role() {
+        let view_only_permissions =
+            get_predefined_role_permissions(consts::user_role::ROLE_ID_MERCHANT_VIEW_ONLY);
+        let operator_permissions =
+            get_predefined_role_permissions(consts::user_role::ROLE_ID_MERCHANT_OPERATOR);
+
+        assert!(check_api_key_authorization(
+            &Flow::RefundsCreate.to_string(),
+            &view_only_permissions
+        )
+        .is_err());
+        assert!(check_api_key_authorization(
+            &Flow::RefundsUpdate.to_string(),
+            &view_only_permissions
+        )
+        .is_err());
+        assert!(check_api_key_authorization(
+            &Flow::PayoutsCreate.to_string(),
+            &view_only_permissions
+        )
+        .is_err());
+        assert!(check_api_key_authorization(
+            &Flow::RoutingLinkConfig.to_string(),
+            &operator_permissions
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_api_key_with_role_cannot_call_flows_without_permission() {
+        let admin_permissions =
+            get_predefined_role_permissions(consts::user_role::ROLE_ID_MERCHANT_ADMIN);
+
+        assert!(check_api_key_authorization(
+            &Flow::ConfigKeyCreate.to_string(),
+            &admin_permissions
+        )
+        .is_err());
+        assert!(check_api_key_authorization("UnknownFlow", &admin_permissions).is_err());
+    }
+}
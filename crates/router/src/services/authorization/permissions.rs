@@ -1,3 +1,4 @@
+use router_env::Flow;
 use strum::Display;
 
 #[derive(
@@ -78,3 +79,221 @@ impl Permission {
         }
     }
 }
+
+/// The permission that an API key with a role assigned requires to call a flow. Flows without a
+/// permission are not meant to be called with merchant API keys, and API keys with a role assigned
+/// cannot call them.
+pub fn get_permission_for_api_key_flow(flow: &Flow) -> Option<Permission> {
+    match flow {
+        Flow::PaymentsCreate
+        | Flow::PaymentsUpdate
+        | Flow::PaymentsConfirm
+        | Flow::PaymentsCapture
+        | Flow::PaymentsCancel
+        | Flow::PaymentsApprove
+        | Flow::PaymentsReject
+        | Flow::PaymentsSessionToken
+        | Flow::PaymentsStart
+        | Flow::PaymentsRedirect
+        | Flow::PaymentsIncrementalAuthorization
+        | Flow::PaymentsExternalAuthentication
+        | Flow::PaymentsAuthorize
+        | Flow::PaymentExportCreate => Some(Permission::PaymentWrite),
+
+        Flow::PaymentsRetrieve
+        | Flow::PaymentsRetrieveForceSync
+        | Flow::PaymentsList
+        | Flow::PaymentsFilters
+        | Flow::GetExtendedCardInfo
+        | Flow::PaymentsConnectorPayloads
+        | Flow::PaymentsRoutingDecisions
+        | Flow::PaymentExportRetrieve
+        | Flow::PaymentExportDownload
+        | Flow::RetrievePollStatus
+        | Flow::PaymentLinkRetrieve
+        | Flow::PaymentLinkList
+        | Flow::CardsInfo
+        | Flow::RetrieveForexFlow => Some(Permission::PaymentRead),
+
+        Flow::RefundsCreate | Flow::RefundsUpdate => Some(Permission::RefundWrite),
+
+        Flow::RefundsRetrieve | Flow::RefundsRetrieveForceSync | Flow::RefundsList => {
+            Some(Permission::RefundRead)
+        }
+
+        Flow::PayoutsCreate | Flow::PayoutsUpdate | Flow::PayoutsCancel | Flow::PayoutsFulfill => {
+            Some(Permission::PayoutWrite)
+        }
+
+        Flow::PayoutsRetrieve | Flow::PayoutsList | Flow::PayoutsFilter | Flow::PayoutsAccounts => {
+            Some(Permission::PayoutRead)
+        }
+
+        Flow::CustomersCreate
+        | Flow::CustomersUpdate
+        | Flow::CustomersDelete
+        | Flow::EphemeralKeyCreate
+        | Flow::EphemeralKeyDelete
+        | Flow::PaymentMethodsCreate
+        | Flow::PaymentMethodsUpdate
+        | Flow::PaymentMethodsDelete
+        | Flow::PaymentMethodSave
+        | Flow::DefaultPaymentMethodsSet
+        | Flow::ValidatePaymentMethod => Some(Permission::CustomerWrite),
+
+        Flow::CustomersRetrieve
+        | Flow::CustomersList
+        | Flow::CustomerPaymentMethodsList
+        | Flow::PaymentMethodsList
+        | Flow::PaymentMethodsRetrieve => Some(Permission::CustomerRead),
+
+        Flow::MandatesRevoke => Some(Permission::MandateWrite),
+
+        Flow::MandatesRetrieve | Flow::MandatesList | Flow::CustomersGetMandates => {
+            Some(Permission::MandateRead)
+        }
+
+        Flow::DisputesEvidenceSubmit
+        | Flow::AttachDisputeEvidence
+        | Flow::DeleteDisputeEvidence
+        | Flow::CreateFile
+        | Flow::DeleteFile => Some(Permission::DisputeWrite),
+
+        Flow::DisputesRetrieve
+        | Flow::DisputesList
+        | Flow::RetrieveDisputeEvidence
+        | Flow::RetrieveFile => Some(Permission::DisputeRead),
+
+        Flow::RoutingCreateConfig
+        | Flow::RoutingLinkConfig
+        | Flow::RoutingUnlinkConfig
+        | Flow::RoutingUpdateConfig
+        | Flow::RoutingUpdateDefaultConfig
+        | Flow::RoutingDeleteConfig
+        | Flow::RoutingUpdateVolumeSplit
+        | Flow::CreateConnectorAgnosticMandateConfig => Some(Permission::RoutingWrite),
+
+        Flow::RoutingRetrieveConfig
+        | Flow::RoutingRetrieveActiveConfig
+        | Flow::RoutingRetrieveDefaultConfig
+        | Flow::RoutingRetrieveDictionary
+        | Flow::RoutingSimulate => Some(Permission::RoutingRead),
+
+        Flow::DecisionManagerUpsertConfig | Flow::DecisionManagerDeleteConfig => {
+            Some(Permission::SurchargeDecisionManagerWrite)
+        }
+
+        Flow::DecisionManagerRetrieveConfig => Some(Permission::SurchargeDecisionManagerRead),
+
+        Flow::MerchantsAccountUpdate
+        | Flow::AddToBlocklist
+        | Flow::DeleteFromBlocklist
+        | Flow::ToggleBlocklistGuard
+        | Flow::BusinessProfileCreate
+        | Flow::BusinessProfileUpdate
+        | Flow::BusinessProfileDelete
+        | Flow::WebhookEndpointCreate
+        | Flow::WebhookEndpointUpdate
+        | Flow::WebhookEndpointDelete
+        | Flow::WebhookEndpointTest
+        | Flow::ToggleExtendedCardInfo
+        | Flow::Verification => Some(Permission::MerchantAccountWrite),
+
+        Flow::MerchantsAccountRetrieve
+        | Flow::ListBlocklist
+        | Flow::BusinessProfileRetrieve
+        | Flow::BusinessProfileList
+        | Flow::WebhookEndpointList
+        | Flow::WebhookEndpointRetrieve
+        | Flow::AuditLogList => Some(Permission::MerchantAccountRead),
+
+        Flow::MerchantConnectorsCreate
+        | Flow::MerchantConnectorsUpdate
+        | Flow::MerchantConnectorsDelete => Some(Permission::MerchantConnectorAccountWrite),
+
+        Flow::MerchantConnectorsRetrieve
+        | Flow::MerchantConnectorsList
+        | Flow::ListCountriesCurrencies
+        | Flow::RetrieveConnectorCapabilities => Some(Permission::MerchantConnectorAccountRead),
+
+        Flow::ApiKeyCreate | Flow::ApiKeyUpdate | Flow::ApiKeyRevoke | Flow::ApiKeyRotate => {
+            Some(Permission::ApiKeyWrite)
+        }
+
+        Flow::ApiKeyRetrieve | Flow::ApiKeyList => Some(Permission::ApiKeyRead),
+
+        Flow::WebhookEventDeliveryRetry | Flow::WebhookEventDeadLetterRequeue => {
+            Some(Permission::WebhookEventWrite)
+        }
+
+        Flow::WebhookEventInitialDeliveryAttemptList
+        | Flow::WebhookEventDeliveryAttemptList
+        | Flow::WebhookEventDeadLetterList => Some(Permission::WebhookEventRead),
+
+        Flow::HealthCheck
+        | Flow::DeepHealthCheck
+        | Flow::MerchantsAccountCreate
+        | Flow::MerchantsAccountDelete
+        | Flow::MerchantAccountList
+        | Flow::ConfigKeyCreate
+        | Flow::ConfigKeyFetch
+        | Flow::ConfigKeyUpdate
+        | Flow::ConfigKeyDelete
+        | Flow::CreateConfigKey
+        | Flow::CacheInvalidate
+        | Flow::IncomingWebhookReceive
+        | Flow::FrmFulfillment
+        | Flow::PaymentLinkInitiate
+        | Flow::PaymentLinkStatus
+        | Flow::PmAuthLinkTokenCreate
+        | Flow::PmAuthExchangeToken
+        | Flow::ReconMerchantUpdate
+        | Flow::ReconTokenRequest
+        | Flow::ReconServiceRequest
+        | Flow::ReconVerifyToken
+        | Flow::RustLockerMigration
+        | Flow::GsmRuleCreate
+        | Flow::GsmRuleRetrieve
+        | Flow::GsmRuleUpdate
+        | Flow::GsmRuleDelete
+        | Flow::UserSignUp
+        | Flow::UserSignUpWithMerchantId
+        | Flow::UserSignIn
+        | Flow::UserConnectAccount
+        | Flow::ChangePassword
+        | Flow::Signout
+        | Flow::SetDashboardMetadata
+        | Flow::GetMultipleDashboardMetadata
+        | Flow::VerifyPaymentConnector
+        | Flow::InternalUserSignup
+        | Flow::SwitchMerchant
+        | Flow::GetAuthorizationInfo
+        | Flow::ListRoles
+        | Flow::GetRole
+        | Flow::GetRoleFromToken
+        | Flow::UpdateUserRole
+        | Flow::TransferOrgOwnership
+        | Flow::UserMerchantAccountCreate
+        | Flow::GenerateSampleData
+        | Flow::DeleteSampleData
+        | Flow::UserMerchantAccountList
+        | Flow::GetUserDetails
+        | Flow::GetUserRoleDetails
+        | Flow::ListUsersForMerchantAccount
+        | Flow::ForgotPassword
+        | Flow::ResetPassword
+        | Flow::InviteMultipleUser
+        | Flow::ReInviteUser
+        | Flow::AcceptInviteFromEmail
+        | Flow::DeleteUserRole
+        | Flow::GetActionUrl
+        | Flow::SyncOnboardingStatus
+        | Flow::ResetTrackingId
+        | Flow::VerifyEmail
+        | Flow::VerifyEmailRequest
+        | Flow::UpdateUserAccountDetails
+        | Flow::AcceptInvitation
+        | Flow::CreateRole
+        | Flow::UpdateRole => None,
+    }
+}
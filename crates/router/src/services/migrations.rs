@@ -0,0 +1,368 @@
+//! Runs the pending database migrations when the router starts.
+//!
+//! Migrations are read from a directory laid out the way the Diesel CLI expects, and the applied
+//! versions are tracked in the same `__diesel_schema_migrations` table, so that the runner and the
+//! CLI can be used interchangeably on the same database. As with the Diesel CLI, a migration is run
+//! outside of a transaction when its `metadata.toml` sets `run_in_transaction = false`.
+
+use std::path::Path;
+
+use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl, AsyncSimpleConnection};
+use diesel::sql_types;
+use diesel_models::PgPooledConn;
+use storage_impl::database::store::diesel_make_pg_pool;
+
+use crate::{
+    configs::settings,
+    core::errors::{ApplicationError, ApplicationResult},
+    logger,
+};
+
+/// Key of the advisory lock held while migrating, so that routers starting together do not race on
+/// schema changes.
+const MIGRATIONS_LOCK_KEY: i64 = 0x6879_7065_7273_7769;
+
+/// Statements which drop or delete data, a migration containing any of them is run only if
+/// destructive migrations are explicitly allowed.
+const DESTRUCTIVE_STATEMENTS: [&str; 9] = [
+    "DROP TABLE",
+    "DROP COLUMN",
+    "DROP CONSTRAINT",
+    "DROP INDEX",
+    "DROP SCHEMA",
+    "DROP TYPE",
+    "DROP DATABASE",
+    "TRUNCATE",
+    "DELETE FROM",
+];
+
+#[derive(Debug)]
+struct Migration {
+    name: String,
+    version: String,
+    up_sql: String,
+    run_in_transaction: bool,
+}
+
+/// The `metadata.toml` of a migration, as read by the Diesel CLI
+#[derive(Debug, serde::Deserialize)]
+#[serde(default)]
+struct MigrationMetadata {
+    run_in_transaction: bool,
+}
+
+impl Default for MigrationMetadata {
+    fn default() -> Self {
+        Self {
+            run_in_transaction: true,
+        }
+    }
+}
+
+impl Migration {
+    fn is_destructive(&self) -> bool {
+        let statements = self
+            .up_sql
+            .lines()
+            .map(|line| line.split("--").next().unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_uppercase()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        DESTRUCTIVE_STATEMENTS
+            .iter()
+            .any(|statement| statements.contains(statement))
+    }
+}
+
+#[derive(diesel::QueryableByName)]
+struct AppliedMigration {
+    #[diesel(sql_type = sql_types::Text)]
+    version: String,
+}
+
+/// Runs the migrations which have not been applied to the master database yet, in order of their
+/// version. Each migration is run in its own transaction, unless it opts out in its metadata.
+pub async fn run_pending_migrations(
+    migrations: &settings::Migrations,
+    database: &settings::Database,
+) -> ApplicationResult<()> {
+    let available_migrations = read_migrations(&migrations.directory)?;
+
+    // Migrations run on a dedicated connection, without the statement timeout configured for the
+    // application pools, as schema changes on large tables can take much longer
+    let database = storage_impl::config::Database {
+        pool_size: 1,
+        statement_timeout: None,
+        ..database.clone().into()
+    };
+    let pool = diesel_make_pg_pool(&database, false)
+        .await
+        .map_err(|error| {
+            ApplicationError::MigrationError(format!("failed to create database pool: {error:?}"))
+        })?;
+    let conn = pool.get().await.map_err(|error| {
+        ApplicationError::MigrationError(format!("failed to get database connection: {error:?}"))
+    })?;
+
+    // Advisory locks are held by the session, the same connection must be used until it is released
+    logger::info!("Acquiring the database migrations lock");
+    diesel::sql_query("SELECT pg_advisory_lock($1)")
+        .bind::<sql_types::BigInt, _>(MIGRATIONS_LOCK_KEY)
+        .execute_async(&conn)
+        .await
+        .map_err(|error| {
+            ApplicationError::MigrationError(format!(
+                "failed to acquire migrations lock: {error:?}"
+            ))
+        })?;
+
+    let result = run_migrations_with_lock_held(&conn, migrations, available_migrations).await;
+
+    if let Err(error) = diesel::sql_query("SELECT pg_advisory_unlock($1)")
+        .bind::<sql_types::BigInt, _>(MIGRATIONS_LOCK_KEY)
+        .execute_async(&conn)
+        .await
+    {
+        logger::error!(?error, "Failed to release the database migrations lock");
+    }
+
+    result
+}
+
+async fn run_migrations_with_lock_held(
+    conn: &PgPooledConn,
+    migrations: &settings::Migrations,
+    available_migrations: Vec<Migration>,
+) -> ApplicationResult<()> {
+    conn.batch_execute_async(
+        "CREATE TABLE IF NOT EXISTS __diesel_schema_migrations (
+            version VARCHAR(50) PRIMARY KEY NOT NULL,
+            run_on TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )",
+    )
+    .await
+    .map_err(|error| {
+        ApplicationError::MigrationError(format!(
+            "failed to create migrations tracking table: {error:?}"
+        ))
+    })?;
+
+    let applied_versions = diesel::sql_query("SELECT version FROM __diesel_schema_migrations")
+        .load_async::<AppliedMigration>(conn)
+        .await
+        .map_err(|error| {
+            ApplicationError::MigrationError(format!(
+                "failed to fetch applied migrations: {error:?}"
+            ))
+        })?
+        .into_iter()
+        .map(|applied_migration| applied_migration.version)
+        .collect::<std::collections::HashSet<_>>();
+
+    let pending_migrations = available_migrations
+        .into_iter()
+        .filter(|migration| !applied_versions.contains(&migration.version))
+        .collect::<Vec<_>>();
+
+    if pending_migrations.is_empty() {
+        logger::info!("Database schema is up to date, no pending migrations");
+        return Ok(());
+    }
+
+    if migrations.dry_run {
+        for migration in &pending_migrations {
+            logger::info!(
+                destructive = migration.is_destructive(),
+                "Pending migration: {}",
+                migration.name
+            );
+        }
+        logger::info!(
+            "Dry run enabled, not running {} pending migrations",
+            pending_migrations.len()
+        );
+        return Ok(());
+    }
+
+    let destructive_migrations = pending_migrations
+        .iter()
+        .filter(|migration| migration.is_destructive())
+        .map(|migration| migration.name.as_str())
+        .collect::<Vec<_>>();
+    if !migrations.allow_destructive && !destructive_migrations.is_empty() {
+        return Err(ApplicationError::MigrationError(format!(
+            "refusing to run destructive migrations without `allow_destructive`: {}",
+            destructive_migrations.join(", ")
+        )));
+    }
+
+    for migration in pending_migrations {
+        logger::info!("Running migration: {}", migration.name);
+        let Migration {
+            name,
+            version,
+            up_sql,
+            run_in_transaction,
+        } = migration;
+
+        let result = if run_in_transaction {
+            conn.transaction_async(
+                |conn| async move { apply_migration(&conn, &up_sql, version).await },
+            )
+            .await
+        } else {
+            apply_migration(conn, &up_sql, version).await
+        };
+        result.map_err(|error| {
+            ApplicationError::MigrationError(format!("failed to run migration {name}: {error:?}"))
+        })?;
+    }
+
+    logger::info!("Database migrations completed");
+    Ok(())
+}
+
+async fn apply_migration(
+    conn: &PgPooledConn,
+    up_sql: &str,
+    version: String,
+) -> Result<(), diesel::result::Error> {
+    conn.batch_execute_async(up_sql).await?;
+    diesel::sql_query("INSERT INTO __diesel_schema_migrations (version) VALUES ($1)")
+        .bind::<sql_types::Text, _>(version)
+        .execute_async(conn)
+        .await?;
+    Ok(())
+}
+
+/// Reads the migrations in `directory`, sorted by their version. The version of a migration is the
+/// part of its directory name before the first underscore, without dashes, as with the Diesel CLI.
+fn read_migrations(directory: &Path) -> ApplicationResult<Vec<Migration>> {
+    let mut migrations = Vec::new();
+
+    let read_error = |error: std::io::Error| {
+        ApplicationError::MigrationError(format!(
+            "failed to read migrations from {}: {error}",
+            directory.display()
+        ))
+    };
+
+    for entry in std::fs::read_dir(directory).map_err(read_error)? {
+        let path = entry.map_err(read_error)?.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        let name = path
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let version = name.split('_').next().unwrap_or_default().replace('-', "");
+        let up_sql = std::fs::read_to_string(path.join("up.sql")).map_err(read_error)?;
+        let metadata = read_migration_metadata(&path)?;
+
+        migrations.push(Migration {
+            name,
+            version,
+            up_sql,
+            run_in_transaction: metadata.run_in_transaction,
+        });
+    }
+
+    migrations.sort_by(|first, second| first.version.cmp(&second.version));
+    Ok(migrations)
+}
+
+/// Reads the optional `metadata.toml` of the migration in `path`.
+fn read_migration_metadata(path: &Path) -> ApplicationResult<MigrationMetadata> {
+    let metadata_path = path.join("metadata.toml");
+    config::Config::builder()
+        .add_source(config::File::from(metadata_path.as_path()).required(false))
+        .build()
+        .and_then(|metadata| metadata.try_deserialize())
+        .map_err(|error| {
+            ApplicationError::MigrationError(format!(
+                "failed to read migration metadata from {}: {error}",
+                metadata_path.display()
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn migration(up_sql: &str) -> Migration {
+        Migration {
+            name: "2024-01-01-000000_test".to_string(),
+            version: "20240101000000".to_string(),
+            up_sql: up_sql.to_string(),
+            run_in_transaction: true,
+        }
+    }
+
+    #[test]
+    fn test_destructive_migrations_are_detected() {
+        assert!(migration("DROP TABLE payment_intent;").is_destructive());
+        assert!(
+            migration("alter table events\n  drop   column is_webhook_notified;").is_destructive()
+        );
+        assert!(migration("ALTER TABLE refund DROP CONSTRAINT refund_pkey;").is_destructive());
+        assert!(migration("DROP INDEX CONCURRENTLY IF EXISTS refund_idx;").is_destructive());
+        assert!(migration("DELETE FROM configs WHERE key = 'k';").is_destructive());
+    }
+
+    #[test]
+    fn test_non_destructive_migrations_are_not_detected() {
+        assert!(
+            !migration("ALTER TABLE refund ADD COLUMN profile_id VARCHAR(64);").is_destructive()
+        );
+        assert!(!migration("CREATE INDEX refund_idx ON refund (refund_id);").is_destructive());
+        assert!(!migration("-- DROP TABLE refund;\nSELECT 1;").is_destructive());
+    }
+
+    #[test]
+    fn test_read_migrations() {
+        let directory = std::env::temp_dir().join(format!("migrations_{}", uuid::Uuid::new_v4()));
+        let create_migration = |name: &str, up_sql: &str, metadata: Option<&str>| {
+            let migration_directory = directory.join(name);
+            std::fs::create_dir_all(&migration_directory).unwrap();
+            std::fs::write(migration_directory.join("up.sql"), up_sql).unwrap();
+            if let Some(metadata) = metadata {
+                std::fs::write(migration_directory.join("metadata.toml"), metadata).unwrap();
+            }
+        };
+        create_migration(
+            "2024-02-01-000000_create_index",
+            "CREATE INDEX CONCURRENTLY refund_idx ON refund (refund_id);",
+            Some("run_in_transaction = false"),
+        );
+        create_migration(
+            "2024-01-01-000000_add_column",
+            "ALTER TABLE refund ADD COLUMN profile_id VARCHAR(64);",
+            None,
+        );
+        std::fs::write(directory.join("README.md"), "").unwrap();
+
+        let migrations = read_migrations(&directory);
+        std::fs::remove_dir_all(&directory).unwrap();
+        let migrations = migrations.unwrap();
+
+        assert_eq!(
+            migrations
+                .iter()
+                .map(|migration| (migration.version.as_str(), migration.run_in_transaction))
+                .collect::<Vec<_>>(),
+            vec![("20240101000000", true), ("20240201000000", false)]
+        );
+        assert_eq!(
+            migrations.first().unwrap().name,
+            "2024-01-01-000000_add_column"
+        );
+    }
+}
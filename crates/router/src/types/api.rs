@@ -1,5 +1,6 @@
 pub mod admin;
 pub mod api_keys;
+pub mod audit_log;
 pub mod authentication;
 pub mod configs;
 #[cfg(feature = "olap")]
@@ -20,7 +21,6 @@ pub mod payouts;
 pub mod poll;
 pub mod refunds;
 pub mod routing;
-#[cfg(feature = "olap")]
 pub mod verify_connector;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
@@ -373,6 +373,7 @@ impl ConnectorData {
                 enums::Connector::Powertranz => Ok(Box::new(&connector::Powertranz)),
                 enums::Connector::Prophetpay => Ok(Box::new(&connector::Prophetpay)),
                 enums::Connector::Rapyd => Ok(Box::new(&connector::Rapyd)),
+                enums::Connector::Razorpay => Ok(Box::new(&connector::Razorpay)),
                 enums::Connector::Shift4 => Ok(Box::new(&connector::Shift4)),
                 enums::Connector::Square => Ok(Box::new(&connector::Square)),
                 enums::Connector::Stax => Ok(Box::new(&connector::Stax)),
@@ -4,6 +4,7 @@ pub use api_models::admin::{
     MerchantConnectorCreate, MerchantConnectorDeleteResponse, MerchantConnectorDetails,
     MerchantConnectorDetailsWrap, MerchantConnectorId, MerchantConnectorResponse, MerchantDetails,
     MerchantId, PaymentMethodsEnabled, ToggleKVRequest, ToggleKVResponse, WebhookDetails,
+    WebhookEndpointCreate, WebhookEndpointResponse, WebhookEndpointUpdate,
 };
 use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
@@ -79,6 +80,31 @@ impl ForeignTryFrom<storage::business_profile::BusinessProfile> for BusinessProf
                     authentication_connector_details.parse_value("AuthenticationDetails")
                 })
                 .transpose()?,
+            statement_descriptor_name: item.statement_descriptor_name,
+        })
+    }
+}
+
+/// The secret of the endpoint is not included in the response, and is only returned by the request
+/// that creates the endpoint
+impl ForeignTryFrom<domain::WebhookEndpoint> for WebhookEndpointResponse {
+    type Error = error_stack::Report<errors::ParsingError>;
+
+    fn foreign_try_from(item: domain::WebhookEndpoint) -> Result<Self, Self::Error> {
+        Ok(Self {
+            endpoint_id: item.endpoint_id,
+            merchant_id: item.merchant_id,
+            profile_id: item.profile_id,
+            url: item.url,
+            description: item.description,
+            secret: None,
+            enabled_events: item
+                .enabled_events
+                .map(|enabled_events| enabled_events.parse_value("EnabledEvents"))
+                .transpose()?,
+            is_active: item.is_active,
+            created_at: item.created_at,
+            modified_at: item.modified_at,
         })
     }
 }
@@ -177,6 +203,10 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
                 })?,
             is_extended_card_info_enabled: None,
             extended_card_info_config: None,
+            previous_payment_response_hash_key: None,
+            previous_payment_response_hash_key_expires_at: None,
+            outgoing_webhook_custom_http_headers: None,
+            statement_descriptor_name: request.statement_descriptor_name,
         })
     }
 }
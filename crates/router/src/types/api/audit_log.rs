@@ -0,0 +1,22 @@
+pub use api_models::audit_log::{
+    AuditLogListConstraints, AuditLogListRequestInternal, AuditLogResponse,
+};
+
+use crate::types::{storage, transformers::ForeignFrom};
+
+impl ForeignFrom<storage::AuditLog> for AuditLogResponse {
+    fn foreign_from(item: storage::AuditLog) -> Self {
+        Self {
+            audit_log_id: item.audit_log_id,
+            merchant_id: item.merchant_id,
+            entity_type: item.entity_type,
+            entity_id: item.entity_id,
+            action: item.action,
+            actor_type: item.actor_type,
+            actor_id: item.actor_id,
+            old_value: item.old_value,
+            new_value: item.new_value,
+            created_at: item.created_at,
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use api_models::customers;
-pub use api_models::customers::{CustomerDeleteResponse, CustomerId, CustomerRequest};
+pub use api_models::customers::{
+    CustomerDeleteResponse, CustomerId, CustomerListConstraints, CustomerRedactionReport,
+    CustomerRequest,
+};
 use serde::Serialize;
 
 use super::payments;
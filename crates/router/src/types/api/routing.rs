@@ -3,9 +3,10 @@ pub use api_models::routing::RoutableChoiceKind;
 pub use api_models::{
     enums as api_enums,
     routing::{
-        ConnectorVolumeSplit, DetailedConnectorChoice, RoutableConnectorChoice, RoutingAlgorithm,
-        RoutingAlgorithmKind, RoutingAlgorithmRef, RoutingConfigRequest, RoutingDictionary,
-        RoutingDictionaryRecord, StraightThroughAlgorithm,
+        ConnectorFilterReason, ConnectorVolumeSplit, DetailedConnectorChoice, FilteredConnector,
+        RoutableConnectorChoice, RoutingAlgorithm, RoutingAlgorithmKind, RoutingAlgorithmRef,
+        RoutingConfigRequest, RoutingDecision, RoutingDictionary, RoutingDictionaryRecord,
+        StraightThroughAlgorithm,
     },
 };
 
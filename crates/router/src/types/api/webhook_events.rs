@@ -1,6 +1,8 @@
 pub use api_models::webhook_events::{
-    EventListConstraints, EventListConstraintsInternal, EventListItemResponse,
-    EventListRequestInternal, EventRetrieveResponse, OutgoingWebhookRequestContent,
-    OutgoingWebhookResponseContent, WebhookDeliveryAttemptListRequestInternal,
-    WebhookDeliveryRetryRequestInternal,
+    DeadLetteredEventListConstraints, DeadLetteredEventListRequestInternal,
+    DeadLetteredEventsRequeueRequest, DeadLetteredEventsRequeueRequestInternal,
+    DeadLetteredEventsRequeueResponse, EventListConstraints, EventListConstraintsInternal,
+    EventListItemResponse, EventListRequestInternal, EventRetrieveResponse,
+    OutgoingWebhookRequestContent, OutgoingWebhookResponseContent,
+    WebhookDeliveryAttemptListRequestInternal, WebhookDeliveryRetryRequestInternal,
 };
@@ -256,6 +256,16 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         _request: &IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError>;
 
+    /// Whether the payment must be synced with the connector, instead of being updated from the
+    /// resource object of the webhook, because the webhook does not carry enough detail to
+    /// update the payment
+    fn is_payment_sync_required_for_webhook(
+        &self,
+        _request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<bool, errors::ConnectorError> {
+        Ok(false)
+    }
+
     fn get_webhook_api_response(
         &self,
         _request: &IncomingWebhookRequestDetails<'_>,
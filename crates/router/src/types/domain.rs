@@ -9,6 +9,7 @@ pub mod payments;
 pub mod types;
 #[cfg(feature = "olap")]
 pub mod user;
+mod webhook_endpoint;
 
 pub use address::*;
 pub use customer::*;
@@ -19,3 +20,4 @@ pub use merchant_key_store::*;
 pub use payments::*;
 #[cfg(feature = "olap")]
 pub use user::*;
+pub use webhook_endpoint::*;
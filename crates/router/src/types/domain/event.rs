@@ -28,6 +28,10 @@ pub struct Event {
     pub request: OptionalEncryptableSecretString,
     pub response: OptionalEncryptableSecretString,
     pub delivery_attempt: Option<WebhookDeliveryAttempt>,
+    pub dead_lettered_at: Option<time::PrimitiveDateTime>,
+    pub webhook_endpoint_id: Option<String>,
+    pub sequence_number: Option<i64>,
+    pub triggering_attempt_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -36,6 +40,9 @@ pub enum EventUpdate {
         is_webhook_notified: bool,
         response: OptionalEncryptableSecretString,
     },
+    DeadLetterUpdate {
+        dead_lettered_at: Option<time::PrimitiveDateTime>,
+    },
 }
 
 impl From<EventUpdate> for EventUpdateInternal {
@@ -47,6 +54,12 @@ impl From<EventUpdate> for EventUpdateInternal {
             } => Self {
                 is_webhook_notified: Some(is_webhook_notified),
                 response: response.map(Into::into),
+                dead_lettered_at: None,
+            },
+            EventUpdate::DeadLetterUpdate { dead_lettered_at } => Self {
+                is_webhook_notified: None,
+                response: None,
+                dead_lettered_at: Some(dead_lettered_at),
             },
         }
     }
@@ -74,6 +87,10 @@ impl super::behaviour::Conversion for Event {
             request: self.request.map(Into::into),
             response: self.response.map(Into::into),
             delivery_attempt: self.delivery_attempt,
+            dead_lettered_at: self.dead_lettered_at,
+            webhook_endpoint_id: self.webhook_endpoint_id,
+            sequence_number: self.sequence_number,
+            triggering_attempt_id: self.triggering_attempt_id,
         })
     }
 
@@ -107,6 +124,10 @@ impl super::behaviour::Conversion for Event {
                     .async_lift(|inner| types::decrypt(inner, key.peek()))
                     .await?,
                 delivery_attempt: item.delivery_attempt,
+                dead_lettered_at: item.dead_lettered_at,
+                webhook_endpoint_id: item.webhook_endpoint_id,
+                sequence_number: item.sequence_number,
+                triggering_attempt_id: item.triggering_attempt_id,
             })
         }
         .await
@@ -132,6 +153,9 @@ impl super::behaviour::Conversion for Event {
             request: self.request.map(Into::into),
             response: self.response.map(Into::into),
             delivery_attempt: self.delivery_attempt,
+            webhook_endpoint_id: self.webhook_endpoint_id,
+            sequence_number: self.sequence_number,
+            triggering_attempt_id: self.triggering_attempt_id,
         })
     }
 }
@@ -1,5 +1,5 @@
 use common_utils::{
-    crypto::{Encryptable, GcmAes256},
+    crypto::{Encryptable, GcmAes256, OptionalEncryptableValue},
     date_time,
     errors::{CustomResult, ValidationError},
     pii,
@@ -11,7 +11,10 @@ use diesel_models::{
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 
-use super::{behaviour, types::TypeEncryption};
+use super::{
+    behaviour,
+    types::{self, AsyncLift, TypeEncryption},
+};
 #[derive(Clone, Debug)]
 pub struct MerchantConnectorAccount {
     pub id: Option<i32>,
@@ -36,6 +39,7 @@ pub struct MerchantConnectorAccount {
     pub applepay_verified_domains: Option<Vec<String>>,
     pub pm_auth_config: Option<serde_json::Value>,
     pub status: enums::ConnectorStatus,
+    pub test_connector_account_details: OptionalEncryptableValue,
 }
 
 #[derive(Debug)]
@@ -56,6 +60,7 @@ pub enum MerchantConnectorAccountUpdate {
         pm_auth_config: Option<serde_json::Value>,
         connector_label: Option<String>,
         status: Option<enums::ConnectorStatus>,
+        test_connector_account_details: OptionalEncryptableValue,
     },
 }
 
@@ -92,6 +97,9 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 applepay_verified_domains: self.applepay_verified_domains,
                 pm_auth_config: self.pm_auth_config,
                 status: self.status,
+                test_connector_account_details: self
+                    .test_connector_account_details
+                    .map(Encryption::from),
             },
         )
     }
@@ -132,6 +140,13 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             applepay_verified_domains: other.applepay_verified_domains,
             pm_auth_config: other.pm_auth_config,
             status: other.status,
+            test_connector_account_details: other
+                .test_connector_account_details
+                .async_lift(|inner| types::decrypt(inner, key.peek()))
+                .await
+                .change_context(ValidationError::InvalidValue {
+                    message: "Failed while decrypting test connector account details".to_string(),
+                })?,
         })
     }
 
@@ -160,6 +175,9 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             applepay_verified_domains: self.applepay_verified_domains,
             pm_auth_config: self.pm_auth_config,
             status: self.status,
+            test_connector_account_details: self
+                .test_connector_account_details
+                .map(Encryption::from),
         })
     }
 }
@@ -183,6 +201,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 pm_auth_config,
                 connector_label,
                 status,
+                test_connector_account_details,
             } => Self {
                 merchant_id,
                 connector_type,
@@ -201,6 +220,8 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 pm_auth_config,
                 connector_label,
                 status,
+                test_connector_account_details: test_connector_account_details
+                    .map(Encryption::from),
             },
         }
     }
@@ -475,7 +475,10 @@ pub enum BankTransferData {
         /// The billing details for BniVa Bank Transfer
         billing_details: DokuBillingDetails,
     },
-    Pix {},
+    Pix {
+        cpf: Option<Secret<String>>,
+        cnpj: Option<Secret<String>>,
+    },
     Pse {},
     LocalBankTransfer {
         bank_code: Option<String>,
@@ -1056,7 +1059,7 @@ impl From<api_models::payments::BankTransferData> for BankTransferData {
                     billing_details: DokuBillingDetails::from(billing_details),
                 }
             }
-            api_models::payments::BankTransferData::Pix {} => Self::Pix {},
+            api_models::payments::BankTransferData::Pix { cpf, cnpj } => Self::Pix { cpf, cnpj },
             api_models::payments::BankTransferData::Pse {} => Self::Pse {},
             api_models::payments::BankTransferData::LocalBankTransfer { bank_code } => {
                 Self::LocalBankTransfer { bank_code }
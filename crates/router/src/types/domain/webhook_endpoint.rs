@@ -0,0 +1,117 @@
+use common_utils::{
+    crypto::{Encryptable, GcmAes256},
+    date_time,
+    errors::{CustomResult, ValidationError},
+};
+use diesel_models::webhook_endpoint::WebhookEndpointUpdateInternal;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+
+use super::{behaviour, types::TypeEncryption};
+
+#[derive(Clone, Debug)]
+pub struct WebhookEndpoint {
+    pub endpoint_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub url: Secret<String>,
+    pub description: Option<String>,
+    pub secret: Encryptable<Secret<String>>,
+    pub enabled_events: Option<serde_json::Value>,
+    pub is_active: bool,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum WebhookEndpointUpdate {
+    Update {
+        url: Option<Secret<String>>,
+        description: Option<String>,
+        secret: Option<Encryptable<Secret<String>>>,
+        enabled_events: Option<serde_json::Value>,
+        is_active: Option<bool>,
+    },
+}
+
+impl From<WebhookEndpointUpdate> for WebhookEndpointUpdateInternal {
+    fn from(webhook_endpoint_update: WebhookEndpointUpdate) -> Self {
+        match webhook_endpoint_update {
+            WebhookEndpointUpdate::Update {
+                url,
+                description,
+                secret,
+                enabled_events,
+                is_active,
+            } => Self {
+                url,
+                description,
+                secret: secret.map(Into::into),
+                enabled_events,
+                is_active,
+                modified_at: date_time::now(),
+            },
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl behaviour::Conversion for WebhookEndpoint {
+    type DstType = diesel_models::webhook_endpoint::WebhookEndpoint;
+    type NewDstType = diesel_models::webhook_endpoint::WebhookEndpointNew;
+
+    async fn convert(self) -> CustomResult<Self::DstType, ValidationError> {
+        Ok(diesel_models::webhook_endpoint::WebhookEndpoint {
+            endpoint_id: self.endpoint_id,
+            merchant_id: self.merchant_id,
+            profile_id: self.profile_id,
+            url: self.url,
+            description: self.description,
+            secret: self.secret.into(),
+            enabled_events: self.enabled_events,
+            is_active: self.is_active,
+            created_at: self.created_at,
+            modified_at: self.modified_at,
+        })
+    }
+
+    async fn convert_back(
+        item: Self::DstType,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<Self, ValidationError>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            endpoint_id: item.endpoint_id,
+            merchant_id: item.merchant_id,
+            profile_id: item.profile_id,
+            url: item.url,
+            description: item.description,
+            secret: Encryptable::decrypt(item.secret, key.peek(), GcmAes256)
+                .await
+                .change_context(ValidationError::InvalidValue {
+                    message: "Failed while decrypting webhook endpoint secret".to_string(),
+                })?,
+            enabled_events: item.enabled_events,
+            is_active: item.is_active,
+            created_at: item.created_at,
+            modified_at: item.modified_at,
+        })
+    }
+
+    async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError> {
+        Ok(diesel_models::webhook_endpoint::WebhookEndpointNew {
+            endpoint_id: self.endpoint_id,
+            merchant_id: self.merchant_id,
+            profile_id: self.profile_id,
+            url: self.url,
+            description: self.description,
+            secret: self.secret.into(),
+            enabled_events: self.enabled_events,
+            is_active: self.is_active,
+            created_at: self.created_at,
+            modified_at: self.modified_at,
+        })
+    }
+}
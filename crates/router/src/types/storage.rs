@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
@@ -9,11 +10,13 @@ pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_payload;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
 pub mod enums;
 pub mod ephemeral_key;
+pub mod event_outbox;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
@@ -26,6 +29,7 @@ pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod payment_attempt;
+pub mod payment_export;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
@@ -36,6 +40,7 @@ pub mod role;
 pub mod routing_algorithm;
 pub mod user;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 use std::collections::HashMap;
 
@@ -55,13 +60,14 @@ pub use hyperswitch_domain_models::payouts::{
 pub use scheduler::db::process_tracker;
 
 pub use self::{
-    address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
+    address::*, api_keys::*, audit_log::*, authentication::*, authorization::*, blocklist::*,
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*, cards_info::*,
-    configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
-    file::*, fraud_check::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
-    merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
+    configs::*, connector_payload::*, customers::*, dashboard_metadata::*, dispute::*,
+    ephemeral_key::*, event_outbox::*, events::*, file::*, fraud_check::*, gsm::*,
+    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    merchant_key_store::*, payment_export::*, payment_link::*, payment_method::*,
     process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*, user::*,
-    user_role::*,
+    user_role::*, webhook_endpoint::*,
 };
 use crate::types::api::routing;
 
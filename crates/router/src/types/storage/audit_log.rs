@@ -0,0 +1 @@
+pub use diesel_models::audit_log::{AuditLog, AuditLogNew};
@@ -0,0 +1 @@
+pub use diesel_models::connector_payload::{ConnectorPayload, ConnectorPayloadNew};
@@ -1,3 +1,5 @@
-pub use diesel_models::customers::{Customer, CustomerNew, CustomerUpdateInternal};
+pub use diesel_models::customers::{
+    Customer, CustomerListConstraints, CustomerNew, CustomerUpdateInternal,
+};
 
 pub use crate::types::domain::CustomerUpdate;
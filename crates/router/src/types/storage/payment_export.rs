@@ -0,0 +1,4 @@
+pub use diesel_models::payment_export::{
+    PaymentExport, PaymentExportNew, PaymentExportTrackingData, PaymentExportUpdate,
+    PaymentExportUpdateInternal,
+};
@@ -4,11 +4,13 @@ use std::{
 };
 
 use api_models::payment_methods;
+use common_utils::consts;
 use diesel_models::enums;
 pub use diesel_models::payment_method::{
-    PaymentMethod, PaymentMethodNew, PaymentMethodUpdate, PaymentMethodUpdateInternal,
-    TokenizeCoreWorkflow,
+    CardAccountUpdaterTrackingData, PaymentMethod, PaymentMethodNew, PaymentMethodUpdate,
+    PaymentMethodUpdateInternal, TokenizeCoreWorkflow,
 };
+use time::PrimitiveDateTime;
 
 use crate::types::api::{self, payments};
 
@@ -56,6 +58,64 @@ pub enum PaymentTokenData {
     WalletToken(WalletTokenData),
 }
 
+/// Whether a payment method token can be used for more than one payment.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentTokenScope {
+    /// The token is tied to the checkout of a single payment, and is invalidated once that payment
+    /// reaches a terminal state or the checkout window elapses
+    #[default]
+    SingleUse,
+    /// The token can be used for any payment of the customer until it expires
+    MultiUse,
+}
+
+impl PaymentTokenScope {
+    /// The number of seconds for which a token issued at `issued_at` remains valid. Single use
+    /// tokens expire with the checkout window of the payment, which started when the payment was
+    /// created.
+    pub fn get_ttl_in_seconds(
+        self,
+        intent_created_at: Option<PrimitiveDateTime>,
+        issued_at: PrimitiveDateTime,
+    ) -> i64 {
+        match self {
+            Self::SingleUse => {
+                let time_elapsed = issued_at - intent_created_at.unwrap_or(issued_at);
+                consts::TOKEN_TTL - time_elapsed.whole_seconds()
+            }
+            Self::MultiUse => consts::MULTI_USE_TOKEN_TTL,
+        }
+    }
+}
+
+/// The value stored in redis against a payment method token.
+///
+/// The token data is flattened so that records written before the scope was introduced
+/// are read back as single use tokens that are not tied to a payment.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentTokenRecord {
+    #[serde(default)]
+    pub scope: PaymentTokenScope,
+    /// The payment a single use token was issued for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_id: Option<String>,
+    #[serde(flatten)]
+    pub token_data: PaymentTokenData,
+}
+
+impl PaymentTokenRecord {
+    /// A token issued for a payment can only be used for that payment.
+    pub fn is_usable_for_payment(&self, payment_id: &str) -> bool {
+        match (self.scope, self.payment_id.as_deref()) {
+            (PaymentTokenScope::SingleUse, Some(token_payment_id)) => {
+                token_payment_id == payment_id
+            }
+            (PaymentTokenScope::SingleUse, None) | (PaymentTokenScope::MultiUse, _) => true,
+        }
+    }
+}
+
 impl PaymentTokenData {
     pub fn permanent_card(
         payment_method_id: Option<String>,
@@ -110,3 +170,73 @@ impl DerefMut for PaymentsMandateReference {
         &mut self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::ext_traits::StringExt;
+
+    use super::*;
+
+    fn token_record(scope: PaymentTokenScope, payment_id: Option<&str>) -> PaymentTokenRecord {
+        PaymentTokenRecord {
+            scope,
+            payment_id: payment_id.map(ToString::to_string),
+            token_data: PaymentTokenData::temporary_generic("token_123".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_token_record_in_older_format_is_read_as_single_use() {
+        let token_record = r#"{"kind":"temporary_generic","token":"token_123"}"#
+            .to_string()
+            .parse_struct::<PaymentTokenRecord>("PaymentTokenRecord")
+            .unwrap();
+
+        assert_eq!(token_record.scope, PaymentTokenScope::SingleUse);
+        assert_eq!(token_record.payment_id, None);
+        assert!(token_record.is_usable_for_payment("pay_123"));
+    }
+
+    #[test]
+    fn test_single_use_token_is_usable_only_for_its_payment() {
+        let token_record = token_record(PaymentTokenScope::SingleUse, Some("pay_123"));
+
+        assert!(token_record.is_usable_for_payment("pay_123"));
+        assert!(!token_record.is_usable_for_payment("pay_456"));
+    }
+
+    #[test]
+    fn test_multi_use_token_is_usable_for_any_payment() {
+        let token_record = token_record(PaymentTokenScope::MultiUse, None);
+
+        assert!(token_record.is_usable_for_payment("pay_123"));
+        assert!(token_record.is_usable_for_payment("pay_456"));
+    }
+
+    #[test]
+    fn test_single_use_token_expires_with_the_checkout_window() {
+        let intent_created_at = common_utils::date_time::now();
+        let issued_at = intent_created_at + time::Duration::seconds(300);
+
+        assert_eq!(
+            PaymentTokenScope::SingleUse.get_ttl_in_seconds(Some(intent_created_at), issued_at),
+            consts::TOKEN_TTL - 300
+        );
+        assert_eq!(
+            PaymentTokenScope::SingleUse.get_ttl_in_seconds(None, issued_at),
+            consts::TOKEN_TTL
+        );
+    }
+
+    #[test]
+    fn test_multi_use_token_ttl_does_not_depend_on_the_payment() {
+        let intent_created_at = common_utils::date_time::now();
+        let issued_at = intent_created_at + time::Duration::seconds(300);
+
+        assert_eq!(
+            PaymentTokenScope::MultiUse.get_ttl_in_seconds(Some(intent_created_at), issued_at),
+            consts::MULTI_USE_TOKEN_TTL
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
-use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl};
 pub use diesel_models::refund::{
     Refund, RefundCoreWorkflow, RefundNew, RefundUpdate, RefundUpdateInternal,
 };
@@ -48,9 +48,10 @@ impl RefundDbExt for Refund {
     ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
         let mut filter = <Self as HasTable>::table()
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(dsl::modified_at.desc())
+            .order((dsl::created_at.desc(), dsl::refund_id.desc()))
             .into_boxed();
         let mut search_by_pay_or_ref_id = false;
+        let mut is_reversed = false;
 
         if let (Some(pid), Some(ref_id)) = (
             &refund_list_details.payment_id,
@@ -104,6 +105,41 @@ impl RefundDbExt for Refund {
             }
         }
 
+        // Keyset pagination on `(created_at, refund_id)`, so that deep pages are read straight
+        // off the index instead of skipping over every earlier row.
+        if let Some(starting_after) = &refund_list_details.starting_after {
+            let cursor = Self::find_by_merchant_id_refund_id(conn, merchant_id, starting_after)
+                .await
+                .attach_printable("Failed to find the refund used as the pagination cursor")?;
+            filter = filter.filter(
+                dsl::created_at.le(cursor.created_at).and(
+                    dsl::created_at
+                        .lt(cursor.created_at)
+                        .or(dsl::refund_id.lt(cursor.refund_id)),
+                ),
+            );
+        }
+
+        if let Some(ending_before) = &refund_list_details.ending_before {
+            let cursor = Self::find_by_merchant_id_refund_id(conn, merchant_id, ending_before)
+                .await
+                .attach_printable("Failed to find the refund used as the pagination cursor")?;
+            filter = filter.filter(
+                dsl::created_at.ge(cursor.created_at).and(
+                    dsl::created_at
+                        .gt(cursor.created_at)
+                        .or(dsl::refund_id.gt(cursor.refund_id)),
+                ),
+            );
+
+            // Read upwards from the cursor so that the page is the one right before it, and put
+            // the rows back in descending order once fetched.
+            if refund_list_details.starting_after.is_none() {
+                filter = filter.order((dsl::created_at.asc(), dsl::refund_id.asc()));
+                is_reversed = true;
+            }
+        }
+
         if let Some(connector) = refund_list_details.clone().connector {
             filter = filter.filter(dsl::connector.eq_any(connector));
         }
@@ -123,6 +159,12 @@ impl RefundDbExt for Refund {
             db_metrics::DatabaseOperation::Filter,
         )
         .await
+        .map(|mut refunds: Vec<Self>| {
+            if is_reversed {
+                refunds.reverse();
+            }
+            refunds
+        })
         .change_context(errors::DatabaseError::NotFound)
         .attach_printable_lazy(|| "Error filtering records by predicate")
     }
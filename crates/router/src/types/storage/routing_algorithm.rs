@@ -1,3 +1,3 @@
 pub use diesel_models::routing_algorithm::{
-    RoutingAlgorithm, RoutingAlgorithmMetadata, RoutingProfileMetadata,
+    RoutingAlgorithm, RoutingAlgorithmMetadata, RoutingAlgorithmUpdate, RoutingProfileMetadata,
 };
@@ -92,6 +92,7 @@ impl ForeignFrom<diesel_models::PaymentMethod> for payment_methods::PaymentMetho
             bank_transfer: None,
             last_used_at: None,
             client_secret: item.client_secret,
+            fingerprint_id: item.fingerprint_id,
         }
     }
 }
@@ -257,6 +258,7 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
             api_enums::Connector::Powertranz => Self::Powertranz,
             api_enums::Connector::Prophetpay => Self::Prophetpay,
             api_enums::Connector::Rapyd => Self::Rapyd,
+            api_enums::Connector::Razorpay => Self::Razorpay,
             api_enums::Connector::Shift4 => Self::Shift4,
             api_enums::Connector::Signifyd => {
                 Err(common_utils::errors::ValidationError::InvalidValue {
@@ -685,6 +687,9 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            test_mode: api_key.test_mode,
+            ip_allowlist: api_key.ip_allowlist,
+            role_id: api_key.role_id,
         }
     }
 }
@@ -699,6 +704,10 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            last_used: api_key.last_used,
+            test_mode: api_key.test_mode,
+            ip_allowlist: api_key.ip_allowlist,
+            role_id: api_key.role_id,
         }
     }
 }
@@ -712,6 +721,7 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            ip_allowlist: api_key.ip_allowlist,
         }
     }
 }
@@ -874,7 +884,17 @@ impl TryFrom<domain::MerchantConnectorAccount> for api_models::admin::MerchantCo
             connector_name: item.connector_name,
             connector_label: item.connector_label,
             merchant_connector_id: item.merchant_connector_id,
-            connector_account_details: item.connector_account_details.into_inner(),
+            connector_account_details: item
+                .connector_account_details
+                .into_inner()
+                .map(crate::utils::mask_connector_account_details),
+            test_connector_account_details: item.test_connector_account_details.map(
+                |test_connector_account_details| {
+                    test_connector_account_details
+                        .into_inner()
+                        .map(crate::utils::mask_connector_account_details)
+                },
+            ),
             test_mode: item.test_mode,
             disabled: item.disabled,
             payment_methods_enabled,
@@ -898,6 +918,7 @@ impl TryFrom<domain::MerchantConnectorAccount> for api_models::admin::MerchantCo
             applepay_verified_domains: item.applepay_verified_domains,
             pm_auth_config: item.pm_auth_config,
             status: item.status,
+            credentials_verified: None,
         })
     }
 }
@@ -1257,6 +1278,8 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
             is_delivery_successful: item.is_webhook_notified,
             initial_attempt_id,
             created: item.created_at,
+            dead_lettered_at: item.dead_lettered_at,
+            webhook_endpoint_id: item.webhook_endpoint_id,
         })
     }
 }
@@ -10,7 +10,6 @@ pub mod storage_partitioning;
 pub mod user;
 #[cfg(feature = "olap")]
 pub mod user_role;
-#[cfg(feature = "olap")]
 pub mod verify_connector;
 
 use std::fmt::Debug;
@@ -520,6 +519,46 @@ pub fn handle_json_response_deserialization_failure(
     }
 }
 
+/// Masks the credentials in the connector account details, so that they are not returned in full
+/// when a merchant connector account is read. Only the last four characters of credentials longer
+/// than eight characters are retained, and the `auth_type` is returned as is.
+pub fn mask_connector_account_details(connector_account_details: Value) -> Value {
+    const VISIBLE_SUFFIX_LENGTH: usize = 4;
+    const MIN_LENGTH_FOR_VISIBLE_SUFFIX: usize = 8;
+
+    match connector_account_details {
+        Value::Object(details) => Value::Object(
+            details
+                .into_iter()
+                .map(|(key, value)| match value {
+                    Value::String(_) if key == "auth_type" => (key, value),
+                    value => (key, mask_connector_account_details(value)),
+                })
+                .collect(),
+        ),
+        Value::Array(details) => Value::Array(
+            details
+                .into_iter()
+                .map(mask_connector_account_details)
+                .collect(),
+        ),
+        Value::String(credential) => {
+            let length = credential.chars().count();
+            let visible_suffix = if length > MIN_LENGTH_FOR_VISIBLE_SUFFIX {
+                credential
+                    .chars()
+                    .skip(length - VISIBLE_SUFFIX_LENGTH)
+                    .collect::<String>()
+            } else {
+                String::new()
+            };
+            let masked_prefix = "*".repeat(length - visible_suffix.chars().count());
+            Value::String(format!("{masked_prefix}{visible_suffix}"))
+        }
+        value => value,
+    }
+}
+
 pub fn get_http_status_code_type(
     status_code: u16,
 ) -> CustomResult<String, errors::ApiErrorResponse> {
@@ -833,6 +872,7 @@ where
 {
     let status = payment_data.payment_intent.status;
     let payment_id = payment_data.payment_intent.payment_id.clone();
+    let triggering_attempt_id = Some(payment_data.payment_attempt.attempt_id.clone());
     let captures = payment_data
         .multiple_capture_data
         .clone()
@@ -892,6 +932,7 @@ where
                                 payments_response_json,
                             ),
                             primary_object_created_at,
+                            triggering_attempt_id,
                         ))
                         .await
                     }
@@ -928,4 +969,24 @@ mod tests {
         let qr_image_data_source_url = utils::QrImage::new_from_data("Hyperswitch".to_string());
         assert!(qr_image_data_source_url.is_ok());
     }
+
+    #[test]
+    fn test_mask_connector_account_details() {
+        let connector_account_details = serde_json::json!({
+            "auth_type": "SignatureKey",
+            "api_key": "sk_test_51HmiJkBjLfFOD2Ym",
+            "key1": "merchant",
+            "api_secret": ""
+        });
+
+        assert_eq!(
+            utils::mask_connector_account_details(connector_account_details),
+            serde_json::json!({
+                "auth_type": "SignatureKey",
+                "api_key": "*********************D2Ym",
+                "key1": "********",
+                "api_secret": ""
+            })
+        );
+    }
 }
@@ -2,7 +2,15 @@
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod authorization_expiry;
+pub mod card_account_updater;
+pub mod event_outbox_relay;
 pub mod outgoing_webhook_retry;
+pub mod payment_data_archival;
+#[cfg(feature = "olap")]
+pub mod payment_export;
+pub mod payment_status_sync;
 pub mod payment_sync;
 pub mod refund_router;
+pub mod scheduled_capture;
 pub mod tokenized_data;
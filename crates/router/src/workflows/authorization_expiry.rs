@@ -0,0 +1,233 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::{
+        payment_methods::Oss,
+        payments::{
+            self as payment_flows,
+            authorization_expiry::{self, AuthorizationExpiryAction},
+            operations,
+        },
+        webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    workflows::outgoing_webhook_retry,
+};
+
+pub struct AuthorizationExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for AuthorizationExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::AuthorizationExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AuthorizationExpiryTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The payment may have been captured, cancelled or retried since the task was scheduled,
+        // and the merchant may have opted out since
+        let config = if payment_intent.status == enums::IntentStatus::RequiresCapture
+            && payment_intent.active_attempt.get_id() == tracking_data.attempt_id
+        {
+            authorization_expiry::get_authorization_expiry_config(db, &tracking_data.merchant_id)
+                .await?
+        } else {
+            None
+        };
+
+        match config.map(|config| config.action) {
+            Some(AuthorizationExpiryAction::Void) => {
+                void_payment(state, merchant_account, key_store, &tracking_data).await?
+            }
+            Some(AuthorizationExpiryAction::Notify) => {
+                notify_merchant(
+                    state,
+                    merchant_account,
+                    key_store,
+                    payment_intent,
+                    &tracking_data,
+                )
+                .await?
+            }
+            None => (),
+        }
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Voids the payment before its authorization expires. The cancel flow does not trigger outgoing
+/// webhooks, so the merchant is sent the `payment_cancelled` webhook here once the void succeeds.
+async fn void_payment(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    tracking_data: &storage::AuthorizationExpiryTrackingData,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let cancel_request = api::PaymentsCancelRequest {
+        payment_id: tracking_data.payment_id.clone(),
+        cancellation_reason: Some("authorization_expiring".to_string()),
+        ..Default::default()
+    };
+
+    let (payment_data, _, _, _, _) = Box::pin(payment_flows::payments_operation_core::<
+        api::Void,
+        _,
+        _,
+        _,
+        Oss,
+    >(
+        state,
+        state.get_req_state(),
+        merchant_account.clone(),
+        key_store.clone(),
+        operations::PaymentCancel,
+        cancel_request,
+        payment_flows::CallConnectorAction::Trigger,
+        services::AuthFlow::Merchant,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await?;
+
+    logger::info!(
+        payment_id = %tracking_data.payment_id,
+        status = ?payment_data.payment_intent.status,
+        "Voided payment with expiring authorization"
+    );
+
+    // The connector may have declined the void, in which case the payment is left as it is
+    if payment_data.payment_intent.status != enums::IntentStatus::Cancelled {
+        return Ok(());
+    }
+
+    trigger_payment_webhook(
+        state,
+        merchant_account,
+        key_store,
+        payment_data.payment_intent,
+        tracking_data,
+        enums::EventType::PaymentCancelled,
+    )
+    .await
+}
+
+/// Sends a `payment_authorization_expiring` webhook to the merchant, carrying the payment details.
+async fn notify_merchant(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_intent: storage::PaymentIntent,
+    tracking_data: &storage::AuthorizationExpiryTrackingData,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    trigger_payment_webhook(
+        state,
+        merchant_account,
+        key_store,
+        payment_intent,
+        tracking_data,
+        enums::EventType::PaymentAuthorizationExpiring,
+    )
+    .await
+}
+
+async fn trigger_payment_webhook(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_intent: storage::PaymentIntent,
+    tracking_data: &storage::AuthorizationExpiryTrackingData,
+    event_type: enums::EventType,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let (content, _) = outgoing_webhook_retry::get_outgoing_webhook_content_and_event_type(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        key_store.clone(),
+        &tracking_data.merchant_id,
+        enums::EventClass::Payments,
+        &tracking_data.payment_id,
+    )
+    .await?;
+
+    let profile_id = payment_intent.profile_id.ok_or(
+        sch_errors::ProcessTrackerError::ResourceFetchingFailed {
+            resource_name: tracking_data.payment_id.clone(),
+        },
+    )?;
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&profile_id)
+        .await?;
+
+    webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile,
+        &key_store,
+        event_type,
+        enums::EventClass::Payments,
+        tracking_data.payment_id.clone(),
+        enums::EventObjectType::PaymentDetails,
+        content,
+        Some(payment_intent.created_at),
+        Some(tracking_data.attempt_id.clone()),
+    )
+    .await
+    .map_err(|error| {
+        logger::error!(
+            ?error,
+            "Failed to create event and trigger outgoing webhook"
+        );
+        sch_errors::ProcessTrackerError::EApiErrorResponse
+    })
+}
@@ -0,0 +1,89 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::enums as storage_enums;
+use scheduler::{consumer::workflows::ProcessTrackerWorkflow, SchedulerAppState};
+
+use crate::{
+    core::payment_methods::account_updater,
+    errors,
+    logger::error,
+    routes::{metrics, AppState},
+    types::storage,
+};
+
+pub struct CardAccountUpdaterWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for CardAccountUpdaterWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: storage::CardAccountUpdaterTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CardAccountUpdaterTrackingData")?;
+
+        let should_reschedule =
+            account_updater::execute_card_account_update(state, &tracking_data).await?;
+
+        if should_reschedule {
+            reschedule_card_account_update(state, process).await
+        } else {
+            state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                .await?;
+            Ok(())
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        // The card is submitted again in the next cycle
+        reschedule_card_account_update(state, process)
+            .await
+            .map_err(error_stack::Report::new)
+    }
+}
+
+async fn reschedule_card_account_update(
+    state: &AppState,
+    process: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::days(
+        account_updater::CARD_ACCOUNT_UPDATER_INTERVAL_IN_DAYS,
+    ));
+    let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: None,
+        schedule_time: Some(schedule_time),
+        tracking_data: None,
+        business_status: None,
+        status: Some(storage_enums::ProcessTrackerStatus::New),
+        updated_at: Some(common_utils::date_time::now()),
+    };
+    state
+        .store
+        .process_tracker_update_process_status_by_ids(
+            vec![process.id],
+            updated_process_tracker_data,
+        )
+        .await?;
+    metrics::TASKS_RESET_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "flow",
+            "CardAccountUpdater",
+        )],
+    );
+
+    Ok(())
+}
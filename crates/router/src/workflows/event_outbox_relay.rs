@@ -0,0 +1,395 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use api_models::webhooks::OutgoingWebhookContent;
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use scheduler::consumer::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::webhooks as webhooks_core,
+    errors,
+    logger::{self, error},
+    routes::{metrics, AppState},
+    types::{api, storage, transformers::ForeignFrom},
+    workflows::outgoing_webhook_retry,
+};
+
+const EVENT_OUTBOX_RELAY_TASK_ID: &str = "EVENT_OUTBOX_RELAY";
+const EVENT_OUTBOX_RELAY_NAME: &str = "EVENT_OUTBOX_RELAY";
+const EVENT_OUTBOX_RELAY_TAG: &str = "EVENT_OUTBOX";
+
+pub struct EventOutboxRelayWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for EventOutboxRelayWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let config = &state.conf.event_outbox;
+        let created_before = common_utils::date_time::now()
+            .saturating_sub(time::Duration::seconds(config.grace_period_in_secs));
+        let mut after_id = 0;
+        let mut relayed_count: usize = 0;
+
+        loop {
+            let entries = state
+                .store
+                .find_event_outbox_entries_created_before(
+                    created_before,
+                    after_id,
+                    config.batch_size,
+                )
+                .await?;
+            let is_last_batch = i64::try_from(entries.len())
+                .map_or(true, |entry_count| entry_count < config.batch_size);
+
+            // Entries that fail to be relayed are left in place, so the next batch starts after
+            // the last entry of this one
+            let Some(last_entry) = entries.last() else {
+                break;
+            };
+            after_id = last_entry.id;
+
+            // Entries are discarded while the relay is disabled, so that the table does not keep
+            // growing with status changes that are never relayed
+            let (relayed_ids, failed_entries) = if config.enabled {
+                relay_event_outbox_entries(state, entries).await
+            } else {
+                (
+                    entries.into_iter().map(|entry| entry.id).collect(),
+                    Vec::new(),
+                )
+            };
+            if config.enabled {
+                relayed_count = relayed_count.saturating_add(relayed_ids.len());
+            }
+
+            let (discarded_entries, retried_entries): (Vec<_>, Vec<_>) = failed_entries
+                .into_iter()
+                .partition(|entry| entry.retry_count >= config.max_retries);
+            for entry in &discarded_entries {
+                logger::error!(
+                    merchant_id = %entry.merchant_id,
+                    primary_object_id = %entry.primary_object_id,
+                    status = %entry.status,
+                    retry_count = entry.retry_count,
+                    "Discarding event outbox entry after exhausting retries"
+                );
+            }
+
+            let deleted_ids = relayed_ids
+                .into_iter()
+                .chain(discarded_entries.into_iter().map(|entry| entry.id))
+                .collect::<Vec<_>>();
+            if !deleted_ids.is_empty() {
+                state
+                    .store
+                    .delete_event_outbox_entries_by_ids(deleted_ids)
+                    .await?;
+            }
+            if !retried_entries.is_empty() {
+                state
+                    .store
+                    .increment_event_outbox_entries_retry_count_by_ids(
+                        retried_entries.into_iter().map(|entry| entry.id).collect(),
+                    )
+                    .await?;
+            }
+
+            if is_last_batch {
+                break;
+            }
+        }
+
+        logger::info!(
+            relayed_count,
+            "Relayed event outbox entries created before {created_before}"
+        );
+
+        reschedule_event_outbox_relay(state, process).await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        // Entries that were not deleted are picked up in the next run
+        reschedule_event_outbox_relay(state, process)
+            .await
+            .map_err(error_stack::Report::new)
+    }
+}
+
+/// The status recorded in an outbox entry.
+#[derive(Clone, Copy, Debug)]
+enum RecordedStatus {
+    Payment(storage_enums::IntentStatus),
+    Refund(storage_enums::RefundStatus),
+}
+
+impl RecordedStatus {
+    fn from_entry(entry: &storage::EventOutbox) -> Result<Self, errors::ProcessTrackerError> {
+        match entry.event_class {
+            storage_enums::EventClass::Payments => {
+                storage_enums::IntentStatus::from_str(&entry.status).map(Self::Payment)
+            }
+            storage_enums::EventClass::Refunds => {
+                storage_enums::RefundStatus::from_str(&entry.status).map(Self::Refund)
+            }
+            storage_enums::EventClass::Disputes
+            | storage_enums::EventClass::Mandates
+            | storage_enums::EventClass::PaymentMethods
+            | storage_enums::EventClass::Connectors => {
+                return Err(errors::ProcessTrackerError::UnexpectedFlow)
+            }
+        }
+        .map_err(|_| errors::ProcessTrackerError::UnexpectedFlow)
+    }
+
+    fn event_type(self) -> Option<storage_enums::EventType> {
+        match self {
+            Self::Payment(status) => Option::<storage_enums::EventType>::foreign_from(status),
+            Self::Refund(status) => Option::<storage_enums::EventType>::foreign_from(status),
+        }
+    }
+
+    /// Overrides the status in the webhook content with the recorded one, since the object may
+    /// have moved on to another status since the change was recorded.
+    fn apply_to(self, content: &mut OutgoingWebhookContent) {
+        match (self, content) {
+            (Self::Payment(status), OutgoingWebhookContent::PaymentDetails(payments_response)) => {
+                payments_response.status = status;
+            }
+            (Self::Refund(status), OutgoingWebhookContent::RefundDetails(refund_response)) => {
+                refund_response.status = api::refunds::RefundStatus::foreign_from(status);
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Relays the outbox entries of a batch, one object at a time. Returns the IDs of the entries that
+/// were relayed, along with the entries that could not be relayed.
+async fn relay_event_outbox_entries(
+    state: &AppState,
+    entries: Vec<storage::EventOutbox>,
+) -> (Vec<i64>, Vec<storage::EventOutbox>) {
+    let mut entries_by_object: HashMap<_, Vec<_>> = HashMap::new();
+    for entry in entries {
+        entries_by_object
+            .entry((
+                entry.merchant_id.clone(),
+                entry.event_class,
+                entry.primary_object_id.clone(),
+            ))
+            .or_default()
+            .push(entry);
+    }
+
+    let mut relayed_ids = Vec::new();
+    let mut failed_entries = Vec::new();
+    for ((merchant_id, event_class, primary_object_id), entries) in entries_by_object {
+        match relay_object_status_changes(
+            state,
+            &merchant_id,
+            event_class,
+            &primary_object_id,
+            &entries,
+        )
+        .await
+        {
+            Ok(()) => relayed_ids.extend(entries.into_iter().map(|entry| entry.id)),
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    %merchant_id,
+                    %primary_object_id,
+                    "Failed to relay event outbox entries"
+                );
+                failed_entries.extend(entries);
+            }
+        }
+    }
+
+    (relayed_ids, failed_entries)
+}
+
+/// Raises the outgoing webhooks for the statuses recorded for an object. Webhooks already raised by
+/// the inline flow are skipped before the object is retrieved, and the object is retrieved at most
+/// once for all of its entries.
+async fn relay_object_status_changes(
+    state: &AppState,
+    merchant_id: &str,
+    event_class: storage_enums::EventClass,
+    primary_object_id: &str,
+    entries: &[storage::EventOutbox],
+) -> Result<(), errors::ProcessTrackerError> {
+    let mut pending_events = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let recorded_status = RecordedStatus::from_entry(entry)?;
+        // Webhooks are not raised for every status
+        if let Some(event_type) = recorded_status.event_type() {
+            pending_events.push((event_type, recorded_status));
+        }
+    }
+    if pending_events.is_empty() {
+        return Ok(());
+    }
+
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await?;
+
+    let raised_event_types = db
+        .list_initial_events_by_merchant_id_primary_object_id(
+            merchant_id,
+            primary_object_id,
+            &key_store,
+        )
+        .await?
+        .into_iter()
+        .map(|event| event.event_type)
+        .collect::<HashSet<_>>();
+    pending_events.retain(|(event_type, _)| !raised_event_types.contains(event_type));
+    if pending_events.is_empty() {
+        return Ok(());
+    }
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await?;
+    let (content, _) = outgoing_webhook_retry::get_outgoing_webhook_content_and_event_type(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        key_store.clone(),
+        merchant_id,
+        event_class,
+        primary_object_id,
+    )
+    .await?;
+
+    let (profile_id, primary_object_created_at, primary_object_type) = match &content {
+        OutgoingWebhookContent::PaymentDetails(payments_response) => (
+            payments_response.profile_id.clone(),
+            payments_response.created,
+            storage_enums::EventObjectType::PaymentDetails,
+        ),
+        OutgoingWebhookContent::RefundDetails(refund_response) => (
+            refund_response.profile_id.clone(),
+            refund_response.created_at,
+            storage_enums::EventObjectType::RefundDetails,
+        ),
+        OutgoingWebhookContent::DisputeDetails(_)
+        | OutgoingWebhookContent::MandateDetails(_)
+        | OutgoingWebhookContent::PaymentMethodDetails(_)
+        | OutgoingWebhookContent::ConnectorHealthDetails(_) => {
+            return Err(errors::ProcessTrackerError::UnexpectedFlow)
+        }
+    };
+    let profile_id = profile_id.ok_or(errors::ProcessTrackerError::ResourceFetchingFailed {
+        resource_name: primary_object_id.to_owned(),
+    })?;
+    let business_profile = db.find_business_profile_by_profile_id(&profile_id).await?;
+
+    for (event_type, recorded_status) in pending_events {
+        let mut content = content.clone();
+        recorded_status.apply_to(&mut content);
+
+        webhooks_core::create_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_account.clone(),
+            business_profile.clone(),
+            &key_store,
+            event_type,
+            event_class,
+            primary_object_id.to_owned(),
+            primary_object_type,
+            content,
+            primary_object_created_at,
+            None,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to create event and trigger outgoing webhook"
+            );
+            errors::ProcessTrackerError::EApiErrorResponse
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Adds the event outbox relay task to the process tracker, unless it already exists.
+pub async fn add_event_outbox_relay_task(
+    state: &AppState,
+) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        EVENT_OUTBOX_RELAY_TASK_ID,
+        EVENT_OUTBOX_RELAY_NAME,
+        storage::ProcessTrackerRunner::EventOutboxRelayWorkflow,
+        [EVENT_OUTBOX_RELAY_TAG],
+        serde_json::json!({}),
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+    .attach_printable("Failed to construct event outbox relay process tracker task")?;
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes("flow", "EventOutboxRelay")],
+            );
+            Ok(())
+        }
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+            .attach_printable("Failed while inserting event outbox relay task"),
+    }
+}
+
+async fn reschedule_event_outbox_relay(
+    state: &AppState,
+    process: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+        state.conf.event_outbox.interval_in_secs,
+    ));
+    let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: None,
+        schedule_time: Some(schedule_time),
+        tracking_data: None,
+        business_status: None,
+        status: Some(storage_enums::ProcessTrackerStatus::New),
+        updated_at: Some(common_utils::date_time::now()),
+    };
+    state
+        .store
+        .process_tracker_update_process_status_by_ids(
+            vec![process.id],
+            updated_process_tracker_data,
+        )
+        .await?;
+    metrics::TASKS_RESET_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes("flow", "EventOutboxRelay")],
+    );
+
+    Ok(())
+}
@@ -14,7 +14,11 @@ use scheduler::{
 };
 
 use crate::{
-    core::webhooks::{self as webhooks_core, types::OutgoingWebhookTrackingData},
+    consts,
+    core::{
+        metrics,
+        webhooks::{self as webhooks_core, types::OutgoingWebhookTrackingData},
+    },
     db::StorageInterface,
     errors, logger,
     routes::{app::ReqState, AppState},
@@ -97,6 +101,10 @@ impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
             request: initial_event.request,
             response: None,
             delivery_attempt: Some(delivery_attempt),
+            dead_lettered_at: None,
+            webhook_endpoint_id: initial_event.webhook_endpoint_id,
+            sequence_number: initial_event.sequence_number,
+            triggering_attempt_id: initial_event.triggering_attempt_id,
         };
 
         let event = db
@@ -140,7 +148,9 @@ impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
                     state.get_req_state(),
                     merchant_account.clone(),
                     key_store.clone(),
-                    &tracking_data,
+                    &tracking_data.merchant_id,
+                    tracking_data.event_class,
+                    &tracking_data.primary_object_id,
                 )
                 .await?;
 
@@ -153,12 +163,29 @@ impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
                             event_type,
                             content: content.clone(),
                             timestamp: event.created_at,
+                            sequence_number: event.sequence_number,
+                            triggering_attempt_id: event.triggering_attempt_id.clone(),
+                        };
+
+                        // The endpoint is looked up again when the webhook is delivered, and the
+                        // task is finished there if the endpoint no longer exists
+                        let webhook_endpoint = match event.webhook_endpoint_id.as_deref() {
+                            Some(webhook_endpoint_id) => db
+                                .find_webhook_endpoint_by_merchant_id_endpoint_id(
+                                    &tracking_data.merchant_id,
+                                    webhook_endpoint_id,
+                                    &key_store,
+                                )
+                                .await
+                                .ok(),
+                            None => None,
                         };
 
                         let request_content = webhooks_core::get_outgoing_webhook_request(
                             &merchant_account,
+                            &business_profile,
+                            webhook_endpoint.as_ref(),
                             outgoing_webhook,
-                            business_profile.payment_response_hash_key.as_deref(),
                         )
                         .map_err(|error| {
                             logger::error!(
@@ -304,6 +331,7 @@ pub(crate) async fn retry_webhook_delivery_task(
                 .await
         }
         None => {
+            dead_letter_webhook_event(db, merchant_id, &process).await;
             db.as_scheduler()
                 .finish_process_with_business_status(process, "RETRIES_EXCEEDED".to_string())
                 .await
@@ -311,13 +339,87 @@ pub(crate) async fn retry_webhook_delivery_task(
     }
 }
 
+/// Moves the event of a webhook that could not be delivered within the configured retries to the
+/// dead-letter queue of the merchant, from where it can be requeued once the merchant's endpoint
+/// is back up. Operators are alerted when the queue of a merchant grows beyond
+/// [`consts::WEBHOOK_DEAD_LETTER_QUEUE_ALERT_THRESHOLD`]. Failures are only logged, so that the
+/// task is finished regardless.
+#[instrument(skip_all)]
+async fn dead_letter_webhook_event(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    process: &storage::ProcessTracker,
+) {
+    let result = async {
+        let tracking_data: OutgoingWebhookTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("OutgoingWebhookTrackingData")
+            .change_context(errors::StorageError::DeserializationFailed)?;
+        let Some(initial_attempt_id) = tracking_data.initial_attempt_id else {
+            logger::debug!(
+                "Tracking data does not contain initial attempt ID, skipping dead-lettering"
+            );
+            return Ok(());
+        };
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        db.update_event_by_merchant_id_event_id(
+            merchant_id,
+            &initial_attempt_id,
+            domain::EventUpdate::DeadLetterUpdate {
+                dead_lettered_at: Some(common_utils::date_time::now()),
+            },
+            &key_store,
+        )
+        .await?;
+
+        let merchant_id_attribute = metrics::KeyValue::new("merchant_id", merchant_id.to_owned());
+        metrics::WEBHOOK_OUTGOING_DEAD_LETTERED_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &[merchant_id_attribute.clone()],
+        );
+
+        let dead_lettered_events_count = db
+            .count_dead_lettered_events_by_merchant_id(merchant_id)
+            .await?;
+        if dead_lettered_events_count > consts::WEBHOOK_DEAD_LETTER_QUEUE_ALERT_THRESHOLD {
+            logger::error!(
+                %merchant_id,
+                threshold = consts::WEBHOOK_DEAD_LETTER_QUEUE_ALERT_THRESHOLD,
+                "Dead-letter queue of outgoing webhooks has grown beyond the alert threshold"
+            );
+            metrics::WEBHOOK_DEAD_LETTER_QUEUE_THRESHOLD_EXCEEDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[merchant_id_attribute],
+            );
+        }
+
+        Ok::<_, error_stack::Report<errors::StorageError>>(())
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to move webhook event to dead-letter queue");
+    }
+}
+
 #[instrument(skip_all)]
-async fn get_outgoing_webhook_content_and_event_type(
+pub(crate) async fn get_outgoing_webhook_content_and_event_type(
     state: AppState,
     req_state: ReqState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
-    tracking_data: &OutgoingWebhookTrackingData,
+    merchant_id: &str,
+    event_class: diesel_models::enums::EventClass,
+    primary_object_id: &str,
 ) -> Result<(OutgoingWebhookContent, Option<EventType>), errors::ProcessTrackerError> {
     use api_models::{
         mandates::MandateId,
@@ -340,12 +442,12 @@ async fn get_outgoing_webhook_content_and_event_type(
         },
     };
 
-    match tracking_data.event_class {
+    match event_class {
         diesel_models::enums::EventClass::Payments => {
-            let payment_id = tracking_data.primary_object_id.clone();
+            let payment_id = primary_object_id.to_owned();
             let request = PaymentsRetrieveRequest {
                 resource_id: PaymentIdType::PaymentIntentId(payment_id),
-                merchant_id: Some(tracking_data.merchant_id.clone()),
+                merchant_id: Some(merchant_id.to_owned()),
                 force_sync: false,
                 ..Default::default()
             };
@@ -376,7 +478,7 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::PaymentLinkForm(_)
                     | ApplicationResponse::FileData(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
-                            resource_name: tracking_data.primary_object_id.clone(),
+                            resource_name: primary_object_id.to_owned(),
                         })
                     }
                 }?;
@@ -390,7 +492,7 @@ async fn get_outgoing_webhook_content_and_event_type(
         }
 
         diesel_models::enums::EventClass::Refunds => {
-            let refund_id = tracking_data.primary_object_id.clone();
+            let refund_id = primary_object_id.to_owned();
             let request = RefundsRetrieveRequest {
                 refund_id,
                 force_sync: Some(false),
@@ -415,7 +517,7 @@ async fn get_outgoing_webhook_content_and_event_type(
         }
 
         diesel_models::enums::EventClass::Disputes => {
-            let dispute_id = tracking_data.primary_object_id.clone();
+            let dispute_id = primary_object_id.to_owned();
             let request = DisputeId { dispute_id };
 
             let dispute_response =
@@ -431,7 +533,7 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::PaymentLinkForm(_)
                     | ApplicationResponse::FileData(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
-                            resource_name: tracking_data.primary_object_id.clone(),
+                            resource_name: primary_object_id.to_owned(),
                         })
                     }
                 }
@@ -446,7 +548,7 @@ async fn get_outgoing_webhook_content_and_event_type(
         }
 
         diesel_models::enums::EventClass::Mandates => {
-            let mandate_id = tracking_data.primary_object_id.clone();
+            let mandate_id = primary_object_id.to_owned();
             let request = MandateId { mandate_id };
 
             let mandate_response =
@@ -462,7 +564,7 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::PaymentLinkForm(_)
                     | ApplicationResponse::FileData(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
-                            resource_name: tracking_data.primary_object_id.clone(),
+                            resource_name: primary_object_id.to_owned(),
                         })
                     }
                 }
@@ -475,5 +577,154 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        diesel_models::enums::EventClass::PaymentMethods => {
+            get_payment_method_webhook_content_and_event_type(
+                &state,
+                &merchant_account,
+                &key_store,
+                primary_object_id,
+            )
+            .await
+        }
+
+        // Connector events describe the circuit breaker at the time it opened, which is not kept
+        // once the breaker changes state, so the content of the original event is sent again
+        diesel_models::enums::EventClass::Connectors => {
+            let connector_health: api_models::routing::ConnectorHealthResponse =
+                get_object_from_last_webhook_request(
+                    &state,
+                    &merchant_account,
+                    &key_store,
+                    primary_object_id,
+                )
+                .await?;
+
+            Ok((
+                OutgoingWebhookContent::ConnectorHealthDetails(Box::new(connector_health)),
+                Some(EventType::ConnectorCircuitBreakerOpened),
+            ))
+        }
     }
 }
+
+/// Builds the content of a payment method webhook from the payment method record. Deleted payment
+/// methods no longer have a record, so their content is taken from the last event raised for them.
+async fn get_payment_method_webhook_content_and_event_type(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method_id: &str,
+) -> Result<(OutgoingWebhookContent, Option<EventType>), errors::ProcessTrackerError> {
+    use crate::core::payment_methods::cards;
+
+    match state
+        .store
+        .find_payment_method(payment_method_id, merchant_account.storage_scheme)
+        .await
+    {
+        Ok(payment_method) => {
+            let (card, event_type) = match payment_method.status {
+                storage::enums::PaymentMethodStatus::Inactive => {
+                    (None, EventType::PaymentMethodClosed)
+                }
+                storage::enums::PaymentMethodStatus::Active
+                | storage::enums::PaymentMethodStatus::AwaitingData
+                | storage::enums::PaymentMethodStatus::Processing => {
+                    let card = if payment_method.payment_method
+                        == Some(storage::enums::PaymentMethod::Card)
+                    {
+                        cards::get_card_details_with_locker_fallback(
+                            &payment_method,
+                            key_store.key.get_inner().peek(),
+                            state,
+                        )
+                        .await?
+                    } else {
+                        None
+                    };
+                    (card, EventType::PaymentMethodUpdated)
+                }
+            };
+            logger::debug!(current_resource_status=%payment_method.status);
+
+            Ok((
+                OutgoingWebhookContent::PaymentMethodDetails(Box::new(
+                    cards::get_payment_method_webhook_content(&payment_method, card),
+                )),
+                Some(event_type),
+            ))
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            let payment_method_response: api_models::payment_methods::PaymentMethodResponse =
+                get_object_from_last_webhook_request(
+                    state,
+                    merchant_account,
+                    key_store,
+                    payment_method_id,
+                )
+                .await?;
+
+            Ok((
+                OutgoingWebhookContent::PaymentMethodDetails(Box::new(payment_method_response)),
+                Some(EventType::PaymentMethodDeleted),
+            ))
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Reads the object sent in the last webhook raised for the resource, for resources whose current
+/// state cannot be fetched anymore.
+async fn get_object_from_last_webhook_request<T>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    primary_object_id: &str,
+) -> Result<T, errors::ProcessTrackerError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let resource_fetching_failed = || errors::ProcessTrackerError::ResourceFetchingFailed {
+        resource_name: primary_object_id.to_owned(),
+    };
+
+    let last_event = state
+        .store
+        .list_initial_events_by_merchant_id_primary_object_id(
+            &merchant_account.merchant_id,
+            primary_object_id,
+            key_store,
+        )
+        .await?
+        .into_iter()
+        .filter(|event| event.request.is_some())
+        .max_by_key(|event| event.created_at)
+        .ok_or_else(resource_fetching_failed)?;
+
+    last_event
+        .request
+        .as_ref()
+        .map(|request| {
+            request
+                .get_inner()
+                .peek()
+                .parse_struct::<OutgoingWebhookRequestContent>("OutgoingWebhookRequestContent")
+        })
+        .transpose()?
+        .and_then(|request_content| get_object_from_webhook_body(request_content.body.peek()))
+        .ok_or_else(resource_fetching_failed)
+}
+
+/// Extracts the object sent in the body of a webhook.
+fn get_object_from_webhook_body<T>(body: &str) -> Option<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()?
+        .get_mut("content")?
+        .get_mut("object")
+        .map(serde_json::Value::take)
+        .and_then(|object| serde_json::from_value(object).ok())
+}
@@ -0,0 +1,224 @@
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use scheduler::consumer::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    errors,
+    logger::{self, error},
+    routes::{metrics, AppState},
+    types::storage,
+};
+
+const PAYMENT_DATA_ARCHIVAL_TASK_ID: &str = "PAYMENT_DATA_ARCHIVAL";
+const PAYMENT_DATA_ARCHIVAL_NAME: &str = "PAYMENT_DATA_ARCHIVAL";
+const PAYMENT_DATA_ARCHIVAL_TAG: &str = "DATA_ARCHIVAL";
+
+pub struct PaymentDataArchivalWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for PaymentDataArchivalWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let config = &state.conf.data_archival;
+
+        // The task keeps rescheduling itself while archival is disabled, so that it resumes
+        // once archival is enabled again
+        if config.enabled {
+            let created_before = get_archival_cutoff(
+                common_utils::date_time::now(),
+                config.retention_period_in_days,
+            );
+            let archived_count = archive_in_batches(config.batch_size, || {
+                state
+                    .store
+                    .archive_payments_created_before(created_before, config.batch_size)
+            })
+            .await?;
+
+            logger::info!(
+                archived_count,
+                "Archived payments created before {created_before}"
+            );
+        }
+
+        reschedule_payment_data_archival(state, process).await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        // Payments that were not archived are picked up in the next run
+        reschedule_payment_data_archival(state, process)
+            .await
+            .map_err(error_stack::Report::new)
+    }
+}
+
+/// Payments created before the returned time are past the retention period and can be archived.
+fn get_archival_cutoff(
+    now: time::PrimitiveDateTime,
+    retention_period_in_days: i64,
+) -> time::PrimitiveDateTime {
+    now.saturating_sub(time::Duration::days(retention_period_in_days))
+}
+
+/// Archives payments in batches of `batch_size` until a batch archives fewer payments than
+/// requested, returning the total number of payments archived.
+async fn archive_in_batches<F, Fut>(
+    batch_size: i64,
+    mut archive_batch: F,
+) -> Result<usize, errors::ProcessTrackerError>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = errors::CustomResult<usize, errors::StorageError>>,
+{
+    let mut archived_count: usize = 0;
+
+    loop {
+        let archived = archive_batch().await?;
+        archived_count = archived_count.saturating_add(archived);
+
+        if i64::try_from(archived).map_or(true, |archived| archived < batch_size) {
+            break;
+        }
+    }
+
+    Ok(archived_count)
+}
+
+/// Adds the payment data archival task to the process tracker, unless it already exists.
+pub async fn add_payment_data_archival_task(
+    state: &AppState,
+) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        PAYMENT_DATA_ARCHIVAL_TASK_ID,
+        PAYMENT_DATA_ARCHIVAL_NAME,
+        storage::ProcessTrackerRunner::PaymentDataArchivalWorkflow,
+        [PAYMENT_DATA_ARCHIVAL_TAG],
+        serde_json::json!({}),
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+    .attach_printable("Failed to construct payment data archival process tracker task")?;
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes(
+                    "flow",
+                    "PaymentDataArchival",
+                )],
+            );
+            Ok(())
+        }
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+            .attach_printable("Failed while inserting payment data archival task"),
+    }
+}
+
+async fn reschedule_payment_data_archival(
+    state: &AppState,
+    process: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::hours(
+        state.conf.data_archival.interval_in_hours,
+    ));
+    let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: None,
+        schedule_time: Some(schedule_time),
+        tracking_data: None,
+        business_status: None,
+        status: Some(storage_enums::ProcessTrackerStatus::New),
+        updated_at: Some(common_utils::date_time::now()),
+    };
+    state
+        .store
+        .process_tracker_update_process_status_by_ids(
+            vec![process.id],
+            updated_process_tracker_data,
+        )
+        .await?;
+    metrics::TASKS_RESET_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "flow",
+            "PaymentDataArchival",
+        )],
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[tokio::test]
+    async fn test_archive_in_batches_stops_at_partial_batch() {
+        let mut batches = vec![2, 2, 1, 2].into_iter();
+
+        let archived_count = archive_in_batches(2, || {
+            std::future::ready(Ok(batches.next().unwrap_or_default()))
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(archived_count, 5);
+        assert_eq!(batches.next(), Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_archive_in_batches_stops_when_nothing_is_archived() {
+        let mut batch_count = 0;
+
+        let archived_count = archive_in_batches(100, || {
+            batch_count += 1;
+            std::future::ready(Ok(0))
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(archived_count, 0);
+        assert_eq!(batch_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_archive_in_batches_propagates_storage_errors() {
+        let mut batches = vec![
+            Ok(10),
+            Err(error_stack::report!(
+                errors::StorageError::DatabaseConnectionError
+            )),
+        ]
+        .into_iter();
+
+        let result =
+            archive_in_batches(10, || std::future::ready(batches.next().unwrap_or(Ok(0)))).await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_archival_cutoff_is_retention_period_before_now() {
+        let now = time::macros::datetime!(2024-06-30 12:00);
+
+        assert_eq!(
+            get_archival_cutoff(now, 30),
+            time::macros::datetime!(2024-05-31 12:00)
+        );
+    }
+}
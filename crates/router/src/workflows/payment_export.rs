@@ -0,0 +1,54 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    SchedulerAppState,
+};
+
+use crate::{core::payment_exports, errors, logger::error, routes::AppState, types::storage};
+
+pub struct PaymentExportWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for PaymentExportWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: storage::PaymentExportTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentExportTrackingData")?;
+
+        payment_exports::generate_payment_export(state, &tracking_data).await?;
+
+        state
+            .get_db()
+            .as_scheduler()
+            .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        // The export is marked as failed rather than retried, so that the merchant can request a
+        // new one instead of waiting on an export that may keep failing
+        let tracking_data: storage::PaymentExportTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentExportTrackingData")
+            .change_context(errors::ProcessTrackerError::DeserializationFailed)?;
+        payment_exports::fail_payment_export(state, &tracking_data)
+            .await
+            .change_context(errors::ProcessTrackerError::EApiErrorResponse)?;
+
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,396 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::{enums as storage_enums, payment_intent::PaymentStatusSyncTrackingData};
+use error_stack::ResultExt;
+use scheduler::consumer::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::{
+        payment_methods::Oss,
+        payments::{self as payment_flows, operations},
+    },
+    errors,
+    logger::{self, error},
+    routes::{metrics, AppState},
+    services,
+    types::{api, storage},
+};
+
+const PAYMENT_STATUS_SYNC_TASK_ID: &str = "PAYMENT_STATUS_SYNC";
+const PAYMENT_STATUS_SYNC_NAME: &str = "PAYMENT_STATUS_SYNC";
+const PAYMENT_STATUS_SYNC_TAG: &str = "SYNC";
+
+/// Statuses of the payments whose status is synced with the connector
+const PAYMENT_STATUS_SYNC_STATUSES: [storage_enums::IntentStatus; 2] = [
+    storage_enums::IntentStatus::Processing,
+    storage_enums::IntentStatus::RequiresCustomerAction,
+];
+
+/// Outcome of the status sync of a payment
+enum PaymentStatusSyncOutcome {
+    Synced,
+    /// The connector of the payment is over its rate limit
+    Throttled,
+    /// The payment was updated since the database row was read, which happens for merchants
+    /// using the KV storage scheme, whose rows lag behind until they are drained
+    Skipped,
+}
+
+pub struct PaymentStatusSyncWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for PaymentStatusSyncWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let config = &state.conf.payment_status_sync;
+        let mut tracking_data: PaymentStatusSyncTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentStatusSyncTrackingData")?;
+
+        // The task keeps rescheduling itself while syncing is disabled, so that it resumes once
+        // syncing is enabled again
+        if config.enabled {
+            let now = common_utils::date_time::now();
+            let created_after = now.saturating_sub(time::Duration::hours(config.timeout_in_hours));
+            let modified_before =
+                now.saturating_sub(time::Duration::seconds(config.stale_after_in_secs));
+            let payments = state
+                .store
+                .find_payments_pending_status_sync(
+                    PAYMENT_STATUS_SYNC_STATUSES.to_vec(),
+                    created_after,
+                    modified_before,
+                    tracking_data.last_synced.take(),
+                    config.batch_size,
+                )
+                .await?;
+            tracking_data = get_next_tracking_data(
+                payments.len(),
+                config.batch_size,
+                payments
+                    .last()
+                    .map(|payment| (payment.modified_at, payment.payment_id.clone())),
+            );
+            let mut synced_count: usize = 0;
+            let mut throttled_count: usize = 0;
+            let mut skipped_count: usize = 0;
+
+            for payment in payments {
+                match sync_payment_status(state, &payment, modified_before).await {
+                    Ok(PaymentStatusSyncOutcome::Synced) => {
+                        synced_count = synced_count.saturating_add(1)
+                    }
+                    Ok(PaymentStatusSyncOutcome::Throttled) => {
+                        throttled_count = throttled_count.saturating_add(1)
+                    }
+                    Ok(PaymentStatusSyncOutcome::Skipped) => {
+                        skipped_count = skipped_count.saturating_add(1)
+                    }
+                    Err(error) => logger::error!(
+                        ?error,
+                        merchant_id = %payment.merchant_id,
+                        payment_id = %payment.payment_id,
+                        "Failed to sync payment status"
+                    ),
+                }
+            }
+
+            logger::info!(
+                synced_count,
+                throttled_count,
+                skipped_count,
+                "Synced payments not modified since {modified_before}"
+            );
+        }
+
+        reschedule_payment_status_sync(state, process, Some(tracking_data)).await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        // Payments that were not synced are picked up in the next run
+        reschedule_payment_status_sync(state, process, None)
+            .await
+            .map_err(error_stack::Report::new)
+    }
+}
+
+/// The position the next run resumes from. Payments are picked up after the last payment of a full
+/// batch, whether or not they could be synced, so that payments which keep failing to sync do not
+/// hold up the others. Once a run reaches the end of the pending payments, the next run starts over.
+fn get_next_tracking_data(
+    fetched_count: usize,
+    batch_size: i64,
+    last_payment: Option<(time::PrimitiveDateTime, String)>,
+) -> PaymentStatusSyncTrackingData {
+    let is_batch_full = i64::try_from(fetched_count).map_or(true, |count| count >= batch_size);
+    PaymentStatusSyncTrackingData {
+        last_synced: last_payment.filter(|_| is_batch_full),
+    }
+}
+
+/// Syncs the status of the payment with the connector. The outgoing webhook is triggered by the
+/// sync flow if the payment has reached a terminal status. Each sync counts against the scheduler
+/// rate limit of the connector like a sync task of its own would, payments whose connector is over
+/// its limit are left for the next run.
+///
+/// The pending payments are read from the database only. For merchants using the KV storage
+/// scheme, the payment is read again through the KV store, and is skipped if it has been updated
+/// since. Payments of such merchants that have not been drained to the database yet are picked up
+/// once they are.
+async fn sync_payment_status(
+    state: &AppState,
+    payment: &diesel_models::PaymentIntent,
+    modified_before: time::PrimitiveDateTime,
+) -> Result<PaymentStatusSyncOutcome, errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let merchant_id = payment.merchant_id.as_str();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await?;
+
+    if merchant_account.storage_scheme == storage_enums::MerchantStorageScheme::RedisKv {
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &payment.payment_id,
+                merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+        if !is_pending_status_sync(
+            payment_intent.status,
+            payment_intent.modified_at,
+            modified_before,
+        ) {
+            return Ok(PaymentStatusSyncOutcome::Skipped);
+        }
+    }
+
+    if let Some(rate_limit) = state
+        .conf
+        .scheduler
+        .as_ref()
+        .map(|scheduler| &scheduler.connector_rate_limit)
+        .filter(|rate_limit| rate_limit.enabled)
+    {
+        let connector = db
+            .find_payment_attempt_by_attempt_id_merchant_id(
+                &payment.active_attempt_id,
+                merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?
+            .connector;
+
+        if let Some(connector) = connector {
+            if scheduler::utils::get_connector_throttled_until(
+                db.as_scheduler(),
+                rate_limit,
+                &connector,
+            )
+            .await
+            .is_some()
+            {
+                logger::debug!(
+                    %connector,
+                    payment_id = %payment.payment_id,
+                    "Connector is over its rate limit, leaving payment for the next run"
+                );
+                return Ok(PaymentStatusSyncOutcome::Throttled);
+            }
+        }
+    }
+
+    let request = api::PaymentsRetrieveRequest {
+        resource_id: api::PaymentIdType::PaymentIntentId(payment.payment_id.clone()),
+        merchant_id: Some(merchant_id.to_string()),
+        force_sync: true,
+        ..Default::default()
+    };
+    Box::pin(payment_flows::payments_operation_core::<
+        api::PSync,
+        _,
+        _,
+        _,
+        Oss,
+    >(
+        state,
+        state.get_req_state(),
+        merchant_account,
+        key_store,
+        operations::PaymentStatus,
+        request,
+        payment_flows::CallConnectorAction::Trigger,
+        services::AuthFlow::Merchant,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await?;
+
+    Ok(PaymentStatusSyncOutcome::Synced)
+}
+
+/// Whether a payment with the status and modification time is still due for a status sync
+fn is_pending_status_sync(
+    status: storage_enums::IntentStatus,
+    modified_at: time::PrimitiveDateTime,
+    modified_before: time::PrimitiveDateTime,
+) -> bool {
+    PAYMENT_STATUS_SYNC_STATUSES.contains(&status) && modified_at < modified_before
+}
+
+/// Adds the payment status sync task to the process tracker, unless it already exists.
+pub async fn add_payment_status_sync_task(
+    state: &AppState,
+) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        PAYMENT_STATUS_SYNC_TASK_ID,
+        PAYMENT_STATUS_SYNC_NAME,
+        storage::ProcessTrackerRunner::PaymentStatusSyncWorkflow,
+        [PAYMENT_STATUS_SYNC_TAG],
+        PaymentStatusSyncTrackingData::default(),
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+    .attach_printable("Failed to construct payment status sync process tracker task")?;
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes(
+                    "flow",
+                    "PaymentStatusSync",
+                )],
+            );
+            Ok(())
+        }
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+            .attach_printable("Failed while inserting payment status sync task"),
+    }
+}
+
+async fn reschedule_payment_status_sync(
+    state: &AppState,
+    process: storage::ProcessTracker,
+    tracking_data: Option<PaymentStatusSyncTrackingData>,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = tracking_data
+        .map(serde_json::to_value)
+        .transpose()
+        .change_context(errors::ProcessTrackerError::SerializationFailed)
+        .attach_printable("Failed to serialize payment status sync tracking data")?;
+    let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+        state.conf.payment_status_sync.interval_in_secs,
+    ));
+    let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: None,
+        schedule_time: Some(schedule_time),
+        tracking_data,
+        business_status: None,
+        status: Some(storage_enums::ProcessTrackerStatus::New),
+        updated_at: Some(common_utils::date_time::now()),
+    };
+    state
+        .store
+        .process_tracker_update_process_status_by_ids(
+            vec![process.id],
+            updated_process_tracker_data,
+        )
+        .await?;
+    metrics::TASKS_RESET_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "flow",
+            "PaymentStatusSync",
+        )],
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_next_run_resumes_after_full_batch() {
+        let modified_at = common_utils::date_time::now();
+        let tracking_data = get_next_tracking_data(2, 2, Some((modified_at, "pay_2".to_string())));
+
+        assert_eq!(
+            tracking_data.last_synced,
+            Some((modified_at, "pay_2".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_next_run_starts_over_after_partial_batch() {
+        let modified_at = common_utils::date_time::now();
+
+        assert!(
+            get_next_tracking_data(1, 2, Some((modified_at, "pay_1".to_string())))
+                .last_synced
+                .is_none()
+        );
+        assert!(get_next_tracking_data(0, 2, None).last_synced.is_none());
+    }
+
+    #[test]
+    fn test_payment_updated_since_read_is_not_pending_status_sync() {
+        let now = common_utils::date_time::now();
+        let modified_before = now.saturating_sub(time::Duration::minutes(10));
+        let stale = now.saturating_sub(time::Duration::hours(1));
+
+        assert!(is_pending_status_sync(
+            storage_enums::IntentStatus::Processing,
+            stale,
+            modified_before
+        ));
+        assert!(!is_pending_status_sync(
+            storage_enums::IntentStatus::Processing,
+            now,
+            modified_before
+        ));
+        assert!(!is_pending_status_sync(
+            storage_enums::IntentStatus::Succeeded,
+            stale,
+            modified_before
+        ));
+    }
+
+    #[test]
+    fn test_tracking_data_round_trip() {
+        let tracking_data = PaymentStatusSyncTrackingData {
+            last_synced: Some((common_utils::date_time::now(), "pay_1".to_string())),
+        };
+        let parsed: PaymentStatusSyncTrackingData = serde_json::to_value(&tracking_data)
+            .unwrap()
+            .parse_value("PaymentStatusSyncTrackingData")
+            .unwrap();
+
+        assert_eq!(parsed.last_synced, tracking_data.last_synced);
+        assert!(serde_json::json!({})
+            .parse_value::<PaymentStatusSyncTrackingData>("PaymentStatusSyncTrackingData")
+            .unwrap()
+            .last_synced
+            .is_none());
+    }
+}
@@ -150,8 +150,9 @@ impl ProcessTrackerWorkflow<AppState> for PaymentsSyncWorkflow {
                         .await
                         .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
-                    payment_data.payment_intent = db
-                        .update_payment_intent(
+                    payment_data.payment_intent =
+                        payment_flows::helpers::update_payment_intent_with_connector_outcome(
+                            db,
                             payment_data.payment_intent,
                             payment_intent_update,
                             merchant_account.storage_scheme,
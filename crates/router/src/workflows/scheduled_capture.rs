@@ -0,0 +1,369 @@
+use std::collections::HashMap;
+
+use common_utils::ext_traits::{StringExt, ValueExt};
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, types::process_data, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors, utils as scheduler_utils,
+};
+
+use crate::{
+    core::{
+        payment_methods::Oss,
+        payments::{self as payment_flows, operations},
+        webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    workflows::outgoing_webhook_retry,
+};
+
+pub struct ScheduledCaptureWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for ScheduledCaptureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::ScheduledCaptureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ScheduledCaptureTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The payment may have been captured or cancelled since the task was scheduled
+        if payment_intent.status != enums::IntentStatus::RequiresCapture {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                .await?;
+            return Ok(());
+        }
+
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &payment_intent.active_attempt.get_id(),
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        let capture_request = api::PaymentsCaptureRequest {
+            payment_id: tracking_data.payment_id.clone(),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            ..Default::default()
+        };
+
+        // The outgoing webhook for the captured or failed payment is triggered by the capture flow
+        let capture_result = Box::pin(payment_flows::payments_operation_core::<
+            api::Capture,
+            _,
+            _,
+            _,
+            Oss,
+        >(
+            state,
+            state.get_req_state(),
+            merchant_account.clone(),
+            key_store.clone(),
+            operations::PaymentCapture,
+            capture_request,
+            payment_flows::CallConnectorAction::Trigger,
+            services::AuthFlow::Merchant,
+            None,
+            api::HeaderPayload::default(),
+        ))
+        .await;
+
+        let is_captured = match capture_result {
+            Ok((payment_data, _, _, _, _)) => {
+                payment_data.payment_intent.status != enums::IntentStatus::RequiresCapture
+            }
+            Err(error) => {
+                logger::error!(?error, "Failed to capture payment at capture_on");
+                false
+            }
+        };
+
+        if is_captured {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                .await?;
+        } else {
+            let connector = payment_attempt
+                .connector
+                .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?;
+
+            let is_last_retry =
+                retry_scheduled_capture_task(db, &connector, &tracking_data.merchant_id, process)
+                    .await?;
+
+            // The capture flow does not trigger a webhook when it returns an error or leaves the
+            // payment uncaptured, so the merchant is notified here once the retries run out
+            if is_last_retry {
+                logger::error!(
+                    payment_id = %tracking_data.payment_id,
+                    "Scheduled capture retries exhausted, payment left uncaptured"
+                );
+                trigger_capture_failed_webhook(
+                    state,
+                    merchant_account,
+                    key_store,
+                    payment_intent,
+                    payment_attempt.attempt_id,
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Sends a `payment_capture_failed` webhook to the merchant, carrying the payment details.
+async fn trigger_capture_failed_webhook(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_intent: storage::PaymentIntent,
+    attempt_id: String,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let (content, _) = outgoing_webhook_retry::get_outgoing_webhook_content_and_event_type(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        key_store.clone(),
+        &payment_intent.merchant_id,
+        enums::EventClass::Payments,
+        &payment_intent.payment_id,
+    )
+    .await?;
+
+    let profile_id = payment_intent.profile_id.ok_or(
+        sch_errors::ProcessTrackerError::ResourceFetchingFailed {
+            resource_name: payment_intent.payment_id.clone(),
+        },
+    )?;
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&profile_id)
+        .await?;
+
+    webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile,
+        &key_store,
+        enums::EventType::PaymentCaptureFailed,
+        enums::EventClass::Payments,
+        payment_intent.payment_id,
+        enums::EventObjectType::PaymentDetails,
+        content,
+        Some(payment_intent.created_at),
+        Some(attempt_id),
+    )
+    .await
+    .map_err(|error| {
+        logger::error!(
+            ?error,
+            "Failed to create event and trigger outgoing webhook"
+        );
+        sch_errors::ProcessTrackerError::EApiErrorResponse
+    })
+}
+
+/// The retry schedule of failed scheduled captures, used when no schedule is configured for the
+/// connector. Captures are retried sooner than syncs, and for longer, as the authorization is held
+/// in the meantime and is lost once it expires.
+fn get_default_scheduled_capture_retry_mapping() -> process_data::ConnectorPTMapping {
+    process_data::ConnectorPTMapping {
+        default_mapping: process_data::RetryMapping {
+            start_after: 60,
+            frequencies: vec![
+                // 1st to 4th retries happen at intervals of 5 minutes each
+                (60 * 5, 4),
+                // 5th to 10th retries happen at intervals of 1 hour each
+                (60 * 60, 6),
+                // 11th to 14th retries happen at intervals of 6 hours each
+                (60 * 60 * 6, 4),
+            ],
+        },
+        custom_merchant_mapping: HashMap::new(),
+        max_retries_count: 14,
+    }
+}
+
+/// Get the schedule time for the specified retry of a failed scheduled capture.
+///
+/// The schedule time can be configured in configs by the key
+/// `pt_mapping_scheduled_capture_{connector}`, in the same format as the payment sync schedule of
+/// the connector.
+async fn get_scheduled_capture_retry_schedule_time(
+    db: &dyn StorageInterface,
+    connector: &str,
+    merchant_id: &str,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    let key = format!("pt_mapping_scheduled_capture_{connector}");
+    let mapping = db
+        .find_config_by_key(&key)
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("ConnectorPTMapping")
+                .change_context(errors::StorageError::DeserializationFailed)
+        })
+        .unwrap_or_else(|error| {
+            if !error.current_context().is_db_not_found() {
+                logger::error!(
+                    ?error,
+                    "Failed to read scheduled capture retry config `{key}`"
+                );
+            }
+            get_default_scheduled_capture_retry_mapping()
+        });
+
+    let time_delta = scheduler_utils::get_schedule_time(mapping, merchant_id, retry_count);
+
+    scheduler_utils::get_time_from_delta(time_delta)
+}
+
+/// Schedule the scheduled capture task for retry
+///
+/// Returns bool which indicates whether this was the last retry or not
+async fn retry_scheduled_capture_task(
+    db: &dyn StorageInterface,
+    connector: &str,
+    merchant_id: &str,
+    process: storage::ProcessTracker,
+) -> Result<bool, sch_errors::ProcessTrackerError> {
+    let schedule_time = get_scheduled_capture_retry_schedule_time(
+        db,
+        connector,
+        merchant_id,
+        process.retry_count + 1,
+    )
+    .await;
+
+    match schedule_time {
+        Some(schedule_time) => {
+            db.as_scheduler()
+                .retry_process(process, schedule_time)
+                .await?;
+            Ok(false)
+        }
+        None => {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, "RETRIES_EXCEEDED".to_string())
+                .await?;
+            Ok(true)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_default_scheduled_capture_retry_schedule() {
+        let retry_delays = (1..=15)
+            .map(|retry_count| {
+                scheduler_utils::get_schedule_time(
+                    get_default_scheduled_capture_retry_mapping(),
+                    "merchant_id",
+                    retry_count,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(retry_delays.first().copied().flatten(), Some(300));
+        assert_eq!(retry_delays.get(4).copied().flatten(), Some(3600));
+        assert_eq!(retry_delays.get(13).copied().flatten(), Some(21600));
+        assert_eq!(retry_delays.get(14).copied().flatten(), None);
+    }
+
+    #[test]
+    fn test_scheduled_capture_retry_mapping_retries_longer_than_sync() {
+        let capture_mapping = get_default_scheduled_capture_retry_mapping();
+        let sync_mapping = process_data::ConnectorPTMapping::default();
+
+        assert_eq!(
+            capture_mapping.max_retries_count,
+            capture_mapping
+                .default_mapping
+                .frequencies
+                .iter()
+                .map(|(_, count)| count)
+                .sum::<i32>()
+        );
+        assert!(capture_mapping.max_retries_count > sync_mapping.max_retries_count);
+    }
+
+    #[test]
+    fn test_scheduled_capture_retry_mapping_from_config() {
+        let mapping: process_data::ConnectorPTMapping = r#"{
+            "default_mapping": { "start_after": 30, "frequencies": [[120, 2]] },
+            "custom_merchant_mapping": {
+                "merchant_1": { "start_after": 10, "frequencies": [[60, 1]] }
+            },
+            "max_retries_count": 2
+        }"#
+        .to_string()
+        .parse_struct("ConnectorPTMapping")
+        .unwrap();
+
+        let merchant_delays = (1..=2)
+            .map(|retry_count| {
+                scheduler_utils::get_schedule_time(mapping.clone(), "merchant_1", retry_count)
+            })
+            .collect::<Vec<_>>();
+        let default_delays = (1..=3)
+            .map(|retry_count| {
+                scheduler_utils::get_schedule_time(mapping.clone(), "merchant_2", retry_count)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(merchant_delays, vec![Some(60), None]);
+        assert_eq!(default_delays, vec![Some(120), Some(120), None]);
+    }
+}
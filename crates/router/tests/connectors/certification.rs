@@ -0,0 +1,268 @@
+//! Connector certification
+//!
+//! Runs a declared matrix of scenarios against a connector implementation and produces a report
+//! of which scenarios the connector complies with. New connectors are expected to declare the
+//! scenarios they support and pass all of them, run with
+//! `cargo test --package router --test connectors -- certification`.
+
+use std::fmt;
+
+use async_trait::async_trait;
+use router::types::{self, storage::enums};
+
+use crate::utils::{self, ConnectorActions, PaymentInfo};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+pub enum Scenario {
+    /// An automatically captured payment succeeds
+    HappyPath,
+    /// A payment made with the declined payment method data fails
+    Decline,
+    /// A 3DS payment is sent for authentication with a redirection to the issuer
+    ThreeDs,
+    /// Part of an authorized payment can be captured
+    PartialCapture,
+    /// A refund for more than the payment amount is rejected
+    OverRefundRejection,
+}
+
+pub struct CertificationConfig {
+    /// The scenarios the connector claims to support
+    pub scenarios: Vec<Scenario>,
+    pub payment_data: Option<types::PaymentsAuthorizeData>,
+    /// Payment method data that the connector declines, required for [`Scenario::Decline`]
+    pub declined_payment_data: Option<types::PaymentsAuthorizeData>,
+    /// Payment method data that requires 3DS, defaults to `payment_data`
+    pub three_ds_payment_data: Option<types::PaymentsAuthorizeData>,
+    pub payment_info: Option<PaymentInfo>,
+}
+
+#[derive(Debug)]
+pub enum ScenarioOutcome {
+    Passed,
+    Failed(String),
+    Skipped(&'static str),
+}
+
+#[derive(Debug)]
+pub struct CertificationReport {
+    pub connector: String,
+    pub results: Vec<(Scenario, ScenarioOutcome)>,
+}
+
+impl CertificationReport {
+    pub fn is_compliant(&self) -> bool {
+        self.results
+            .iter()
+            .all(|(_, outcome)| !matches!(outcome, ScenarioOutcome::Failed(_)))
+    }
+}
+
+impl fmt::Display for CertificationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Certification report for {}", self.connector)?;
+        for (scenario, outcome) in &self.results {
+            match outcome {
+                ScenarioOutcome::Passed => writeln!(f, "  [PASSED]  {scenario}")?,
+                ScenarioOutcome::Failed(reason) => writeln!(f, "  [FAILED]  {scenario}: {reason}")?,
+                ScenarioOutcome::Skipped(reason) => {
+                    writeln!(f, "  [SKIPPED] {scenario}: {reason}")?
+                }
+            }
+        }
+        write!(
+            f,
+            "Result: {}",
+            if self.is_compliant() {
+                "compliant"
+            } else {
+                "not compliant"
+            }
+        )
+    }
+}
+
+#[async_trait]
+pub trait ConnectorCertification: ConnectorActions + Sync {
+    async fn run_certification(&self, config: CertificationConfig) -> CertificationReport {
+        let mut results = Vec::with_capacity(config.scenarios.len());
+        for scenario in config.scenarios.iter().copied() {
+            let outcome = match scenario {
+                Scenario::HappyPath => self.certify_happy_path(&config).await,
+                Scenario::Decline => self.certify_decline(&config).await,
+                Scenario::ThreeDs => self.certify_three_ds(&config).await,
+                Scenario::PartialCapture => self.certify_partial_capture(&config).await,
+                Scenario::OverRefundRejection => self.certify_over_refund_rejection(&config).await,
+            };
+            results.push((scenario, outcome));
+        }
+        CertificationReport {
+            connector: self.get_name(),
+            results,
+        }
+    }
+
+    async fn certify_happy_path(&self, config: &CertificationConfig) -> ScenarioOutcome {
+        match self
+            .make_payment(config.payment_data.clone(), config.payment_info.clone())
+            .await
+        {
+            Ok(response) if response.status == enums::AttemptStatus::Charged => {
+                ScenarioOutcome::Passed
+            }
+            Ok(response) => {
+                ScenarioOutcome::Failed(format!("expected status charged, got {}", response.status))
+            }
+            Err(error) => ScenarioOutcome::Failed(error.to_string()),
+        }
+    }
+
+    async fn certify_decline(&self, config: &CertificationConfig) -> ScenarioOutcome {
+        let Some(declined_payment_data) = config.declined_payment_data.clone() else {
+            return ScenarioOutcome::Skipped("no declined payment method data declared");
+        };
+        match self
+            .make_payment(Some(declined_payment_data), config.payment_info.clone())
+            .await
+        {
+            Ok(response)
+                if response.response.is_err()
+                    || matches!(
+                        response.status,
+                        enums::AttemptStatus::Failure | enums::AttemptStatus::AuthorizationFailed
+                    ) =>
+            {
+                ScenarioOutcome::Passed
+            }
+            Ok(response) => ScenarioOutcome::Failed(format!(
+                "expected the payment to be declined, got {}",
+                response.status
+            )),
+            Err(error) => ScenarioOutcome::Failed(error.to_string()),
+        }
+    }
+
+    async fn certify_three_ds(&self, config: &CertificationConfig) -> ScenarioOutcome {
+        let payment_info = PaymentInfo {
+            auth_type: Some(enums::AuthenticationType::ThreeDs),
+            ..config.payment_info.clone().unwrap_or_default()
+        };
+        let payment_data = config
+            .three_ds_payment_data
+            .clone()
+            .or_else(|| config.payment_data.clone());
+        match self.make_payment(payment_data, Some(payment_info)).await {
+            Ok(types::RouterData {
+                status: enums::AttemptStatus::AuthenticationPending,
+                response:
+                    Ok(types::PaymentsResponseData::TransactionResponse {
+                        redirection_data: Some(_),
+                        ..
+                    }),
+                ..
+            }) => ScenarioOutcome::Passed,
+            Ok(response) => ScenarioOutcome::Failed(format!(
+                "expected status authentication_pending with a redirection, got {}",
+                response.status
+            )),
+            Err(error) => ScenarioOutcome::Failed(error.to_string()),
+        }
+    }
+
+    async fn certify_partial_capture(&self, config: &CertificationConfig) -> ScenarioOutcome {
+        let authorize_response = match self
+            .authorize_payment(config.payment_data.clone(), config.payment_info.clone())
+            .await
+        {
+            Ok(response) if response.status == enums::AttemptStatus::Authorized => response,
+            Ok(response) => {
+                return ScenarioOutcome::Failed(format!(
+                    "expected status authorized, got {}",
+                    response.status
+                ))
+            }
+            Err(error) => return ScenarioOutcome::Failed(error.to_string()),
+        };
+        let amount = authorize_response.request.amount;
+        let Some(transaction_id) = utils::get_connector_transaction_id(authorize_response.response)
+        else {
+            return ScenarioOutcome::Failed("missing connector transaction id".to_string());
+        };
+        match self
+            .capture_payment(
+                transaction_id,
+                Some(types::PaymentsCaptureData {
+                    amount_to_capture: amount / 2,
+                    payment_amount: amount,
+                    currency: authorize_response.request.currency,
+                    ..utils::PaymentCaptureType::default().0
+                }),
+                config.payment_info.clone(),
+            )
+            .await
+        {
+            Ok(response)
+                if matches!(
+                    response.status,
+                    enums::AttemptStatus::Charged
+                        | enums::AttemptStatus::PartialCharged
+                        | enums::AttemptStatus::Pending
+                ) =>
+            {
+                ScenarioOutcome::Passed
+            }
+            Ok(response) => ScenarioOutcome::Failed(format!(
+                "expected the partial capture to succeed, got {}",
+                response.status
+            )),
+            Err(error) => ScenarioOutcome::Failed(error.to_string()),
+        }
+    }
+
+    async fn certify_over_refund_rejection(&self, config: &CertificationConfig) -> ScenarioOutcome {
+        let payment_response = match self
+            .make_payment(config.payment_data.clone(), config.payment_info.clone())
+            .await
+        {
+            Ok(response) if response.status == enums::AttemptStatus::Charged => response,
+            Ok(response) => {
+                return ScenarioOutcome::Failed(format!(
+                    "expected status charged, got {}",
+                    response.status
+                ))
+            }
+            Err(error) => return ScenarioOutcome::Failed(error.to_string()),
+        };
+        let amount = payment_response.request.amount;
+        let Some(transaction_id) = utils::get_connector_transaction_id(payment_response.response)
+        else {
+            return ScenarioOutcome::Failed("missing connector transaction id".to_string());
+        };
+        tokio::time::sleep(std::time::Duration::from_secs(self.get_request_interval())).await;
+        match self
+            .refund_payment(
+                transaction_id,
+                Some(types::RefundsData {
+                    payment_amount: amount,
+                    refund_amount: amount + 1,
+                    currency: payment_response.request.currency,
+                    ..utils::PaymentRefundType::default().0
+                }),
+                config.payment_info.clone(),
+            )
+            .await
+        {
+            Ok(response)
+                if response.response.as_ref().map_or(true, |refund| {
+                    refund.refund_status == enums::RefundStatus::Failure
+                }) =>
+            {
+                ScenarioOutcome::Passed
+            }
+            Ok(_) => ScenarioOutcome::Failed("the refund was not rejected".to_string()),
+            Err(error) => ScenarioOutcome::Failed(error.to_string()),
+        }
+    }
+}
+
+impl<T: ConnectorActions + Sync> ConnectorCertification for T {}
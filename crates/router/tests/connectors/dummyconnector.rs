@@ -5,7 +5,10 @@ use masking::Secret;
 use router::types::{self, domain, storage::enums};
 use test_utils::connector_auth;
 
-use crate::utils::{self, ConnectorActions};
+use crate::{
+    certification::{CertificationConfig, ConnectorCertification, Scenario},
+    utils::{self, ConnectorActions},
+};
 
 #[derive(Clone, Copy)]
 struct DummyConnectorTest;
@@ -440,6 +443,36 @@ async fn should_fail_for_refund_amount_higher_than_payment_amount() {
     );
 }
 
+// Runs the connector certification scenarios
+#[actix_web::test]
+async fn should_pass_connector_certification() {
+    let card_payment_data = |card_number: &str| {
+        Some(types::PaymentsAuthorizeData {
+            payment_method_data: types::domain::PaymentMethodData::Card(domain::Card {
+                card_number: CardNumber::from_str(card_number).unwrap(),
+                ..utils::CCardType::default().0
+            }),
+            ..utils::PaymentAuthorizeType::default().0
+        })
+    };
+    let report = CONNECTOR
+        .run_certification(CertificationConfig {
+            scenarios: vec![
+                Scenario::HappyPath,
+                Scenario::Decline,
+                Scenario::ThreeDs,
+                Scenario::PartialCapture,
+                Scenario::OverRefundRejection,
+            ],
+            payment_data: card_payment_data("4111111111111111"),
+            declined_payment_data: card_payment_data("4000000000000002"),
+            three_ds_payment_data: card_payment_data("4000003800000446"),
+            payment_info: get_default_payment_info(),
+        })
+        .await;
+    assert!(report.is_compliant(), "{report}");
+}
+
 // Connector dependent test cases goes here
 
 // [#478]: add unit tests for non 3DS, wallets & webhooks in connector tests
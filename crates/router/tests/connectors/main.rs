@@ -19,6 +19,8 @@ mod bitpay;
 mod bluesnap;
 mod boku;
 mod cashtocode;
+#[cfg(feature = "dummy_connector")]
+mod certification;
 mod checkout;
 mod coinbase;
 mod cryptopay;
@@ -54,6 +56,7 @@ mod powertranz;
 #[cfg(feature = "dummy_connector")]
 mod prophetpay;
 mod rapyd;
+mod razorpay;
 mod shift4;
 mod square;
 mod stax;
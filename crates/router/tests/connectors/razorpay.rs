@@ -0,0 +1,105 @@
+use masking::Secret;
+use router::types::{self, domain, storage::enums};
+use test_utils::connector_auth;
+
+use crate::utils::{self, ConnectorActions};
+
+#[derive(Clone, Copy)]
+struct RazorpayTest;
+impl ConnectorActions for RazorpayTest {}
+impl utils::Connector for RazorpayTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Razorpay;
+        types::api::ConnectorData {
+            connector: Box::new(&Razorpay),
+            connector_name: types::Connector::Razorpay,
+            get_token: types::api::GetToken::Connector,
+            merchant_connector_id: None,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        utils::to_connector_auth_type(
+            connector_auth::ConnectorAuthentication::new()
+                .razorpay
+                .expect("Missing connector authentication configuration")
+                .into(),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "razorpay".to_string()
+    }
+}
+
+static CONNECTOR: RazorpayTest = RazorpayTest {};
+
+fn get_default_payment_info() -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        currency: Some(enums::Currency::INR),
+        ..Default::default()
+    })
+}
+
+fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        currency: enums::Currency::INR,
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+// Payments are made against an order, which is created in the pre-processing step that is not
+// run by the connector test utilities
+#[actix_web::test]
+async fn should_fail_payment_without_order() {
+    let response = CONNECTOR
+        .make_payment(payment_method_details(), get_default_payment_info())
+        .await;
+    assert!(response.is_err());
+}
+
+// Creates a UPI collect request, the customer has to approve it in their UPI app
+#[ignore = "payments require an order to be created in the pre-processing step"]
+#[actix_web::test]
+async fn should_create_upi_collect_request() {
+    let response = CONNECTOR
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                payment_method_data: domain::PaymentMethodData::Upi(domain::UpiData {
+                    vpa_id: Some(Secret::new("success@razorpay".to_string())),
+                }),
+                ..payment_method_details().unwrap()
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+}
+
+// Synchronizes a payment using the automatic capture flow
+#[ignore = "payments require an order to be created in the pre-processing step"]
+#[actix_web::test]
+async fn should_sync_auto_captured_payment() {
+    let authorize_response = CONNECTOR
+        .make_payment(payment_method_details(), get_default_payment_info())
+        .await
+        .unwrap();
+    let txn_id = utils::get_connector_transaction_id(authorize_response.response);
+    assert_ne!(txn_id, None, "Empty connector transaction id");
+    let response = CONNECTOR
+        .psync_retry_till_status_matches(
+            enums::AttemptStatus::Charged,
+            Some(types::PaymentsSyncData {
+                connector_transaction_id: types::ResponseId::ConnectorTransactionId(
+                    txn_id.unwrap(),
+                ),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                ..Default::default()
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+}
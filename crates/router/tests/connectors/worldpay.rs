@@ -220,6 +220,32 @@ async fn should_refund_succeeded_payment() {
     );
 }
 
+#[actix_web::test]
+#[serial]
+async fn should_partially_refund_succeeded_payment() {
+    let connector = Worldpay {};
+    let _mock = connector.start_server(get_mock_config()).await;
+    let response = connector.make_payment(None, None).await.unwrap();
+
+    let transaction_id = utils::get_connector_transaction_id(response.response).unwrap();
+    let response = connector
+        .refund_payment(
+            transaction_id,
+            Some(types::RefundsData {
+                payment_amount: 1000,
+                refund_amount: 100,
+                ..utils::PaymentRefundType::default().0
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        response.response.unwrap().refund_status,
+        enums::RefundStatus::Success,
+    );
+}
+
 #[actix_web::test]
 #[serial]
 async fn should_sync_refund() {
@@ -296,6 +322,21 @@ fn get_mock_config() -> MockConfig {
             }]
         }
     });
+    let full_refund = partial_refund.clone();
+    let cancelled = json!({
+        "_links": {
+            "payments:events": {
+                "href": "/payments/events/123456"
+            },
+            "curies": [
+                {
+                    "name": "payments",
+                    "href": "/rels/payments/{rel}",
+                    "templated": true
+                }
+            ]
+        }
+    });
     let partial_refund_req_body = json!({
         "value": {
             "amount": 100,
@@ -349,6 +390,16 @@ fn get_mock_config() -> MockConfig {
             Mock::given(method("POST"))
                 .and(path("/payments/settlements/12345".to_string()))
                 .respond_with(ResponseTemplate::new(400).set_body_json(error_resp)),
+            Mock::given(method("POST"))
+                .and(path(
+                    "/payments/authorizations/cancellations/123456".to_string(),
+                ))
+                .respond_with(ResponseTemplate::new(202).set_body_json(cancelled)),
+            Mock::given(method("POST"))
+                .and(path(
+                    "/payments/settlements/refunds/full/123456".to_string(),
+                ))
+                .respond_with(ResponseTemplate::new(202).set_body_json(full_refund)),
             Mock::given(method("POST"))
                 .and(path(
                     "/payments/settlements/refunds/partials/123456".to_string(),
@@ -55,7 +55,7 @@ pub enum Tag {
 }
 
 /// API Flow
-#[derive(Debug, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Display, EnumString, Clone, PartialEq, Eq)]
 pub enum Flow {
     /// Health check
     HealthCheck,
@@ -222,6 +222,10 @@ pub enum Flow {
     RoutingUpdateDefaultConfig,
     /// Routing delete config
     RoutingDeleteConfig,
+    /// Routing simulate configs
+    RoutingSimulate,
+    /// Routing update volume split
+    RoutingUpdateVolumeSplit,
     /// Add record to blocklist
     AddToBlocklist,
     /// Delete record from blocklist
@@ -230,6 +234,12 @@ pub enum Flow {
     ListBlocklist,
     /// Toggle blocklist for merchant
     ToggleBlocklistGuard,
+    /// Request an export of payments
+    PaymentExportCreate,
+    /// Retrieve a payment export
+    PaymentExportRetrieve,
+    /// Download the file of a payment export
+    PaymentExportDownload,
     /// Incoming Webhook Receive
     IncomingWebhookReceive,
     /// Validate payment method flow
@@ -242,6 +252,8 @@ pub enum Flow {
     ApiKeyUpdate,
     /// API Key revoke flow
     ApiKeyRevoke,
+    /// API Key rotate flow
+    ApiKeyRotate,
     /// API Key list flow
     ApiKeyList,
     /// Dispute Retrieve flow
@@ -286,6 +298,20 @@ pub enum Flow {
     BusinessProfileDelete,
     /// List all the business profiles for a merchant
     BusinessProfileList,
+    /// Create a webhook endpoint for a business profile
+    WebhookEndpointCreate,
+    /// List the webhook endpoints of a business profile
+    WebhookEndpointList,
+    /// Retrieve a webhook endpoint
+    WebhookEndpointRetrieve,
+    /// Update a webhook endpoint
+    WebhookEndpointUpdate,
+    /// Delete a webhook endpoint
+    WebhookEndpointDelete,
+    /// Send a test event to a webhook endpoint
+    WebhookEndpointTest,
+    /// List the audit logs of changes to the configuration of a merchant
+    AuditLogList,
     /// Different verification flows
     Verification,
     /// Rust locker migration
@@ -400,12 +426,22 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// List dead-lettered webhook events
+    WebhookEventDeadLetterList,
+    /// Requeue dead-lettered webhook events for delivery
+    WebhookEventDeadLetterRequeue,
     /// Retrieve status of the Poll
     RetrievePollStatus,
+    /// Retrieve the capabilities of a connector
+    RetrieveConnectorCapabilities,
     /// Toggles the extended card info feature in profile level
     ToggleExtendedCardInfo,
     /// Get the extended card info associated to a payment_id
     GetExtendedCardInfo,
+    /// Get the connector request and response payloads of a payment attempt
+    PaymentsConnectorPayloads,
+    /// Get the routing decisions of the attempts of a payment
+    PaymentsRoutingDecisions,
 }
 
 ///
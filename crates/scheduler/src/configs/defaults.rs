@@ -7,6 +7,8 @@ impl Default for super::settings::SchedulerSettings {
             graceful_shutdown_interval: 60000,
             loop_interval: 5000,
             server: super::settings::Server::default(),
+            priority: super::settings::PrioritySettings::default(),
+            connector_rate_limit: super::settings::ConnectorRateLimitSettings::default(),
         }
     }
 }
@@ -41,3 +43,35 @@ impl Default for super::settings::Server {
         }
     }
 }
+
+impl Default for super::settings::PrioritySettings {
+    fn default() -> Self {
+        use diesel_models::process_tracker::ProcessTrackerRunner;
+
+        Self {
+            enabled: false,
+            stream: "SCHEDULER_PRIORITY_STREAM".into(),
+            runners: [
+                ProcessTrackerRunner::PaymentsSyncWorkflow,
+                ProcessTrackerRunner::RefundWorkflowRouter,
+                ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+                ProcessTrackerRunner::ScheduledCaptureWorkflow,
+                ProcessTrackerRunner::AuthorizationExpiryWorkflow,
+            ]
+            .into_iter()
+            .collect(),
+            default_stream_interval: 5,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorRateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_in_secs: 60,
+            default_limit: 600,
+            connectors: std::collections::HashMap::new(),
+        }
+    }
+}
@@ -1,3 +1,6 @@
+use std::collections::{HashMap, HashSet};
+
+use diesel_models::process_tracker::{ProcessTracker, ProcessTrackerRunner};
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use serde::Deserialize;
 
@@ -10,6 +13,8 @@ pub struct SchedulerSettings {
     pub loop_interval: u64,
     pub graceful_shutdown_interval: u64,
     pub server: Server,
+    pub priority: PrioritySettings,
+    pub connector_rate_limit: ConnectorRateLimitSettings,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -37,3 +42,104 @@ pub struct ConsumerSettings {
     pub disabled: bool,
     pub consumer_group: String,
 }
+
+/// Tasks of customer facing runners are pushed to a separate stream, which consumers drain before
+/// the default stream, so that housekeeping tasks do not delay them. Every
+/// `default_stream_interval`-th read starts with the default stream instead, so that a steady flow
+/// of priority tasks cannot starve the default stream.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PrioritySettings {
+    pub enabled: bool,
+    pub stream: String,
+    pub runners: HashSet<ProcessTrackerRunner>,
+    pub default_stream_interval: u64,
+}
+
+impl PrioritySettings {
+    /// Returns whether the task is to be pushed to the priority stream.
+    pub fn is_priority_task(&self, task: &ProcessTracker) -> bool {
+        self.enabled
+            && task
+                .runner
+                .as_deref()
+                .and_then(|runner| runner.parse::<ProcessTrackerRunner>().ok())
+                .is_some_and(|runner| self.runners.contains(&runner))
+    }
+
+    /// Returns the streams in the order in which the consumer reads them on its `tick`-th read,
+    /// the consumer stops at the first stream that has tasks.
+    pub fn get_stream_read_order(&self, default_stream: &str, tick: u64) -> Vec<String> {
+        if !self.enabled {
+            return vec![default_stream.to_owned()];
+        }
+
+        if tick.checked_rem(self.default_stream_interval) == Some(0) {
+            vec![default_stream.to_owned(), self.stream.clone()]
+        } else {
+            vec![self.stream.clone(), default_stream.to_owned()]
+        }
+    }
+}
+
+/// Limits the number of tasks calling a connector that are executed within a window, across all
+/// consumers. Tasks over the limit are rescheduled to the next window.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ConnectorRateLimitSettings {
+    pub enabled: bool,
+    pub window_in_secs: i64,
+    pub default_limit: i64,
+    pub connectors: HashMap<String, i64>,
+}
+
+impl ConnectorRateLimitSettings {
+    /// Returns the number of tasks calling the connector that can be executed within a window.
+    pub fn get_limit(&self, connector: &str) -> i64 {
+        self.connectors
+            .get(connector)
+            .copied()
+            .unwrap_or(self.default_limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_stream_read_order() {
+        let mut settings = PrioritySettings {
+            enabled: true,
+            stream: "PRIORITY".to_string(),
+            runners: HashSet::new(),
+            default_stream_interval: 3,
+        };
+
+        let read_orders = (1..=6)
+            .map(|tick| settings.get_stream_read_order("DEFAULT", tick))
+            .collect::<Vec<_>>();
+        let priority_first = vec!["PRIORITY".to_string(), "DEFAULT".to_string()];
+        let default_first = vec!["DEFAULT".to_string(), "PRIORITY".to_string()];
+        assert_eq!(
+            read_orders,
+            vec![
+                priority_first.clone(),
+                priority_first.clone(),
+                default_first.clone(),
+                priority_first.clone(),
+                priority_first.clone(),
+                default_first,
+            ]
+        );
+
+        settings.default_stream_interval = 0;
+        assert_eq!(settings.get_stream_read_order("DEFAULT", 3), priority_first);
+
+        settings.enabled = false;
+        assert_eq!(
+            settings.get_stream_read_order("DEFAULT", 1),
+            vec!["DEFAULT".to_string()]
+        );
+    }
+}
@@ -21,6 +21,10 @@ impl super::settings::SchedulerSettings {
 
         self.server.validate()?;
 
+        self.priority.validate(&self.stream)?;
+
+        self.connector_rate_limit.validate()?;
+
         Ok(())
     }
 }
@@ -44,3 +48,42 @@ impl super::settings::Server {
         })
     }
 }
+
+impl super::settings::PrioritySettings {
+    pub fn validate(&self, default_stream: &str) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.enabled && self.stream.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "scheduler priority stream must not be empty".into(),
+            ))
+        })?;
+
+        when(self.enabled && self.stream == default_stream, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "scheduler priority stream must differ from the scheduler stream".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ConnectorRateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.window_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector rate limit window must be greater than 0".into(),
+            ))
+        })?;
+
+        when(
+            self.default_limit <= 0 || self.connectors.values().any(|limit| *limit <= 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector rate limits must be greater than 0".into(),
+                ))
+            },
+        )
+    }
+}
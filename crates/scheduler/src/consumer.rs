@@ -58,6 +58,7 @@ pub async fn start_consumer<T: SchedulerAppState + 'static>(
         tokio::time::interval(Duration::from_millis(settings.graceful_shutdown_interval));
 
     let consumer_operation_counter = sync::Arc::new(atomic::AtomicU64::new(0));
+    let mut tick: u64 = 0;
     let signal = get_allowed_signals()
         .map_err(|error| {
             logger::error!(?error, "Signal Handler Error");
@@ -86,8 +87,10 @@ pub async fn start_consumer<T: SchedulerAppState + 'static>(
                     },
                     sync::Arc::clone(&consumer_operation_counter),
                     workflow_selector,
+                    tick,
                 )
                 .await;
+                tick = tick.wrapping_add(1);
             }
             Ok(()) | Err(mpsc::error::TryRecvError::Disconnected) => {
                 logger::debug!("Awaiting shutdown!");
@@ -120,29 +123,62 @@ pub async fn consumer_operations<T: SchedulerAppState + 'static>(
     state: &T,
     settings: &SchedulerSettings,
     workflow_selector: impl workflows::ProcessTrackerWorkflows<T> + 'static + Copy + std::fmt::Debug,
+    tick: u64,
 ) -> CustomResult<(), errors::ProcessTrackerError> {
-    let stream_name = settings.stream.clone();
     let group_name = settings.consumer.consumer_group.clone();
     let consumer_name = format!("consumer_{}", Uuid::new_v4());
 
-    let group_created = &mut state
-        .get_db()
-        .consumer_group_create(&stream_name, &group_name, &RedisEntryId::AfterLastID)
-        .await;
-    if group_created.is_err() {
-        logger::info!("Consumer group {group_name} already exists");
-    }
+    let stream_names = settings
+        .priority
+        .get_stream_read_order(&settings.stream, tick);
+
+    let mut stream_name = String::new();
+    let mut tasks = Vec::new();
+    for name in stream_names {
+        let group_created = &mut state
+            .get_db()
+            .consumer_group_create(&name, &group_name, &RedisEntryId::AfterLastID)
+            .await;
+        if group_created.is_err() {
+            logger::info!("Consumer group {group_name} already exists for stream {name}");
+        }
+
+        tasks = state
+            .get_db()
+            .as_scheduler()
+            .fetch_consumer_tasks(&name, &group_name, &consumer_name)
+            .await?;
+        stream_name = name;
 
-    let mut tasks = state
-        .get_db()
-        .as_scheduler()
-        .fetch_consumer_tasks(&stream_name, &group_name, &consumer_name)
-        .await?;
+        if !tasks.is_empty() {
+            break;
+        }
+    }
 
-    logger::info!("{} picked {} tasks", consumer_name, tasks.len());
+    logger::info!(
+        "{} picked {} tasks from {}",
+        consumer_name,
+        tasks.len(),
+        stream_name
+    );
     let mut handler = vec![];
+    let db = state.get_db();
 
     for task in tasks.iter_mut() {
+        if let Some(schedule_time) = pt_utils::get_throttled_schedule_time(
+            db.as_scheduler(),
+            &settings.connector_rate_limit,
+            task,
+        )
+        .await
+        {
+            match reschedule_throttled_task(db.as_scheduler(), task, schedule_time).await {
+                Ok(()) => continue,
+                // The task is executed rather than left behind as started
+                Err(error) => logger::error!(?error, "Failed to reschedule throttled task"),
+            }
+        }
+
         let pickup_time = common_utils::date_time::now();
 
         pt_utils::add_histogram_metrics(&pickup_time, task, &stream_name);
@@ -207,6 +243,30 @@ pub async fn fetch_consumer_tasks(
     Ok(tasks)
 }
 
+/// Returns the task to the producer, to be picked up again at `schedule_time` without counting as
+/// a retry.
+async fn reschedule_throttled_task(
+    db: &dyn SchedulerInterface,
+    task: &storage::ProcessTracker,
+    schedule_time: PrimitiveDateTime,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    logger::info!(pt.name=?task.name, pt.id=%task.id, %schedule_time, "Throttling task");
+
+    db.update_process(
+        task.clone(),
+        storage::ProcessTrackerUpdate::StatusRetryUpdate {
+            status: enums::ProcessTrackerStatus::New,
+            retry_count: task.retry_count,
+            schedule_time,
+        },
+    )
+    .await
+    .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+    metrics::TASK_THROTTLED.add(&metrics::CONTEXT, 1, &[]);
+
+    Ok(())
+}
+
 // Accept flow_options if required
 #[instrument(skip(state), fields(workflow_id))]
 pub async fn start_workflow<T>(
@@ -40,6 +40,10 @@ pub trait QueueInterface {
     ) -> CustomResult<(), RedisError>;
 
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError>;
+
+    /// Increments the counter stored at `key`, setting its expiry when the counter is created.
+    async fn increment_key_with_expiry(&self, key: &str, ttl: i64)
+        -> CustomResult<i64, RedisError>;
 }
 
 #[async_trait::async_trait]
@@ -132,6 +136,16 @@ impl QueueInterface for Store {
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError> {
         self.get_redis_conn()?.get_key::<Vec<u8>>(key).await
     }
+
+    async fn increment_key_with_expiry(
+        &self,
+        key: &str,
+        ttl: i64,
+    ) -> CustomResult<i64, RedisError> {
+        self.get_redis_conn()?
+            .increment_key_with_expiry(key, ttl)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -187,4 +201,13 @@ impl QueueInterface for MockDb {
     async fn get_key(&self, _key: &str) -> CustomResult<Vec<u8>, RedisError> {
         Err(RedisError::RedisConnectionError.into())
     }
+
+    async fn increment_key_with_expiry(
+        &self,
+        _key: &str,
+        _ttl: i64,
+    ) -> CustomResult<i64, RedisError> {
+        // [#172]: Implement function for `MockDb`
+        Err(RedisError::IncrementFailed)?
+    }
 }
@@ -16,10 +16,16 @@ use super::{
     env::logger,
 };
 use crate::{
-    configs::settings::SchedulerSettings, consumer::types::ProcessTrackerBatch, errors,
-    flow::SchedulerFlow, metrics, SchedulerAppState, SchedulerInterface,
+    configs::settings::{ConnectorRateLimitSettings, SchedulerSettings},
+    consumer::types::ProcessTrackerBatch,
+    db::queue::QueueInterface,
+    errors,
+    flow::SchedulerFlow,
+    metrics, SchedulerAppState, SchedulerInterface,
 };
 
+const CONNECTOR_TAG_PREFIX: &str = "CONNECTOR_";
+
 pub async fn divide_and_append_tasks<T>(
     state: &T,
     flow: SchedulerFlow,
@@ -143,7 +149,20 @@ pub fn divide(
 ) -> Vec<ProcessTrackerBatch> {
     let now = common_utils::date_time::now();
     let batch_size = conf.producer.batch_size;
-    divide_into_batches(batch_size, tasks, now, conf)
+    let (priority_tasks, tasks): (Vec<_>, Vec<_>) = tasks
+        .into_iter()
+        .partition(|task| conf.priority.is_priority_task(task));
+
+    let mut batches =
+        divide_into_batches(batch_size, priority_tasks, now, conf, &conf.priority.stream);
+    batches.extend(divide_into_batches(
+        batch_size,
+        tasks,
+        now,
+        conf,
+        &conf.stream,
+    ));
+    batches
 }
 
 pub fn divide_into_batches(
@@ -151,6 +170,7 @@ pub fn divide_into_batches(
     tasks: Vec<storage::ProcessTracker>,
     batch_creation_time: time::PrimitiveDateTime,
     conf: &SchedulerSettings,
+    stream_name: &str,
 ) -> Vec<ProcessTrackerBatch> {
     let batch_id = Uuid::new_v4().to_string();
 
@@ -160,7 +180,7 @@ pub fn divide_into_batches(
             let batch = ProcessTrackerBatch {
                 id: batch_id.clone(),
                 group_name: conf.consumer.consumer_group.clone(),
-                stream_name: conf.stream.clone(),
+                stream_name: stream_name.to_owned(),
                 connection_name: String::new(),
                 created_time: batch_creation_time,
                 rule: String::new(), // is it required?
@@ -249,6 +269,83 @@ pub fn get_process_tracker_id<'a>(
     format!("{runner}_{task_name}_{txn_id}_{merchant_id}")
 }
 
+/// Returns the tags of a task which calls `connector`, so that the consumer can throttle the task
+/// as per the rate limit of the connector.
+pub fn get_tags_with_connector<'a>(
+    tags: impl IntoIterator<Item = &'a str>,
+    connector: Option<&str>,
+) -> Vec<String> {
+    tags.into_iter()
+        .map(ToOwned::to_owned)
+        .chain(connector.map(|connector| format!("{CONNECTOR_TAG_PREFIX}{connector}")))
+        .collect()
+}
+
+pub fn get_connector_from_tags(tags: &[String]) -> Option<&str> {
+    tags.iter()
+        .find_map(|tag| tag.strip_prefix(CONNECTOR_TAG_PREFIX))
+}
+
+/// Counts the task against the rate limit of the connector it calls, and returns the start of the
+/// next window if the limit has been reached in the current window.
+pub async fn get_throttled_schedule_time<D>(
+    db: &D,
+    settings: &ConnectorRateLimitSettings,
+    task: &storage::ProcessTracker,
+) -> Option<time::PrimitiveDateTime>
+where
+    D: QueueInterface + Sync + ?Sized,
+{
+    let connector = get_connector_from_tags(&task.tag)?;
+    get_connector_throttled_until(db, settings, connector).await
+}
+
+/// Counts a call to the connector against its rate limit, and returns the start of the next window
+/// if the limit has been reached in the current window. Workflows which call connectors for many
+/// resources in a single task count each call through this. Calls are not throttled if the counter
+/// could not be updated.
+pub async fn get_connector_throttled_until<D>(
+    db: &D,
+    settings: &ConnectorRateLimitSettings,
+    connector: &str,
+) -> Option<time::PrimitiveDateTime>
+where
+    D: QueueInterface + Sync + ?Sized,
+{
+    if !settings.enabled {
+        return None;
+    }
+
+    let window = common_utils::date_time::now()
+        .assume_utc()
+        .unix_timestamp()
+        .checked_div(settings.window_in_secs)?;
+    let key = format!("SCHEDULER_CONNECTOR_RATE_LIMIT_{connector}_{window}");
+
+    match db
+        .increment_key_with_expiry(&key, settings.window_in_secs)
+        .await
+    {
+        Ok(count) if count > settings.get_limit(connector) => {
+            let next_window = time::OffsetDateTime::from_unix_timestamp(
+                window
+                    .saturating_add(1)
+                    .saturating_mul(settings.window_in_secs),
+            )
+            .ok()?;
+            Some(time::PrimitiveDateTime::new(
+                next_window.date(),
+                next_window.time(),
+            ))
+        }
+        Ok(_) => None,
+        Err(error) => {
+            logger::error!(?error, %connector, "Failed to update connector rate limit counter");
+            None
+        }
+    }
+}
+
 pub fn get_time_from_delta(delta: Option<i32>) -> Option<time::PrimitiveDateTime> {
     delta.map(|t| common_utils::date_time::now().saturating_add(time::Duration::seconds(t.into())))
 }
@@ -260,6 +357,7 @@ pub async fn consumer_operation_handler<E, T: Send + Sync + 'static>(
     error_handler_fun: E,
     consumer_operation_counter: sync::Arc<atomic::AtomicU64>,
     workflow_selector: impl workflows::ProcessTrackerWorkflows<T> + 'static + Copy + std::fmt::Debug,
+    tick: u64,
 ) where
     // Error handler function
     E: FnOnce(error_stack::Report<errors::ProcessTrackerError>),
@@ -268,7 +366,7 @@ pub async fn consumer_operation_handler<E, T: Send + Sync + 'static>(
     consumer_operation_counter.fetch_add(1, atomic::Ordering::SeqCst);
     let start_time = std_time::Instant::now();
 
-    match consumer::consumer_operations(&state, &settings, workflow_selector).await {
+    match consumer::consumer_operations(&state, &settings, workflow_selector, tick).await {
         Ok(_) => (),
         Err(err) => error_handler_fun(err),
     }
@@ -410,8 +508,293 @@ where
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::collections::HashMap;
+
+    use diesel_models::process_tracker::ProcessTrackerRunner;
+    use redis_interface::errors::RedisError;
+
     use super::*;
 
+    /// Keeps the counters in memory, or fails every call when `is_available` is false
+    struct MockCounters {
+        counters: sync::Mutex<HashMap<String, i64>>,
+        is_available: bool,
+    }
+
+    impl MockCounters {
+        fn new(is_available: bool) -> Self {
+            Self {
+                counters: sync::Mutex::new(HashMap::new()),
+                is_available,
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl QueueInterface for MockCounters {
+        async fn fetch_consumer_tasks(
+            &self,
+            _stream_name: &str,
+            _group_name: &str,
+            _consumer_name: &str,
+        ) -> CustomResult<Vec<storage::ProcessTracker>, errors::ProcessTrackerError> {
+            Ok(Vec::new())
+        }
+
+        async fn consumer_group_create(
+            &self,
+            _stream: &str,
+            _group: &str,
+            _id: &RedisEntryId,
+        ) -> CustomResult<(), RedisError> {
+            Ok(())
+        }
+
+        async fn acquire_pt_lock(
+            &self,
+            _tag: &str,
+            _lock_key: &str,
+            _lock_val: &str,
+            _ttl: i64,
+        ) -> CustomResult<bool, RedisError> {
+            Ok(true)
+        }
+
+        async fn release_pt_lock(
+            &self,
+            _tag: &str,
+            _lock_key: &str,
+        ) -> CustomResult<bool, RedisError> {
+            Ok(true)
+        }
+
+        async fn stream_append_entry(
+            &self,
+            _stream: &str,
+            _entry_id: &RedisEntryId,
+            _fields: Vec<(&str, String)>,
+        ) -> CustomResult<(), RedisError> {
+            Ok(())
+        }
+
+        async fn get_key(&self, _key: &str) -> CustomResult<Vec<u8>, RedisError> {
+            Err(RedisError::NotFound.into())
+        }
+
+        async fn increment_key_with_expiry(
+            &self,
+            key: &str,
+            _ttl: i64,
+        ) -> CustomResult<i64, RedisError> {
+            if !self.is_available {
+                return Err(RedisError::IncrementFailed.into());
+            }
+            let mut counters = self.counters.lock().unwrap();
+            let count = counters.entry(key.to_owned()).or_insert(0);
+            *count += 1;
+            Ok(*count)
+        }
+    }
+
+    fn get_task(runner: ProcessTrackerRunner, tag: Vec<String>) -> storage::ProcessTracker {
+        let now = common_utils::date_time::now();
+        storage::ProcessTracker {
+            id: Uuid::new_v4().to_string(),
+            name: Some("TASK".to_string()),
+            tag,
+            runner: Some(runner.to_string()),
+            retry_count: 0,
+            schedule_time: Some(now),
+            rule: String::new(),
+            tracking_data: serde_json::json!({}),
+            business_status: "Pending".to_string(),
+            status: enums::ProcessTrackerStatus::New,
+            event: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn get_rate_limit_settings() -> ConnectorRateLimitSettings {
+        ConnectorRateLimitSettings {
+            enabled: true,
+            window_in_secs: 86400,
+            default_limit: 2,
+            connectors: HashMap::from([("adyen".to_string(), 1)]),
+        }
+    }
+
+    #[test]
+    fn test_get_tags_with_connector() {
+        assert_eq!(
+            get_tags_with_connector(["PAYMENT"], Some("stripe")),
+            vec!["PAYMENT".to_string(), "CONNECTOR_stripe".to_string()]
+        );
+        assert_eq!(
+            get_tags_with_connector(["PAYMENT"], None),
+            vec!["PAYMENT".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_get_connector_from_tags() {
+        let tags = get_tags_with_connector(["REFUND"], Some("adyen"));
+        assert_eq!(get_connector_from_tags(&tags), Some("adyen"));
+        assert_eq!(get_connector_from_tags(&["REFUND".to_string()]), None);
+        assert_eq!(get_connector_from_tags(&[]), None);
+    }
+
+    #[test]
+    fn test_divide_pushes_priority_tasks_to_priority_stream() {
+        let mut settings = SchedulerSettings::default();
+        settings.producer.batch_size = 2;
+        settings.priority.enabled = true;
+
+        let tasks = vec![
+            get_task(ProcessTrackerRunner::PaymentsSyncWorkflow, Vec::new()),
+            get_task(
+                ProcessTrackerRunner::PaymentDataArchivalWorkflow,
+                Vec::new(),
+            ),
+            get_task(ProcessTrackerRunner::RefundWorkflowRouter, Vec::new()),
+            get_task(
+                ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+                Vec::new(),
+            ),
+        ];
+
+        let batches = divide(tasks, &settings);
+        let batch_sizes = batches
+            .iter()
+            .map(|batch| (batch.stream_name.as_str(), batch.trackers.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            batch_sizes,
+            vec![
+                (settings.priority.stream.as_str(), 2),
+                (settings.priority.stream.as_str(), 1),
+                (settings.stream.as_str(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_divide_uses_default_stream_when_priority_is_disabled() {
+        let mut settings = SchedulerSettings::default();
+        settings.producer.batch_size = 2;
+
+        let tasks = vec![
+            get_task(ProcessTrackerRunner::PaymentsSyncWorkflow, Vec::new()),
+            get_task(
+                ProcessTrackerRunner::PaymentDataArchivalWorkflow,
+                Vec::new(),
+            ),
+            get_task(ProcessTrackerRunner::RefundWorkflowRouter, Vec::new()),
+        ];
+
+        let batches = divide(tasks, &settings);
+        assert_eq!(batches.len(), 2);
+        assert!(batches
+            .iter()
+            .all(|batch| batch.stream_name == settings.stream));
+    }
+
+    #[tokio::test]
+    async fn test_get_throttled_schedule_time_throttles_over_the_limit() {
+        let db = MockCounters::new(true);
+        let settings = get_rate_limit_settings();
+        let task = get_task(
+            ProcessTrackerRunner::PaymentsSyncWorkflow,
+            get_tags_with_connector(["PAYMENT"], Some("stripe")),
+        );
+
+        assert!(get_throttled_schedule_time(&db, &settings, &task)
+            .await
+            .is_none());
+        assert!(get_throttled_schedule_time(&db, &settings, &task)
+            .await
+            .is_none());
+
+        let schedule_time = get_throttled_schedule_time(&db, &settings, &task)
+            .await
+            .unwrap();
+        assert!(schedule_time > common_utils::date_time::now());
+        assert_eq!(
+            schedule_time.assume_utc().unix_timestamp() % settings.window_in_secs,
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_throttled_schedule_time_uses_connector_limit() {
+        let db = MockCounters::new(true);
+        let settings = get_rate_limit_settings();
+        let adyen_task = get_task(
+            ProcessTrackerRunner::RefundWorkflowRouter,
+            get_tags_with_connector(["REFUND"], Some("adyen")),
+        );
+        let stripe_task = get_task(
+            ProcessTrackerRunner::RefundWorkflowRouter,
+            get_tags_with_connector(["REFUND"], Some("stripe")),
+        );
+
+        assert!(get_throttled_schedule_time(&db, &settings, &adyen_task)
+            .await
+            .is_none());
+        assert!(get_throttled_schedule_time(&db, &settings, &adyen_task)
+            .await
+            .is_some());
+        // Connectors are counted separately
+        assert!(get_throttled_schedule_time(&db, &settings, &stripe_task)
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_throttled_schedule_time_does_not_throttle() {
+        let settings = get_rate_limit_settings();
+        let task = get_task(
+            ProcessTrackerRunner::PaymentsSyncWorkflow,
+            get_tags_with_connector(["PAYMENT"], Some("adyen")),
+        );
+        let untagged_task = get_task(
+            ProcessTrackerRunner::PaymentsSyncWorkflow,
+            vec!["PAYMENT".to_string()],
+        );
+
+        // Tasks are not throttled while throttling is disabled
+        let db = MockCounters::new(true);
+        let disabled_settings = ConnectorRateLimitSettings {
+            enabled: false,
+            ..get_rate_limit_settings()
+        };
+        for _ in 0..3 {
+            assert!(get_throttled_schedule_time(&db, &disabled_settings, &task)
+                .await
+                .is_none());
+        }
+
+        // Tasks which do not call a connector are not throttled
+        for _ in 0..3 {
+            assert!(get_throttled_schedule_time(&db, &settings, &untagged_task)
+                .await
+                .is_none());
+        }
+        assert!(db.counters.lock().unwrap().is_empty());
+
+        // Tasks are not throttled if the counter cannot be updated
+        let unavailable_db = MockCounters::new(false);
+        for _ in 0..3 {
+            assert!(
+                get_throttled_schedule_time(&unavailable_db, &settings, &task)
+                    .await
+                    .is_none()
+            );
+        }
+    }
+
     #[test]
     fn test_get_delay() {
         let frequency_count = vec![(300, 10), (600, 5), (1800, 3), (3600, 2)];
@@ -12,6 +12,8 @@ pub struct Database {
     pub queue_strategy: QueueStrategy,
     pub min_idle: Option<u32>,
     pub max_lifetime: Option<u64>,
+    pub idle_timeout: Option<u64>,
+    pub statement_timeout: Option<u64>,
 }
 
 #[derive(Debug, serde::Deserialize, Clone, Copy, Default)]
@@ -44,6 +46,8 @@ impl Default for Database {
             queue_strategy: QueueStrategy::default(),
             min_idle: None,
             max_lifetime: None,
+            idle_timeout: None,
+            statement_timeout: None,
         }
     }
 }
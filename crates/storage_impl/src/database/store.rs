@@ -5,7 +5,7 @@ use error_stack::ResultExt;
 use hyperswitch_domain_models::errors::{StorageError, StorageResult};
 use masking::PeekInterface;
 
-use crate::config::Database;
+use crate::{config::Database, metrics};
 
 pub type PgPool = bb8::Pool<async_bb8_diesel::ConnectionManager<PgConnection>>;
 pub type PgPooledConn = async_bb8_diesel::Connection<PgConnection>;
@@ -77,7 +77,7 @@ pub async fn diesel_make_pg_pool(
     database: &Database,
     test_transaction: bool,
 ) -> StorageResult<PgPool> {
-    let database_url = format!(
+    let mut database_url = format!(
         "postgres://{}:{}@{}:{}/{}",
         database.username,
         database.password.peek(),
@@ -85,13 +85,19 @@ pub async fn diesel_make_pg_pool(
         database.port,
         database.dbname
     );
+    if let Some(statement_timeout) = database.statement_timeout {
+        // Applied to every connection of the pool through the `options` connection parameter
+        database_url =
+            format!("{database_url}?options=-c%20statement_timeout%3D{statement_timeout}s");
+    }
     let manager = async_bb8_diesel::ConnectionManager::<PgConnection>::new(database_url);
     let mut pool = bb8::Pool::builder()
         .max_size(database.pool_size)
         .min_idle(database.min_idle)
         .queue_strategy(database.queue_strategy.into())
         .connection_timeout(std::time::Duration::from_secs(database.connection_timeout))
-        .max_lifetime(database.max_lifetime.map(std::time::Duration::from_secs));
+        .max_lifetime(database.max_lifetime.map(std::time::Duration::from_secs))
+        .idle_timeout(database.idle_timeout.map(std::time::Duration::from_secs));
 
     if test_transaction {
         pool = pool.connection_customizer(Box::new(TestTransaction));
@@ -103,6 +109,43 @@ pub async fn diesel_make_pg_pool(
         .attach_printable("Failed to create PostgreSQL connection pool")
 }
 
+/// Checks out a connection from the pool, recording the time spent waiting for it, checkout
+/// failures, and the number of connections of the pool that are in use and idle.
+pub async fn get_pooled_connection<'a>(
+    pool: &'a PgPool,
+    pool_name: &'static str,
+) -> Result<
+    bb8::PooledConnection<'a, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    bb8::RunError<ConnectionError>,
+> {
+    let attributes = [router_env::opentelemetry::KeyValue::new("pool", pool_name)];
+
+    let checkout_started_at = std::time::Instant::now();
+    let connection = pool.get().await;
+    metrics::DB_POOL_CHECKOUT_TIME.record(
+        &metrics::CONTEXT,
+        checkout_started_at.elapsed().as_secs_f64(),
+        &attributes,
+    );
+    if connection.is_err() {
+        metrics::DB_POOL_CHECKOUT_FAILURES.add(&metrics::CONTEXT, 1, &attributes);
+    }
+
+    let state = pool.state();
+    metrics::DB_POOL_CONNECTIONS_IN_USE.record(
+        &metrics::CONTEXT,
+        u64::from(state.connections.saturating_sub(state.idle_connections)),
+        &attributes,
+    );
+    metrics::DB_POOL_IDLE_CONNECTIONS.record(
+        &metrics::CONTEXT,
+        u64::from(state.idle_connections),
+        &attributes,
+    );
+
+    connection
+}
+
 #[derive(Debug)]
 struct TestTransaction;
 
@@ -64,6 +64,9 @@ impl Into<DataStorageError> for &StorageError {
                     DataStorageError::ValueNotFound(String::from("db value not found"))
                 }
                 // TODO: Update this error type to encompass & propagate the duplicate type (instead of generic `db value not found`)
+                storage_errors::DatabaseError::ConcurrentModification => {
+                    DataStorageError::ConcurrentModification
+                }
                 storage_errors::DatabaseError::UniqueViolation => {
                     DataStorageError::DuplicateValue {
                         entity: "db entity",
@@ -170,6 +173,9 @@ pub enum ApplicationError {
 
     #[error("Error while constructing api client: {0}")]
     ApiClientError(ApiClientError),
+
+    #[error("Database migration error: {0}")]
+    MigrationError(String),
 }
 
 impl From<MetricsError> for ApplicationError {
@@ -203,7 +209,8 @@ impl ResponseError for ApplicationError {
             | Self::IoError(_)
             | Self::ConfigurationError(_)
             | Self::InvalidConfigurationValueError(_)
-            | Self::ApiClientError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            | Self::ApiClientError(_)
+            | Self::MigrationError(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 
@@ -266,6 +266,9 @@ pub(crate) fn diesel_error_to_data_error(
             entity: "entity ",
             key: None,
         },
+        diesel_models::errors::DatabaseError::ConcurrentModification => {
+            StorageError::ConcurrentModification
+        }
         _ => StorageError::DatabaseError(error_stack::report!(*diesel_error)),
     }
 }
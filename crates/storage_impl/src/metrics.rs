@@ -1,4 +1,6 @@
-use router_env::{counter_metric, global_meter, metrics_context};
+use router_env::{
+    counter_metric, global_meter, histogram_metric, histogram_metric_u64, metrics_context,
+};
 
 metrics_context!(CONTEXT);
 global_meter!(GLOBAL_METER, "ROUTER_API");
@@ -10,3 +12,9 @@ counter_metric!(KV_OPERATION_SUCCESSFUL, GLOBAL_METER);
 counter_metric!(KV_OPERATION_FAILED, GLOBAL_METER);
 counter_metric!(KV_PUSHED_TO_DRAINER, GLOBAL_METER);
 counter_metric!(KV_FAILED_TO_PUSH_TO_DRAINER, GLOBAL_METER);
+
+// Metrics for the database connection pools
+histogram_metric!(DB_POOL_CHECKOUT_TIME, GLOBAL_METER); // Time spent waiting for a connection
+counter_metric!(DB_POOL_CHECKOUT_FAILURES, GLOBAL_METER);
+histogram_metric_u64!(DB_POOL_CONNECTIONS_IN_USE, GLOBAL_METER);
+histogram_metric_u64!(DB_POOL_IDLE_CONNECTIONS, GLOBAL_METER);
@@ -57,6 +57,9 @@ pub struct MockDb {
     pub payouts: Arc<Mutex<Vec<store::payouts::Payouts>>>,
     pub authentications: Arc<Mutex<Vec<store::authentication::Authentication>>>,
     pub roles: Arc<Mutex<Vec<store::role::Role>>>,
+    pub webhook_endpoints: Arc<Mutex<Vec<store::webhook_endpoint::WebhookEndpoint>>>,
+    pub audit_logs: Arc<Mutex<Vec<store::audit_log::AuditLog>>>,
+    pub connector_payloads: Arc<Mutex<Vec<store::connector_payload::ConnectorPayload>>>,
 }
 
 impl MockDb {
@@ -100,6 +103,9 @@ impl MockDb {
             payouts: Default::default(),
             authentications: Default::default(),
             roles: Default::default(),
+            webhook_endpoints: Default::default(),
+            audit_logs: Default::default(),
+            connector_payloads: Default::default(),
         })
     }
 }
@@ -109,3 +115,11 @@ impl PayoutsInterface for MockDb {}
 
 #[cfg(not(feature = "payouts"))]
 impl PayoutAttemptInterface for MockDb {}
+
+/// Matches `value` against an optional list filter: a filter that is not specified matches every
+/// value, while a filter that is specified only matches the values it contains.
+pub(crate) fn matches_any<T: PartialEq>(allowed: Option<&[T]>, value: Option<&T>) -> bool {
+    allowed.map_or(true, |allowed| {
+        value.map_or(false, |value| allowed.contains(value))
+    })
+}
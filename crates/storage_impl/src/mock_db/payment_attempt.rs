@@ -1,93 +1,187 @@
+use std::collections::HashSet;
+
 use api_models::enums::{AuthenticationType, Connector, PaymentMethod, PaymentMethodType};
 use common_utils::errors::CustomResult;
 use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
 use hyperswitch_domain_models::{
     errors::StorageError,
     payments::payment_attempt::{
         PaymentAttempt, PaymentAttemptInterface, PaymentAttemptNew, PaymentAttemptUpdate,
+        PaymentListFilters,
     },
 };
 
-use super::MockDb;
+use super::{matches_any, MockDb};
 use crate::DataModelExt;
 
 #[async_trait::async_trait]
 impl PaymentAttemptInterface for MockDb {
     async fn find_payment_attempt_by_payment_id_merchant_id_attempt_id(
         &self,
-        _payment_id: &str,
-        _merchant_id: &str,
-        _attempt_id: &str,
+        payment_id: &str,
+        merchant_id: &str,
+        attempt_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_payment_attempt(|payment_attempt| {
+            payment_attempt.payment_id == payment_id
+                && payment_attempt.merchant_id == merchant_id
+                && payment_attempt.attempt_id == attempt_id
+        })
+        .await
     }
 
     async fn get_filters_for_payments(
         &self,
-        _pi: &[hyperswitch_domain_models::payments::PaymentIntent],
-        _merchant_id: &str,
+        pi: &[hyperswitch_domain_models::payments::PaymentIntent],
+        merchant_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
-    ) -> CustomResult<
-        hyperswitch_domain_models::payments::payment_attempt::PaymentListFilters,
-        StorageError,
-    > {
-        Err(StorageError::MockDbError)?
+    ) -> CustomResult<PaymentListFilters, StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+        let active_attempt_ids = pi
+            .iter()
+            .map(|payment_intent| payment_intent.active_attempt.get_id())
+            .collect::<HashSet<_>>();
+        let active_attempts = payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == merchant_id
+                    && active_attempt_ids.contains(&payment_attempt.attempt_id)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(PaymentListFilters {
+            connector: unique(
+                active_attempts
+                    .iter()
+                    .filter_map(|payment_attempt| payment_attempt.connector.clone()),
+            ),
+            currency: unique(
+                active_attempts
+                    .iter()
+                    .filter_map(|payment_attempt| payment_attempt.currency),
+            ),
+            status: unique(pi.iter().map(|payment_intent| payment_intent.status)),
+            payment_method: unique(
+                active_attempts
+                    .iter()
+                    .filter_map(|payment_attempt| payment_attempt.payment_method),
+            ),
+            payment_method_type: unique(
+                active_attempts
+                    .iter()
+                    .filter_map(|payment_attempt| payment_attempt.payment_method_type),
+            ),
+            authentication_type: unique(
+                active_attempts
+                    .iter()
+                    .filter_map(|payment_attempt| payment_attempt.authentication_type),
+            ),
+        })
     }
 
     async fn get_total_count_of_filtered_payment_attempts(
         &self,
-        _merchant_id: &str,
-        _active_attempt_ids: &[String],
-        _connector: Option<Vec<Connector>>,
-        _payment_method: Option<Vec<PaymentMethod>>,
-        _payment_method_type: Option<Vec<PaymentMethodType>>,
-        _authentication_type: Option<Vec<AuthenticationType>>,
-        _merchanat_connector_id: Option<Vec<String>>,
+        merchant_id: &str,
+        active_attempt_ids: &[String],
+        connector: Option<Vec<Connector>>,
+        payment_method: Option<Vec<PaymentMethod>>,
+        payment_method_type: Option<Vec<PaymentMethodType>>,
+        authentication_type: Option<Vec<AuthenticationType>>,
+        merchant_connector_id: Option<Vec<String>>,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<i64, StorageError> {
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+        let connector = connector.map(|connectors| {
+            connectors
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        });
+
+        let count = payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == merchant_id
+                    && active_attempt_ids.contains(&payment_attempt.attempt_id)
+                    && matches_any(connector.as_deref(), payment_attempt.connector.as_ref())
+                    && matches_any(
+                        payment_method.as_deref(),
+                        payment_attempt.payment_method.as_ref(),
+                    )
+                    && matches_any(
+                        payment_method_type.as_deref(),
+                        payment_attempt.payment_method_type.as_ref(),
+                    )
+                    && matches_any(
+                        authentication_type.as_deref(),
+                        payment_attempt.authentication_type.as_ref(),
+                    )
+                    && matches_any(
+                        merchant_connector_id.as_deref(),
+                        payment_attempt.merchant_connector_id.as_ref(),
+                    )
+            })
+            .count();
+
+        i64::try_from(count).change_context(StorageError::MockDbError)
     }
 
     async fn find_payment_attempt_by_attempt_id_merchant_id(
         &self,
-        _attempt_id: &str,
-        _merchant_id: &str,
+        attempt_id: &str,
+        merchant_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_payment_attempt(|payment_attempt| {
+            payment_attempt.attempt_id == attempt_id && payment_attempt.merchant_id == merchant_id
+        })
+        .await
     }
 
     async fn find_payment_attempt_by_preprocessing_id_merchant_id(
         &self,
-        _preprocessing_id: &str,
-        _merchant_id: &str,
+        preprocessing_id: &str,
+        merchant_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_payment_attempt(|payment_attempt| {
+            payment_attempt.preprocessing_step_id.as_deref() == Some(preprocessing_id)
+                && payment_attempt.merchant_id == merchant_id
+        })
+        .await
     }
 
     async fn find_payment_attempt_by_merchant_id_connector_txn_id(
         &self,
-        _merchant_id: &str,
-        _connector_txn_id: &str,
+        merchant_id: &str,
+        connector_txn_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_payment_attempt(|payment_attempt| {
+            payment_attempt.merchant_id == merchant_id
+                && payment_attempt.connector_transaction_id.as_deref() == Some(connector_txn_id)
+        })
+        .await
     }
 
     async fn find_attempts_by_merchant_id_payment_id(
         &self,
-        _merchant_id: &str,
-        _payment_id: &str,
+        merchant_id: &str,
+        payment_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == merchant_id
+                    && payment_attempt.payment_id == payment_id
+            })
+            .cloned()
+            .collect())
     }
 
     #[allow(clippy::panic)]
@@ -157,6 +251,9 @@ impl PaymentAttemptInterface for MockDb {
             mandate_data: payment_attempt.mandate_data,
             payment_method_billing_address_id: payment_attempt.payment_method_billing_address_id,
             fingerprint_id: payment_attempt.fingerprint_id,
+            estimated_connector_fee: payment_attempt.estimated_connector_fee,
+            three_ds_decision_reason: payment_attempt.three_ds_decision_reason,
+            routing_decision: payment_attempt.routing_decision,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -188,52 +285,68 @@ impl PaymentAttemptInterface for MockDb {
 
     async fn find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
         &self,
-        _connector_transaction_id: &str,
-        _payment_id: &str,
-        _merchant_id: &str,
+        connector_transaction_id: &str,
+        payment_id: &str,
+        merchant_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        self.find_payment_attempt(|payment_attempt| {
+            payment_attempt.connector_transaction_id.as_deref() == Some(connector_transaction_id)
+                && payment_attempt.payment_id == payment_id
+                && payment_attempt.merchant_id == merchant_id
+        })
+        .await
     }
 
-    // safety: only used for testing
-    #[allow(clippy::unwrap_used)]
     async fn find_payment_attempt_last_successful_attempt_by_payment_id_merchant_id(
         &self,
         payment_id: &str,
         merchant_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        let payment_attempts = self.payment_attempts.lock().await;
-
-        Ok(payment_attempts
-            .iter()
-            .find(|payment_attempt| {
-                payment_attempt.payment_id == payment_id
-                    && payment_attempt.merchant_id == merchant_id
-            })
-            .cloned()
-            .unwrap())
+        self.find_payment_attempt(|payment_attempt| {
+            payment_attempt.payment_id == payment_id
+                && payment_attempt.merchant_id == merchant_id
+                && payment_attempt.status == storage_enums::AttemptStatus::Charged
+        })
+        .await
     }
-    #[allow(clippy::unwrap_used)]
+
     async fn find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id_merchant_id(
         &self,
         payment_id: &str,
         merchant_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<PaymentAttempt, StorageError> {
+        self.find_payment_attempt(|payment_attempt| {
+            payment_attempt.payment_id == payment_id
+                && payment_attempt.merchant_id == merchant_id
+                && (payment_attempt.status == storage_enums::AttemptStatus::PartialCharged
+                    || payment_attempt.status == storage_enums::AttemptStatus::Charged)
+        })
+        .await
+    }
+}
+
+impl MockDb {
+    /// Returns the most recently created payment attempt matching `predicate`.
+    async fn find_payment_attempt(
+        &self,
+        predicate: impl Fn(&PaymentAttempt) -> bool,
     ) -> CustomResult<PaymentAttempt, StorageError> {
         let payment_attempts = self.payment_attempts.lock().await;
 
         Ok(payment_attempts
             .iter()
-            .find(|payment_attempt| {
-                payment_attempt.payment_id == payment_id
-                    && payment_attempt.merchant_id == merchant_id
-                    && (payment_attempt.status == storage_enums::AttemptStatus::PartialCharged
-                        || payment_attempt.status == storage_enums::AttemptStatus::Charged)
-            })
+            .filter(|payment_attempt| predicate(payment_attempt))
+            .max_by_key(|payment_attempt| payment_attempt.created_at)
             .cloned()
-            .unwrap())
+            .ok_or(StorageError::ValueNotFound(
+                "No payment attempt found".to_string(),
+            ))?)
     }
 }
+
+fn unique<T: Eq + std::hash::Hash>(values: impl Iterator<Item = T>) -> Vec<T> {
+    values.collect::<HashSet<_>>().into_iter().collect()
+}
@@ -1,6 +1,8 @@
 use common_utils::errors::CustomResult;
 use diesel_models::enums as storage_enums;
 use error_stack::ResultExt;
+#[cfg(feature = "olap")]
+use hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints;
 use hyperswitch_domain_models::{
     errors::StorageError,
     payments::{
@@ -10,6 +12,8 @@ use hyperswitch_domain_models::{
     },
 };
 
+#[cfg(feature = "olap")]
+use super::matches_any;
 use super::MockDb;
 use crate::DataModelExt;
 
@@ -18,42 +22,84 @@ impl PaymentIntentInterface for MockDb {
     #[cfg(feature = "olap")]
     async fn filter_payment_intent_by_constraints(
         &self,
-        _merchant_id: &str,
-        _filters: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
+        merchant_id: &str,
+        filters: &PaymentIntentFetchConstraints,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_intents = self.payment_intents.lock().await;
+        let filtered_payment_intents =
+            filter_payment_intents(&payment_intents, merchant_id, filters);
+
+        Ok(paginate(filtered_payment_intents, filters))
     }
     #[cfg(feature = "olap")]
     async fn filter_payment_intents_by_time_range_constraints(
         &self,
-        _merchant_id: &str,
-        _time_range: &api_models::payments::TimeRange,
+        merchant_id: &str,
+        time_range: &api_models::payments::TimeRange,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_intents = self.payment_intents.lock().await;
+        let end_time = time_range
+            .end_time
+            .unwrap_or_else(common_utils::date_time::now);
+
+        Ok(payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == merchant_id
+                    && payment_intent.created_at >= time_range.start_time
+                    && payment_intent.created_at <= end_time
+            })
+            .cloned()
+            .collect())
     }
     #[cfg(feature = "olap")]
     async fn get_filtered_active_attempt_ids_for_total_count(
         &self,
-        _merchant_id: &str,
-        _constraints: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
+        merchant_id: &str,
+        constraints: &PaymentIntentFetchConstraints,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<String>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_intents = self.payment_intents.lock().await;
+
+        Ok(
+            filter_payment_intents(&payment_intents, merchant_id, constraints)
+                .into_iter()
+                .map(|payment_intent| payment_intent.active_attempt.get_id())
+                .collect(),
+        )
     }
     #[cfg(feature = "olap")]
     async fn get_filtered_payment_intents_attempt(
         &self,
-        _merchant_id: &str,
-        _constraints: &hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints,
+        merchant_id: &str,
+        constraints: &PaymentIntentFetchConstraints,
         _storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<(PaymentIntent, PaymentAttempt)>, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_intents = self.payment_intents.lock().await;
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        let filtered_payment_intents_attempts =
+            filter_payment_intents(&payment_intents, merchant_id, constraints)
+                .into_iter()
+                .filter_map(|payment_intent| {
+                    let attempt_id = payment_intent.active_attempt.get_id();
+                    payment_attempts
+                        .iter()
+                        .find(|payment_attempt| {
+                            payment_attempt.attempt_id == attempt_id
+                                && payment_attempt.merchant_id == merchant_id
+                        })
+                        .filter(|payment_attempt| {
+                            payment_attempt_matches_constraints(payment_attempt, constraints)
+                        })
+                        .cloned()
+                        .map(|payment_attempt| (payment_intent, payment_attempt))
+                })
+                .collect();
+
+        Ok(paginate(filtered_payment_intents_attempts, constraints))
     }
 
     #[allow(clippy::panic)]
@@ -108,6 +154,7 @@ impl PaymentIntentInterface for MockDb {
             fingerprint_id: new.fingerprint_id,
             session_expiry: new.session_expiry,
             request_external_three_ds_authentication: new.request_external_three_ds_authentication,
+            test_mode: new.test_mode,
         };
         payment_intents.push(payment_intent.clone());
         Ok(payment_intent)
@@ -126,6 +173,9 @@ impl PaymentIntentInterface for MockDb {
             .iter_mut()
             .find(|item| item.id == this.id)
             .unwrap();
+        if payment_intent.modified_at != this.modified_at {
+            Err(StorageError::ConcurrentModification)?
+        }
         *payment_intent = PaymentIntent::from_storage_model(
             update
                 .to_storage_model()
@@ -134,8 +184,6 @@ impl PaymentIntentInterface for MockDb {
         Ok(payment_intent.clone())
     }
 
-    // safety: only used for testing
-    #[allow(clippy::unwrap_used)]
     async fn find_payment_intent_by_payment_id_merchant_id(
         &self,
         payment_id: &str,
@@ -150,7 +198,9 @@ impl PaymentIntentInterface for MockDb {
                 payment_intent.payment_id == payment_id && payment_intent.merchant_id == merchant_id
             })
             .cloned()
-            .unwrap())
+            .ok_or(StorageError::ValueNotFound(format!(
+                "No payment intent found for payment_id = {payment_id}"
+            )))?)
     }
 
     async fn get_active_payment_attempt(
@@ -172,4 +222,143 @@ impl PaymentIntentInterface for MockDb {
             hyperswitch_domain_models::RemoteStorageObject::Object(pa) => Ok(pa.clone()),
         }
     }
+
+    async fn find_payment_intents_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        let payment_intents = self.payment_intents.lock().await;
+
+        Ok(payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == merchant_id
+                    && payment_intent.customer_id.as_deref() == Some(customer_id)
+            })
+            .cloned()
+            .collect())
+    }
+}
+
+/// Applies the filters of `constraints` that are present on the payment intent, returning the
+/// matching payment intents of the merchant ordered by `(created_at, payment_id)`, most recent
+/// first.
+#[cfg(feature = "olap")]
+fn filter_payment_intents(
+    payment_intents: &[PaymentIntent],
+    merchant_id: &str,
+    constraints: &PaymentIntentFetchConstraints,
+) -> Vec<PaymentIntent> {
+    let cursor_of = |payment_id: &String| {
+        payment_intents
+            .iter()
+            .find(|payment_intent| {
+                payment_intent.merchant_id == merchant_id
+                    && &payment_intent.payment_id == payment_id
+            })
+            .map(|payment_intent| (payment_intent.created_at, payment_intent.payment_id.clone()))
+    };
+
+    let mut filtered_payment_intents = payment_intents
+        .iter()
+        .filter(|payment_intent| payment_intent.merchant_id == merchant_id)
+        .filter(|payment_intent| match constraints {
+            PaymentIntentFetchConstraints::Single { payment_intent_id } => {
+                &payment_intent.payment_id == payment_intent_id
+            }
+            PaymentIntentFetchConstraints::List(params) => {
+                let key = (payment_intent.created_at, payment_intent.payment_id.clone());
+                let starting_after = params.starting_after_id.as_ref().map(cursor_of);
+                let ending_before = params.ending_before_id.as_ref().map(cursor_of);
+
+                matches_any(
+                    params.customer_id.as_ref().map(std::slice::from_ref),
+                    payment_intent.customer_id.as_ref(),
+                ) && matches_any(
+                    params.profile_id.as_ref().map(std::slice::from_ref),
+                    payment_intent.profile_id.as_ref(),
+                ) && params
+                    .starting_at
+                    .map_or(true, |starting_at| payment_intent.created_at >= starting_at)
+                    && params
+                        .ending_at
+                        .map_or(true, |ending_at| payment_intent.created_at <= ending_at)
+                    && starting_after.map_or(true, |cursor| cursor.map_or(false, |c| key < c))
+                    && ending_before.map_or(true, |cursor| cursor.map_or(false, |c| key > c))
+                    && matches_any(params.currency.as_deref(), payment_intent.currency.as_ref())
+                    && matches_any(params.status.as_deref(), Some(&payment_intent.status))
+            }
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    filtered_payment_intents
+        .sort_by(|a, b| (b.created_at, &b.payment_id).cmp(&(a.created_at, &a.payment_id)));
+    filtered_payment_intents
+}
+
+/// Applies the filters of `constraints` that are present on the payment attempt.
+#[cfg(feature = "olap")]
+fn payment_attempt_matches_constraints(
+    payment_attempt: &PaymentAttempt,
+    constraints: &PaymentIntentFetchConstraints,
+) -> bool {
+    let PaymentIntentFetchConstraints::List(params) = constraints else {
+        return true;
+    };
+    let connectors = params.connector.as_ref().map(|connectors| {
+        connectors
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+    });
+
+    matches_any(connectors.as_deref(), payment_attempt.connector.as_ref())
+        && matches_any(
+            params.payment_method.as_deref(),
+            payment_attempt.payment_method.as_ref(),
+        )
+        && matches_any(
+            params.payment_method_type.as_deref(),
+            payment_attempt.payment_method_type.as_ref(),
+        )
+        && matches_any(
+            params.authentication_type.as_deref(),
+            payment_attempt.authentication_type.as_ref(),
+        )
+        && matches_any(
+            params.merchant_connector_id.as_deref(),
+            payment_attempt.merchant_connector_id.as_ref(),
+        )
+}
+
+/// Pages through `items`, which are ordered most recent first. A page requested with only an
+/// `ending_before` cursor is taken from the end closest to the cursor, as the database does.
+#[cfg(feature = "olap")]
+fn paginate<T>(items: Vec<T>, constraints: &PaymentIntentFetchConstraints) -> Vec<T> {
+    match constraints {
+        PaymentIntentFetchConstraints::Single { .. } => items,
+        PaymentIntentFetchConstraints::List(params) => {
+            let offset = usize::try_from(params.offset).unwrap_or_default();
+            let limit = params
+                .limit
+                .and_then(|limit| usize::try_from(limit).ok())
+                .unwrap_or(usize::MAX);
+
+            if params.ending_before_id.is_some() && params.starting_after_id.is_none() {
+                let mut page = items
+                    .into_iter()
+                    .rev()
+                    .skip(offset)
+                    .take(limit)
+                    .collect::<Vec<_>>();
+                page.reverse();
+                page
+            } else {
+                items.into_iter().skip(offset).take(limit).collect()
+            }
+        }
+    }
 }
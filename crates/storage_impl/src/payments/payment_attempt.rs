@@ -5,6 +5,7 @@ use diesel_models::{
         MandateAmountData as DieselMandateAmountData, MandateDataType as DieselMandateType,
         MandateDetails as DieselMandateDetails, MerchantStorageScheme,
     },
+    errors::DatabaseError,
     kv,
     payment_attempt::{
         PaymentAttempt as DieselPaymentAttempt, PaymentAttemptNew as DieselPaymentAttemptNew,
@@ -32,7 +33,10 @@ use crate::{
     errors::RedisErrorExt,
     lookup::ReverseLookupInterface,
     redis::kv_store::{kv_wrapper, KvOperation, PartitionKey},
-    utils::{pg_connection_read, pg_connection_write, try_redis_get_else_try_database_get},
+    utils::{
+        pg_connection_read, pg_connection_read_replica, pg_connection_write,
+        try_redis_get_else_try_database_get,
+    },
     DataModelExt, DatabaseStore, KVRouterStore, RouterStore,
 };
 
@@ -83,13 +87,25 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, errors::StorageError> {
         let conn = pg_connection_read(self).await?;
-        DieselPaymentAttempt::find_by_connector_transaction_id_payment_id_merchant_id(
+        match DieselPaymentAttempt::find_by_connector_transaction_id_payment_id_merchant_id(
             &conn,
             connector_transaction_id,
             payment_id,
             merchant_id,
         )
         .await
+        {
+            Err(er) if matches!(er.current_context(), DatabaseError::NotFound) => {
+                DieselPaymentAttempt::find_archived_by_connector_transaction_id_payment_id_merchant_id(
+                    &conn,
+                    connector_transaction_id,
+                    payment_id,
+                    merchant_id,
+                )
+                .await
+            }
+            result => result,
+        }
         .map_err(|er| {
             let new_err = diesel_error_to_data_error(er.current_context());
             er.change_context(new_err)
@@ -147,12 +163,25 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, errors::StorageError> {
         let conn = pg_connection_read(self).await?;
-        DieselPaymentAttempt::find_by_merchant_id_connector_txn_id(
+        // Webhooks for payments past the data archival retention window are matched against the
+        // archive
+        match DieselPaymentAttempt::find_by_merchant_id_connector_txn_id(
             &conn,
             merchant_id,
             connector_txn_id,
         )
         .await
+        {
+            Err(er) if matches!(er.current_context(), DatabaseError::NotFound) => {
+                DieselPaymentAttempt::find_archived_by_merchant_id_connector_txn_id(
+                    &conn,
+                    merchant_id,
+                    connector_txn_id,
+                )
+                .await
+            }
+            result => result,
+        }
         .map_err(|er| {
             let new_err = diesel_error_to_data_error(er.current_context());
             er.change_context(new_err)
@@ -170,13 +199,25 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
     ) -> CustomResult<PaymentAttempt, errors::StorageError> {
         let conn = pg_connection_read(self).await?;
 
-        DieselPaymentAttempt::find_by_payment_id_merchant_id_attempt_id(
+        match DieselPaymentAttempt::find_by_payment_id_merchant_id_attempt_id(
             &conn,
             payment_id,
             merchant_id,
             attempt_id,
         )
         .await
+        {
+            Err(er) if matches!(er.current_context(), DatabaseError::NotFound) => {
+                DieselPaymentAttempt::find_archived_by_payment_id_merchant_id_attempt_id(
+                    &conn,
+                    payment_id,
+                    merchant_id,
+                    attempt_id,
+                )
+                .await
+            }
+            result => result,
+        }
         .map_err(|er| {
             let new_err = diesel_error_to_data_error(er.current_context());
             er.change_context(new_err)
@@ -191,7 +232,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         merchant_id: &str,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentListFilters, errors::StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_replica(self).await?;
         let intents = pi
             .iter()
             .cloned()
@@ -252,17 +293,29 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
         let conn = pg_connection_read(self).await?;
-        DieselPaymentAttempt::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
-            .await
-            .map_err(|er| {
-                let new_err = diesel_error_to_data_error(er.current_context());
-                er.change_context(new_err)
-            })
-            .map(|a| {
-                a.into_iter()
-                    .map(PaymentAttempt::from_storage_model)
-                    .collect()
-            })
+        let attempts =
+            DieselPaymentAttempt::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+                .await;
+        match attempts {
+            Ok(attempts) if attempts.is_empty() => {
+                DieselPaymentAttempt::find_archived_by_merchant_id_payment_id(
+                    &conn,
+                    merchant_id,
+                    payment_id,
+                )
+                .await
+            }
+            attempts => attempts,
+        }
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+        .map(|a| {
+            a.into_iter()
+                .map(PaymentAttempt::from_storage_model)
+                .collect()
+        })
     }
 
     #[instrument(skip_all)]
@@ -295,12 +348,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         merchant_connector_id: Option<Vec<String>>,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<i64, errors::StorageError> {
-        let conn = self
-            .db_store
-            .get_replica_pool()
-            .get()
-            .await
-            .change_context(errors::StorageError::DatabaseConnectionError)?;
+        let conn = pg_connection_read_replica(self).await?;
         let connector_strings = connector.as_ref().map(|connector| {
             connector
                 .iter()
@@ -410,6 +458,9 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                         .payment_method_billing_address_id
                         .clone(),
                     fingerprint_id: payment_attempt.fingerprint_id.clone(),
+                    estimated_connector_fee: payment_attempt.estimated_connector_fee,
+                    three_ds_decision_reason: payment_attempt.three_ds_decision_reason.clone(),
+                    routing_decision: payment_attempt.routing_decision.clone(),
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -981,23 +1032,41 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     merchant_id,
                     payment_id,
                 };
-                Box::pin(try_redis_get_else_try_database_get(
-                    async {
-                        kv_wrapper(self, KvOperation::<DieselPaymentAttempt>::Scan("pa_*"), key)
-                            .await?
-                            .try_into_scan()
-                    },
-                    || async {
-                        self.router_store
-                            .find_attempts_by_merchant_id_payment_id(
-                                merchant_id,
-                                payment_id,
-                                storage_scheme,
-                            )
-                            .await
-                    },
-                ))
-                .await
+                let kv_attempts: Vec<PaymentAttempt> =
+                    match kv_wrapper(self, KvOperation::<DieselPaymentAttempt>::Scan("pa_*"), key)
+                        .await
+                        .and_then(|result| result.try_into_scan())
+                    {
+                        Ok(kv_attempts) => kv_attempts,
+                        Err(error) => match error.current_context() {
+                            redis_interface::errors::RedisError::NotFound => Vec::new(),
+                            _ => return Err(error.to_redis_failed_response(payment_id)),
+                        },
+                    };
+
+                // Attempts made before the merchant moved to the KV storage scheme are only
+                // present in the database, so the attempts in Redis are merged with them
+                let db_attempts = self
+                    .router_store
+                    .find_attempts_by_merchant_id_payment_id(
+                        merchant_id,
+                        payment_id,
+                        storage_scheme,
+                    )
+                    .await?;
+                let kv_attempt_ids = kv_attempts
+                    .iter()
+                    .map(|attempt| attempt.attempt_id.clone())
+                    .collect::<std::collections::HashSet<_>>();
+
+                Ok(kv_attempts
+                    .into_iter()
+                    .chain(
+                        db_attempts
+                            .into_iter()
+                            .filter(|attempt| !kv_attempt_ids.contains(&attempt.attempt_id)),
+                    )
+                    .collect())
             }
         }
     }
@@ -1164,6 +1233,9 @@ impl DataModelExt for PaymentAttempt {
             mandate_data: self.mandate_data.map(|d| d.to_storage_model()),
             payment_method_billing_address_id: self.payment_method_billing_address_id,
             fingerprint_id: self.fingerprint_id,
+            estimated_connector_fee: self.estimated_connector_fee,
+            three_ds_decision_reason: self.three_ds_decision_reason,
+            routing_decision: self.routing_decision,
         }
     }
 
@@ -1228,6 +1300,9 @@ impl DataModelExt for PaymentAttempt {
                 .map(MandateDetails::from_storage_model),
             payment_method_billing_address_id: storage_model.payment_method_billing_address_id,
             fingerprint_id: storage_model.fingerprint_id,
+            estimated_connector_fee: storage_model.estimated_connector_fee,
+            three_ds_decision_reason: storage_model.three_ds_decision_reason,
+            routing_decision: storage_model.routing_decision,
         }
     }
 }
@@ -1290,6 +1365,9 @@ impl DataModelExt for PaymentAttemptNew {
             mandate_data: self.mandate_data.map(|d| d.to_storage_model()),
             payment_method_billing_address_id: self.payment_method_billing_address_id,
             fingerprint_id: self.fingerprint_id,
+            estimated_connector_fee: self.estimated_connector_fee,
+            three_ds_decision_reason: self.three_ds_decision_reason,
+            routing_decision: self.routing_decision,
         }
     }
 
@@ -1352,6 +1430,9 @@ impl DataModelExt for PaymentAttemptNew {
                 .map(MandateDetails::from_storage_model),
             payment_method_billing_address_id: storage_model.payment_method_billing_address_id,
             fingerprint_id: storage_model.fingerprint_id,
+            estimated_connector_fee: storage_model.estimated_connector_fee,
+            three_ds_decision_reason: storage_model.three_ds_decision_reason,
+            routing_decision: storage_model.routing_decision,
         }
     }
 }
@@ -1405,6 +1486,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 merchant_connector_id,
+                routing_decision,
             } => DieselPaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
                 connector,
@@ -1414,6 +1496,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id,
+                routing_decision,
             },
             Self::AuthenticationTypeUpdate {
                 authentication_type,
@@ -1468,6 +1551,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 authentication_connector,
                 authentication_id,
                 payment_method_billing_address_id,
+                estimated_connector_fee,
+                three_ds_decision_reason,
+                routing_decision,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount,
                 currency,
@@ -1496,6 +1582,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 authentication_connector,
                 authentication_id,
                 payment_method_billing_address_id,
+                estimated_connector_fee,
+                three_ds_decision_reason,
+                routing_decision,
             },
             Self::VoidUpdate {
                 status,
@@ -1724,6 +1813,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 merchant_connector_id: connector_id,
+                routing_decision,
             } => Self::UpdateTrackers {
                 payment_token,
                 connector,
@@ -1733,6 +1823,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: connector_id,
+                routing_decision,
             },
             DieselPaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -1769,6 +1860,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 authentication_connector,
                 authentication_id,
                 payment_method_billing_address_id,
+                estimated_connector_fee,
+                three_ds_decision_reason,
+                routing_decision,
             } => Self::ConfirmUpdate {
                 amount,
                 currency,
@@ -1797,6 +1891,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 authentication_connector,
                 authentication_id,
                 payment_method_billing_address_id,
+                estimated_connector_fee,
+                three_ds_decision_reason,
+                routing_decision,
             },
             DieselPaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -6,9 +6,12 @@ use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
 use common_utils::errors::ReportSwitchExt;
 use common_utils::{date_time, ext_traits::Encode};
 #[cfg(feature = "olap")]
-use diesel::{associations::HasTable, ExpressionMethods, JoinOnDsl, QueryDsl};
+use diesel::{
+    associations::HasTable, BoolExpressionMethods, ExpressionMethods, JoinOnDsl, QueryDsl,
+};
 use diesel_models::{
     enums::MerchantStorageScheme,
+    errors::DatabaseError,
     kv,
     payment_attempt::PaymentAttempt as DieselPaymentAttempt,
     payment_intent::{
@@ -33,7 +36,7 @@ use hyperswitch_domain_models::{
     },
     RemoteStorageObject,
 };
-use redis_interface::HsetnxReply;
+use redis_interface::{errors::RedisError, HsetnxReply};
 #[cfg(feature = "olap")]
 use router_env::logger;
 use router_env::{instrument, tracing};
@@ -43,7 +46,7 @@ use crate::connection;
 use crate::{
     diesel_error_to_data_error,
     errors::RedisErrorExt,
-    redis::kv_store::{kv_wrapper, KvOperation, PartitionKey},
+    redis::kv_store::{kv_wrapper, KvOperation, PartitionKey, RedisConnInterface},
     utils::{self, pg_connection_read, pg_connection_write},
     DataModelExt, DatabaseStore, KVRouterStore,
 };
@@ -70,6 +73,28 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                     payment_id: &payment_id,
                 };
                 let key_str = key.to_string();
+
+                // Payment IDs of archived payments can't be reused
+                let conn = pg_connection_read(self).await?;
+                if DieselPaymentIntent::find_optional_archived_by_payment_id_merchant_id(
+                    &conn,
+                    &payment_id,
+                    &merchant_id,
+                )
+                .await
+                .map_err(|er| {
+                    let new_err = diesel_error_to_data_error(er.current_context());
+                    er.change_context(new_err)
+                })?
+                .is_some()
+                {
+                    return Err(StorageError::DuplicateValue {
+                        entity: "payment_intent",
+                        key: Some(key_str),
+                    }
+                    .into());
+                }
+
                 let field = format!("pi_{}", new.payment_id);
                 let created_intent = PaymentIntent {
                     id: 0i32,
@@ -115,7 +140,16 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                     session_expiry: new.session_expiry,
                     request_external_three_ds_authentication: new
                         .request_external_three_ds_authentication,
+                    test_mode: new.test_mode,
                 };
+                track_customer_payment_id(
+                    self,
+                    &merchant_id,
+                    new.customer_id.as_deref(),
+                    &payment_id,
+                )
+                .await?;
+
                 let redis_entry = kv::TypedSql {
                     op: kv::DBOperation::Insert {
                         insertable: kv::Insertable::PaymentIntent(new.to_storage_model()),
@@ -170,6 +204,20 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                 let key_str = key.to_string();
                 let field = format!("pi_{}", this.payment_id);
 
+                // Same version check as `update_if_unmodified` on the database path. The read and
+                // the write below are separate redis calls, so this narrows the window for lost
+                // updates without closing it.
+                let latest_intent = self
+                    .find_payment_intent_by_payment_id_merchant_id(
+                        &payment_id,
+                        &merchant_id,
+                        storage_scheme,
+                    )
+                    .await?;
+                if latest_intent.modified_at != this.modified_at {
+                    Err(StorageError::ConcurrentModification)?
+                }
+
                 let diesel_intent_update = payment_intent_update.to_storage_model();
                 let origin_diesel_intent = this.to_storage_model();
 
@@ -182,6 +230,14 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                     .encode_to_string_of_json()
                     .change_context(StorageError::SerializationFailed)?;
 
+                track_customer_payment_id(
+                    self,
+                    &merchant_id,
+                    diesel_intent.customer_id.as_deref(),
+                    &payment_id,
+                )
+                .await?;
+
                 let redis_entry = kv::TypedSql {
                     op: kv::DBOperation::Update {
                         updatable: kv::Updateable::PaymentIntentUpdate(
@@ -217,12 +273,27 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
     ) -> error_stack::Result<PaymentIntent, StorageError> {
         let database_call = || async {
             let conn = pg_connection_read(self).await?;
-            DieselPaymentIntent::find_by_payment_id_merchant_id(&conn, payment_id, merchant_id)
-                .await
-                .map_err(|er| {
-                    let new_err = diesel_error_to_data_error(er.current_context());
-                    er.change_context(new_err)
-                })
+            match DieselPaymentIntent::find_by_payment_id_merchant_id(
+                &conn,
+                payment_id,
+                merchant_id,
+            )
+            .await
+            {
+                Err(er) if matches!(er.current_context(), DatabaseError::NotFound) => {
+                    DieselPaymentIntent::find_archived_by_payment_id_merchant_id(
+                        &conn,
+                        payment_id,
+                        merchant_id,
+                    )
+                    .await
+                }
+                result => result,
+            }
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(er.current_context());
+                er.change_context(new_err)
+            })
         };
         match storage_scheme {
             MerchantStorageScheme::PostgresOnly => database_call().await,
@@ -279,6 +350,70 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn find_payment_intents_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        let database_intents = self
+            .router_store
+            .find_payment_intents_by_merchant_id_customer_id(
+                merchant_id,
+                customer_id,
+                storage_scheme,
+            )
+            .await?;
+
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => Ok(database_intents),
+            MerchantStorageScheme::RedisKv => {
+                // Intents that are not drained yet are either missing from the database, or are
+                // more recent in redis than in the database
+                let customer_payment_ids_key =
+                    get_customer_payment_ids_key(merchant_id, customer_id);
+                let payment_ids = self
+                    .get_redis_conn()
+                    .map_err(|err| err.to_redis_failed_response(&customer_payment_ids_key))?
+                    .get_set_members(&customer_payment_ids_key)
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&customer_payment_ids_key))?;
+
+                let mut kv_intents = Vec::with_capacity(payment_ids.len());
+                for payment_id in payment_ids {
+                    let key = PartitionKey::MerchantIdPaymentId {
+                        merchant_id,
+                        payment_id: &payment_id,
+                    };
+                    let field = format!("pi_{payment_id}");
+                    let kv_intent = kv_wrapper::<DieselPaymentIntent, _, _>(
+                        self,
+                        KvOperation::<DieselPaymentIntent>::HGet(&field),
+                        key,
+                    )
+                    .await
+                    .and_then(|result| result.try_into_hget());
+
+                    match kv_intent {
+                        // The intent belongs to another customer if its customer was changed
+                        Ok(intent) if intent.customer_id.as_deref() == Some(customer_id) => {
+                            kv_intents.push(PaymentIntent::from_storage_model(intent))
+                        }
+                        Ok(_) => {}
+                        // The intent was drained and has expired from redis
+                        Err(err) if matches!(err.current_context(), RedisError::NotFound) => {}
+                        Err(err) => {
+                            return Err(err.to_redis_failed_response(&payment_id));
+                        }
+                    }
+                }
+
+                Ok(merge_payment_intents(database_intents, kv_intents))
+            }
+        }
+    }
+
     #[cfg(feature = "olap")]
     async fn filter_payment_intent_by_constraints(
         &self,
@@ -364,7 +499,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
     ) -> error_stack::Result<PaymentIntent, StorageError> {
         let conn = pg_connection_write(self).await?;
         this.to_storage_model()
-            .update(&conn, payment_intent.to_storage_model())
+            .update_if_unmodified(&conn, payment_intent.to_storage_model())
             .await
             .map_err(|er| {
                 let new_err = diesel_error_to_data_error(er.current_context());
@@ -381,13 +516,25 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<PaymentIntent, StorageError> {
         let conn = pg_connection_read(self).await?;
-        DieselPaymentIntent::find_by_payment_id_merchant_id(&conn, payment_id, merchant_id)
+        // Payments past the data archival retention window are only present in the archive
+        match DieselPaymentIntent::find_by_payment_id_merchant_id(&conn, payment_id, merchant_id)
             .await
-            .map(PaymentIntent::from_storage_model)
-            .map_err(|er| {
-                let new_err = diesel_error_to_data_error(er.current_context());
-                er.change_context(new_err)
-            })
+        {
+            Err(er) if matches!(er.current_context(), DatabaseError::NotFound) => {
+                DieselPaymentIntent::find_archived_by_payment_id_merchant_id(
+                    &conn,
+                    payment_id,
+                    merchant_id,
+                )
+                .await
+            }
+            result => result,
+        }
+        .map(PaymentIntent::from_storage_model)
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
     }
 
     #[instrument(skip_all)]
@@ -419,6 +566,28 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn find_payment_intents_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        let conn = pg_connection_write(self).await?;
+        DieselPaymentIntent::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+            .await
+            .map(|payment_intents| {
+                payment_intents
+                    .into_iter()
+                    .map(PaymentIntent::from_storage_model)
+                    .collect()
+            })
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(er.current_context());
+                er.change_context(new_err)
+            })
+    }
+
     #[cfg(feature = "olap")]
     #[instrument(skip_all)]
     async fn filter_payment_intent_by_constraints(
@@ -429,15 +598,18 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
     ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
         use common_utils::errors::ReportSwitchExt;
 
-        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = connection::pg_connection_read_replica(self)
+            .await
+            .switch()?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
 
         //[#350]: Replace this with Boxable Expression and pass it into generic filter
         // when https://github.com/rust-lang/rust/issues/52662 becomes stable
         let mut query = <DieselPaymentIntent as HasTable>::table()
             .filter(pi_dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(pi_dsl::created_at.desc())
+            .order((pi_dsl::created_at.desc(), pi_dsl::payment_id.desc()))
             .into_boxed();
+        let mut is_reversed = false;
 
         match filters {
             PaymentIntentFetchConstraints::Single { payment_intent_id } => {
@@ -455,39 +627,58 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                     query = query.filter(pi_dsl::profile_id.eq(profile_id.clone()));
                 }
 
-                query = match (params.starting_at, &params.starting_after_id) {
-                    (Some(starting_at), _) => query.filter(pi_dsl::created_at.ge(starting_at)),
-                    (None, Some(starting_after_id)) => {
-                        // TODO: Fetch partial columns for this query since we only need some columns
-                        let starting_at = self
-                            .find_payment_intent_by_payment_id_merchant_id(
-                                starting_after_id,
-                                merchant_id,
-                                storage_scheme,
-                            )
-                            .await?
-                            .created_at;
-                        query.filter(pi_dsl::created_at.ge(starting_at))
-                    }
-                    (None, None) => query,
-                };
+                if let Some(starting_at) = params.starting_at {
+                    query = query.filter(pi_dsl::created_at.ge(starting_at));
+                }
+
+                if let Some(ending_at) = params.ending_at {
+                    query = query.filter(pi_dsl::created_at.le(ending_at));
+                }
+
+                // Keyset pagination on `(created_at, payment_id)`: the cursor bounds the index
+                // range directly, so deep pages don't have to scan past every earlier row.
+                if let Some(starting_after_id) = &params.starting_after_id {
+                    // TODO: Fetch partial columns for this query since we only need some columns
+                    let cursor = self
+                        .find_payment_intent_by_payment_id_merchant_id(
+                            starting_after_id,
+                            merchant_id,
+                            storage_scheme,
+                        )
+                        .await?;
+                    query = query.filter(
+                        pi_dsl::created_at.le(cursor.created_at).and(
+                            pi_dsl::created_at
+                                .lt(cursor.created_at)
+                                .or(pi_dsl::payment_id.lt(cursor.payment_id)),
+                        ),
+                    );
+                }
 
-                query = match (params.ending_at, &params.ending_before_id) {
-                    (Some(ending_at), _) => query.filter(pi_dsl::created_at.le(ending_at)),
-                    (None, Some(ending_before_id)) => {
-                        // TODO: Fetch partial columns for this query since we only need some columns
-                        let ending_at = self
-                            .find_payment_intent_by_payment_id_merchant_id(
-                                ending_before_id,
-                                merchant_id,
-                                storage_scheme,
-                            )
-                            .await?
-                            .created_at;
-                        query.filter(pi_dsl::created_at.le(ending_at))
+                if let Some(ending_before_id) = &params.ending_before_id {
+                    // TODO: Fetch partial columns for this query since we only need some columns
+                    let cursor = self
+                        .find_payment_intent_by_payment_id_merchant_id(
+                            ending_before_id,
+                            merchant_id,
+                            storage_scheme,
+                        )
+                        .await?;
+                    query = query.filter(
+                        pi_dsl::created_at.ge(cursor.created_at).and(
+                            pi_dsl::created_at
+                                .gt(cursor.created_at)
+                                .or(pi_dsl::payment_id.gt(cursor.payment_id)),
+                        ),
+                    );
+
+                    // Walk the index upwards from the cursor so that the page returned is the
+                    // one immediately before it; the rows are put back in descending order below.
+                    if params.starting_after_id.is_none() {
+                        query = query.order((pi_dsl::created_at.asc(), pi_dsl::payment_id.asc()));
+                        is_reversed = true;
                     }
-                    (None, None) => query,
-                };
+                }
 
                 query = query.offset(params.offset.into());
 
@@ -518,7 +709,10 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
             db_metrics::DatabaseOperation::Filter,
         )
         .await
-        .map(|payment_intents| {
+        .map(|mut payment_intents| {
+            if is_reversed {
+                payment_intents.reverse();
+            }
             payment_intents
                 .into_iter()
                 .map(PaymentIntent::from_storage_model)
@@ -555,7 +749,9 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         constraints: &PaymentIntentFetchConstraints,
         storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Vec<(PaymentIntent, PaymentAttempt)>, StorageError> {
-        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = connection::pg_connection_read_replica(self)
+            .await
+            .switch()?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
         let mut query = DieselPaymentIntent::table()
             .inner_join(
@@ -563,8 +759,9 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                     .on(pa_dsl::attempt_id.eq(pi_dsl::active_attempt_id)),
             )
             .filter(pi_dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(pi_dsl::created_at.desc())
+            .order((pi_dsl::created_at.desc(), pi_dsl::payment_id.desc()))
             .into_boxed();
+        let mut is_reversed = false;
 
         query = match constraints {
             PaymentIntentFetchConstraints::Single { payment_intent_id } => {
@@ -583,39 +780,58 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                     query = query.filter(pi_dsl::profile_id.eq(profile_id.clone()));
                 }
 
-                query = match (params.starting_at, &params.starting_after_id) {
-                    (Some(starting_at), _) => query.filter(pi_dsl::created_at.ge(starting_at)),
-                    (None, Some(starting_after_id)) => {
-                        // TODO: Fetch partial columns for this query since we only need some columns
-                        let starting_at = self
-                            .find_payment_intent_by_payment_id_merchant_id(
-                                starting_after_id,
-                                merchant_id,
-                                storage_scheme,
-                            )
-                            .await?
-                            .created_at;
-                        query.filter(pi_dsl::created_at.ge(starting_at))
-                    }
-                    (None, None) => query,
-                };
+                if let Some(starting_at) = params.starting_at {
+                    query = query.filter(pi_dsl::created_at.ge(starting_at));
+                }
+
+                if let Some(ending_at) = params.ending_at {
+                    query = query.filter(pi_dsl::created_at.le(ending_at));
+                }
+
+                // Keyset pagination on `(created_at, payment_id)`: the cursor bounds the index
+                // range directly, so deep pages don't have to scan past every earlier row.
+                if let Some(starting_after_id) = &params.starting_after_id {
+                    // TODO: Fetch partial columns for this query since we only need some columns
+                    let cursor = self
+                        .find_payment_intent_by_payment_id_merchant_id(
+                            starting_after_id,
+                            merchant_id,
+                            storage_scheme,
+                        )
+                        .await?;
+                    query = query.filter(
+                        pi_dsl::created_at.le(cursor.created_at).and(
+                            pi_dsl::created_at
+                                .lt(cursor.created_at)
+                                .or(pi_dsl::payment_id.lt(cursor.payment_id)),
+                        ),
+                    );
+                }
 
-                query = match (params.ending_at, &params.ending_before_id) {
-                    (Some(ending_at), _) => query.filter(pi_dsl::created_at.le(ending_at)),
-                    (None, Some(ending_before_id)) => {
-                        // TODO: Fetch partial columns for this query since we only need some columns
-                        let ending_at = self
-                            .find_payment_intent_by_payment_id_merchant_id(
-                                ending_before_id,
-                                merchant_id,
-                                storage_scheme,
-                            )
-                            .await?
-                            .created_at;
-                        query.filter(pi_dsl::created_at.le(ending_at))
+                if let Some(ending_before_id) = &params.ending_before_id {
+                    // TODO: Fetch partial columns for this query since we only need some columns
+                    let cursor = self
+                        .find_payment_intent_by_payment_id_merchant_id(
+                            ending_before_id,
+                            merchant_id,
+                            storage_scheme,
+                        )
+                        .await?;
+                    query = query.filter(
+                        pi_dsl::created_at.ge(cursor.created_at).and(
+                            pi_dsl::created_at
+                                .gt(cursor.created_at)
+                                .or(pi_dsl::payment_id.gt(cursor.payment_id)),
+                        ),
+                    );
+
+                    // Walk the index upwards from the cursor so that the page returned is the
+                    // one immediately before it; the rows are put back in descending order below.
+                    if params.starting_after_id.is_none() {
+                        query = query.order((pi_dsl::created_at.asc(), pi_dsl::payment_id.asc()));
+                        is_reversed = true;
                     }
-                    (None, None) => query,
-                };
+                }
 
                 query = query.offset(params.offset.into());
 
@@ -690,7 +906,10 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         query
             .get_results_async::<(DieselPaymentIntent, DieselPaymentAttempt)>(conn)
             .await
-            .map(|results| {
+            .map(|mut results| {
+                if is_reversed {
+                    results.reverse();
+                }
                 results
                     .into_iter()
                     .map(|(pi, pa)| {
@@ -718,7 +937,9 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         constraints: &PaymentIntentFetchConstraints,
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Vec<String>, StorageError> {
-        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = connection::pg_connection_read_replica(self)
+            .await
+            .switch()?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
         let mut query = DieselPaymentIntent::table()
             .select(pi_dsl::active_attempt_id)
@@ -839,6 +1060,7 @@ impl DataModelExt for PaymentIntentNew {
             fingerprint_id: self.fingerprint_id,
             session_expiry: self.session_expiry,
             request_external_three_ds_authentication: self.request_external_three_ds_authentication,
+            test_mode: self.test_mode,
         }
     }
 
@@ -886,6 +1108,7 @@ impl DataModelExt for PaymentIntentNew {
             session_expiry: storage_model.session_expiry,
             request_external_three_ds_authentication: storage_model
                 .request_external_three_ds_authentication,
+            test_mode: storage_model.test_mode,
         }
     }
 }
@@ -937,6 +1160,7 @@ impl DataModelExt for PaymentIntent {
             fingerprint_id: self.fingerprint_id,
             session_expiry: self.session_expiry,
             request_external_three_ds_authentication: self.request_external_three_ds_authentication,
+            test_mode: self.test_mode,
         }
     }
 
@@ -985,6 +1209,7 @@ impl DataModelExt for PaymentIntent {
             session_expiry: storage_model.session_expiry,
             request_external_three_ds_authentication: storage_model
                 .request_external_three_ds_authentication,
+            test_mode: storage_model.test_mode,
         }
     }
 }
@@ -1155,3 +1380,51 @@ impl DataModelExt for PaymentIntentUpdate {
         todo!("Reverse map should no longer be needed")
     }
 }
+
+/// Payment intents of merchants on KV storage can only be looked up in redis by their payment ID,
+/// so the payment IDs of each customer are tracked in a set, which expires along with the intents.
+fn get_customer_payment_ids_key(merchant_id: &str, customer_id: &str) -> String {
+    format!("customer_payment_ids_{merchant_id}_{customer_id}")
+}
+
+async fn track_customer_payment_id<T: DatabaseStore>(
+    store: &KVRouterStore<T>,
+    merchant_id: &str,
+    customer_id: Option<&str>,
+    payment_id: &str,
+) -> error_stack::Result<(), StorageError> {
+    let Some(customer_id) = customer_id else {
+        return Ok(());
+    };
+    let customer_payment_ids_key = get_customer_payment_ids_key(merchant_id, customer_id);
+
+    store
+        .get_redis_conn()
+        .map_err(|err| err.to_redis_failed_response(&customer_payment_ids_key))?
+        .add_set_members_with_expiry(
+            &customer_payment_ids_key,
+            payment_id.to_owned(),
+            i64::from(store.ttl_for_kv),
+        )
+        .await
+        .change_context(StorageError::KVError)
+        .attach_printable("Failed to track the payment ID of the customer")
+}
+
+/// Merges the intents read from redis into the ones read from the database, the intent in redis
+/// being the more recent one for a payment that is in both.
+fn merge_payment_intents(
+    database_intents: Vec<PaymentIntent>,
+    kv_intents: Vec<PaymentIntent>,
+) -> Vec<PaymentIntent> {
+    let mut intents = database_intents
+        .into_iter()
+        .filter(|database_intent| {
+            !kv_intents
+                .iter()
+                .any(|kv_intent| kv_intent.payment_id == database_intent.payment_id)
+        })
+        .collect::<Vec<_>>();
+    intents.extend(kv_intents);
+    intents
+}
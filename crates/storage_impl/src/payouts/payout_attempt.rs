@@ -30,7 +30,7 @@ use crate::{
     errors::RedisErrorExt,
     lookup::ReverseLookupInterface,
     redis::kv_store::{kv_wrapper, KvOperation, PartitionKey},
-    utils::{self, pg_connection_read, pg_connection_write},
+    utils::{self, pg_connection_read, pg_connection_read_replica, pg_connection_write},
     DataModelExt, DatabaseStore, KVRouterStore,
 };
 
@@ -327,7 +327,7 @@ impl<T: DatabaseStore> PayoutAttemptInterface for crate::RouterStore<T> {
         merchant_id: &str,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PayoutListFilters, errors::StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_replica(self).await?;
         let payouts = payouts
             .iter()
             .cloned()
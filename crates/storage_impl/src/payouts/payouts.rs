@@ -87,6 +87,7 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
                     status: new.status,
                     attempt_count: new.attempt_count,
                     confirm: new.confirm,
+                    test_mode: new.test_mode,
                 };
 
                 let redis_entry = kv::TypedSql {
@@ -396,7 +397,9 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
     ) -> error_stack::Result<Vec<Payouts>, StorageError> {
         use common_utils::errors::ReportSwitchExt;
 
-        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = connection::pg_connection_read_replica(self)
+            .await
+            .switch()?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
 
         //[#350]: Replace this with Boxable Expression and pass it into generic filter
@@ -512,7 +515,9 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
     ) -> error_stack::Result<Vec<(Payouts, PayoutAttempt, DieselCustomer)>, StorageError> {
         use common_utils::errors::ReportSwitchExt;
 
-        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = connection::pg_connection_read_replica(self)
+            .await
+            .switch()?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
         let mut query = DieselPayouts::table()
             .inner_join(
@@ -676,6 +681,7 @@ impl DataModelExt for Payouts {
             status: self.status,
             attempt_count: self.attempt_count,
             confirm: self.confirm,
+            test_mode: self.test_mode,
         }
     }
 
@@ -702,6 +708,7 @@ impl DataModelExt for Payouts {
             status: storage_model.status,
             attempt_count: storage_model.attempt_count,
             confirm: storage_model.confirm,
+            test_mode: storage_model.test_mode,
         }
     }
 }
@@ -731,6 +738,7 @@ impl DataModelExt for PayoutsNew {
             status: self.status,
             attempt_count: self.attempt_count,
             confirm: self.confirm,
+            test_mode: self.test_mode,
         }
     }
 
@@ -757,6 +765,7 @@ impl DataModelExt for PayoutsNew {
             status: storage_model.status,
             attempt_count: storage_model.attempt_count,
             confirm: storage_model.confirm,
+            test_mode: storage_model.test_mode,
         }
     }
 }
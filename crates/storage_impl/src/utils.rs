@@ -3,7 +3,9 @@ use diesel::PgConnection;
 use error_stack::ResultExt;
 use hyperswitch_domain_models::errors::StorageError;
 
-use crate::{errors::RedisErrorExt, metrics, DatabaseStore};
+use crate::{
+    database::store::get_pooled_connection, errors::RedisErrorExt, metrics, DatabaseStore,
+};
 
 pub async fn pg_connection_read<T: DatabaseStore>(
     store: &T,
@@ -13,7 +15,7 @@ pub async fn pg_connection_read<T: DatabaseStore>(
 > {
     // If only OLAP is enabled get replica pool.
     #[cfg(all(feature = "olap", not(feature = "oltp")))]
-    let pool = store.get_replica_pool();
+    let (pool, pool_name) = (store.get_replica_pool(), "replica");
 
     // If either one of these are true we need to get master pool.
     //  1. Only OLTP is enabled.
@@ -24,9 +26,23 @@ pub async fn pg_connection_read<T: DatabaseStore>(
         all(feature = "olap", feature = "oltp"),
         all(not(feature = "olap"), not(feature = "oltp"))
     ))]
-    let pool = store.get_master_pool();
+    let (pool, pool_name) = (store.get_master_pool(), "master");
 
-    pool.get()
+    get_pooled_connection(pool, pool_name)
+        .await
+        .change_context(StorageError::DatabaseConnectionError)
+}
+
+/// Gets a connection to the replica database, for the read-heavy queries of the list and filter
+/// endpoints which can tolerate replication lag. Reads that must observe a write made just before,
+/// such as a retrieve right after a create, should use [`pg_connection_read`] instead.
+pub async fn pg_connection_read_replica<T: DatabaseStore>(
+    store: &T,
+) -> error_stack::Result<
+    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    StorageError,
+> {
+    get_pooled_connection(store.get_replica_pool(), "replica")
         .await
         .change_context(StorageError::DatabaseConnectionError)
 }
@@ -38,9 +54,7 @@ pub async fn pg_connection_write<T: DatabaseStore>(
     StorageError,
 > {
     // Since all writes should happen to master DB only choose master DB.
-    let pool = store.get_master_pool();
-
-    pool.get()
+    get_pooled_connection(store.get_master_pool(), "master")
         .await
         .change_context(StorageError::DatabaseConnectionError)
 }
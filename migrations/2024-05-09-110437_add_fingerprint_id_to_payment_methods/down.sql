@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_methods_customer_id_merchant_id_fingerprint_id_index;
+
+ALTER TABLE payment_methods DROP COLUMN IF EXISTS fingerprint_id;
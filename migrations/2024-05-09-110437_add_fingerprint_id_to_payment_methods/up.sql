@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_methods ADD COLUMN IF NOT EXISTS fingerprint_id VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS payment_methods_customer_id_merchant_id_fingerprint_id_index ON payment_methods (customer_id, merchant_id, fingerprint_id);
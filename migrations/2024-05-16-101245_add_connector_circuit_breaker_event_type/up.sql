@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'connectors';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'connector_health_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'connector_circuit_breaker_opened';
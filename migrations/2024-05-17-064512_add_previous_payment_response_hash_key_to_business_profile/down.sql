@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE business_profile
+DROP COLUMN IF EXISTS previous_payment_response_hash_key,
+DROP COLUMN IF EXISTS previous_payment_response_hash_key_expires_at;
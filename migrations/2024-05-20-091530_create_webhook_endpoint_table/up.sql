@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS webhook_endpoint (
+    endpoint_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    url TEXT NOT NULL,
+    description VARCHAR(255),
+    secret BYTEA NOT NULL,
+    enabled_events JSONB,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS webhook_endpoint_merchant_id_profile_id_index ON webhook_endpoint (merchant_id, profile_id);
+
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS webhook_endpoint_id VARCHAR(64);
+
+ALTER TABLE events
+ALTER COLUMN idempotent_event_id TYPE VARCHAR(128);
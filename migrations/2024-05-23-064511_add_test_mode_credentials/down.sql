@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE api_keys DROP COLUMN IF EXISTS test_mode;
+
+ALTER TABLE merchant_connector_account
+DROP COLUMN IF EXISTS test_connector_account_details;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE api_keys
+ADD COLUMN IF NOT EXISTS test_mode BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE merchant_connector_account
+ADD COLUMN IF NOT EXISTS test_connector_account_details BYTEA;
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS audit_log (
+    audit_log_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    entity_type VARCHAR(64) NOT NULL,
+    entity_id VARCHAR(64) NOT NULL,
+    action VARCHAR(64) NOT NULL,
+    actor_type VARCHAR(64) NOT NULL,
+    actor_id VARCHAR(255),
+    old_value JSONB,
+    new_value JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS audit_log_merchant_id_created_at_index ON audit_log (merchant_id, created_at);
+
+CREATE INDEX IF NOT EXISTS audit_log_merchant_id_entity_type_entity_id_index ON audit_log (merchant_id, entity_type, entity_id);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_intent_created_at_index;
+
+DROP TABLE IF EXISTS payment_attempt_archive;
+
+DROP TABLE IF EXISTS payment_intent_archive;
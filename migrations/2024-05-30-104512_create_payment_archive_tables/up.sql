@@ -0,0 +1,7 @@
+-- Your SQL goes here
+-- Archive tables share the columns, defaults and indexes of the tables they archive
+CREATE TABLE IF NOT EXISTS payment_intent_archive (LIKE payment_intent INCLUDING ALL);
+
+CREATE TABLE IF NOT EXISTS payment_attempt_archive (LIKE payment_attempt INCLUDING ALL);
+
+CREATE INDEX IF NOT EXISTS payment_intent_created_at_index ON payment_intent (created_at);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS customers_merchant_id_created_at_customer_id_index;
+
+DROP INDEX IF EXISTS refund_merchant_id_created_at_refund_id_index;
+
+DROP INDEX IF EXISTS payment_intent_merchant_id_created_at_payment_id_index;
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+-- Indexes matching the `(created_at, id)` keyset used to paginate the list endpoints
+CREATE INDEX IF NOT EXISTS payment_intent_merchant_id_created_at_payment_id_index ON payment_intent (merchant_id, created_at, payment_id);
+
+CREATE INDEX IF NOT EXISTS refund_merchant_id_created_at_refund_id_index ON refund (merchant_id, created_at, refund_id);
+
+CREATE INDEX IF NOT EXISTS customers_merchant_id_created_at_customer_id_index ON customers (merchant_id, created_at, customer_id);
@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP TRIGGER IF EXISTS refund_event_outbox ON refund;
+
+DROP TRIGGER IF EXISTS payment_intent_event_outbox ON payment_intent;
+
+DROP FUNCTION IF EXISTS record_refund_status_change;
+
+DROP FUNCTION IF EXISTS record_payment_intent_status_change;
+
+DROP TABLE IF EXISTS event_outbox;
@@ -0,0 +1,49 @@
+-- Your SQL goes here
+-- Status changes of payments and refunds are recorded here by triggers, in the same transaction as
+-- the change itself, so that the outgoing webhooks for them can be relayed even if the application
+-- stops before raising them
+CREATE TABLE IF NOT EXISTS event_outbox (
+    id BIGSERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    primary_object_id VARCHAR(64) NOT NULL,
+    event_class "EventClass" NOT NULL,
+    status VARCHAR(64) NOT NULL,
+    retry_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS event_outbox_created_at_index ON event_outbox (created_at);
+
+-- Status changes are only recorded once the outbox is enabled on the database, using
+-- `ALTER DATABASE <database> SET hyperswitch.event_outbox_enabled = 'true';`
+CREATE OR REPLACE FUNCTION record_payment_intent_status_change() RETURNS TRIGGER AS $$
+BEGIN
+    IF current_setting('hyperswitch.event_outbox_enabled', true) = 'true' THEN
+        INSERT INTO event_outbox (merchant_id, primary_object_id, event_class, status)
+        VALUES (NEW.merchant_id, NEW.payment_id, 'payments', NEW.status::TEXT);
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION record_refund_status_change() RETURNS TRIGGER AS $$
+BEGIN
+    IF current_setting('hyperswitch.event_outbox_enabled', true) = 'true' THEN
+        INSERT INTO event_outbox (merchant_id, primary_object_id, event_class, status)
+        VALUES (NEW.merchant_id, NEW.refund_id, 'refunds', NEW.refund_status::TEXT);
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Payments and refunds are created in statuses that webhooks are not raised for, so only updates
+-- are recorded
+CREATE TRIGGER payment_intent_event_outbox
+AFTER UPDATE OF status ON payment_intent
+FOR EACH ROW WHEN (NEW.status IS DISTINCT FROM OLD.status)
+EXECUTE FUNCTION record_payment_intent_status_change();
+
+CREATE TRIGGER refund_event_outbox
+AFTER UPDATE OF refund_status ON refund
+FOR EACH ROW WHEN (NEW.refund_status IS DISTINCT FROM OLD.refund_status)
+EXECUTE FUNCTION record_refund_status_change();
@@ -0,0 +1,13 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_export (
+    export_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    format VARCHAR(32) NOT NULL,
+    filters JSONB NOT NULL,
+    file_key VARCHAR(255),
+    row_count BIGINT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (export_id, merchant_id)
+);
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+-- Partial index used by the scheduler to find the payments whose status is to be synced
+CREATE INDEX IF NOT EXISTS payment_intent_pending_status_modified_at_index ON payment_intent (modified_at) WHERE status IN ('processing', 'requires_customer_action');
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_intent DROP COLUMN IF EXISTS test_mode;
+
+ALTER TABLE payment_intent_archive DROP COLUMN IF EXISTS test_mode;
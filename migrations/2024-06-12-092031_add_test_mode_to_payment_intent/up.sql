@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent ADD COLUMN IF NOT EXISTS test_mode BOOLEAN;
+
+ALTER TABLE payment_intent_archive ADD COLUMN IF NOT EXISTS test_mode BOOLEAN;
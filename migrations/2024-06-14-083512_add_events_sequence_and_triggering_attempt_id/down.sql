@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP SEQUENCE IF EXISTS events_sequence_number_seq;
+
+ALTER TABLE events DROP COLUMN IF EXISTS triggering_attempt_id;
@@ -0,0 +1,12 @@
+-- Your SQL goes here
+CREATE SEQUENCE IF NOT EXISTS events_sequence_number_seq AS BIGINT;
+
+SELECT setval(
+        'events_sequence_number_seq',
+        GREATEST(COALESCE(MAX(sequence_number), 0), 1),
+        MAX(sequence_number) IS NOT NULL
+    )
+FROM events;
+
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS triggering_attempt_id VARCHAR(64);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TRIGGER IF EXISTS payment_intent_unique_archived_payment_id ON payment_intent;
+
+DROP FUNCTION IF EXISTS reject_archived_payment_id;
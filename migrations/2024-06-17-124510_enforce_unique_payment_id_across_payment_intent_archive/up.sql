@@ -0,0 +1,21 @@
+-- Your SQL goes here
+-- Payment IDs must stay unique across the payment intent and payment intent archive tables. Payments
+-- restored from the archive keep their `id`, which a newly created payment never shares with an
+-- archived one, so they are not rejected.
+CREATE OR REPLACE FUNCTION reject_archived_payment_id() RETURNS TRIGGER AS $$
+BEGIN
+    IF EXISTS (
+        SELECT 1 FROM payment_intent_archive
+        WHERE payment_id = NEW.payment_id AND merchant_id = NEW.merchant_id AND id <> NEW.id
+    ) THEN
+        RAISE EXCEPTION 'payment intent % already exists in the archive', NEW.payment_id
+            USING ERRCODE = 'unique_violation';
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER payment_intent_unique_archived_payment_id
+BEFORE INSERT ON payment_intent
+FOR EACH ROW
+EXECUTE FUNCTION reject_archived_payment_id();
@@ -0,0 +1,18 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS connector_payload (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    flow VARCHAR(64) NOT NULL,
+    url TEXT NOT NULL,
+    method VARCHAR(16) NOT NULL,
+    status_code INTEGER NOT NULL,
+    request TEXT NOT NULL,
+    response TEXT,
+    error TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS connector_payload_merchant_id_attempt_id_index ON connector_payload (merchant_id, attempt_id);
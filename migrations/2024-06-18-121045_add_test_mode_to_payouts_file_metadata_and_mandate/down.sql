@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payouts DROP COLUMN IF EXISTS test_mode;
+
+ALTER TABLE file_metadata DROP COLUMN IF EXISTS test_mode;
+
+ALTER TABLE mandate DROP COLUMN IF EXISTS test_mode;
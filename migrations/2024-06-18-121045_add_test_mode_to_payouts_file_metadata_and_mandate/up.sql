@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE payouts ADD COLUMN IF NOT EXISTS test_mode BOOLEAN;
+
+ALTER TABLE file_metadata ADD COLUMN IF NOT EXISTS test_mode BOOLEAN;
+
+ALTER TABLE mandate ADD COLUMN IF NOT EXISTS test_mode BOOLEAN;
@@ -0,0 +1,43 @@
+#! /usr/bin/env bash
+# Creates the GSM rules that retry payments declined because the issuer was unavailable. The issuer
+# being unavailable is a transient condition that is not specific to the connector, so these
+# payments are retried on the next eligible connector.
+#
+# Usage: scripts/seed_issuer_unavailable_gsm_rules.sh <base_url> <admin_api_key>
+# Rules that already exist are reported as duplicates by the GSM API, and are left as they are.
+
+set -euo pipefail
+
+if [ "$#" -ne 2 ]; then
+    echo "Usage: $0 <base_url> <admin_api_key>" >&2
+    exit 1
+fi
+
+base_url=$1
+admin_api_key=$2
+
+# connector, code, message
+rules=(
+    "adyen|9|Issuer Unavailable"
+    "bluesnap|14002|THE_ISSUER_IS_UNAVAILABLE_OR_OFFLINE"
+    "checkout|issuer_network_unavailable|issuer_network_unavailable"
+)
+
+for rule in "${rules[@]}"; do
+    IFS='|' read -r connector code message <<< "$rule"
+    echo "Creating GSM rule for $connector: $code ($message)"
+    curl --silent --show-error --request POST "$base_url/gsm" \
+        --header "Content-Type: application/json" \
+        --header "api-key: $admin_api_key" \
+        --data "{
+            \"connector\": \"$connector\",
+            \"flow\": \"Authorize\",
+            \"sub_flow\": \"sub_flow\",
+            \"code\": \"$code\",
+            \"message\": \"$message\",
+            \"status\": \"failure\",
+            \"decision\": \"retry\",
+            \"step_up_possible\": false
+        }"
+    echo
+done